@@ -13,6 +13,77 @@ let getExceptionsUnlistenFunction: (() => void) | null = null;
 let injectErrorTrackerUnlistenFunction: (() => void) | null = null;
 let clearExceptionsUnlistenFunction: (() => void) | null = null;
 let inspectStorageUnlistenFunction: (() => void) | null = null;
+let injectConsoleCaptureUnlistenFunction: (() => void) | null = null;
+let getConsoleLogsUnlistenFunction: (() => void) | null = null;
+let jsResponseForwarderInstalled = false;
+
+// Capture buffer eviction
+// - fifo: drop the oldest entry
+// - keep_errors: drop the oldest non-error entry, falling back to fifo when only errors remain
+// - sampled: reservoir sampling, so a long session keeps an even sample instead of only the tail
+type EvictionPolicy = 'fifo' | 'keep_errors' | 'sampled';
+
+interface CaptureBuffer<T> {
+  entries: Map<string, T>;
+  maxEntries: number;
+  policy: EvictionPolicy;
+  seen: number;
+  evicted: number;
+}
+
+function createCaptureBuffer<T>(maxEntries: number): CaptureBuffer<T> {
+    return { entries: new Map(), maxEntries, policy: 'fifo', seen: 0, evicted: 0 };
+}
+
+function configureCaptureBuffer<T>(buffer: CaptureBuffer<T>, payload: any): void {
+    const policy = payload?.eviction_policy;
+    if (policy === 'fifo' || policy === 'keep_errors' || policy === 'sampled') {
+        buffer.policy = policy;
+    }
+    if (typeof payload?.max_entries === 'number' && payload.max_entries > 0) {
+        buffer.maxEntries = payload.max_entries;
+    }
+}
+
+// Insert an entry, evicting according to the buffer's policy. Returns false if the entry was dropped.
+function admitToBuffer<T>(buffer: CaptureBuffer<T>, id: string, entry: T, isError: (entry: T) => boolean): boolean {
+    buffer.seen += 1;
+
+    if (buffer.entries.size < buffer.maxEntries) {
+        buffer.entries.set(id, entry);
+        return true;
+    }
+
+    if (buffer.policy === 'sampled') {
+        // Keep the new entry with probability maxEntries / seen, replacing a random one
+        if (Math.random() >= buffer.maxEntries / buffer.seen) {
+            buffer.evicted += 1;
+            return false;
+        }
+        const keys = Array.from(buffer.entries.keys());
+        buffer.entries.delete(keys[Math.floor(Math.random() * keys.length)]);
+    } else {
+        let victim: string | undefined;
+        if (buffer.policy === 'keep_errors') {
+            for (const [key, value] of buffer.entries) {
+                if (!isError(value)) {
+                    victim = key;
+                    break;
+                }
+            }
+        }
+        if (victim === undefined) {
+            victim = buffer.entries.keys().next().value;
+        }
+        if (victim !== undefined) {
+            buffer.entries.delete(victim);
+        }
+    }
+
+    buffer.evicted += 1;
+    buffer.entries.set(id, entry);
+    return true;
+}
 
 // Network request tracking
 interface NetworkRequest {
@@ -29,11 +100,57 @@ interface NetworkRequest {
   start_time_ms: number;
   end_time_ms?: number;
   duration_ms?: number;
+  // WebSocket connections only
+  open_time_ms?: number;
+  protocol?: string;
+  close_code?: number;
+  close_reason?: string;
+  frames?: WebSocketFrame[];
+  frame_count?: number;
+}
+
+interface WebSocketFrame {
+  direction: 'sent' | 'received';
+  opcode: 'text' | 'binary';
+  size_bytes: number;
+  time_ms: number;
+  payload?: string;
 }
 
-const networkRequests: Map<string, NetworkRequest> = new Map();
+// Frames kept per connection, and the largest text frame whose payload is kept (0 keeps none)
+const websocketCapture = { maxFrames: 100, payloadLimit: 0 };
+
+const networkBuffer = createCaptureBuffer<NetworkRequest>(500); // Circular buffer limit
+const networkRequests = networkBuffer.entries;
 let networkCaptureActive = false;
-const MAX_REQUESTS = 500; // Circular buffer limit
+
+// Console log tracking
+interface ConsoleLogEntry {
+  timestamp: number;
+  level: string;
+  message: string;
+  args: string[];
+}
+
+const consoleBuffer = createCaptureBuffer<ConsoleLogEntry>(1000);
+let consoleCaptureActive = false;
+let consoleLogCounter = 0;
+
+// CSP violation tracking (read by get_csp through window.__MCP_CSP_VIOLATIONS__)
+interface CspViolationEntry {
+  timestamp: number;
+  effective_directive: string;
+  violated_directive: string;
+  blocked_uri: string;
+  source_file?: string;
+  line_number?: number;
+  column_number?: number;
+  disposition: string;
+  sample?: string;
+}
+
+const MAX_CSP_VIOLATIONS = 200;
+let cspViolationListenerInstalled = false;
 
 // Exception/Error tracking
 interface StackFrame {
@@ -61,11 +178,68 @@ const exceptions: Map<string, ExceptionEntry> = new Map();
 let errorTrackerActive = false;
 let circularBufferSize = 1000;
 
+// Capture overhead: every shim times its own bookkeeping (never the call it wraps) into a
+// registry on window that get_capture_overhead reads. Probe-installed captures write to the
+// same registry, so nested shims (a console shim running inside the error tracker) share
+// one stack and each only counts its self-time.
+interface CaptureOverhead {
+  calls: number;
+  timed_sections: number;
+  self_time_ms: number;
+  max_ms: number;
+  installed_at_ms: number;
+  active: boolean;
+}
+
+interface CaptureOverheadRegistry {
+  captures: Record<string, CaptureOverhead>;
+  stack: number[];
+}
+
+const captureOverhead: CaptureOverheadRegistry =
+    (window as any).__MCP_CAPTURE_OVERHEAD__ ||= { captures: {}, stack: [] };
+
+function overheadEntry(capture: string): CaptureOverhead {
+    return captureOverhead.captures[capture] ||= {
+        calls: 0,
+        timed_sections: 0,
+        self_time_ms: 0,
+        max_ms: 0,
+        installed_at_ms: Date.now(),
+        active: true,
+    };
+}
+
+// Run a shim's bookkeeping and charge its time to a capture. Pass intercepted = false for
+// follow-up work on a call that was already counted, like reading a fetch response.
+function measureOverhead<T>(capture: string, work: () => T, intercepted = true): T {
+    const stack = captureOverhead.stack;
+    const started = performance.now();
+    stack.push(0);
+    try {
+        return work();
+    } finally {
+        const total = performance.now() - started;
+        const self = total - (stack.pop() || 0);
+        if (stack.length > 0) {
+            stack[stack.length - 1] += total;
+        }
+        const entry = overheadEntry(capture);
+        if (intercepted) {
+            entry.calls += 1;
+        }
+        entry.timed_sections += 1;
+        entry.self_time_ms += self;
+        entry.max_ms = Math.max(entry.max_ms, self);
+    }
+}
+
 export async function setupPluginListeners() {
     const currentWindow: WebviewWindow = getCurrentWebviewWindow();
     domContentUnlistenFunction = await currentWindow.listen('got-dom-content', handleDomContentRequest);
     localStorageUnlistenFunction = await currentWindow.listen('get-local-storage', handleLocalStorageRequest);
     jsExecutionUnlistenFunction = await currentWindow.listen('execute-js', handleJsExecutionRequest);
+    installJsResponseForwarder();
     elementPositionUnlistenFunction = await currentWindow.listen('get-element-position', handleGetElementPositionRequest);
     sendTextToElementUnlistenFunction = await currentWindow.listen('send-text-to-element', handleSendTextToElementRequest);
     getNetworkRequestsUnlistenFunction = await currentWindow.listen('get-network-requests', handleGetNetworkRequestsRequest);
@@ -74,8 +248,32 @@ export async function setupPluginListeners() {
     injectErrorTrackerUnlistenFunction = await currentWindow.listen('inject-error-tracker', handleInjectErrorTracker);
     clearExceptionsUnlistenFunction = await currentWindow.listen('clear-exceptions', handleClearExceptions);
     inspectStorageUnlistenFunction = await currentWindow.listen('inspect-storage', handleInspectStorageRequest);
+    injectConsoleCaptureUnlistenFunction = await currentWindow.listen('inject-console-capture', handleInjectConsoleCapture);
+    getConsoleLogsUnlistenFunction = await currentWindow.listen('get-console-logs', handleGetConsoleLogsRequest);
+    installCspViolationListener();
+
+    console.log('TAURI-PLUGIN-MCP: Event listeners for "got-dom-content", "get-local-storage", "execute-js", "get-element-position", "send-text-to-element", network inspection, console capture, error tracking, and storage inspection are set up on the current window.');
+
+    await applyAutoInject();
+}
+
+// Activate the captures the plugin auto-injected on page load if its events
+// arrived before these listeners were registered
+async function applyAutoInject() {
+    const autoInject = (window as any).__MCP_AUTO_INJECT__;
+    if (!autoInject) {
+        return;
+    }
 
-    console.log('TAURI-PLUGIN-MCP: Event listeners for "got-dom-content", "get-local-storage", "execute-js", "get-element-position", "send-text-to-element", network inspection, error tracking, and storage inspection are set up on the current window.');
+    if (autoInject['inject-console-capture'] && !consoleCaptureActive) {
+        await handleInjectConsoleCapture({ payload: autoInject['inject-console-capture'] });
+    }
+    if (autoInject['inject-network-capture'] && !networkCaptureActive) {
+        await handleInjectNetworkCapture({ payload: autoInject['inject-network-capture'] });
+    }
+    if (autoInject['inject-error-tracker'] && !errorTrackerActive) {
+        await handleInjectErrorTracker({ payload: autoInject['inject-error-tracker'] });
+    }
 }
 
 export async function cleanupPluginListeners() {
@@ -97,6 +295,11 @@ export async function cleanupPluginListeners() {
         console.log('TAURI-PLUGIN-MCP: Event listener for "execute-js" has been removed.');
     }
 
+    if (jsResponseForwarderInstalled) {
+        window.removeEventListener('execute-js-response', forwardJsResponse);
+        jsResponseForwarderInstalled = false;
+    }
+
     if (elementPositionUnlistenFunction) {
         elementPositionUnlistenFunction();
         elementPositionUnlistenFunction = null;
@@ -145,11 +348,32 @@ export async function cleanupPluginListeners() {
         console.log('TAURI-PLUGIN-MCP: Event listener for "inspect-storage" has been removed.');
     }
 
+    if (injectConsoleCaptureUnlistenFunction) {
+        injectConsoleCaptureUnlistenFunction();
+        injectConsoleCaptureUnlistenFunction = null;
+        console.log('TAURI-PLUGIN-MCP: Event listener for "inject-console-capture" has been removed.');
+    }
+
+    if (getConsoleLogsUnlistenFunction) {
+        getConsoleLogsUnlistenFunction();
+        getConsoleLogsUnlistenFunction = null;
+        console.log('TAURI-PLUGIN-MCP: Event listener for "get-console-logs" has been removed.');
+    }
+
     // Stop network capture
     networkCaptureActive = false;
 
+    // Stop console capture
+    consoleCaptureActive = false;
+
     // Stop error tracking
     errorTrackerActive = false;
+
+    ['network', 'console', 'errors'].forEach(capture => {
+        if (captureOverhead.captures[capture]) {
+            captureOverhead.captures[capture].active = false;
+        }
+    });
 }
 
 async function handleGetElementPositionRequest(event: any) {
@@ -368,22 +592,52 @@ async function handleLocalStorageRequest(event: any) {
     }
 }
 
+// Scripts the plugin generates report their result themselves, possibly after awaiting,
+// by dispatching an 'execute-js-response' CustomEvent on window; pass those on to Rust
+function forwardJsResponse(event: Event) {
+    const detail = (event as CustomEvent).detail;
+    getCurrentWebviewWindow().emit('execute-js-response', detail).catch((e) => {
+        console.error('TAURI-PLUGIN-MCP: Error forwarding execute-js-response', e);
+    });
+}
+
+function installJsResponseForwarder(): void {
+    if (jsResponseForwarderInstalled) {
+        return;
+    }
+    jsResponseForwarderInstalled = true;
+    window.addEventListener('execute-js-response', forwardJsResponse);
+}
+
 async function handleJsExecutionRequest(event: any) {
     console.log('TAURI-PLUGIN-MCP: Received execute-js, payload:', event.payload);
 
     const currentWindow: WebviewWindow = getCurrentWebviewWindow();
 
-    const { code } = event.payload;
+    // request_id is echoed back so concurrent requests each get their own response.
+    // A bare string is a script from a plugin version without request ids.
+    const { code, request_id = null, self_reporting = false } =
+        typeof event.payload === 'string' ? { code: event.payload } : event.payload;
     try {
+        if (self_reporting) {
+            // Direct eval inside a function, so the script sees __mcpRequestId and can put
+            // it on the response it dispatches
+            // eslint-disable-next-line no-new-func
+            new Function('__mcpRequestId', '__mcpCode', 'return eval(__mcpCode);')(request_id, code);
+            return;
+        }
+        // Plain scripts run in global scope, as before
         // eslint-disable-next-line no-eval
         const result = (0, eval)(code);
         await currentWindow.emit('execute-js-response', {
+            request_id,
             success: true,
             result: typeof result === 'object' ? JSON.stringify(result) : String(result)
         });
     } catch (error) {
         console.error('TAURI-PLUGIN-MCP: Error executing JS:', error);
         await currentWindow.emit('execute-js-response', {
+            request_id,
             success: false,
             error: String(error)
         });
@@ -673,15 +927,8 @@ function recordNetworkRequest(
         request_body: requestBody,
         start_time_ms: Date.now(),
     };
-    networkRequests.set(id, request);
-
-    // Maintain circular buffer limit
-    if (networkRequests.size > MAX_REQUESTS) {
-        const firstKey = networkRequests.keys().next().value;
-        if (firstKey) {
-            networkRequests.delete(firstKey);
-        }
-    }
+    // Maintain circular buffer limit; failed requests count as errors for keep_errors
+    admitToBuffer(networkBuffer, id, request, r => !!r.error || (r.status_code !== undefined && r.status_code >= 400));
 
     return id;
 }
@@ -714,47 +961,56 @@ function recordNetworkError(id: string, error: string): void {
 function interceptFetch(): void {
     const originalFetch = window.fetch;
     window.fetch = function(...args: any[]): Promise<Response> {
-        const url = args[0]?.toString() || 'unknown';
-        const options = args[1] || {};
-        const method = (options.method || 'GET').toUpperCase();
-
-        const requestHeaders: Record<string, string> = {};
-        if (options.headers) {
-            if (options.headers instanceof Headers) {
-                options.headers.forEach((value: string, key: string) => {
-                    requestHeaders[key.toLowerCase()] = value;
-                });
-            } else if (typeof options.headers === 'object') {
-                Object.entries(options.headers).forEach(([key, value]) => {
-                    requestHeaders[key.toLowerCase()] = String(value);
-                });
+        const requestId = measureOverhead('network', () => {
+            const url = args[0]?.toString() || 'unknown';
+            const options = args[1] || {};
+            const method = (options.method || 'GET').toUpperCase();
+
+            const requestHeaders: Record<string, string> = {};
+            if (options.headers) {
+                if (options.headers instanceof Headers) {
+                    options.headers.forEach((value: string, key: string) => {
+                        requestHeaders[key.toLowerCase()] = value;
+                    });
+                } else if (typeof options.headers === 'object') {
+                    Object.entries(options.headers).forEach(([key, value]) => {
+                        requestHeaders[key.toLowerCase()] = String(value);
+                    });
+                }
             }
-        }
 
-        const requestBody = options.body ? String(options.body).substring(0, 10000) : undefined;
-        const requestId = recordNetworkRequest(url, method, 'fetch', requestHeaders, requestBody);
+            const requestBody = options.body ? String(options.body).substring(0, 10000) : undefined;
+            return recordNetworkRequest(url, method, 'fetch', requestHeaders, requestBody);
+        });
 
         return originalFetch.apply(this, args)
             .then((response: Response) => {
                 const responseHeaders: Record<string, string> = {};
-                response.headers.forEach((value: string, key: string) => {
-                    responseHeaders[key.toLowerCase()] = value;
-                });
-
                 // Try to clone and read response body
-                const clonedResponse = response.clone();
+                const clonedResponse = measureOverhead('network', () => {
+                    response.headers.forEach((value: string, key: string) => {
+                        responseHeaders[key.toLowerCase()] = value;
+                    });
+                    return response.clone();
+                }, false);
                 clonedResponse.text()
                     .then((body: string) => {
-                        updateNetworkResponse(requestId, response.status, responseHeaders, body.substring(0, 10000));
+                        measureOverhead('network', () => {
+                            updateNetworkResponse(requestId, response.status, responseHeaders, body.substring(0, 10000));
+                        }, false);
                     })
                     .catch(() => {
-                        updateNetworkResponse(requestId, response.status, responseHeaders);
+                        measureOverhead('network', () => {
+                            updateNetworkResponse(requestId, response.status, responseHeaders);
+                        }, false);
                     });
 
                 return response;
             })
             .catch((error: Error) => {
-                recordNetworkError(requestId, error.message || 'Unknown fetch error');
+                measureOverhead('network', () => {
+                    recordNetworkError(requestId, error.message || 'Unknown fetch error');
+                }, false);
                 throw error;
             });
     };
@@ -768,51 +1024,58 @@ function interceptXHR(): void {
     let xhrRequestMap = new Map<XMLHttpRequest, string>();
 
     XMLHttpRequest.prototype.open = function(method: string, url: string, ...args: any[]): void {
-        const requestHeaders: Record<string, string> = {};
-        const requestId = recordNetworkRequest(url, method, 'xhr', requestHeaders);
-        xhrRequestMap.set(this, requestId);
+        measureOverhead('network', () => {
+            const requestHeaders: Record<string, string> = {};
+            const requestId = recordNetworkRequest(url, method, 'xhr', requestHeaders);
+            xhrRequestMap.set(this, requestId);
+        });
         return originalOpen.apply(this, [method, url, ...args]);
     };
 
     XMLHttpRequest.prototype.send = function(body?: any): void {
         const requestId = xhrRequestMap.get(this);
-        const request = requestId ? networkRequests.get(requestId) : null;
 
-        if (request && this.getAllResponseHeaders) {
-            // Capture request headers set via setRequestHeader
-            // Note: We can't directly access setRequestHeader calls, but we try to get common ones
-            try {
-                const auth = (this as any).getRequestHeader?.('Authorization');
-                if (auth) request.request_headers['authorization'] = auth;
-            } catch (e) {
-                // Ignore errors
-            }
+        measureOverhead('network', () => {
+            const request = requestId ? networkRequests.get(requestId) : null;
 
-            if (body) {
-                request.request_body = String(body).substring(0, 10000);
+            if (request && this.getAllResponseHeaders) {
+                // Capture request headers set via setRequestHeader
+                // Note: We can't directly access setRequestHeader calls, but we try to get common ones
+                try {
+                    const auth = (this as any).getRequestHeader?.('Authorization');
+                    if (auth) request.request_headers['authorization'] = auth;
+                } catch (e) {
+                    // Ignore errors
+                }
+
+                if (body) {
+                    request.request_body = String(body).substring(0, 10000);
+                }
             }
-        }
+        }, false);
 
         const originalOnReadyStateChange = this.onreadystatechange;
         this.onreadystatechange = function() {
             if (this.readyState === 4 && requestId) {
-                const responseHeaders: Record<string, string> = {};
-                const headerLines = this.getAllResponseHeaders().split('\r\n');
-                headerLines.forEach((line: string) => {
-                    const colonIndex = line.indexOf(':');
-                    if (colonIndex > 0) {
-                        const key = line.substring(0, colonIndex).trim().toLowerCase();
-                        const value = line.substring(colonIndex + 1).trim();
-                        responseHeaders[key] = value;
-                    }
-                });
+                measureOverhead('network', () => {
+                    const responseHeaders: Record<string, string> = {};
+                    const headerLines = this.getAllResponseHeaders().split('\r\n');
+                    headerLines.forEach((line: string) => {
+                        const colonIndex = line.indexOf(':');
+                        if (colonIndex > 0) {
+                            const key = line.substring(0, colonIndex).trim().toLowerCase();
+                            const value = line.substring(colonIndex + 1).trim();
+                            responseHeaders[key] = value;
+                        }
+                    });
 
-                try {
-                    const responseBody = this.responseText?.substring(0, 10000);
-                    updateNetworkResponse(requestId, this.status, responseHeaders, responseBody);
-                } catch (e) {
-                    updateNetworkResponse(requestId, this.status, responseHeaders);
-                }
+                    try {
+                        const responseBody = this.responseText?.substring(0, 10000);
+                        updateNetworkResponse(requestId, this.status, responseHeaders, responseBody);
+                    } catch (e) {
+                        updateNetworkResponse(requestId, this.status, responseHeaders);
+                    }
+                }, false);
             }
 
             if (originalOnReadyStateChange) {
@@ -822,7 +1085,7 @@ function interceptXHR(): void {
 
         this.onerror = function() {
             if (requestId) {
-                recordNetworkError(requestId, 'XHR request failed');
+                measureOverhead('network', () => recordNetworkError(requestId, 'XHR request failed'), false);
             }
         };
 
@@ -832,14 +1095,118 @@ function interceptXHR(): void {
     console.log('TAURI-PLUGIN-MCP: XHR interceptor installed');
 }
 
+function frameSize(data: any): number {
+    if (typeof data === 'string') return new TextEncoder().encode(data).length;
+    if (data instanceof Blob) return data.size;
+    if (data instanceof ArrayBuffer || ArrayBuffer.isView(data)) return data.byteLength;
+    return 0;
+}
+
+function recordWebSocketFrame(id: string, direction: 'sent' | 'received', data: any): void {
+    const request = networkRequests.get(id);
+    if (!request) return;
+
+    const frame: WebSocketFrame = {
+        direction,
+        opcode: typeof data === 'string' ? 'text' : 'binary',
+        size_bytes: frameSize(data),
+        time_ms: Date.now(),
+    };
+    if (typeof data === 'string' && frame.size_bytes <= websocketCapture.payloadLimit) {
+        frame.payload = data;
+    }
+
+    const frames = request.frames || (request.frames = []);
+    frames.push(frame);
+    // Ring buffer: keep only the most recent frames
+    if (frames.length > websocketCapture.maxFrames) {
+        frames.splice(0, frames.length - websocketCapture.maxFrames);
+    }
+    request.frame_count = (request.frame_count || 0) + 1;
+}
+
+function interceptWebSocket(): void {
+    const OriginalWebSocket = window.WebSocket;
+
+    class CapturedWebSocket extends OriginalWebSocket {
+        constructor(url: string | URL, protocols?: string | string[]) {
+            super(url, protocols);
+            const requestId = measureOverhead('network', () => {
+                const id = recordNetworkRequest(String(url), 'GET', 'websocket', {});
+                const request = networkRequests.get(id);
+                if (request) {
+                    request.frames = [];
+                    request.frame_count = 0;
+                }
+                return id;
+            });
+
+            this.addEventListener('open', () => {
+                measureOverhead('network', () => {
+                    const request = networkRequests.get(requestId);
+                    if (request) {
+                        request.open_time_ms = Date.now();
+                        request.status_code = 101;
+                        request.protocol = this.protocol || undefined;
+                    }
+                }, false);
+            });
+            this.addEventListener('message', (event: MessageEvent) => {
+                measureOverhead('network', () => recordWebSocketFrame(requestId, 'received', event.data), false);
+            });
+            this.addEventListener('error', () => {
+                measureOverhead('network', () => {
+                    const request = networkRequests.get(requestId);
+                    if (request) request.error = 'WebSocket error';
+                }, false);
+            });
+            this.addEventListener('close', (event: CloseEvent) => {
+                measureOverhead('network', () => {
+                    const request = networkRequests.get(requestId);
+                    if (request) {
+                        request.close_code = event.code;
+                        request.close_reason = event.reason || undefined;
+                        request.end_time_ms = Date.now();
+                        request.duration_ms = request.end_time_ms - request.start_time_ms;
+                    }
+                }, false);
+            });
+
+            (this as any).__mcpRequestId = requestId;
+        }
+
+        send(data: string | ArrayBufferLike | Blob | ArrayBufferView): void {
+            const requestId = (this as any).__mcpRequestId;
+            if (requestId && this.readyState === OriginalWebSocket.OPEN) {
+                measureOverhead('network', () => recordWebSocketFrame(requestId, 'sent', data));
+            }
+            super.send(data);
+        }
+    }
+
+    window.WebSocket = CapturedWebSocket;
+
+    console.log('TAURI-PLUGIN-MCP: WebSocket interceptor installed');
+}
+
 async function handleInjectNetworkCapture(event: any) {
     console.log('TAURI-PLUGIN-MCP: Injecting network capture');
 
     try {
+        configureCaptureBuffer(networkBuffer, event.payload);
+        if (typeof event.payload?.websocket_max_frames === 'number' && event.payload.websocket_max_frames > 0) {
+            websocketCapture.maxFrames = event.payload.websocket_max_frames;
+        }
+        if (typeof event.payload?.websocket_payload_limit === 'number') {
+            websocketCapture.payloadLimit = event.payload.websocket_payload_limit;
+        }
+
         if (!networkCaptureActive) {
             interceptFetch();
             interceptXHR();
+            interceptWebSocket();
             networkCaptureActive = true;
+            overheadEntry('network').active = true;
             console.log('TAURI-PLUGIN-MCP: Network capture activated');
         }
 
@@ -905,8 +1272,19 @@ async function handleGetNetworkRequestsRequest(event: any) {
             requests = requests.filter(r => r.start_time_ms <= filter.end_time_ms);
         }
 
-        // Sort by start time descending (newest first)
-        requests.sort((a, b) => b.start_time_ms - a.start_time_ms);
+        // Skip requests an earlier since_last_poll query already returned
+        if (Array.isArray(filter.exclude_ids) && filter.exclude_ids.length > 0) {
+            const seen = new Set(filter.exclude_ids);
+            requests = requests.filter(r => !seen.has(r.id));
+        }
+
+        // Newest first, except for since_last_poll queries, which page forward oldest first
+        // so the limit never skips a request
+        if (filter.oldest_first) {
+            requests.sort((a, b) => a.start_time_ms - b.start_time_ms);
+        } else {
+            requests.sort((a, b) => b.start_time_ms - a.start_time_ms);
+        }
 
         // Apply limit
         const limit = filter.limit || 100;
@@ -917,6 +1295,8 @@ async function handleGetNetworkRequestsRequest(event: any) {
             requests: paginatedRequests,
             total_count: requests.length,
             capture_active: networkCaptureActive,
+            eviction_policy: networkBuffer.policy,
+            evicted_count: networkBuffer.evicted,
         });
     } catch (error) {
         console.error('TAURI-PLUGIN-MCP: Error handling network requests request', error);
@@ -930,6 +1310,153 @@ async function handleGetNetworkRequestsRequest(event: any) {
     }
 }
 
+// Console capture functions
+
+function stringifyConsoleArg(arg: any): string {
+    if (typeof arg === 'string') {
+        return arg;
+    }
+    if (arg instanceof Error) {
+        return arg.stack || arg.message;
+    }
+    try {
+        return JSON.stringify(arg);
+    } catch (e) {
+        return String(arg);
+    }
+}
+
+function interceptConsole(): void {
+    const levels: Array<[keyof Console, string]> = [
+        ['debug', 'debug'],
+        ['log', 'info'],
+        ['info', 'info'],
+        ['warn', 'warn'],
+        ['error', 'error'],
+    ];
+
+    levels.forEach(([method, level]) => {
+        const original = (console as any)[method].bind(console);
+        (console as any)[method] = (...args: any[]) => {
+            if (consoleCaptureActive) {
+                measureOverhead('console', () => {
+                    const formatted = args.map(stringifyConsoleArg);
+                    const entry: ConsoleLogEntry = {
+                        timestamp: Date.now(),
+                        level,
+                        message: formatted.join(' ').substring(0, 10000),
+                        args: formatted.map(a => a.substring(0, 2000)),
+                    };
+                    consoleLogCounter += 1;
+                    admitToBuffer(consoleBuffer, `log_${consoleLogCounter}`, entry, e => e.level === 'error');
+                });
+            }
+            original(...args);
+        };
+    });
+}
+
+let consoleInterceptorsInstalled = false;
+
+function installCspViolationListener(): void {
+    if (cspViolationListenerInstalled) {
+        return;
+    }
+    cspViolationListenerInstalled = true;
+
+    const violations: CspViolationEntry[] = ((window as any).__MCP_CSP_VIOLATIONS__ = []);
+
+    document.addEventListener('securitypolicyviolation', (event: SecurityPolicyViolationEvent) => measureOverhead('csp', () => {
+        const entry: CspViolationEntry = {
+            timestamp: Date.now(),
+            effective_directive: event.effectiveDirective,
+            violated_directive: event.violatedDirective,
+            blocked_uri: event.blockedURI,
+            source_file: event.sourceFile || undefined,
+            line_number: event.lineNumber || undefined,
+            column_number: event.columnNumber || undefined,
+            disposition: event.disposition,
+            sample: event.sample || undefined,
+        };
+        violations.push(entry);
+        if (violations.length > MAX_CSP_VIOLATIONS) {
+            violations.shift();
+        }
+
+        // Surface violations alongside console output so they show up in get_console_logs
+        if (consoleCaptureActive) {
+            const location = entry.source_file ? ` at ${entry.source_file}:${entry.line_number ?? 0}` : '';
+            const message = `Refused to load '${entry.blocked_uri || 'inline'}' because it violates the '${entry.effective_directive}' directive${location}`;
+            consoleLogCounter += 1;
+            admitToBuffer(consoleBuffer, `log_${consoleLogCounter}`, {
+                timestamp: entry.timestamp,
+                level: 'csp',
+                message,
+                args: [JSON.stringify(entry)],
+            }, () => true);
+        }
+    }));
+}
+
+async function handleInjectConsoleCapture(event: any) {
+    try {
+        configureCaptureBuffer(consoleBuffer, event.payload);
+
+        if (!consoleInterceptorsInstalled) {
+            interceptConsole();
+            consoleInterceptorsInstalled = true;
+        }
+        consoleCaptureActive = true;
+        overheadEntry('console').active = true;
+        console.log('TAURI-PLUGIN-MCP: Console capture activated');
+
+        await emit('inject-console-capture-response', { success: true });
+    } catch (error) {
+        console.error('TAURI-PLUGIN-MCP: Error injecting console capture', error);
+        await emit('inject-console-capture-response', { error: String(error) });
+    }
+}
+
+async function handleGetConsoleLogsRequest(event: any) {
+    try {
+        const filter = event.payload || {};
+
+        let logs = Array.from(consoleBuffer.entries.values());
+
+        // Filter by level ("all" or missing means every level)
+        if (filter.level && filter.level !== 'all') {
+            const level = String(filter.level).toLowerCase();
+            logs = logs.filter(l => l.level === level);
+        }
+
+        // Filter by time range
+        if (filter.start_time_ms !== undefined && filter.start_time_ms !== null) {
+            logs = logs.filter(l => l.timestamp >= filter.start_time_ms);
+        }
+        if (filter.end_time_ms !== undefined && filter.end_time_ms !== null) {
+            logs = logs.filter(l => l.timestamp <= filter.end_time_ms);
+        }
+
+        // Oldest first, keeping the newest entries when limited
+        logs.sort((a, b) => a.timestamp - b.timestamp);
+        const limit = filter.limit || 1000;
+
+        await emit('get-console-logs-response', {
+            logs: logs.slice(-limit),
+            total_count: logs.length,
+            capture_active: consoleCaptureActive,
+            eviction_policy: consoleBuffer.policy,
+            evicted_count: consoleBuffer.evicted,
+        });
+    } catch (error) {
+        await emit('get-console-logs-response', {
+            error: String(error),
+            logs: [],
+            total_count: 0,
+        });
+    }
+}
+
 // Error tracking functions
 function parseStackTrace(stack: string | undefined): StackFrame[] {
     if (!stack) {
@@ -979,43 +1506,59 @@ function recordException(errorType: string, message: string, stack: string | und
         return;
     }
 
-    const stackFrames = parseStackTrace(stack);
-    const now = Date.now();
-
-    // Create a unique key for this exception based on type and message
-    const exceptionKey = `${errorType}::${message}`;
+    measureOverhead('errors', () => {
+        const stackFrames = parseStackTrace(stack);
+        const now = Date.now();
 
-    if (exceptions.has(exceptionKey)) {
-        // Update existing exception entry
-        const existing = exceptions.get(exceptionKey)!;
-        existing.frequency += 1;
-        existing.last_occurrence_ms = now;
-    } else {
-        // Create new exception entry
-        const entry: ExceptionEntry = {
-            id: generateExceptionId(),
-            error_type: errorType,
-            message,
-            stack_trace: stackFrames,
-            first_occurrence_ms: now,
-            last_occurrence_ms: now,
-            frequency: 1,
-            error_details: errorDetails,
-        };
+        // Create a unique key for this exception based on type and message
+        const exceptionKey = `${errorType}::${message}`;
 
-        exceptions.set(exceptionKey, entry);
+        let recorded: ExceptionEntry;
+        const repeat = exceptions.has(exceptionKey);
 
-        // Enforce circular buffer limit
-        if (exceptions.size > circularBufferSize) {
-            // Remove the oldest exception
-            const firstKey = exceptions.keys().next().value;
-            if (firstKey) {
-                exceptions.delete(firstKey);
+        if (repeat) {
+            // Update existing exception entry
+            const existing = exceptions.get(exceptionKey)!;
+            existing.frequency += 1;
+            existing.last_occurrence_ms = now;
+            recorded = existing;
+        } else {
+            // Create new exception entry
+            const entry: ExceptionEntry = {
+                id: generateExceptionId(),
+                error_type: errorType,
+                message,
+                stack_trace: stackFrames,
+                first_occurrence_ms: now,
+                last_occurrence_ms: now,
+                frequency: 1,
+                error_details: errorDetails,
+            };
+
+            exceptions.set(exceptionKey, entry);
+            recorded = entry;
+
+            // Enforce circular buffer limit
+            if (exceptions.size > circularBufferSize) {
+                // Remove the oldest exception
+                const firstKey = exceptions.keys().next().value;
+                if (firstKey) {
+                    exceptions.delete(firstKey);
+                }
             }
         }
-    }
 
-    console.log(`TAURI-PLUGIN-MCP: Exception recorded [${errorType}] ${message}`);
+        console.log(`TAURI-PLUGIN-MCP: Exception recorded [${errorType}] ${message}`);
+
+        // Forwarded to socket clients by start_exception_stream; the id matches get_exceptions
+        emit('exception-recorded', {
+            window_label: getCurrentWebviewWindow().label,
+            exception: recorded,
+            repeat,
+        }).catch((error) => {
+            console.error('TAURI-PLUGIN-MCP: Error emitting exception-recorded', error);
+        });
+    });
 }
 
 function installErrorTrackers(): void {
@@ -1054,6 +1597,7 @@ async function handleInjectErrorTracker(event: any) {
         if (!errorTrackerActive) {
             installErrorTrackers();
             errorTrackerActive = true;
+            overheadEntry('errors').active = true;
             console.log('TAURI-PLUGIN-MCP: Error tracking activated');
         }
 
@@ -1142,7 +1686,7 @@ async function handleInspectStorageRequest(event: any) {
     console.log('TAURI-PLUGIN-MCP: Received inspect-storage, payload:', event.payload);
 
     const currentWindow: WebviewWindow = getCurrentWebviewWindow();
-    const { action, storage_type, key_pattern, page, page_size, db_name, store_name } = event.payload;
+    const { action, storage_type, key_pattern, page, page_size, db_name, store_name, key, value } = event.payload;
 
     try {
         let result: any = { error: null, data: null };
@@ -1156,6 +1700,25 @@ async function handleInspectStorageRequest(event: any) {
                 sessionStorage.clear();
             }
             result.data = { success: true, message: `${storage_type} cleared` };
+        } else if ((action === 'set_item' || action === 'remove_item') && (storage_type === 'localStorage' || storage_type === 'sessionStorage') && typeof key === 'string') {
+            const storage = storage_type === 'localStorage' ? localStorage : sessionStorage;
+            const existed = storage.getItem(key) !== null;
+            if (action === 'set_item') {
+                storage.setItem(key, String(value));
+            } else {
+                storage.removeItem(key);
+            }
+            // Totals use the same size accounting as get_storage
+            const totals = (await getStorageData(storage_type)).data;
+            result.data = {
+                success: true,
+                action,
+                storage_type,
+                key,
+                existed,
+                item_count: totals.total_items,
+                total_size_bytes: totals.total_size_bytes
+            };
         } else if (action === 'list_indexeddb') {
             result.data = await listIndexedDBDatabases();
         } else if (action === 'query_indexeddb' && db_name && store_name) {
@@ -0,0 +1,102 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerAnalyticsTools(server: McpServer) {
+  server.tool(
+    "capture_analytics",
+    "Installs a recorder for analytics calls: every navigator.sendBeacon call plus fetch, XHR and tracking-pixel requests to known analytics endpoints (Google Analytics, Segment, Mixpanel, Amplitude, PostHog, Plausible and others). Each request is split into events with a name and properties using the provider's payload format. Requests pass through unchanged. Read the events with get_analytics_events to check that a click fired the right tracking event.",
+    {
+      patterns: z.array(z.string()).optional().describe("Optional. URL patterns (case-insensitive regular expressions) of analytics endpoints, replacing the built-in list."),
+      extra_patterns: z.array(z.string()).optional().describe("Optional. URL patterns added to the built-in list, e.g. for a first-party collector."),
+      all_beacons: z.boolean().optional().describe("Optional. Record every sendBeacon call whatever its URL. Defaults to true."),
+      max_entries: z.number().int().min(1).max(100000).optional().describe("Optional. Events kept before the oldest are dropped. Defaults to 1000."),
+      max_payload_chars: z.number().int().min(0).max(1000000).optional().describe("Optional. Characters of the raw request payload kept. Defaults to 4000."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Capture Analytics Events",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ patterns, extra_patterns, all_beacons, max_entries, max_payload_chars, window_label, timeout_ms }) => {
+      try {
+        const params = { patterns, extra_patterns, all_beacons, max_entries, max_payload_chars, window_label: window_label || "main", timeout_ms };
+        logCommandParams("capture_analytics", params);
+
+        const result = await socketClient.sendCommand("capture_analytics", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Analytics capture error:", error);
+        return createErrorResponse(`Failed to capture analytics: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "get_analytics_events",
+    "Returns the analytics events recorded since capture_analytics, oldest first, with transport (beacon, fetch, xhr, image), provider, event name, properties and the raw payload. Filter by event name or provider, or poll with since_id. Also returns a count per event name.",
+    {
+      event_name: z.string().optional().describe("Optional. Only events with this name (case-insensitive)."),
+      provider: z.string().optional().describe("Optional. Only events from this provider: ga4, universal_analytics, segment, mixpanel, amplitude, posthog, plausible, rudderstack or generic."),
+      since_id: z.number().int().min(0).optional().describe("Optional. Only events with an id greater than this, for polling."),
+      limit: z.number().int().min(1).max(100000).optional().describe("Optional. Maximum number of events returned, newest kept. Defaults to 100."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Analytics Events",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ event_name, provider, since_id, limit, window_label, timeout_ms }) => {
+      try {
+        const params = { event_name, provider, since_id, limit, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_analytics_events", params);
+
+        const result = await socketClient.sendCommand("get_analytics_events", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Analytics read error:", error);
+        return createErrorResponse(`Failed to get analytics events: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "clear_analytics_events",
+    "Empties the analytics event buffer without removing the recorder.",
+    {
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Clear Analytics Events",
+      readOnlyHint: false,
+      destructiveHint: true,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ window_label, timeout_ms }) => {
+      try {
+        const params = { window_label: window_label || "main", timeout_ms };
+        logCommandParams("clear_analytics_events", params);
+
+        const result = await socketClient.sendCommand("clear_analytics_events", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Analytics clear error:", error);
+        return createErrorResponse(`Failed to clear analytics events: ${(error as Error).message}`);
+      }
+    }
+  );
+}
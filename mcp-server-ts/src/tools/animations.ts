@@ -0,0 +1,37 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerSetAnimationsPausedTool(server: McpServer) {
+  server.tool(
+    "set_animations_paused",
+    "Freezes or resumes CSS animations and transitions for deterministic screenshots. Pausing pauses every running animation from document.getAnimations() and injects a stylesheet that pauses later animations and zeroes transition durations. Resuming removes the stylesheet and plays only the animations this tool paused. Reports how many animations were affected.",
+    {
+      paused: z.boolean().describe("true to freeze animations and transitions, false to resume them."),
+      finish: z.boolean().optional().describe("Optional. When pausing, jump finite animations to their end state instead of freezing them mid-way. Defaults to false."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Pause or Resume Animations",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ paused, finish, window_label, timeout_ms }) => {
+      try {
+        const params = { paused, finish, window_label: window_label || "main", timeout_ms };
+        logCommandParams("set_animations_paused", params);
+
+        const result = await socketClient.sendCommand("set_animations_paused", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Animation control error:", error);
+        return createErrorResponse(`Failed to set animation state: ${(error as Error).message}`);
+      }
+    }
+  );
+}
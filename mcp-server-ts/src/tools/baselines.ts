@@ -0,0 +1,71 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerBaselineTools(server: McpServer) {
+  server.tool(
+    "save_baseline",
+    "Captures a window or element screenshot at 1x and stores it as a named PNG baseline in the plugin's baseline directory (PluginConfig::baseline_dir, or mcp-baselines in the app data directory). Use compare_baseline later to check for visual regressions.",
+    {
+      name: z.string().min(1).regex(/^[A-Za-z0-9_\-][A-Za-z0-9_.\-]*$/).describe("Baseline name; letters, digits, '-', '_' and '.'. Used as the file name."),
+      selector: z.string().min(1).optional().describe("Optional. CSS selector of an element to capture instead of the whole window."),
+      overwrite: z.boolean().optional().describe("Optional. Replace an existing baseline with this name. Defaults to true."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+    },
+    {
+      title: "Save Screenshot Baseline",
+      readOnlyHint: false,
+      destructiveHint: true,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ name, selector, overwrite, window_label }) => {
+      try {
+        const params = { name, selector, overwrite, window_label: window_label || "main" };
+        logCommandParams("save_baseline", params);
+
+        const result = await socketClient.sendCommand("save_baseline", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Save baseline error:", error);
+        return createErrorResponse(`Failed to save baseline: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "compare_baseline",
+    "Captures a fresh window or element screenshot and diffs it pixel by pixel against a stored baseline. Returns passed, status ('passed', 'failed', 'size_mismatch' or 'new'), the differing pixel count and percentage, and a diff image that marks differences in red. A missing baseline is created from the capture and reported as 'new'. On failure the capture and diff are also saved next to the baseline.",
+    {
+      name: z.string().min(1).regex(/^[A-Za-z0-9_\-][A-Za-z0-9_.\-]*$/).describe("Name of the baseline to compare against."),
+      selector: z.string().min(1).optional().describe("Optional. CSS selector of the element to capture; should match how the baseline was saved."),
+      pixel_threshold: z.number().int().min(0).max(255).optional().describe("Optional. Per-channel difference at or below which pixels count as equal, absorbing compression noise. Defaults to 24."),
+      max_diff_percent: z.number().min(0).max(100).optional().describe("Optional. Percentage of differing pixels that still passes. Defaults to 0.5."),
+      create_missing: z.boolean().optional().describe("Optional. Save the capture as the baseline when none exists. Defaults to true."),
+      include_diff_image: z.boolean().optional().describe("Optional. Return the diff image as a PNG data URL. Defaults to true."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+    },
+    {
+      title: "Compare Against Screenshot Baseline",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ name, selector, pixel_threshold, max_diff_percent, create_missing, include_diff_image, window_label }) => {
+      try {
+        const params = { name, selector, pixel_threshold, max_diff_percent, create_missing, include_diff_image, window_label: window_label || "main" };
+        logCommandParams("compare_baseline", params);
+
+        const result = await socketClient.sendCommand("compare_baseline", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Compare baseline error:", error);
+        return createErrorResponse(`Failed to compare baseline: ${(error as Error).message}`);
+      }
+    }
+  );
+}
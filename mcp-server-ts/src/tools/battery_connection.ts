@@ -0,0 +1,84 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+// Define override actions
+const ACTIONS = ["set", "clear", "get"] as const;
+
+// Define NetworkInformation effective types
+const EFFECTIVE_TYPES = ["slow-2g", "2g", "3g", "4g"] as const;
+
+// Define NetworkInformation connection types
+const CONNECTION_TYPES = ["bluetooth", "cellular", "ethernet", "none", "wifi", "wimax", "other", "unknown"] as const;
+
+export function registerBatteryConnectionTools(server: McpServer) {
+  server.tool(
+    "set_battery",
+    "Overrides what navigator.getBattery() reports (level, charging state, charging and discharging times) and dispatches the matching change events, so you can test low-battery adaptations. BatteryManager objects the app already holds see the new values. Where the webview has no Battery API (WebKit), a stand-in is installed. Omitted fields keep their current value. 'clear' restores the real readings, or removes the stand-in, and 'get' reports the current values. Overrides last until the page reloads.",
+    {
+      action: z.enum(ACTIONS).optional().describe("Optional. 'set' (default) applies the given values, 'clear' removes the override, 'get' reports the current values."),
+      level: z.number().min(0).max(1).optional().describe("Optional. Charge level from 0 to 1, e.g. 0.05 for 5%."),
+      charging: z.boolean().optional().describe("Optional. Whether the battery is charging. The time for the opposite direction becomes unknown unless given."),
+      charging_time: z.number().nonnegative().optional().describe("Optional. Seconds until fully charged."),
+      discharging_time: z.number().nonnegative().optional().describe("Optional. Seconds until empty."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Override Battery Status",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ action, level, charging, charging_time, discharging_time, window_label, timeout_ms }) => {
+      try {
+        const params = { action, level, charging, charging_time, discharging_time, window_label: window_label || "main", timeout_ms };
+        logCommandParams("set_battery", params);
+
+        const result = await socketClient.sendCommand("set_battery", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Battery override error:", error);
+        return createErrorResponse(`Failed to override battery: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "set_connection",
+    "Overrides what navigator.connection reports (effectiveType, downlink, rtt, saveData, type) and dispatches its change event, so you can test slow-connection adaptations. The existing NetworkInformation object is updated in place. Where the webview has no such API (WebKit), a stand-in is installed. Omitted fields keep their current value. This only changes what the API reports, not real network speed. 'clear' restores the real values, or removes the stand-in, and 'get' reports the current values.",
+    {
+      action: z.enum(ACTIONS).optional().describe("Optional. 'set' (default) applies the given values, 'clear' removes the override, 'get' reports the current values."),
+      effective_type: z.enum(EFFECTIVE_TYPES).optional().describe("Optional. Effective connection type: 'slow-2g', '2g', '3g' or '4g'."),
+      downlink: z.number().nonnegative().optional().describe("Optional. Bandwidth estimate in Mbit/s."),
+      rtt: z.number().nonnegative().optional().describe("Optional. Round-trip time estimate in milliseconds."),
+      save_data: z.boolean().optional().describe("Optional. Whether the user requested reduced data usage."),
+      type: z.enum(CONNECTION_TYPES).optional().describe("Optional. Connection medium, e.g. 'cellular' or 'wifi'."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Override Network Information",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ action, effective_type, downlink, rtt, save_data, type, window_label, timeout_ms }) => {
+      try {
+        const params = { action, effective_type, downlink, rtt, save_data, type, window_label: window_label || "main", timeout_ms };
+        logCommandParams("set_connection", params);
+
+        const result = await socketClient.sendCommand("set_connection", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Connection override error:", error);
+        return createErrorResponse(`Failed to override connection: ${(error as Error).message}`);
+      }
+    }
+  );
+}
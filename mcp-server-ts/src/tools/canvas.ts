@@ -0,0 +1,50 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createImageResponse, extractBase64Data, logCommandParams } from "./response-helpers.js";
+
+// Define output formats
+const FORMATS = ["jpeg", "png"] as const;
+
+export function registerCaptureCanvasTool(server: McpServer) {
+  server.tool(
+    "capture_canvas",
+    "Captures the pixels of a <canvas> element (2D or WebGL) directly from the webview, independent of window position or overlapping UI. Returns a JPEG compressed like take_screenshot, or a lossless PNG. Fails with a clear error if the canvas is tainted by cross-origin content.",
+    {
+      selector: z.string().describe("CSS selector of the <canvas> element to capture."),
+      format: z.enum(FORMATS).optional().describe("Optional. 'jpeg' (default) or 'png' for lossless output."),
+      quality: z.number().int().min(1).max(100).optional().describe("Optional. JPEG quality (1-100). Defaults to 85."),
+      max_width: z.number().int().positive().optional().describe("Optional. Maximum image width in pixels; larger canvases are scaled down."),
+      max_size_mb: z.number().positive().optional().describe("Optional. Maximum JPEG size in MB; quality is reduced to fit. Defaults to 2."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Capture Canvas Content",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ selector, format, quality, max_width, max_size_mb, window_label, timeout_ms }) => {
+      try {
+        const params = { selector, format, quality, max_width, max_size_mb, window_label: window_label || "main", timeout_ms };
+        logCommandParams("capture_canvas", params);
+
+        const result = await socketClient.sendCommand("capture_canvas", params);
+
+        const base64Data = extractBase64Data(result);
+
+        if (!base64Data) {
+          console.error("Failed to extract base64 data from response:", JSON.stringify(result));
+          return createErrorResponse(`Failed to extract image data from response: ${JSON.stringify(result).substring(0, 100)}...`);
+        }
+
+        return createImageResponse(base64Data, format === "png" ? "image/png" : "image/jpeg");
+      } catch (error) {
+        console.error("Canvas capture error:", error);
+        return createErrorResponse(`Failed to capture canvas: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,37 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerCaptureOverheadTool(server: McpServer) {
+  server.tool(
+    "get_capture_overhead",
+    "Reports how much the injected captures slow the app down. Each capture shim times its own bookkeeping (never the call it wraps), so per capture you get the number of intercepted calls, the total and mean self-time, the slowest single section and the share of wall time since it was installed. Covers network, console, csp and errors from the guest script and the live_regions, detached_nodes and reflows observers. Captures above the threshold are listed in recommend_disable; turn those off before a performance measurement. Use reset to start a fresh measurement window.",
+    {
+      reset: z.boolean().optional().describe("Optional. Zero the counters after reading them. Defaults to false."),
+      threshold_percent: z.number().min(0).max(100).optional().describe("Optional. Share of wall time, in percent, above which an active capture is recommended for disabling. Defaults to 1."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Capture Overhead",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ reset, threshold_percent, window_label, timeout_ms }) => {
+      try {
+        const params = { reset, threshold_percent, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_capture_overhead", params);
+
+        const result = await socketClient.sendCommand("get_capture_overhead", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Capture overhead error:", error);
+        return createErrorResponse(`Failed to get capture overhead: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -28,6 +28,7 @@ export class TauriSocketClient {
   private responseCallbacks: Map<string, { resolve: (value: any) => void, reject: (reason: any) => void }> = new Map();
   private buffer = '';
   private reconnectAttempts = 0;
+  private pushListeners: Map<string, Set<(data: any) => void>> = new Map();
 
   constructor(config?: ConnectionConfig) {
     // Default to IPC with default path
@@ -117,6 +118,12 @@ export class TauriSocketClient {
       
       try {
         const response = JSON.parse(jsonStr);
+
+        // Pushed events (e.g. from exception streams) carry no success flag and answer no request
+        if (response && typeof response.event === 'string' && !('success' in response)) {
+          this.dispatchPush(response.event, response.data);
+          continue;
+        }
         
         // Process all matching callbacks that might be waiting for this response
         // Rather than just taking the first one, match based on timestamps (oldest first)
@@ -172,6 +179,32 @@ export class TauriSocketClient {
     }
   }
 
+  // Subscribe to events the server pushes outside the request/response cycle
+  onPush(event: string, listener: (data: any) => void): () => void {
+    if (!this.pushListeners.has(event)) {
+      this.pushListeners.set(event, new Set());
+    }
+    this.pushListeners.get(event)!.add(listener);
+    return () => {
+      this.pushListeners.get(event)?.delete(listener);
+    };
+  }
+
+  private dispatchPush(event: string, data: any) {
+    const listeners = this.pushListeners.get(event);
+    if (!listeners || listeners.size === 0) {
+      console.error(`Received pushed event '${event}' with no listeners`);
+      return;
+    }
+    for (const listener of listeners) {
+      try {
+        listener(data);
+      } catch (err) {
+        console.error(`Error in listener for pushed event '${event}':`, err);
+      }
+    }
+  }
+
   async sendCommand(command: string, payload: Record<string, any> | string = {}): Promise<any> {
     if (!this.isConnected) {
       try {
@@ -0,0 +1,37 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerCloseRequestTool(server: McpServer) {
+  server.tool(
+    "simulate_close_request",
+    "Tests a window's unsaved-changes guard. Dispatches beforeunload in the page and reports whether a handler tried to block the close (preventDefault, returnValue or a string returned from onbeforeunload) along with any prompt text it supplied. With confirm: false (default) nothing is closed, like choosing 'Stay on page'. With confirm: true the native close is requested, like choosing 'Leave', and the result says whether the window actually closed or the app's close-requested handler kept it open. Closing the last window may exit the app.",
+    {
+      confirm: z.boolean().optional().describe("Optional. Go ahead with the close after the beforeunload check. Defaults to false, which only reports whether the guard fired."),
+      close_timeout_ms: z.number().int().min(0).max(20000).optional().describe("Optional. How long to wait for the window to close after confirming. Defaults to 2000ms."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the beforeunload check. Defaults to 5000ms."),
+    },
+    {
+      title: "Simulate Window Close Request",
+      readOnlyHint: false,
+      destructiveHint: true,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ confirm, close_timeout_ms, window_label, timeout_ms }) => {
+      try {
+        const params = { confirm, close_timeout_ms, window_label: window_label || "main", timeout_ms };
+        logCommandParams("simulate_close_request", params);
+
+        const result = await socketClient.sendCommand("simulate_close_request", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Close request error:", error);
+        return createErrorResponse(`Failed to simulate close request: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,44 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+// Define snapshot actions
+const ACTIONS = ["auto", "snapshot", "diff", "clear"] as const;
+
+export function registerDiffComputedStyleTool(server: McpServer) {
+  server.tool(
+    "diff_computed_style",
+    "Captures every computed style property of an element and stores it under snapshot_id. A later call with the same snapshot_id returns only the properties whose values changed, with before and after values. Use it to pinpoint CSS regressions, e.g. after a theme toggle. With the default 'auto' action, the first call takes the snapshot and later calls diff against it. Pass properties to compare only an allowlist.",
+    {
+      snapshot_id: z.string().min(1).describe("Id the snapshot is stored under."),
+      action: z.enum(ACTIONS).optional().describe("Optional. 'auto' (default) snapshots when none exists and diffs otherwise; 'snapshot' (re)captures; 'diff' compares; 'clear' drops the snapshot."),
+      selector: z.string().min(1).optional().describe("CSS selector of the element. Required for the first snapshot; a diff defaults to the snapshot's selector."),
+      pseudo_element: z.string().optional().describe("Optional. Pseudo-element to read, e.g. '::before'."),
+      properties: z.array(z.string()).optional().describe("Optional. Only compare these properties, e.g. ['color', 'background-color']."),
+      update_snapshot: z.boolean().optional().describe("Optional. Replace the snapshot with the current styles after diffing. Defaults to false."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Diff Computed Style",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ snapshot_id, action, selector, pseudo_element, properties, update_snapshot, window_label, timeout_ms }) => {
+      try {
+        const params = { snapshot_id, action, selector, pseudo_element, properties, update_snapshot, window_label: window_label || "main", timeout_ms };
+        logCommandParams("diff_computed_style", params);
+
+        const result = await socketClient.sendCommand("diff_computed_style", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Computed style diff error:", error);
+        return createErrorResponse(`Failed to diff computed style: ${(error as Error).message}`);
+      }
+    }
+  );
+}
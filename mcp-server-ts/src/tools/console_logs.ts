@@ -4,14 +4,17 @@ import { socketClient } from "./client.js";
 import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
 
 // Define the log levels
-const LOG_LEVELS = ["debug", "info", "warn", "error", "all"] as const;
+const LOG_LEVELS = ["debug", "info", "warn", "error", "csp", "all"] as const;
+
+// Define capture buffer eviction policies
+const EVICTION_POLICIES = ["fifo", "keep_errors", "sampled"] as const;
 
 export function registerConsoleLogsTool(server: McpServer) {
   server.tool(
     "get_console_logs",
     "Retrieves console output messages (console.log, console.error, console.warn, console.info, console.debug) captured from the webview. Allows filtering by log level and time range. Useful for debugging and monitoring runtime behavior of the application.",
     {
-      level: z.enum(LOG_LEVELS).optional().describe("Optional. Filter logs by level: 'debug', 'info', 'warn', 'error', or 'all' (default). Use 'all' or omit to get all log levels."),
+      level: z.enum(LOG_LEVELS).optional().describe("Optional. Filter logs by level: 'debug', 'info', 'warn', 'error', 'csp' (Content-Security-Policy violations), or 'all' (default). Use 'all' or omit to get all log levels."),
       start_time_ms: z.number().int().nonnegative().optional().describe("Optional. Only return logs after this Unix timestamp in milliseconds. Use for time range filtering."),
       end_time_ms: z.number().int().nonnegative().optional().describe("Optional. Only return logs before this Unix timestamp in milliseconds. Use for time range filtering."),
       limit: z.number().int().positive().optional().describe("Optional. Maximum number of log entries to return. Defaults to 1000. Use for pagination or limiting output size."),
@@ -48,7 +51,7 @@ export function registerConsoleLogsTool(server: McpServer) {
 
         // Format the result as text for display
         if (typeof result === 'object' && result && 'logs' in result) {
-          const logsData = result as { logs: Array<{ timestamp: number; level: string; message: string; args: string[] }>; total_count: number; returned_count: number };
+          const logsData = result as { logs: Array<{ timestamp: number; level: string; message: string; args: string[] }>; total_count: number; returned_count: number; eviction_policy?: string; evicted_count?: number };
 
           if (logsData.logs.length === 0) {
             return createSuccessResponse("No console logs found matching the specified criteria.");
@@ -64,7 +67,8 @@ export function registerConsoleLogsTool(server: McpServer) {
             })
             .join('\n');
 
-          const summary = `Console Logs (${logsData.returned_count} of ${logsData.total_count} total)\n\n${formattedLogs}`;
+          const evictionNote = logsData.eviction_policy ? ` [Eviction: ${logsData.eviction_policy}, ${logsData.evicted_count ?? 0} evicted]` : "";
+          const summary = `Console Logs (${logsData.returned_count} of ${logsData.total_count} total)${evictionNote}\n\n${formattedLogs}`;
           return createSuccessResponse(summary);
         }
 
@@ -83,6 +87,8 @@ export function registerInjectConsoleCaptureTool(server: McpServer) {
     "Injects the console capture script into the webview to start capturing console.log, console.error, console.warn, console.info, and console.debug calls. This must be called once when the application starts to enable console log retrieval. Subsequent calls re-inject the capture mechanism.",
     {
       window_label: z.string().optional().describe("Optional. The identifier of the application window to inject the capture script into. Defaults to 'main' if not specified."),
+      eviction_policy: z.enum(EVICTION_POLICIES).optional().describe("Optional. How the capture buffer makes room when full: 'fifo' (default) drops the oldest entry, 'keep_errors' protects error-level entries from eviction, 'sampled' keeps an even sample across the whole session."),
+      max_entries: z.number().int().positive().optional().describe("Optional. Capacity of the capture buffer. Defaults to 1000."),
     },
     {
       title: "Inject Console Capture Script into Webview",
@@ -91,17 +97,17 @@ export function registerInjectConsoleCaptureTool(server: McpServer) {
       idempotentHint: false,
       openWorldHint: false,
     },
-    async ({ window_label }) => {
+    async ({ window_label, eviction_policy, max_entries }) => {
       try {
         const params = {
-          window_label: window_label || "main"
+          window_label: window_label || "main",
+          eviction_policy,
+          max_entries,
         };
 
         logCommandParams('inject_console_capture', params);
 
-        const result = await socketClient.sendCommand('inject_console_capture', {
-          window_label: params.window_label
-        });
+        const result = await socketClient.sendCommand('inject_console_capture', params);
 
         console.error(`Console capture injection result: ${typeof result}`);
 
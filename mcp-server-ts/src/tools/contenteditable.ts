@@ -0,0 +1,102 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+// Define insertion modes
+const MODES = ["replace", "append"] as const;
+
+export function registerContentEditableTools(server: McpServer) {
+  server.tool(
+    "get_contenteditable",
+    "Returns the innerHTML and plain text of a contenteditable region, such as a rich-text or note editor. Fails if the element is not editable.",
+    {
+      selector: z.string().min(1).describe("CSS selector of the editable element (or an element inside it)."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Contenteditable Content",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ selector, window_label, timeout_ms }) => {
+      try {
+        const params = { selector, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_contenteditable", params);
+
+        const result = await socketClient.sendCommand("get_contenteditable", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Contenteditable error:", error);
+        return createErrorResponse(`Failed to read contenteditable: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "set_contenteditable",
+    "Replaces or appends to the content of a contenteditable region. Inserts through execCommand so the editor receives the same beforeinput/input events as typing, and falls back to a Range edit plus a synthetic input event. Pass exactly one of text or html. Returns the resulting HTML and text and the insertion method used.",
+    {
+      selector: z.string().min(1).describe("CSS selector of the editable element (or an element inside it)."),
+      text: z.string().optional().describe("Plain text to insert. Provide either text or html."),
+      html: z.string().optional().describe("HTML to insert. Provide either text or html."),
+      mode: z.enum(MODES).optional().describe("Optional. 'replace' (default) replaces all content; 'append' inserts at the end."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Set Contenteditable Content",
+      readOnlyHint: false,
+      destructiveHint: true,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ selector, text, html, mode, window_label, timeout_ms }) => {
+      try {
+        const params = { selector, text, html, mode, window_label: window_label || "main", timeout_ms };
+        logCommandParams("set_contenteditable", params);
+
+        const result = await socketClient.sendCommand("set_contenteditable", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Contenteditable error:", error);
+        return createErrorResponse(`Failed to set contenteditable: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "set_design_mode",
+    "Reads or toggles document.designMode, which makes the whole page editable like a text editor. Pass enabled: true to turn it on, false to turn it off, or omit it to only read the current state. Useful for manual content experiments and for quickly mutating arbitrary text. Same-origin iframes keep their own setting.",
+    {
+      enabled: z.boolean().optional().describe("Optional. true turns design mode on, false turns it off. Omit to only read the current state."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Set Design Mode",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ enabled, window_label, timeout_ms }) => {
+      try {
+        const params = { enabled, window_label: window_label || "main", timeout_ms };
+        logCommandParams("set_design_mode", params);
+
+        const result = await socketClient.sendCommand("set_design_mode", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Design mode error:", error);
+        return createErrorResponse(`Failed to set design mode: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,43 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+// Define WCAG conformance levels
+const LEVELS = ["AA", "AAA"] as const;
+
+export function registerCheckContrastTool(server: McpServer) {
+  server.tool(
+    "check_contrast",
+    "Checks WCAG text contrast. For each visible text element (from selectors, or scanned under a root), computes the foreground color and the effective background by compositing translucent ancestor backgrounds, then the contrast ratio against the AA or AAA threshold for its font size (large text: 24px, or 18.66px bold). Returns failures sorted worst first. Results over background images or gradients are marked indeterminate.",
+    {
+      selectors: z.array(z.string()).optional().describe("Optional. CSS selectors of the elements to check; every match is checked."),
+      root_selector: z.string().min(1).optional().describe("Optional. When no selectors are given, scan text elements inside this element. Defaults to the whole body."),
+      level: z.enum(LEVELS).optional().describe("Optional. WCAG level to check against. Defaults to 'AA'."),
+      include_passing: z.boolean().optional().describe("Optional. Also return elements that pass. Defaults to false."),
+      max_elements: z.number().int().min(1).max(10000).optional().describe("Optional. Maximum number of text elements to check. Defaults to 500."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Check Contrast Ratios",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ selectors, root_selector, level, include_passing, max_elements, window_label, timeout_ms }) => {
+      try {
+        const params = { selectors, root_selector, level, include_passing, max_elements, window_label: window_label || "main", timeout_ms };
+        logCommandParams("check_contrast", params);
+
+        const result = await socketClient.sendCommand("check_contrast", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Contrast check error:", error);
+        return createErrorResponse(`Failed to check contrast: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,37 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerGetCspTool(server: McpServer) {
+  server.tool(
+    "get_csp",
+    "Reports the Content-Security-Policy active in the webview: policies from <meta http-equiv> tags and response headers (parsed into directives), the CSP configured in tauri.conf.json, and recently recorded securitypolicyviolation events. Useful for diagnosing why scripts, styles, or resource loads are blocked in production builds. Violations are also surfaced in get_console_logs under the 'csp' level while console capture is active.",
+    {
+      include_headers: z.boolean().optional().describe("Optional. Re-request the current document to read its CSP response headers. Defaults to true."),
+      violation_limit: z.number().int().min(0).optional().describe("Optional. Maximum number of recent violations to return. Defaults to 50."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window to inspect. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Content-Security-Policy",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ include_headers, violation_limit, window_label, timeout_ms }) => {
+      try {
+        const params = { include_headers, violation_limit, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_csp", params);
+
+        const result = await socketClient.sendCommand("get_csp", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("CSP inspection error:", error);
+        return createErrorResponse(`Failed to get CSP: ${(error as Error).message}`);
+      }
+    }
+  );
+}
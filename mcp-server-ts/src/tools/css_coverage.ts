@@ -0,0 +1,38 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerCssCoverageTool(server: McpServer) {
+  server.tool(
+    "get_css_coverage",
+    "Checks every stylesheet rule against the current DOM and reports used and unused rule counts per sheet, with a sample of unused selectors. This is a snapshot, not runtime coverage: rules for views that are not rendered count as unused. Interaction states such as :hover are ignored when matching and counted as state_dependent. Rules in @media blocks that do not currently apply are counted separately. Cross-origin sheets that cannot be read are marked inaccessible.",
+    {
+      max_sheets: z.number().int().min(1).max(500).optional().describe("Optional. Maximum number of stylesheets to report. Defaults to 50."),
+      max_unused_per_sheet: z.number().int().min(0).max(500).optional().describe("Optional. Unused selectors sampled per stylesheet. Defaults to 20."),
+      include_inactive_media: z.boolean().optional().describe("Optional. Evaluate rules in @media blocks that do not currently apply instead of counting them separately. Defaults to false."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get CSS Coverage",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ max_sheets, max_unused_per_sheet, include_inactive_media, window_label, timeout_ms }) => {
+      try {
+        const params = { max_sheets, max_unused_per_sheet, include_inactive_media, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_css_coverage", params);
+
+        const result = await socketClient.sendCommand("get_css_coverage", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("CSS coverage error:", error);
+        return createErrorResponse(`Failed to get CSS coverage: ${(error as Error).message}`);
+      }
+    }
+  );
+}
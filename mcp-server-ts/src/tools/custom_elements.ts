@@ -0,0 +1,37 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerCustomElementsTool(server: McpServer) {
+  server.tool(
+    "get_custom_elements",
+    "Takes a census of the web components on the page. Finds every custom element tag in the DOM, including customized built-ins (is=\"...\") and, by default, instances inside open shadow roots. For each tag it reports whether it is defined in customElements, its class name, how many instances exist and how many are upgraded, whether their shadow roots are open, observed attributes, and example selectors. Tags that are used but never defined are listed separately. Definitions with no instances in the DOM cannot be discovered.",
+    {
+      pierce_shadow: z.boolean().optional().describe("Optional. Also count instances inside open shadow roots. Defaults to true."),
+      max_samples: z.number().int().min(0).max(100).optional().describe("Optional. Example selectors listed per tag. Defaults to 3."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Custom Elements",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ pierce_shadow, max_samples, window_label, timeout_ms }) => {
+      try {
+        const params = { pierce_shadow, max_samples, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_custom_elements", params);
+
+        const result = await socketClient.sendCommand("get_custom_elements", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Custom elements error:", error);
+        return createErrorResponse(`Failed to get custom elements: ${(error as Error).message}`);
+      }
+    }
+  );
+}
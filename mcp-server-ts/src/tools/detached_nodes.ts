@@ -0,0 +1,42 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+// Define tracking actions
+const ACTIONS = ["start", "report", "stop"] as const;
+
+export function registerFindDetachedNodesTool(server: McpServer) {
+  server.tool(
+    "find_detached_nodes",
+    "Heuristically finds DOM leaks: subtrees removed from the document that JS still references. Call 'start' to track removals as weak references, perform the action under test (e.g. open and close a modal a few times), then 'report'. The report attempts garbage collection first and returns detached subtree counts by tag with the largest samples. Counts are an upper bound unless the webview exposes gc().",
+    {
+      action: z.enum(ACTIONS).optional().describe("Optional. 'start' begins tracking; 'report' (default) counts detached subtrees still alive; 'stop' reports and removes the tracker."),
+      force_gc: z.boolean().optional().describe("Optional. Try to collect garbage before counting. Defaults to true."),
+      min_age_ms: z.number().int().nonnegative().optional().describe("Optional. Ignore nodes removed less than this many milliseconds ago. Defaults to 0."),
+      sample_size: z.number().int().min(0).max(100).optional().describe("Optional. Number of sample detached subtrees to return. Defaults to 10."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Find Detached DOM Nodes",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ action, force_gc, min_age_ms, sample_size, window_label, timeout_ms }) => {
+      try {
+        const params = { action, force_gc, min_age_ms, sample_size, window_label: window_label || "main", timeout_ms };
+        logCommandParams("find_detached_nodes", params);
+
+        const result = await socketClient.sendCommand("find_detached_nodes", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Detached node detection error:", error);
+        return createErrorResponse(`Failed to find detached nodes: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,69 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+// Define media device kinds
+const DEVICE_KINDS = ["audioinput", "audiooutput", "videoinput"] as const;
+
+export function registerDeviceTools(server: McpServer) {
+  server.tool(
+    "enumerate_devices",
+    "Lists media devices from navigator.mediaDevices.enumerateDevices() with kind, label, device_id and group_id, plus counts per kind. Labels are empty until camera/microphone permission is granted; the result flags this with labels_hidden.",
+    {
+      kind: z.enum(DEVICE_KINDS).optional().describe("Optional. Only return devices of this kind."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Enumerate Media Devices",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ kind, window_label, timeout_ms }) => {
+      try {
+        const params = { kind, window_label: window_label || "main", timeout_ms };
+        logCommandParams("enumerate_devices", params);
+
+        const result = await socketClient.sendCommand("enumerate_devices", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Device enumeration error:", error);
+        return createErrorResponse(`Failed to enumerate devices: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "get_permissions_state",
+    "Queries the Permissions API and returns 'granted', 'denied' or 'prompt' for each permission name. Names the engine does not know are reported as 'unsupported'. Defaults to camera, microphone, geolocation, notifications, clipboard-read, clipboard-write and persistent-storage.",
+    {
+      names: z.array(z.string()).optional().describe("Optional. Permission names to query, e.g. ['camera', 'microphone']."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Permissions State",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ names, window_label, timeout_ms }) => {
+      try {
+        const params = { names, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_permissions_state", params);
+
+        const result = await socketClient.sendCommand("get_permissions_state", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Permissions query error:", error);
+        return createErrorResponse(`Failed to query permissions: ${(error as Error).message}`);
+      }
+    }
+  );
+}
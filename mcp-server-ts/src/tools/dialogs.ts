@@ -0,0 +1,42 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+// Define modes
+const MODES = ["once", "persistent", "off"] as const;
+
+export function registerNextDialogTool(server: McpServer) {
+  server.tool(
+    "handle_next_dialog",
+    "Captures and auto-answers JavaScript dialogs (alert/confirm/prompt) so they never block automation. Records each dialog's type, message, and the response given, and can wait for the next dialog to appear and return it. Call it before the action that opens the dialog, or with wait_ms to block until one shows up.",
+    {
+      mode: z.enum(MODES).optional().describe("Optional. 'once' (default) restores the native dialogs after answering one, 'persistent' keeps answering, 'off' restores them immediately."),
+      accept: z.boolean().optional().describe("Optional. Accept confirm/prompt dialogs (true, default) or dismiss them (false)."),
+      prompt_text: z.string().optional().describe("Optional. Text to return from prompt dialogs when accepting. Defaults to the prompt's default value."),
+      wait_ms: z.number().int().nonnegative().optional().describe("Optional. Wait up to this many milliseconds for a dialog to appear and return it. Defaults to 0 (only arm the handler)."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Extra time in milliseconds to wait for the result on top of wait_ms. Defaults to 5000ms."),
+    },
+    {
+      title: "Capture and Answer JavaScript Dialogs",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ mode, accept, prompt_text, wait_ms, window_label, timeout_ms }) => {
+      try {
+        const params = { mode, accept, prompt_text, wait_ms, window_label: window_label || "main", timeout_ms };
+        logCommandParams("handle_next_dialog", params);
+
+        const result = await socketClient.sendCommand("handle_next_dialog", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Dialog handler error:", error);
+        return createErrorResponse(`Failed to handle dialog: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,125 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerDocumentTools(server: McpServer) {
+  server.tool(
+    "get_document_title",
+    "Returns the page's document.title together with the native window title and the current URL. Tauri does not keep the two titles in sync, so both are reported.",
+    {
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Document Title",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ window_label, timeout_ms }) => {
+      try {
+        const params = { window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_document_title", params);
+
+        const result = await socketClient.sendCommand("get_document_title", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Document title error:", error);
+        return createErrorResponse(`Failed to get document title: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "set_document_title",
+    "Sets document.title and returns the previous title. Set sync_window_title to also change the native window title.",
+    {
+      title: z.string().describe("The new document title."),
+      sync_window_title: z.boolean().optional().describe("Optional. Also set the native window title. Defaults to false."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Set Document Title",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ title, sync_window_title, window_label, timeout_ms }) => {
+      try {
+        const params = { title, sync_window_title, window_label: window_label || "main", timeout_ms };
+        logCommandParams("set_document_title", params);
+
+        const result = await socketClient.sendCommand("set_document_title", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Document title error:", error);
+        return createErrorResponse(`Failed to set document title: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "get_favicon",
+    "Returns the URL of the favicon the page is using and every icon link it declares. Falls back to /favicon.ico when no icon is declared. Set include_data to also fetch the icon and return it as a base64 data URL, which is useful for checking dynamically generated badge icons.",
+    {
+      include_data: z.boolean().optional().describe("Optional. Fetch the active icon and include it as a data URL. Defaults to false."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Favicon",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ include_data, window_label, timeout_ms }) => {
+      try {
+        const params = { include_data, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_favicon", params);
+
+        const result = await socketClient.sendCommand("get_favicon", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Favicon error:", error);
+        return createErrorResponse(`Failed to get favicon: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "get_metadata",
+    "Returns the page's metadata for SEO and social-preview checks: every <meta> tag (name, property, content), all <link rel> relations, the canonical URL, and parsed JSON-LD structured data blocks. Open Graph and Twitter card tags are also grouped into objects. JSON-LD blocks that fail to parse are reported with the error.",
+    {
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Page Metadata",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ window_label, timeout_ms }) => {
+      try {
+        const params = { window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_metadata", params);
+
+        const result = await socketClient.sendCommand("get_metadata", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Metadata error:", error);
+        return createErrorResponse(`Failed to get metadata: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,38 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerDomStatsTool(server: McpServer) {
+  server.tool(
+    "get_dom_stats",
+    "Measures the size of the DOM for the whole document or an optional root element. Returns the total node count (elements, text, comments and open shadow roots), the maximum tree depth with the single deepest path, the element with the most direct children, and element counts by tag. Each call also reports growth since the previous call for the same root. Poll it periodically to catch runaway DOM growth, such as a list that never recycles rows.",
+    {
+      root_selector: z.string().min(1).optional().describe("Optional. Only measure the subtree of this element. Defaults to the whole document."),
+      pierce_shadow: z.boolean().optional().describe("Optional. Count nodes inside open shadow roots. Defaults to true."),
+      top_tags: z.number().int().min(1).max(1000).optional().describe("Optional. Number of tags listed in the per-tag counts, most frequent first. Defaults to 30."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get DOM Statistics",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ root_selector, pierce_shadow, top_tags, window_label, timeout_ms }) => {
+      try {
+        const params = { root_selector, pierce_shadow, top_tags, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_dom_stats", params);
+
+        const result = await socketClient.sendCommand("get_dom_stats", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("DOM stats error:", error);
+        return createErrorResponse(`Failed to get DOM stats: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,48 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+// Define drag backends
+const DRAG_BACKENDS = ["auto", "html5", "pointer"] as const;
+
+export function registerReorderItemTool(server: McpServer) {
+  server.tool(
+    "reorder_item",
+    "Drags an item of a sortable list from one index to another and returns the order before and after, the expected order and whether they match. The 'html5' backend sends the full dragstart/dragover/drop/dragend sequence (native draggable, SortableJS); 'pointer' sends stepwise pointer and mouse events (dnd-kit, react-beautiful-dnd, SortableJS fallback mode). 'auto' picks html5 for draggable items and falls back to the other backend when the order does not change. Items are identified by data-id, id or text, so re-rendered lists are compared correctly.",
+    {
+      selector: z.string().min(1).describe("CSS selector of the sortable list container"),
+      from_index: z.number().int().min(0).describe("Zero-based index of the item to move"),
+      to_index: z.number().int().min(0).describe("Zero-based index the item should end up at"),
+      item_selector: z.string().min(1).optional().describe("Optional. Selector of the items inside the container. Defaults to its visible child elements."),
+      handle_selector: z.string().min(1).optional().describe("Optional. Selector of the drag handle inside the item. Defaults to the item itself."),
+      backend: z.enum(DRAG_BACKENDS).optional().describe("Optional. 'html5', 'pointer' or 'auto'. Defaults to 'auto'."),
+      steps: z.number().int().min(1).max(200).optional().describe("Optional. Number of intermediate moves. Defaults to 12."),
+      step_delay_ms: z.number().int().min(0).max(1000).optional().describe("Optional. Delay between moves in milliseconds. Defaults to 16."),
+      hold_ms: z.number().int().min(0).max(5000).optional().describe("Optional. Time to hold the pointer down before moving, for sensors with an activation delay. Defaults to 0."),
+      settle_ms: z.number().int().min(0).max(10000).optional().describe("Optional. Time to wait after the drop before reading the order. Defaults to 150."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result, on top of the drag itself. Defaults to 5000ms."),
+    },
+    {
+      title: "Reorder Item",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ selector, from_index, to_index, item_selector, handle_selector, backend, steps, step_delay_ms, hold_ms, settle_ms, window_label, timeout_ms }) => {
+      try {
+        const params = { selector, from_index, to_index, item_selector, handle_selector, backend, steps, step_delay_ms, hold_ms, settle_ms, window_label: window_label || "main", timeout_ms };
+        logCommandParams("reorder_item", params);
+
+        const result = await socketClient.sendCommand("reorder_item", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Reorder error:", error);
+        return createErrorResponse(`Failed to reorder item: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,38 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerGetTextTool(server: McpServer) {
+  server.tool(
+    "get_text",
+    "Reads the text of one element: its rendered text (innerText, excluding hidden content) and, with include_hidden, its full textContent. Whitespace is collapsed by default. Form fields also report their current value. Fails if the selector matches nothing; match_count shows when it matches several (the first is used). The quickest way to assert on an element's text.",
+    {
+      selector: z.string().describe("CSS selector of the element to read."),
+      normalize_whitespace: z.boolean().optional().describe("Optional. Collapse whitespace runs into single spaces and trim. Defaults to true."),
+      include_hidden: z.boolean().optional().describe("Optional. Also return textContent, which includes text hidden by CSS. Defaults to false."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Element Text",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ selector, normalize_whitespace, include_hidden, window_label, timeout_ms }) => {
+      try {
+        const params = { selector, normalize_whitespace, include_hidden, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_text", params);
+
+        const result = await socketClient.sendCommand("get_text", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Get text error:", error);
+        return createErrorResponse(`Failed to get text: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -160,3 +160,84 @@ export function registerClearExceptionsTool(server: McpServer) {
     },
   );
 }
+
+// Exceptions pushed while a stream is active, per window and deduplicated by id
+const streamedExceptions: Map<string, Map<string, any>> = new Map();
+const streamUnsubscribers: Map<string, () => void> = new Map();
+
+export function registerExceptionStreamTools(server: McpServer) {
+  server.tool(
+    "start_exception_stream",
+    "Starts pushing exceptions over the socket the moment the error tracker records them, instead of relying on a get_exceptions poll that can miss the error that crashes the app. Requires the error tracker (inject_error_tracker or auto-injection). Pushed exceptions are collected until stop_exception_stream, which returns them. Exceptions first recorded before the stream started, including later repeats of them, are not pushed; they are only in get_exceptions (use end_time_ms = started_at_ms).",
+    {
+      window_label: z.string().optional().describe("Optional. The identifier of the application window to stream exceptions from. Defaults to 'main' if not specified."),
+    },
+    {
+      title: "Start Exception Stream",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ window_label }) => {
+      try {
+        const label = window_label || "main";
+        const params = { window_label: label };
+        logCommandParams("start_exception_stream", params);
+
+        if (!streamUnsubscribers.has(label)) {
+          streamedExceptions.set(label, new Map());
+          streamUnsubscribers.set(label, socketClient.onPush("exception", (data) => {
+            if (!data || data.window_label !== label || !data.exception) {
+              return;
+            }
+            // Repeats of a known exception update its frequency instead of adding an entry
+            streamedExceptions.get(label)?.set(data.exception.id, data.exception);
+            console.error(`Streamed exception [${data.exception.error_type}] ${data.exception.message}`);
+          }));
+        }
+
+        const result = await socketClient.sendCommand("start_exception_stream", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Exception stream error:", error);
+        return createErrorResponse(`Failed to start exception stream: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "stop_exception_stream",
+    "Stops the exception stream for a window and returns every exception pushed while it was active, one entry per exception id with its latest frequency.",
+    {
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+    },
+    {
+      title: "Stop Exception Stream",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ window_label }) => {
+      try {
+        const label = window_label || "main";
+        const params = { window_label: label };
+        logCommandParams("stop_exception_stream", params);
+
+        const result = await socketClient.sendCommand("stop_exception_stream", params);
+
+        streamUnsubscribers.get(label)?.();
+        streamUnsubscribers.delete(label);
+        const exceptions = Array.from(streamedExceptions.get(label)?.values() ?? []);
+        streamedExceptions.delete(label);
+
+        return createSuccessResponse(formatResultAsText({ ...result, streamed_count: exceptions.length, exceptions }));
+      } catch (error) {
+        console.error("Exception stream error:", error);
+        return createErrorResponse(`Failed to stop exception stream: ${(error as Error).message}`);
+      }
+    }
+  );
+}
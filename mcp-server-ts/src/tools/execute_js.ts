@@ -47,4 +47,36 @@ export function registerExecuteJsTool(server: McpServer) {
       }
     },
   );
-} 
\ No newline at end of file
+} 
+export function registerExecuteJsFileTool(server: McpServer) {
+  server.tool(
+    "execute_js_file",
+    "Evaluates a JavaScript file from the host machine in a webview window, with the same result handling as execute_js. Values in args are available to the script as variables of the same name. The file must live inside one of the directories the app allows via PluginConfig::js_file_roots; the command is disabled otherwise. Keeps large probe scripts versioned in files instead of inline strings.",
+    {
+      path: z.string().describe("Path of the script on the host machine. Must be inside an allowed directory."),
+      args: z.record(z.any()).optional().describe("Optional. Variables to expose to the script, e.g. { selector: '#login' }. Keys must be valid JavaScript identifiers other than reserved words (class, return, let, eval, ...)."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Execute JavaScript File in Webview",
+      readOnlyHint: false,
+      destructiveHint: true,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ path, args, window_label, timeout_ms }) => {
+      try {
+        const params = { path, args, window_label: window_label || "main", timeout_ms };
+        logCommandParams("execute_js_file", params);
+
+        const result = await socketClient.sendCommand("execute_js_file", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("JS file execution error:", error);
+        return createErrorResponse(`Failed to execute JavaScript file: ${(error as Error).message}`);
+      }
+    },
+  );
+}
@@ -0,0 +1,79 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerFixtureTools(server: McpServer) {
+  server.tool(
+    "capture_fixture",
+    "Captures the app's current renderable and logical state as one named fixture: a normalized DOM snapshot (tags, sorted attributes, text, live form values), the semantic outline, the state dump and a window screenshot. The bundle is written as <name>.fixture.json to the baseline directory, with the screenshot stored as the baseline <name>.fixture. Use assert_fixture later as a snapshot test.",
+    {
+      name: z.string().min(1).describe("Fixture name; letters, digits, '-', '_' and '.'"),
+      root_selector: z.string().min(1).optional().describe("Optional. Element whose subtree is captured. Defaults to body."),
+      ignore_selectors: z.array(z.string().min(1)).optional().describe("Optional. Subtrees left out of the DOM snapshot, such as clocks or ads."),
+      ignore_attributes: z.array(z.string().min(1)).optional().describe("Optional. Attributes left out of the DOM snapshot, such as generated ids."),
+      max_nodes: z.number().int().min(1).max(50000).optional().describe("Optional. Maximum number of elements in the DOM snapshot. Defaults to 2000."),
+      state_path: z.string().optional().describe("Optional. Keep only this part of the state dump, in state_dump path syntax."),
+      max_depth: z.number().int().min(1).optional().describe("Optional. Depth limit of the state dump. Defaults to 10."),
+      include_screenshot: z.boolean().optional().describe("Optional. Store a window screenshot with the fixture. Defaults to true."),
+      overwrite: z.boolean().optional().describe("Optional. Replace an existing fixture. Defaults to true."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for each capture step. Defaults to 5000ms."),
+    },
+    {
+      title: "Capture Fixture",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ name, root_selector, ignore_selectors, ignore_attributes, max_nodes, state_path, max_depth, include_screenshot, overwrite, window_label, timeout_ms }) => {
+      try {
+        const params = { name, root_selector, ignore_selectors, ignore_attributes, max_nodes, state_path, max_depth, include_screenshot, overwrite, window_label: window_label || "main", timeout_ms };
+        logCommandParams("capture_fixture", params);
+
+        const result = await socketClient.sendCommand("capture_fixture", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Fixture capture error:", error);
+        return createErrorResponse(`Failed to capture fixture: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "assert_fixture",
+    "Re-captures a fixture with the options it was stored with and diffs it against the stored bundle: DOM, outline and state changes as paths with before/after values, URL/title/viewport changes and the screenshot pixel diff, all in one pass/fail result. With update the fixture is replaced by the fresh capture when it differs.",
+    {
+      name: z.string().min(1).describe("Name of a fixture stored with capture_fixture"),
+      max_changes: z.number().int().min(1).optional().describe("Optional. Maximum changes listed per section. Defaults to 100."),
+      pixel_threshold: z.number().int().min(0).max(255).optional().describe("Optional. Per-channel difference below which pixels count as equal. Defaults to 24."),
+      max_diff_percent: z.number().min(0).max(100).optional().describe("Optional. Percentage of differing pixels that still passes. Defaults to 0.5."),
+      include_diff_image: z.boolean().optional().describe("Optional. Return the screenshot diff image as a data URL. Defaults to false."),
+      update: z.boolean().optional().describe("Optional. Replace the stored fixture with the fresh capture when it differs. Defaults to false."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to the window the fixture was captured from."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for each capture step. Defaults to 5000ms."),
+    },
+    {
+      title: "Assert Fixture",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ name, max_changes, pixel_threshold, max_diff_percent, include_diff_image, update, window_label, timeout_ms }) => {
+      try {
+        const params = { name, max_changes, pixel_threshold, max_diff_percent, include_diff_image, update, window_label, timeout_ms };
+        logCommandParams("assert_fixture", params);
+
+        const result = await socketClient.sendCommand("assert_fixture", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Fixture assertion error:", error);
+        return createErrorResponse(`Failed to assert fixture: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,37 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerFontInfoTool(server: McpServer) {
+  server.tool(
+    "get_font_info",
+    "Reports the font actually used to render each selected element: the requested font-family stack, the first family that is available on this system, whether a fallback was used, and the computed size, weight, and style. Optionally detects characters rendered as missing-glyph boxes (tofu). Also lists registered web fonts and their load status. Useful for explaining screenshot differences between macOS, Windows, and Linux.",
+    {
+      selectors: z.array(z.string()).min(1).describe("CSS selectors of the elements to inspect. The first matching element of each selector is used."),
+      detect_missing_glyphs: z.boolean().optional().describe("Optional. Check each element's text for characters that render as the missing-glyph box. Defaults to false."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window to inspect. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Rendered Font Information",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ selectors, detect_missing_glyphs, window_label, timeout_ms }) => {
+      try {
+        const params = { selectors, detect_missing_glyphs, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_font_info", params);
+
+        const result = await socketClient.sendCommand("get_font_info", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Font info error:", error);
+        return createErrorResponse(`Failed to get font info: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,51 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+// Define gestures and their options
+const GESTURES = ["tap", "swipe", "pinch", "rotate"] as const;
+const DIRECTIONS = ["up", "down", "left", "right"] as const;
+const POINTER_TYPES = ["touch", "pen"] as const;
+
+export function registerSimulateGestureTool(server: McpServer) {
+  server.tool(
+    "simulate_gesture",
+    "Synthesizes touch gestures in the webview: 'tap', 'swipe', and the two-finger 'pinch' and 'rotate'. Each finger is a separate pointer id moving through pointerdown/pointermove/pointerup, with matching touchstart/touchmove/touchend events where supported. Targets the center of a selector or given viewport coordinates. Reports the pointer paths and which events a handler cancelled.",
+    {
+      gesture: z.enum(GESTURES).describe("The gesture to perform."),
+      selector: z.string().min(1).optional().describe("CSS selector of the element whose center is the gesture origin. Either selector or x/y is required."),
+      x: z.number().optional().describe("Origin x in CSS pixels relative to the viewport, when no selector is given."),
+      y: z.number().optional().describe("Origin y in CSS pixels relative to the viewport, when no selector is given."),
+      direction: z.enum(DIRECTIONS).optional().describe("Swipe direction. Required for 'swipe'."),
+      distance: z.number().min(1).optional().describe("Optional. Swipe length, or the starting distance between fingers for pinch/rotate, in CSS pixels. Defaults to 200 for swipe and 100 otherwise."),
+      scale: z.number().min(0.05).max(20).optional().describe("Pinch scale factor: above 1 zooms in, below 1 zooms out. Required for 'pinch'."),
+      angle: z.number().min(-720).max(720).optional().describe("Rotation in degrees, clockwise. Required for 'rotate'."),
+      duration_ms: z.number().int().min(0).max(10000).optional().describe("Optional. Total gesture duration in milliseconds. Defaults to 50 for tap and 300 otherwise."),
+      steps: z.number().int().min(1).max(200).optional().describe("Optional. Number of pointermove steps. Defaults to 10."),
+      pointer_type: z.enum(POINTER_TYPES).optional().describe("Optional. Pointer type to report. Defaults to 'touch'."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result, in addition to duration_ms. Defaults to 5000ms."),
+    },
+    {
+      title: "Simulate Touch Gesture",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ gesture, selector, x, y, direction, distance, scale, angle, duration_ms, steps, pointer_type, window_label, timeout_ms }) => {
+      try {
+        const params = { gesture, selector, x, y, direction, distance, scale, angle, duration_ms, steps, pointer_type, window_label: window_label || "main", timeout_ms };
+        logCommandParams("simulate_gesture", params);
+
+        const result = await socketClient.sendCommand("simulate_gesture", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Gesture simulation error:", error);
+        return createErrorResponse(`Failed to simulate gesture: ${(error as Error).message}`);
+      }
+    }
+  );
+}
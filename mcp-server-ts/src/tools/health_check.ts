@@ -61,8 +61,18 @@ export function registerHealthCheckTool(server: McpServer) {
             webviewAvailable: boolean;
             mainWindowAvailable: boolean;
           };
+          initTiming?: {
+            readyAfterMs: number | null;
+            phaseCount: number;
+            slowestPhase: string | null;
+            slowestPhaseMs: number | null;
+            failedPhases: string[];
+          } | null;
         };
 
+        const initTiming = healthInfo.initTiming;
+        const formatMs = (ms: number | null | undefined) => (ms === null || ms === undefined ? "n/a" : `${ms.toFixed(1)}ms`);
+
         // Build a detailed text report
         const report = [
           `Plugin Status: ${healthInfo.status}`,
@@ -87,6 +97,15 @@ export function registerHealthCheckTool(server: McpServer) {
           `  - Webview Available: ${healthInfo.webviewStatus.webviewAvailable ? "Yes" : "No"}`,
           `  - Main Window Available: ${healthInfo.webviewStatus.mainWindowAvailable ? "Yes" : "No"}`,
           ``,
+          ...(initTiming
+            ? [
+                `Startup Timing (see get_init_timing for every phase):`,
+                `  - Ready After: ${formatMs(initTiming.readyAfterMs)}`,
+                `  - Slowest Phase: ${initTiming.slowestPhase ?? "n/a"} (${formatMs(initTiming.slowestPhaseMs)})`,
+                ...(initTiming.failedPhases.length > 0 ? [`  - Failed Phases: ${initTiming.failedPhases.join(", ")}`] : []),
+                ``,
+              ]
+            : []),
           `Available Capabilities (${healthInfo.capabilities.length} tools):`,
           ...healthInfo.capabilities.map((cap) => `  - ${cap}`),
         ].join("\n");
@@ -0,0 +1,70 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerHoverTools(server: McpServer) {
+  server.tool(
+    "simulate_hover",
+    "Hovers an element by dispatching the pointerover/mouseenter/mousemove sequence a real pointer produces, then reports which of its styles changed and, if watch_selector is given, whether that element (e.g. a tooltip or hover menu) became visible. Synthetic events trigger JS hover handlers but not CSS :hover rules; use simulate_mouse_movement for those.",
+    {
+      selector: z.string().describe("CSS selector of the element to hover."),
+      watch_selector: z.string().optional().describe("Optional. CSS selector of an element expected to appear on hover, such as a tooltip."),
+      wait_ms: z.number().int().min(0).max(10000).optional().describe("Optional. Time in milliseconds to let hover-triggered UI react before reading back. Defaults to 300."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result, in addition to wait_ms. Defaults to 5000ms."),
+    },
+    {
+      title: "Simulate Hover",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ selector, watch_selector, wait_ms, window_label, timeout_ms }) => {
+      try {
+        const params = { selector, watch_selector, wait_ms, window_label: window_label || "main", timeout_ms };
+        logCommandParams("simulate_hover", params);
+
+        const result = await socketClient.sendCommand("simulate_hover", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Hover simulation error:", error);
+        return createErrorResponse(`Failed to simulate hover: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "unhover",
+    "Ends a simulated hover by dispatching pointerout/mouseleave to the element, then reports style changes and whether the watched element (e.g. a tooltip) disappeared. Defaults to the element hovered last with simulate_hover.",
+    {
+      selector: z.string().optional().describe("Optional. CSS selector of the element to leave. Defaults to the element hovered last."),
+      watch_selector: z.string().optional().describe("Optional. CSS selector of an element expected to disappear, such as a tooltip."),
+      wait_ms: z.number().int().min(0).max(10000).optional().describe("Optional. Time in milliseconds to let the UI react before reading back. Defaults to 300."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result, in addition to wait_ms. Defaults to 5000ms."),
+    },
+    {
+      title: "End Simulated Hover",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ selector, watch_selector, wait_ms, window_label, timeout_ms }) => {
+      try {
+        const params = { selector, watch_selector, wait_ms, window_label: window_label || "main", timeout_ms };
+        logCommandParams("unhover", params);
+
+        const result = await socketClient.sendCommand("unhover", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Unhover error:", error);
+        return createErrorResponse(`Failed to end hover: ${(error as Error).message}`);
+      }
+    }
+  );
+}
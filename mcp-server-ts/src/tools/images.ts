@@ -0,0 +1,40 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerImagesTool(server: McpServer) {
+  server.tool(
+    "get_images",
+    "Audits every <img> and CSS background image. For each: URL, natural and displayed size, load state (complete/loaded/failed), alt text and visibility. Flags issues: failed_to_load, missing_src, missing_alt, loading or not_loaded_lazy, distorted (displayed aspect ratio far from natural, without object-fit), and upscaled (rendered well beyond natural resolution). Background images are test-loaded to find broken URLs.",
+    {
+      root_selector: z.string().min(1).optional().describe("Optional. Only report images inside this element. Defaults to the whole document."),
+      include_background: z.boolean().optional().describe("Optional. Include CSS background images. Defaults to true."),
+      issues_only: z.boolean().optional().describe("Optional. Return only images with at least one issue. Defaults to false."),
+      aspect_tolerance: z.number().min(0).max(10).optional().describe("Optional. Relative aspect-ratio difference that counts as distorted. Defaults to 0.1 (10%)."),
+      limit: z.number().int().min(1).max(5000).optional().describe("Optional. Maximum number of images to return. Defaults to 200."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Images",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ root_selector, include_background, issues_only, aspect_tolerance, limit, window_label, timeout_ms }) => {
+      try {
+        const params = { root_selector, include_background, issues_only, aspect_tolerance, limit, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_images", params);
+
+        const result = await socketClient.sendCommand("get_images", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Image audit error:", error);
+        return createErrorResponse(`Failed to get images: ${(error as Error).message}`);
+      }
+    }
+  );
+}
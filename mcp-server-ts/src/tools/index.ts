@@ -1,21 +1,81 @@
 import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
 import { registerTakeScreenshotTool } from "./take_screenshot.js";
-import { registerExecuteJsTool } from "./execute_js.js";
+import { registerExecuteJsTool, registerExecuteJsFileTool } from "./execute_js.js";
 import { registerGetDomTool } from "./get_dom.js";
 import { registerManageWindowTool } from "./manage_window.js";
-import { registerManageLocalStorageTool } from "./manage_local_storage.js";
+import { registerManageLocalStorageTool, registerLocalStorageMapTools } from "./manage_local_storage.js";
 import { registerTextInputTool } from "./text_input.js";
 import { registerMouseMovementTool } from "./mouse_movement.js";
 import { registerGetElementPositionTool } from "./get_element_position.js";
 import { registerSendTextToElementTool } from "./send_text_to_element.js";
 import { registerConsoleLogsTool, registerInjectConsoleCaptureTool } from "./console_logs.js";
 import { registerNetworkInspectorTool, registerInjectNetworkCaptureTool } from "./network_inspector.js";
-import { registerStateDumpTool } from "./state_dump.js";
+import { registerDiffStateTool, registerStateDumpTool } from "./state_dump.js";
 import { registerDevToolsBridgeTool } from "./devtools_bridge.js";
-import { registerGetExceptionsTool, registerInjectErrorTrackerTool, registerClearExceptionsTool } from "./error_tracker.js";
-import { registerPerformanceMetricsTool } from "./performance.js";
-import { registerStorageInspectorTool } from "./storage_inspector.js";
+import { registerGetExceptionsTool, registerInjectErrorTrackerTool, registerClearExceptionsTool, registerExceptionStreamTools } from "./error_tracker.js";
+import { registerPerformanceMetricsTool, registerLoadedScriptsTool, registerClearPerformanceEntriesTool, registerScriptTimingTool } from "./performance.js";
+import { registerStorageInspectorTool, registerGetCookiesTool } from "./storage_inspector.js";
 import { registerHealthCheckTool } from "./health_check.js";
+import { registerBoxModelTool, registerElementRelationsTool, registerLayoutShiftsTool, registerPositionedElementsTool, registerElementFromPointTool, registerStackingContextsTool, registerHitTestTool, registerInteractionBlockersTool, registerCompositingLayersTool } from "./layout.js";
+import { registerZoomTools } from "./zoom.js";
+import { registerFontInfoTool } from "./fonts.js";
+import { registerTrayTool } from "./tray.js";
+import { registerMonitorTools } from "./monitors.js";
+import { registerNextDialogTool } from "./dialogs.js";
+import { registerServiceWorkerTools } from "./service_workers.js";
+import { registerCaptureCanvasTool } from "./canvas.js";
+import { registerGetCspTool } from "./csp.js";
+import { registerSemanticOutlineTool } from "./outline.js";
+import { registerHoverTools } from "./hover.js";
+import { registerWebviewInfoTool } from "./webview_info.js";
+import { registerNavigateHistoryTool, registerScrollRestorationTools } from "./navigation.js";
+import { registerGetTextTool } from "./element_text.js";
+import { registerMeasureReflowsTool } from "./reflows.js";
+import { registerDocumentTools } from "./document.js";
+import { registerShortcutTools } from "./shortcuts.js";
+import { registerDiffComputedStyleTool } from "./computed_style.js";
+import { registerSetAnimationsPausedTool } from "./animations.js";
+import { registerContentEditableTools } from "./contenteditable.js";
+import { registerDeviceTools } from "./devices.js";
+import { registerSimulateGestureTool } from "./gestures.js";
+import { registerFindDetachedNodesTool } from "./detached_nodes.js";
+import { registerCheckContrastTool } from "./contrast.js";
+import { registerSelectionTools } from "./selection.js";
+import { registerMutationJournalTool } from "./journal.js";
+import { registerInputLatencyTool, registerActionFpsTool } from "./latency.js";
+import { registerImagesTool } from "./images.js";
+import { registerForceRerenderTool } from "./rerender.js";
+import { registerWindowLayoutTools } from "./window_layout.js";
+import { registerPostMessageTools } from "./postmessage.js";
+import { registerBaselineTools } from "./baselines.js";
+import { registerWorkersTool } from "./workers.js";
+import { registerCssCoverageTool } from "./css_coverage.js";
+import { registerLazyLoadTool } from "./lazy_load.js";
+import { registerMenuTools } from "./menu.js";
+import { registerBatteryConnectionTools } from "./battery_connection.js";
+import { registerDomStatsTool } from "./dom_stats.js";
+import { registerRouteTool } from "./route.js";
+import { registerUserScriptTools } from "./user_scripts.js";
+import { registerPixelColorTool } from "./pixel_color.js";
+import { registerCustomElementsTool } from "./custom_elements.js";
+import { registerScrollSnapTool } from "./scroll_snap.js";
+import { registerTabOrderTool } from "./tab_order.js";
+import { registerObserversTool } from "./observers.js";
+import { registerCloseRequestTool } from "./close_request.js";
+import { registerLiveRegionTools } from "./live_regions.js";
+import { registerShadowDomTool } from "./shadow_dom.js";
+import { registerInteractionTraceTool } from "./interaction_trace.js";
+import { registerStylesheetTool } from "./stylesheets.js";
+import { registerLayoutOverlayTool } from "./layout_overlay.js";
+import { registerPurgeAllStorageTool } from "./storage_purge.js";
+import { registerCaptureOverheadTool } from "./capture_overhead.js";
+import { registerScrollBehaviorTools } from "./scroll_behavior.js";
+import { registerInitTimingTool } from "./init_timing.js";
+import { registerAnalyticsTools } from "./analytics.js";
+import { registerIntlLocaleTools } from "./intl_locale.js";
+import { registerReorderItemTool } from "./drag_reorder.js";
+import { registerSelectStateTools } from "./select_state.js";
+import { registerFixtureTools } from "./fixture.js";
 import { socketClient } from "./client.js";
 
 // Re-export the socket client for direct use
@@ -38,12 +98,90 @@ export function registerAllTools(server: McpServer) {
   registerNetworkInspectorTool(server);
   registerInjectNetworkCaptureTool(server);
   registerStateDumpTool(server);
+  registerDiffStateTool(server);
   registerDevToolsBridgeTool(server);
   registerGetExceptionsTool(server);
   registerInjectErrorTrackerTool(server);
   registerClearExceptionsTool(server);
+  registerExceptionStreamTools(server);
   registerPerformanceMetricsTool(server);
   registerStorageInspectorTool(server);
+  registerBoxModelTool(server);
+  registerZoomTools(server);
+  registerFontInfoTool(server);
+  registerTrayTool(server);
+  registerMonitorTools(server);
+  registerNextDialogTool(server);
+  registerServiceWorkerTools(server);
+  registerElementRelationsTool(server);
+  registerCaptureCanvasTool(server);
+  registerGetCspTool(server);
+  registerSemanticOutlineTool(server);
+  registerHoverTools(server);
+  registerLayoutShiftsTool(server);
+  registerLoadedScriptsTool(server);
+  registerLocalStorageMapTools(server);
+  registerWebviewInfoTool(server);
+  registerNavigateHistoryTool(server);
+  registerGetTextTool(server);
+  registerExecuteJsFileTool(server);
+  registerMeasureReflowsTool(server);
+  registerDocumentTools(server);
+  registerShortcutTools(server);
+  registerDiffComputedStyleTool(server);
+  registerSetAnimationsPausedTool(server);
+  registerContentEditableTools(server);
+  registerDeviceTools(server);
+  registerSimulateGestureTool(server);
+  registerClearPerformanceEntriesTool(server);
+  registerFindDetachedNodesTool(server);
+  registerPositionedElementsTool(server);
+  registerCheckContrastTool(server);
+  registerSelectionTools(server);
+  registerMutationJournalTool(server);
+  registerElementFromPointTool(server);
+  registerInputLatencyTool(server);
+  registerImagesTool(server);
+  registerForceRerenderTool(server);
+  registerWindowLayoutTools(server);
+  registerPostMessageTools(server);
+  registerBaselineTools(server);
+  registerWorkersTool(server);
+  registerCssCoverageTool(server);
+  registerLazyLoadTool(server);
+  registerMenuTools(server);
+  registerStackingContextsTool(server);
+  registerBatteryConnectionTools(server);
+  registerDomStatsTool(server);
+  registerRouteTool(server);
+  registerUserScriptTools(server);
+  registerPixelColorTool(server);
+  registerActionFpsTool(server);
+  registerCustomElementsTool(server);
+  registerScrollSnapTool(server);
+  registerTabOrderTool(server);
+  registerObserversTool(server);
+  registerCloseRequestTool(server);
+  registerLiveRegionTools(server);
+  registerShadowDomTool(server);
+  registerInteractionTraceTool(server);
+  registerHitTestTool(server);
+  registerInteractionBlockersTool(server);
+  registerStylesheetTool(server);
+  registerScriptTimingTool(server);
+  registerLayoutOverlayTool(server);
+  registerScrollRestorationTools(server);
+  registerPurgeAllStorageTool(server);
+  registerCaptureOverheadTool(server);
+  registerScrollBehaviorTools(server);
+  registerInitTimingTool(server);
+  registerAnalyticsTools(server);
+  registerIntlLocaleTools(server);
+  registerCompositingLayersTool(server);
+  registerReorderItemTool(server);
+  registerSelectStateTools(server);
+  registerFixtureTools(server);
+  registerGetCookiesTool(server);
 }
 
 // Function to initialize socket connection (can be awaited before registering tools)
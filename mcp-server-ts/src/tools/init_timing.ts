@@ -0,0 +1,31 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerInitTimingTool(server: McpServer) {
+  server.tool(
+    "get_init_timing",
+    "Returns how long each phase of the plugin's own startup took: registering init scripts, binding the socket server, setting up plugin state, and injecting auto-inject captures and user scripts on each window's first page load. Each phase has its offset from plugin initialization, its duration and any error, plus the time until the plugin was ready and the slowest phase. Use it to pinpoint why the plugin is slow to become ready; health_check includes a short summary.",
+    {},
+    {
+      title: "Get Plugin Init Timing",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async () => {
+      try {
+        const params = {};
+        logCommandParams("get_init_timing", params);
+
+        const result = await socketClient.sendCommand("get_init_timing", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Init timing error:", error);
+        return createErrorResponse(`Failed to get init timing: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,43 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+// Define the trace output formats
+const TRACE_FORMATS = ["entries", "replay"] as const;
+
+export function registerInteractionTraceTool(server: McpServer) {
+  server.tool(
+    "get_interaction_trace",
+    "Shows what the user just did in a window: the most recent clicks, text edits, shortcut-style key presses (Enter, Escape, arrows, modifier chords), form submits and navigations, oldest first, each with a CSS selector, a short label, the page URL and a timestamp. Plain typing is collapsed into one entry per field holding the final value; password values are never recorded. The recorder runs from page start when the app enables PluginConfig::record_interactions and survives reloads within the session; otherwise this call starts it and only later interactions are seen. With format: 'replay' the trace is returned as a list of { command, payload } steps (get_element_position clicks, send_text_to_element, trigger_shortcut, execute_js) that reproduce it when sent in order, plus the events that could not be converted.",
+    {
+      limit: z.number().int().min(1).max(10000).optional().describe("Optional. Only return the most recent N events. Defaults to everything buffered."),
+      since_id: z.number().int().min(0).optional().describe("Optional. Only return events with a greater id, e.g. the last_id of a previous call."),
+      clear: z.boolean().optional().describe("Optional. Empty the buffer after reading it. Defaults to false."),
+      format: z.enum(TRACE_FORMATS).optional().describe("Optional. 'entries' (default) for the recorded events, or 'replay' for socket commands that reproduce them."),
+      capacity: z.number().int().min(1).max(10000).optional().describe("Optional. Resize the ring buffer. Defaults to the configured size, or 200 when the recorder is started by this call."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the trace. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Interaction Trace",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ limit, since_id, clear, format, capacity, window_label, timeout_ms }) => {
+      try {
+        const params = { limit, since_id, clear, format, capacity, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_interaction_trace", params);
+
+        const result = await socketClient.sendCommand("get_interaction_trace", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Interaction trace error:", error);
+        return createErrorResponse(`Failed to get interaction trace: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,68 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerIntlLocaleTools(server: McpServer) {
+  server.tool(
+    "get_intl_locale",
+    "Reports the locale, time zone, calendar, numbering system and hour cycle that Intl constructors resolve to when the app passes no locale, with sample number, currency, percent, date, relative-time and list formatting. While set_intl_locale is active the untouched browser defaults are reported alongside.",
+    {
+      currency: z.string().min(1).optional().describe("Optional. ISO 4217 currency code for the sample currency formatting. Defaults to 'USD'."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Intl Locale",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ currency, window_label, timeout_ms }) => {
+      try {
+        const params = { currency, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_intl_locale", params);
+
+        const result = await socketClient.sendCommand("get_intl_locale", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Intl locale error:", error);
+        return createErrorResponse(`Failed to get Intl locale: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "set_intl_locale",
+    "Fixes the locale and/or time zone used by Intl.NumberFormat, Intl.DateTimeFormat and the other Intl constructors, and by toLocaleString/localeCompare, whenever the app does not pass one explicitly. Use it to screenshot number, date and currency formatting deterministically. Formatters created before the call keep their old settings, so re-render afterwards. Date getters keep the system time zone. Lasts until the page reloads; reset restores the originals.",
+    {
+      locale: z.string().min(1).optional().describe("Optional. BCP 47 locale tag, e.g. 'de-DE' or 'ja-JP-u-ca-japanese'."),
+      time_zone: z.string().min(1).optional().describe("Optional. IANA time zone for date formatting, e.g. 'America/New_York' or 'UTC'."),
+      reset: z.boolean().optional().describe("Optional. Remove the override and restore the original constructors."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Set Intl Locale",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ locale, time_zone, reset, window_label, timeout_ms }) => {
+      try {
+        const params = { locale, time_zone, reset, window_label: window_label || "main", timeout_ms };
+        logCommandParams("set_intl_locale", params);
+
+        const result = await socketClient.sendCommand("set_intl_locale", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Intl locale override error:", error);
+        return createErrorResponse(`Failed to set Intl locale: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,41 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+// Define journal actions and output formats
+const ACTIONS = ["get", "start", "stop", "clear"] as const;
+const FORMATS = ["entries", "replay"] as const;
+
+export function registerMutationJournalTool(server: McpServer) {
+  server.tool(
+    "get_mutation_journal",
+    "Records and returns an ordered journal of the mutating commands the plugin executes: script execution, text and mouse input, window operations, storage writes, navigation and the like. Each entry keeps its sequence number, time, command, full payload, outcome and duration. Call with action 'start' before the session, then 'get' or 'stop'. The 'replay' format returns a bare list of {command, payload} steps; resend them in order to reproduce the session.",
+    {
+      action: z.enum(ACTIONS).optional().describe("Optional. 'start' begins recording, 'stop' ends it and returns the journal, 'clear' empties it, 'get' (default) returns it."),
+      since_seq: z.number().int().min(0).optional().describe("Optional. Only return entries with a greater sequence number."),
+      successful_only: z.boolean().optional().describe("Optional. Leave out commands that failed. Defaults to false."),
+      format: z.enum(FORMATS).optional().describe("Optional. 'entries' (default) returns full entries; 'replay' returns only {command, payload} steps."),
+    },
+    {
+      title: "Get Mutation Journal",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ action, since_seq, successful_only, format }) => {
+      try {
+        const params = { action, since_seq, successful_only, format };
+        logCommandParams("get_mutation_journal", params);
+
+        const result = await socketClient.sendCommand("get_mutation_journal", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Mutation journal error:", error);
+        return createErrorResponse(`Failed to access mutation journal: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,86 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+// Define supported inputs
+const INPUTS = ["click", "keypress"] as const;
+
+export function registerInputLatencyTool(server: McpServer) {
+  server.tool(
+    "measure_input_latency",
+    "Measures how long the app takes to respond visibly to an input. Dispatches a click or keypress on an element and timestamps three points: the end of the synchronous handlers, the first DOM mutation, and the next paint after it. Repeat to get a distribution; returns min/median/p95/max/mean plus every run. Inputs that cause no DOM change within max_wait_ms are reported as not responding.",
+    {
+      selector: z.string().min(1).describe("CSS selector of the element that receives the input."),
+      input: z.enum(INPUTS).optional().describe("Optional. Input to dispatch. Defaults to 'click'."),
+      key: z.string().min(1).optional().describe("Optional. Key for 'keypress', e.g. 'Enter' or 'a'. Defaults to 'Enter'."),
+      repeat: z.number().int().min(1).max(100).optional().describe("Optional. Number of samples to take. Defaults to 1."),
+      interval_ms: z.number().int().min(0).max(10000).optional().describe("Optional. Pause between samples in milliseconds. Defaults to 100."),
+      max_wait_ms: z.number().int().min(16).max(30000).optional().describe("Optional. How long to wait for a DOM change after each input. Defaults to 1000."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Extra time in milliseconds to wait for the result beyond the sampling time. Defaults to 5000ms."),
+    },
+    {
+      title: "Measure Input Latency",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ selector, input, key, repeat, interval_ms, max_wait_ms, window_label, timeout_ms }) => {
+      try {
+        const params = { selector, input, key, repeat, interval_ms, max_wait_ms, window_label: window_label || "main", timeout_ms };
+        logCommandParams("measure_input_latency", params);
+
+        const result = await socketClient.sendCommand("measure_input_latency", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Input latency error:", error);
+        return createErrorResponse(`Failed to measure input latency: ${(error as Error).message}`);
+      }
+    }
+  );
+}
+
+// Define supported actions
+const ACTIONS = ["click", "keypress", "scroll"] as const;
+
+export function registerActionFpsTool(server: McpServer) {
+  server.tool(
+    "measure_action_fps",
+    "Measures the frame rate while a specific interaction plays out, so jank can be attributed to that action. Samples animation frames through a short idle baseline, then clicks, presses a key on, or smoothly scrolls the element (its nearest scrollable ancestor), and keeps sampling for duration_ms. Returns FPS, dropped and janky frames, frame-time percentiles for the action window next to the idle baseline, the worst frames with their offset from the action, and long tasks where supported. The frame budget is estimated from the baseline unless frame_budget_ms is given.",
+    {
+      selector: z.string().min(1).describe("CSS selector of the element that receives the action."),
+      action: z.enum(ACTIONS).optional().describe("Optional. 'click' (default), 'keypress' or 'scroll'."),
+      key: z.string().min(1).optional().describe("Optional. Key to press for 'keypress'. Defaults to 'Enter'."),
+      scroll_x: z.number().optional().describe("Optional. Horizontal scroll distance in CSS pixels for 'scroll'. Defaults to 0."),
+      scroll_y: z.number().optional().describe("Optional. Vertical scroll distance in CSS pixels for 'scroll'. Defaults to 1000."),
+      duration_ms: z.number().int().min(100).max(20000).optional().describe("Optional. How long to sample frames after the action starts. Defaults to 1000ms."),
+      baseline_ms: z.number().int().min(0).max(2000).optional().describe("Optional. Idle sampling before the action, used for comparison and to estimate the refresh rate. Defaults to 300ms."),
+      frame_budget_ms: z.number().min(1).max(1000).optional().describe("Optional. Frame budget in milliseconds, e.g. 16.67 for 60Hz. Estimated from the baseline when omitted."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Extra time in milliseconds to wait for the result beyond the sampling time. Defaults to 5000ms."),
+    },
+    {
+      title: "Measure Action Frame Rate",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ selector, action, key, scroll_x, scroll_y, duration_ms, baseline_ms, frame_budget_ms, window_label, timeout_ms }) => {
+      try {
+        const params = { selector, action, key, scroll_x, scroll_y, duration_ms, baseline_ms, frame_budget_ms, window_label: window_label || "main", timeout_ms };
+        logCommandParams("measure_action_fps", params);
+
+        const result = await socketClient.sendCommand("measure_action_fps", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Action FPS error:", error);
+        return createErrorResponse(`Failed to measure action frame rate: ${(error as Error).message}`);
+      }
+    }
+  );
+}
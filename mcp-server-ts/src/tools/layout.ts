@@ -0,0 +1,307 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerBoxModelTool(server: McpServer) {
+  server.tool(
+    "get_box_model",
+    "Reads the rendered box model of an element, mirroring the DevTools layout panel. Returns content, padding, border, and margin rects as eight-point quads (viewport coordinates), the individual edge sizes, and the element's position, display, z-index, and overflow styles. Useful for asserting precise spacing and catching overlap or overflow bugs.",
+    {
+      selector: z.string().describe("CSS selector of the element to inspect. The first matching element is used."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window to inspect. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Element Box Model",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ selector, window_label, timeout_ms }) => {
+      try {
+        const params = { selector, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_box_model", params);
+
+        const result = await socketClient.sendCommand("get_box_model", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Box model error:", error);
+        return createErrorResponse(`Failed to get box model: ${(error as Error).message}`);
+      }
+    }
+  );
+}
+
+export function registerElementRelationsTool(server: McpServer) {
+  server.tool(
+    "element_relations",
+    "Compares the positions of two elements (B relative to A): whether one contains the other in the DOM and geometrically, whether their rects overlap (with area and intersection rect), the distance between their centers, the edge-to-edge gap, and B's direction relative to A (e.g. 'above', 'below-right', 'overlapping'). Useful for assertions like 'the tooltip is above the button' or 'the badge is inside the avatar'.",
+    {
+      selector_a: z.string().describe("CSS selector of the reference element (A)."),
+      selector_b: z.string().describe("CSS selector of the element to relate to A (B)."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window to inspect. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Element-to-Element Relations",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ selector_a, selector_b, window_label, timeout_ms }) => {
+      try {
+        const params = { selector_a, selector_b, window_label: window_label || "main", timeout_ms };
+        logCommandParams("element_relations", params);
+
+        const result = await socketClient.sendCommand("element_relations", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Element relations error:", error);
+        return createErrorResponse(`Failed to compute element relations: ${(error as Error).message}`);
+      }
+    }
+  );
+}
+
+export function registerLayoutShiftsTool(server: McpServer) {
+  server.tool(
+    "get_layout_shifts",
+    "Reports layout shifts with attribution: each shift's score, timestamp, and the nodes that moved (selector, description, and previous/current rects), plus the cumulative score and the Core Web Vitals CLS (worst session window). Shifts right after user input are excluded from the scores. Requires an engine that reports layout-shift entries (Chromium-based WebView2); other engines report supported: false.",
+    {
+      observe_ms: z.number().int().min(0).max(60000).optional().describe("Optional. Keep observing for new shifts this many milliseconds before reporting. Defaults to 0 (shifts recorded so far only)."),
+      include_input_shifts: z.boolean().optional().describe("Optional. Also list shifts that happened right after user input. Defaults to false."),
+      limit: z.number().int().positive().optional().describe("Optional. Maximum number of shifts to return, largest first. Defaults to 50."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window to inspect. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result, in addition to observe_ms. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Layout Shift Attribution",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ observe_ms, include_input_shifts, limit, window_label, timeout_ms }) => {
+      try {
+        const params = { observe_ms, include_input_shifts, limit, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_layout_shifts", params);
+
+        const result = await socketClient.sendCommand("get_layout_shifts", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Layout shift error:", error);
+        return createErrorResponse(`Failed to get layout shifts: ${(error as Error).message}`);
+      }
+    }
+  );
+}
+
+export function registerPositionedElementsTool(server: McpServer) {
+  server.tool(
+    "get_positioned_elements",
+    "Lists every element with position: sticky or fixed, with its rect, insets and z-index. Sticky elements report whether they are currently stuck (pinned at their inset inside a scrolled container) and on which edges, plus a warning when no inset is set. Fixed elements report how many visible interactive elements they cover. Use it to verify a sticky header pins correctly on scroll and that fixed overlays do not hide content.",
+    {
+      root_selector: z.string().min(1).optional().describe("Optional. Only report elements inside this element. Defaults to the whole body."),
+      include_hidden: z.boolean().optional().describe("Optional. Include sticky/fixed elements that are not rendered. Defaults to false."),
+      limit: z.number().int().positive().optional().describe("Optional. Maximum number of elements to return. Defaults to 100."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Sticky and Fixed Elements",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ root_selector, include_hidden, limit, window_label, timeout_ms }) => {
+      try {
+        const params = { root_selector, include_hidden, limit, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_positioned_elements", params);
+
+        const result = await socketClient.sendCommand("get_positioned_elements", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Positioned elements error:", error);
+        return createErrorResponse(`Failed to get positioned elements: ${(error as Error).message}`);
+      }
+    }
+  );
+}
+
+export function registerElementFromPointTool(server: McpServer) {
+  server.tool(
+    "element_from_point",
+    "The inverse of get_element_position. Returns the topmost element at a viewport point (descending into open shadow roots), with its selector path, rect, position and z-index. Also returns the interactive element a click there would activate, and the full stack of elements under the point from elementsFromPoint, topmost first. Use this to find out what a click actually hit.",
+    {
+      x: z.number().describe("X coordinate in CSS pixels relative to the viewport, as returned by get_element_position."),
+      y: z.number().describe("Y coordinate in CSS pixels relative to the viewport, as returned by get_element_position."),
+      pierce_shadow: z.boolean().optional().describe("Optional. Descend into open shadow roots for the topmost element. Defaults to true."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Element At Point",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ x, y, pierce_shadow, window_label, timeout_ms }) => {
+      try {
+        const params = { x, y, pierce_shadow, window_label: window_label || "main", timeout_ms };
+        logCommandParams("element_from_point", params);
+
+        const result = await socketClient.sendCommand("element_from_point", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Element from point error:", error);
+        return createErrorResponse(`Failed to get element at point: ${(error as Error).message}`);
+      }
+    }
+  );
+}
+
+export function registerHitTestTool(server: McpServer) {
+  server.tool(
+    "hit_test",
+    "Answers 'will a click at the center of this element hit it?'. Hit tests the element's center with elementFromPoint (descending into open shadow roots) and reports clickable: true when the hit is the element, something inside it or a label for it. Otherwise the reason says why: obscured (with the covering element's selector and the overlay it belongs to), hit_ancestor (the element ignores pointer events), outside_viewport, zero_size, hidden or not_rendered. Also reports whether the element is disabled. Use it to explain 'element not interactable' failures before clicking.",
+    {
+      selector: z.string().min(1).describe("CSS selector of the element to test."),
+      scroll_into_view: z.boolean().optional().describe("Optional. Scroll the element to the center of the viewport before testing. Defaults to false, which reports outside_viewport for off-screen elements."),
+      pierce_shadow: z.boolean().optional().describe("Optional. Descend into open shadow roots when hit testing. Defaults to true."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Hit Test Element Center",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ selector, scroll_into_view, pierce_shadow, window_label, timeout_ms }) => {
+      try {
+        const params = { selector, scroll_into_view, pierce_shadow, window_label: window_label || "main", timeout_ms };
+        logCommandParams("hit_test", params);
+
+        const result = await socketClient.sendCommand("hit_test", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Hit test error:", error);
+        return createErrorResponse(`Failed to hit test element: ${(error as Error).message}`);
+      }
+    }
+  );
+}
+
+export function registerInteractionBlockersTool(server: McpServer) {
+  server.tool(
+    "get_interaction_blockers",
+    "Answers 'why can't I click or type into this element?' by walking its ancestors (across shadow roots) for anything that blocks input. It reports pointer-events: none (naming the ancestor it is inherited from), visibility hidden, display: none, the inert attribute, an open modal dialog the element is outside of, and the disabled attribute, including a disabled fieldset or optgroup. Returns interactive: false and the first blocking element when something blocks. aria-disabled and cursor: not-allowed are listed as advisories because they do not stop events. Pair it with hit_test, which finds overlays covering the element.",
+    {
+      selector: z.string().min(1).describe("CSS selector of the element to check."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Interaction Blockers",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ selector, window_label, timeout_ms }) => {
+      try {
+        const params = { selector, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_interaction_blockers", params);
+
+        const result = await socketClient.sendCommand("get_interaction_blockers", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Interaction blockers error:", error);
+        return createErrorResponse(`Failed to get interaction blockers: ${(error as Error).message}`);
+      }
+    }
+  );
+}
+
+export function registerStackingContextsTool(server: McpServer) {
+  server.tool(
+    "get_stacking_contexts",
+    "Builds the tree of stacking contexts. For each context it returns the establishing element, its z-index and why it creates a context (positioned with z-index, opacity, transform, isolation, and so on). It also lists the positioned elements and child contexts painted within it in paint order, with counts of non-positioned content. Flags z-index values that have no effect. Pass two selectors to learn which one paints on top and why, through their closest common stacking context. Use it when a modal renders behind an overlay and the raw z-index values do not explain it.",
+    {
+      root_selector: z.string().min(1).optional().describe("Optional. Start the tree at the stacking context containing this element. Defaults to the document root."),
+      selectors: z.array(z.string().min(1)).optional().describe("Optional. Elements to explain with their chain of stacking contexts. With exactly two, also reports which paints on top and why."),
+      max_depth: z.number().int().min(1).max(100).optional().describe("Optional. Maximum nesting depth of the returned tree. Defaults to 20."),
+      max_contexts: z.number().int().min(1).max(5000).optional().describe("Optional. Maximum number of contexts in the returned tree. Defaults to 200."),
+      max_items_per_context: z.number().int().min(0).max(1000).optional().describe("Optional. Maximum paint-order entries listed per context. Defaults to 50."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Stacking Contexts",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ root_selector, selectors, max_depth, max_contexts, max_items_per_context, window_label, timeout_ms }) => {
+      try {
+        const params = { root_selector, selectors, max_depth, max_contexts, max_items_per_context, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_stacking_contexts", params);
+
+        const result = await socketClient.sendCommand("get_stacking_contexts", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Stacking context error:", error);
+        return createErrorResponse(`Failed to get stacking contexts: ${(error as Error).message}`);
+      }
+    }
+  );
+}
+
+export function registerCompositingLayersTool(server: McpServer) {
+  server.tool(
+    "get_compositing_layers",
+    "Lists elements that are likely promoted to their own GPU compositing layer, with the reason (3D transform, will-change, running transform/opacity/filter animation, fixed or sticky position, backdrop-filter, scroller, video, canvas, iframe) and an estimated memory cost from the layer size and device pixel ratio. Warns about many layers, layer memory far above the viewport, will-change left on idle elements and positioned elements that may be promoted for overlap. The result is a heuristic from computed styles, as the engine does not expose its layer tree to script.",
+    {
+      root_selector: z.string().min(1).optional().describe("Optional. Only consider elements inside this one. Defaults to the whole document."),
+      include_offscreen: z.boolean().optional().describe("Optional. Include layers outside the viewport. Defaults to true."),
+      limit: z.number().int().min(1).max(5000).optional().describe("Optional. Maximum number of layers returned, largest estimated memory first. Defaults to 100."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Compositing Layers",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ root_selector, include_offscreen, limit, window_label, timeout_ms }) => {
+      try {
+        const params = { root_selector, include_offscreen, limit, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_compositing_layers", params);
+
+        const result = await socketClient.sendCommand("get_compositing_layers", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Compositing layer error:", error);
+        return createErrorResponse(`Failed to get compositing layers: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,43 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+// Define the overlay actions
+const OVERLAY_ACTIONS = ["on", "off", "toggle", "status"] as const;
+
+export function registerLayoutOverlayTool(server: McpServer) {
+  server.tool(
+    "toggle_layout_overlay",
+    "A 'show element boundaries' debug mode. Outlines every element, or only those matching a selector, with an inset outline that does not change layout, so a following screenshot shows the box structure. With show_margins, each outlined element's margins are also shaded in orange by a click-through overlay that follows scrolling. Use action 'status' to see whether it is on and with which settings, and 'off' (or 'toggle' again) to remove it. Elements inside shadow roots are not outlined.",
+    {
+      action: z.enum(OVERLAY_ACTIONS).optional().describe("Optional. 'on', 'off', 'toggle' or 'status'. Defaults to 'toggle'. Turning it on again replaces the previous settings."),
+      selector: z.string().min(1).optional().describe("Optional. CSS selector for the elements to outline, e.g. 'main *'. Defaults to every element."),
+      color: z.string().min(1).optional().describe("Optional. Any CSS color for the outlines. Defaults to 'rgba(255, 0, 0, 0.6)'."),
+      show_margins: z.boolean().optional().describe("Optional. Shade the margins of the outlined elements. Defaults to false."),
+      max_margin_boxes: z.number().int().min(1).max(20000).optional().describe("Optional. Maximum number of elements given a margin overlay. Defaults to 2000."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Toggle Layout Boundary Overlay",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ action, selector, color, show_margins, max_margin_boxes, window_label, timeout_ms }) => {
+      try {
+        const params = { action, selector, color, show_margins, max_margin_boxes, window_label: window_label || "main", timeout_ms };
+        logCommandParams("toggle_layout_overlay", params);
+
+        const result = await socketClient.sendCommand("toggle_layout_overlay", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Layout overlay error:", error);
+        return createErrorResponse(`Failed to toggle layout overlay: ${(error as Error).message}`);
+      }
+    }
+  );
+}
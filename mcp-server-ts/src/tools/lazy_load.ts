@@ -0,0 +1,50 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerLazyLoadTool(server: McpServer) {
+  server.tool(
+    "trigger_lazy_load",
+    "Scrolls the page, or a scrollable container, to the bottom in steps. After each step it waits for the network to go idle. Reports how many new elements and images appeared, how the new images loaded, and how many requests fired by type, with a per-step breakdown. Stops at the bottom once the scroll height stops growing, after max_steps, or when the time budget runs out. Use it to test infinite scroll and lazy-loaded images.",
+    {
+      container_selector: z.string().optional().describe("Optional. CSS selector of a scrollable container. Defaults to scrolling the page."),
+      step_px: z.number().int().positive().optional().describe("Optional. Pixels to scroll per step. Defaults to 90% of the visible height."),
+      max_steps: z.number().int().min(1).max(500).optional().describe("Optional. Maximum number of scroll steps. Defaults to 30."),
+      idle_ms: z.number().int().min(0).max(10000).optional().describe("Optional. Quiet period without new requests that counts as network idle. Defaults to 500ms."),
+      step_timeout_ms: z.number().int().min(0).max(60000).optional().describe("Optional. Longest wait for network idle after each step. Defaults to 3000ms."),
+      restore_scroll: z.boolean().optional().describe("Optional. Scroll back to the starting position afterwards. Defaults to false."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds for the whole run; stepping stops early to report within it. Defaults to 25000ms."),
+    },
+    {
+      title: "Trigger Lazy Loading",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ container_selector, step_px, max_steps, idle_ms, step_timeout_ms, restore_scroll, window_label, timeout_ms }) => {
+      try {
+        const params = {
+          container_selector,
+          step_px,
+          max_steps,
+          idle_ms,
+          step_timeout_ms,
+          restore_scroll,
+          window_label: window_label || "main",
+          timeout_ms,
+        };
+        logCommandParams("trigger_lazy_load", params);
+
+        const result = await socketClient.sendCommand("trigger_lazy_load", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Lazy load error:", error);
+        return createErrorResponse(`Failed to trigger lazy loading: ${(error as Error).message}`);
+      }
+    }
+  );
+}
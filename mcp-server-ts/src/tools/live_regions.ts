@@ -0,0 +1,73 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+// Define live region politeness levels
+const POLITENESS = ["polite", "assertive"] as const;
+
+export function registerLiveRegionTools(server: McpServer) {
+  server.tool(
+    "capture_live_regions",
+    "Starts recording screen-reader announcements in a window. Watches aria-live regions and the status, alert and log roles, and records what would be announced when they change: the whole text for atomic regions, otherwise the added or changed text. It follows aria-relevant and aria-busy, and flags regions added together with their content, which most screen readers do not announce. Read the results with get_announcements. Installing again keeps the existing buffer.",
+    {
+      max_entries: z.number().int().min(1).max(100000).optional().describe("Optional. Announcements kept before the oldest are dropped. Defaults to 500."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Capture Live Region Announcements",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ max_entries, window_label, timeout_ms }) => {
+      try {
+        const params = { max_entries, window_label: window_label || "main", timeout_ms };
+        logCommandParams("capture_live_regions", params);
+
+        const result = await socketClient.sendCommand("capture_live_regions", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Live region capture error:", error);
+        return createErrorResponse(`Failed to capture live regions: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "get_announcements",
+    "Returns the screen-reader announcements recorded since capture_live_regions, oldest first. Each has its text, politeness (polite or assertive), the region's selector and role, and whether it is likely to be heard. Filter by politeness, text or since_id to check that a form error or toast was actually announced.",
+    {
+      politeness: z.enum(POLITENESS).optional().describe("Optional. Only 'polite' or 'assertive' announcements."),
+      text_contains: z.string().optional().describe("Optional. Only announcements containing this text (case-insensitive)."),
+      since_id: z.number().int().min(0).optional().describe("Optional. Only announcements with an id greater than this, for incremental reads."),
+      limit: z.number().int().min(1).max(100000).optional().describe("Optional. Maximum number of announcements to return, newest last. Defaults to 100."),
+      clear: z.boolean().optional().describe("Optional. Empty the buffer after reading. Defaults to false."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Live Region Announcements",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ politeness, text_contains, since_id, limit, clear, window_label, timeout_ms }) => {
+      try {
+        const params = { politeness, text_contains, since_id, limit, clear, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_announcements", params);
+
+        const result = await socketClient.sendCommand("get_announcements", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Announcement read error:", error);
+        return createErrorResponse(`Failed to get announcements: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -1,6 +1,7 @@
 import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
 import { z } from "zod";
 import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
 
 export function registerManageLocalStorageTool(server: McpServer) {
   server.tool(
@@ -113,4 +114,65 @@ export function registerManageLocalStorageTool(server: McpServer) {
       }
     },
   );
-} 
\ No newline at end of file
+} 
+
+export function registerLocalStorageMapTools(server: McpServer) {
+  server.tool(
+    "get_local_storage_map",
+    "Reads the entire localStorage as one JSON object, parsing each value as JSON where possible and keeping the raw string otherwise. Keys whose values were not valid JSON are listed in raw_string_keys. Handy for asserting storage state in one call.",
+    {
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get localStorage as a Typed Map",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ window_label, timeout_ms }) => {
+      try {
+        const params = { window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_local_storage_map", params);
+
+        const result = await socketClient.sendCommand("get_local_storage_map", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("localStorage map error:", error);
+        return createErrorResponse(`Failed to read localStorage: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "set_local_storage_map",
+    "Replaces the entire localStorage with the given object: clears it, then stores each key. String values are stored as-is; other values are stored as JSON. If any write fails (e.g. quota exceeded), the previous contents are restored. Destructive: existing keys not in the object are removed.",
+    {
+      entries: z.record(z.any()).describe("Object whose keys and values become the new localStorage contents."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Replace localStorage from a Map",
+      readOnlyHint: false,
+      destructiveHint: true,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ entries, window_label, timeout_ms }) => {
+      try {
+        const params = { entries, window_label: window_label || "main", timeout_ms };
+        logCommandParams("set_local_storage_map", params);
+
+        const result = await socketClient.sendCommand("set_local_storage_map", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("localStorage map error:", error);
+        return createErrorResponse(`Failed to write localStorage: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,64 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerMenuTools(server: McpServer) {
+  server.tool(
+    "get_menu",
+    "Returns the native menu of a window: the window's own menu on Windows/Linux, otherwise the app menu (macOS). Each item includes its id, kind (item, check, icon, predefined or submenu), text, enabled state, checked state for check items, and nested submenu items. Use the ids with trigger_menu_item.",
+    {
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+    },
+    {
+      title: "Get Native Menu",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ window_label }) => {
+      try {
+        const params = { window_label: window_label || "main" };
+        logCommandParams("get_menu", params);
+
+        const result = await socketClient.sendCommand("get_menu", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Menu error:", error);
+        return createErrorResponse(`Failed to get menu: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "trigger_menu_item",
+    "Simulates clicking a native menu item, such as File > New. It checks that the item exists and is enabled and emits the opt-in 'tauri-mcp://menu-item-activate' event with the item id. It does not fire a real menu event or toggle check items, so on_menu_event handlers only react if the app routes that event to them. Submenus and predefined items such as Copy or Quit cannot be triggered.",
+    {
+      item_id: z.string().describe("The id of the menu item to activate, as reported by get_menu."),
+      allow_disabled: z.boolean().optional().describe("Optional. Trigger the item even when it is disabled. Defaults to false."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+    },
+    {
+      title: "Trigger Native Menu Item",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ item_id, allow_disabled, window_label }) => {
+      try {
+        const params = { item_id, allow_disabled, window_label: window_label || "main" };
+        logCommandParams("trigger_menu_item", params);
+
+        const result = await socketClient.sendCommand("trigger_menu_item", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Menu error:", error);
+        return createErrorResponse(`Failed to trigger menu item: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,69 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createImageResponse, createSuccessResponse, extractBase64Data, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerMonitorTools(server: McpServer) {
+  server.tool(
+    "get_monitors",
+    "Lists the monitors connected to the system with their id, name, position, size (physical pixels), scale factor, and whether they are the primary display. Use the returned id with screenshot_monitor.",
+    {},
+    {
+      title: "List Connected Monitors",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async () => {
+      try {
+        logCommandParams("get_monitors", {});
+
+        const result = await socketClient.sendCommand("get_monitors", {});
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Get monitors error:", error);
+        return createErrorResponse(`Failed to list monitors: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "screenshot_monitor",
+    "Captures the whole display rather than a single window, returning a JPEG image. Covers native UI that lives outside any Tauri window, such as native dialogs or the system tray. Supported on macOS, Windows, and Linux.",
+    {
+      monitor_id: z.string().optional().describe("Optional. The monitor id returned by get_monitors. Defaults to the primary monitor."),
+      quality: z.number().int().min(1).max(100).optional().describe("Optional. JPEG quality (1-100). Defaults to 85."),
+      max_width: z.number().int().positive().optional().describe("Optional. Maximum image width in pixels; larger captures are scaled down. Defaults to 1920."),
+      max_size_mb: z.number().positive().optional().describe("Optional. Maximum image size in MB; quality is reduced to fit. Defaults to 2."),
+    },
+    {
+      title: "Capture Screenshot of a Monitor",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ monitor_id, quality, max_width, max_size_mb }) => {
+      try {
+        const params = { monitor_id, quality, max_width, max_size_mb };
+        logCommandParams("screenshot_monitor", params);
+
+        const result = await socketClient.sendCommand("screenshot_monitor", params);
+
+        const base64Data = extractBase64Data(result);
+
+        if (!base64Data) {
+          console.error("Failed to extract base64 data from response:", JSON.stringify(result));
+          return createErrorResponse(`Failed to extract image data from response: ${JSON.stringify(result).substring(0, 100)}...`);
+        }
+
+        return createImageResponse(base64Data, "image/jpeg");
+      } catch (error) {
+        console.error("Monitor screenshot error:", error);
+        return createErrorResponse(`Failed to capture monitor: ${(error as Error).message}`);
+      }
+    }
+  );
+}
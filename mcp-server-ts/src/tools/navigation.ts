@@ -0,0 +1,102 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerNavigateHistoryTool(server: McpServer) {
+  server.tool(
+    "navigate_history",
+    "Moves back or forward through the webview's history (like the browser back/forward buttons) and waits for the URL to change, returning the URLs before and after. Fails if the URL does not change within the timeout or does not contain expect_url_contains. Works for client-side routes and full page loads alike.",
+    {
+      direction: z.enum(["back", "forward"]).describe("Direction to move through history."),
+      steps: z.number().int().min(1).max(100).optional().describe("Optional. Number of history entries to move. Defaults to 1."),
+      expect_url_contains: z.string().optional().describe("Optional. Substring the resulting URL must contain, e.g. '/settings'."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the URL to change. Defaults to 3000ms."),
+    },
+    {
+      title: "Navigate Back/Forward in History",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ direction, steps, expect_url_contains, window_label, timeout_ms }) => {
+      try {
+        const params = { direction, steps, expect_url_contains, window_label: window_label || "main", timeout_ms };
+        logCommandParams("navigate_history", params);
+
+        const result = await socketClient.sendCommand("navigate_history", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("History navigation error:", error);
+        return createErrorResponse(`Failed to navigate history: ${(error as Error).message}`);
+      }
+    }
+  );
+}
+
+// Define the scroll restoration modes
+const SCROLL_RESTORATION_MODES = ["auto", "manual"] as const;
+
+export function registerScrollRestorationTools(server: McpServer) {
+  server.tool(
+    "get_scroll_restoration",
+    "Reads history.scrollRestoration for the current history entry: 'auto' lets the webview restore the scroll position on back/forward navigation, 'manual' leaves it to the app. Also returns the URL, history length and current scroll position. Use it to assert which mode is active when debugging back-navigation scroll bugs in an SPA.",
+    {
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Scroll Restoration Mode",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ window_label, timeout_ms }) => {
+      try {
+        const params = { window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_scroll_restoration", params);
+
+        const result = await socketClient.sendCommand("get_scroll_restoration", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Scroll restoration error:", error);
+        return createErrorResponse(`Failed to get scroll restoration: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "set_scroll_restoration",
+    "Sets history.scrollRestoration to 'auto' or 'manual' and returns the previous mode. The mode belongs to the current history entry: entries added later with pushState inherit it, but a full page load starts again at 'auto'.",
+    {
+      mode: z.enum(SCROLL_RESTORATION_MODES).describe("'auto' to let the webview restore scroll positions, 'manual' to leave it to the app."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Set Scroll Restoration Mode",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ mode, window_label, timeout_ms }) => {
+      try {
+        const params = { mode, window_label: window_label || "main", timeout_ms };
+        logCommandParams("set_scroll_restoration", params);
+
+        const result = await socketClient.sendCommand("set_scroll_restoration", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Scroll restoration error:", error);
+        return createErrorResponse(`Failed to set scroll restoration: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -7,33 +7,44 @@ import { createErrorResponse, createSuccessResponse, formatResultAsText, logComm
 const HTTP_METHODS = ["GET", "POST", "PUT", "DELETE", "PATCH", "HEAD", "OPTIONS", "TRACE", "CONNECT"] as const;
 
 // Define request types
-const REQUEST_TYPES = ["fetch", "xhr"] as const;
+const REQUEST_TYPES = ["fetch", "xhr", "websocket"] as const;
 
 // Define actions
-const ACTIONS = ["get_requests", "clear_requests", "start_capture", "stop_capture"] as const;
+const ACTIONS = ["get_requests", "clear_requests", "start_capture", "stop_capture", "set_default_headers", "clear_default_headers", "get_header_injections", "fail_resource_type", "clear_resource_failures", "get_resource_failures", "export_har"] as const;
+
+// Define resource types that fail_resource_type can make fail
+const RESOURCE_TYPES = ["image", "script", "fetch", "xhr", "stylesheet"] as const;
+
+// Define capture buffer eviction policies
+const EVICTION_POLICIES = ["fifo", "keep_errors", "sampled"] as const;
 
 export function registerNetworkInspectorTool(server: McpServer) {
   server.tool(
     "network_inspector",
-    "Inspects and retrieves network requests (fetch/XHR) made by the application. Allows querying request/response headers, bodies, status codes, and timing information. Useful for debugging API integration issues and understanding network behavior.",
+    "Inspects and retrieves network requests (fetch/XHR) made by the application. Allows querying request/response headers, bodies, status codes, and timing information. Useful for debugging API integration issues and understanding network behavior. 'set_default_headers' adds headers (e.g. an auth token or staging header) to every fetch/XHR request whose URL matches url_pattern, without overriding headers the app sets itself, and records which requests got them. 'fail_resource_type' makes every request of the given resource types (optionally narrowed by url_pattern) error out, so you can test fallbacks when, say, all images fail to load. Each failed request is recorded with the rule that triggered it. 'export_har' returns the captured requests as a HAR 1.2 document that can be saved to a .har file and opened in Chrome DevTools.",
     {
       action: z.enum(ACTIONS).describe(
-        "The action to perform: 'get_requests' to retrieve captured requests, 'clear_requests' to clear the capture buffer, 'start_capture' to start capturing, or 'stop_capture' to stop capturing."
+        "The action to perform: 'get_requests' to retrieve captured requests, 'clear_requests' to clear the capture buffer, 'start_capture' to start capturing, or 'stop_capture' to stop capturing. 'set_default_headers' injects headers into matching requests, 'get_header_injections' lists requests that had headers injected, and 'clear_default_headers' stops injecting and returns that list. 'fail_resource_type' adds a failure rule, 'get_resource_failures' lists the active rules and failed requests, and 'clear_resource_failures' removes all rules. 'export_har' returns the most recent requests matching the filters (up to 1000) as HAR JSON without moving the since_last_poll cursor."
       ),
-      url_pattern: z.string().optional().describe("Optional. Filter requests by URL pattern (regex or substring match). Use to focus on specific endpoints."),
+      url_pattern: z.string().optional().describe("Optional. Filter requests by URL pattern (regex or substring match). Use to focus on specific endpoints. For 'set_default_headers', only matching requests get the headers."),
+      headers: z.record(z.string()).optional().describe("Headers to inject, e.g. { \"X-Env\": \"staging\" }. Required for 'set_default_headers'."),
+      resource_types: z.array(z.enum(RESOURCE_TYPES)).optional().describe("Resource types to fail: 'image', 'script', 'fetch', 'xhr' or 'stylesheet'. Required for 'fail_resource_type'. Elements are only affected when their URL is set after the rule is added; markup that was already loaded and CSS url() images are not."),
       method: z.enum(HTTP_METHODS).optional().describe("Optional. Filter requests by HTTP method (GET, POST, PUT, DELETE, PATCH, HEAD, OPTIONS, TRACE, CONNECT)."),
       status_code: z.number().int().min(100).max(599).optional().describe("Optional. Filter requests by HTTP status code (e.g., 200, 404, 500)."),
       min_duration_ms: z.number().int().nonnegative().optional().describe("Optional. Only return requests that took at least this many milliseconds."),
       max_duration_ms: z.number().int().nonnegative().optional().describe("Optional. Only return requests that took at most this many milliseconds."),
-      request_type: z.enum(REQUEST_TYPES).optional().describe("Optional. Filter by request type: 'fetch' for Fetch API or 'xhr' for XMLHttpRequest."),
+      request_type: z.enum(REQUEST_TYPES).optional().describe("Optional. Filter by request type: 'fetch' for Fetch API, 'xhr' for XMLHttpRequest or 'websocket' for WebSocket connections."),
       start_time_ms: z.number().int().nonnegative().optional().describe("Optional. Only return requests that started after this Unix timestamp in milliseconds."),
       end_time_ms: z.number().int().nonnegative().optional().describe("Optional. Only return requests that started before this Unix timestamp in milliseconds."),
-      limit: z.number().int().positive().optional().describe("Optional. Maximum number of requests to return. Defaults to 100. Use for pagination."),
+      limit: z.number().int().positive().optional().describe("Optional. Maximum number of requests to return. Defaults to 100 ('export_har' defaults to and is capped at 1000). Use for pagination."),
+      since_last_poll: z.boolean().optional().describe("Optional. For 'get_requests', only return requests the previous since_last_poll query on this window did not return, oldest first, so repeated queries page through new traffic without gaps or duplicates even when limited. The returned cursor (start time of the newest request returned so far) can also be passed as start_time_ms for explicit deltas, which may repeat requests from that millisecond."),
+      max_body_bytes: z.number().int().nonnegative().optional().describe("Optional. For 'get_requests' and 'export_har', truncate request and response bodies and WebSocket frame payloads to this many bytes, marking each cut with '[truncated N bytes]'. Bodies are not truncated by default."),
+      redact_headers: z.array(z.string()).optional().describe("Optional. For 'get_requests' and 'export_har', header names (case-insensitive, e.g. [\"authorization\", \"cookie\"]) whose values are replaced with '[REDACTED]'. Nothing is redacted by default."),
       window_label: z.string().optional().describe("Optional. The identifier of the application window to inspect. Defaults to 'main' if not specified."),
     },
     {
       title: "Inspect Network Requests from Application",
-      readOnlyHint: true,
+      readOnlyHint: false,
       destructiveHint: false,
       idempotentHint: true,
       openWorldHint: false,
@@ -41,6 +52,8 @@ export function registerNetworkInspectorTool(server: McpServer) {
     async ({
       action,
       url_pattern,
+      headers,
+      resource_types,
       method,
       status_code,
       min_duration_ms,
@@ -49,12 +62,17 @@ export function registerNetworkInspectorTool(server: McpServer) {
       start_time_ms,
       end_time_ms,
       limit,
+      since_last_poll,
+      max_body_bytes,
+      redact_headers,
       window_label,
     }) => {
       try {
         const params = {
           action,
           window_label: window_label || "main",
+          headers,
+          resource_types,
           filter: {
             url_pattern,
             method,
@@ -64,7 +82,10 @@ export function registerNetworkInspectorTool(server: McpServer) {
             request_type,
             start_time_ms,
             end_time_ms,
-            limit: limit || 100,
+            limit: action === "export_har" ? limit : limit || 100,
+            since_last_poll,
+            max_body_bytes,
+            redact_headers,
           },
         };
 
@@ -74,6 +95,11 @@ export function registerNetworkInspectorTool(server: McpServer) {
 
         console.error(`Got network inspector result: ${typeof result}`);
 
+        // HAR exports are returned verbatim so they can be saved as a .har file
+        if (action === "export_har") {
+          return createSuccessResponse(JSON.stringify(result, null, 2));
+        }
+
         // Format the result for display
         if (typeof result === "object" && result && "requests" in result) {
           const requestsData = result as {
@@ -91,14 +117,32 @@ export function registerNetworkInspectorTool(server: McpServer) {
               start_time_ms: number;
               end_time_ms?: number;
               duration_ms?: number;
+              open_time_ms?: number;
+              protocol?: string;
+              close_code?: number;
+              close_reason?: string;
+              frames?: Array<{
+                direction: "sent" | "received";
+                opcode: "text" | "binary";
+                size_bytes: number;
+                time_ms: number;
+                payload?: string;
+              }>;
+              frame_count?: number;
             }>;
             total_count: number;
             returned_count: number;
             capture_active: boolean;
+            cursor?: number;
+            eviction_policy?: string;
+            evicted_count?: number;
           };
 
+          const cursorNote = requestsData.cursor !== undefined ? ` [Cursor: ${requestsData.cursor}]` : "";
+          const evictionNote = requestsData.eviction_policy ? ` [Eviction: ${requestsData.eviction_policy}, ${requestsData.evicted_count ?? 0} evicted]` : "";
+
           if (requestsData.requests.length === 0) {
-            return createSuccessResponse("No network requests found matching the specified criteria.");
+            return createSuccessResponse(`No network requests found matching the specified criteria.${cursorNote}`);
           }
 
           // Format requests for display
@@ -115,6 +159,18 @@ export function registerNetworkInspectorTool(server: McpServer) {
                 summary += `\n    ERROR: ${req.error}`;
               }
 
+              if (req.frames) {
+                const closed = req.close_code !== undefined
+                  ? `closed ${req.close_code}${req.close_reason ? ` (${req.close_reason})` : ""}`
+                  : req.open_time_ms ? "open" : "connecting";
+                summary += `\n    WebSocket: ${closed}, ${req.frame_count ?? req.frames.length} frames (${req.frames.length} kept)`;
+                req.frames.slice(-5).forEach((frame) => {
+                  const arrow = frame.direction === "sent" ? "->" : "<-";
+                  const payload = frame.payload !== undefined ? ` ${frame.payload.substring(0, 100)}` : "";
+                  summary += `\n      ${arrow} ${frame.opcode} ${frame.size_bytes}B${payload}`;
+                });
+              }
+
               if (req.request_body) {
                 const bodyPreview = req.request_body.substring(0, 100);
                 const truncated = req.request_body.length > 100 ? "..." : "";
@@ -148,7 +204,7 @@ export function registerNetworkInspectorTool(server: McpServer) {
             .join("\n\n");
 
           const captureStatus = requestsData.capture_active ? "ACTIVE" : "INACTIVE";
-          const summary = `Network Requests (${requestsData.returned_count} of ${requestsData.total_count} total) [Capture: ${captureStatus}]\n\n${formattedRequests}`;
+          const summary = `Network Requests (${requestsData.returned_count} of ${requestsData.total_count} total) [Capture: ${captureStatus}]${cursorNote}${evictionNote}\n\n${formattedRequests}`;
           return createSuccessResponse(summary);
         }
 
@@ -164,9 +220,13 @@ export function registerNetworkInspectorTool(server: McpServer) {
 export function registerInjectNetworkCaptureTool(server: McpServer) {
   server.tool(
     "inject_network_capture",
-    "Injects the network capture script into the webview to start intercepting fetch and XMLHttpRequest (XHR) calls. This must be called once when the application starts to enable network request inspection. Subsequent calls re-inject the capture mechanism.",
+    "Injects the network capture script into the webview to start intercepting fetch and XMLHttpRequest (XHR) calls and WebSocket connections. This must be called once when the application starts to enable network request inspection. Subsequent calls re-inject the capture mechanism.",
     {
       window_label: z.string().optional().describe("Optional. The identifier of the application window to inject the capture script into. Defaults to 'main' if not specified."),
+      eviction_policy: z.enum(EVICTION_POLICIES).optional().describe("Optional. How the capture buffer makes room when full: 'fifo' (default) drops the oldest request, 'keep_errors' protects failed requests (network errors or status >= 400) from eviction, 'sampled' keeps an even sample across the whole session."),
+      max_entries: z.number().int().positive().optional().describe("Optional. Capacity of the capture buffer. Defaults to 500."),
+      websocket_max_frames: z.number().int().min(1).max(10000).optional().describe("Optional. Most recent frames kept per WebSocket connection; older frames are dropped but still counted. Defaults to 100."),
+      websocket_payload_limit: z.number().int().min(0).max(1000000).optional().describe("Optional. Keep the payload of text frames up to this many bytes. Defaults to 0, which records frame sizes only."),
     },
     {
       title: "Inject Network Capture Script into Webview",
@@ -175,10 +235,14 @@ export function registerInjectNetworkCaptureTool(server: McpServer) {
       idempotentHint: false,
       openWorldHint: false,
     },
-    async ({ window_label }) => {
+    async ({ window_label, eviction_policy, max_entries, websocket_max_frames, websocket_payload_limit }) => {
       try {
         const params = {
           window_label: window_label || "main",
+          eviction_policy,
+          max_entries,
+          websocket_max_frames,
+          websocket_payload_limit,
         };
 
         logCommandParams("inject_network_capture", params);
@@ -188,7 +252,7 @@ export function registerInjectNetworkCaptureTool(server: McpServer) {
         console.error(`Network capture injection result: ${typeof result}`);
 
         return createSuccessResponse(
-          "Network capture script successfully injected. Fetch and XHR requests will now be captured."
+          "Network capture script successfully injected. Fetch and XHR requests and WebSocket connections will now be captured."
         );
       } catch (error) {
         console.error("Network capture injection error:", error);
@@ -0,0 +1,78 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+// Define observer types
+const OBSERVER_TYPES = ["intersection", "resize", "all"] as const;
+
+// Define intersection trigger modes
+const TRIGGER_MODES = ["auto", "scroll", "dispatch"] as const;
+
+export function registerObserversTool(server: McpServer) {
+  server.tool(
+    "get_observers_for",
+    "Reports which IntersectionObserver and ResizeObserver instances currently watch an element. For each observer it returns its root, rootMargin and thresholds (or the observed box for resize observers), how often its callback ran, and the latest entry delivered for the element, plus the element's current rect and viewport visibility. Omit selector to list every tracked observer with its targets. Use it to debug why a lazy load or infinite scroll does not fire. Observers are only tracked after the first call unless the plugin is configured with track_observers, which tracks them from page start.",
+    {
+      selector: z.string().min(1).optional().describe("Optional. CSS selector of the element to report observers for. Lists all tracked observers when omitted."),
+      observer_type: z.enum(OBSERVER_TYPES).optional().describe("Optional. Only report 'intersection' or 'resize' observers. Defaults to 'all'."),
+      max_targets: z.number().int().min(0).max(1000).optional().describe("Optional. Target selectors listed per observer when no selector is given. Defaults to 10."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Observers For Element",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ selector, observer_type, max_targets, window_label, timeout_ms }) => {
+      try {
+        const params = { selector, observer_type, max_targets, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_observers_for", params);
+
+        const result = await socketClient.sendCommand("get_observers_for", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Observer inspection error:", error);
+        return createErrorResponse(`Failed to get observers: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "trigger_intersection",
+    "Makes an element's IntersectionObserver callbacks fire without depending on exact scroll math, to test lazy loading and reveal-on-scroll logic deterministically. In 'dispatch' mode every tracked observer watching the element gets a synthetic entry through its own callback (is_intersecting false simulates the element leaving). In 'scroll' mode the element is scrolled into view and the tool waits for real callbacks. 'auto' dispatches when a tracked observer watches the element and scrolls otherwise. Reports per observer whether the callback fired; this is only known for observers tracked from page start, so configure the plugin with track_observers.",
+    {
+      selector: z.string().min(1).describe("CSS selector of the element whose intersection to trigger"),
+      mode: z.enum(TRIGGER_MODES).optional().describe("Optional. 'auto', 'scroll' or 'dispatch'. Defaults to 'auto'."),
+      is_intersecting: z.boolean().optional().describe("Optional. Whether the synthetic entry reports the element as entering (true) or leaving (false). Dispatch mode only. Defaults to true."),
+      wait_ms: z.number().int().min(0).max(10000).optional().describe("Optional. How long to wait for callbacks after scrolling. Defaults to 300."),
+      restore_scroll: z.boolean().optional().describe("Optional. Scroll back to the starting position afterwards. Defaults to false."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms; wait_ms is added on top."),
+    },
+    {
+      title: "Trigger Intersection",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ selector, mode, is_intersecting, wait_ms, restore_scroll, window_label, timeout_ms }) => {
+      try {
+        const params = { selector, mode, is_intersecting, wait_ms, restore_scroll, window_label: window_label || "main", timeout_ms };
+        logCommandParams("trigger_intersection", params);
+
+        const result = await socketClient.sendCommand("trigger_intersection", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Intersection trigger error:", error);
+        return createErrorResponse(`Failed to trigger intersection: ${(error as Error).message}`);
+      }
+    }
+  );
+}
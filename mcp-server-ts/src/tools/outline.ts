@@ -0,0 +1,38 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerSemanticOutlineTool(server: McpServer) {
+  server.tool(
+    "get_semantic_outline",
+    "Returns a compact tree of what can be interacted with on the page: landmarks (nav, main, aside, forms, dialogs), headings, and interactive elements (buttons, links, inputs, selects) with their accessible name, a CSS selector usable by other tools, and enabled/visible/checked state. Presentational wrappers are skipped. Much smaller than get_dom; prefer it for deciding what to click or type into.",
+    {
+      root_selector: z.string().optional().describe("Optional. CSS selector of the element to outline. Defaults to the document body."),
+      max_nodes: z.number().int().min(1).max(5000).optional().describe("Optional. Maximum number of nodes in the outline. Defaults to 300; the result is marked truncated when reached."),
+      include_hidden: z.boolean().optional().describe("Optional. Include elements that are not currently visible. Defaults to false."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window to inspect. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Semantic Page Outline",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ root_selector, max_nodes, include_hidden, window_label, timeout_ms }) => {
+      try {
+        const params = { root_selector, max_nodes, include_hidden, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_semantic_outline", params);
+
+        const result = await socketClient.sendCommand("get_semantic_outline", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Semantic outline error:", error);
+        return createErrorResponse(`Failed to get semantic outline: ${(error as Error).message}`);
+      }
+    }
+  );
+}
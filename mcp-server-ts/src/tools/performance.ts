@@ -6,13 +6,18 @@ import { createErrorResponse, createSuccessResponse, formatResultAsText, logComm
 export function registerPerformanceMetricsTool(server: McpServer) {
   server.tool(
     "get_performance_metrics",
-    "Retrieves application performance metrics including navigation timing, resource timing, user timing marks/measures, memory usage, and long tasks. Helps understand application performance and identify bottlenecks. Useful for AI agents to evaluate application responsiveness and resource efficiency.",
+    "Retrieves application performance metrics including navigation timing, resource timing, user timing marks/measures, memory usage, long tasks, and optionally the CLS, INP and FID web vitals. Helps understand application performance and identify bottlenecks. Useful for AI agents to evaluate application responsiveness and resource efficiency.",
     {
       include_navigation: z.boolean().optional().describe("Optional. Include navigation timing data (page load metrics). Defaults to true."),
       include_resources: z.boolean().optional().describe("Optional. Include resource timing data (script, stylesheet, image load times). Defaults to true."),
       include_user_timing: z.boolean().optional().describe("Optional. Include user-defined timing marks and measures. Defaults to true."),
       include_memory: z.boolean().optional().describe("Optional. Include memory usage data (if available). Defaults to true."),
       include_long_tasks: z.boolean().optional().describe("Optional. Include long tasks (main thread blocks > 50ms). Defaults to false."),
+      include_waterfall: z.boolean().optional().describe("Optional. Include a network waterfall: resources ordered by start time with time-to-first-byte, blocking time, and offsets normalized to the whole load span. Defaults to false."),
+      include_cls: z.boolean().optional().describe("Optional. Include Cumulative Layout Shift (sum of shifts without recent input) under web_vitals with a good/needs-improvement/poor rating. Defaults to false."),
+      include_inp: z.boolean().optional().describe("Optional. Include Interaction to Next Paint under web_vitals with a rating. Needs interactions to have happened. Defaults to false."),
+      include_fid: z.boolean().optional().describe("Optional. Include First Input Delay under web_vitals with a rating. Needs a first input to have happened. Defaults to false."),
+      vitals_window_ms: z.number().int().min(0).max(30000).optional().describe("Optional. How long the buffered web vitals observers collect before the response is sent. Defaults to 500."),
       resource_types: z.array(z.string()).optional().describe("Optional. Filter resources by type (e.g., 'script', 'stylesheet', 'image', 'fetch', 'xmlhttprequest'). If not specified, all resource types are included."),
       min_duration_ms: z.number().optional().describe("Optional. Only include resources with duration >= this value in milliseconds."),
       max_duration_ms: z.number().optional().describe("Optional. Only include resources with duration <= this value in milliseconds."),
@@ -31,6 +36,11 @@ export function registerPerformanceMetricsTool(server: McpServer) {
       include_user_timing,
       include_memory,
       include_long_tasks,
+      include_waterfall,
+      include_cls,
+      include_inp,
+      include_fid,
+      vitals_window_ms,
       resource_types,
       min_duration_ms,
       max_duration_ms,
@@ -44,6 +54,11 @@ export function registerPerformanceMetricsTool(server: McpServer) {
           include_user_timing: include_user_timing !== false,
           include_memory: include_memory !== false,
           include_long_tasks: include_long_tasks || false,
+          include_waterfall: include_waterfall || false,
+          include_cls: include_cls || false,
+          include_inp: include_inp || false,
+          include_fid: include_fid || false,
+          vitals_window_ms,
           resource_filter: {
             resource_type: resource_types && resource_types.length > 0 ? resource_types : undefined,
             min_duration_ms: min_duration_ms,
@@ -110,6 +125,19 @@ export function registerPerformanceMetricsTool(server: McpServer) {
                 output += `    ${type}: ${count} resources, ${totalDuration.toFixed(0)}ms, ${totalSize} bytes\n`;
               }
             }
+
+            // Waterfall, one bar per resource scaled to 40 columns
+            if (resources.waterfall && resources.waterfall.resources.length > 0) {
+              output += `\n  Waterfall (${resources.waterfall.span_ms.toFixed(0)}ms span):\n`;
+              resources.waterfall.resources.forEach((r: any) => {
+                const offset = Math.round(r.offset_ratio * 40);
+                const width = Math.max(1, Math.round(r.duration_ratio * 40));
+                const bar = " ".repeat(Math.min(offset, 40)) + "#".repeat(Math.min(width, 40 - Math.min(offset, 39)));
+                const ttfb = r.ttfb_ms !== null ? `, ttfb ${r.ttfb_ms.toFixed(0)}ms` : "";
+                const name = r.name.length > 50 ? "..." + r.name.slice(-47) : r.name;
+                output += `    |${bar.padEnd(40)}| ${r.duration_ms.toFixed(0)}ms${ttfb} ${name}\n`;
+              });
+            }
             output += "\n";
           }
 
@@ -181,6 +209,24 @@ export function registerPerformanceMetricsTool(server: McpServer) {
             output += "\n";
           }
 
+          // Core Web Vitals
+          if (metricsData.metrics.web_vitals) {
+            const vitals = metricsData.metrics.web_vitals;
+            const rating = (metric: any) => metric.rating ?? "no data";
+            output += `Web Vitals (collected over ${vitals.collected_over_ms}ms)\n`;
+            output += "-".repeat(40) + "\n";
+            if (vitals.cls) {
+              output += `  CLS: ${vitals.cls.value} (${rating(vitals.cls)}, ${vitals.cls.shift_count} shifts)\n`;
+            }
+            if (vitals.inp) {
+              output += `  INP: ${vitals.inp.value_ms ?? "n/a"}ms (${rating(vitals.inp)}, ${vitals.inp.interaction_count} interactions)\n`;
+            }
+            if (vitals.fid) {
+              output += `  FID: ${vitals.fid.value_ms ?? "n/a"}ms (${rating(vitals.fid)})\n`;
+            }
+            output += "\n";
+          }
+
           // Long Tasks
           if (metricsData.metrics.long_tasks) {
             const longTasks = metricsData.metrics.long_tasks;
@@ -226,3 +272,102 @@ export function registerPerformanceMetricsTool(server: McpServer) {
     }
   );
 }
+
+export function registerLoadedScriptsTool(server: McpServer) {
+  server.tool(
+    "get_loaded_scripts",
+    "Lists every script the page has loaded: <script src> tags and dynamically imported modules, each with transfer/encoded/decoded size from resource timing and whether it came from cache, sorted largest first. Also reports the number of inline scripts and their total bytes. Useful for auditing bundle size and code-splitting.",
+    {
+      window_label: z.string().optional().describe("Optional. The identifier of the application window to inspect. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Loaded Scripts and Sizes",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ window_label, timeout_ms }) => {
+      try {
+        const params = { window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_loaded_scripts", params);
+
+        const result = await socketClient.sendCommand("get_loaded_scripts", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Loaded scripts error:", error);
+        return createErrorResponse(`Failed to list loaded scripts: ${(error as Error).message}`);
+      }
+    }
+  );
+}
+
+export function registerScriptTimingTool(server: McpServer) {
+  server.tool(
+    "get_script_timing",
+    "Estimates, per loaded script, how long it took to download versus parse/compile and execute, to tell whether slow startup comes from the network or from running a heavy bundle. Download time comes from resource timing. Execute time comes from the script's own user timing measures (named after the file), from long-animation-frame script attribution where supported (with compile time split out), or from the long task that started right after the download, marked by 'method'. Each script also has its sizes (as in get_loaded_scripts), whether it is render-blocking, whether it finished before first paint, execute time per 100KB and a suggestion when it is slow. The heaviest scripts are listed first. Cheap scripts that never ran in a task over 50ms have no estimate.",
+    {
+      observe_ms: z.number().int().min(0).max(10000).optional().describe("Optional. How long to collect buffered long-animation-frame and long task entries. Defaults to 100ms."),
+      top: z.number().int().min(1).max(100).optional().describe("Optional. Number of scripts listed as the heaviest. Defaults to 5."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window to inspect. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms; observe_ms is added on top."),
+    },
+    {
+      title: "Get Script Parse/Execute Timing",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ observe_ms, top, window_label, timeout_ms }) => {
+      try {
+        const params = { observe_ms, top, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_script_timing", params);
+
+        const result = await socketClient.sendCommand("get_script_timing", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Script timing error:", error);
+        return createErrorResponse(`Failed to get script timing: ${(error as Error).message}`);
+      }
+    }
+  );
+}
+
+export function registerClearPerformanceEntriesTool(server: McpServer) {
+  server.tool(
+    "clear_performance_entries",
+    "Clears the webview's performance entry buffers (resource timings, user timing marks and measures) so a following get_performance_metrics call only covers the interaction under test. Optionally resizes the resource timing buffer (browser default 250), which stops long sessions from silently dropping entries. Reports how many entries were cleared. Navigation and paint entries cannot be cleared.",
+    {
+      resources: z.boolean().optional().describe("Optional. Clear resource timings. Defaults to true."),
+      marks: z.boolean().optional().describe("Optional. Clear user timing marks. Defaults to true."),
+      measures: z.boolean().optional().describe("Optional. Clear user timing measures. Defaults to true."),
+      reset_buffer_size: z.number().int().min(1).max(100000).optional().describe("Optional. New size for the resource timing buffer."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Clear Performance Entries",
+      readOnlyHint: false,
+      destructiveHint: true,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ resources, marks, measures, reset_buffer_size, window_label, timeout_ms }) => {
+      try {
+        const params = { resources, marks, measures, reset_buffer_size, window_label: window_label || "main", timeout_ms };
+        logCommandParams("clear_performance_entries", params);
+
+        const result = await socketClient.sendCommand("clear_performance_entries", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Performance entries error:", error);
+        return createErrorResponse(`Failed to clear performance entries: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,37 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerPixelColorTool(server: McpServer) {
+  server.tool(
+    "get_pixel_color",
+    "Reads the rendered color at a point in the window without transferring a screenshot. The point is in CSS pixels relative to the viewport, the same coordinates get_element_position reports. The window is captured losslessly at native resolution and the pixel is returned as RGBA values and a hex string. Pass radius to average over a small circle instead of one pixel, which smooths out anti-aliasing. Only available on macOS and Windows.",
+    {
+      x: z.number().min(0).describe("Horizontal position in CSS pixels from the left edge of the viewport."),
+      y: z.number().min(0).describe("Vertical position in CSS pixels from the top edge of the viewport."),
+      radius: z.number().int().min(0).max(50).optional().describe("Optional. Average the pixels within this radius in CSS pixels. Defaults to 0, a single pixel."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+    },
+    {
+      title: "Get Pixel Color",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ x, y, radius, window_label }) => {
+      try {
+        const params = { x, y, radius, window_label: window_label || "main" };
+        logCommandParams("get_pixel_color", params);
+
+        const result = await socketClient.sendCommand("get_pixel_color", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Pixel color error:", error);
+        return createErrorResponse(`Failed to read pixel color: ${(error as Error).message}`);
+      }
+    }
+  );
+}
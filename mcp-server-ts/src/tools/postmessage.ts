@@ -0,0 +1,104 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+// Define message directions and channels
+const DIRECTIONS = ["in", "out"] as const;
+const CHANNELS = ["window", "worker", "port", "broadcast", "service_worker"] as const;
+
+export function registerPostMessageTools(server: McpServer) {
+  server.tool(
+    "capture_postmessage",
+    "Installs a postMessage recorder in a window. Records outgoing messages posted on the window, Workers, MessagePorts, BroadcastChannels and ServiceWorkers, and incoming messages on the window (iframes, parent, opener, self) and on any of those channels once the page uses them. Each entry keeps origin, source or target, and safely serialized data. Only traffic after installation is recorded, and a navigation removes the recorder. Safe to call again to change limits.",
+    {
+      max_entries: z.number().int().min(1).max(100000).optional().describe("Optional. Messages kept before the oldest are dropped. Defaults to 1000."),
+      max_data_chars: z.number().int().min(0).max(1000000).optional().describe("Optional. Characters of serialized data kept per message. Defaults to 2000."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Capture postMessage Traffic",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ max_entries, max_data_chars, window_label, timeout_ms }) => {
+      try {
+        const params = { max_entries, max_data_chars, window_label: window_label || "main", timeout_ms };
+        logCommandParams("capture_postmessage", params);
+
+        const result = await socketClient.sendCommand("capture_postmessage", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("postMessage capture error:", error);
+        return createErrorResponse(`Failed to capture postMessage traffic: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "get_postmessages",
+    "Returns postMessage traffic recorded by capture_postmessage, oldest first. Filter by direction, channel, origin or id. Pass the returned last_id as since_id to poll for new messages only.",
+    {
+      direction: z.enum(DIRECTIONS).optional().describe("Optional. Only incoming ('in') or outgoing ('out') messages."),
+      channel: z.enum(CHANNELS).optional().describe("Optional. Only messages on this channel."),
+      origin: z.string().optional().describe("Optional. Only messages whose origin (or target origin) contains this text."),
+      since_id: z.number().int().min(0).optional().describe("Optional. Only messages with a greater id."),
+      limit: z.number().int().min(1).max(100000).optional().describe("Optional. Maximum number of messages to return (the most recent). Defaults to 100."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get postMessage Traffic",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ direction, channel, origin, since_id, limit, window_label, timeout_ms }) => {
+      try {
+        const params = { direction, channel, origin, since_id, limit, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_postmessages", params);
+
+        const result = await socketClient.sendCommand("get_postmessages", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("postMessage read error:", error);
+        return createErrorResponse(`Failed to get postMessage traffic: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "clear_postmessages",
+    "Empties the postMessage buffer recorded by capture_postmessage. Recording continues.",
+    {
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Clear postMessage Traffic",
+      readOnlyHint: false,
+      destructiveHint: true,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ window_label, timeout_ms }) => {
+      try {
+        const params = { window_label: window_label || "main", timeout_ms };
+        logCommandParams("clear_postmessages", params);
+
+        const result = await socketClient.sendCommand("clear_postmessages", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("postMessage clear error:", error);
+        return createErrorResponse(`Failed to clear postMessage traffic: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,41 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+// Define measurement actions
+const ACTIONS = ["measure", "start", "stop"] as const;
+
+export function registerMeasureReflowsTool(server: McpServer) {
+  server.tool(
+    "measure_reflows",
+    "Counts forced synchronous layouts (reflows): layout reads such as offsetHeight, getBoundingClientRect, or getComputedStyle that happen right after a DOM write. Reports the count, reads per property, frames rendered vs frames with DOM mutations, and the most frequent call stacks causing them, which flags read-write-read-write layout thrashing. Use 'measure' for a fixed window, or 'start', drive the UI with other tools, then 'stop'. Counts are approximate: style sheet changes that bypass the DOM are not seen.",
+    {
+      action: z.enum(ACTIONS).optional().describe("Optional. 'measure' (default) instruments for duration_ms and reports; 'start' leaves instrumentation running; 'stop' reports and removes it."),
+      duration_ms: z.number().int().min(1).max(60000).optional().describe("Optional. Measurement window for 'measure' in milliseconds. Defaults to 2000."),
+      top_stacks: z.number().int().min(1).max(100).optional().describe("Optional. Number of distinct call stacks to report. Defaults to 10."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result, in addition to duration_ms. Defaults to 5000ms."),
+    },
+    {
+      title: "Measure Forced Reflows",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ action, duration_ms, top_stacks, window_label, timeout_ms }) => {
+      try {
+        const params = { action, duration_ms, top_stacks, window_label: window_label || "main", timeout_ms };
+        logCommandParams("measure_reflows", params);
+
+        const result = await socketClient.sendCommand("measure_reflows", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Reflow measurement error:", error);
+        return createErrorResponse(`Failed to measure reflows: ${(error as Error).message}`);
+      }
+    }
+  );
+}
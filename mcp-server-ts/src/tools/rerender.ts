@@ -0,0 +1,43 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+// Define supported frameworks
+const FRAMEWORKS = ["auto", "react", "vue"] as const;
+
+export function registerForceRerenderTool(server: McpServer) {
+  server.tool(
+    "force_rerender",
+    "Forces a React or Vue component to re-render and reports whether it did. Targets the root component, or a component by DOM selector, display name, or Vue uid (as reported by devtools_bridge). React uses the DevTools hook's scheduleUpdate when available and counts commits; Vue uses $forceUpdate and counts renders. Counters persist between calls. Returns forced: false with a note when no framework is detected.",
+    {
+      framework: z.enum(FRAMEWORKS).optional().describe("Optional. Framework to target. Defaults to 'auto' (React first, then Vue)."),
+      selector: z.string().min(1).optional().describe("Optional. CSS selector of a DOM element; its owning component is re-rendered."),
+      component_name: z.string().min(1).optional().describe("Optional. Display name of the component to re-render (first match)."),
+      component_id: z.string().min(1).optional().describe("Optional. Vue component uid, as reported by devtools_bridge."),
+      settle_ms: z.number().int().min(0).max(10000).optional().describe("Optional. Time to let the framework flush before checking. Defaults to 100."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Force Re-render",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ framework, selector, component_name, component_id, settle_ms, window_label, timeout_ms }) => {
+      try {
+        const params = { framework, selector, component_name, component_id, settle_ms, window_label: window_label || "main", timeout_ms };
+        logCommandParams("force_rerender", params);
+
+        const result = await socketClient.sendCommand("force_rerender", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Force re-render error:", error);
+        return createErrorResponse(`Failed to force re-render: ${(error as Error).message}`);
+      }
+    }
+  );
+}
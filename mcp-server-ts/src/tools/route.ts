@@ -0,0 +1,35 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerRouteTool(server: McpServer) {
+  server.tool(
+    "get_route",
+    "Returns the current client-side route: the router detected (React Router v5/v6 or Vue Router), the matched route pattern (e.g. /users/:id), params, query, hash, and the matched route chain. Also includes the raw location and history state. Without a detected router, the route is parsed from location, including #/ hash routes. Use this for route assertions instead of string-matching the URL.",
+    {
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Current Route",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ window_label, timeout_ms }) => {
+      try {
+        const params = { window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_route", params);
+
+        const result = await socketClient.sendCommand("get_route", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Route error:", error);
+        return createErrorResponse(`Failed to get route: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,113 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+// Define scroll-behavior values
+const SCROLL_BEHAVIORS = ["auto", "smooth", "reset"] as const;
+
+// Define scroll call behaviors
+const SCROLL_CALL_BEHAVIORS = ["auto", "smooth", "instant"] as const;
+
+// Define expected scroll kinds
+const SCROLL_EXPECTATIONS = ["smooth", "instant"] as const;
+
+export function registerScrollBehaviorTools(server: McpServer) {
+  server.tool(
+    "get_scroll_behavior",
+    "Reads the effective CSS scroll-behavior ('auto' or 'smooth') of the page or a scroll container, any inline override, and whether prefers-reduced-motion is set. For the page the value comes from the root element, as browsers ignore it on body. It decides how script scrolls with behavior 'auto' and anchor links animate.",
+    {
+      selector: z.string().min(1).optional().describe("Optional. CSS selector of the scroll container. Defaults to the page."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Scroll Behavior",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ selector, window_label, timeout_ms }) => {
+      try {
+        const params = { selector, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_scroll_behavior", params);
+
+        const result = await socketClient.sendCommand("get_scroll_behavior", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Scroll behavior error:", error);
+        return createErrorResponse(`Failed to get scroll behavior: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "set_scroll_behavior",
+    "Overrides the CSS scroll-behavior of the page or a scroll container with an !important inline style, or removes the override with 'reset'. Returns the value before and after.",
+    {
+      behavior: z.enum(SCROLL_BEHAVIORS).describe("'auto', 'smooth', or 'reset' to remove the override"),
+      selector: z.string().min(1).optional().describe("Optional. CSS selector of the scroll container. Defaults to the page."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Set Scroll Behavior",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ behavior, selector, window_label, timeout_ms }) => {
+      try {
+        const params = { behavior, selector, window_label: window_label || "main", timeout_ms };
+        logCommandParams("set_scroll_behavior", params);
+
+        const result = await socketClient.sendCommand("set_scroll_behavior", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Scroll behavior error:", error);
+        return createErrorResponse(`Failed to set scroll behavior: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "set_scroll_position",
+    "Scrolls the page or a scroll container to x/y, or brings an element into view with to_selector (honouring scroll-margin and scroll-padding), then samples the position every frame until it settles. Reports whether the scroll animated or jumped, how many intermediate frames it took, and whether it landed exactly on the target. With expect 'smooth' or 'instant' the call fails when the scroll did not behave that way, which catches a CSS change that silently disabled smooth scrolling.",
+    {
+      x: z.number().min(0).optional().describe("Optional. Target horizontal scroll offset in pixels. Unchanged when omitted."),
+      y: z.number().min(0).optional().describe("Optional. Target vertical scroll offset in pixels. Unchanged when omitted."),
+      to_selector: z.string().min(1).optional().describe("Optional. CSS selector of an element to scroll to instead of x/y."),
+      selector: z.string().min(1).optional().describe("Optional. CSS selector of the scroll container. Defaults to the page."),
+      behavior: z.enum(SCROLL_CALL_BEHAVIORS).optional().describe("Optional. 'auto' lets the CSS scroll-behavior decide, 'smooth' or 'instant' force it. Defaults to 'auto'."),
+      expect: z.enum(SCROLL_EXPECTATIONS).optional().describe("Optional. Fail unless the scroll animated ('smooth') or jumped ('instant')."),
+      max_wait_ms: z.number().int().min(100).max(20000).optional().describe("Optional. Longest time to wait for the scroll to settle. Defaults to 3000."),
+      tolerance_px: z.number().min(0).optional().describe("Optional. Allowed distance from the target position. Defaults to 1."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms; max_wait_ms is added on top."),
+    },
+    {
+      title: "Set Scroll Position",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ x, y, to_selector, selector, behavior, expect, max_wait_ms, tolerance_px, window_label, timeout_ms }) => {
+      try {
+        const params = { x, y, to_selector, selector, behavior, expect, max_wait_ms, tolerance_px, window_label: window_label || "main", timeout_ms };
+        logCommandParams("set_scroll_position", params);
+
+        const result = await socketClient.sendCommand("set_scroll_position", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Scroll position error:", error);
+        return createErrorResponse(`Failed to set scroll position: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,37 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerScrollSnapTool(server: McpServer) {
+  server.tool(
+    "get_scroll_snap_state",
+    "Inspects a scroll-snap container such as a carousel. Returns its scroll-snap-type (axis and strictness), scroll padding, current scroll position, every snap point with the scroll offset it snaps to, the index and selector of the child it is currently snapped to, and whether the container is still scrolling. Use it to assert a carousel landed on the right slide.",
+    {
+      selector: z.string().min(1).describe("CSS selector of the scroll-snap container."),
+      settle_ms: z.number().int().min(0).max(5000).optional().describe("Optional. How long to watch the scroll position to decide whether it is mid-scroll. Defaults to 100ms."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Scroll Snap State",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ selector, settle_ms, window_label, timeout_ms }) => {
+      try {
+        const params = { selector, settle_ms, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_scroll_snap_state", params);
+
+        const result = await socketClient.sendCommand("get_scroll_snap_state", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Scroll snap error:", error);
+        return createErrorResponse(`Failed to get scroll snap state: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,72 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerSelectStateTools(server: McpServer) {
+  server.tool(
+    "get_select_state",
+    "Reads a dropdown. For a native <select>: value, selected index, multiple/disabled/required and every option with value, label, group and selected/disabled state. For an ARIA combobox or listbox: expanded state, displayed text or input value, active descendant and the listbox options with their aria-selected state. A selector on a wrapper resolves to the dropdown inside it. Custom comboboxes often render options only while open.",
+    {
+      selector: z.string().min(1).describe("CSS selector of the <select>, combobox, listbox or an element containing one"),
+      max_options: z.number().int().min(1).max(10000).optional().describe("Optional. Maximum number of options returned. Defaults to 200."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Select State",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ selector, max_options, window_label, timeout_ms }) => {
+      try {
+        const params = { selector, max_options, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_select_state", params);
+
+        const result = await socketClient.sendCommand("get_select_state", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Select state error:", error);
+        return createErrorResponse(`Failed to get select state: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "select_option",
+    "Chooses an option by value, label or index. A native <select> is set directly and fires input and change. An ARIA combobox is opened the way a user would (click, then Alt+ArrowDown), the option is clicked with the full pointer and mouse sequence, and the resulting state is read back to verify the pick. Give exactly one of value, label or index.",
+    {
+      selector: z.string().min(1).describe("CSS selector of the <select>, combobox, listbox or an element containing one"),
+      value: z.string().optional().describe("Optional. Option value attribute (data-value for ARIA options)."),
+      label: z.string().optional().describe("Optional. Visible option text; exact match first, then case-insensitive."),
+      index: z.number().int().min(0).optional().describe("Optional. Zero-based option index."),
+      add: z.boolean().optional().describe("Optional. For a multiple select, keep other selected options. Defaults to false."),
+      wait_ms: z.number().int().min(0).max(30000).optional().describe("Optional. How long to wait for an ARIA listbox to open or close. Defaults to 1000."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result, on top of the waits. Defaults to 5000ms."),
+    },
+    {
+      title: "Select Option",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ selector, value, label, index, add, wait_ms, window_label, timeout_ms }) => {
+      try {
+        const params = { selector, value, label, index, add, wait_ms, window_label: window_label || "main", timeout_ms };
+        logCommandParams("select_option", params);
+
+        const result = await socketClient.sendCommand("select_option", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Select option error:", error);
+        return createErrorResponse(`Failed to select option: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,73 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+// Define selection directions
+const DIRECTIONS = ["forward", "backward", "none"] as const;
+
+export function registerSelectionTools(server: McpServer) {
+  server.tool(
+    "get_selection",
+    "Returns the selection or caret in a text input, textarea or contenteditable: start/end offsets, anchor/focus, direction, the selected text and the content length. Defaults to the focused element. Fails if the target is not editable text.",
+    {
+      selector: z.string().min(1).optional().describe("Optional. CSS selector of the text field or contenteditable. Defaults to the focused element."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Text Selection",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ selector, window_label, timeout_ms }) => {
+      try {
+        const params = { selector, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_selection", params);
+
+        const result = await socketClient.sendCommand("get_selection", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Selection error:", error);
+        return createErrorResponse(`Failed to get selection: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "set_selection",
+    "Focuses a text input, textarea or contenteditable and selects a character range (or places the caret when end is omitted), then dispatches a select event. Offsets past the end are clamped. Contenteditable offsets count characters across text nodes. Returns the resulting selection.",
+    {
+      selector: z.string().min(1).describe("CSS selector of the text field or contenteditable."),
+      start: z.number().int().min(0).optional().describe("Start offset in characters. Required unless select_all is true."),
+      end: z.number().int().min(0).optional().describe("Optional. End offset in characters. Defaults to start (a collapsed caret)."),
+      direction: z.enum(DIRECTIONS).optional().describe("Optional. Selection direction. 'backward' puts the focus at start. Defaults to 'forward'."),
+      select_all: z.boolean().optional().describe("Optional. Select the whole value or content instead of an offset range."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Set Text Selection",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ selector, start, end, direction, select_all, window_label, timeout_ms }) => {
+      try {
+        const params = { selector, start, end, direction, select_all, window_label: window_label || "main", timeout_ms };
+        logCommandParams("set_selection", params);
+
+        const result = await socketClient.sendCommand("set_selection", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Selection error:", error);
+        return createErrorResponse(`Failed to set selection: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,66 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerServiceWorkerTools(server: McpServer) {
+  server.tool(
+    "get_service_workers",
+    "Lists the service worker registrations of the webview: each registration's scope and the script URL and state of its active, installing, and waiting workers, plus the worker currently controlling the page. Useful for debugging offline/PWA behavior.",
+    {
+      window_label: z.string().optional().describe("Optional. The identifier of the application window to inspect. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Service Worker Registrations",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ window_label, timeout_ms }) => {
+      try {
+        const params = { window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_service_workers", params);
+
+        const result = await socketClient.sendCommand("get_service_workers", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Service worker error:", error);
+        return createErrorResponse(`Failed to get service workers: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "unregister_service_worker",
+    "Unregisters service workers from the webview, either the registration with the given scope or, with all set to true, all of them. Useful for resetting offline state between tests.",
+    {
+      scope: z.string().min(1).optional().describe("Optional. Scope URL of the registration to remove, absolute or relative to the page. Required unless all is true."),
+      all: z.boolean().optional().describe("Optional. Remove every registration. Required when no scope is given. Defaults to false."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Unregister Service Workers",
+      readOnlyHint: false,
+      destructiveHint: true,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ scope, all, window_label, timeout_ms }) => {
+      try {
+        const params = { scope, all, window_label: window_label || "main", timeout_ms };
+        logCommandParams("unregister_service_worker", params);
+
+        const result = await socketClient.sendCommand("unregister_service_worker", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Service worker unregister error:", error);
+        return createErrorResponse(`Failed to unregister service worker: ${(error as Error).message}`);
+      }
+    }
+  );
+}
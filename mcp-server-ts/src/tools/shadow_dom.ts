@@ -0,0 +1,39 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerShadowDomTool(server: McpServer) {
+  server.tool(
+    "get_shadow_dom",
+    "Serializes the open shadow root of a web component host, which get_dom cannot reach. Nested open shadow roots are expanded inline as <template shadowrootmode=\"open\"> up to max_depth. Also reports the root's mode, delegatesFocus, adopted stylesheets and slots with their assigned content. Closed shadow roots are inaccessible to script; hosts that are defined custom elements without an open root are listed as possibly closed.",
+    {
+      selector: z.string().min(1).describe("CSS selector of the shadow host element."),
+      max_depth: z.number().int().min(0).max(50).optional().describe("Optional. Levels of nested shadow roots to expand below the host's own root. Defaults to 5."),
+      include_styles: z.boolean().optional().describe("Optional. Keep the contents of <style> elements. Defaults to true."),
+      max_chars: z.number().int().min(100).max(5000000).optional().describe("Optional. Longest serialized HTML returned; longer output is truncated. Defaults to 200000."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Shadow DOM",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ selector, max_depth, include_styles, max_chars, window_label, timeout_ms }) => {
+      try {
+        const params = { selector, max_depth, include_styles, max_chars, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_shadow_dom", params);
+
+        const result = await socketClient.sendCommand("get_shadow_dom", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Shadow DOM error:", error);
+        return createErrorResponse(`Failed to get shadow DOM: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,69 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+// Define trigger mechanisms
+const MECHANISMS = ["auto", "event", "native", "webview"] as const;
+
+export function registerShortcutTools(server: McpServer) {
+  server.tool(
+    "list_shortcuts",
+    "Lists keyboard shortcuts: the accelerators the app declared in the plugin config ('declared'; registrations with the global-shortcut plugin cannot be enumerated), and shortcuts the page advertises through accesskey and aria-keyshortcuts attributes.",
+    {
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "List Shortcuts",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ window_label, timeout_ms }) => {
+      try {
+        const params = { window_label: window_label || "main", timeout_ms };
+        logCommandParams("list_shortcuts", params);
+
+        const result = await socketClient.sendCommand("list_shortcuts", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Shortcut listing error:", error);
+        return createErrorResponse(`Failed to list shortcuts: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "trigger_shortcut",
+    "Fires a keyboard shortcut and reports which mechanism handled it. 'auto' (default) and 'webview' dispatch keydown/keyup in the webview on the focused element, reporting whether a handler called preventDefault. 'event' emits the opt-in tauri-mcp://shortcut-trigger event without calling the registered handler, so it only has an effect if the app routes that event. 'native' presses the keys at the OS level, which reaches real global shortcut registrations.",
+    {
+      accelerator: z.string().min(1).describe("The shortcut, e.g. 'CmdOrCtrl+Shift+K', 'Alt+F4', 'Escape'."),
+      mechanism: z.enum(MECHANISMS).optional().describe("Optional. How to fire the shortcut: 'auto' (default), 'event', 'native', or 'webview'."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Trigger Shortcut",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ accelerator, mechanism, window_label, timeout_ms }) => {
+      try {
+        const params = { accelerator, mechanism, window_label: window_label || "main", timeout_ms };
+        logCommandParams("trigger_shortcut", params);
+
+        const result = await socketClient.sendCommand("trigger_shortcut", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Shortcut trigger error:", error);
+        return createErrorResponse(`Failed to trigger shortcut: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -10,7 +10,7 @@ export function registerStateDumpTool(server: McpServer) {
     {
       window_label: z.string().default("main").describe("The identifier (e.g., visible title or internal label) of the application window from which to retrieve state. Defaults to 'main' if not specified."),
       max_depth: z.number().int().positive().default(10).describe("Maximum depth for recursive state traversal. Prevents infinite recursion and truncates very deep nested structures. Defaults to 10."),
-      path: z.string().optional().describe("Optional dot-notation path to a specific portion of state (e.g., 'zustand.userStore.profile'). If provided, only that portion of state is returned."),
+      path: z.string().optional().describe("Optional path to a specific portion of state (e.g., 'zustand.userStore.profile'). Supports array indices and quoted keys ('redux.items[0].id', 'pinia[\"my.store\"]') and * wildcards over object keys or array items ('redux.users.*.name', 'redux.items[*].id'). A plain path returns only that portion of state; a wildcard path returns a flat map of every resolved path to its value (up to 1000 matches)."),
       timeout_ms: z.number().int().positive().optional().describe("Maximum time in milliseconds to wait for the state dump operation to complete. Defaults to 5000ms if not specified."),
     },
     {
@@ -84,3 +84,43 @@ export function registerStateDumpTool(server: McpServer) {
     },
   );
 }
+
+// Define diff_state actions
+const DIFF_ACTIONS = ["diff", "save_baseline", "clear", "list"] as const;
+
+export function registerDiffStateTool(server: McpServer) {
+  server.tool(
+    "diff_state",
+    "Compares the application state against a baseline stored on the plugin side. First call with action 'save_baseline' to store a state dump under an id, perform the action under test, then call with action 'diff' to get a structured list of added, removed, and changed paths with their before/after values. Pinpoints exactly which store fields an interaction touched.",
+    {
+      baseline_id: z.string().optional().describe("Identifier of the baseline. Required for every action except 'list'."),
+      action: z.enum(DIFF_ACTIONS).optional().describe("Optional. 'diff' (default) compares a fresh dump with the baseline, 'save_baseline' stores a fresh dump, 'clear' removes the baseline, 'list' returns the stored baseline ids. Up to 32 baselines are kept; saving another drops the least recently used one and reports it as evicted_baseline."),
+      update_baseline: z.boolean().optional().describe("Optional. After diffing, replace the baseline with the fresh dump so the next diff is incremental. Defaults to false."),
+      max_changes: z.number().int().positive().optional().describe("Optional. Maximum number of changes to return. Defaults to 200."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      max_depth: z.number().int().positive().optional().describe("Optional. Maximum depth for state traversal, as in dump_application_state. Defaults to 10."),
+      path: z.string().optional().describe("Optional. Path to restrict the dump to, with the same syntax and wildcards as dump_application_state. Use the same path for the baseline and the diff."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the state dump. Defaults to 5000ms."),
+    },
+    {
+      title: "Diff Application State Against a Baseline",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ baseline_id, action, update_baseline, max_changes, window_label, max_depth, path, timeout_ms }) => {
+      try {
+        const params = { baseline_id, action, update_baseline, max_changes, window_label: window_label || "main", max_depth, path, timeout_ms };
+        logCommandParams("diff_state", params);
+
+        const result = await socketClient.sendCommand("diff_state", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("State diff error:", error);
+        return createErrorResponse(`Failed to diff application state: ${(error as Error).message}`);
+      }
+    },
+  );
+}
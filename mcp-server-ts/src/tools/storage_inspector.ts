@@ -7,21 +7,21 @@ import { createErrorResponse, createSuccessResponse, formatResultAsText, logComm
 const STORAGE_TYPES = ["localStorage", "sessionStorage", "indexedDB"] as const;
 
 // Define actions
-const ACTIONS = ["get_storage", "clear_storage", "list_indexeddb", "query_indexeddb"] as const;
+const ACTIONS = ["get_storage", "clear_storage", "set_item", "remove_item", "list_indexeddb", "query_indexeddb", "get_cookies", "clear_cookies"] as const;
 
 export function registerStorageInspectorTool(server: McpServer) {
   server.tool(
     "storage_inspector",
-    "Inspects and retrieves browser storage data including localStorage, sessionStorage, and IndexedDB. Supports querying, filtering, pagination, and introspection of IndexedDB databases and object stores.",
+    "Inspects and retrieves browser storage data including localStorage, sessionStorage, IndexedDB and cookies. Supports querying, filtering, pagination, and introspection of IndexedDB databases and object stores.",
     {
       action: z.enum(ACTIONS).describe(
-        "The action to perform: 'get_storage' to retrieve localStorage or sessionStorage items, 'clear_storage' to clear storage, 'list_indexeddb' to list all IndexedDB databases and stores, or 'query_indexeddb' to query specific IndexedDB data."
+        "The action to perform: 'get_storage' to retrieve localStorage or sessionStorage items, 'clear_storage' to clear storage, 'set_item' or 'remove_item' to write or delete a single localStorage or sessionStorage key, 'list_indexeddb' to list all IndexedDB databases and stores, 'query_indexeddb' to query specific IndexedDB data, 'get_cookies' to list cookies, or 'clear_cookies' to expire the cookies visible to script."
       ),
       storage_type: z.enum(STORAGE_TYPES).optional().describe(
-        "Optional. The type of storage to inspect: 'localStorage', 'sessionStorage', or 'indexedDB'. Required for 'get_storage' and 'clear_storage' actions."
+        "Optional. The type of storage to inspect: 'localStorage', 'sessionStorage', or 'indexedDB'. Required for 'get_storage', 'clear_storage', 'set_item' and 'remove_item' actions; the item actions do not support 'indexedDB'."
       ),
       key_pattern: z.string().optional().describe(
-        "Optional. Filter items by key pattern (regex or substring match). Use to focus on specific keys or search for patterns."
+        "Optional. Filter items (or cookie names) by key pattern (regex or substring match). Use to focus on specific keys or search for patterns."
       ),
       page: z.number().int().nonnegative().optional().describe(
         "Optional. Page number for pagination (0-based). Defaults to 0."
@@ -35,6 +35,12 @@ export function registerStorageInspectorTool(server: McpServer) {
       store_name: z.string().optional().describe(
         "Optional. The name of the object store within the IndexedDB database. Required for 'query_indexeddb' action."
       ),
+      key: z.string().optional().describe(
+        "Optional. The storage key to write or delete. Required for 'set_item' and 'remove_item' actions."
+      ),
+      value: z.string().optional().describe(
+        "Optional. The string value to store; serialize objects with JSON.stringify first. Required for 'set_item' action."
+      ),
       window_label: z.string().optional().describe(
         "Optional. The identifier of the application window to inspect. Defaults to 'main' if not specified."
       ),
@@ -54,6 +60,8 @@ export function registerStorageInspectorTool(server: McpServer) {
       page_size,
       db_name,
       store_name,
+      key,
+      value,
       window_label,
     }) => {
       try {
@@ -63,12 +71,20 @@ export function registerStorageInspectorTool(server: McpServer) {
         }
 
         // Validate actions that require storage_type
-        if ((action === "get_storage" || action === "clear_storage") && !storage_type) {
+        if ((action === "get_storage" || action === "clear_storage" || action === "set_item" || action === "remove_item") && !storage_type) {
           return createErrorResponse(
             `The storage_type parameter is required for the '${action}' action`
           );
         }
 
+        // Validate item actions have a key, and set_item a value
+        if ((action === "set_item" || action === "remove_item") && key === undefined) {
+          return createErrorResponse(`The key parameter is required for the '${action}' action`);
+        }
+        if (action === "set_item" && value === undefined) {
+          return createErrorResponse("The value parameter is required for the 'set_item' action");
+        }
+
         // Validate query_indexeddb requires db_name and store_name
         if (action === "query_indexeddb" && (!db_name || !store_name)) {
           return createErrorResponse(
@@ -88,6 +104,8 @@ export function registerStorageInspectorTool(server: McpServer) {
           page_size: page_size ?? 50,
           db_name: db_name || null,
           store_name: store_name || null,
+          key: key ?? null,
+          value: value ?? null,
           window_label: effectiveWindowLabel,
         };
 
@@ -210,6 +228,38 @@ export function registerStorageInspectorTool(server: McpServer) {
   );
 }
 
+export function registerGetCookiesTool(server: McpServer) {
+  server.tool(
+    "get_cookies",
+    "Lists the cookies of a window with name, value, domain, path, expiry (Unix seconds, null for session cookies), http_only, secure and same_site. Cookies come from the webview's native cookie store, merged with what document.cookie shows the page. HttpOnly cookies are listed with a null value because page script cannot read them; when the native store is unavailable only script-visible cookies are listed, as the metadata notes.",
+    {
+      key_pattern: z.string().optional().describe("Optional. Only list cookies whose name matches this pattern (case-insensitive regex, or substring if not a valid regex)."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Cookies",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ key_pattern, window_label, timeout_ms }) => {
+      try {
+        const params = { key_pattern, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_cookies", params);
+
+        const result = await socketClient.sendCommand("get_cookies", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Get cookies error:", error);
+        return createErrorResponse(`Failed to get cookies: ${(error as Error).message}`);
+      }
+    }
+  );
+}
+
 // Helper function to format bytes
 function formatBytes(bytes: number): string {
   if (bytes === 0) return "0 B";
@@ -0,0 +1,37 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerPurgeAllStorageTool(server: McpServer) {
+  server.tool(
+    "purge_all_storage",
+    "Clean-slate reset between test cases. Clears localStorage and sessionStorage, expires every cookie visible to script, deletes all IndexedDB databases, deletes all Cache Storage caches and unregisters all service workers for the window's origin. Reports success and counts per subsystem and fails if any subsystem could not be fully cleared (for example an IndexedDB database the app still holds open). HttpOnly cookies cannot be removed from script; set clear_browsing_data to also wipe the webview's whole data store natively, including those cookies and the HTTP cache. In-memory app state is untouched, so reload the page afterwards for a true fresh start.",
+    {
+      clear_browsing_data: z.boolean().optional().describe("Optional. Also clear all browsing data of the webview natively, including HttpOnly cookies and the HTTP cache. This may affect other windows sharing the same data store. Defaults to false."),
+      blocked_wait_ms: z.number().int().min(0).max(10000).optional().describe("Optional. How long to wait for an IndexedDB deletion blocked by an open connection. Defaults to 2000ms."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the purge. Defaults to 10000ms; blocked_wait_ms is added on top."),
+    },
+    {
+      title: "Purge All Storage",
+      readOnlyHint: false,
+      destructiveHint: true,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ clear_browsing_data, blocked_wait_ms, window_label, timeout_ms }) => {
+      try {
+        const params = { clear_browsing_data, blocked_wait_ms, window_label: window_label || "main", timeout_ms };
+        logCommandParams("purge_all_storage", params);
+
+        const result = await socketClient.sendCommand("purge_all_storage", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Storage purge error:", error);
+        return createErrorResponse(`Failed to purge storage: ${(error as Error).message}`);
+      }
+    }
+  );
+}
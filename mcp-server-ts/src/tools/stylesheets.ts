@@ -0,0 +1,39 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerStylesheetTool(server: McpServer) {
+  server.tool(
+    "get_stylesheet",
+    "Returns the parsed CSSOM of one stylesheet, picked by index or href: every rule in order with its type and a dotted path, style rules with their selector, the specificity of each selector in the list and their declarations (property, value, !important), and grouping rules (@media with whether it currently matches, @supports, @container, @layer, @scope, CSS nesting) with their child rules. Also covers @import (optionally with the imported sheet's rules), @font-face, @keyframes, @property and @page. Cross-origin sheets whose rules cannot be read are reported as inaccessible with the reason. Call without index or href to list the sheets. Declarations come from the CSSOM, so shorthands appear expanded into longhands.",
+    {
+      index: z.number().int().min(0).optional().describe("Optional. Position of the sheet in document.styleSheets, followed by adopted stylesheets. Omit both index and href to list the sheets."),
+      href: z.string().min(1).optional().describe("Optional. URL of the sheet. An exact match wins, otherwise the first href containing this text."),
+      max_rules: z.number().int().min(1).max(20000).optional().describe("Optional. Maximum number of rules returned, counting nested rules. Defaults to 500."),
+      include_imports: z.boolean().optional().describe("Optional. Include the rules of @import-ed sheets under their import rule. Defaults to true."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Stylesheet Rules",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ index, href, max_rules, include_imports, window_label, timeout_ms }) => {
+      try {
+        const params = { index, href, max_rules, include_imports, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_stylesheet", params);
+
+        const result = await socketClient.sendCommand("get_stylesheet", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Stylesheet error:", error);
+        return createErrorResponse(`Failed to get stylesheet: ${(error as Error).message}`);
+      }
+    }
+  );
+}
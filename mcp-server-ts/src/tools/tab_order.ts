@@ -0,0 +1,37 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerTabOrderTool(server: McpServer) {
+  server.tool(
+    "get_tab_order",
+    "Computes the keyboard Tab order of the page: every tab stop in sequence, respecting positive tabindex values, disabled and inert elements, open shadow roots and radio groups, with selectors and labels. Flags accessibility problems: positive tabindex, tab stops inside aria-hidden content or with no visible size, and an open modal that leaves elements outside it reachable. With simulate_tab, it presses Tab through the page (letting app key handlers move focus, as focus-trap libraries do) and reports a focus trap when Tab cycles through only part of the page. Focus is restored afterwards.",
+    {
+      simulate_tab: z.boolean().optional().describe("Optional. Press Tab through the page to detect cycles and traps created by key handlers. Defaults to false."),
+      max_steps: z.number().int().min(1).max(1000).optional().describe("Optional. Maximum number of Tab presses in the simulation. Defaults to 100."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Tab Order",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ simulate_tab, max_steps, window_label, timeout_ms }) => {
+      try {
+        const params = { simulate_tab, max_steps, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_tab_order", params);
+
+        const result = await socketClient.sendCommand("get_tab_order", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Tab order error:", error);
+        return createErrorResponse(`Failed to get tab order: ${(error as Error).message}`);
+      }
+    }
+  );
+}
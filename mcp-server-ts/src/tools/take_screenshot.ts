@@ -3,12 +3,24 @@ import { z } from "zod";
 import { socketClient } from "./client.js";
 import { createErrorResponse, createImageResponse, extractBase64Data, logCommandParams } from "./response-helpers.js";
 
+// Define screenshot encodings
+const SCREENSHOT_FORMATS = ["png", "jpeg", "webp"] as const;
+
 export function registerTakeScreenshotTool(server: McpServer) {
   server.tool(
     "take_screenshot",
     "Captures a still image (screenshot) of a designated application window and returns it, typically as a JPEG image. This tool is read-only and does not modify any application or system state. Useful for visual inspection or documentation.",
     {
       window_label: z.string().default("main").describe("The identifier for the window to capture. This could be the window's visible title text or a unique internal label if available. Ensure this label accurately targets the desired window. Defaults to 'main' if not specified."),
+      target_dpr: z.number().min(0.25).max(8).optional().describe("Optional. Device pixel ratio to normalize the screenshot to (e.g. 1 for baselines shared between Retina and 1x machines). The capture is rescaled from the window's actual scale factor; defaults to the native resolution."),
+      region: z.object({
+        x: z.number().int().min(0),
+        y: z.number().int().min(0),
+        width: z.number().int().positive(),
+        height: z.number().int().positive(),
+      }).optional().describe("Optional. Rectangle to crop the capture to, in logical (CSS) pixels from the top-left of the captured window. Scaled by the device pixel ratio before cropping; must fit inside the capture."),
+      format: z.enum(SCREENSHOT_FORMATS).optional().describe("Optional. Image encoding. 'jpeg' (default) is smallest, which keeps large 4K captures cheap; 'png' and 'webp' are lossless."),
+      quality: z.number().int().optional().describe("Optional. Quality for lossy encodings, clamped to 1-100. Defaults to 80. Only jpeg is lossy; png and webp are always encoded losslessly and ignore it."),
     },
     {
       title: "Capture Screenshot of a Specific Application Window",
@@ -17,7 +29,7 @@ export function registerTakeScreenshotTool(server: McpServer) {
       idempotentHint: true,
       openWorldHint: false,
     },
-    async ({ window_label }) => {
+    async ({ window_label, target_dpr, region, format, quality }) => {
       try {
         // The window_label now has a default value in the schema, so this check is redundant
         // But we'll keep it for extra safety
@@ -25,7 +37,7 @@ export function registerTakeScreenshotTool(server: McpServer) {
           window_label = "main";
         }
         
-        const params = { window_label };
+        const params = { window_label, target_dpr, region, format, quality };
         logCommandParams('take_screenshot', params);
         
         const result = await socketClient.sendCommand('take_screenshot', params);
@@ -40,7 +52,21 @@ export function registerTakeScreenshotTool(server: McpServer) {
           return createErrorResponse(`Failed to extract image data from response: ${JSON.stringify(result).substring(0, 100)}...`);
         }
         
-        return createImageResponse(base64Data, 'image/jpeg');
+        const response = createImageResponse(base64Data, `image/${format ?? 'jpeg'}`);
+
+        // Report the scaling applied so visual diffs can tell normalized captures apart
+        const dpr = result as { sourceDpr?: number | null; targetDpr?: number | null; dprNormalized?: boolean };
+        if (target_dpr !== undefined && dpr && typeof dpr === 'object') {
+          const note = dpr.dprNormalized
+            ? `Normalized from DPR ${dpr.sourceDpr ?? 'unknown'} to ${dpr.targetDpr}`
+            : `Captured at DPR ${dpr.sourceDpr ?? 'unknown'}; target_dpr is not applied on this platform`;
+          return {
+            ...response,
+            content: [...response.content, { type: "text" as const, text: note }],
+          };
+        }
+
+        return response;
       } catch (error) {
         console.error('Screenshot error:', error);
         return createErrorResponse(`Failed to take screenshot: ${(error as Error).message}`);
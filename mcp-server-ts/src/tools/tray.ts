@@ -0,0 +1,39 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+// Define actions
+const ACTIONS = ["get_state", "activate_menu_item"] as const;
+
+export function registerTrayTool(server: McpServer) {
+  server.tool(
+    "tray",
+    "Inspects the system tray icon and simulates tray menu item activation. 'get_state' reports whether the tray exists, its on-screen rect and, if the app registered the tray menu, its items. 'activate_menu_item' checks the item exists in the registered menu and emits the opt-in 'tauri-mcp://tray-menu-item-activate' event with the item id. It does not fire a real menu event, so on_menu_event handlers only react if the app routes that event to them. Requires the plugin's 'tray' feature; reports an error when no tray is configured.",
+    {
+      action: z.enum(ACTIONS).describe("The action to perform: 'get_state' to inspect the tray, or 'activate_menu_item' to simulate clicking a tray menu item."),
+      tray_id: z.string().optional().describe("Optional. The id of the tray icon. Defaults to 'main', the id Tauri gives a tray defined in the app config."),
+      item_id: z.string().optional().describe("The id of the menu item to activate. Required for 'activate_menu_item'."),
+    },
+    {
+      title: "Inspect and Drive the System Tray",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ action, tray_id, item_id }) => {
+      try {
+        const params = { action, tray_id, item_id };
+        logCommandParams("tray", params);
+
+        const result = await socketClient.sendCommand("tray", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Tray error:", error);
+        return createErrorResponse(`Failed to run tray action: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,98 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+// Define user script kinds
+const KINDS = ["css", "js"] as const;
+
+export function registerUserScriptTools(server: McpServer) {
+  server.tool(
+    "register_user_script",
+    "Registers a CSS or JS snippet that is re-injected on every page load, including reloads and hot_reload, until it is unregistered. CSS is added as a <style> element; JS is evaluated once per page. By default it applies to one window and is also injected into the current page right away. Returns a handle id for unregister_user_script. Use it to persistently override styles or add debug instrumentation.",
+    {
+      kind: z.enum(KINDS).describe("'css' for a stylesheet or 'js' for a script."),
+      source: z.string().min(1).describe("The CSS or JavaScript source. At most 1 MB."),
+      all_windows: z.boolean().optional().describe("Optional. Apply to every window, including ones opened later, instead of only window_label. Defaults to false."),
+      inject_now: z.boolean().optional().describe("Optional. Also apply to the currently loaded page. Defaults to true."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+    },
+    {
+      title: "Register Persistent User Script",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+    async ({ kind, source, all_windows, inject_now, window_label }) => {
+      try {
+        const params = { kind, source, all_windows, inject_now, window_label: window_label || "main" };
+        logCommandParams("register_user_script", { ...params, source: `${source.length} chars` });
+
+        const result = await socketClient.sendCommand("register_user_script", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("User script error:", error);
+        return createErrorResponse(`Failed to register user script: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "unregister_user_script",
+    "Stops re-injecting a user script registered with register_user_script, or all of them with all: true. Injected CSS is also removed from open pages. JS that already ran cannot be undone, so its effects remain until the next reload.",
+    {
+      id: z.string().min(1).optional().describe("The handle id returned by register_user_script. Required unless all is true."),
+      all: z.boolean().optional().describe("Optional. Remove every registered user script. Defaults to false."),
+    },
+    {
+      title: "Unregister User Script",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ id, all }) => {
+      try {
+        const params = { id, all };
+        logCommandParams("unregister_user_script", params);
+
+        const result = await socketClient.sendCommand("unregister_user_script", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("User script error:", error);
+        return createErrorResponse(`Failed to unregister user script: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "list_user_scripts",
+    "Lists the user scripts registered with register_user_script, with their ids, kinds, target windows and sources.",
+    {
+      window_label: z.string().optional().describe("Optional. Only list scripts that apply to this window. Lists all scripts when omitted."),
+    },
+    {
+      title: "List User Scripts",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ window_label }) => {
+      try {
+        const params = { window_label };
+        logCommandParams("list_user_scripts", params);
+
+        const result = await socketClient.sendCommand("list_user_scripts", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("User script error:", error);
+        return createErrorResponse(`Failed to list user scripts: ${(error as Error).message}`);
+      }
+    }
+  );
+}
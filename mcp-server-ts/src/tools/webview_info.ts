@@ -0,0 +1,35 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerWebviewInfoTool(server: McpServer) {
+  server.tool(
+    "get_webview_info",
+    "Reports the webview engine a window runs on (WebView2, WKWebView, or WebKitGTK) with its native version, the user agent, Tauri version, OS, and feature support flags (performance.memory, PerformanceObserver entry types such as longtask and layout-shift, navigator.clipboard, service workers, WebGL/WebGPU, modern CSS features). Include this in bug reports to capture the exact engine in use.",
+    {
+      window_label: z.string().optional().describe("Optional. The identifier of the application window to inspect. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Webview Engine Info",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ window_label, timeout_ms }) => {
+      try {
+        const params = { window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_webview_info", params);
+
+        const result = await socketClient.sendCommand("get_webview_info", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Webview info error:", error);
+        return createErrorResponse(`Failed to get webview info: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,75 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerWindowLayoutTools(server: McpServer) {
+  server.tool(
+    "export_window_layout",
+    "Captures the layout of every application window: label, outer position and inner size in physical pixels, and maximized/minimized/fullscreen/visible/focused state. Title, scale factor and monitor are included for reference. Pass the returned object to import_window_layout to restore it later.",
+    {},
+    {
+      title: "Export Window Layout",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async () => {
+      try {
+        const params = {};
+        logCommandParams("export_window_layout", params);
+
+        const result = await socketClient.sendCommand("export_window_layout", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Window layout export error:", error);
+        return createErrorResponse(`Failed to export window layout: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "import_window_layout",
+    "Restores a layout captured by export_window_layout using manage_window operations. Each window leaves fullscreen/maximized/minimized, is moved and resized, then has its saved state reapplied. The window that had focus is focused last. Windows whose labels no longer exist are skipped. Reports the steps and their outcome per window.",
+    {
+      layout: z.object({
+        windows: z.array(z.object({
+          label: z.string(),
+          x: z.number().int(),
+          y: z.number().int(),
+          width: z.number().int().positive(),
+          height: z.number().int().positive(),
+          maximized: z.boolean().optional(),
+          minimized: z.boolean().optional(),
+          fullscreen: z.boolean().optional(),
+          visible: z.boolean().optional(),
+          focused: z.boolean().optional(),
+        }).passthrough()),
+      }).passthrough().describe("The layout object returned by export_window_layout."),
+      labels: z.array(z.string()).optional().describe("Optional. Only restore these window labels."),
+      restore_state: z.boolean().optional().describe("Optional. Also restore maximized/minimized/fullscreen/visibility and focus. Defaults to true."),
+    },
+    {
+      title: "Import Window Layout",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ layout, labels, restore_state }) => {
+      try {
+        const params = { layout, labels, restore_state };
+        logCommandParams("import_window_layout", params);
+
+        const result = await socketClient.sendCommand("import_window_layout", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Window layout import error:", error);
+        return createErrorResponse(`Failed to import window layout: ${(error as Error).message}`);
+      }
+    }
+  );
+}
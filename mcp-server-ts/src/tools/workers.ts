@@ -0,0 +1,38 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerWorkersTool(server: McpServer) {
+  server.tool(
+    "get_workers",
+    "Reports the dedicated Workers and SharedWorkers the page creates: script URL, name, type, state (running, connected or terminated), message counts in each direction, and errors. Optionally includes the last messages each way. The first call installs tracking by wrapping the Worker and SharedWorker constructors, so only workers created after it are seen. Call once early, exercise the app, then call again.",
+    {
+      include_messages: z.boolean().optional().describe("Optional. Include previews of the most recent messages in each direction. Defaults to false."),
+      message_limit: z.number().int().min(1).max(20).optional().describe("Optional. Messages per direction when include_messages is set. Defaults to 5."),
+      include_terminated: z.boolean().optional().describe("Optional. Include workers that were terminated. Defaults to true."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Web Workers",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ include_messages, message_limit, include_terminated, window_label, timeout_ms }) => {
+      try {
+        const params = { include_messages, message_limit, include_terminated, window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_workers", params);
+
+        const result = await socketClient.sendCommand("get_workers", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Worker inspection error:", error);
+        return createErrorResponse(`Failed to get workers: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -0,0 +1,66 @@
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { z } from "zod";
+import { socketClient } from "./client.js";
+import { createErrorResponse, createSuccessResponse, formatResultAsText, logCommandParams } from "./response-helpers.js";
+
+export function registerZoomTools(server: McpServer) {
+  server.tool(
+    "get_zoom",
+    "Reads the effective zoom factor of a window. Reports the native webview zoom last applied via set_zoom, any CSS zoom fallback applied to the document, the device pixel ratio, the viewport size, and whether native zoom is supported on this platform.",
+    {
+      window_label: z.string().optional().describe("Optional. The identifier of the application window to inspect. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the result. Defaults to 5000ms."),
+    },
+    {
+      title: "Get Window Zoom Level",
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ window_label, timeout_ms }) => {
+      try {
+        const params = { window_label: window_label || "main", timeout_ms };
+        logCommandParams("get_zoom", params);
+
+        const result = await socketClient.sendCommand("get_zoom", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Get zoom error:", error);
+        return createErrorResponse(`Failed to get zoom level: ${(error as Error).message}`);
+      }
+    }
+  );
+
+  server.tool(
+    "set_zoom",
+    "Sets the zoom factor of a window (1.0 = 100%, 1.5 = 150%, 2.0 = 200%). Uses the native webview zoom where available and falls back to CSS zoom on the document otherwise. Returns the effective factor and which method was used. Useful for verifying the UI holds up at accessibility zoom levels.",
+    {
+      factor: z.number().min(0.1).max(10).describe("The zoom factor to apply, e.g. 1.5 for 150%."),
+      force_css: z.boolean().optional().describe("Optional. Apply CSS zoom to the document instead of the native webview zoom. Defaults to false."),
+      window_label: z.string().optional().describe("Optional. The identifier of the application window to zoom. Defaults to 'main' if not specified."),
+      timeout_ms: z.number().int().positive().optional().describe("Optional. Maximum time in milliseconds to wait for the CSS fallback to apply. Defaults to 5000ms."),
+    },
+    {
+      title: "Set Window Zoom Level",
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+    async ({ factor, force_css, window_label, timeout_ms }) => {
+      try {
+        const params = { factor, force_css, window_label: window_label || "main", timeout_ms };
+        logCommandParams("set_zoom", params);
+
+        const result = await socketClient.sendCommand("set_zoom", params);
+
+        return createSuccessResponse(formatResultAsText(result));
+      } catch (error) {
+        console.error("Set zoom error:", error);
+        return createErrorResponse(`Failed to set zoom level: ${(error as Error).message}`);
+      }
+    }
+  );
+}
@@ -6,10 +6,12 @@ use crate::shared::{
     WindowManagerParams, WindowManagerResult,
 };
 use crate::socket_server::SocketServer;
+use crate::tools::init_timing::{time_fallible_phase, InitTiming, SharedInitTiming};
 use crate::tools::mouse_movement;
-use crate::{PluginConfig, Result};
+use crate::{AutoInjectConfig, PluginConfig, Result};
 use enigo::{Enigo, Keyboard, Settings};
 use serde::de::DeserializeOwned;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
@@ -47,27 +49,75 @@ pub fn init<R: Runtime, C: DeserializeOwned>(
     app: &AppHandle<R>,
     _api: PluginApi<R, C>,
     config: &PluginConfig,
+    init_timing: SharedInitTiming,
 ) -> crate::Result<TauriMcp<R>> {
     let socket_server = if config.start_socket_server {
         let mut server = SocketServer::new(app.clone(), config.socket_type.clone());
-        server.start()?;
+        time_fallible_phase(&init_timing, "socket_bind", || server.start())?;
         Some(Arc::new(Mutex::new(server)))
     } else {
         None
     };
 
-    Ok(TauriMcp {
+    let state_started = Instant::now();
+    let tauri_mcp = TauriMcp {
         app: app.clone(),
+        #[cfg(all(desktop, feature = "tray"))]
+        tray_menus: Mutex::new(HashMap::new()),
         socket_server,
         application_name: config.application_name.clone(),
-    })
+        auto_inject: config.auto_inject.clone(),
+        js_file_roots: config.js_file_roots.clone(),
+        shortcuts: config.shortcuts.clone(),
+        baseline_dir: config.baseline_dir.clone(),
+        network_poll_cursors: Mutex::new(HashMap::new()),
+        zoom_levels: Mutex::new(HashMap::new()),
+        state_baselines: Mutex::new(Vec::new()),
+        style_snapshots: Mutex::new(HashMap::new()),
+        exception_streams: Mutex::new(HashMap::new()),
+        mutation_journal: Mutex::new(Default::default()),
+        user_scripts: Mutex::new(Default::default()),
+        init_timing: init_timing.clone(),
+    };
+    if let Ok(mut timing) = init_timing.lock() {
+        timing.record("state_setup", None, state_started, None);
+    }
+
+    Ok(tauri_mcp)
 }
 
 /// Access to the tauri-mcp APIs.
 pub struct TauriMcp<R: Runtime> {
     app: AppHandle<R>,
+    /// Tray menus registered by the app, keyed by tray id, since Tauri cannot read them back
+    #[cfg(all(desktop, feature = "tray"))]
+    tray_menus: Mutex<HashMap<String, tauri::menu::Menu<R>>>,
     socket_server: Option<Arc<Mutex<SocketServer<R>>>>,
     application_name: String,
+    /// Capture scripts injected on page load, from the plugin config
+    auto_inject: AutoInjectConfig,
+    /// Directories `execute_js_file` may read from, from the plugin config
+    js_file_roots: Vec<std::path::PathBuf>,
+    /// Global shortcuts declared by the app, from the plugin config
+    shortcuts: Vec<String>,
+    /// Screenshot baseline directory, from the plugin config
+    baseline_dir: Option<std::path::PathBuf>,
+    /// Per-window position of network `since_last_poll` queries
+    network_poll_cursors: Mutex<HashMap<String, crate::tools::network_inspector::NetworkPollCursor>>,
+    /// Zoom factor last applied natively to each window
+    zoom_levels: Mutex<HashMap<String, f64>>,
+    /// State dumps stored as baselines for `diff_state` by id, least recently used first
+    state_baselines: Mutex<Vec<(String, serde_json::Value)>>,
+    /// Computed style snapshots for `diff_computed_style`, keyed by id
+    style_snapshots: Mutex<HashMap<String, serde_json::Value>>,
+    /// Active streams forwarding each window's recorded exceptions to socket clients
+    exception_streams: Mutex<HashMap<String, crate::tools::error_tracker::ExceptionStream>>,
+    /// Mutating commands recorded for `get_mutation_journal`
+    mutation_journal: Mutex<crate::tools::journal::MutationJournal>,
+    /// CSS/JS snippets re-injected on page load by `register_user_script`
+    user_scripts: Mutex<crate::tools::user_scripts::UserScriptRegistry>,
+    /// Startup phases recorded for `get_init_timing`
+    init_timing: SharedInitTiming,
 }
 
 impl<R: Runtime> TauriMcp<R> {
@@ -77,6 +127,21 @@ impl<R: Runtime> TauriMcp<R> {
         })
     }
 
+    /// Register the menu of a tray icon so the `tray` command can list its items and
+    /// check the ids it is asked to activate. Call it with the menu passed to the tray builder.
+    #[cfg(all(desktop, feature = "tray"))]
+    pub fn register_tray_menu(&self, tray_id: impl Into<String>, menu: tauri::menu::Menu<R>) {
+        if let Ok(mut menus) = self.tray_menus.lock() {
+            menus.insert(tray_id.into(), menu);
+        }
+    }
+
+    /// Menu registered for a tray icon, if any
+    #[cfg(all(desktop, feature = "tray"))]
+    pub fn tray_menu(&self, tray_id: &str) -> Option<tauri::menu::Menu<R>> {
+        self.tray_menus.lock().ok().and_then(|menus| menus.get(tray_id).cloned())
+    }
+
     // Take screenshot - this feature depends on Tauri's window capabilities
     pub async fn take_screenshot_async(
         &self,
@@ -92,10 +157,15 @@ impl<R: Runtime> TauriMcp<R> {
         // Create shared parameters struct from the request
         let params = ScreenshotParams {
             window_label: Some(window_label),
-            quality: None,
+            quality: payload.quality,
             max_width: None,
             max_size_mb: None,
             application_name: Some(self.application_name.clone()),
+            target_dpr: payload.target_dpr,
+            source_dpr: window.scale_factor().ok(),
+            lossless: payload.lossless,
+            region: payload.region,
+            format: payload.format,
         };
 
         // Create a context with the window for platform implementation
@@ -272,6 +342,201 @@ impl<R: Runtime> TauriMcp<R> {
         })
     }
 
+    /// Capture auto-injection configured at plugin init
+    pub fn auto_inject_config(&self) -> &AutoInjectConfig {
+        &self.auto_inject
+    }
+
+    /// Directories `execute_js_file` is allowed to read scripts from
+    pub fn js_file_roots(&self) -> &[std::path::PathBuf] {
+        &self.js_file_roots
+    }
+
+    /// Global shortcut accelerators the app declared at plugin init
+    pub fn shortcuts(&self) -> &[String] {
+        &self.shortcuts
+    }
+
+    /// Screenshot baseline directory from the plugin config, if one was set
+    pub fn baseline_dir(&self) -> Option<&std::path::Path> {
+        self.baseline_dir.as_deref()
+    }
+
+    /// Where the next network `since_last_poll` query for a window continues, if any
+    pub fn network_poll_cursor(&self, window_label: &str) -> Option<crate::tools::network_inspector::NetworkPollCursor> {
+        self.network_poll_cursors
+            .lock()
+            .ok()
+            .and_then(|cursors| cursors.get(window_label).cloned())
+    }
+
+    /// Record where the next network `since_last_poll` query for a window continues
+    pub fn set_network_poll_cursor(&self, window_label: &str, cursor: crate::tools::network_inspector::NetworkPollCursor) {
+        if let Ok(mut cursors) = self.network_poll_cursors.lock() {
+            cursors.insert(window_label.to_string(), cursor);
+        }
+    }
+
+    /// Zoom factor last applied to a window through `set_zoom`, if any
+    pub fn zoom_level(&self, window_label: &str) -> Option<f64> {
+        self.zoom_levels
+            .lock()
+            .ok()
+            .and_then(|levels| levels.get(window_label).copied())
+    }
+
+    /// Record the zoom factor applied to a window
+    pub fn set_zoom_level(&self, window_label: &str, factor: f64) {
+        if let Ok(mut levels) = self.zoom_levels.lock() {
+            levels.insert(window_label.to_string(), factor);
+        }
+    }
+
+    /// State dump stored as a baseline under `id`, if any, marking it as recently used
+    pub fn state_baseline(&self, id: &str) -> Option<serde_json::Value> {
+        let mut baselines = self.state_baselines.lock().ok()?;
+        let index = baselines.iter().position(|(key, _)| key == id)?;
+        let entry = baselines.remove(index);
+        let state = entry.1.clone();
+        baselines.push(entry);
+        Some(state)
+    }
+
+    /// Store a state dump as the baseline for `id`, replacing any previous one. Past
+    /// `MAX_STATE_BASELINES` the least recently used baseline is dropped and its id returned.
+    pub fn set_state_baseline(&self, id: &str, state: serde_json::Value) -> Option<String> {
+        let mut baselines = self.state_baselines.lock().ok()?;
+        baselines.retain(|(key, _)| key != id);
+        baselines.push((id.to_string(), state));
+        if baselines.len() > crate::tools::state_dump::MAX_STATE_BASELINES {
+            Some(baselines.remove(0).0)
+        } else {
+            None
+        }
+    }
+
+    /// Drop the baseline stored under `id`, returning whether one existed
+    pub fn remove_state_baseline(&self, id: &str) -> bool {
+        self.state_baselines
+            .lock()
+            .map(|mut baselines| {
+                let before = baselines.len();
+                baselines.retain(|(key, _)| key != id);
+                baselines.len() != before
+            })
+            .unwrap_or(false)
+    }
+
+    /// Ids of all stored state baselines
+    pub fn state_baseline_ids(&self) -> Vec<String> {
+        let mut ids: Vec<String> = self
+            .state_baselines
+            .lock()
+            .map(|baselines| baselines.iter().map(|(key, _)| key.clone()).collect())
+            .unwrap_or_default();
+        ids.sort();
+        ids
+    }
+
+    /// Computed style snapshot stored under an id, if any
+    pub fn style_snapshot(&self, id: &str) -> Option<serde_json::Value> {
+        self.style_snapshots
+            .lock()
+            .ok()
+            .and_then(|snapshots| snapshots.get(id).cloned())
+    }
+
+    /// Store a computed style snapshot, replacing any with the same id
+    pub fn set_style_snapshot(&self, id: &str, snapshot: serde_json::Value) {
+        if let Ok(mut snapshots) = self.style_snapshots.lock() {
+            snapshots.insert(id.to_string(), snapshot);
+        }
+    }
+
+    /// Drop a computed style snapshot, returning whether it existed
+    pub fn remove_style_snapshot(&self, id: &str) -> bool {
+        self.style_snapshots
+            .lock()
+            .map(|mut snapshots| snapshots.remove(id).is_some())
+            .unwrap_or(false)
+    }
+
+    /// Push an event to every connected socket client, returning how many received it
+    pub fn push_event(&self, event: &str, data: serde_json::Value) -> usize {
+        self.socket_server
+            .as_ref()
+            .and_then(|server| server.lock().ok().map(|server| server.push_event(event, data)))
+            .unwrap_or(0)
+    }
+
+    /// Register a window's exception stream, returning the one it replaces
+    pub fn set_exception_stream(
+        &self,
+        window_label: &str,
+        stream: crate::tools::error_tracker::ExceptionStream,
+    ) -> Option<crate::tools::error_tracker::ExceptionStream> {
+        self.exception_streams
+            .lock()
+            .ok()
+            .and_then(|mut streams| streams.insert(window_label.to_string(), stream))
+    }
+
+    /// Attach the listener of a registered exception stream; false if the stream is gone
+    pub fn attach_exception_listener(&self, window_label: &str, listener: tauri::EventId) -> bool {
+        self.exception_streams
+            .lock()
+            .ok()
+            .and_then(|mut streams| streams.get_mut(window_label).map(|stream| stream.listener = Some(listener)))
+            .is_some()
+    }
+
+    /// Whether a window's exception stream should push an exception with this fingerprint
+    pub fn admit_streamed_exception(&self, window_label: &str, fingerprint: String, first_occurrence_ms: u64) -> bool {
+        self.exception_streams
+            .lock()
+            .ok()
+            .and_then(|mut streams| {
+                streams
+                    .get_mut(window_label)
+                    .map(|stream| stream.admit(fingerprint, first_occurrence_ms))
+            })
+            .unwrap_or(false)
+    }
+
+    /// Forget a window's exception stream, returning it
+    pub fn remove_exception_stream(&self, window_label: &str) -> Option<crate::tools::error_tracker::ExceptionStream> {
+        self.exception_streams
+            .lock()
+            .ok()
+            .and_then(|mut streams| streams.remove(window_label))
+    }
+
+    /// Windows with an active exception stream
+    pub fn exception_stream_windows(&self) -> Vec<String> {
+        let mut windows: Vec<String> = self
+            .exception_streams
+            .lock()
+            .map(|streams| streams.keys().cloned().collect())
+            .unwrap_or_default();
+        windows.sort();
+        windows
+    }
+
+    /// Run a closure against the mutation journal, or `None` if its lock is poisoned
+    pub fn with_mutation_journal<T>(&self, f: impl FnOnce(&mut crate::tools::journal::MutationJournal) -> T) -> Option<T> {
+        self.mutation_journal.lock().ok().map(|mut journal| f(&mut journal))
+    }
+
+    /// Run a closure against the user script registry, or `None` if its lock is poisoned
+    pub fn with_user_scripts<T>(&self, f: impl FnOnce(&mut crate::tools::user_scripts::UserScriptRegistry) -> T) -> Option<T> {
+        self.user_scripts.lock().ok().map(|mut registry| f(&mut registry))
+    }
+
+    /// Snapshot of the recorded startup phases, or `None` if its lock is poisoned
+    pub fn init_timing(&self) -> Option<InitTiming> {
+        self.init_timing.lock().ok().map(|timing| timing.clone())
+    }
+
     // Mouse movement simulation
     pub async fn simulate_mouse_movement_async(
         &self,
@@ -300,15 +565,27 @@ impl<R: Runtime> McpInterface for TauriMcp<R> {
         // Create a ScreenshotRequest from our interface params
         let window_label = params.window_label.unwrap_or_else(|| "main".to_string());
 
-        let request = ScreenshotRequest { window_label };
+        let request = ScreenshotRequest {
+            window_label,
+            target_dpr: params.target_dpr,
+            lossless: params.lossless,
+            region: params.region,
+            format: params.format,
+            quality: params.quality,
+        };
         match futures::executor::block_on(self.take_screenshot_async(request)) {
             Ok(response) => {
                 // Convert to the shared result type
                 Ok(SharedScreenshotResult {
                     success: response.success,
                     error: response.error,
+                    mime_type: response
+                        .data
+                        .as_deref()
+                        .and_then(|data| data.strip_prefix("data:"))
+                        .and_then(|data| data.split(';').next())
+                        .map(str::to_string),
                     data: response.data,
-                    mime_type: Some("image/jpeg".to_string()),
                 })
             }
             Err(err) => {
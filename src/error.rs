@@ -27,6 +27,13 @@ pub enum Error {
         received: String,
     },
 
+    /// Payload failed validation, with every problem found rather than just the first
+    #[error("Invalid payload for {command}: {}", format_issues(.issues))]
+    ValidationFailed {
+        command: String,
+        issues: Vec<ValidationIssue>,
+    },
+
     /// Operation timed out with duration and operation name
     #[error("Operation timed out: {operation} (exceeded {duration_ms}ms)")]
     TimeoutError {
@@ -63,6 +70,22 @@ pub enum Error {
     TauriError { message: String },
 }
 
+/// A single problem found while validating a command payload
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ValidationIssue {
+    pub param: String,
+    pub expected: String,
+    pub received: String,
+}
+
+fn format_issues(issues: &[ValidationIssue]) -> String {
+    issues
+        .iter()
+        .map(|issue| format!("'{}' expected {}, got {}", issue.param, issue.expected, issue.received))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
 impl Error {
     /// Create a WindowNotFound error with label
     pub fn window_not_found(label: impl Into<String>) -> Self {
@@ -109,6 +132,14 @@ impl Error {
         }
     }
 
+    /// Create a ValidationFailed error from the collected issues
+    pub fn validation_failed(command: impl Into<String>, issues: Vec<ValidationIssue>) -> Self {
+        Self::ValidationFailed {
+            command: command.into(),
+            issues,
+        }
+    }
+
     /// Create a TimeoutError
     pub fn timeout_error(operation: impl Into<String>, duration_ms: u64) -> Self {
         Self::TimeoutError {
@@ -1,6 +1,7 @@
 use tauri::{
     Manager, Runtime,
     plugin::{Builder, TauriPlugin},
+    webview::PageLoadEvent,
 };
 use log::info;
 
@@ -64,6 +65,40 @@ impl Default for SocketType {
     }
 }
 
+/// Capture script that can be injected automatically when a page loads.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaptureScript {
+    /// Console output capture (`inject_console_capture`)
+    Console,
+    /// fetch/XHR capture (`inject_network_capture`)
+    Network,
+    /// Uncaught error and unhandled rejection tracking (`inject_error_tracker`)
+    Errors,
+}
+
+impl CaptureScript {
+    /// Every capture script, in injection order.
+    pub const ALL: [CaptureScript; 3] = [CaptureScript::Console, CaptureScript::Network, CaptureScript::Errors];
+}
+
+/// Which capture scripts to inject into which windows on page load.
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct AutoInjectConfig {
+    /// Capture scripts to activate. Nothing is injected when empty.
+    pub captures: Vec<CaptureScript>,
+    /// Labels of the windows to inject into. All windows when empty.
+    pub windows: Vec<String>,
+}
+
+impl AutoInjectConfig {
+    /// Whether anything should be injected into the window with the given label.
+    pub fn applies_to(&self, window_label: &str) -> bool {
+        !self.captures.is_empty()
+            && (self.windows.is_empty() || self.windows.iter().any(|w| w == window_label))
+    }
+}
+
 /// Plugin configuration options.
 #[derive(Default)]
 pub struct PluginConfig {
@@ -73,6 +108,24 @@ pub struct PluginConfig {
     pub socket_type: SocketType,
     /// Whether to start the socket server automatically. Default is true.
     pub start_socket_server: bool,
+    /// Capture scripts injected automatically on page load. Default is none.
+    pub auto_inject: AutoInjectConfig,
+    /// Directories `execute_js_file` may read scripts from. Default is none,
+    /// which disables the command.
+    pub js_file_roots: Vec<std::path::PathBuf>,
+    /// Global shortcut accelerators the app registers, for `list_shortcuts` and
+    /// `trigger_shortcut`. Default is none.
+    pub shortcuts: Vec<String>,
+    /// Directory `save_baseline` and `compare_baseline` store screenshots in, and
+    /// `capture_fixture` its bundles.
+    /// Default is `mcp-baselines` under the app data directory.
+    pub baseline_dir: Option<std::path::PathBuf>,
+    /// Whether to track Intersection/Resize observers from page start for
+    /// `get_observers_for`. Default is false.
+    pub track_observers: bool,
+    /// Ring buffer size for the always-on interaction recorder read by
+    /// `get_interaction_trace`. Default is none, which leaves it off.
+    pub record_interactions: Option<usize>,
 }
 
 impl PluginConfig {
@@ -82,6 +135,12 @@ impl PluginConfig {
             application_name,
             socket_type: SocketType::default(),
             start_socket_server: true,
+            auto_inject: AutoInjectConfig::default(),
+            js_file_roots: Vec::new(),
+            shortcuts: Vec::new(),
+            baseline_dir: None,
+            track_observers: false,
+            record_interactions: None,
         }
     }
 
@@ -102,6 +161,55 @@ impl PluginConfig {
         self.start_socket_server = start;
         self
     }
+
+    /// Inject the given capture scripts into every window as soon as its page loads,
+    /// so early console output, requests and errors are not missed.
+    pub fn auto_inject(mut self, captures: Vec<CaptureScript>) -> Self {
+        self.auto_inject.captures = captures;
+        self
+    }
+
+    /// Restrict auto-injection to the windows with the given labels.
+    pub fn auto_inject_windows(mut self, windows: Vec<String>) -> Self {
+        self.auto_inject.windows = windows;
+        self
+    }
+
+    /// Allow `execute_js_file` to read scripts from the given directories.
+    pub fn js_file_roots(mut self, roots: Vec<std::path::PathBuf>) -> Self {
+        self.js_file_roots = roots;
+        self
+    }
+
+    /// Declare the global shortcuts the app registers (e.g. `"CmdOrCtrl+Shift+K"`).
+    /// The global-shortcut plugin cannot enumerate registrations, so this is how
+    /// `list_shortcuts` learns about them and `trigger_shortcut` knows to route them
+    /// to the app instead of the webview.
+    pub fn shortcuts(mut self, accelerators: Vec<String>) -> Self {
+        self.shortcuts = accelerators;
+        self
+    }
+
+    /// Store screenshot baselines in the given directory, e.g. one checked into the repo.
+    pub fn baseline_dir(mut self, dir: std::path::PathBuf) -> Self {
+        self.baseline_dir = Some(dir);
+        self
+    }
+
+    /// Wrap `IntersectionObserver` and `ResizeObserver` before any page script runs,
+    /// so `get_observers_for` also sees observers the app creates at startup.
+    pub fn track_observers(mut self, track: bool) -> Self {
+        self.track_observers = track;
+        self
+    }
+
+    /// Keep the last `capacity` user clicks, edits, key presses and navigations in
+    /// every page from the moment it loads, so `get_interaction_trace` can show what
+    /// led up to a problem.
+    pub fn record_interactions(mut self, capacity: usize) -> Self {
+        self.record_interactions = Some(capacity);
+        self
+    }
 }
 
 /// Initializes the plugin.
@@ -111,6 +219,8 @@ pub fn init<R: Runtime>() -> TauriPlugin<R> {
 
 /// Initializes the plugin with the given configuration.
 pub fn init_with_config<R: Runtime>(config: PluginConfig) -> TauriPlugin<R> {
+    let init_timing = tools::init_timing::InitTiming::new_shared();
+
     // Log socket configuration
     match &config.socket_type {
         SocketType::Ipc { path } => {
@@ -141,17 +251,65 @@ pub fn init_with_config<R: Runtime>(config: PluginConfig) -> TauriPlugin<R> {
         info!("[TAURI_MCP] Socket server auto-start is disabled");
     }
 
-    Builder::new("tauri-mcp")
+    if config.auto_inject.captures.is_empty() {
+        info!("[TAURI_MCP] Capture auto-injection is disabled");
+    } else {
+        info!(
+            "[TAURI_MCP] Capture scripts {:?} will be injected on page load",
+            config.auto_inject.captures
+        );
+    }
+
+    let auto_inject = config.auto_inject.clone();
+
+    let builder = tools::init_timing::time_phase(&init_timing, "init_scripts", None, || {
+        let mut builder = Builder::new("tauri-mcp");
+        if config.track_observers {
+            info!("[TAURI_MCP] Intersection/Resize observers will be tracked from page start");
+            builder = builder.js_init_script(tools::observers::OBSERVER_TRACKER_JS.to_string());
+        }
+        if let Some(capacity) = config.record_interactions {
+            info!("[TAURI_MCP] Recording the last {} user interactions per page", capacity);
+            builder = builder.js_init_script(tools::interaction_trace::recorder_script(capacity));
+        }
+        builder
+    });
+    let page_load_timing = init_timing.clone();
+
+    builder
         .invoke_handler(tauri::generate_handler![
         // Server Commands
         ])
+        .on_page_load(move |webview, payload| {
+            if payload.event() != PageLoadEvent::Finished {
+                return;
+            }
+            // Only a window's first load is part of startup
+            let label = webview.label().to_string();
+            let first_load = page_load_timing
+                .lock()
+                .map(|mut timing| timing.first_page_load(&label))
+                .unwrap_or(false);
+            let timed_label = first_load.then_some(label.as_str());
+            let run = |name: &str, work: &dyn Fn()| match timed_label {
+                Some(label) => tools::init_timing::time_phase(&page_load_timing, name, Some(label), work),
+                None => work(),
+            };
+            if auto_inject.applies_to(webview.label()) {
+                run("auto_inject", &|| tools::auto_inject::inject_captures(webview, &auto_inject));
+            }
+            run("user_scripts", &|| tools::user_scripts::inject_user_scripts(webview));
+        })
         .setup(move |app, api| {
             info!("[TAURI_MCP] Setting up plugin");
             #[cfg(mobile)]
             panic!("Mobile is not supported");
             #[cfg(desktop)]
-            let tauri_mcp = desktop::init(app, api, &config)?;
+            let tauri_mcp = desktop::init(app, api, &config, init_timing.clone())?;
             app.manage(tauri_mcp);
+            if let Ok(mut timing) = init_timing.lock() {
+                timing.mark_ready();
+            }
             info!("[TAURI_MCP] Plugin setup complete");
             Ok(())
         })
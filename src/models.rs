@@ -116,16 +116,36 @@ pub struct TypeTextRequest {
 #[serde(rename_all = "snake_case")]
 pub struct ScreenshotRequest {
     pub window_label: String,
+    /// Device pixel ratio to scale the capture to
+    #[serde(default)]
+    pub target_dpr: Option<f64>,
+    /// Capture at full size without compression: PNG, or WebP when `format` is "webp"
+    #[serde(default)]
+    pub lossless: bool,
+    /// Crop the capture to this rectangle, in logical pixels
+    #[serde(default)]
+    pub region: Option<crate::shared::ScreenshotRegion>,
+    /// Output encoding: "png", "jpeg" or "webp"
+    #[serde(default)]
+    pub format: Option<String>,
+    /// Quality for lossy formats, clamped to 1-100; PNG and (lossless) WebP ignore it
+    #[serde(default)]
+    pub quality: Option<u8>,
 }
 
 impl From<ScreenshotRequest> for crate::shared::ScreenshotParams {
     fn from(req: ScreenshotRequest) -> Self {
         Self {
             window_label: Some(req.window_label),
-            quality: None,
+            quality: req.quality,
             max_width: None,
             max_size_mb: None,
             application_name: None,
+            target_dpr: req.target_dpr,
+            source_dpr: None,
+            lossless: req.lossless,
+            region: req.region,
+            format: req.format,
         }
     }
 }
@@ -0,0 +1,205 @@
+use crate::models::ScreenshotResponse;
+use crate::{Error, Result};
+use image;
+use log::{debug, info, error, warn};
+use tauri::Runtime;
+
+// Import shared functionality
+use crate::desktop::{ScreenshotContext, create_success_response};
+use crate::platform::shared::{capture_xcap_monitor, get_window_title, handle_screenshot_task};
+use crate::shared::ScreenshotParams;
+use crate::tools::monitors::MonitorTarget;
+use crate::tools::take_screenshot::process_image;
+
+/// Whether the desktop session is Wayland, where clients can neither list other windows
+/// nor capture them directly
+pub fn is_wayland_session() -> bool {
+    let session_type = std::env::var("XDG_SESSION_TYPE").unwrap_or_default();
+    session_type.eq_ignore_ascii_case("wayland")
+        || std::env::var("WAYLAND_DISPLAY").map(|v| !v.is_empty()).unwrap_or(false)
+}
+
+// Linux-specific implementation for taking screenshots
+pub async fn take_screenshot<R: Runtime>(
+    params: ScreenshotParams,
+    window_context: ScreenshotContext<R>,
+) -> Result<ScreenshotResponse> {
+    // Clone necessary parameters for use in the closure
+    let params_clone = params.clone();
+    let window_clone = window_context.window.clone();
+    let window_label = params
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    // Get application name from params or use a default
+    let application_name = params.application_name.clone().unwrap_or_else(|| "".to_string());
+
+    // Physical geometry of the Tauri window, used to crop a full-screen capture
+    let window_geometry = match (window_clone.outer_position(), window_clone.outer_size()) {
+        (Ok(position), Ok(size)) => Some((position.x, position.y, size.width, size.height)),
+        _ => None,
+    };
+
+    handle_screenshot_task(move || {
+        // Get the window title to help identify the right window
+        let window_title = get_window_title(&window_clone)?;
+        let wayland = is_wayland_session();
+
+        info!(
+            "[TAURI-MCP] Looking for window with title: {} (label: {}, session: {})",
+            window_title,
+            window_label,
+            if wayland { "wayland" } else { "x11" }
+        );
+
+        // X11 lists every window; under Wayland xcap only sees XWayland clients, so a
+        // native Wayland window falls through to the full-screen capture below
+        let window_capture = match xcap::Window::all() {
+            Ok(xcap_windows) => {
+                info!("[TAURI-MCP] Found {} windows through xcap", xcap_windows.len());
+                match find_window(&xcap_windows, &window_title, &application_name) {
+                    Some(window) => window
+                        .capture_image()
+                        .map_err(|e| format!("Failed to capture window image: {}", e)),
+                    None => Err("Window not found using any detection method".to_string()),
+                }
+            }
+            Err(e) => Err(format!("Failed to get window list: {}", e)),
+        };
+
+        let image = match window_capture {
+            Ok(img) => img,
+            Err(reason) if !wayland => {
+                return Err(Error::window_operation_failed(
+                    "capture window",
+                    format!("{}. Please ensure the window is visible and not minimized.", reason),
+                ));
+            }
+            Err(reason) => {
+                warn!("[TAURI-MCP] Per-window capture unavailable under Wayland ({}), cropping a screen capture", reason);
+                capture_cropped_screen(window_geometry)?
+            }
+        };
+
+        info!("[TAURI-MCP] Successfully captured window image: {}x{}",
+              image.width(), image.height());
+
+        // Convert to DynamicImage for further processing
+        let dynamic_image = image::DynamicImage::ImageRgba8(image);
+
+        // Process the image
+        match process_image(dynamic_image, &params_clone) {
+            Ok(data_url) => Ok(create_success_response(data_url)),
+            Err(e) => Err(e),
+        }
+    }).await
+}
+
+// Helper function to find the window in the xcap window list. xcap reports the WM_CLASS
+// as app name on Linux, so the title is tried first.
+fn find_window(xcap_windows: &[xcap::Window], window_title: &str, application_name: &str) -> Option<xcap::Window> {
+    let window_title_lower = window_title.to_lowercase();
+    let application_name_lower = application_name.to_lowercase();
+
+    debug!("[TAURI-MCP] ============= ALL WINDOWS =============");
+    for window in xcap_windows {
+        if !window.is_minimized() {
+            debug!(
+                "[TAURI-MCP] Window: title='{}', app_name='{}'",
+                window.title(),
+                window.app_name()
+            );
+        }
+    }
+    debug!("[TAURI-MCP] ======================================");
+
+    let visible = || xcap_windows.iter().filter(|w| !w.is_minimized());
+
+    // Step 1: exact title, then case-insensitive title
+    if !window_title.is_empty() {
+        if let Some(window) = visible()
+            .find(|w| w.title() == window_title)
+            .or_else(|| visible().find(|w| w.title().to_lowercase().contains(&window_title_lower)))
+        {
+            info!("[TAURI-MCP] Found window by title: '{}'", window.title());
+            return Some(window.clone());
+        }
+    }
+
+    // Step 2: application name
+    if !application_name_lower.is_empty() {
+        if let Some(window) = visible().find(|w| w.app_name().to_lowercase().contains(&application_name_lower)) {
+            info!("[TAURI-MCP] Found window by app name: '{}'", window.app_name());
+            return Some(window.clone());
+        }
+    }
+
+    error!(
+        "[TAURI-MCP] No matching window found for '{}'",
+        window_title
+    );
+    None
+}
+
+// Wayland fallback: capture the monitor under the window through the screenshot portal
+// and crop it to the window's geometry
+fn capture_cropped_screen(window_geometry: Option<(i32, i32, u32, u32)>) -> Result<image::RgbaImage> {
+    let wayland_error = |reason: String| {
+        Error::window_operation_failed_with_context(
+            "capture window",
+            reason,
+            "Wayland does not let applications capture individual windows. Run the app under XWayland (GDK_BACKEND=x11) or allow screen capture through xdg-desktop-portal.",
+        )
+    };
+
+    let Some((x, y, width, height)) = window_geometry.filter(|g| g.2 > 0 && g.3 > 0) else {
+        return Err(wayland_error("The window geometry is unknown, so a screen capture cannot be cropped to it".to_string()));
+    };
+
+    let monitors = xcap::Monitor::all().map_err(|e| wayland_error(format!("Failed to get monitor list: {}", e)))?;
+
+    // The monitor containing the window's centre, or the primary one
+    let (cx, cy) = (x + width as i32 / 2, y + height as i32 / 2);
+    let monitor = monitors
+        .iter()
+        .find(|m| {
+            cx >= m.x() && cx < m.x() + m.width() as i32 && cy >= m.y() && cy < m.y() + m.height() as i32
+        })
+        .or_else(|| monitors.iter().find(|m| m.is_primary()))
+        .ok_or_else(|| wayland_error("No display found to capture".to_string()))?;
+
+    let screen = monitor
+        .capture_image()
+        .map_err(|e| wayland_error(format!("Failed to capture screen image: {}", e)))?;
+
+    if screen.width() == 0 || screen.height() == 0 {
+        return Err(wayland_error("The screen capture came back empty".to_string()));
+    }
+
+    // The portal may hand back the screen at a different resolution than the monitor reports
+    let scale_x = screen.width() as f64 / monitor.width().max(1) as f64;
+    let scale_y = screen.height() as f64 / monitor.height().max(1) as f64;
+    let left = (((x - monitor.x()).max(0) as f64) * scale_x).round() as u32;
+    let top = (((y - monitor.y()).max(0) as f64) * scale_y).round() as u32;
+    let left = left.min(screen.width().saturating_sub(1));
+    let top = top.min(screen.height().saturating_sub(1));
+    let crop_width = ((width as f64 * scale_x).round() as u32).clamp(1, screen.width() - left);
+    let crop_height = ((height as f64 * scale_y).round() as u32).clamp(1, screen.height() - top);
+
+    info!(
+        "[TAURI-MCP] Cropping {}x{} screen capture to {}x{} at ({}, {})",
+        screen.width(), screen.height(), crop_width, crop_height, left, top
+    );
+
+    Ok(image::imageops::crop_imm(&screen, left, top, crop_width, crop_height).to_image())
+}
+
+// Linux-specific implementation for capturing a whole monitor
+pub async fn capture_monitor(
+    params: ScreenshotParams,
+    target: MonitorTarget,
+) -> Result<ScreenshotResponse> {
+    let position = (target.x, target.y);
+    capture_xcap_monitor(params, target, position).await
+}
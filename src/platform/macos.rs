@@ -6,8 +6,9 @@ use tauri::Runtime;
 
 // Import shared functionality
 use crate::desktop::{ScreenshotContext, create_success_response};
-use crate::platform::shared::{get_window_title, handle_screenshot_task};
+use crate::platform::shared::{capture_xcap_monitor, get_window_title, handle_screenshot_task};
 use crate::shared::ScreenshotParams;
+use crate::tools::monitors::MonitorTarget;
 use crate::tools::take_screenshot::process_image;
 
 // macOS-specific implementation for taking screenshots
@@ -116,4 +117,16 @@ fn find_window(xcap_windows: &[xcap::Window], window_title: &str, application_na
     None
 }
 
+// macOS-specific implementation for capturing a whole monitor
+pub async fn capture_monitor(
+    params: ScreenshotParams,
+    target: MonitorTarget,
+) -> Result<ScreenshotResponse> {
+    // xcap reports logical coordinates, Tauri physical ones
+    let logical_x = (target.x as f64 / target.scale_factor).round() as i32;
+    let logical_y = (target.y as f64 / target.scale_factor).round() as i32;
+
+    capture_xcap_monitor(params, target, (logical_x, logical_y)).await
+}
+
 // Add any other macOS-specific functionality here
@@ -10,7 +10,10 @@ pub mod macos;
 #[cfg(target_os = "windows")]
 pub mod windows;
 
-#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+#[cfg(target_os = "linux")]
+pub mod linux;
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
 pub mod unix;
 
 // Re-export the current platform implementation under a common name
@@ -20,5 +23,8 @@ pub use self::macos as current;
 #[cfg(target_os = "windows")]
 pub use self::windows as current;
 
-#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+#[cfg(target_os = "linux")]
+pub use self::linux as current;
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
 pub use self::unix as current;
@@ -30,3 +30,46 @@ pub fn get_window_title<R: Runtime>(window: &tauri::WebviewWindow<R>) -> Result<
         )),
     }
 }
+
+// Common function for capturing a whole monitor through xcap. `position` is the monitor
+// origin in the coordinate space xcap uses on the platform.
+#[cfg(any(target_os = "macos", target_os = "windows", target_os = "linux"))]
+pub async fn capture_xcap_monitor(
+    params: crate::shared::ScreenshotParams,
+    target: crate::tools::monitors::MonitorTarget,
+    position: (i32, i32),
+) -> Result<ScreenshotResponse> {
+    use crate::desktop::create_success_response;
+    use crate::tools::take_screenshot::process_image;
+    use log::info;
+
+    handle_screenshot_task(move || {
+        let monitors = match xcap::Monitor::all() {
+            Ok(monitors) => monitors,
+            Err(e) => return Err(Error::window_operation_failed("get monitor list", format!("Failed to get monitor list: {}", e))),
+        };
+
+        info!("[TAURI-MCP] Found {} monitors through xcap", monitors.len());
+
+        let monitor = monitors
+            .iter()
+            .find(|m| target.name.as_deref() == Some(m.name()))
+            .or_else(|| monitors.iter().find(|m| (m.x(), m.y()) == position))
+            .or_else(|| monitors.iter().find(|m| target.is_primary && m.is_primary()));
+
+        let Some(monitor) = monitor else {
+            return Err(Error::window_operation_failed("find monitor", format!("No display matches monitor {:?}", target.name)));
+        };
+
+        let image = match monitor.capture_image() {
+            Ok(img) => img,
+            Err(e) => return Err(Error::window_operation_failed("capture monitor image", format!("Failed to capture monitor image: {}", e))),
+        };
+
+        info!("[TAURI-MCP] Successfully captured monitor image: {}x{}",
+              image.width(), image.height());
+
+        let dynamic_image = image::DynamicImage::ImageRgba8(image);
+        process_image(dynamic_image, &params).map(create_success_response)
+    }).await
+}
@@ -6,6 +6,7 @@ use tauri::Runtime;
 use crate::desktop::ScreenshotContext;
 use crate::platform::shared::handle_screenshot_task;
 use crate::shared::ScreenshotParams;
+use crate::tools::monitors::MonitorTarget;
 
 // Unix-specific implementation for taking screenshots (fallback for non-macOS Unix systems)
 pub async fn take_screenshot<R: Runtime>(
@@ -14,7 +15,7 @@ pub async fn take_screenshot<R: Runtime>(
 ) -> Result<ScreenshotResponse> {
     // Clone necessary values from params for use in the closure
     let window_clone = window_context.window.clone();
-    let quality = params.quality.unwrap_or(85) as u8;
+    let quality = params.quality.unwrap_or(80);
     let max_width = params.max_width.map(|w| w as u32).unwrap_or(0);
 
     handle_screenshot_task(move || {
@@ -69,4 +70,16 @@ pub async fn take_screenshot<R: Runtime>(
   }).await
 }
 
+// Monitor capture needs a native screen grabber, which is only wired up on macOS so far
+pub async fn capture_monitor(
+    _params: ScreenshotParams,
+    target: MonitorTarget,
+) -> Result<ScreenshotResponse> {
+    Err(Error::window_operation_failed_with_context(
+        "capture monitor",
+        "Monitor capture is not supported on this platform yet",
+        format!("monitor: {:?}", target.name),
+    ))
+}
+
 // Add any other Unix-specific functionality here
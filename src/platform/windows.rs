@@ -1,14 +1,14 @@
 use crate::models::ScreenshotResponse;
 use crate::{Error, Result};
-use image::{DynamicImage, RgbaImage};
-use log::info;
+use image;
+use log::{debug, info, error};
 use tauri::Runtime;
-use win_screenshot::prelude::*;
 
 // Import shared functionality
 use crate::desktop::{ScreenshotContext, create_success_response};
-use crate::platform::shared::{get_window_title, handle_screenshot_task};
+use crate::platform::shared::{capture_xcap_monitor, get_window_title, handle_screenshot_task};
 use crate::shared::ScreenshotParams;
+use crate::tools::monitors::MonitorTarget;
 use crate::tools::take_screenshot::process_image;
 
 // Windows-specific implementation for taking screenshots
@@ -16,76 +16,155 @@ pub async fn take_screenshot<R: Runtime>(
     params: ScreenshotParams,
     window_context: ScreenshotContext<R>,
 ) -> Result<ScreenshotResponse> {
-    // Clone params for use in the closure
-    let params_clone = params.clone();
+    // Clone necessary parameters for use in the closure
+    let mut params_clone = params.clone();
     let window_clone = window_context.window.clone();
     let window_label = params
         .window_label
         .clone()
         .unwrap_or_else(|| "main".to_string());
 
+    // Get application name from params or use a default
+    let application_name = params.application_name.clone().unwrap_or_else(|| "".to_string());
+
+    // Physical position of the Tauri window, used to tell apart windows sharing a title
+    let window_position = window_clone.outer_position().ok().map(|p| (p.x, p.y));
+
     handle_screenshot_task(move || {
-    // Get the window title to help identify the right window
-    let window_title = get_window_title(&window_clone)?;
-    
-    info!("[SCREENSHOT] Looking for window with title: {} (label: {})", window_title, window_label);
-    
-    // Get all windows
-    let windows = match window_list() {
-      Ok(list) => list,
-      Err(e) => return Err(Error::WindowOperationFailed(format!("Failed to get window list: {:?}", e))),
-    };
-    
-    info!("[SCREENSHOT] Found {} windows through win-screenshot", windows.len());
-    
-    // Log all windows with titles for debugging
-    info!("[SCREENSHOT] ============= ALL WINDOWS =============");
-    for window_info in &windows {
-      info!("[SCREENSHOT] Window: hwnd={}, title='{}'", 
-              window_info.hwnd, window_info.window_name);
+        // Get the window title to help identify the right window
+        let window_title = get_window_title(&window_clone)?;
+
+        info!("[TAURI-MCP] Looking for window with title: {} (label: {})", window_title, window_label);
+
+        // Get all windows using xcap - do this only once
+        let xcap_windows = match xcap::Window::all() {
+            Ok(windows) => windows,
+            Err(e) => return Err(Error::window_operation_failed("get window list", format!("Failed to get window list: {}", e))),
+        };
+
+        info!("[TAURI-MCP] Found {} windows through xcap", xcap_windows.len());
+
+        if let Some(window) = find_window(&xcap_windows, &window_title, &application_name, window_position) {
+            // xcap captures in physical pixels of the monitor the window is on, which may be a
+            // secondary monitor with a different scale than the one Tauri last reported
+            let scale_factor = window.current_monitor().scale_factor() as f64;
+            if scale_factor > 0.0 {
+                params_clone.source_dpr = Some(scale_factor);
+            }
+
+            let image = match window.capture_image() {
+                Ok(img) => img,
+                Err(e) => return Err(Error::window_operation_failed("capture window image", format!("Failed to capture window image: {}", e))),
+            };
+
+            info!("[TAURI-MCP] Successfully captured window image: {}x{} (scale factor {})",
+                  image.width(), image.height(), scale_factor);
+
+            // Convert to DynamicImage for further processing
+            let dynamic_image = image::DynamicImage::ImageRgba8(image);
+
+            // Process the image
+            match process_image(dynamic_image, &params_clone) {
+                Ok(data_url) => Ok(create_success_response(data_url)),
+                Err(e) => Err(e),
+            }
+        } else {
+            // No window found
+            Err(Error::window_operation_failed("find window", "Window not found using any detection method. Please ensure the window is visible and not minimized."))
+        }
+    }).await
+}
+
+// Helper function to find the window in the xcap window list. Windows titles are usually
+// unique per app, so the title is tried before the (process description based) app name.
+fn find_window(
+    xcap_windows: &[xcap::Window],
+    window_title: &str,
+    application_name: &str,
+    window_position: Option<(i32, i32)>,
+) -> Option<xcap::Window> {
+    let window_title_lower = window_title.to_lowercase();
+    let application_name_lower = application_name.to_lowercase();
+
+    debug!(
+        "[TAURI-MCP] Searching for window with title: '{}' (case-insensitive)",
+        window_title
+    );
+
+    // Debug all windows to help with troubleshooting
+    debug!("[TAURI-MCP] ============= ALL WINDOWS =============");
+    for window in xcap_windows {
+        if !window.is_minimized() {
+            debug!(
+                "[TAURI-MCP] Window: title='{}', app_name='{}', position=({}, {})",
+                window.title(),
+                window.app_name(),
+                window.x(),
+                window.y()
+            );
+        }
     }
-    info!("[SCREENSHOT] ======================================");
-    
-    // Try to find the window
-    let mut target_hwnd = None;
-    
-    // First try exact match
-    for window_info in &windows {
-      if window_info.window_name == window_title {
-        info!("[SCREENSHOT] Found exact window title match: {}", window_info.window_name);
-        target_hwnd = Some(window_info.hwnd);
-        break;
-      }
+    debug!("[TAURI-MCP] ======================================");
+
+    let visible: Vec<&xcap::Window> = xcap_windows.iter().filter(|w| !w.is_minimized()).collect();
+
+    // Of several candidates, prefer the one at the Tauri window's position
+    let pick = |candidates: Vec<&xcap::Window>| -> Option<xcap::Window> {
+        let by_position = window_position.and_then(|(x, y)| {
+            candidates.iter().find(|w| w.x() == x && w.y() == y).copied()
+        });
+        by_position.or_else(|| candidates.first().copied()).cloned()
+    };
+
+    // Step 1: exact title match
+    if !window_title.is_empty() {
+        let exact: Vec<&xcap::Window> = visible.iter().copied().filter(|w| w.title() == window_title).collect();
+        if let Some(window) = pick(exact) {
+            info!("[TAURI-MCP] Found window by exact title: '{}'", window.title());
+            return Some(window);
+        }
+
+        // Step 2: case-insensitive title match
+        let partial: Vec<&xcap::Window> = visible
+            .iter()
+            .copied()
+            .filter(|w| w.title().to_lowercase().contains(&window_title_lower))
+            .collect();
+        if let Some(window) = pick(partial) {
+            info!("[TAURI-MCP] Found window by title: '{}'", window.title());
+            return Some(window);
+        }
     }
-   
-  
-    // Take screenshot if a window was found
-    if let Some(hwnd) = target_hwnd {
-      info!("[SCREENSHOT] Taking screenshot of window with hwnd: {}", hwnd);
-      
-      // Use PrintWindow for more reliable capture
-      let buffer = match capture_window_ex(hwnd, Using::PrintWindow, Area::Full, None, None) {
-        Ok(buf) => buf,
-        Err(e) => return Err(Error::WindowOperationFailed(format!("Failed to capture window: {:?}", e))),
-      };
-      
-      info!("[SCREENSHOT] Successfully captured window image: {}x{}", 
-              buffer.width, buffer.height);
-      
-      // Convert to dynamic image for processing
-      let dynamic_image = DynamicImage::ImageRgba8(
-        RgbaImage::from_raw(buffer.width, buffer.height, buffer.pixels)
-          .ok_or_else(|| Error::WindowOperationFailed("Failed to create image from buffer".to_string()))?
-      );
-      
-      // Process the image
-      match process_image(dynamic_image, &params_clone) {
-        Ok(data_url) => Ok(create_success_response(data_url)),
-        Err(e) => Err(e),
-      }
-    } else {
-      // No window found at all
-      Err(Error::WindowOperationFailed("Window not found using any detection method. Please ensure the window is visible and not minimized.".to_string()))
+
+    // Step 3: application name match
+    if !application_name_lower.is_empty() {
+        let by_app: Vec<&xcap::Window> = visible
+            .iter()
+            .copied()
+            .filter(|w| w.app_name().to_lowercase().contains(&application_name_lower))
+            .collect();
+        if let Some(window) = pick(by_app) {
+            info!(
+                "[TAURI-MCP] Found window by app name: '{}'",
+                window.app_name()
+            );
+            return Some(window);
+        }
     }
-  }).await
+
+    error!(
+        "[TAURI-MCP] No matching window found for '{}'",
+        window_title
+    );
+    None
+}
+
+// Windows-specific implementation for capturing a whole monitor
+pub async fn capture_monitor(
+    params: ScreenshotParams,
+    target: MonitorTarget,
+) -> Result<ScreenshotResponse> {
+    // On Windows both xcap and Tauri report physical coordinates
+    let position = (target.x, target.y);
+    capture_xcap_monitor(params, target, position).await
 }
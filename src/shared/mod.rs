@@ -8,8 +8,8 @@ pub struct ScreenshotParams {
     /// The label of the window to capture
     pub window_label: Option<String>,
 
-    /// JPEG quality (1-100)
-    pub quality: Option<i32>,
+    /// Quality (clamped to 1-100) for lossy formats, 80 when omitted; only JPEG is lossy
+    pub quality: Option<u8>,
 
     /// Maximum image width in pixels
     pub max_width: Option<i32>,
@@ -19,6 +19,36 @@ pub struct ScreenshotParams {
 
     /// Application name to look for in window matching
     pub application_name: Option<String>,
+
+    /// Device pixel ratio to normalize the output to, so captures from
+    /// displays with different scale factors are comparable
+    #[serde(default)]
+    pub target_dpr: Option<f64>,
+
+    /// Device pixel ratio the image was captured at
+    #[serde(default)]
+    pub source_dpr: Option<f64>,
+
+    /// Encode as PNG without the size limits, for exact pixel values
+    #[serde(default)]
+    pub lossless: bool,
+
+    /// Output encoding: "png", "jpeg" or "webp", JPEG when omitted
+    #[serde(default)]
+    pub format: Option<String>,
+
+    /// Sub-rectangle of the capture to keep, in logical (CSS) pixels
+    #[serde(default)]
+    pub region: Option<ScreenshotRegion>,
+}
+
+/// Rectangle within a screenshot, in logical (CSS) pixels from the top-left corner
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ScreenshotRegion {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
 }
 
 /// Result of taking a screenshot
@@ -142,6 +172,7 @@ pub mod commands {
     pub const NETWORK_INSPECTOR: &str = "network_inspector";
     pub const INJECT_NETWORK_CAPTURE: &str = "inject_network_capture";
     pub const STATE_DUMP: &str = "state_dump";
+    pub const DIFF_STATE: &str = "diff_state";
     pub const DEVTOOLS_BRIDGE: &str = "devtools_bridge";
     pub const GET_EXCEPTIONS: &str = "get_exceptions";
     pub const INJECT_ERROR_TRACKER: &str = "inject_error_tracker";
@@ -149,4 +180,113 @@ pub mod commands {
     pub const GET_PERFORMANCE_METRICS: &str = "get_performance_metrics";
     pub const STORAGE_INSPECTOR: &str = "storage_inspector";
     pub const HEALTH_CHECK: &str = "health_check";
+    pub const GET_BOX_MODEL: &str = "get_box_model";
+    pub const GET_ZOOM: &str = "get_zoom";
+    pub const SET_ZOOM: &str = "set_zoom";
+    pub const GET_FONT_INFO: &str = "get_font_info";
+    pub const TRAY: &str = "tray";
+    pub const GET_MONITORS: &str = "get_monitors";
+    pub const SCREENSHOT_MONITOR: &str = "screenshot_monitor";
+    pub const HANDLE_NEXT_DIALOG: &str = "handle_next_dialog";
+    pub const GET_SERVICE_WORKERS: &str = "get_service_workers";
+    pub const UNREGISTER_SERVICE_WORKER: &str = "unregister_service_worker";
+    pub const ELEMENT_RELATIONS: &str = "element_relations";
+    pub const CAPTURE_CANVAS: &str = "capture_canvas";
+    pub const GET_CSP: &str = "get_csp";
+    pub const GET_SEMANTIC_OUTLINE: &str = "get_semantic_outline";
+    pub const SIMULATE_HOVER: &str = "simulate_hover";
+    pub const UNHOVER: &str = "unhover";
+    pub const GET_LAYOUT_SHIFTS: &str = "get_layout_shifts";
+    pub const GET_LOADED_SCRIPTS: &str = "get_loaded_scripts";
+    pub const GET_LOCAL_STORAGE_MAP: &str = "get_local_storage_map";
+    pub const SET_LOCAL_STORAGE_MAP: &str = "set_local_storage_map";
+    pub const GET_WEBVIEW_INFO: &str = "get_webview_info";
+    pub const NAVIGATE_HISTORY: &str = "navigate_history";
+    pub const GET_TEXT: &str = "get_text";
+    pub const EXECUTE_JS_FILE: &str = "execute_js_file";
+    pub const MEASURE_REFLOWS: &str = "measure_reflows";
+    pub const GET_DOCUMENT_TITLE: &str = "get_document_title";
+    pub const SET_DOCUMENT_TITLE: &str = "set_document_title";
+    pub const GET_FAVICON: &str = "get_favicon";
+    pub const LIST_SHORTCUTS: &str = "list_shortcuts";
+    pub const TRIGGER_SHORTCUT: &str = "trigger_shortcut";
+    pub const DIFF_COMPUTED_STYLE: &str = "diff_computed_style";
+    pub const SET_ANIMATIONS_PAUSED: &str = "set_animations_paused";
+    pub const GET_CONTENTEDITABLE: &str = "get_contenteditable";
+    pub const SET_CONTENTEDITABLE: &str = "set_contenteditable";
+    pub const ENUMERATE_DEVICES: &str = "enumerate_devices";
+    pub const GET_PERMISSIONS_STATE: &str = "get_permissions_state";
+    pub const START_EXCEPTION_STREAM: &str = "start_exception_stream";
+    pub const STOP_EXCEPTION_STREAM: &str = "stop_exception_stream";
+    pub const SIMULATE_GESTURE: &str = "simulate_gesture";
+    pub const CLEAR_PERFORMANCE_ENTRIES: &str = "clear_performance_entries";
+    pub const FIND_DETACHED_NODES: &str = "find_detached_nodes";
+    pub const GET_POSITIONED_ELEMENTS: &str = "get_positioned_elements";
+    pub const CHECK_CONTRAST: &str = "check_contrast";
+    pub const GET_SELECTION: &str = "get_selection";
+    pub const SET_SELECTION: &str = "set_selection";
+    pub const GET_MUTATION_JOURNAL: &str = "get_mutation_journal";
+    pub const ELEMENT_FROM_POINT: &str = "element_from_point";
+    pub const MEASURE_INPUT_LATENCY: &str = "measure_input_latency";
+    pub const GET_IMAGES: &str = "get_images";
+    pub const FORCE_RERENDER: &str = "force_rerender";
+    pub const EXPORT_WINDOW_LAYOUT: &str = "export_window_layout";
+    pub const IMPORT_WINDOW_LAYOUT: &str = "import_window_layout";
+    pub const CAPTURE_POSTMESSAGE: &str = "capture_postmessage";
+    pub const GET_POSTMESSAGES: &str = "get_postmessages";
+    pub const CLEAR_POSTMESSAGES: &str = "clear_postmessages";
+    pub const SAVE_BASELINE: &str = "save_baseline";
+    pub const COMPARE_BASELINE: &str = "compare_baseline";
+    pub const GET_WORKERS: &str = "get_workers";
+    pub const GET_CSS_COVERAGE: &str = "get_css_coverage";
+    pub const TRIGGER_LAZY_LOAD: &str = "trigger_lazy_load";
+    pub const GET_METADATA: &str = "get_metadata";
+    pub const GET_MENU: &str = "get_menu";
+    pub const TRIGGER_MENU_ITEM: &str = "trigger_menu_item";
+    pub const GET_STACKING_CONTEXTS: &str = "get_stacking_contexts";
+    pub const SET_BATTERY: &str = "set_battery";
+    pub const SET_CONNECTION: &str = "set_connection";
+    pub const GET_DOM_STATS: &str = "get_dom_stats";
+    pub const GET_ROUTE: &str = "get_route";
+    pub const REGISTER_USER_SCRIPT: &str = "register_user_script";
+    pub const UNREGISTER_USER_SCRIPT: &str = "unregister_user_script";
+    pub const LIST_USER_SCRIPTS: &str = "list_user_scripts";
+    pub const GET_PIXEL_COLOR: &str = "get_pixel_color";
+    pub const SET_DESIGN_MODE: &str = "set_design_mode";
+    pub const MEASURE_ACTION_FPS: &str = "measure_action_fps";
+    pub const GET_CUSTOM_ELEMENTS: &str = "get_custom_elements";
+    pub const GET_SCROLL_SNAP_STATE: &str = "get_scroll_snap_state";
+    pub const GET_TAB_ORDER: &str = "get_tab_order";
+    pub const GET_OBSERVERS_FOR: &str = "get_observers_for";
+    pub const SIMULATE_CLOSE_REQUEST: &str = "simulate_close_request";
+    pub const CAPTURE_LIVE_REGIONS: &str = "capture_live_regions";
+    pub const GET_ANNOUNCEMENTS: &str = "get_announcements";
+    pub const GET_SHADOW_DOM: &str = "get_shadow_dom";
+    pub const GET_INTERACTION_TRACE: &str = "get_interaction_trace";
+    pub const HIT_TEST: &str = "hit_test";
+    pub const GET_STYLESHEET: &str = "get_stylesheet";
+    pub const GET_SCRIPT_TIMING: &str = "get_script_timing";
+    pub const TOGGLE_LAYOUT_OVERLAY: &str = "toggle_layout_overlay";
+    pub const GET_SCROLL_RESTORATION: &str = "get_scroll_restoration";
+    pub const SET_SCROLL_RESTORATION: &str = "set_scroll_restoration";
+    pub const PURGE_ALL_STORAGE: &str = "purge_all_storage";
+    pub const GET_CAPTURE_OVERHEAD: &str = "get_capture_overhead";
+    pub const TRIGGER_INTERSECTION: &str = "trigger_intersection";
+    pub const GET_SCROLL_BEHAVIOR: &str = "get_scroll_behavior";
+    pub const SET_SCROLL_BEHAVIOR: &str = "set_scroll_behavior";
+    pub const SET_SCROLL_POSITION: &str = "set_scroll_position";
+    pub const GET_INIT_TIMING: &str = "get_init_timing";
+    pub const GET_INTERACTION_BLOCKERS: &str = "get_interaction_blockers";
+    pub const CAPTURE_ANALYTICS: &str = "capture_analytics";
+    pub const GET_ANALYTICS_EVENTS: &str = "get_analytics_events";
+    pub const CLEAR_ANALYTICS_EVENTS: &str = "clear_analytics_events";
+    pub const GET_INTL_LOCALE: &str = "get_intl_locale";
+    pub const SET_INTL_LOCALE: &str = "set_intl_locale";
+    pub const GET_COMPOSITING_LAYERS: &str = "get_compositing_layers";
+    pub const REORDER_ITEM: &str = "reorder_item";
+    pub const GET_SELECT_STATE: &str = "get_select_state";
+    pub const SELECT_OPTION: &str = "select_option";
+    pub const CAPTURE_FIXTURE: &str = "capture_fixture";
+    pub const ASSERT_FIXTURE: &str = "assert_fixture";
+    pub const GET_COOKIES: &str = "get_cookies";
 }
@@ -5,7 +5,9 @@ use interprocess::local_socket::{
 };
 use serde_json::Value;
 use std::io::{BufRead, BufReader, Read, Write};
+use std::collections::HashMap;
 use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
 use tauri::{AppHandle, Runtime};
@@ -65,6 +67,14 @@ pub struct SocketResponse {
     pub error: Option<String>,
 }
 
+/// Message pushed to clients outside the request/response cycle, e.g. by a stream.
+/// It has no `success` field, which is how clients tell it apart from a response.
+#[derive(Debug, Serialize)]
+pub struct SocketPush {
+    pub event: String,
+    pub data: Value,
+}
+
 /// Unified stream type that can handle both IPC and TCP
 enum UnifiedStream {
     Ipc(IpcStream),
@@ -111,11 +121,33 @@ enum UnifiedListener {
     Tcp(TcpListener),
 }
 
+type ClientWriter = Arc<Mutex<LoggingStream<UnifiedStream>>>;
+
+/// Writers of the connected clients, so pushes can reach them between responses
+type ClientWriters = Arc<Mutex<HashMap<u64, ClientWriter>>>;
+
+static NEXT_CLIENT_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Removes a client's writer from the registry when its connection ends
+struct ClientRegistration {
+    clients: ClientWriters,
+    id: u64,
+}
+
+impl Drop for ClientRegistration {
+    fn drop(&mut self) {
+        if let Ok(mut clients) = self.clients.lock() {
+            clients.remove(&self.id);
+        }
+    }
+}
+
 pub struct SocketServer<R: Runtime> {
     listener: Option<Arc<Mutex<UnifiedListener>>>,
     socket_type: SocketType,
     app: AppHandle<R>,
     running: Arc<Mutex<bool>>,
+    clients: ClientWriters,
 }
 
 impl<R: Runtime> SocketServer<R> {
@@ -149,6 +181,7 @@ impl<R: Runtime> SocketServer<R> {
             socket_type,
             app,
             running: Arc::new(Mutex::new(false)),
+            clients: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
@@ -209,6 +242,7 @@ impl<R: Runtime> SocketServer<R> {
         let app = self.app.clone();
         let running = self.running.clone();
         let socket_type = self.socket_type.clone();
+        let clients = self.clients.clone();
 
         // Spawn a thread to handle socket connections
         info!("[TAURI_MCP] Spawning listener thread");
@@ -264,6 +298,7 @@ impl<R: Runtime> SocketServer<R> {
                                 Ok(stream) => {
                                     info!("[TAURI_MCP] Accepted new IPC connection");
                                     let app_clone = app.clone();
+                                    let clients_clone = clients.clone();
                                     let unified_stream = UnifiedStream::Ipc(stream);
 
                                     // Spawn a new thread with its own panic handler for client handling
@@ -292,7 +327,7 @@ impl<R: Runtime> SocketServer<R> {
                                         }));
 
                                         // Handle the client with error trapping
-                                        if let Err(e) = handle_client(unified_stream, app_clone) {
+                                        if let Err(e) = handle_client(unified_stream, app_clone, clients_clone) {
                                             if e.to_string()
                                                 .contains("No process is on the other end of the pipe")
                                             {
@@ -337,12 +372,13 @@ impl<R: Runtime> SocketServer<R> {
                                     }
                                     
                                     let app_clone = app.clone();
+                                    let clients_clone = clients.clone();
                                     let unified_stream = UnifiedStream::Tcp(stream);
 
                                     // Spawn a new thread for client handling
                                     thread::spawn(move || {
                                         // Handle the client with error trapping
-                                        if let Err(e) = handle_client(unified_stream, app_clone) {
+                                        if let Err(e) = handle_client(unified_stream, app_clone, clients_clone) {
                                             error!("[TAURI_MCP] Error handling TCP client: {}", e);
                                         }
                                     });
@@ -395,6 +431,37 @@ impl<R: Runtime> SocketServer<R> {
         Ok(())
     }
 
+    /// Write an event line to every connected client. Returns how many received it;
+    /// clients that fail the write are left for their connection thread to clean up.
+    pub fn push_event(&self, event: &str, data: Value) -> usize {
+        let push = SocketPush {
+            event: event.to_string(),
+            data,
+        };
+        let line = match serde_json::to_string(&push) {
+            Ok(json) => json + "\n",
+            Err(e) => {
+                error!("[TAURI_MCP] Failed to serialize push event {}: {}", event, e);
+                return 0;
+            }
+        };
+
+        let writers: Vec<ClientWriter> = match self.clients.lock() {
+            Ok(clients) => clients.values().cloned().collect(),
+            Err(_) => return 0,
+        };
+
+        writers
+            .iter()
+            .filter(|writer| {
+                writer
+                    .lock()
+                    .map(|mut writer| writer.write_all(line.as_bytes()).and_then(|_| writer.flush()).is_ok())
+                    .unwrap_or(false)
+            })
+            .count()
+    }
+
     #[cfg(desktop)]
     fn get_socket_name(&self, path: &Option<std::path::PathBuf>) -> Result<Name<'_>, Error> {
         let socket_path = if let Some(p) = path {
@@ -419,7 +486,7 @@ impl<R: Runtime> SocketServer<R> {
     }
 }
 
-fn handle_client<R: Runtime>(stream: UnifiedStream, app: AppHandle<R>) -> crate::Result<()> {
+fn handle_client<R: Runtime>(stream: UnifiedStream, app: AppHandle<R>, clients: ClientWriters) -> crate::Result<()> {
     info!("[TAURI_MCP] Handling new client connection");
     // Create a new runtime for this thread since handle_client runs in a separate thread
     // spawned by the socket listener, not in Tauri's async context
@@ -445,7 +512,14 @@ fn handle_client<R: Runtime>(stream: UnifiedStream, app: AppHandle<R>) -> crate:
         // Wrap the streams with our logging wrapper
         let logging_reader = LoggingStream::new(stream_clone);
         let mut reader = BufReader::new(logging_reader);
-        let mut writer = LoggingStream::new(stream);
+        let shared_writer: ClientWriter = Arc::new(Mutex::new(LoggingStream::new(stream)));
+
+        // Register the writer so pushed events reach this client too
+        let client_id = NEXT_CLIENT_ID.fetch_add(1, Ordering::Relaxed);
+        if let Ok(mut registry) = clients.lock() {
+            registry.insert(client_id, shared_writer.clone());
+        }
+        let _registration = ClientRegistration { clients, id: client_id };
 
         // Keep handling requests until the client disconnects
         loop {
@@ -495,6 +569,9 @@ fn handle_client<R: Runtime>(stream: UnifiedStream, app: AppHandle<R>) -> crate:
                     }
                 };
 
+                let mut writer = shared_writer
+                    .lock()
+                    .map_err(|_| Error::Io { message: "Socket writer lock poisoned".to_string() })?;
                 match writer.write_all(error_json.as_bytes()) {
                     Ok(_) => {
                         if let Err(e) = writer.flush() {
@@ -520,9 +597,14 @@ fn handle_client<R: Runtime>(stream: UnifiedStream, app: AppHandle<R>) -> crate:
             Err(e) => {
                 // Convert the error into a response structure
                 info!("[TAURI_MCP] Command error: {}", e);
+                // Validation failures also list each problem, so clients can fix them in one go
+                let data = match &e {
+                    Error::ValidationFailed { issues, .. } => Some(serde_json::json!({ "issues": issues })),
+                    _ => None,
+                };
                 SocketResponse {
                     success: false,
-                    data: None,
+                    data,
                     error: Some(e.to_string()),
                 }
             }
@@ -537,6 +619,11 @@ fn handle_client<R: Runtime>(stream: UnifiedStream, app: AppHandle<R>) -> crate:
             response_json.len()
         );
 
+        // Hold the writer lock for the whole line so pushed events cannot interleave
+        let mut writer = shared_writer
+            .lock()
+            .map_err(|_| Error::Io { message: "Socket writer lock poisoned".to_string() })?;
+
         // Write the response directly without chunking
         match writer.write_all(response_json.as_bytes()) {
             Ok(_) => {
@@ -0,0 +1,466 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+/// URL patterns (case-insensitive regular expressions) of common analytics endpoints
+const DEFAULT_ANALYTICS_PATTERNS: &[&str] = &[
+    r"google-analytics\.com/(g/|j/)?collect",
+    r"analytics\.google\.com/g/collect",
+    r"googletagmanager\.com/.*collect",
+    r"api\.segment\.io/v1/",
+    r"api(-js)?\.mixpanel\.com/(track|engage|import)",
+    r"api2?\.amplitude\.com/",
+    r"(posthog\.com|/ingest)/(e|capture|batch|i/v0/e)/?",
+    r"plausible\.io/api/event",
+    r"/api/event$",
+    r"clarity\.ms/collect",
+    r"heapanalytics\.com/",
+    r"rudderlabs\.com/v1/",
+];
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct CaptureAnalyticsRequest {
+    window_label: Option<String>,
+    /// URL patterns (regular expressions) of analytics endpoints, replacing the defaults
+    patterns: Option<Vec<String>>,
+    /// Extra URL patterns added to the defaults
+    extra_patterns: Option<Vec<String>>,
+    /// Record every `navigator.sendBeacon` call, whatever its URL (default true)
+    all_beacons: Option<bool>,
+    /// Events kept before the oldest are dropped (default 1000)
+    max_entries: Option<usize>,
+    /// Characters of the raw payload kept per request (default 4000)
+    max_payload_chars: Option<usize>,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct GetAnalyticsEventsRequest {
+    window_label: Option<String>,
+    /// Only events with exactly this name (case-insensitive)
+    event_name: Option<String>,
+    /// Only events from this provider, e.g. "ga4" or "segment"
+    provider: Option<String>,
+    /// Only events with a greater id
+    since_id: Option<u64>,
+    /// Maximum number of events to return, newest last (default 100)
+    limit: Option<usize>,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ClearAnalyticsEventsRequest {
+    window_label: Option<String>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for installing the analytics call recorder in a window
+pub async fn handle_capture_analytics<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("capture_analytics", &payload)
+        .optional("patterns", FieldKind::Array)
+        .optional("extra_patterns", FieldKind::Array)
+        .optional("all_beacons", FieldKind::Bool)
+        .optional("max_entries", FieldKind::Integer { min: Some(1), max: Some(100_000) })
+        .optional("max_payload_chars", FieldKind::Integer { min: Some(0), max: Some(1_000_000) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: CaptureAnalyticsRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for capture_analytics: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let mut patterns = request
+        .patterns
+        .clone()
+        .unwrap_or_else(|| DEFAULT_ANALYTICS_PATTERNS.iter().map(|p| p.to_string()).collect());
+    patterns.extend(request.extra_patterns.clone().unwrap_or_default());
+
+    let js_code = build_probe_script(
+        "Analytics capture error",
+        &json!({
+            "patterns": patterns,
+            "all_beacons": request.all_beacons.unwrap_or(true),
+            "max_entries": request.max_entries.unwrap_or(1000),
+            "max_payload_chars": request.max_payload_chars.unwrap_or(4000),
+        }),
+        CAPTURE_ANALYTICS_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "analytics capture",
+    )
+    .await
+}
+
+/// Handler function for reading recorded analytics events
+pub async fn handle_get_analytics_events<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_analytics_events", &payload)
+        .optional("event_name", FieldKind::String)
+        .optional("provider", FieldKind::String)
+        .optional("since_id", FieldKind::UINT)
+        .optional("limit", FieldKind::Integer { min: Some(1), max: Some(100_000) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: GetAnalyticsEventsRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_analytics_events: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Analytics read error",
+        &json!({
+            "event_name": request.event_name,
+            "provider": request.provider,
+            "since_id": request.since_id.unwrap_or(0),
+            "limit": request.limit.unwrap_or(100),
+        }),
+        GET_ANALYTICS_EVENTS_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "analytics read",
+    )
+    .await
+}
+
+/// Handler function for emptying the analytics event buffer
+pub async fn handle_clear_analytics_events<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("clear_analytics_events", &payload)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: ClearAnalyticsEventsRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for clear_analytics_events: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script("Analytics clear error", &json!({}), CLEAR_ANALYTICS_EVENTS_JS);
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "analytics clear",
+    )
+    .await
+}
+
+/// Wraps `navigator.sendBeacon`, `fetch`, `XMLHttpRequest` and image pixel `src`
+/// assignments. Calls to a matching URL are split into events by the payload format of
+/// the provider it belongs to (GA4, Universal Analytics, Segment, Mixpanel, Amplitude,
+/// PostHog, Plausible), falling back to common field names like `event`. The recorder
+/// only observes: requests are passed on unchanged. A navigation discards it.
+const CAPTURE_ANALYTICS_JS: &str = r#"        const existing = window.__MCP_ANALYTICS__;
+        const compile = (patterns) => patterns.map(pattern => {
+            try {
+                return new RegExp(pattern, 'i');
+            } catch (error) {
+                throw new Error('Invalid pattern ' + JSON.stringify(pattern) + ': ' + error.message);
+            }
+        });
+        if (existing) {
+            existing.matchers = compile(args.patterns);
+            existing.patterns = args.patterns;
+            existing.all_beacons = args.all_beacons;
+            existing.max_entries = args.max_entries;
+            existing.max_payload_chars = args.max_payload_chars;
+            return { installed: true, already_installed: true, installed_at_ms: existing.installed_at_ms, buffered: existing.entries.length, patterns: args.patterns };
+        }
+
+        const store = {
+            installed_at_ms: Date.now(),
+            next_id: 1,
+            next_request: 1,
+            dropped: 0,
+            entries: [],
+            patterns: args.patterns,
+            matchers: compile(args.patterns),
+            all_beacons: args.all_beacons,
+            max_entries: args.max_entries,
+            max_payload_chars: args.max_payload_chars
+        };
+        window.__MCP_ANALYTICS__ = store;
+
+        const absolute = (url) => {
+            try { return new URL(String(url), location.href).href; } catch (e) { return String(url); }
+        };
+        const matches = (url) => store.matchers.some(matcher => matcher.test(url));
+
+        const provider = (url) => {
+            if (/google-analytics\.com|analytics\.google\.com|googletagmanager\.com/i.test(url)) return /\/g\/collect/i.test(url) ? 'ga4' : 'universal_analytics';
+            if (/segment\.io/i.test(url)) return 'segment';
+            if (/mixpanel\.com/i.test(url)) return 'mixpanel';
+            if (/amplitude\.com/i.test(url)) return 'amplitude';
+            if (/posthog|\/ingest\//i.test(url)) return 'posthog';
+            if (/plausible\.io|\/api\/event$/i.test(url)) return 'plausible';
+            if (/rudderlabs\.com/i.test(url)) return 'rudderstack';
+            return 'generic';
+        };
+
+        // Bodies become { text, data }: data is parsed JSON or query parameters when possible
+        const fromQuery = (text) => {
+            const params = {};
+            for (const [key, value] of new URLSearchParams(text)) params[key] = value;
+            return params;
+        };
+        const parseText = (text) => {
+            if (text === null || text === undefined || text === '') return { text: null, data: null };
+            const trimmed = text.trim();
+            if (trimmed.startsWith('{') || trimmed.startsWith('[')) {
+                try { return { text, data: JSON.parse(trimmed) }; } catch (e) {}
+            }
+            if (/^[^\s=&]+=/.test(trimmed)) return { text, data: fromQuery(trimmed) };
+            return { text, data: null };
+        };
+        const readBody = async (body) => {
+            if (body === null || body === undefined) return { text: null, data: null };
+            if (typeof body === 'string') return parseText(body);
+            if (body instanceof URLSearchParams) return parseText(body.toString());
+            if (typeof FormData !== 'undefined' && body instanceof FormData) {
+                const data = {};
+                for (const [key, value] of body) data[key] = typeof value === 'string' ? value : '[File ' + value.name + ']';
+                return { text: JSON.stringify(data), data };
+            }
+            if (typeof Blob !== 'undefined' && body instanceof Blob) return parseText(await body.text());
+            if (body instanceof ArrayBuffer || ArrayBuffer.isView(body)) {
+                try { return parseText(new TextDecoder().decode(body)); } catch (e) { return { text: '[binary ' + body.byteLength + ' bytes]', data: null }; }
+            }
+            return { text: String(body), data: null };
+        };
+
+        const decodeBase64Json = (value) => {
+            try { return JSON.parse(atob(decodeURIComponent(value))); } catch (e) { return null; }
+        };
+        const number = (value) => value !== undefined && value !== '' && !isNaN(Number(value)) ? Number(value) : value;
+
+        // Split one request into named events by the provider's payload format
+        const extractEvents = (kind, query, body) => {
+            const bodyData = body.data;
+            if (kind === 'ga4') {
+                // Batched GA4 hits put one query string per line in the body
+                const isJson = body.text !== null && /^\s*[\[{]/.test(body.text);
+                const lines = body.text && !isJson ? body.text.split('\n').filter(Boolean) : [];
+                const hits = lines.length > 0 ? lines.map(line => ({ ...query, ...fromQuery(line) })) : [{ ...query, ...(bodyData || {}) }];
+                return hits.map(hit => {
+                    const properties = {};
+                    for (const [key, value] of Object.entries(hit)) {
+                        if (key.startsWith('ep.')) properties[key.substring(3)] = value;
+                        else if (key.startsWith('epn.')) properties[key.substring(4)] = number(value);
+                    }
+                    return { name: hit.en || null, properties, context: { measurement_id: hit.tid || null, client_id: hit.cid || null, page: hit.dl || null } };
+                });
+            }
+            if (kind === 'universal_analytics') {
+                const hit = { ...query, ...(bodyData || {}) };
+                return [{
+                    name: hit.t === 'event' ? (hit.ea || 'event') : (hit.t || null),
+                    properties: { category: hit.ec, action: hit.ea, label: hit.el, value: number(hit.ev), page: hit.dp || hit.dl },
+                    context: { tracking_id: hit.tid || null, client_id: hit.cid || null, hit_type: hit.t || null }
+                }];
+            }
+            if (kind === 'mixpanel') {
+                let data = query.data ? decodeBase64Json(query.data) : null;
+                if (!data && bodyData) data = bodyData.data ? (typeof bodyData.data === 'string' ? decodeBase64Json(bodyData.data) || JSON.parse(bodyData.data) : bodyData.data) : bodyData;
+                const list = Array.isArray(data) ? data : data ? [data] : [];
+                return list.map(item => ({ name: item.event || null, properties: item.properties || {}, context: {} }));
+            }
+            if (kind === 'amplitude') {
+                let data = bodyData;
+                if (data && typeof data.e === 'string') { try { data = { events: JSON.parse(data.e) }; } catch (e) {} }
+                const list = data && Array.isArray(data.events) ? data.events : [];
+                return list.map(item => ({ name: item.event_type || null, properties: item.event_properties || {}, context: { user_id: item.user_id || null, device_id: item.device_id || null } }));
+            }
+            if (kind === 'plausible' && bodyData) {
+                let props = bodyData.p || bodyData.props || {};
+                if (typeof props === 'string') { try { props = JSON.parse(props); } catch (e) {} }
+                return [{ name: bodyData.n || bodyData.name || null, properties: props, context: { url: bodyData.u || bodyData.url || null } }];
+            }
+            // Segment, PostHog, RudderStack and unknown JSON: single events or batches
+            const data = bodyData || query;
+            const list = data && Array.isArray(data.batch) ? data.batch : Array.isArray(data) ? data : [data];
+            return list.filter(Boolean).map(item => ({
+                name: item.event || item.event_name || item.eventName || item.name || item.en || item.type || item.t || null,
+                properties: item.properties || item.props || item.params || item.data || {},
+                context: { type: item.type || null, user_id: item.userId || item.distinct_id || null }
+            }));
+        };
+
+        const record = async (transport, url, body) => {
+            const href = absolute(url);
+            const requestId = store.next_request++;
+            const timestamp = Date.now();
+            let parsed;
+            try {
+                parsed = await readBody(body);
+            } catch (error) {
+                parsed = { text: '[unreadable body: ' + error.message + ']', data: null };
+            }
+            const kind = provider(href);
+            let query = {};
+            try { query = fromQuery(new URL(href).search); } catch (e) {}
+            let events;
+            try {
+                events = extractEvents(kind, query, parsed);
+            } catch (error) {
+                events = [];
+            }
+            if (events.length === 0) events = [{ name: null, properties: {}, context: {} }];
+            const raw = parsed.text === null ? null : parsed.text.substring(0, store.max_payload_chars);
+            for (const event of events) {
+                store.entries.push({
+                    id: store.next_id++,
+                    request_id: requestId,
+                    timestamp_ms: timestamp,
+                    transport,
+                    provider: kind,
+                    url: href,
+                    event_name: event.name,
+                    properties: event.properties,
+                    context: event.context,
+                    raw_payload: raw,
+                    payload_truncated: parsed.text !== null && parsed.text.length > store.max_payload_chars
+                });
+            }
+            if (store.entries.length > store.max_entries) {
+                const excess = store.entries.length - store.max_entries;
+                store.entries.splice(0, excess);
+                store.dropped += excess;
+            }
+        };
+        const observe = (transport, url, body) => {
+            record(transport, url, body).catch(() => {});
+        };
+
+        const hooked = [];
+        if (navigator.sendBeacon) {
+            const originalBeacon = navigator.sendBeacon;
+            navigator.sendBeacon = function (url, data) {
+                if (store.all_beacons || matches(absolute(url))) observe('beacon', url, data);
+                return originalBeacon.apply(this, arguments);
+            };
+            hooked.push('beacon');
+        }
+
+        const originalFetch = window.fetch;
+        window.fetch = function (input, init) {
+            const url = input instanceof Request ? input.url : String(input);
+            if (matches(absolute(url))) {
+                let body = init && init.body;
+                if (body === undefined && input instanceof Request) {
+                    body = input.clone().text().catch(() => null);
+                }
+                Promise.resolve(body).then(resolved => observe('fetch', url, resolved));
+            }
+            return originalFetch.apply(this, arguments);
+        };
+        hooked.push('fetch');
+
+        const originalOpen = XMLHttpRequest.prototype.open;
+        const originalSend = XMLHttpRequest.prototype.send;
+        XMLHttpRequest.prototype.open = function (method, url) {
+            this.__mcpAnalyticsUrl = String(url);
+            return originalOpen.apply(this, arguments);
+        };
+        XMLHttpRequest.prototype.send = function (body) {
+            if (this.__mcpAnalyticsUrl && matches(absolute(this.__mcpAnalyticsUrl))) observe('xhr', this.__mcpAnalyticsUrl, body);
+            return originalSend.apply(this, arguments);
+        };
+        hooked.push('xhr');
+
+        // Tracking pixels: new Image().src = 'https://…/collect?…'
+        const srcDescriptor = Object.getOwnPropertyDescriptor(HTMLImageElement.prototype, 'src');
+        if (srcDescriptor && srcDescriptor.set && srcDescriptor.configurable) {
+            Object.defineProperty(HTMLImageElement.prototype, 'src', {
+                ...srcDescriptor,
+                set(value) {
+                    if (matches(absolute(value))) observe('image', value, null);
+                    return srcDescriptor.set.call(this, value);
+                }
+            });
+            hooked.push('image');
+        }
+
+        return {
+            installed: true,
+            already_installed: false,
+            installed_at_ms: store.installed_at_ms,
+            transports: hooked,
+            patterns: args.patterns,
+            note: 'Only calls made after installation are recorded; a navigation removes the recorder'
+        };"#;
+
+const GET_ANALYTICS_EVENTS_JS: &str = r#"        const store = window.__MCP_ANALYTICS__;
+        if (!store) {
+            return { installed: false, events: [], note: 'Analytics capture is not installed in this window; call capture_analytics first' };
+        }
+
+        const wanted = args.event_name === null ? null : args.event_name.toLowerCase();
+        const matches = store.entries.filter(entry =>
+            entry.id > args.since_id &&
+            (wanted === null || String(entry.event_name || '').toLowerCase() === wanted) &&
+            (!args.provider || entry.provider === args.provider)
+        );
+        const events = matches.slice(-args.limit);
+        const byName = {};
+        for (const entry of matches) {
+            const name = entry.event_name || '(unnamed)';
+            byName[name] = (byName[name] || 0) + 1;
+        }
+
+        return {
+            installed: true,
+            installed_at_ms: store.installed_at_ms,
+            total_buffered: store.entries.length,
+            matched: matches.length,
+            returned: events.length,
+            dropped: store.dropped,
+            last_id: store.next_id - 1,
+            counts_by_event: byName,
+            events
+        };"#;
+
+const CLEAR_ANALYTICS_EVENTS_JS: &str = r#"        const store = window.__MCP_ANALYTICS__;
+        if (!store) return { installed: false, cleared: 0 };
+        const cleared = store.entries.length;
+        store.entries = [];
+        store.dropped = 0;
+        return { installed: true, cleared };"#;
@@ -0,0 +1,130 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct SetAnimationsPausedRequest {
+    window_label: Option<String>,
+    /// true pauses, false resumes
+    paused: bool,
+    /// Jump running animations to their end state instead of freezing mid-way (default false)
+    finish: Option<bool>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for freezing and resuming CSS animations and transitions
+pub async fn handle_set_animations_paused<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("set_animations_paused", &payload)
+        .required("paused", FieldKind::Bool)
+        .optional("finish", FieldKind::Bool)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: SetAnimationsPausedRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for set_animations_paused: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Animation control error",
+        &json!({
+            "paused": request.paused,
+            "finish": request.finish.unwrap_or(false),
+        }),
+        SET_ANIMATIONS_PAUSED_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "animation control",
+    )
+    .await
+}
+
+/// Pauses every `document.getAnimations()` entry and adds a stylesheet so animations
+/// and transitions that start later are frozen too. Resuming only plays the
+/// animations this command paused, leaving ones the app paused itself alone.
+const SET_ANIMATIONS_PAUSED_JS: &str = r#"        const STYLE_ID = '__mcp_pause_animations__';
+        const state = window.__MCP_ANIMATIONS_PAUSED__ || (window.__MCP_ANIMATIONS_PAUSED__ = { paused: [] });
+        const supported = typeof document.getAnimations === 'function';
+        const animations = supported ? document.getAnimations() : [];
+
+        const describe = (animation) => ({
+            type: animation.constructor ? animation.constructor.name : 'Animation',
+            name: animation.animationName || animation.transitionProperty || animation.id || null,
+            target: animation.effect && animation.effect.target instanceof Element ? mcp.cssPath(animation.effect.target) : null
+        });
+
+        if (args.paused) {
+            let style = document.getElementById(STYLE_ID);
+            if (!style) {
+                style = document.createElement('style');
+                style.id = STYLE_ID;
+                style.textContent = '*, *::before, *::after { animation-play-state: paused !important; transition-duration: 0s !important; transition-delay: 0s !important; caret-color: transparent !important; }';
+                (document.head || document.documentElement).appendChild(style);
+            }
+
+            const affected = [];
+            for (const animation of animations) {
+                if (args.finish) {
+                    try {
+                        animation.finish();
+                        affected.push(describe(animation));
+                    } catch (error) {
+                        // Infinite animations cannot finish; freeze them instead
+                        if (animation.playState === 'running') {
+                            animation.pause();
+                            state.paused.push(animation);
+                            affected.push(describe(animation));
+                        }
+                    }
+                } else if (animation.playState === 'running') {
+                    animation.pause();
+                    state.paused.push(animation);
+                    affected.push(describe(animation));
+                }
+            }
+
+            return {
+                paused: true,
+                affected_count: affected.length,
+                affected: affected.slice(0, 50),
+                total_animations: animations.length,
+                web_animations_supported: supported
+            };
+        }
+
+        const style = document.getElementById(STYLE_ID);
+        if (style) style.remove();
+
+        const resumed = [];
+        for (const animation of state.paused) {
+            if (animation.playState === 'paused') {
+                animation.play();
+                resumed.push(describe(animation));
+            }
+        }
+        state.paused = [];
+
+        return {
+            paused: false,
+            affected_count: resumed.length,
+            affected: resumed.slice(0, 50),
+            stylesheet_removed: style !== null,
+            web_animations_supported: supported
+        };"#;
@@ -0,0 +1,40 @@
+use serde_json::json;
+use tauri::{Emitter, Runtime, Webview};
+use log::{info, warn};
+
+use crate::{AutoInjectConfig, CaptureScript};
+
+/// Event the guest-js listens on to activate each capture script
+fn inject_event(capture: CaptureScript) -> &'static str {
+    match capture {
+        CaptureScript::Console => "inject-console-capture",
+        CaptureScript::Network => "inject-network-capture",
+        CaptureScript::Errors => "inject-error-tracker",
+    }
+}
+
+/// Activate the configured capture scripts in a freshly loaded webview.
+///
+/// The guest-js may register its listeners before or after the page finishes
+/// loading, so the captures are both requested by event and recorded on
+/// `window.__MCP_AUTO_INJECT__` for `setupPluginListeners` to pick up.
+pub fn inject_captures<R: Runtime>(webview: &Webview<R>, config: &AutoInjectConfig) {
+    let label = webview.label().to_string();
+    info!("[TAURI_MCP] Auto-injecting {:?} into window {}", config.captures, label);
+
+    let flags: serde_json::Map<String, serde_json::Value> = config
+        .captures
+        .iter()
+        .map(|capture| (inject_event(*capture).to_string(), json!({})))
+        .collect();
+
+    if let Err(e) = webview.eval(&format!("window.__MCP_AUTO_INJECT__ = {};", serde_json::Value::Object(flags))) {
+        warn!("[TAURI_MCP] Failed to record auto-inject flags in window {}: {}", label, e);
+    }
+
+    for capture in &config.captures {
+        if let Err(e) = webview.emit_to(label.as_str(), inject_event(*capture), json!({})) {
+            warn!("[TAURI_MCP] Failed to auto-inject {:?} into window {}: {}", capture, label, e);
+        }
+    }
+}
@@ -0,0 +1,325 @@
+use image::{DynamicImage, GenericImageView, Rgba, RgbaImage};
+use serde_json::{json, Value};
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager, Runtime};
+use log::info;
+
+use crate::error::Error;
+use crate::models::ScreenshotRequest;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, eval_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::take_screenshot::decode_data_url;
+use crate::tools::validation::{FieldKind, PayloadValidator};
+use crate::TauriMcpExt;
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct SaveBaselineRequest {
+    window_label: Option<String>,
+    /// Baseline name; letters, digits, `-`, `_` and `.`
+    name: String,
+    /// Capture only this element instead of the whole window
+    selector: Option<String>,
+    /// Replace an existing baseline (default true)
+    overwrite: Option<bool>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct CompareBaselineRequest {
+    window_label: Option<String>,
+    name: String,
+    selector: Option<String>,
+    /// Per-channel difference below which pixels count as equal (default 24)
+    pixel_threshold: Option<u8>,
+    /// Percentage of differing pixels that still passes (default 0.5)
+    max_diff_percent: Option<f64>,
+    /// Save the capture as the baseline when none exists (default true)
+    create_missing: Option<bool>,
+    /// Return the diff image as a data URL (default true)
+    include_diff_image: Option<bool>,
+}
+
+/// Directory holding the baselines: the configured one, else `mcp-baselines` in app data
+pub(crate) fn baseline_dir<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, Error> {
+    let dir = match app.tauri_mcp().baseline_dir() {
+        Some(dir) => dir.to_path_buf(),
+        None => app
+            .path()
+            .app_data_dir()
+            .map_err(|e| Error::Anyhow { message: format!("No baseline directory configured and no app data directory: {}", e) })?
+            .join("mcp-baselines"),
+    };
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| Error::Anyhow { message: format!("Failed to create baseline directory {}: {}", dir.display(), e) })?;
+    Ok(dir)
+}
+
+/// Baseline names become file names, so keep them to a safe character set
+pub(crate) fn validate_name(name: &str) -> Result<(), Error> {
+    let valid = !name.is_empty()
+        && !name.starts_with('.')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::invalid_parameter("name", "letters, digits, '-', '_' and '.', not starting with '.'", name))
+    }
+}
+
+/// Capture the window at 1x, cropped to an element when a selector is given
+async fn capture<R: Runtime>(app: &AppHandle<R>, window_label: &str, selector: Option<&str>) -> Result<RgbaImage, Error> {
+    let response = app
+        .tauri_mcp()
+        .take_screenshot_async(ScreenshotRequest {
+            window_label: window_label.to_string(),
+            target_dpr: Some(1.0),
+            lossless: false,
+            region: None,
+            format: None,
+            quality: None,
+        })
+        .await?;
+    let data_url = match (response.success, response.data) {
+        (true, Some(data)) => data,
+        _ => {
+            return Err(Error::window_operation_failed(
+                "baseline capture",
+                response.error.unwrap_or_else(|| "Screenshot returned no data".to_string()),
+            ))
+        }
+    };
+    let image = decode_data_url(&data_url)?;
+
+    let Some(selector) = selector else {
+        return Ok(image.to_rgba8());
+    };
+
+    let js_code = build_probe_script("Baseline element error", &json!({ "selector": selector }), ELEMENT_RECT_JS);
+    let rect = eval_probe(app, window_label, js_code, DEFAULT_PROBE_TIMEOUT_MS, "baseline element lookup").await?;
+    Ok(crop_to_element(&image, &rect).to_rgba8())
+}
+
+/// Map the element's viewport rect onto the screenshot. Window captures may include the
+/// title bar, so the viewport is taken to be the bottom of the image, scaled to its width.
+fn crop_to_element(image: &DynamicImage, rect: &Value) -> DynamicImage {
+    let get = |key: &str| rect.get(key).and_then(|v| v.as_f64()).unwrap_or(0.0);
+    let viewport_width = get("viewport_width").max(1.0);
+    let scale = image.width() as f64 / viewport_width;
+    let offset_y = (image.height() as f64 - get("viewport_height") * scale).max(0.0);
+
+    let x = (get("x") * scale).round().clamp(0.0, image.width() as f64) as u32;
+    let y = (get("y") * scale + offset_y).round().clamp(0.0, image.height() as f64) as u32;
+    let width = ((get("width") * scale).round() as u32).min(image.width() - x).max(1);
+    let height = ((get("height") * scale).round() as u32).min(image.height() - y).max(1);
+    image.crop_imm(x, y, width, height)
+}
+
+fn save_png(image: &RgbaImage, path: &std::path::Path) -> Result<(), Error> {
+    image
+        .save_with_format(path, image::ImageFormat::Png)
+        .map_err(|e| Error::Anyhow { message: format!("Failed to write {}: {}", path.display(), e) })
+}
+
+fn png_data_url(image: &RgbaImage) -> Result<String, Error> {
+    let mut output_data = Vec::new();
+    DynamicImage::ImageRgba8(image.clone())
+        .write_to(&mut std::io::Cursor::new(&mut output_data), image::ImageOutputFormat::Png)
+        .map_err(|e| Error::window_operation_failed("PNG encoding", format!("Failed to encode PNG: {}", e)))?;
+    Ok(format!("data:image/png;base64,{}", base64::encode(&output_data)))
+}
+
+/// Pixel diff over the union of both sizes. Differing pixels are red on a faded
+/// grayscale copy of the baseline; pixels outside either image count as different.
+fn diff_images(baseline: &RgbaImage, actual: &RgbaImage, threshold: u8) -> (u64, RgbaImage) {
+    let width = baseline.width().max(actual.width());
+    let height = baseline.height().max(actual.height());
+    let mut diff = RgbaImage::new(width, height);
+    let mut differing = 0u64;
+
+    for y in 0..height {
+        for x in 0..width {
+            let a = baseline.get_pixel_checked(x, y);
+            let b = actual.get_pixel_checked(x, y);
+            let same = match (a, b) {
+                (Some(a), Some(b)) => a.0.iter().zip(b.0.iter()).all(|(p, q)| p.abs_diff(*q) <= threshold),
+                _ => false,
+            };
+            let pixel = if same {
+                let p = a.map(|p| p.0).unwrap_or([255, 255, 255, 255]);
+                let gray = (p[0] as u32 * 299 + p[1] as u32 * 587 + p[2] as u32 * 114) / 1000;
+                let faded = (255 - (255 - gray) / 4) as u8;
+                Rgba([faded, faded, faded, 255])
+            } else {
+                differing += 1;
+                Rgba([255, 0, 0, 255])
+            };
+            diff.put_pixel(x, y, pixel);
+        }
+    }
+    (differing, diff)
+}
+
+/// Handler function for capturing a screenshot into the baseline store
+pub async fn handle_save_baseline<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("save_baseline", &payload)
+        .required("name", FieldKind::NonEmptyString)
+        .optional("selector", FieldKind::NonEmptyString)
+        .optional("overwrite", FieldKind::Bool)
+        .optional("window_label", FieldKind::String)
+        .finish()?;
+
+    let request: SaveBaselineRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for save_baseline: {}", e)))?;
+    validate_name(&request.name)?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let path = baseline_dir(app)?.join(format!("{}.png", request.name));
+    let existed = path.exists();
+    if existed && !request.overwrite.unwrap_or(true) {
+        return Ok(SocketResponse {
+            success: false,
+            data: None,
+            error: Some(format!("Baseline '{}' already exists at {}", request.name, path.display())),
+        });
+    }
+
+    let image = capture(app, &window_label, request.selector.as_deref()).await?;
+    save_png(&image, &path)?;
+    info!("[TAURI_MCP] Saved baseline '{}' to {}", request.name, path.display());
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(json!({
+            "name": request.name,
+            "path": path.display().to_string(),
+            "width": image.width(),
+            "height": image.height(),
+            "replaced": existed,
+        })),
+        error: None,
+    })
+}
+
+/// Handler function for comparing a fresh screenshot against a stored baseline
+pub async fn handle_compare_baseline<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("compare_baseline", &payload)
+        .required("name", FieldKind::NonEmptyString)
+        .optional("selector", FieldKind::NonEmptyString)
+        .optional("pixel_threshold", FieldKind::Integer { min: Some(0), max: Some(255) })
+        .optional("max_diff_percent", FieldKind::Number { min: Some(0.0), max: Some(100.0) })
+        .optional("create_missing", FieldKind::Bool)
+        .optional("include_diff_image", FieldKind::Bool)
+        .optional("window_label", FieldKind::String)
+        .finish()?;
+
+    let request: CompareBaselineRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for compare_baseline: {}", e)))?;
+    validate_name(&request.name)?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let dir = baseline_dir(app)?;
+    let path = dir.join(format!("{}.png", request.name));
+    let actual = capture(app, &window_label, request.selector.as_deref()).await?;
+
+    if !path.exists() {
+        if !request.create_missing.unwrap_or(true) {
+            return Ok(SocketResponse {
+                success: false,
+                data: None,
+                error: Some(format!("No baseline named '{}' in {}", request.name, dir.display())),
+            });
+        }
+        save_png(&actual, &path)?;
+        return Ok(SocketResponse {
+            success: true,
+            data: Some(json!({
+                "name": request.name,
+                "status": "new",
+                "passed": true,
+                "path": path.display().to_string(),
+                "width": actual.width(),
+                "height": actual.height(),
+            })),
+            error: None,
+        });
+    }
+
+    let baseline = image::open(&path)
+        .map_err(|e| Error::Anyhow { message: format!("Failed to read baseline {}: {}", path.display(), e) })?
+        .to_rgba8();
+
+    // Screenshots are JPEG-compressed, so exact equality is never expected
+    let threshold = request.pixel_threshold.unwrap_or(24);
+    let max_diff_percent = request.max_diff_percent.unwrap_or(0.5);
+    let (differing, diff) = diff_images(&baseline, &actual, threshold);
+    let total = (diff.width() as u64 * diff.height() as u64).max(1);
+    let diff_percent = differing as f64 * 100.0 / total as f64;
+    let size_matches = baseline.dimensions() == actual.dimensions();
+    let passed = size_matches && diff_percent <= max_diff_percent;
+
+    // Keep the failing capture and diff next to the baseline for inspection
+    let mut artifacts = json!(null);
+    if !passed {
+        let actual_path = dir.join(format!("{}.actual.png", request.name));
+        let diff_path = dir.join(format!("{}.diff.png", request.name));
+        save_png(&actual, &actual_path)?;
+        save_png(&diff, &diff_path)?;
+        artifacts = json!({
+            "actual": actual_path.display().to_string(),
+            "diff": diff_path.display().to_string(),
+        });
+    }
+
+    let diff_image = if request.include_diff_image.unwrap_or(true) && differing > 0 {
+        Some(png_data_url(&diff)?)
+    } else {
+        None
+    };
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(json!({
+            "name": request.name,
+            "status": if passed { "passed" } else if size_matches { "failed" } else { "size_mismatch" },
+            "passed": passed,
+            "path": path.display().to_string(),
+            "baseline_size": { "width": baseline.width(), "height": baseline.height() },
+            "actual_size": { "width": actual.width(), "height": actual.height() },
+            "differing_pixels": differing,
+            "diff_percent": (diff_percent * 1000.0).round() / 1000.0,
+            "pixel_threshold": threshold,
+            "max_diff_percent": max_diff_percent,
+            "artifacts": artifacts,
+            "diff_image": diff_image,
+        })),
+        error: None,
+    })
+}
+
+const ELEMENT_RECT_JS: &str = r#"        const el = mcp.query(args.selector);
+        el.scrollIntoView({ block: 'nearest', inline: 'nearest' });
+        const r = el.getBoundingClientRect();
+        if (r.width === 0 || r.height === 0) throw new Error('Element has no size: ' + args.selector);
+        return {
+            x: r.left,
+            y: r.top,
+            width: r.width,
+            height: r.height,
+            viewport_width: window.innerWidth,
+            viewport_height: window.innerHeight
+        };"#;
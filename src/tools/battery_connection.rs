@@ -0,0 +1,311 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+/// Actions shared by `set_battery` and `set_connection`
+const OVERRIDE_ACTIONS: &[&str] = &["set", "clear", "get"];
+
+/// `NetworkInformation.effectiveType` values
+const EFFECTIVE_TYPES: &[&str] = &["slow-2g", "2g", "3g", "4g"];
+
+/// `NetworkInformation.type` values
+const CONNECTION_TYPES: &[&str] = &[
+    "bluetooth", "cellular", "ethernet", "none", "wifi", "wimax", "other", "unknown",
+];
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct SetBatteryRequest {
+    window_label: Option<String>,
+    /// "set" (default), "clear" or "get"
+    action: Option<String>,
+    /// Charge level from 0 to 1
+    level: Option<f64>,
+    charging: Option<bool>,
+    /// Seconds until full
+    charging_time: Option<f64>,
+    /// Seconds until empty
+    discharging_time: Option<f64>,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct SetConnectionRequest {
+    window_label: Option<String>,
+    /// "set" (default), "clear" or "get"
+    action: Option<String>,
+    effective_type: Option<String>,
+    /// Bandwidth estimate in Mbit/s
+    downlink: Option<f64>,
+    /// Round-trip time estimate in milliseconds
+    rtt: Option<f64>,
+    save_data: Option<bool>,
+    #[serde(rename = "type")]
+    connection_type: Option<String>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for overriding what `navigator.getBattery()` reports
+pub async fn handle_set_battery<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("set_battery", &payload)
+        .optional("action", FieldKind::OneOf(OVERRIDE_ACTIONS))
+        .optional("level", FieldKind::Number { min: Some(0.0), max: Some(1.0) })
+        .optional("charging", FieldKind::Bool)
+        .optional("charging_time", FieldKind::Number { min: Some(0.0), max: None })
+        .optional("discharging_time", FieldKind::Number { min: Some(0.0), max: None })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: SetBatteryRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for set_battery: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Battery override error",
+        &json!({
+            "action": request.action.as_deref().unwrap_or("set"),
+            "values": {
+                "level": request.level,
+                "charging": request.charging,
+                "chargingTime": request.charging_time,
+                "dischargingTime": request.discharging_time,
+            },
+        }),
+        SET_BATTERY_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "battery override",
+    )
+    .await
+}
+
+/// Handler function for overriding what `navigator.connection` reports
+pub async fn handle_set_connection<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("set_connection", &payload)
+        .optional("action", FieldKind::OneOf(OVERRIDE_ACTIONS))
+        .optional("effective_type", FieldKind::OneOf(EFFECTIVE_TYPES))
+        .optional("downlink", FieldKind::Number { min: Some(0.0), max: None })
+        .optional("rtt", FieldKind::Number { min: Some(0.0), max: None })
+        .optional("save_data", FieldKind::Bool)
+        .optional("type", FieldKind::OneOf(CONNECTION_TYPES))
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: SetConnectionRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for set_connection: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Connection override error",
+        &json!({
+            "action": request.action.as_deref().unwrap_or("set"),
+            "values": {
+                "effectiveType": request.effective_type,
+                "downlink": request.downlink,
+                "rtt": request.rtt,
+                "saveData": request.save_data,
+                "type": request.connection_type,
+            },
+        }),
+        SET_CONNECTION_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "connection override",
+    )
+    .await
+}
+
+/// When the webview has a real `BatteryManager`, the override shadows its getters with
+/// own properties, so objects the app already holds see the new values and receive the
+/// change events. Without the API (WebKit), a stand-in object is installed as
+/// `navigator.getBattery`. Omitted fields keep their current value, and overrides last
+/// until the page reloads.
+const SET_BATTERY_JS: &str = r#"        const state = window.__MCP_BATTERY__ || (window.__MCP_BATTERY__ = { manager: null, native: false, overridden: {} });
+        const FIELDS = { level: 'levelchange', charging: 'chargingchange', chargingTime: 'chargingtimechange', dischargingTime: 'dischargingtimechange' };
+
+        if (!state.manager) {
+            if (typeof navigator.getBattery === 'function') {
+                state.manager = await navigator.getBattery();
+                state.native = true;
+            } else {
+                // Stand-in with the same shape; on* handlers are called like native ones
+                const manager = Object.assign(new EventTarget(), { level: 1, charging: true, chargingTime: 0, dischargingTime: Infinity });
+                for (const type of Object.values(FIELDS)) {
+                    manager['on' + type] = null;
+                    manager.addEventListener(type, (event) => {
+                        if (typeof manager['on' + type] === 'function') manager['on' + type](event);
+                    });
+                }
+                const promise = Promise.resolve(manager);
+                Object.defineProperty(navigator, 'getBattery', { value: () => promise, configurable: true, writable: true });
+                state.manager = manager;
+            }
+        }
+
+        const manager = state.manager;
+        const snapshot = () => {
+            const current = {};
+            for (const field of Object.keys(FIELDS)) {
+                const value = manager[field];
+                current[field] = value === Infinity ? null : value;
+            }
+            return {
+                level: current.level,
+                charging: current.charging,
+                charging_time: current.chargingTime,
+                discharging_time: current.dischargingTime
+            };
+        };
+        const apply = (values) => {
+            const changed = [];
+            for (const [field, event] of Object.entries(FIELDS)) {
+                if (!(field in values)) continue;
+                const before = manager[field];
+                if (values[field] === undefined) {
+                    delete manager[field];
+                } else {
+                    Object.defineProperty(manager, field, { value: values[field], configurable: true, enumerable: true, writable: true });
+                }
+                if (manager[field] !== before) {
+                    changed.push(event);
+                    manager.dispatchEvent(new Event(event));
+                }
+            }
+            return changed;
+        };
+
+        if (args.action === 'get') {
+            return { native_api: state.native, overridden: Object.keys(state.overridden), battery: snapshot() };
+        }
+
+        if (args.action === 'clear') {
+            if (!state.native) {
+                // Remove the stand-in so the API is absent again, as on an unmodified page
+                delete navigator.getBattery;
+                window.__MCP_BATTERY__ = undefined;
+                return { cleared: true, native_api: false, events_dispatched: [], battery: null };
+            }
+            const restore = {};
+            for (const field of Object.keys(state.overridden)) restore[field] = undefined;
+            state.overridden = {};
+            const events = apply(restore);
+            return { cleared: true, native_api: true, events_dispatched: events, battery: snapshot() };
+        }
+
+        const values = {};
+        for (const [field, value] of Object.entries(args.values)) {
+            if (value === null) continue;
+            values[field] = value;
+            state.overridden[field] = value;
+        }
+        // The time for the direction the battery is not going in is unknown (Infinity)
+        if (args.values.charging !== null) {
+            const unknown = args.values.charging ? 'dischargingTime' : 'chargingTime';
+            if (args.values[unknown] === null) {
+                values[unknown] = Infinity;
+                state.overridden[unknown] = Infinity;
+            }
+        }
+        const events = apply(values);
+        return { native_api: state.native, overridden: Object.keys(state.overridden), events_dispatched: events, battery: snapshot() };"#;
+
+/// Same approach as the battery override: a real `NetworkInformation` object has its
+/// getters shadowed, otherwise a stand-in is defined as `navigator.connection`.
+const SET_CONNECTION_JS: &str = r#"        const state = window.__MCP_CONNECTION__ || (window.__MCP_CONNECTION__ = { connection: null, native: false, overridden: {} });
+        const FIELDS = ['effectiveType', 'downlink', 'rtt', 'saveData', 'type'];
+
+        if (!state.connection) {
+            if (navigator.connection) {
+                state.connection = navigator.connection;
+                state.native = true;
+            } else {
+                const connection = Object.assign(new EventTarget(), { effectiveType: '4g', downlink: 10, rtt: 50, saveData: false, type: 'unknown' });
+                connection.onchange = null;
+                connection.addEventListener('change', (event) => {
+                    if (typeof connection.onchange === 'function') connection.onchange(event);
+                });
+                Object.defineProperty(navigator, 'connection', { get: () => connection, configurable: true });
+                state.connection = connection;
+            }
+        }
+
+        const connection = state.connection;
+        const snapshot = () => ({
+            effective_type: connection.effectiveType,
+            downlink: connection.downlink,
+            rtt: connection.rtt,
+            save_data: connection.saveData,
+            type: connection.type === undefined ? null : connection.type
+        });
+        const apply = (values) => {
+            let changed = false;
+            for (const field of FIELDS) {
+                if (!(field in values)) continue;
+                const before = connection[field];
+                if (values[field] === undefined) {
+                    delete connection[field];
+                } else {
+                    Object.defineProperty(connection, field, { value: values[field], configurable: true, enumerable: true, writable: true });
+                }
+                if (connection[field] !== before) changed = true;
+            }
+            if (changed) connection.dispatchEvent(new Event('change'));
+            return changed;
+        };
+
+        if (args.action === 'get') {
+            return { native_api: state.native, overridden: Object.keys(state.overridden), connection: snapshot() };
+        }
+
+        if (args.action === 'clear') {
+            if (!state.native) {
+                delete navigator.connection;
+                window.__MCP_CONNECTION__ = undefined;
+                return { cleared: true, native_api: false, change_dispatched: false, connection: null };
+            }
+            const restore = {};
+            for (const field of Object.keys(state.overridden)) restore[field] = undefined;
+            state.overridden = {};
+            const changed = apply(restore);
+            return { cleared: true, native_api: true, change_dispatched: changed, connection: snapshot() };
+        }
+
+        const values = {};
+        for (const [field, value] of Object.entries(args.values)) {
+            if (value === null) continue;
+            values[field] = value;
+            state.overridden[field] = value;
+        }
+        const changed = apply(values);
+        return { native_api: state.native, overridden: Object.keys(state.overridden), change_dispatched: changed, connection: snapshot() };"#;
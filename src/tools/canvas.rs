@@ -0,0 +1,164 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+use log::info;
+
+use crate::error::Error;
+use crate::shared::ScreenshotParams;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, eval_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::take_screenshot::process_image;
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct CaptureCanvasRequest {
+    window_label: Option<String>,
+    selector: String,
+    /// "jpeg" (default, compressed like screenshots) or "png" (lossless)
+    format: Option<String>,
+    quality: Option<i32>,
+    max_width: Option<i32>,
+    max_size_mb: Option<f32>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for capturing the pixels of a `<canvas>` element
+pub async fn handle_capture_canvas<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("capture_canvas", &payload)
+        .required("selector", FieldKind::NonEmptyString)
+        .optional("format", FieldKind::OneOf(&["jpeg", "png"]))
+        .optional("quality", FieldKind::Integer { min: Some(1), max: Some(100) })
+        .optional("max_width", FieldKind::Integer { min: Some(1), max: None })
+        .optional("max_size_mb", FieldKind::Number { min: Some(0.0), max: None })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: CaptureCanvasRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for capture_canvas: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Canvas capture error",
+        &json!({ "selector": request.selector }),
+        CAPTURE_CANVAS_JS,
+    );
+
+    let canvas = match eval_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "canvas capture",
+    )
+    .await
+    {
+        Ok(canvas) => canvas,
+        Err(Error::Anyhow { message }) => {
+            return Ok(SocketResponse {
+                success: false,
+                data: None,
+                error: Some(message),
+            })
+        }
+        Err(e) => return Err(e),
+    };
+
+    // The webview always hands back lossless PNG; re-encode it on our side
+    let png_base64 = canvas
+        .get("data_url")
+        .and_then(|v| v.as_str())
+        .and_then(|url| url.split_once(','))
+        .map(|(_, data)| data)
+        .ok_or_else(|| Error::Anyhow { message: "Canvas capture returned no image data".to_string() })?;
+
+    let png_bytes = base64::decode(png_base64)
+        .map_err(|e| Error::serialization_error(format!("Invalid canvas image data: {}", e)))?;
+
+    let image = image::load_from_memory(&png_bytes)
+        .map_err(|e| Error::window_operation_failed("canvas decoding", format!("Failed to decode canvas image: {}", e)))?;
+
+    let format = request.format.clone().unwrap_or_else(|| "jpeg".to_string());
+
+    let data_url = if format == "png" {
+        encode_png(image, request.max_width)?
+    } else {
+        let params = ScreenshotParams {
+            window_label: Some(window_label.clone()),
+            quality: request.quality.map(|q| q as u8),
+            max_width: request.max_width,
+            max_size_mb: request.max_size_mb,
+            application_name: None,
+            target_dpr: None,
+            source_dpr: None,
+            lossless: false,
+            region: None,
+            format: None,
+        };
+        process_image(image, &params)?
+    };
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(json!({
+            "selector": request.selector,
+            "format": format,
+            "width": canvas.get("width"),
+            "height": canvas.get("height"),
+            "data": data_url,
+        })),
+        error: None,
+    })
+}
+
+/// Encode an image as a PNG data URL, scaling it down to `max_width` if needed
+fn encode_png(mut image: image::DynamicImage, max_width: Option<i32>) -> Result<String, Error> {
+    if let Some(max_width) = max_width.map(|w| w as u32) {
+        if image.width() > max_width {
+            let height = (image.height() as f32 * (max_width as f32 / image.width() as f32)) as u32;
+            info!("[TAURI_MCP] Resizing canvas capture to {}x{}", max_width, height);
+            image = image.resize(max_width, height, image::imageops::FilterType::Triangle);
+        }
+    }
+
+    let mut output_data = Vec::new();
+    image
+        .write_to(&mut std::io::Cursor::new(&mut output_data), image::ImageOutputFormat::Png)
+        .map_err(|e| Error::window_operation_failed("PNG encoding", format!("Failed to encode PNG: {}", e)))?;
+
+    Ok(format!("data:image/png;base64,{}", base64::encode(&output_data)))
+}
+
+/// Reads a canvas as PNG, turning the tainted-canvas SecurityError into an actionable message
+const CAPTURE_CANVAS_JS: &str = r#"        const canvas = mcp.query(args.selector);
+        if (!(canvas instanceof HTMLCanvasElement)) {
+            throw new Error('Element is not a <canvas>: ' + args.selector + ' is <' + canvas.tagName.toLowerCase() + '>');
+        }
+
+        // WebGL canvases without preserveDrawingBuffer are cleared after compositing,
+        // so read the pixels right after the next frame is drawn. The context type is
+        // deliberately not probed: getContext() on a fresh canvas would claim it.
+        await new Promise(resolve => requestAnimationFrame(resolve));
+
+        let dataUrl;
+        try {
+            dataUrl = canvas.toDataURL('image/png');
+        } catch (error) {
+            if (error && error.name === 'SecurityError') {
+                throw new Error('Canvas is tainted by cross-origin content and cannot be read; serve the images with CORS headers and crossOrigin="anonymous"');
+            }
+            throw error;
+        }
+
+        return {
+            width: canvas.width,
+            height: canvas.height,
+            data_url: dataUrl
+        };"#;
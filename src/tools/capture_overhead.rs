@@ -0,0 +1,112 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct CaptureOverheadRequest {
+    window_label: Option<String>,
+    /// Zero the counters after reading them, starting a new measurement window (default false)
+    reset: Option<bool>,
+    /// Share of wall time above which an active capture is recommended for disabling,
+    /// in percent (default 1)
+    threshold_percent: Option<f64>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for reporting how much time the injected capture shims spend on themselves
+pub async fn handle_get_capture_overhead<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_capture_overhead", &payload)
+        .optional("reset", FieldKind::Bool)
+        .optional("threshold_percent", FieldKind::Number { min: Some(0.0), max: Some(100.0) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: CaptureOverheadRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_capture_overhead: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Capture overhead error",
+        &json!({
+            "reset": request.reset.unwrap_or(false),
+            "threshold": request.threshold_percent.unwrap_or(1.0) / 100.0,
+        }),
+        CAPTURE_OVERHEAD_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "capture overhead",
+    )
+    .await
+}
+
+/// Reads `window.__MCP_CAPTURE_OVERHEAD__`, which the guest-js shims (network, console,
+/// csp, errors) and the probe-installed observers (live_regions, detached_nodes, reflows)
+/// fill by timing their own bookkeeping with `performance.now()`. Time spent in the
+/// wrapped call itself is never counted, and nested shims only count their self-time.
+/// Timer resolution is coarsened in webviews, so very cheap calls round to zero.
+const CAPTURE_OVERHEAD_JS: &str = r#"        const registry = window.__MCP_CAPTURE_OVERHEAD__;
+        // Probe-installed captures are running while their state object is on window
+        const PROBE_STATE = {
+            live_regions: '__MCP_LIVE_REGIONS__',
+            detached_nodes: '__MCP_DETACHED__',
+            reflows: '__MCP_REFLOW__'
+        };
+        const round = (value) => Math.round(value * 1000) / 1000;
+        const now = Date.now();
+
+        const captures = Object.entries(registry ? registry.captures : {}).map(([name, entry]) => {
+            const active = name in PROBE_STATE ? !!window[PROBE_STATE[name]] : entry.active;
+            const windowMs = Math.max(1, now - entry.installed_at_ms);
+            return {
+                capture: name,
+                active,
+                calls: entry.calls,
+                timed_sections: entry.timed_sections,
+                self_time_ms: round(entry.self_time_ms),
+                mean_per_call_ms: entry.calls > 0 ? round(entry.self_time_ms / entry.calls) : null,
+                max_section_ms: round(entry.max_ms),
+                measured_for_ms: windowMs,
+                share_of_wall_time: round(entry.self_time_ms / windowMs)
+            };
+        }).sort((a, b) => b.self_time_ms - a.self_time_ms);
+
+        const recommendDisable = captures
+            .filter(capture => capture.active && capture.share_of_wall_time >= args.threshold)
+            .map(capture => capture.capture);
+
+        if (args.reset && registry) {
+            Object.values(registry.captures).forEach(entry => {
+                entry.calls = 0;
+                entry.timed_sections = 0;
+                entry.self_time_ms = 0;
+                entry.max_ms = 0;
+                entry.installed_at_ms = now;
+            });
+        }
+
+        return {
+            captures,
+            active_count: captures.filter(capture => capture.active).length,
+            total_self_time_ms: round(captures.reduce((sum, capture) => sum + capture.self_time_ms, 0)),
+            recommend_disable: recommendDisable,
+            reset: !!(args.reset && registry),
+            note: registry ? null : 'No capture has recorded any work in this page yet'
+        };"#;
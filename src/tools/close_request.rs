@@ -0,0 +1,133 @@
+use serde_json::{json, Value};
+use std::sync::mpsc;
+use std::time::Duration;
+use tauri::{AppHandle, Manager, Runtime, WindowEvent};
+use log::info;
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, eval_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct SimulateCloseRequest {
+    window_label: Option<String>,
+    /// Go ahead with the close, like choosing "Leave" on the prompt (default false)
+    confirm: Option<bool>,
+    /// How long to wait for the window to close after confirming (default 2000)
+    close_timeout_ms: Option<u64>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for running a window's close flow with its beforeunload guard
+pub async fn handle_simulate_close_request<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("simulate_close_request", &payload)
+        .optional("confirm", FieldKind::Bool)
+        .optional("close_timeout_ms", FieldKind::Integer { min: Some(0), max: Some(20_000) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: SimulateCloseRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for simulate_close_request: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script("Close request error", &json!({}), BEFOREUNLOAD_JS);
+    let guard = eval_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "beforeunload check",
+    )
+    .await?;
+    let blocked = guard.get("blocked").and_then(|v| v.as_bool()).unwrap_or(false);
+    let confirm = request.confirm.unwrap_or(false);
+
+    if !confirm {
+        return Ok(SocketResponse {
+            success: true,
+            data: Some(json!({
+                "window_label": window_label,
+                "beforeunload": guard,
+                "prompt_shown": blocked,
+                "confirmed": false,
+                "close_attempted": false,
+                "closed": false,
+                "would_close_without_prompt": !blocked,
+            })),
+            error: None,
+        });
+    }
+
+    // The native close request lets the app's CloseRequested handlers veto it
+    let window = app
+        .get_webview_window(&window_label)
+        .ok_or_else(|| Error::window_not_found(&window_label))?;
+    let (tx, rx) = mpsc::channel();
+    window.on_window_event(move |event| {
+        if let WindowEvent::Destroyed = event {
+            let _ = tx.send(());
+        }
+    });
+    info!("[TAURI_MCP] Requesting close of window {} (beforeunload blocked: {})", window_label, blocked);
+    window
+        .close()
+        .map_err(|e| Error::window_operation_failed("close window", e.to_string()))?;
+
+    // Wait for the window to be destroyed on a blocking thread, not the async runtime
+    let close_timeout_ms = request.close_timeout_ms.unwrap_or(2000);
+    let destroyed = tauri::async_runtime::spawn_blocking(move || {
+        rx.recv_timeout(Duration::from_millis(close_timeout_ms)).is_ok()
+    })
+    .await
+    .unwrap_or(false);
+    let closed = destroyed || app.get_webview_window(&window_label).is_none();
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(json!({
+            "window_label": window_label,
+            "beforeunload": guard,
+            "prompt_shown": blocked,
+            "confirmed": true,
+            "close_attempted": true,
+            "closed": closed,
+            "prevented_by_app": !closed,
+        })),
+        error: None,
+    })
+}
+
+/// Webviews do not run `beforeunload` when the native window closes, so the event is
+/// dispatched here the way a navigation would: as a cancelable `BeforeUnloadEvent`,
+/// where either `preventDefault()` or a non-empty `returnValue` (including a string
+/// returned from `onbeforeunload`) asks for the prompt. Browsers no longer show custom
+/// prompt text, but whatever the page supplied is reported.
+const BEFOREUNLOAD_JS: &str = r#"        let event;
+        try {
+            event = document.createEvent('BeforeUnloadEvent');
+            event.initEvent('beforeunload', false, true);
+        } catch (e) {
+            event = new Event('beforeunload', { cancelable: true });
+        }
+        const hasHandler = typeof window.onbeforeunload === 'function';
+        window.dispatchEvent(event);
+
+        const returnValue = typeof event.returnValue === 'string' ? event.returnValue : null;
+        const blocked = event.defaultPrevented || (returnValue !== null && returnValue !== '');
+        return {
+            blocked,
+            prompt_text: returnValue || null,
+            default_prevented: event.defaultPrevented,
+            onbeforeunload_property: hasHandler,
+            event_type: event.constructor.name
+        };"#;
@@ -0,0 +1,215 @@
+use serde_json::{json, Map, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::TauriMcpExt;
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, eval_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct DiffComputedStyleRequest {
+    window_label: Option<String>,
+    /// Id the snapshot is stored under
+    snapshot_id: String,
+    /// "auto" (default) snapshots when no snapshot exists and diffs otherwise
+    action: Option<String>,
+    /// Element to capture; a diff defaults to the snapshot's selector
+    selector: Option<String>,
+    /// Pseudo-element such as `::before`
+    pseudo_element: Option<String>,
+    /// Only compare these properties
+    properties: Option<Vec<String>>,
+    /// Replace the snapshot with the current styles after diffing (default false)
+    update_snapshot: Option<bool>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for snapshotting an element's computed style and diffing against it later
+pub async fn handle_diff_computed_style<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    let validator = PayloadValidator::new("diff_computed_style", &payload)
+        .optional("action", FieldKind::OneOf(&["auto", "snapshot", "diff", "clear"]));
+    let action = validator
+        .field("action")
+        .and_then(|v| v.as_str())
+        .unwrap_or("auto")
+        .to_string();
+    validator
+        .required("snapshot_id", FieldKind::NonEmptyString)
+        .required_if(action == "snapshot", "selector", FieldKind::NonEmptyString)
+        .optional("selector", FieldKind::NonEmptyString)
+        .optional("pseudo_element", FieldKind::NonEmptyString)
+        .optional("properties", FieldKind::Array)
+        .optional("update_snapshot", FieldKind::Bool)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: DiffComputedStyleRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for diff_computed_style: {}", e)))?;
+
+    let mcp = app.tauri_mcp();
+    let snapshot_id = request.snapshot_id.clone();
+
+    if action == "clear" {
+        return Ok(SocketResponse {
+            success: true,
+            data: Some(json!({
+                "snapshot_id": snapshot_id,
+                "removed": mcp.remove_style_snapshot(&snapshot_id),
+            })),
+            error: None,
+        });
+    }
+
+    let stored = mcp.style_snapshot(&snapshot_id);
+    let diffing = match action.as_str() {
+        "diff" => true,
+        "snapshot" => false,
+        _ => stored.is_some(),
+    };
+
+    if diffing && stored.is_none() {
+        return Err(Error::Anyhow {
+            message: format!(
+                "No computed style snapshot stored with id '{}'; take one first with action 'snapshot'",
+                snapshot_id
+            ),
+        });
+    }
+
+    let stored_field = |name: &str| {
+        stored
+            .as_ref()
+            .filter(|_| diffing)
+            .and_then(|snapshot| snapshot.get(name))
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+    };
+    let selector = request
+        .selector
+        .clone()
+        .or_else(|| stored_field("selector"))
+        .ok_or_else(|| Error::invalid_parameter("selector", "a CSS selector for the first snapshot", "nothing"))?;
+    let pseudo_element = request.pseudo_element.clone().or_else(|| stored_field("pseudo_element"));
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Computed style error",
+        &json!({
+            "selector": selector,
+            "pseudo_element": pseudo_element,
+        }),
+        COMPUTED_STYLE_JS,
+    );
+
+    let captured = match eval_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "computed style capture",
+    )
+    .await
+    {
+        Ok(captured) => captured,
+        Err(Error::Anyhow { message }) => {
+            return Ok(SocketResponse {
+                success: false,
+                data: None,
+                error: Some(message),
+            })
+        }
+        Err(e) => return Err(e),
+    };
+
+    let current = captured.get("styles").cloned().unwrap_or_else(|| json!({}));
+    let snapshot = json!({
+        "selector": selector,
+        "pseudo_element": pseudo_element,
+        "element": captured.get("element"),
+        "styles": current,
+    });
+
+    if !diffing {
+        let property_count = current.as_object().map(|styles| styles.len()).unwrap_or(0);
+        mcp.set_style_snapshot(&snapshot_id, snapshot);
+        return Ok(SocketResponse {
+            success: true,
+            data: Some(json!({
+                "snapshot_id": snapshot_id,
+                "saved": true,
+                "selector": selector,
+                "element": captured.get("element"),
+                "property_count": property_count,
+            })),
+            error: None,
+        });
+    }
+
+    let empty = Map::new();
+    let before = stored
+        .as_ref()
+        .and_then(|snapshot| snapshot.get("styles"))
+        .and_then(|styles| styles.as_object())
+        .unwrap_or(&empty);
+    let after = current.as_object().unwrap_or(&empty);
+
+    let mut names: Vec<&String> = match &request.properties {
+        Some(allowlist) => allowlist.iter().collect(),
+        None => before.keys().chain(after.keys().filter(|k| !before.contains_key(*k))).collect(),
+    };
+    names.sort();
+    names.dedup();
+
+    let mut changes = Map::new();
+    for name in names {
+        let old = before.get(name).cloned().unwrap_or(Value::Null);
+        let new = after.get(name).cloned().unwrap_or(Value::Null);
+        if old != new {
+            changes.insert(name.clone(), json!({ "before": old, "after": new }));
+        }
+    }
+
+    let updated = request.update_snapshot.unwrap_or(false);
+    if updated {
+        mcp.set_style_snapshot(&snapshot_id, snapshot);
+    }
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(json!({
+            "snapshot_id": snapshot_id,
+            "selector": selector,
+            "element": captured.get("element"),
+            "identical": changes.is_empty(),
+            "changed_count": changes.len(),
+            "changes": changes,
+            "snapshot_updated": updated,
+        })),
+        error: None,
+    })
+}
+
+/// Reads every property the engine exposes on the computed style, including custom properties
+const COMPUTED_STYLE_JS: &str = r#"        const el = mcp.query(args.selector);
+        const computed = getComputedStyle(el, args.pseudo_element || null);
+
+        const styles = {};
+        for (let i = 0; i < computed.length; i++) {
+            const name = computed[i];
+            styles[name] = computed.getPropertyValue(name).trim();
+        }
+
+        return {
+            element: mcp.cssPath(el),
+            styles
+        };"#;
@@ -7,6 +7,7 @@ use tauri::{AppHandle, Emitter, Listener, Manager, Runtime};
 
 use crate::error::Error;
 use crate::socket_server::SocketResponse;
+use crate::tools::validation::{FieldKind, PayloadValidator, EVICTION_POLICIES};
 
 // Console log level enumeration
 #[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash)]
@@ -16,6 +17,8 @@ pub enum LogLevel {
     Info,
     Warn,
     Error,
+    /// Content-Security-Policy violations reported by the webview
+    Csp,
 }
 
 impl LogLevel {
@@ -25,6 +28,7 @@ impl LogLevel {
             "info" => Some(LogLevel::Info),
             "warn" => Some(LogLevel::Warn),
             "error" => Some(LogLevel::Error),
+            "csp" => Some(LogLevel::Csp),
             "all" => None, // Special value to indicate all levels
             _ => None,
         }
@@ -74,7 +78,7 @@ impl From<mpsc::RecvTimeoutError> for ConsoleLogsError {
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct ConsoleLogsRequest {
     window_label: Option<String>,
-    level: Option<String>, // "debug", "info", "warn", "error", or "all"
+    level: Option<String>, // "debug", "info", "warn", "error", "csp", or "all"
     start_time_ms: Option<u64>,
     end_time_ms: Option<u64>,
     limit: Option<usize>,
@@ -95,6 +99,12 @@ pub struct ConsoleLogsResponse {
     pub logs: Vec<ConsoleLogEntry>,
     pub total_count: usize,
     pub returned_count: usize,
+    /// Eviction policy of the capture buffer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eviction_policy: Option<String>,
+    /// Entries evicted from the capture buffer so far
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evicted_count: Option<u64>,
 }
 
 /// Handler function for retrieving console logs
@@ -147,8 +157,18 @@ pub async fn handle_inject_console_capture<R: Runtime>(
     #[derive(serde::Deserialize)]
     pub struct InjectionRequest {
         window_label: Option<String>,
+        /// "fifo" (default), "keep_errors" or "sampled"
+        eviction_policy: Option<String>,
+        /// Capacity of the capture buffer (default 1000)
+        max_entries: Option<usize>,
     }
 
+    PayloadValidator::new("inject_console_capture", &payload)
+        .optional("window_label", FieldKind::String)
+        .optional("eviction_policy", FieldKind::OneOf(EVICTION_POLICIES))
+        .optional("max_entries", FieldKind::Integer { min: Some(1), max: None })
+        .finish()?;
+
     let request: InjectionRequest = serde_json::from_value(payload)
         .map_err(|e| Error::serialization_error(format!("Invalid payload for injection: {}", e)))?;
 
@@ -163,7 +183,13 @@ pub async fn handle_inject_console_capture<R: Runtime>(
 
     // Send injection event to the window
     window
-        .emit("inject-console-capture", ())
+        .emit(
+            "inject-console-capture",
+            serde_json::json!({
+                "eviction_policy": request.eviction_policy,
+                "max_entries": request.max_entries,
+            }),
+        )
         .map_err(|e| Error::communication_error_with_context(
             "Failed to emit injection event",
             format!("window: {}, error: {}", window_label, e),
@@ -238,10 +264,19 @@ async fn retrieve_console_logs<R: Runtime>(
 
             let returned_count = logs.len();
 
+            let eviction_policy = response
+                .get("eviction_policy")
+                .and_then(|p| p.as_str())
+                .map(|p| p.to_string());
+
+            let evicted_count = response.get("evicted_count").and_then(|c| c.as_u64());
+
             Ok(ConsoleLogsResponse {
                 logs,
                 total_count,
                 returned_count,
+                eviction_policy,
+                evicted_count,
             })
         }
         Err(e) => Err(e.into()),
@@ -0,0 +1,240 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ContentEditableRequest {
+    window_label: Option<String>,
+    /// Editable element (or an element inside the editable region)
+    selector: String,
+    /// Plain text to insert
+    text: Option<String>,
+    /// HTML to insert
+    html: Option<String>,
+    /// "replace" (default) or "append"
+    mode: Option<String>,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct DesignModeRequest {
+    window_label: Option<String>,
+    /// Turn design mode on or off; omit to only read the current state
+    enabled: Option<bool>,
+    timeout_ms: Option<u64>,
+}
+
+fn parse_request(command: &str, payload: Value, setting: bool) -> Result<(ContentEditableRequest, String), Error> {
+    PayloadValidator::new(command, &payload)
+        .required("selector", FieldKind::NonEmptyString)
+        .optional("text", FieldKind::String)
+        .optional("html", FieldKind::String)
+        .optional("mode", FieldKind::OneOf(&["replace", "append"]))
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: ContentEditableRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for {}: {}", command, e)))?;
+
+    if setting && request.text.is_some() == request.html.is_some() {
+        return Err(Error::invalid_parameter(
+            "text/html",
+            "exactly one of 'text' or 'html'",
+            if request.text.is_some() { "both" } else { "neither" },
+        ));
+    }
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    Ok((request, window_label))
+}
+
+/// Handler function for reading a contenteditable region
+pub async fn handle_get_contenteditable<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    let (request, window_label) = parse_request("get_contenteditable", payload, false)?;
+
+    let js_code = build_probe_script(
+        "Contenteditable error",
+        &json!({ "selector": request.selector, "write": false }),
+        CONTENTEDITABLE_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "contenteditable read",
+    )
+    .await
+}
+
+/// Handler function for replacing or appending to a contenteditable region
+pub async fn handle_set_contenteditable<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    let (request, window_label) = parse_request("set_contenteditable", payload, true)?;
+
+    let js_code = build_probe_script(
+        "Contenteditable error",
+        &json!({
+            "selector": request.selector,
+            "write": true,
+            "text": request.text,
+            "html": request.html,
+            "mode": request.mode.clone().unwrap_or_else(|| "replace".to_string()),
+        }),
+        CONTENTEDITABLE_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "contenteditable update",
+    )
+    .await
+}
+
+/// Handler function for reading or toggling `document.designMode`
+pub async fn handle_set_design_mode<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("set_design_mode", &payload)
+        .optional("enabled", FieldKind::Bool)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: DesignModeRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for set_design_mode: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Design mode error",
+        &json!({ "enabled": request.enabled }),
+        DESIGN_MODE_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "design mode",
+    )
+    .await
+}
+
+/// Inserts through `execCommand` first so the editor sees the same beforeinput/input
+/// events and undo entry as typing; falls back to a Range edit plus a synthetic
+/// `input` event when the command is unavailable or refused
+const CONTENTEDITABLE_JS: &str = r#"        const el = mcp.query(args.selector);
+        if (!el.isContentEditable) {
+            throw new Error('Element is not editable (contenteditable is not set or is "false"): ' + args.selector);
+        }
+        // Edit the whole editing host even when the selector matched a child
+        let host = el;
+        while (host.parentElement && host.parentElement.isContentEditable) host = host.parentElement;
+
+        const read = () => ({
+            selector: mcp.cssPath(host),
+            html: host.innerHTML,
+            text: host.innerText,
+            length: host.innerText.length
+        });
+
+        if (!args.write) {
+            return { ...read(), plaintext_only: host.getAttribute('contenteditable') === 'plaintext-only' };
+        }
+
+        const before = read();
+        host.focus();
+        const selection = window.getSelection();
+        const range = document.createRange();
+        range.selectNodeContents(host);
+        if (args.mode === 'append') range.collapse(false);
+        selection.removeAllRanges();
+        selection.addRange(range);
+
+        const isHtml = typeof args.html === 'string';
+        const content = isHtml ? args.html : args.text;
+        let method = 'execCommand';
+        let ok = false;
+        try {
+            if (content === '' && args.mode === 'replace') {
+                ok = document.execCommand('delete', false);
+            } else {
+                ok = document.execCommand(isHtml ? 'insertHTML' : 'insertText', false, content);
+            }
+        } catch (error) {
+            ok = false;
+        }
+
+        if (!ok) {
+            method = 'range';
+            const active = selection.rangeCount > 0 ? selection.getRangeAt(0) : range;
+            active.deleteContents();
+            let fragment;
+            if (isHtml) {
+                fragment = active.createContextualFragment(content);
+            } else {
+                fragment = document.createDocumentFragment();
+                fragment.appendChild(document.createTextNode(content));
+            }
+            const last = fragment.lastChild;
+            active.insertNode(fragment);
+            if (last) {
+                active.setStartAfter(last);
+                active.collapse(true);
+                selection.removeAllRanges();
+                selection.addRange(active);
+            }
+            host.dispatchEvent(new InputEvent('input', {
+                bubbles: true,
+                inputType: isHtml ? 'insertFromPaste' : 'insertText',
+                data: isHtml ? null : content
+            }));
+        }
+
+        const after = read();
+        return {
+            ...after,
+            mode: args.mode,
+            method,
+            changed: before.html !== after.html,
+            previous_text: before.text
+        };"#;
+
+/// Design mode applies to the document only; same-origin iframes keep their own setting
+const DESIGN_MODE_JS: &str = r#"        const previous = document.designMode === 'on';
+        if (typeof args.enabled === 'boolean') {
+            document.designMode = args.enabled ? 'on' : 'off';
+        }
+        const enabled = document.designMode === 'on';
+        return {
+            enabled,
+            previous,
+            changed: enabled !== previous,
+            design_mode: document.designMode
+        };"#;
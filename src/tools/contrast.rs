@@ -0,0 +1,188 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct CheckContrastRequest {
+    window_label: Option<String>,
+    /// Elements to check; every match of each selector is checked
+    selectors: Option<Vec<String>>,
+    /// Element to scan for text when no selectors are given; defaults to `body`
+    root_selector: Option<String>,
+    /// "AA" (default) or "AAA"
+    level: Option<String>,
+    /// Return passing elements as well (default false)
+    include_passing: Option<bool>,
+    /// Maximum number of text elements to check (default 500)
+    max_elements: Option<usize>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for checking WCAG text contrast
+pub async fn handle_check_contrast<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("check_contrast", &payload)
+        .optional("selectors", FieldKind::Array)
+        .optional("root_selector", FieldKind::NonEmptyString)
+        .optional("level", FieldKind::OneOf(&["AA", "AAA"]))
+        .optional("include_passing", FieldKind::Bool)
+        .optional("max_elements", FieldKind::Integer { min: Some(1), max: Some(10_000) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: CheckContrastRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for check_contrast: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Contrast check error",
+        &json!({
+            "selectors": request.selectors,
+            "root_selector": request.root_selector,
+            "level": request.level.clone().unwrap_or_else(|| "AA".to_string()),
+            "include_passing": request.include_passing.unwrap_or(false),
+            "max_elements": request.max_elements.unwrap_or(500),
+        }),
+        CHECK_CONTRAST_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "contrast check",
+    )
+    .await
+}
+
+/// Composites translucent backgrounds up the ancestor chain onto white. Background
+/// images and gradients cannot be resolved, so those results are marked indeterminate.
+const CHECK_CONTRAST_JS: &str = r#"        // Let the engine normalize any color syntax (oklch, color-mix, named) to sRGB bytes
+        const canvas = document.createElement('canvas');
+        canvas.width = canvas.height = 1;
+        const ctx = canvas.getContext('2d', { willReadFrequently: true });
+        const parse = (value) => {
+            const m = /^rgba?\(\s*([\d.]+)[\s,]+([\d.]+)[\s,]+([\d.]+)(?:\s*[,/]\s*([\d.]+%?))?\s*\)$/.exec(value);
+            if (m) {
+                let a = m[4] === undefined ? 1 : (m[4].endsWith('%') ? parseFloat(m[4]) / 100 : parseFloat(m[4]));
+                return { r: +m[1], g: +m[2], b: +m[3], a };
+            }
+            ctx.clearRect(0, 0, 1, 1);
+            ctx.fillStyle = '#000';
+            ctx.fillStyle = value;
+            ctx.fillRect(0, 0, 1, 1);
+            const [r, g, b, a] = ctx.getImageData(0, 0, 1, 1).data;
+            return { r, g, b, a: a / 255 };
+        };
+        const blend = (top, bottom) => {
+            const a = top.a + bottom.a * (1 - top.a);
+            if (a === 0) return { r: 0, g: 0, b: 0, a: 0 };
+            const mix = (t, b) => (t * top.a + b * bottom.a * (1 - top.a)) / a;
+            return { r: mix(top.r, bottom.r), g: mix(top.g, bottom.g), b: mix(top.b, bottom.b), a };
+        };
+        const luminance = (c) => {
+            const ch = (v) => { v /= 255; return v <= 0.03928 ? v / 12.92 : Math.pow((v + 0.055) / 1.055, 2.4); };
+            return 0.2126 * ch(c.r) + 0.7152 * ch(c.g) + 0.0722 * ch(c.b);
+        };
+        const ratio = (a, b) => {
+            const [l1, l2] = [luminance(a), luminance(b)].sort((x, y) => y - x);
+            return (l1 + 0.05) / (l2 + 0.05);
+        };
+        const hex = (c) => '#' + [c.r, c.g, c.b].map(v => Math.round(v).toString(16).padStart(2, '0')).join('');
+
+        const background = (el) => {
+            const layers = [];
+            let indeterminate = null;
+            for (let node = el; node; node = node.parentElement) {
+                const style = getComputedStyle(node);
+                if (style.backgroundImage && style.backgroundImage !== 'none' && !indeterminate) {
+                    indeterminate = 'background image or gradient on ' + mcp.cssPath(node);
+                }
+                const color = parse(style.backgroundColor);
+                const opacity = parseFloat(style.opacity);
+                if (opacity < 1) color.a *= opacity;
+                if (color.a > 0) layers.push(color);
+                if (color.a >= 1) break;
+            }
+            let result = { r: 255, g: 255, b: 255, a: 1 };
+            for (let i = layers.length - 1; i >= 0; i--) result = blend(layers[i], result);
+            return { color: result, indeterminate };
+        };
+
+        const hasOwnText = (el) => Array.from(el.childNodes).some(n => n.nodeType === Node.TEXT_NODE && n.textContent.trim().length > 0);
+
+        let elements;
+        if (Array.isArray(args.selectors) && args.selectors.length > 0) {
+            elements = args.selectors.flatMap(selector => {
+                const matches = Array.from(document.querySelectorAll(selector));
+                if (matches.length === 0) throw new Error('No element matches selector: ' + selector);
+                return matches;
+            });
+        } else {
+            const root = args.root_selector ? mcp.query(args.root_selector) : document.body;
+            elements = [root, ...root.querySelectorAll('*')].filter(el => hasOwnText(el) || ['INPUT', 'TEXTAREA', 'SELECT', 'BUTTON'].includes(el.tagName));
+        }
+        elements = elements.filter(el => mcp.isVisible(el));
+        const truncated = elements.length > args.max_elements;
+        elements = elements.slice(0, args.max_elements);
+
+        const results = [];
+        let failures = 0;
+        let indeterminateCount = 0;
+        for (const el of elements) {
+            const style = getComputedStyle(el);
+            const fontSize = parseFloat(style.fontSize);
+            const bold = parseInt(style.fontWeight, 10) >= 700;
+            // WCAG large text: 18pt (24px), or 14pt (18.66px) bold
+            const large = fontSize >= 24 || (bold && fontSize >= 18.66);
+            const required = args.level === 'AAA' ? (large ? 4.5 : 7) : (large ? 3 : 4.5);
+
+            const bg = background(el);
+            let fg = parse(style.color);
+            const opacity = parseFloat(style.opacity);
+            if (opacity < 1) fg.a *= opacity;
+            fg = blend(fg, bg.color);
+
+            const value = Math.round(ratio(fg, bg.color) * 100) / 100;
+            const passes = value >= required;
+            if (!passes) failures += 1;
+            if (bg.indeterminate) indeterminateCount += 1;
+            if (passes && !args.include_passing) continue;
+
+            results.push({
+                selector: mcp.cssPath(el),
+                text: (el.innerText || el.value || '').replace(/\s+/g, ' ').trim().substring(0, 60),
+                foreground: hex(fg),
+                background: hex(bg.color),
+                ratio: value,
+                required,
+                passes,
+                large_text: large,
+                font_size_px: fontSize,
+                font_weight: style.fontWeight,
+                indeterminate: bg.indeterminate
+            });
+        }
+
+        results.sort((a, b) => a.ratio - b.ratio);
+        return {
+            level: args.level,
+            checked: elements.length,
+            failures,
+            indeterminate: indeterminateCount,
+            truncated,
+            results
+        };"#;
@@ -0,0 +1,132 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, eval_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct GetCspRequest {
+    window_label: Option<String>,
+    /// Re-request the current document to read the `Content-Security-Policy` headers (default true)
+    include_headers: Option<bool>,
+    /// Maximum number of recorded violations to return, newest last (default 50)
+    violation_limit: Option<usize>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for reporting the active Content-Security-Policy and recent violations
+pub async fn handle_get_csp<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_csp", &payload)
+        .optional("include_headers", FieldKind::Bool)
+        .optional("violation_limit", FieldKind::UINT)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: GetCspRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_csp: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "CSP inspection error",
+        &json!({
+            "include_headers": request.include_headers.unwrap_or(true),
+            "violation_limit": request.violation_limit.unwrap_or(50),
+        }),
+        GET_CSP_JS,
+    );
+
+    let mut page = match eval_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "CSP inspection",
+    )
+    .await
+    {
+        Ok(page) => page,
+        Err(Error::Anyhow { message }) => {
+            return Ok(SocketResponse {
+                success: false,
+                data: None,
+                error: Some(message),
+            })
+        }
+        Err(e) => return Err(e),
+    };
+
+    // Tauri injects the configured policy into its own responses, so report it
+    // even when the header could not be read back from the webview
+    let security = &app.config().app.security;
+    if let Some(object) = page.as_object_mut() {
+        object.insert(
+            "tauri_config".to_string(),
+            json!({
+                "csp": security.csp.as_ref().map(|csp| csp.to_string()),
+                "dev_csp": security.dev_csp.as_ref().map(|csp| csp.to_string()),
+            }),
+        );
+    }
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(page),
+        error: None,
+    })
+}
+
+const GET_CSP_JS: &str = r#"        const metaPolicies = Array.from(document.querySelectorAll('meta[http-equiv]'))
+            .filter(meta => /^content-security-policy(-report-only)?$/i.test(meta.httpEquiv))
+            .map(meta => ({
+                report_only: /report-only$/i.test(meta.httpEquiv),
+                policy: meta.content
+            }));
+
+        let headerPolicies = null;
+        let headerError = null;
+        if (args.include_headers) {
+            try {
+                const response = await fetch(location.href, { method: 'GET', cache: 'no-store', credentials: 'same-origin' });
+                headerPolicies = [];
+                const enforced = response.headers.get('content-security-policy');
+                const reportOnly = response.headers.get('content-security-policy-report-only');
+                if (enforced) headerPolicies.push({ report_only: false, policy: enforced });
+                if (reportOnly) headerPolicies.push({ report_only: true, policy: reportOnly });
+            } catch (error) {
+                headerError = String(error && error.message || error);
+            }
+        }
+
+        const parse = (policy) => {
+            const directives = {};
+            policy.split(';').map(part => part.trim()).filter(Boolean).forEach(part => {
+                const [name, ...values] = part.split(/\s+/);
+                directives[name.toLowerCase()] = values;
+            });
+            return directives;
+        };
+
+        const policies = metaPolicies.map(p => ({ source: 'meta', ...p }))
+            .concat((headerPolicies || []).map(p => ({ source: 'header', ...p })))
+            .map(p => ({ ...p, directives: parse(p.policy) }));
+
+        const violations = window.__MCP_CSP_VIOLATIONS__;
+        return {
+            url: location.href,
+            policies,
+            header_error: headerError,
+            violation_tracking: Array.isArray(violations),
+            violations: Array.isArray(violations) ? violations.slice(-args.violation_limit) : [],
+            violation_count: Array.isArray(violations) ? violations.length : 0
+        };"#;
@@ -0,0 +1,200 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct GetCssCoverageRequest {
+    window_label: Option<String>,
+    /// Maximum number of stylesheets to report (default 50)
+    max_sheets: Option<usize>,
+    /// Unused selectors sampled per stylesheet (default 20)
+    max_unused_per_sheet: Option<usize>,
+    /// Count rules in `@media` blocks that do not currently apply as unused (default false)
+    include_inactive_media: Option<bool>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for reporting stylesheet rules that match no element
+pub async fn handle_get_css_coverage<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_css_coverage", &payload)
+        .optional("max_sheets", FieldKind::Integer { min: Some(1), max: Some(500) })
+        .optional("max_unused_per_sheet", FieldKind::Integer { min: Some(0), max: Some(500) })
+        .optional("include_inactive_media", FieldKind::Bool)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: GetCssCoverageRequest = serde_json::from_value(payload).map_err(|e| {
+        Error::serialization_error(format!("Invalid payload for get_css_coverage: {}", e))
+    })?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "CSS coverage error",
+        &json!({
+            "max_sheets": request.max_sheets.unwrap_or(50),
+            "max_unused_per_sheet": request.max_unused_per_sheet.unwrap_or(20),
+            "include_inactive_media": request.include_inactive_media.unwrap_or(false),
+        }),
+        GET_CSS_COVERAGE_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "CSS coverage",
+    )
+    .await
+}
+
+/// A rule counts as used when any selector in its list matches an element right now.
+/// Interaction pseudo-classes (`:hover`, `:focus`, ...) and pseudo-elements are
+/// stripped before matching so that `a:hover` is judged by whether any `a` exists;
+/// such rules are also counted as `state_dependent`. Rules inside `@media` blocks that
+/// do not currently apply are reported separately unless `include_inactive_media`.
+const GET_CSS_COVERAGE_JS: &str = r#"        const DYNAMIC_PSEUDO = /:(?:hover|active|focus|focus-visible|focus-within|visited|target|target-within)\b/g;
+        const PSEUDO_ELEMENT = /::?(?:before|after|first-line|first-letter|placeholder|selection|marker|backdrop|file-selector-button|cue|part\([^)]*\)|slotted\([^)]*\)|-webkit-[\w-]+|-moz-[\w-]+)(?![\w-])/g;
+
+        // Split a selector list on top-level commas (not inside parens, brackets or quotes)
+        const splitSelectors = (text) => {
+            const parts = [];
+            let depth = 0;
+            let quote = null;
+            let current = '';
+            for (const ch of text) {
+                if (quote) {
+                    if (ch === quote) quote = null;
+                } else if (ch === '"' || ch === "'") {
+                    quote = ch;
+                } else if (ch === '(' || ch === '[') {
+                    depth += 1;
+                } else if (ch === ')' || ch === ']') {
+                    depth -= 1;
+                } else if (ch === ',' && depth === 0) {
+                    parts.push(current.trim());
+                    current = '';
+                    continue;
+                }
+                current += ch;
+            }
+            if (current.trim()) parts.push(current.trim());
+            return parts;
+        };
+
+        const toMatchable = (selector) => {
+            let stripped = selector.replace(PSEUDO_ELEMENT, '').replace(DYNAMIC_PSEUDO, '').trim();
+            if (stripped === '' || /[\s>+~(]$/.test(stripped)) stripped += '*';
+            return stripped;
+        };
+
+        const matches = (selector) => {
+            try {
+                return document.querySelector(selector) !== null;
+            } catch (error) {
+                return null;
+            }
+        };
+
+        const sheets = [...document.styleSheets, ...(document.adoptedStyleSheets || [])];
+        const totals = { sheets: sheets.length, rules: 0, used: 0, unused: 0, state_dependent: 0, inactive_media: 0, unsupported: 0, inaccessible_sheets: 0 };
+        const reports = [];
+
+        sheets.forEach((sheet, index) => {
+            const owner = sheet.ownerNode;
+            const report = {
+                source: sheet.href || (owner ? 'inline <style> ' + mcp.cssPath(owner) : 'adopted stylesheet #' + index),
+                disabled: sheet.disabled,
+                rules: 0,
+                used: 0,
+                unused: 0,
+                state_dependent: 0,
+                inactive_media: 0,
+                unsupported: 0,
+                unused_selectors: [],
+                unused_selectors_truncated: false
+            };
+
+            let rules;
+            try {
+                rules = sheet.cssRules;
+            } catch (error) {
+                // Cross-origin sheets without CORS headers cannot be read
+                totals.inaccessible_sheets += 1;
+                report.inaccessible = true;
+                if (reports.length < args.max_sheets) reports.push(report);
+                return;
+            }
+
+            const visit = (list, mediaActive) => {
+                for (const rule of list) {
+                    if (rule instanceof CSSStyleRule) {
+                        report.rules += 1;
+                        const selectors = splitSelectors(rule.selectorText);
+                        const stateDependent = selectors.some(sel => {
+                            DYNAMIC_PSEUDO.lastIndex = 0;
+                            return DYNAMIC_PSEUDO.test(sel);
+                        });
+                        if (stateDependent) report.state_dependent += 1;
+
+                        if (!mediaActive && !args.include_inactive_media) {
+                            report.inactive_media += 1;
+                        } else {
+                            const results = selectors.map(sel => matches(toMatchable(sel)));
+                            if (results.some(result => result === true)) {
+                                report.used += 1;
+                            } else if (results.every(result => result === null)) {
+                                report.unsupported += 1;
+                            } else {
+                                report.unused += 1;
+                                if (report.unused_selectors.length < args.max_unused_per_sheet) {
+                                    report.unused_selectors.push(rule.selectorText.length > 200 ? rule.selectorText.substring(0, 200) + '...' : rule.selectorText);
+                                } else {
+                                    report.unused_selectors_truncated = true;
+                                }
+                            }
+                        }
+                        // Nested style rules use relative selectors and are not evaluated
+                    } else if (typeof CSSMediaRule !== 'undefined' && rule instanceof CSSMediaRule) {
+                        visit(rule.cssRules, mediaActive && window.matchMedia(rule.conditionText || rule.media.mediaText).matches);
+                    } else if (rule.cssRules) {
+                        // @supports, @layer, @container and similar grouping rules
+                        visit(rule.cssRules, mediaActive);
+                    }
+                }
+            };
+            visit(rules, !sheet.disabled && (!sheet.media || !sheet.media.mediaText || window.matchMedia(sheet.media.mediaText).matches));
+
+            for (const key of ['rules', 'used', 'unused', 'state_dependent', 'inactive_media', 'unsupported']) {
+                totals[key] += report[key];
+            }
+            if (reports.length < args.max_sheets) reports.push(report);
+        });
+
+        const evaluated = totals.used + totals.unused;
+        return {
+            totals: {
+                ...totals,
+                used_percent: evaluated > 0 ? Math.round((totals.used / evaluated) * 1000) / 10 : null
+            },
+            sheets_truncated: sheets.length > reports.length,
+            sheets: reports,
+            caveats: [
+                'Snapshot of the current DOM: rules for routes, dialogs or states not currently rendered are reported as unused.',
+                'Interaction pseudo-classes and pseudo-elements are ignored when matching; see state_dependent.',
+                'Styles inside shadow roots are not matched against the document and may appear unused.'
+            ]
+        };"#;
@@ -0,0 +1,133 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct CustomElementsRequest {
+    window_label: Option<String>,
+    /// Count instances inside open shadow roots (default true)
+    pierce_shadow: Option<bool>,
+    /// Example selectors listed per tag (default 3)
+    max_samples: Option<usize>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for taking a census of the custom elements on the page
+pub async fn handle_get_custom_elements<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_custom_elements", &payload)
+        .optional("pierce_shadow", FieldKind::Bool)
+        .optional("max_samples", FieldKind::Integer { min: Some(0), max: Some(100) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: CustomElementsRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_custom_elements: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Custom elements error",
+        &json!({
+            "pierce_shadow": request.pierce_shadow.unwrap_or(true),
+            "max_samples": request.max_samples.unwrap_or(3),
+        }),
+        CUSTOM_ELEMENTS_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "custom element census",
+    )
+    .await
+}
+
+/// The registry cannot be enumerated, so the census starts from the tags in the DOM:
+/// hyphenated tag names and `is="..."` customized built-ins, each looked up with
+/// `customElements.get`. Closed shadow roots look the same as no shadow root from
+/// outside, so those instances are counted together.
+const CUSTOM_ELEMENTS_JS: &str = r#"        if (!window.customElements) {
+            return { supported: false, tags: [], note: 'customElements is not available in this webview' };
+        }
+
+        const byTag = new Map();
+        const entry = (name, builtIn) => {
+            const key = builtIn ? builtIn + '[is=' + name + ']' : name;
+            if (!byTag.has(key)) {
+                byTag.set(key, { name, extends: builtIn, instances: 0, upgraded: 0, open_shadow_roots: 0, in_shadow_dom: 0, samples: [] });
+            }
+            return byTag.get(key);
+        };
+
+        const stack = [{ node: document.documentElement, inShadow: false }];
+        let scanned = 0;
+        while (stack.length > 0) {
+            const { node, inShadow } = stack.pop();
+            if (node.nodeType !== Node.ELEMENT_NODE) continue;
+            scanned += 1;
+            const tag = node.localName;
+            const isAttr = node.getAttribute('is');
+            let info = null;
+            if (tag.includes('-')) info = entry(tag, null);
+            else if (isAttr && isAttr.includes('-')) info = entry(isAttr, tag);
+            if (info) {
+                info.instances += 1;
+                const definition = customElements.get(info.name);
+                if (definition && node instanceof definition) info.upgraded += 1;
+                if (node.shadowRoot) info.open_shadow_roots += 1;
+                if (inShadow) info.in_shadow_dom += 1;
+                if (info.samples.length < args.max_samples) info.samples.push(mcp.cssPath(node));
+            }
+            for (const child of node.children) stack.push({ node: child, inShadow });
+            if (args.pierce_shadow && node.shadowRoot) {
+                for (const child of node.shadowRoot.children) stack.push({ node: child, inShadow: true });
+            }
+        }
+
+        const tags = [...byTag.values()].map(info => {
+            const definition = customElements.get(info.name) || null;
+            let observed = null;
+            try { observed = definition && definition.observedAttributes ? [...definition.observedAttributes] : null; } catch (e) { observed = null; }
+            return {
+                tag: info.name,
+                extends: info.extends,
+                defined: definition !== null,
+                class_name: definition ? definition.name || '(anonymous)' : null,
+                instances: info.instances,
+                upgraded: info.upgraded,
+                not_upgraded: info.instances - info.upgraded,
+                open_shadow_roots: info.open_shadow_roots,
+                closed_or_no_shadow_root: info.instances - info.open_shadow_roots,
+                shadow_mode: info.open_shadow_roots === 0 ? 'none_or_closed' : (info.open_shadow_roots === info.instances ? 'open' : 'mixed'),
+                inside_shadow_dom: info.in_shadow_dom,
+                observed_attributes: observed,
+                form_associated: definition ? definition.formAssociated === true : null,
+                samples: info.samples
+            };
+        }).sort((a, b) => b.instances - a.instances || a.tag.localeCompare(b.tag));
+
+        const undefinedTags = tags.filter(t => !t.defined).map(t => t.tag);
+        return {
+            supported: true,
+            elements_scanned: scanned,
+            distinct_tags: tags.length,
+            defined_tags: tags.length - undefinedTags.length,
+            total_instances: tags.reduce((sum, t) => sum + t.instances, 0),
+            undefined_tags: undefinedTags,
+            tags,
+            note: 'Definitions without instances in the DOM cannot be listed; closed shadow roots are counted with elements that have none'
+        };"#;
@@ -0,0 +1,179 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct FindDetachedNodesRequest {
+    window_label: Option<String>,
+    /// "report" (default) counts removed subtrees still alive, "start" begins tracking
+    /// removals, "stop" reports and removes the tracker
+    action: Option<String>,
+    /// Try to collect garbage before counting (default true)
+    force_gc: Option<bool>,
+    /// Ignore nodes removed less than this long ago (default 0)
+    min_age_ms: Option<u64>,
+    /// Number of sample nodes to return (default 10)
+    sample_size: Option<usize>,
+    timeout_ms: Option<u64>,
+}
+
+/// Extra time the garbage collection attempt may take
+const GC_SETTLE_MS: u64 = 1500;
+
+/// Handler function for estimating DOM nodes that were removed but are still referenced
+pub async fn handle_find_detached_nodes<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("find_detached_nodes", &payload)
+        .optional("action", FieldKind::OneOf(&["start", "report", "stop"]))
+        .optional("force_gc", FieldKind::Bool)
+        .optional("min_age_ms", FieldKind::UINT)
+        .optional("sample_size", FieldKind::Integer { min: Some(0), max: Some(100) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: FindDetachedNodesRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for find_detached_nodes: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let action = request.action.clone().unwrap_or_else(|| "report".to_string());
+    let force_gc = request.force_gc.unwrap_or(true) && action != "start";
+
+    let js_code = build_probe_script(
+        "Detached node detection error",
+        &json!({
+            "action": action,
+            "force_gc": force_gc,
+            "min_age_ms": request.min_age_ms.unwrap_or(0),
+            "sample_size": request.sample_size.unwrap_or(10),
+        }),
+        FIND_DETACHED_NODES_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS) + if force_gc { GC_SETTLE_MS } else { 0 },
+        "detached node detection",
+    )
+    .await
+}
+
+/// Records every removed subtree root as a WeakRef. A root that is still alive and
+/// disconnected after GC is held by something in JS. Without an exposed `gc()` the
+/// collection is only encouraged with allocation pressure, so counts are an upper bound.
+const FIND_DETACHED_NODES_JS: &str = r#"        const start = () => {
+            const tracker = { started_at: Date.now(), removed: [], total_removed: 0 };
+            tracker.observer = new MutationObserver(records => mcp.timed('detached_nodes', () => {
+                const now = Date.now();
+                for (const record of records) {
+                    for (const node of record.removedNodes) {
+                        if (node.nodeType !== Node.ELEMENT_NODE) continue;
+                        tracker.total_removed += 1;
+                        tracker.removed.push({
+                            ref: new WeakRef(node),
+                            removed_at: now,
+                            tag: node.tagName.toLowerCase(),
+                            id: node.id || null,
+                            class_name: typeof node.className === 'string' ? node.className.substring(0, 80) : null,
+                            parent: record.target instanceof Element ? mcp.cssPath(record.target) : null
+                        });
+                    }
+                }
+                // Prune entries that were collected or re-attached to keep the registry small
+                if (tracker.removed.length > 20000) {
+                    tracker.removed = tracker.removed.filter(entry => {
+                        const node = entry.ref.deref();
+                        return node && !node.isConnected;
+                    });
+                }
+            }));
+            tracker.observer.observe(document, { childList: true, subtree: true });
+            window.__MCP_DETACHED__ = tracker;
+            return tracker;
+        };
+
+        const collect = async () => {
+            if (typeof window.gc === 'function') {
+                window.gc();
+                return 'gc';
+            }
+            // Allocation pressure across a few turns usually triggers a minor and major GC
+            for (let round = 0; round < 5; round++) {
+                let garbage = [];
+                for (let i = 0; i < 200; i++) garbage.push(new ArrayBuffer(1024 * 1024));
+                garbage = null;
+                await new Promise(resolve => setTimeout(resolve, 200));
+            }
+            return 'allocation_pressure';
+        };
+
+        const report = async (tracker) => {
+            const gcMethod = args.force_gc ? await collect() : 'none';
+            const now = Date.now();
+            const byTag = {};
+            const leaked = [];
+            let collected = 0;
+            let reattached = 0;
+
+            for (const entry of tracker.removed) {
+                const node = entry.ref.deref();
+                if (!node) { collected += 1; continue; }
+                if (node.isConnected) { reattached += 1; continue; }
+                if (now - entry.removed_at < args.min_age_ms) continue;
+                const subtree = 1 + node.getElementsByTagName('*').length;
+                const tag = byTag[entry.tag] || (byTag[entry.tag] = { roots: 0, nodes: 0 });
+                tag.roots += 1;
+                tag.nodes += subtree;
+                leaked.push({ ...entry, ref: undefined, subtree_nodes: subtree, age_ms: now - entry.removed_at });
+            }
+
+            leaked.sort((a, b) => b.subtree_nodes - a.subtree_nodes);
+            return {
+                tracking_since_ms: tracker.started_at,
+                total_removed: tracker.total_removed,
+                detached_roots: leaked.length,
+                detached_nodes: leaked.reduce((sum, entry) => sum + entry.subtree_nodes, 0),
+                collected,
+                reattached,
+                by_tag: byTag,
+                sample: leaked.slice(0, args.sample_size),
+                gc_method: gcMethod,
+                // Without a real gc() some of these may simply not have been collected yet
+                approximate: gcMethod !== 'gc'
+            };
+        };
+
+        const existing = window.__MCP_DETACHED__;
+
+        if (args.action === 'start') {
+            if (existing) existing.observer.disconnect();
+            start();
+            return { tracking: true, note: 'Perform the action under test, then call with action "report"' };
+        }
+
+        if (!existing) {
+            throw new Error('Detached node tracking is not running; call with action "start" before the action under test');
+        }
+
+        const result = await report(existing);
+        if (args.action === 'stop') {
+            existing.observer.disconnect();
+            delete window.__MCP_DETACHED__;
+            result.tracking = false;
+        } else {
+            result.tracking = true;
+        }
+        return result;"#;
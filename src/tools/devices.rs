@@ -0,0 +1,164 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+/// Permissions queried when no names are given
+const DEFAULT_PERMISSION_NAMES: &[&str] = &[
+    "camera",
+    "microphone",
+    "geolocation",
+    "notifications",
+    "clipboard-read",
+    "clipboard-write",
+    "persistent-storage",
+];
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct EnumerateDevicesRequest {
+    window_label: Option<String>,
+    /// Only return devices of this kind
+    kind: Option<String>,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct PermissionsStateRequest {
+    window_label: Option<String>,
+    /// Permission names to query; defaults to the common device and storage permissions
+    names: Option<Vec<String>>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for listing media input and output devices
+pub async fn handle_enumerate_devices<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("enumerate_devices", &payload)
+        .optional("kind", FieldKind::OneOf(&["audioinput", "audiooutput", "videoinput"]))
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: EnumerateDevicesRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for enumerate_devices: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Device enumeration error",
+        &json!({ "kind": request.kind }),
+        ENUMERATE_DEVICES_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "device enumeration",
+    )
+    .await
+}
+
+/// Handler function for querying the Permissions API
+pub async fn handle_get_permissions_state<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_permissions_state", &payload)
+        .optional("names", FieldKind::Array)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: PermissionsStateRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_permissions_state: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let names = request
+        .names
+        .clone()
+        .unwrap_or_else(|| DEFAULT_PERMISSION_NAMES.iter().map(|name| name.to_string()).collect());
+
+    let js_code = build_probe_script("Permissions query error", &json!({ "names": names }), PERMISSIONS_STATE_JS);
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "permissions query",
+    )
+    .await
+}
+
+const ENUMERATE_DEVICES_JS: &str = r#"        if (!navigator.mediaDevices || typeof navigator.mediaDevices.enumerateDevices !== 'function') {
+            return {
+                supported: false,
+                secure_context: window.isSecureContext,
+                devices: [],
+                note: 'navigator.mediaDevices is not available in this webview (it requires a secure context)'
+            };
+        }
+
+        const all = await navigator.mediaDevices.enumerateDevices();
+        const devices = all
+            .filter(device => !args.kind || device.kind === args.kind)
+            .map(device => ({
+                kind: device.kind,
+                label: device.label,
+                device_id: device.deviceId,
+                group_id: device.groupId
+            }));
+
+        const counts = {};
+        all.forEach(device => { counts[device.kind] = (counts[device.kind] || 0) + 1; });
+        const labelsHidden = devices.length > 0 && devices.every(device => device.label === '');
+
+        return {
+            supported: true,
+            devices,
+            counts,
+            labels_hidden: labelsHidden,
+            // Engines hide labels (and often collapse ids) until a getUserMedia grant
+            note: labelsHidden ? 'Device labels are empty until camera/microphone permission is granted' : null
+        };"#;
+
+/// Unknown names make `permissions.query` throw, so each name is reported separately
+const PERMISSIONS_STATE_JS: &str = r#"        if (!navigator.permissions || typeof navigator.permissions.query !== 'function') {
+            return {
+                supported: false,
+                permissions: {},
+                note: 'The Permissions API is not available in this webview'
+            };
+        }
+
+        const permissions = {};
+        for (const name of args.names) {
+            try {
+                const status = await navigator.permissions.query({ name });
+                permissions[name] = { state: status.state };
+            } catch (error) {
+                permissions[name] = { state: 'unsupported', error: String(error && error.message || error) };
+            }
+        }
+
+        return {
+            supported: true,
+            secure_context: window.isSecureContext,
+            permissions
+        };"#;
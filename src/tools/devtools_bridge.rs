@@ -1,9 +1,10 @@
 use serde_json::{json, Value};
-use tauri::{AppHandle, Runtime, Manager, Emitter, Listener};
+use tauri::{AppHandle, Runtime, Manager};
 use log::info;
 
 use crate::error::Error;
 use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::request_js_response;
 
 #[allow(dead_code)]
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -86,26 +87,10 @@ pub async fn handle_devtools_bridge<R: Runtime>(
         request.component_filter.clone(),
     );
 
-    // Execute the JavaScript in the window
-    let (tx, rx) = std::sync::mpsc::channel();
-
-    app.emit_to(&window_label, "execute-js", &js_code)
-        .map_err(|e| Error::communication_error_with_context(
-            "Failed to emit execute-js event",
-            format!("window: {}, error: {}", window_label, e),
-        ))?;
-
-    // Listen for response
-    app.once("execute-js-response", move |event| {
-        let payload = event.payload().to_string();
-        let _ = tx.send(payload);
-    });
-
-    // Wait for the response with timeout
-    let timeout = std::time::Duration::from_millis(request.timeout_ms.unwrap_or(5000));
-    let result_string = rx
-        .recv_timeout(timeout)
-        .map_err(|_| Error::timeout_error("devtools bridge execution", request.timeout_ms.unwrap_or(5000)))?;
+    // Execute the JavaScript in the window and wait for its own response
+    let timeout_ms = request.timeout_ms.unwrap_or(5000);
+    let result_string = request_js_response(app, &window_label, &js_code, true, std::time::Duration::from_millis(timeout_ms))
+        .map_err(|e| e.into_error(&window_label, "devtools bridge execution", timeout_ms))?;
 
     // Parse the response
     let response_value: Value = serde_json::from_str(&result_string)
@@ -498,6 +483,7 @@ fn generate_devtools_bridge_code(max_depth: usize, component_filter: Option<Stri
 
         window.dispatchEvent(new CustomEvent('execute-js-response', {{
             detail: {{
+                request_id: typeof __mcpRequestId === 'undefined' ? null : __mcpRequestId,
                 result: JSON.stringify(response),
                 type: 'object'
             }}
@@ -505,6 +491,7 @@ fn generate_devtools_bridge_code(max_depth: usize, component_filter: Option<Stri
     }} catch (error) {{
         window.dispatchEvent(new CustomEvent('execute-js-response', {{
             detail: {{
+                request_id: typeof __mcpRequestId === 'undefined' ? null : __mcpRequestId,
                 error: 'DevTools bridge error: ' + error.message,
                 type: 'error'
             }}
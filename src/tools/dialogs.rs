@@ -0,0 +1,134 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct NextDialogRequest {
+    window_label: Option<String>,
+    /// "once" (default) restores the native dialogs after the first one, "persistent" keeps
+    /// answering, "off" restores them immediately
+    mode: Option<String>,
+    /// Accept confirm/prompt dialogs (default) or dismiss them
+    accept: Option<bool>,
+    /// Text returned from prompt dialogs when accepting; defaults to the prompt's default value
+    prompt_text: Option<String>,
+    /// How long to wait for a dialog to appear; 0 only arms the handler
+    wait_ms: Option<u64>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for capturing and auto-answering JS dialogs
+pub async fn handle_next_dialog<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("handle_next_dialog", &payload)
+        .optional("mode", FieldKind::OneOf(&["once", "persistent", "off"]))
+        .optional("accept", FieldKind::Bool)
+        .optional("prompt_text", FieldKind::String)
+        .optional("wait_ms", FieldKind::UINT)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: NextDialogRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for handle_next_dialog: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let wait_ms = request.wait_ms.unwrap_or(0);
+
+    let js_code = build_probe_script(
+        "Dialog handler error",
+        &json!({
+            "mode": request.mode.clone().unwrap_or_else(|| "once".to_string()),
+            "accept": request.accept.unwrap_or(true),
+            "prompt_text": request.prompt_text,
+            "wait_ms": wait_ms,
+        }),
+        NEXT_DIALOG_JS,
+    );
+
+    // The probe itself blocks for up to wait_ms, so give it that on top of the usual budget
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS).saturating_add(wait_ms),
+        "dialog capture",
+    )
+    .await
+}
+
+/// Replaces alert/confirm/prompt with recording stand-ins kept on `window.__MCP_DIALOGS__`
+const NEXT_DIALOG_JS: &str = r#"        const state = window.__MCP_DIALOGS__ || (window.__MCP_DIALOGS__ = {
+            original: { alert: window.alert, confirm: window.confirm, prompt: window.prompt },
+            config: null,
+            log: [],
+            waiters: []
+        });
+
+        const restore = () => {
+            window.alert = state.original.alert;
+            window.confirm = state.original.confirm;
+            window.prompt = state.original.prompt;
+            state.config = null;
+        };
+
+        const handle = (type, message, defaultValue) => {
+            const config = state.config;
+            let response;
+            if (type === 'confirm') {
+                response = config.accept;
+            } else if (type === 'prompt') {
+                response = config.accept ? (config.prompt_text ?? defaultValue ?? '') : null;
+            }
+            const entry = {
+                type,
+                message: String(message ?? ''),
+                default_value: defaultValue ?? null,
+                response: response ?? null,
+                timestamp: Date.now()
+            };
+            state.log.push(entry);
+            if (state.log.length > 100) state.log.shift();
+            if (config.mode === 'once') restore();
+            state.waiters.splice(0).forEach(notify => notify(entry));
+            return response;
+        };
+
+        if (args.mode === 'off') {
+            restore();
+        } else {
+            state.config = { mode: args.mode, accept: args.accept, prompt_text: args.prompt_text };
+            window.alert = (message) => { handle('alert', message); };
+            window.confirm = (message) => handle('confirm', message);
+            window.prompt = (message, defaultValue) => handle('prompt', message, defaultValue);
+        }
+
+        let dialog = null;
+        if (args.mode !== 'off' && args.wait_ms > 0) {
+            dialog = await new Promise(resolve => {
+                const timer = setTimeout(() => resolve(null), args.wait_ms);
+                state.waiters.push(entry => {
+                    clearTimeout(timer);
+                    resolve(entry);
+                });
+            });
+        }
+
+        return {
+            armed: state.config !== null,
+            mode: state.config ? state.config.mode : 'off',
+            dialog,
+            timed_out: args.mode !== 'off' && args.wait_ms > 0 && dialog === null,
+            history: state.log.slice(-20)
+        };"#;
@@ -0,0 +1,281 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Manager, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, eval_probe, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct DocumentRequest {
+    window_label: Option<String>,
+    /// New title for `set_document_title`
+    title: Option<String>,
+    /// Also set the native window title (default false)
+    sync_window_title: Option<bool>,
+    /// Include the favicon bytes as a data URL (default false)
+    include_data: Option<bool>,
+    timeout_ms: Option<u64>,
+}
+
+fn parse_request(command: &str, payload: Value) -> Result<(DocumentRequest, String), Error> {
+    PayloadValidator::new(command, &payload)
+        .required_if(command == "set_document_title", "title", FieldKind::String)
+        .optional("sync_window_title", FieldKind::Bool)
+        .optional("include_data", FieldKind::Bool)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: DocumentRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for {}: {}", command, e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    Ok((request, window_label))
+}
+
+/// Read the document title and pair it with the native window title, which Tauri
+/// does not keep in sync with `document.title`
+async fn document_title_response<R: Runtime>(
+    app: &AppHandle<R>,
+    window_label: &str,
+    js_code: String,
+    timeout_ms: u64,
+    operation: &str,
+) -> Result<SocketResponse, Error> {
+    let window = app
+        .get_webview_window(window_label)
+        .ok_or_else(|| Error::window_not_found(window_label))?;
+
+    let page = match eval_probe(app, window_label, js_code, timeout_ms, operation).await {
+        Ok(page) => page,
+        Err(Error::Anyhow { message }) => {
+            return Ok(SocketResponse {
+                success: false,
+                data: None,
+                error: Some(message),
+            })
+        }
+        Err(e) => return Err(e),
+    };
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(json!({
+            "title": page.get("title"),
+            "previous_title": page.get("previous_title"),
+            "window_title": window.title().ok(),
+            "url": page.get("url"),
+        })),
+        error: None,
+    })
+}
+
+/// Handler function for reading the document title
+pub async fn handle_get_document_title<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    let (request, window_label) = parse_request("get_document_title", payload)?;
+
+    let js_code = build_probe_script("Document title error", &json!({}), DOCUMENT_TITLE_JS);
+
+    document_title_response(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "document title",
+    )
+    .await
+}
+
+/// Handler function for changing the document title
+pub async fn handle_set_document_title<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    let (request, window_label) = parse_request("set_document_title", payload)?;
+    let title = request.title.clone().unwrap_or_default();
+
+    if request.sync_window_title.unwrap_or(false) {
+        let window = app
+            .get_webview_window(&window_label)
+            .ok_or_else(|| Error::window_not_found(&window_label))?;
+        window
+            .set_title(&title)
+            .map_err(|e| Error::window_operation_failed("set window title", e.to_string()))?;
+    }
+
+    let js_code = build_probe_script("Document title error", &json!({ "title": title }), DOCUMENT_TITLE_JS);
+
+    document_title_response(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "document title update",
+    )
+    .await
+}
+
+/// Handler function for reading the favicon the page declares
+pub async fn handle_get_favicon<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    let (request, window_label) = parse_request("get_favicon", payload)?;
+
+    let js_code = build_probe_script(
+        "Favicon error",
+        &json!({ "include_data": request.include_data.unwrap_or(false) }),
+        FAVICON_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "favicon inspection",
+    )
+    .await
+}
+
+/// Handler function for reading meta tags, link relations and JSON-LD blocks
+pub async fn handle_get_metadata<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    let (request, window_label) = parse_request("get_metadata", payload)?;
+
+    let js_code = build_probe_script("Metadata error", &json!({}), METADATA_JS);
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "metadata inspection",
+    )
+    .await
+}
+
+/// Sets `document.title` when a title is given, reporting the previous one
+const DOCUMENT_TITLE_JS: &str = r#"        const previous = document.title;
+        if (typeof args.title === 'string') {
+            document.title = args.title;
+        }
+        return {
+            title: document.title,
+            previous_title: typeof args.title === 'string' ? previous : null,
+            url: location.href
+        };"#;
+
+/// Lists declared icons (the last matching `rel=icon` wins in browsers) and can fetch
+/// the active one, which covers data URLs and canvas-generated badge icons too
+const FAVICON_JS: &str = r#"        const links = Array.from(document.querySelectorAll('link[rel]'))
+            .filter(link => link.rel.split(/\s+/).some(rel => ['icon', 'shortcut', 'apple-touch-icon', 'mask-icon'].includes(rel.toLowerCase())))
+            .map(link => ({
+                rel: link.rel,
+                href: link.href,
+                type: link.type || null,
+                sizes: link.sizes ? link.sizes.value || null : null
+            }));
+
+        const icons = links.filter(link => link.rel.toLowerCase().split(/\s+/).includes('icon'));
+        const active = icons.length > 0 ? icons[icons.length - 1].href : new URL('/favicon.ico', location.href).href;
+
+        const result = {
+            url: active,
+            declared: icons.length > 0,
+            links
+        };
+
+        if (args.include_data) {
+            try {
+                const response = await fetch(active, { cache: 'no-store' });
+                if (!response.ok) throw new Error('HTTP ' + response.status);
+                const blob = await response.blob();
+                result.data = await new Promise((resolve, reject) => {
+                    const reader = new FileReader();
+                    reader.onload = () => resolve(reader.result);
+                    reader.onerror = () => reject(reader.error);
+                    reader.readAsDataURL(blob);
+                });
+                result.mime_type = blob.type || null;
+                result.size = blob.size;
+            } catch (error) {
+                result.data = null;
+                result.data_error = String(error && error.message || error);
+            }
+        }
+
+        return result;"#;
+
+
+/// Open Graph (`og:*`) and Twitter card tags are also grouped into objects; repeated
+/// properties such as `og:image` become arrays. JSON-LD blocks that fail to parse are
+/// reported with the parse error and a prefix of the raw text instead of being dropped.
+const METADATA_JS: &str = r#"        const meta = Array.from(document.querySelectorAll('meta')).map(tag => ({
+            name: tag.getAttribute('name'),
+            property: tag.getAttribute('property'),
+            http_equiv: tag.getAttribute('http-equiv'),
+            charset: tag.getAttribute('charset'),
+            itemprop: tag.getAttribute('itemprop'),
+            content: tag.getAttribute('content')
+        }));
+
+        const group = (prefix) => {
+            const grouped = {};
+            for (const tag of meta) {
+                const key = tag.property || tag.name;
+                if (!key || !key.toLowerCase().startsWith(prefix) || tag.content === null) continue;
+                const field = key.substring(prefix.length);
+                if (field in grouped) {
+                    grouped[field] = [].concat(grouped[field], tag.content);
+                } else {
+                    grouped[field] = tag.content;
+                }
+            }
+            return grouped;
+        };
+
+        const links = Array.from(document.querySelectorAll('link[rel]')).map(link => ({
+            rel: link.rel,
+            href: link.href || null,
+            hreflang: link.hreflang || null,
+            type: link.type || null,
+            media: link.media || null,
+            sizes: link.sizes ? link.sizes.value || null : null
+        }));
+
+        const canonical = document.querySelector('link[rel~="canonical" i]');
+
+        const jsonLd = Array.from(document.querySelectorAll('script[type="application/ld+json" i]')).map((script, index) => {
+            const text = script.textContent || '';
+            try {
+                return { index, data: JSON.parse(text) };
+            } catch (error) {
+                return { index, error: error.message, raw: text.length > 500 ? text.substring(0, 500) + '...' : text };
+            }
+        });
+
+        return {
+            url: location.href,
+            title: document.title,
+            lang: document.documentElement.lang || null,
+            canonical_url: canonical ? canonical.href : null,
+            description: (meta.find(tag => (tag.name || '').toLowerCase() === 'description') || {}).content || null,
+            open_graph: group('og:'),
+            twitter: group('twitter:'),
+            meta,
+            links,
+            json_ld: jsonLd,
+            json_ld_errors: jsonLd.filter(block => block.error).length
+        };"#;
@@ -0,0 +1,140 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct DomStatsRequest {
+    window_label: Option<String>,
+    /// Only measure the subtree of this element
+    root_selector: Option<String>,
+    /// Count nodes inside open shadow roots (default true)
+    pierce_shadow: Option<bool>,
+    /// Number of tags listed in the per-tag counts (default 30)
+    top_tags: Option<usize>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for measuring DOM size and depth
+pub async fn handle_get_dom_stats<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_dom_stats", &payload)
+        .optional("root_selector", FieldKind::NonEmptyString)
+        .optional("pierce_shadow", FieldKind::Bool)
+        .optional("top_tags", FieldKind::Integer { min: Some(1), max: Some(1000) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: DomStatsRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_dom_stats: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "DOM stats error",
+        &json!({
+            "root_selector": request.root_selector,
+            "pierce_shadow": request.pierce_shadow.unwrap_or(true),
+            "top_tags": request.top_tags.unwrap_or(30),
+        }),
+        DOM_STATS_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "DOM stats",
+    )
+    .await
+}
+
+/// Each call keeps its totals on the page, keyed by root selector, so repeated polling
+/// reports growth since the previous call. The widest element (most direct children)
+/// is included because a list that never recycles rows shows up there first.
+const DOM_STATS_JS: &str = r#"        const root = args.root_selector ? mcp.query(args.root_selector) : document.documentElement;
+        const counts = { nodes: 0, elements: 0, text: 0, comments: 0, shadow_roots: 0 };
+        const byTag = new Map();
+        let maxDepth = 0;
+        let deepest = root;
+        let widest = { el: root, children: root.children.length };
+
+        // Iterative walk so very deep trees cannot overflow the call stack
+        const stack = [{ node: root, depth: 0 }];
+        while (stack.length > 0) {
+            const { node, depth } = stack.pop();
+            counts.nodes += 1;
+            if (node.nodeType === Node.ELEMENT_NODE) {
+                counts.elements += 1;
+                const tag = node.tagName.toLowerCase();
+                byTag.set(tag, (byTag.get(tag) || 0) + 1);
+                if (depth > maxDepth) {
+                    maxDepth = depth;
+                    deepest = node;
+                }
+                if (node.children.length > widest.children) widest = { el: node, children: node.children.length };
+                if (args.pierce_shadow && node.shadowRoot) {
+                    counts.shadow_roots += 1;
+                    for (const child of node.shadowRoot.childNodes) stack.push({ node: child, depth: depth + 1 });
+                }
+            } else if (node.nodeType === Node.TEXT_NODE) {
+                counts.text += 1;
+            } else if (node.nodeType === Node.COMMENT_NODE) {
+                counts.comments += 1;
+            }
+            for (const child of node.childNodes) stack.push({ node: child, depth: depth + 1 });
+        }
+
+        // Tag chain from the root to the deepest element, crossing shadow boundaries
+        const path = [];
+        for (let node = deepest; node; ) {
+            if (node.nodeType === Node.ELEMENT_NODE) path.unshift(node.tagName.toLowerCase());
+            if (node === root) break;
+            node = node.parentNode instanceof ShadowRoot ? node.parentNode.host : node.parentNode;
+            if (node && node.nodeType === Node.DOCUMENT_FRAGMENT_NODE) break;
+        }
+
+        const tags = [...byTag.entries()]
+            .sort((a, b) => b[1] - a[1])
+            .slice(0, args.top_tags)
+            .map(([tag, count]) => ({ tag, count }));
+
+        const key = args.root_selector || ':root';
+        const history = window.__MCP_DOM_STATS__ || (window.__MCP_DOM_STATS__ = {});
+        const previous = history[key];
+        const now = Date.now();
+        history[key] = { time_ms: now, nodes: counts.nodes, elements: counts.elements, max_depth: maxDepth };
+
+        return {
+            root: args.root_selector ? mcp.cssPath(root) : 'document',
+            ...counts,
+            max_depth: maxDepth,
+            deepest: {
+                selector: mcp.cssPath(deepest),
+                depth: maxDepth,
+                path: path.join(' > ')
+            },
+            widest: {
+                selector: mcp.cssPath(widest.el),
+                child_count: widest.children
+            },
+            distinct_tags: byTag.size,
+            by_tag: tags,
+            since_previous: previous ? {
+                elapsed_ms: now - previous.time_ms,
+                nodes: counts.nodes - previous.nodes,
+                elements: counts.elements - previous.elements,
+                max_depth: maxDepth - previous.max_depth
+            } : null
+        };"#;
@@ -0,0 +1,267 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+/// Drag implementations `reorder_item` can drive
+const DRAG_BACKENDS: &[&str] = &["auto", "html5", "pointer"];
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ReorderItemRequest {
+    window_label: Option<String>,
+    /// The sortable list
+    selector: String,
+    /// Selector of the items inside the list (default: its rendered child elements)
+    item_selector: Option<String>,
+    /// Selector of the drag handle inside an item (default: the item itself)
+    handle_selector: Option<String>,
+    /// Index of the item to move
+    from_index: usize,
+    /// Index the item should end up at
+    to_index: usize,
+    /// "auto" (default), "html5" or "pointer"
+    backend: Option<String>,
+    /// Number of intermediate moves between the two items (default 12)
+    steps: Option<u32>,
+    /// Delay between moves (default 16)
+    step_delay_ms: Option<u64>,
+    /// Time the pointer is held down before moving, for delay-activated sensors (default 0)
+    hold_ms: Option<u64>,
+    /// Time to wait after the drop before reading the new order (default 150)
+    settle_ms: Option<u64>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for moving an item of a sortable list by dragging it
+pub async fn handle_reorder_item<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("reorder_item", &payload)
+        .required("selector", FieldKind::NonEmptyString)
+        .required("from_index", FieldKind::UINT)
+        .required("to_index", FieldKind::UINT)
+        .optional("item_selector", FieldKind::NonEmptyString)
+        .optional("handle_selector", FieldKind::NonEmptyString)
+        .optional("backend", FieldKind::OneOf(DRAG_BACKENDS))
+        .optional("steps", FieldKind::Integer { min: Some(1), max: Some(200) })
+        .optional("step_delay_ms", FieldKind::Integer { min: Some(0), max: Some(1_000) })
+        .optional("hold_ms", FieldKind::Integer { min: Some(0), max: Some(5_000) })
+        .optional("settle_ms", FieldKind::Integer { min: Some(0), max: Some(10_000) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: ReorderItemRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for reorder_item: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let steps = request.steps.unwrap_or(12);
+    let step_delay_ms = request.step_delay_ms.unwrap_or(16);
+    let hold_ms = request.hold_ms.unwrap_or(0);
+    let settle_ms = request.settle_ms.unwrap_or(150);
+    // "auto" may run the drag a second time with the other backend
+    let drag_ms = (u64::from(steps) * step_delay_ms + hold_ms + settle_ms) * 2;
+
+    let js_code = build_probe_script(
+        "Reorder error",
+        &json!({
+            "selector": request.selector,
+            "item_selector": request.item_selector,
+            "handle_selector": request.handle_selector,
+            "from_index": request.from_index,
+            "to_index": request.to_index,
+            "backend": request.backend.as_deref().unwrap_or("auto"),
+            "steps": steps,
+            "step_delay_ms": step_delay_ms,
+            "hold_ms": hold_ms,
+            "settle_ms": settle_ms,
+        }),
+        REORDER_ITEM_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS).saturating_add(drag_ms),
+        "drag reorder",
+    )
+    .await
+}
+
+/// The html5 backend sends `dragstart`/`drag` to the source, `dragenter`/`dragover`/
+/// `dragleave` to whatever is under each intermediate point and `drop`/`dragend` at the
+/// end, sharing one `DataTransfer`. The pointer backend sends pointer and mouse events
+/// in small steps, which passes the movement thresholds of pointer-based libraries. The
+/// drop point is just past the middle of the target item in the direction of travel.
+/// "auto" starts with html5 when the source or its handle is `draggable` and tries the
+/// other backend when the first leaves the order untouched. Items are told apart by a
+/// label (data-id, id, or their text) because frameworks often re-create them.
+const REORDER_ITEM_JS: &str = r#"        const container = mcp.query(args.selector);
+        const listItems = () => {
+            const items = args.item_selector
+                ? Array.from(container.querySelectorAll(args.item_selector))
+                : Array.from(container.children);
+            return items.filter(item => {
+                const style = getComputedStyle(item);
+                return style.display !== 'none' && item.getClientRects().length > 0;
+            });
+        };
+        const label = (item) => {
+            for (const attr of ['data-id', 'data-key', 'data-rbd-draggable-id', 'data-sortable-id', 'id']) {
+                const value = item.getAttribute(attr);
+                if (value) return attr === 'id' ? '#' + value : value;
+            }
+            return (item.textContent || '').replace(/\s+/g, ' ').trim().substring(0, 60);
+        };
+        const order = () => listItems().map(label);
+
+        const initial = listItems();
+        if (args.from_index >= initial.length) throw new Error('from_index ' + args.from_index + ' is out of range; the list has ' + initial.length + ' items');
+        if (args.to_index >= initial.length) throw new Error('to_index ' + args.to_index + ' is out of range; the list has ' + initial.length + ' items');
+        const before = initial.map(label);
+        const expected = before.slice();
+        expected.splice(args.to_index, 0, expected.splice(args.from_index, 1)[0]);
+        const movedLabel = before[args.from_index];
+        if (args.from_index === args.to_index) {
+            return { moved: false, note: 'from_index equals to_index; nothing to do', before, after: before, expected, matches_expected: true };
+        }
+
+        const wait = (ms) => new Promise(resolve => setTimeout(resolve, ms));
+        const center = (r) => ({ x: r.left + r.width / 2, y: r.top + r.height / 2 });
+
+        const source = initial[args.from_index];
+        source.scrollIntoView({ block: 'nearest', inline: 'nearest' });
+        const handle = args.handle_selector ? source.querySelector(args.handle_selector) : source;
+        if (!handle) throw new Error('No element matches handle_selector ' + JSON.stringify(args.handle_selector) + ' inside the source item');
+        const draggable = handle.closest('[draggable="true"]');
+        const usesHtml5 = !!draggable && (draggable === source || source.contains(draggable) || draggable.contains(source));
+
+        // Drop just past the target's middle along the axis the items are laid out on
+        const dropPoint = () => {
+            const items = listItems();
+            const targetItem = items[args.to_index] || items[items.length - 1];
+            const r = targetItem.getBoundingClientRect();
+            const s = items[Math.min(args.from_index, items.length - 1)].getBoundingClientRect();
+            const horizontal = Math.abs(center(r).x - center(s).x) > Math.abs(center(r).y - center(s).y);
+            const forward = args.to_index > args.from_index;
+            const fraction = forward ? 0.75 : 0.25;
+            return horizontal
+                ? { x: r.left + r.width * fraction, y: r.top + r.height / 2, axis: 'horizontal' }
+                : { x: r.left + r.width / 2, y: r.top + r.height * fraction, axis: 'vertical' };
+        };
+
+        const runHtml5 = async () => {
+            const counts = {};
+            const dragSource = draggable || handle;
+            const start = center(handle.getBoundingClientRect());
+            let dataTransfer = null;
+            try { dataTransfer = new DataTransfer(); dataTransfer.effectAllowed = 'move'; } catch (e) {}
+            const fire = (target, type, point) => {
+                const event = new DragEvent(type, {
+                    bubbles: true,
+                    cancelable: type !== 'dragleave' && type !== 'dragend',
+                    composed: true,
+                    view: window,
+                    clientX: point.x,
+                    clientY: point.y,
+                    dataTransfer
+                });
+                const accepted = !target.dispatchEvent(event);
+                counts[type] = (counts[type] || 0) + 1;
+                return accepted;
+            };
+            const end = dropPoint();
+            const cancelled = fire(dragSource, 'dragstart', start);
+            if (cancelled) return { backend: 'html5', cancelled_at: 'dragstart', events: counts };
+            await wait(args.step_delay_ms);
+            let over = null;
+            let dropAccepted = false;
+            for (let step = 1; step <= args.steps; step++) {
+                const t = step / args.steps;
+                const point = { x: start.x + (end.x - start.x) * t, y: start.y + (end.y - start.y) * t };
+                const hit = document.elementFromPoint(point.x, point.y) || container;
+                fire(dragSource, 'drag', point);
+                if (hit !== over) {
+                    fire(hit, 'dragenter', point);
+                    if (over) fire(over, 'dragleave', point);
+                    over = hit;
+                }
+                // preventDefault on dragover is how a drop target accepts the drop
+                dropAccepted = fire(hit, 'dragover', point);
+                await wait(args.step_delay_ms);
+            }
+            const finalPoint = dropPoint();
+            const dropTarget = document.elementFromPoint(finalPoint.x, finalPoint.y) || over || container;
+            if (dropAccepted) fire(dropTarget, 'drop', finalPoint);
+            else if (over) fire(over, 'dragleave', finalPoint);
+            // The source may have been moved or replaced during the drag
+            fire(dragSource.isConnected ? dragSource : document, 'dragend', finalPoint);
+            return { backend: 'html5', drop_accepted: dropAccepted, data_transfer: !!dataTransfer, events: counts };
+        };
+
+        const runPointer = async () => {
+            const counts = {};
+            const start = center(handle.getBoundingClientRect());
+            const fire = (target, point, pointerType, mouseType, buttons) => {
+                const init = { bubbles: true, cancelable: true, composed: true, view: window, clientX: point.x, clientY: point.y, screenX: point.x + window.screenX, screenY: point.y + window.screenY, button: 0, buttons };
+                target.dispatchEvent(new PointerEvent(pointerType, { ...init, pointerId: 1, pointerType: 'mouse', isPrimary: true, width: 1, height: 1, pressure: buttons ? 0.5 : 0 }));
+                counts[pointerType] = (counts[pointerType] || 0) + 1;
+                target.dispatchEvent(new MouseEvent(mouseType, init));
+                counts[mouseType] = (counts[mouseType] || 0) + 1;
+            };
+            fire(handle, start, 'pointerdown', 'mousedown', 1);
+            if (args.hold_ms > 0) await wait(args.hold_ms);
+            let point = start;
+            for (let step = 1; step <= args.steps; step++) {
+                // Recompute the end each step, as the list may shift while dragging
+                const end = dropPoint();
+                const t = step / args.steps;
+                point = { x: start.x + (end.x - start.x) * t, y: start.y + (end.y - start.y) * t };
+                fire(document.elementFromPoint(point.x, point.y) || document, point, 'pointermove', 'mousemove', 1);
+                await wait(args.step_delay_ms);
+            }
+            fire(document.elementFromPoint(point.x, point.y) || document, point, 'pointerup', 'mouseup', 0);
+            return { backend: 'pointer', events: counts };
+        };
+
+        const same = (a, b) => a.length === b.length && a.every((value, i) => value === b[i]);
+        const first = args.backend === 'auto' ? (usesHtml5 ? 'html5' : 'pointer') : args.backend;
+        const attempts = [];
+        let after = before;
+        for (const backend of args.backend === 'auto' ? [first, first === 'html5' ? 'pointer' : 'html5'] : [first]) {
+            const attempt = backend === 'html5' ? await runHtml5() : await runPointer();
+            await wait(args.settle_ms);
+            after = order();
+            attempt.order_changed = !same(after, before);
+            attempts.push(attempt);
+            if (attempt.order_changed) break;
+        }
+
+        const matches = same(after, expected);
+        return {
+            moved: !same(after, before),
+            matches_expected: matches,
+            item: movedLabel,
+            from_index: args.from_index,
+            to_index: args.to_index,
+            final_index: after.indexOf(movedLabel),
+            source_draggable: usesHtml5,
+            axis: dropPoint().axis,
+            before,
+            after,
+            expected,
+            attempts,
+            note: matches ? null : same(after, before)
+                ? 'The order did not change; the list may need a different backend, a drag handle (handle_selector), a hold delay (hold_ms) or more settle time'
+                : 'The order changed but not as expected; check for duplicate item labels or a list that animates after the drop'
+        };"#;
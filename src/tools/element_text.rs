@@ -0,0 +1,83 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct GetTextRequest {
+    window_label: Option<String>,
+    selector: String,
+    /// Collapse runs of whitespace and trim (default true)
+    normalize_whitespace: Option<bool>,
+    /// Also return `textContent`, which includes hidden text (default false)
+    include_hidden: Option<bool>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for reading the text of a single element
+pub async fn handle_get_text<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_text", &payload)
+        .required("selector", FieldKind::NonEmptyString)
+        .optional("normalize_whitespace", FieldKind::Bool)
+        .optional("include_hidden", FieldKind::Bool)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: GetTextRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_text: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Get text error",
+        &json!({
+            "selector": request.selector,
+            "normalize_whitespace": request.normalize_whitespace.unwrap_or(true),
+            "include_hidden": request.include_hidden.unwrap_or(false),
+        }),
+        GET_TEXT_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "text extraction",
+    )
+    .await
+}
+
+/// `innerText` is the rendered text (layout-aware, hidden content excluded);
+/// `textContent` is every text node regardless of styling
+const GET_TEXT_JS: &str = r#"        const el = mcp.query(args.selector);
+        const matchCount = document.querySelectorAll(args.selector).length;
+        const normalize = (text) => args.normalize_whitespace ? text.replace(/\s+/g, ' ').trim() : text;
+
+        // Form controls keep their text in value rather than in child nodes
+        const isField = el instanceof HTMLInputElement || el instanceof HTMLTextAreaElement || el instanceof HTMLSelectElement;
+        const visibleText = typeof el.innerText === 'string' ? el.innerText : el.textContent;
+
+        const result = {
+            selector: args.selector,
+            element: mcp.cssPath(el),
+            match_count: matchCount,
+            visible: mcp.isVisible(el),
+            text: normalize(visibleText || ''),
+            value: isField ? el.value : null
+        };
+        if (args.include_hidden) {
+            result.text_content = normalize(el.textContent || '');
+        }
+        return result;"#;
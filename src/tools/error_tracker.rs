@@ -5,8 +5,13 @@ use std::sync::mpsc;
 use std::time::Duration;
 use tauri::{AppHandle, Emitter, Listener, Manager, Runtime};
 
+use crate::TauriMcpExt;
 use crate::error::Error;
 use crate::socket_server::SocketResponse;
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+/// Socket push event carrying an exception recorded while a stream is active
+pub const EXCEPTION_PUSH_EVENT: &str = "exception";
 
 // Error type enumeration
 #[allow(dead_code)]
@@ -246,6 +251,151 @@ pub async fn handle_clear_exceptions<R: Runtime>(
     })
 }
 
+/// An active exception stream for one window
+#[derive(Debug)]
+pub struct ExceptionStream {
+    /// Listener forwarding recorded exceptions, attached once registered
+    pub listener: Option<tauri::EventId>,
+    pub started_at_ms: u64,
+    /// First occurrence (Unix ms) of each fingerprint the stream has seen
+    first_seen: std::collections::HashMap<String, u64>,
+}
+
+impl ExceptionStream {
+    pub fn new(started_at_ms: u64) -> Self {
+        Self {
+            listener: None,
+            started_at_ms,
+            first_seen: Default::default(),
+        }
+    }
+
+    /// Whether a recorded exception should be pushed. Exceptions whose fingerprint was first
+    /// seen before the stream started belong to `get_exceptions`, repeats included.
+    pub fn admit(&mut self, fingerprint: String, first_occurrence_ms: u64) -> bool {
+        let first_seen = *self.first_seen.entry(fingerprint).or_insert(first_occurrence_ms);
+        first_seen >= self.started_at_ms
+    }
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ExceptionStreamRequest {
+    pub window_label: Option<String>,
+}
+
+fn parse_stream_request(command: &str, payload: Value) -> Result<String, Error> {
+    PayloadValidator::new(command, &payload)
+        .optional("window_label", FieldKind::String)
+        .finish()?;
+
+    let request: ExceptionStreamRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for {}: {}", command, e)))?;
+
+    // Get the window label or use "main" as default
+    Ok(request.window_label.unwrap_or_else(|| "main".to_string()))
+}
+
+/// Handler function to push a window's exceptions to socket clients as they are recorded
+pub async fn handle_start_exception_stream<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    let window_label = parse_stream_request("start_exception_stream", payload)?;
+
+    // Verify the window exists
+    let _window = app
+        .get_webview_window(&window_label)
+        .ok_or_else(|| Error::window_not_found(&window_label))?;
+
+    let started_at_ms = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0);
+
+    // Register the stream before listening so no exception slips in between
+    let mcp = app.tauri_mcp();
+    let restarted = match mcp.set_exception_stream(&window_label, ExceptionStream::new(started_at_ms)) {
+        Some(previous) => {
+            if let Some(listener) = previous.listener {
+                app.unlisten(listener);
+            }
+            true
+        }
+        None => false,
+    };
+
+    let app_handle = app.clone();
+    let label = window_label.clone();
+    let listener = app.listen("exception-recorded", move |event| {
+        let Ok(recorded) = serde_json::from_str::<Value>(event.payload()) else {
+            return;
+        };
+        if recorded.get("window_label").and_then(|l| l.as_str()) != Some(label.as_str()) {
+            return;
+        }
+        let exception = recorded.get("exception");
+        let field = |name: &str| exception.and_then(|e| e.get(name)).and_then(|v| v.as_str()).unwrap_or_default();
+        // Same key the page deduplicates exceptions by
+        let fingerprint = format!("{}::{}", field("error_type"), field("message"));
+        let first_occurrence_ms = exception
+            .and_then(|e| e.get("first_occurrence_ms"))
+            .and_then(|v| v.as_u64())
+            .unwrap_or(u64::MAX);
+        if !app_handle.tauri_mcp().admit_streamed_exception(&label, fingerprint, first_occurrence_ms) {
+            return;
+        }
+        app_handle.tauri_mcp().push_event(EXCEPTION_PUSH_EVENT, recorded);
+    });
+
+    // A stop that raced this start leaves nothing to attach to
+    if !mcp.attach_exception_listener(&window_label, listener) {
+        app.unlisten(listener);
+    }
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(serde_json::json!({
+            "window_label": window_label,
+            "streaming": true,
+            "restarted": restarted,
+            "event": EXCEPTION_PUSH_EVENT,
+            // Exceptions first seen before this, including later repeats of them, are not
+            // pushed; get_exceptions with end_time_ms set to this value returns them
+            "started_at_ms": started_at_ms,
+        })),
+        error: None,
+    })
+}
+
+/// Handler function to stop pushing a window's exceptions
+pub async fn handle_stop_exception_stream<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    let window_label = parse_stream_request("stop_exception_stream", payload)?;
+
+    let mcp = app.tauri_mcp();
+    let stopped = match mcp.remove_exception_stream(&window_label) {
+        Some(stream) => {
+            if let Some(listener) = stream.listener {
+                app.unlisten(listener);
+            }
+            true
+        }
+        None => false,
+    };
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(serde_json::json!({
+            "window_label": window_label,
+            "stopped": stopped,
+            "active_streams": mcp.exception_stream_windows(),
+        })),
+        error: None,
+    })
+}
+
 /// Helper function to retrieve exceptions from the webview
 async fn retrieve_exceptions<R: Runtime>(
     app: AppHandle<R>,
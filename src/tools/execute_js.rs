@@ -3,10 +3,16 @@ use serde_json::Value;
 use std::fmt;
 use std::sync::mpsc;
 use std::time::Duration;
-use tauri::{AppHandle, Emitter, Listener, Manager, Runtime};
+use tauri::{AppHandle, Manager, Runtime};
 
 use crate::error::Error;
 use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{request_js_response, JsRequestError};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+use crate::TauriMcpExt;
+
+/// Largest script `execute_js_file` will read
+const MAX_JS_FILE_BYTES: u64 = 1024 * 1024;
 
 // Define a custom error type for JavaScript execution operations
 #[derive(Debug)]
@@ -55,6 +61,16 @@ pub struct ExecuteJsRequest {
     timeout_ms: Option<u64>,
 }
 
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ExecuteJsFileRequest {
+    window_label: Option<String>,
+    /// Host path of the script; must be inside one of the configured `js_file_roots`
+    path: String,
+    /// Values exposed to the script as variables of the same name
+    args: Option<serde_json::Map<String, Value>>,
+    timeout_ms: Option<u64>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct ExecuteJsResponse {
     result: String,
@@ -66,6 +82,12 @@ pub async fn handle_execute_js<R: Runtime>(
     app: &AppHandle<R>,
     payload: Value,
 ) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("executeJs", &payload)
+        .required("code", FieldKind::String)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
     let request: ExecuteJsRequest = serde_json::from_value(payload)
         .map_err(|e| Error::serialization_error(format!("Invalid payload for executeJs: {}", e)))?;
 
@@ -104,6 +126,145 @@ pub async fn handle_execute_js<R: Runtime>(
     }
 }
 
+/// Handler function for evaluating a script file from the host
+pub async fn handle_execute_js_file<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("execute_js_file", &payload)
+        .required("path", FieldKind::NonEmptyString)
+        .optional("args", FieldKind::Object)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: ExecuteJsFileRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for execute_js_file: {}", e)))?;
+
+    let path = resolve_js_file(app, &request.path)?;
+
+    let size = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+    if size > MAX_JS_FILE_BYTES {
+        return Err(Error::invalid_parameter(
+            "path",
+            format!("a script of at most {} bytes", MAX_JS_FILE_BYTES),
+            format!("{} bytes", size),
+        ));
+    }
+
+    let source = std::fs::read_to_string(&path)
+        .map_err(|e| Error::Anyhow { message: format!("Failed to read {}: {}", path.display(), e) })?;
+
+    let args = request.args.clone().unwrap_or_default();
+    if let Some(name) = args.keys().find(|name| !is_js_identifier(name)) {
+        return Err(Error::invalid_parameter(
+            format!("args.{}", name),
+            "a valid JavaScript identifier that is not a reserved word",
+            name,
+        ));
+    }
+
+    // Declare each argument in a function scope and evaluate the file with a direct
+    // eval, so the script sees them as variables and its completion value is the result
+    let declarations: String = args
+        .iter()
+        .map(|(name, value)| format!("const {} = {};\n", name, value))
+        .collect();
+    let source_literal = serde_json::to_string(&source)
+        .map_err(|e| Error::serialization_error(format!("Failed to encode script: {}", e)))?;
+    let code = format!("(function () {{\n{}return eval({});\n}})()", declarations, source_literal);
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    // Verify the window exists
+    let _window = app
+        .get_webview_window(&window_label)
+        .ok_or_else(|| Error::window_not_found(&window_label))?;
+
+    let result = execute_js_in_window(
+        app.clone(),
+        ExecuteJsRequest {
+            window_label: Some(window_label),
+            code,
+            timeout_ms: request.timeout_ms,
+        },
+    )
+    .await;
+
+    match result {
+        Ok(response) => {
+            let mut data = serde_json::to_value(response)
+                .map_err(|e| Error::serialization_error(format!("Failed to serialize response: {}", e)))?;
+            if let Some(object) = data.as_object_mut() {
+                object.insert("path".to_string(), Value::String(path.display().to_string()));
+            }
+
+            Ok(SocketResponse {
+                success: true,
+                data: Some(data),
+                error: None,
+            })
+        }
+        Err(e) => Ok(SocketResponse {
+            success: false,
+            data: None,
+            error: Some(format!("{} ({})", e, path.display())),
+        }),
+    }
+}
+
+/// Canonicalize `path` and make sure it is a file inside one of the allowed roots
+fn resolve_js_file<R: Runtime>(app: &AppHandle<R>, path: &str) -> Result<std::path::PathBuf, Error> {
+    let roots = app.tauri_mcp().js_file_roots();
+    if roots.is_empty() {
+        return Err(Error::Anyhow {
+            message: "execute_js_file is disabled; allow script directories with PluginConfig::js_file_roots".to_string(),
+        });
+    }
+
+    let canonical = std::fs::canonicalize(path)
+        .map_err(|e| Error::invalid_parameter("path", "an existing file", format!("{} ({})", path, e)))?;
+    if !canonical.is_file() {
+        return Err(Error::invalid_parameter("path", "a file", path));
+    }
+
+    let allowed = roots
+        .iter()
+        .filter_map(|root| std::fs::canonicalize(root).ok())
+        .any(|root| canonical.starts_with(root));
+    if !allowed {
+        let roots: Vec<String> = roots.iter().map(|r| r.display().to_string()).collect();
+        return Err(Error::invalid_parameter(
+            "path",
+            format!("a file inside {}", roots.join(", ")),
+            path,
+        ));
+    }
+
+    Ok(canonical)
+}
+
+/// Words that cannot name a `const` in the wrapper. `eval` and `arguments` are included
+/// because shadowing `eval` would turn the wrapper's direct eval into an indirect one.
+const RESERVED_JS_WORDS: &[&str] = &[
+    "arguments", "await", "break", "case", "catch", "class", "const", "continue", "debugger",
+    "default", "delete", "do", "else", "enum", "eval", "export", "extends", "false", "finally",
+    "for", "function", "if", "implements", "import", "in", "instanceof", "interface", "let",
+    "new", "null", "package", "private", "protected", "public", "return", "static", "super",
+    "switch", "this", "throw", "true", "try", "typeof", "var", "void", "while", "with", "yield",
+];
+
+fn is_js_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+        && !RESERVED_JS_WORDS.contains(&name)
+}
+
 // Helper function to execute JS in a window and await response
 async fn execute_js_in_window<R: Runtime>(
     app: AppHandle<R>,
@@ -118,23 +279,10 @@ async fn execute_js_in_window<R: Runtime>(
     // Get timeout or use default (5 seconds)
     let timeout = Duration::from_millis(params.timeout_ms.unwrap_or(5000));
 
-    // Emit event to execute the JavaScript in the specified window
-    app.emit_to(&window_label, "execute-js", &params.code)
-        .map_err(|e| {
-            ExecuteJsError::WebviewOperation(format!("Failed to emit execute-js event to {}: {}", window_label, e))
-        })?;
-
-    // Set up a channel to receive the response
-    let (tx, rx) = mpsc::channel();
-
-    // Listen for response
-    app.once("execute-js-response", move |event| {
-        let payload = event.payload().to_string();
-        let _ = tx.send(payload);
-    });
+    // Execute the JavaScript in the specified window; the guest replies with its value
+    let response = request_js_response(&app, &window_label, &params.code, false, timeout);
 
-    // Wait for the response with timeout
-    match rx.recv_timeout(timeout) {
+    match response {
         Ok(result_string) => {
             // Parse the response JSON
             let response: Value = serde_json::from_str(&result_string).map_err(|e| {
@@ -170,6 +318,10 @@ async fn execute_js_in_window<R: Runtime>(
                 result_type,
             })
         }
-        Err(e) => Err(e.into()),
+        Err(JsRequestError::Emit(e)) => Err(ExecuteJsError::WebviewOperation(format!(
+            "Failed to emit execute-js event to {}: {}",
+            window_label, e
+        ))),
+        Err(JsRequestError::Timeout(e)) => Err(e.into()),
     }
 }
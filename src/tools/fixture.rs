@@ -0,0 +1,461 @@
+use serde_json::{json, Value};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Runtime};
+use log::info;
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::baselines::{baseline_dir, handle_compare_baseline, handle_save_baseline, validate_name};
+use crate::tools::js_probe::{build_probe_script, eval_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::outline::handle_get_semantic_outline;
+use crate::tools::state_dump::{diff_values, handle_state_dump};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+/// Bumped when the bundle layout changes, so old fixtures are reported instead of misdiffed
+const FIXTURE_VERSION: u64 = 1;
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct CaptureFixtureRequest {
+    window_label: Option<String>,
+    /// Fixture name; letters, digits, `-`, `_` and `.`
+    name: String,
+    /// Element whose subtree is captured (default `body`)
+    root_selector: Option<String>,
+    /// Subtrees left out of the DOM snapshot, e.g. clocks or ads
+    ignore_selectors: Option<Vec<String>>,
+    /// Attributes left out of the DOM snapshot
+    ignore_attributes: Option<Vec<String>>,
+    /// Upper bound on DOM snapshot elements (default 2000)
+    max_nodes: Option<usize>,
+    /// Only keep this part of the state dump, in `state_dump` path syntax
+    state_path: Option<String>,
+    /// Depth limit of the state dump (default 10)
+    max_depth: Option<usize>,
+    /// Store a window screenshot with the bundle (default true)
+    include_screenshot: Option<bool>,
+    /// Replace an existing fixture (default true)
+    overwrite: Option<bool>,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct AssertFixtureRequest {
+    window_label: Option<String>,
+    name: String,
+    /// Maximum changes reported per section (default 100)
+    max_changes: Option<usize>,
+    /// Per-channel difference below which pixels count as equal (default 24)
+    pixel_threshold: Option<u8>,
+    /// Percentage of differing pixels that still passes (default 0.5)
+    max_diff_percent: Option<f64>,
+    /// Return the screenshot diff image as a data URL (default false)
+    include_diff_image: Option<bool>,
+    /// Replace the stored fixture with the fresh capture when it differs (default false)
+    update: Option<bool>,
+    timeout_ms: Option<u64>,
+}
+
+/// Capture options stored in the bundle, so `assert_fixture` re-captures the same way
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct FixtureOptions {
+    root_selector: Option<String>,
+    ignore_selectors: Vec<String>,
+    ignore_attributes: Vec<String>,
+    max_nodes: usize,
+    state_path: Option<String>,
+    max_depth: usize,
+    include_screenshot: bool,
+}
+
+fn fixture_path<R: Runtime>(app: &AppHandle<R>, name: &str) -> Result<std::path::PathBuf, Error> {
+    Ok(baseline_dir(app)?.join(format!("{}.fixture.json", name)))
+}
+
+/// The screenshot is kept as an ordinary baseline next to the bundle
+fn screenshot_baseline(name: &str) -> String {
+    format!("{}.fixture", name)
+}
+
+/// `data` of a nested handler's response, or its error as a string
+fn response_data(response: Result<SocketResponse, Error>) -> Result<Value, String> {
+    match response {
+        Ok(SocketResponse { success: true, data, .. }) => Ok(data.unwrap_or(Value::Null)),
+        Ok(SocketResponse { error, .. }) => Err(error.unwrap_or_else(|| "failed without an error message".to_string())),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Capture the DOM snapshot, semantic outline and state dump of a window. Sections that
+/// fail are recorded with their error instead of failing the whole capture.
+async fn capture_sections<R: Runtime>(
+    app: &AppHandle<R>,
+    window_label: &str,
+    options: &FixtureOptions,
+    timeout_ms: u64,
+) -> Result<Value, Error> {
+    let js_code = build_probe_script(
+        "Fixture DOM snapshot error",
+        &json!({
+            "root_selector": options.root_selector,
+            "ignore_selectors": options.ignore_selectors,
+            "ignore_attributes": options.ignore_attributes,
+            "max_nodes": options.max_nodes,
+        }),
+        DOM_SNAPSHOT_JS,
+    );
+    let page = eval_probe(app, window_label, js_code, timeout_ms, "fixture DOM snapshot").await?;
+
+    let outline = response_data(
+        handle_get_semantic_outline(
+            app,
+            json!({
+                "window_label": window_label,
+                "root_selector": options.root_selector,
+                "timeout_ms": timeout_ms,
+            }),
+        )
+        .await,
+    );
+
+    let state = response_data(
+        handle_state_dump(
+            app,
+            json!({
+                "window_label": window_label,
+                "max_depth": options.max_depth,
+                "path": options.state_path,
+                "timeout_ms": timeout_ms,
+            }),
+        )
+        .await,
+    )
+    .map(|dump| dump.get("state").cloned().unwrap_or(Value::Null));
+
+    let section = |result: Result<Value, String>| match result {
+        Ok(value) => json!({ "value": value, "error": null }),
+        Err(error) => json!({ "value": null, "error": error }),
+    };
+
+    Ok(json!({
+        "url": page.get("url"),
+        "title": page.get("title"),
+        "viewport": page.get("viewport"),
+        "dom": { "value": page.get("dom"), "error": null, "truncated": page.get("truncated") },
+        "outline": section(outline),
+        "state": section(state),
+    }))
+}
+
+/// Handler function for capturing DOM, outline, state and a screenshot as one fixture
+pub async fn handle_capture_fixture<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("capture_fixture", &payload)
+        .required("name", FieldKind::NonEmptyString)
+        .optional("root_selector", FieldKind::NonEmptyString)
+        .optional("ignore_selectors", FieldKind::Array)
+        .optional("ignore_attributes", FieldKind::Array)
+        .optional("max_nodes", FieldKind::Integer { min: Some(1), max: Some(50_000) })
+        .optional("state_path", FieldKind::String)
+        .optional("max_depth", FieldKind::Integer { min: Some(1), max: None })
+        .optional("include_screenshot", FieldKind::Bool)
+        .optional("overwrite", FieldKind::Bool)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: CaptureFixtureRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for capture_fixture: {}", e)))?;
+    validate_name(&request.name)?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let path = fixture_path(app, &request.name)?;
+    let existed = path.exists();
+    if existed && !request.overwrite.unwrap_or(true) {
+        return Ok(SocketResponse {
+            success: false,
+            data: None,
+            error: Some(format!("Fixture '{}' already exists at {}", request.name, path.display())),
+        });
+    }
+
+    let options = FixtureOptions {
+        root_selector: request.root_selector.clone(),
+        ignore_selectors: request.ignore_selectors.clone().unwrap_or_default(),
+        ignore_attributes: request.ignore_attributes.clone().unwrap_or_default(),
+        max_nodes: request.max_nodes.unwrap_or(2000),
+        state_path: request.state_path.clone().filter(|path| !path.is_empty()),
+        max_depth: request.max_depth.unwrap_or(10),
+        include_screenshot: request.include_screenshot.unwrap_or(true),
+    };
+    let timeout_ms = request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS);
+
+    let sections = capture_sections(app, &window_label, &options, timeout_ms).await?;
+    let screenshot = if options.include_screenshot {
+        let saved = response_data(
+            handle_save_baseline(
+                app,
+                json!({ "name": screenshot_baseline(&request.name), "window_label": window_label }),
+            )
+            .await,
+        )
+        .map_err(|error| Error::Anyhow { message: format!("Fixture screenshot failed: {}", error) })?;
+        saved.get("path").cloned()
+    } else {
+        None
+    };
+
+    let bundle = json!({
+        "version": FIXTURE_VERSION,
+        "name": request.name,
+        "window_label": window_label,
+        "captured_at_ms": SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0),
+        "options": options,
+        "screenshot": screenshot,
+        "sections": sections,
+    });
+    let serialized = serde_json::to_string_pretty(&bundle)
+        .map_err(|e| Error::serialization_error(format!("Failed to serialize fixture: {}", e)))?;
+    std::fs::write(&path, serialized)
+        .map_err(|e| Error::Anyhow { message: format!("Failed to write {}: {}", path.display(), e) })?;
+    info!("[TAURI_MCP] Saved fixture '{}' to {}", request.name, path.display());
+
+    let section_error = |key: &str| sections[key]["error"].clone();
+    Ok(SocketResponse {
+        success: true,
+        data: Some(json!({
+            "name": request.name,
+            "path": path.display().to_string(),
+            "replaced": existed,
+            "url": sections["url"],
+            "dom_truncated": sections["dom"]["truncated"],
+            "screenshot": screenshot,
+            "section_errors": {
+                "outline": section_error("outline"),
+                "state": section_error("state"),
+            },
+        })),
+        error: None,
+    })
+}
+
+/// Handler function for re-capturing a fixture and diffing it against the stored bundle
+pub async fn handle_assert_fixture<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("assert_fixture", &payload)
+        .required("name", FieldKind::NonEmptyString)
+        .optional("max_changes", FieldKind::Integer { min: Some(1), max: None })
+        .optional("pixel_threshold", FieldKind::Integer { min: Some(0), max: Some(255) })
+        .optional("max_diff_percent", FieldKind::Number { min: Some(0.0), max: Some(100.0) })
+        .optional("include_diff_image", FieldKind::Bool)
+        .optional("update", FieldKind::Bool)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: AssertFixtureRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for assert_fixture: {}", e)))?;
+    validate_name(&request.name)?;
+
+    let path = fixture_path(app, &request.name)?;
+    let stored: Value = std::fs::read_to_string(&path)
+        .map_err(|e| Error::Anyhow {
+            message: format!("No fixture named '{}' at {} ({}); capture one with capture_fixture", request.name, path.display(), e),
+        })
+        .and_then(|text| {
+            serde_json::from_str(&text)
+                .map_err(|e| Error::serialization_error(format!("Failed to parse fixture {}: {}", path.display(), e)))
+        })?;
+    let version = stored["version"].as_u64().unwrap_or(0);
+    if version != FIXTURE_VERSION {
+        return Err(Error::Anyhow {
+            message: format!(
+                "Fixture '{}' has format version {}, expected {}; capture it again",
+                request.name, version, FIXTURE_VERSION
+            ),
+        });
+    }
+    let options: FixtureOptions = serde_json::from_value(stored["options"].clone())
+        .map_err(|e| Error::serialization_error(format!("Invalid options in fixture {}: {}", path.display(), e)))?;
+
+    // Default to the window the fixture was captured from
+    let window_label = request
+        .window_label
+        .clone()
+        .or_else(|| stored["window_label"].as_str().map(str::to_string))
+        .unwrap_or_else(|| "main".to_string());
+    let timeout_ms = request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS);
+    let max_changes = request.max_changes.unwrap_or(100);
+
+    let current = capture_sections(app, &window_label, &options, timeout_ms).await?;
+    let before = &stored["sections"];
+
+    let compare = |key: &str| {
+        let (old, new) = (&before[key], &current[key]);
+        if !old["error"].is_null() || !new["error"].is_null() {
+            return json!({
+                "passed": old["error"] == new["error"] && old["value"] == new["value"],
+                "skipped": true,
+                "stored_error": old["error"],
+                "current_error": new["error"],
+            });
+        }
+        let mut changes = Vec::new();
+        diff_values("", &old["value"], &new["value"], &mut changes);
+        let total = changes.len();
+        changes.truncate(max_changes);
+        json!({
+            "passed": total == 0,
+            "total_changes": total,
+            "truncated": total > max_changes,
+            "changes": changes,
+        })
+    };
+    let dom = compare("dom");
+    let outline = compare("outline");
+    let state = compare("state");
+    let page_changes: Vec<Value> = ["url", "title", "viewport"]
+        .iter()
+        .filter(|key| before[**key] != current[**key])
+        .map(|key| json!({ "field": key, "before": before[*key], "after": current[*key] }))
+        .collect();
+
+    let screenshot = if options.include_screenshot {
+        match response_data(
+            handle_compare_baseline(
+                app,
+                json!({
+                    "name": screenshot_baseline(&request.name),
+                    "window_label": window_label,
+                    "pixel_threshold": request.pixel_threshold,
+                    "max_diff_percent": request.max_diff_percent,
+                    "create_missing": false,
+                    "include_diff_image": request.include_diff_image.unwrap_or(false),
+                }),
+            )
+            .await,
+        ) {
+            Ok(result) => result,
+            Err(error) => json!({ "passed": false, "error": error }),
+        }
+    } else {
+        Value::Null
+    };
+
+    let passed_section = |section: &Value| section["passed"].as_bool().unwrap_or(false);
+    let screenshot_passed = screenshot.is_null() || passed_section(&screenshot);
+    let passed = passed_section(&dom) && passed_section(&outline) && passed_section(&state) && screenshot_passed;
+    let failed_sections: Vec<&str> = [("dom", &dom), ("outline", &outline), ("state", &state)]
+        .iter()
+        .filter(|(_, section)| !passed_section(section))
+        .map(|(key, _)| *key)
+        .chain((!screenshot_passed).then_some("screenshot"))
+        .collect();
+
+    // Updating re-captures through capture_fixture so the bundle and screenshot stay in step
+    let updated = !passed && request.update.unwrap_or(false);
+    if updated {
+        let recaptured = handle_capture_fixture(
+            app,
+            json!({
+                "name": request.name,
+                "window_label": window_label,
+                "root_selector": options.root_selector,
+                "ignore_selectors": options.ignore_selectors,
+                "ignore_attributes": options.ignore_attributes,
+                "max_nodes": options.max_nodes,
+                "state_path": options.state_path,
+                "max_depth": options.max_depth,
+                "include_screenshot": options.include_screenshot,
+                "timeout_ms": timeout_ms,
+            }),
+        )
+        .await?;
+        if !recaptured.success {
+            return Ok(recaptured);
+        }
+    }
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(json!({
+            "name": request.name,
+            "passed": passed,
+            "failed_sections": failed_sections,
+            "captured_at_ms": stored["captured_at_ms"],
+            "page_changes": page_changes,
+            "dom": dom,
+            "outline": outline,
+            "state": state,
+            "screenshot": screenshot,
+            "fixture_updated": updated,
+        })),
+        error: None,
+    })
+}
+
+/// Serializes the subtree as nested `{ tag, attrs, text, children }` objects, so a
+/// structural diff points at the element and attribute that changed. Attributes are
+/// sorted, text is whitespace-collapsed, and live form values are recorded as `value`,
+/// `checked` and `selected` since they are not reflected in attributes. Scripts,
+/// styles and SVG internals are left out; SVG elements keep only their tag.
+const DOM_SNAPSHOT_JS: &str = r#"        const root = args.root_selector ? mcp.query(args.root_selector) : document.body;
+        const SKIP = ['SCRIPT', 'STYLE', 'NOSCRIPT', 'TEMPLATE', 'LINK', 'META'];
+        const ignored = new Set(args.ignore_attributes.map(name => name.toLowerCase()));
+        const isIgnored = (el) => args.ignore_selectors.some(selector => {
+            try { return el.matches(selector); } catch (e) { throw new Error('Invalid ignore selector ' + JSON.stringify(selector) + ': ' + e.message); }
+        });
+        let budget = args.max_nodes;
+
+        const snapshot = (el) => {
+            budget--;
+            const node = { tag: el.tagName.toLowerCase() };
+            const attrs = {};
+            for (const name of el.getAttributeNames().sort()) {
+                if (!ignored.has(name.toLowerCase())) attrs[name] = el.getAttribute(name);
+            }
+            if (Object.keys(attrs).length > 0) node.attrs = attrs;
+            if (el instanceof HTMLInputElement && (el.type === 'checkbox' || el.type === 'radio')) node.checked = el.checked;
+            else if (el instanceof HTMLInputElement || el instanceof HTMLTextAreaElement || el instanceof HTMLSelectElement) node.value = el.value;
+            if (el instanceof HTMLOptionElement) node.selected = el.selected;
+            if (el instanceof SVGElement) return node;
+
+            const text = Array.from(el.childNodes)
+                .filter(child => child.nodeType === Node.TEXT_NODE)
+                .map(child => child.textContent)
+                .join(' ')
+                .replace(/\s+/g, ' ')
+                .trim();
+            if (text) node.text = text.length > 200 ? text.substring(0, 200) + '…' : text;
+
+            const children = [];
+            const childRoot = el.shadowRoot || el;
+            for (const child of childRoot.children) {
+                if (SKIP.includes(child.tagName) || isIgnored(child)) continue;
+                if (budget <= 0) {
+                    node.children_truncated = true;
+                    break;
+                }
+                children.push(snapshot(child));
+            }
+            if (el.shadowRoot) node.shadow = true;
+            if (children.length > 0) node.children = children;
+            return node;
+        };
+
+        const dom = snapshot(root);
+        return {
+            url: location.href,
+            title: document.title,
+            viewport: { width: window.innerWidth, height: window.innerHeight, device_pixel_ratio: window.devicePixelRatio },
+            dom,
+            truncated: budget <= 0
+        };"#;
@@ -0,0 +1,144 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct FontInfoRequest {
+    window_label: Option<String>,
+    selectors: Vec<String>,
+    /// Measure each element's text for characters rendered as missing-glyph boxes
+    detect_missing_glyphs: Option<bool>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for reporting the fonts actually used to render elements
+pub async fn handle_get_font_info<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_font_info", &payload)
+        .required("selectors", FieldKind::Array)
+        .optional("window_label", FieldKind::String)
+        .optional("detect_missing_glyphs", FieldKind::Bool)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: FontInfoRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_font_info: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Font info error",
+        &json!({
+            "selectors": request.selectors,
+            "detect_missing_glyphs": request.detect_missing_glyphs.unwrap_or(false),
+        }),
+        FONT_INFO_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "font inspection",
+    )
+    .await
+}
+
+/// Resolves each element's font stack to the first face that is actually available,
+/// using canvas width comparisons against generic fallbacks
+const FONT_INFO_JS: &str = r#"        const GENERIC = ['serif', 'sans-serif', 'monospace', 'cursive', 'fantasy', 'system-ui',
+            'ui-serif', 'ui-sans-serif', 'ui-monospace', 'ui-rounded', 'math', 'emoji', 'fangsong'];
+        const SAMPLE = 'mmmmmmmmmmlli10OO@#WwQq';
+        const canvas = document.createElement('canvas');
+        const ctx = canvas.getContext('2d');
+
+        const parseStack = (stack) => stack
+            .split(',')
+            .map(f => f.trim().replace(/^["']|["']$/g, ''))
+            .filter(Boolean);
+
+        const measure = (font) => {
+            ctx.font = font;
+            return ctx.measureText(SAMPLE).width;
+        };
+
+        // A family is available if it renders differently from at least one generic baseline
+        const isAvailable = (family) => {
+            if (GENERIC.includes(family.toLowerCase())) return true;
+            return ['monospace', 'serif', 'sans-serif'].some(base =>
+                measure('72px "' + family + '", ' + base) !== measure('72px ' + base));
+        };
+
+        // Compare a character's pixels to the reference .notdef glyph of the same font
+        const glyphPixels = (font, ch) => {
+            canvas.width = 48;
+            canvas.height = 48;
+            ctx.clearRect(0, 0, 48, 48);
+            ctx.font = font;
+            ctx.textBaseline = 'top';
+            ctx.fillText(ch, 4, 4);
+            return ctx.getImageData(0, 0, 48, 48).data.join(',');
+        };
+
+        const missingGlyphs = (el, style) => {
+            const font = '32px ' + style.fontFamily;
+            const notdef = glyphPixels(font, '\uFFFF');
+            const chars = Array.from(new Set(Array.from(el.textContent || '')))
+                .filter(ch => ch.trim() !== '')
+                .slice(0, 200);
+            return chars.filter(ch => glyphPixels(font, ch) === notdef);
+        };
+
+        const fonts = args.selectors.map(selector => {
+            const el = document.querySelector(selector);
+            if (!el) return { selector, error: 'No element matches selector: ' + selector };
+
+            const style = getComputedStyle(el);
+            const stack = parseStack(style.fontFamily);
+            const rendered = stack.find(isAvailable) || null;
+            const unavailable = stack.slice(0, rendered ? stack.indexOf(rendered) : stack.length);
+
+            const info = {
+                selector,
+                element: mcp.cssPath(el),
+                requested_stack: stack,
+                rendered_family: rendered,
+                is_fallback: rendered !== null && rendered !== stack[0],
+                unavailable_families: unavailable,
+                font_size: style.fontSize,
+                font_weight: style.fontWeight,
+                font_style: style.fontStyle,
+                line_height: style.lineHeight
+            };
+            if (args.detect_missing_glyphs) {
+                info.missing_glyphs = missingGlyphs(el, style);
+            }
+            return info;
+        });
+
+        // Web fonts registered with the document and their load state
+        const loadedFaces = document.fonts
+            ? Array.from(document.fonts).map(face => ({
+                family: face.family.replace(/^["']|["']$/g, ''),
+                style: face.style,
+                weight: face.weight,
+                status: face.status
+            }))
+            : [];
+
+        return {
+            fonts,
+            web_fonts: loadedFaces,
+            fonts_ready: document.fonts ? document.fonts.status === 'loaded' : null
+        };"#;
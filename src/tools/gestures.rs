@@ -0,0 +1,233 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct GestureRequest {
+    window_label: Option<String>,
+    /// "tap", "swipe", "pinch" or "rotate"
+    gesture: String,
+    /// Element whose center is the gesture origin
+    selector: Option<String>,
+    /// Origin in CSS pixels relative to the viewport, when no selector is given
+    x: Option<f64>,
+    y: Option<f64>,
+    /// Swipe direction: "up", "down", "left" or "right"
+    direction: Option<String>,
+    /// Swipe length, or the starting finger distance for pinch/rotate, in CSS pixels
+    distance: Option<f64>,
+    /// Pinch scale factor (>1 zooms in, <1 zooms out)
+    scale: Option<f64>,
+    /// Rotation in degrees, clockwise
+    angle: Option<f64>,
+    /// Total gesture duration (default 300)
+    duration_ms: Option<u64>,
+    /// Number of pointermove steps (default 10)
+    steps: Option<u32>,
+    /// "touch" (default) or "pen"
+    pointer_type: Option<String>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for synthesizing single and multi-pointer touch gestures
+pub async fn handle_simulate_gesture<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    let validator = PayloadValidator::new("simulate_gesture", &payload);
+    let gesture = validator.field("gesture").and_then(|v| v.as_str()).map(str::to_string);
+    let has_selector = validator.field("selector").is_some();
+    validator
+        .required("gesture", FieldKind::OneOf(&["tap", "swipe", "pinch", "rotate"]))
+        .optional("selector", FieldKind::NonEmptyString)
+        .required_if(!has_selector, "x", FieldKind::Number { min: None, max: None })
+        .required_if(!has_selector, "y", FieldKind::Number { min: None, max: None })
+        .required_if(gesture.as_deref() == Some("swipe"), "direction", FieldKind::OneOf(&["up", "down", "left", "right"]))
+        .required_if(gesture.as_deref() == Some("pinch"), "scale", FieldKind::Number { min: Some(0.05), max: Some(20.0) })
+        .required_if(gesture.as_deref() == Some("rotate"), "angle", FieldKind::Number { min: Some(-720.0), max: Some(720.0) })
+        .optional("distance", FieldKind::Number { min: Some(1.0), max: None })
+        .optional("duration_ms", FieldKind::Integer { min: Some(0), max: Some(10_000) })
+        .optional("steps", FieldKind::Integer { min: Some(1), max: Some(200) })
+        .optional("pointer_type", FieldKind::OneOf(&["touch", "pen"]))
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: GestureRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for simulate_gesture: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let duration_ms = request.duration_ms.unwrap_or(if request.gesture == "tap" { 50 } else { 300 });
+    let default_distance = match request.gesture.as_str() {
+        "swipe" => 200.0,
+        _ => 100.0,
+    };
+
+    let js_code = build_probe_script(
+        "Gesture simulation error",
+        &json!({
+            "gesture": request.gesture,
+            "selector": request.selector,
+            "x": request.x,
+            "y": request.y,
+            "direction": request.direction,
+            "distance": request.distance.unwrap_or(default_distance),
+            "scale": request.scale,
+            "angle": request.angle,
+            "duration_ms": duration_ms,
+            "steps": request.steps.unwrap_or(10),
+            "pointer_type": request.pointer_type.clone().unwrap_or_else(|| "touch".to_string()),
+        }),
+        SIMULATE_GESTURE_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS).saturating_add(duration_ms),
+        "gesture simulation",
+    )
+    .await
+}
+
+/// Each finger is a pointer with its own id. Fingers move along paths computed per
+/// step and the matching Touch events are dispatched alongside where the engine
+/// supports constructing them, since many gesture libraries still listen for those.
+const SIMULATE_GESTURE_JS: &str = r#"        let origin;
+        let target;
+        if (args.selector) {
+            target = mcp.query(args.selector);
+            const r = target.getBoundingClientRect();
+            origin = { x: r.left + r.width / 2, y: r.top + r.height / 2 };
+        } else {
+            origin = { x: args.x, y: args.y };
+            target = document.elementFromPoint(origin.x, origin.y);
+            if (!target) throw new Error('No element at (' + origin.x + ', ' + origin.y + ')');
+        }
+
+        // Paths map progress t in [0, 1] to a point, one per finger
+        const half = args.distance / 2;
+        let paths;
+        if (args.gesture === 'tap') {
+            paths = [() => origin];
+        } else if (args.gesture === 'swipe') {
+            const d = { up: [0, -1], down: [0, 1], left: [-1, 0], right: [1, 0] }[args.direction];
+            paths = [t => ({ x: origin.x + d[0] * args.distance * t, y: origin.y + d[1] * args.distance * t })];
+        } else if (args.gesture === 'pinch') {
+            // Two fingers on a horizontal line, spreading or closing by the scale factor
+            const radius = t => half * (1 + (args.scale - 1) * t);
+            paths = [
+                t => ({ x: origin.x - radius(t), y: origin.y }),
+                t => ({ x: origin.x + radius(t), y: origin.y })
+            ];
+        } else {
+            const rad = t => (args.angle * t) * Math.PI / 180;
+            paths = [
+                t => ({ x: origin.x - half * Math.cos(rad(t)), y: origin.y - half * Math.sin(rad(t)) }),
+                t => ({ x: origin.x + half * Math.cos(rad(t)), y: origin.y + half * Math.sin(rad(t)) })
+            ];
+        }
+
+        const baseId = 100;
+        const touchSupported = typeof Touch === 'function' && typeof TouchEvent === 'function';
+        const fingers = paths.map((path, i) => ({ id: baseId + i, path, pos: path(0) }));
+        const received = {};
+
+        const makeTouch = (finger) => new Touch({
+            identifier: finger.id,
+            target,
+            clientX: finger.pos.x,
+            clientY: finger.pos.y,
+            radiusX: 5,
+            radiusY: 5,
+            force: 0.5
+        });
+
+        const dispatchPointer = (type, finger) => {
+            const event = new PointerEvent(type, {
+                bubbles: true,
+                cancelable: true,
+                composed: true,
+                view: window,
+                pointerId: finger.id,
+                pointerType: args.pointer_type,
+                isPrimary: finger.id === baseId,
+                clientX: finger.pos.x,
+                clientY: finger.pos.y,
+                width: 10,
+                height: 10,
+                pressure: type === 'pointerup' ? 0 : 0.5,
+                buttons: type === 'pointerup' ? 0 : 1
+            });
+            if (!target.dispatchEvent(event)) received[type + '_prevented'] = true;
+            received[type] = (received[type] || 0) + 1;
+        };
+
+        const dispatchTouch = (type, active, changed) => {
+            if (!touchSupported || args.pointer_type !== 'touch') return;
+            const event = new TouchEvent(type, {
+                bubbles: true,
+                cancelable: true,
+                composed: true,
+                view: window,
+                touches: active.map(makeTouch),
+                targetTouches: active.map(makeTouch),
+                changedTouches: changed.map(makeTouch)
+            });
+            if (!target.dispatchEvent(event)) received[type + '_prevented'] = true;
+            received[type] = (received[type] || 0) + 1;
+        };
+
+        const wait = (ms) => new Promise(resolve => setTimeout(resolve, ms));
+
+        // Fingers land one after another like a real hand
+        fingers.forEach((finger, i) => {
+            dispatchPointer('pointerdown', finger);
+            dispatchTouch('touchstart', fingers.slice(0, i + 1), [finger]);
+        });
+
+        const steps = args.gesture === 'tap' ? 0 : args.steps;
+        const stepDelay = steps > 0 ? args.duration_ms / steps : args.duration_ms;
+        for (let step = 1; step <= steps; step++) {
+            await wait(stepDelay);
+            const t = step / steps;
+            fingers.forEach(finger => {
+                finger.pos = finger.path(t);
+                dispatchPointer('pointermove', finger);
+            });
+            dispatchTouch('touchmove', fingers, fingers);
+        }
+        if (steps === 0 && stepDelay > 0) await wait(stepDelay);
+
+        fingers.forEach((finger, i) => {
+            dispatchPointer('pointerup', finger);
+            dispatchTouch('touchend', fingers.slice(i + 1), [finger]);
+        });
+
+        // A tap also produces the compatibility click
+        if (args.gesture === 'tap') {
+            const f = fingers[0];
+            target.dispatchEvent(new MouseEvent('click', { bubbles: true, cancelable: true, composed: true, view: window, clientX: f.pos.x, clientY: f.pos.y }));
+            received.click = 1;
+        }
+
+        return {
+            gesture: args.gesture,
+            target: mcp.cssPath(target),
+            origin,
+            pointers: fingers.map(finger => ({ pointer_id: finger.id, start: finger.path(0), end: finger.pos })),
+            steps,
+            duration_ms: args.duration_ms,
+            touch_events: touchSupported && args.pointer_type === 'touch',
+            events: received
+        };"#;
@@ -4,6 +4,7 @@ use log::info;
 
 use crate::error::Error;
 use crate::socket_server::SocketResponse;
+use crate::{AutoInjectConfig, TauriMcpExt};
 use std::env;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -16,6 +17,10 @@ pub struct HealthCheckResponse {
     pub capabilities: Vec<String>,
     pub connection_status: ConnectionStatus,
     pub webview_status: WebviewStatus,
+    /// Capture scripts injected automatically on page load
+    pub auto_inject: AutoInjectConfig,
+    /// Startup time and slowest phase; `get_init_timing` has every phase
+    pub init_timing: Option<Value>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -79,6 +84,8 @@ pub fn handle_health_check<R: Runtime>(
         capabilities,
         connection_status,
         webview_status,
+        auto_inject: app.tauri_mcp().auto_inject_config().clone(),
+        init_timing: app.tauri_mcp().init_timing().map(|timing| timing.summary()),
     };
 
     let data = serde_json::to_value(&response)
@@ -108,11 +115,121 @@ fn detect_capabilities<R: Runtime>(_app: &AppHandle<R>) -> Vec<String> {
         "network_inspector".to_string(),
         "inject_network_capture".to_string(),
         "state_dump".to_string(),
+        "diff_state".to_string(),
         "get_exceptions".to_string(),
         "inject_error_tracker".to_string(),
         "clear_exceptions".to_string(),
         "get_performance_metrics".to_string(),
         "health_check".to_string(),
+        "get_box_model".to_string(),
+        "get_zoom".to_string(),
+        "set_zoom".to_string(),
+        "get_font_info".to_string(),
+        "tray".to_string(),
+        "get_monitors".to_string(),
+        "screenshot_monitor".to_string(),
+        "handle_next_dialog".to_string(),
+        "get_service_workers".to_string(),
+        "unregister_service_worker".to_string(),
+        "element_relations".to_string(),
+        "capture_canvas".to_string(),
+        "get_csp".to_string(),
+        "get_semantic_outline".to_string(),
+        "simulate_hover".to_string(),
+        "unhover".to_string(),
+        "get_layout_shifts".to_string(),
+        "get_loaded_scripts".to_string(),
+        "get_local_storage_map".to_string(),
+        "set_local_storage_map".to_string(),
+        "get_webview_info".to_string(),
+        "navigate_history".to_string(),
+        "get_text".to_string(),
+        "execute_js_file".to_string(),
+        "measure_reflows".to_string(),
+        "get_document_title".to_string(),
+        "set_document_title".to_string(),
+        "get_favicon".to_string(),
+        "list_shortcuts".to_string(),
+        "trigger_shortcut".to_string(),
+        "diff_computed_style".to_string(),
+        "set_animations_paused".to_string(),
+        "get_contenteditable".to_string(),
+        "set_contenteditable".to_string(),
+        "enumerate_devices".to_string(),
+        "get_permissions_state".to_string(),
+        "start_exception_stream".to_string(),
+        "stop_exception_stream".to_string(),
+        "simulate_gesture".to_string(),
+        "clear_performance_entries".to_string(),
+        "find_detached_nodes".to_string(),
+        "get_positioned_elements".to_string(),
+        "check_contrast".to_string(),
+        "get_selection".to_string(),
+        "set_selection".to_string(),
+        "get_mutation_journal".to_string(),
+        "element_from_point".to_string(),
+        "measure_input_latency".to_string(),
+        "get_images".to_string(),
+        "force_rerender".to_string(),
+        "export_window_layout".to_string(),
+        "import_window_layout".to_string(),
+        "capture_postmessage".to_string(),
+        "get_postmessages".to_string(),
+        "clear_postmessages".to_string(),
+        "save_baseline".to_string(),
+        "compare_baseline".to_string(),
+        "get_workers".to_string(),
+        "get_css_coverage".to_string(),
+        "trigger_lazy_load".to_string(),
+        "get_metadata".to_string(),
+        "get_menu".to_string(),
+        "trigger_menu_item".to_string(),
+        "get_stacking_contexts".to_string(),
+        "set_battery".to_string(),
+        "set_connection".to_string(),
+        "get_dom_stats".to_string(),
+        "get_route".to_string(),
+        "register_user_script".to_string(),
+        "unregister_user_script".to_string(),
+        "list_user_scripts".to_string(),
+        "get_pixel_color".to_string(),
+        "set_design_mode".to_string(),
+        "measure_action_fps".to_string(),
+        "get_custom_elements".to_string(),
+        "get_scroll_snap_state".to_string(),
+        "get_tab_order".to_string(),
+        "get_observers_for".to_string(),
+        "simulate_close_request".to_string(),
+        "capture_live_regions".to_string(),
+        "get_announcements".to_string(),
+        "get_shadow_dom".to_string(),
+        "get_interaction_trace".to_string(),
+        "hit_test".to_string(),
+        "get_stylesheet".to_string(),
+        "get_script_timing".to_string(),
+        "toggle_layout_overlay".to_string(),
+        "get_scroll_restoration".to_string(),
+        "set_scroll_restoration".to_string(),
+        "purge_all_storage".to_string(),
+        "get_capture_overhead".to_string(),
+        "trigger_intersection".to_string(),
+        "get_scroll_behavior".to_string(),
+        "set_scroll_behavior".to_string(),
+        "set_scroll_position".to_string(),
+        "get_init_timing".to_string(),
+        "get_interaction_blockers".to_string(),
+        "capture_analytics".to_string(),
+        "get_analytics_events".to_string(),
+        "clear_analytics_events".to_string(),
+        "get_intl_locale".to_string(),
+        "set_intl_locale".to_string(),
+        "get_compositing_layers".to_string(),
+        "reorder_item".to_string(),
+        "get_select_state".to_string(),
+        "select_option".to_string(),
+        "capture_fixture".to_string(),
+        "assert_fixture".to_string(),
+        "get_cookies".to_string(),
     ]
 }
 
@@ -0,0 +1,156 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct HoverRequest {
+    window_label: Option<String>,
+    /// Element to hover; `unhover` defaults to the element hovered last
+    selector: Option<String>,
+    /// Element expected to appear (or disappear on unhover), e.g. a tooltip
+    watch_selector: Option<String>,
+    /// How long to wait for hover-triggered UI to react (default 300)
+    wait_ms: Option<u64>,
+    timeout_ms: Option<u64>,
+}
+
+fn parse_request(command: &str, payload: Value, selector_required: bool) -> Result<(HoverRequest, String), Error> {
+    PayloadValidator::new(command, &payload)
+        .required_if(selector_required, "selector", FieldKind::NonEmptyString)
+        .optional("watch_selector", FieldKind::NonEmptyString)
+        .optional("wait_ms", FieldKind::Integer { min: Some(0), max: Some(10_000) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: HoverRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for {}: {}", command, e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    Ok((request, window_label))
+}
+
+async fn run_hover<R: Runtime>(
+    app: &AppHandle<R>,
+    command: &str,
+    payload: Value,
+    leave: bool,
+) -> Result<SocketResponse, Error> {
+    let (request, window_label) = parse_request(command, payload, !leave)?;
+    let wait_ms = request.wait_ms.unwrap_or(300);
+
+    let js_code = build_probe_script(
+        "Hover simulation error",
+        &json!({
+            "selector": request.selector,
+            "watch_selector": request.watch_selector,
+            "wait_ms": wait_ms,
+            "leave": leave,
+        }),
+        HOVER_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS).saturating_add(wait_ms),
+        command,
+    )
+    .await
+}
+
+/// Handler function for hovering an element with synthetic pointer events
+pub async fn handle_simulate_hover<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    run_hover(app, "simulate_hover", payload, false).await
+}
+
+/// Handler function for ending a simulated hover
+pub async fn handle_unhover<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    run_hover(app, "unhover", payload, true).await
+}
+
+/// Dispatches the enter/over/move (or out/leave) sequence a real pointer produces,
+/// then reports how the element and the watched element changed
+const HOVER_JS: &str = r#"        const state = window.__MCP_HOVER__ || (window.__MCP_HOVER__ = { selector: null });
+        const selector = args.selector || state.selector;
+        if (!selector) {
+            throw new Error('No selector given and no element is currently hovered');
+        }
+        const el = mcp.query(selector);
+
+        const STYLE_PROPS = ['display', 'visibility', 'opacity', 'color', 'background-color', 'border-color', 'text-decoration-line', 'transform', 'cursor'];
+        const styles = () => {
+            const computed = getComputedStyle(el);
+            return Object.fromEntries(STYLE_PROPS.map(p => [p, computed.getPropertyValue(p)]));
+        };
+        const watched = () => {
+            if (!args.watch_selector) return null;
+            const target = document.querySelector(args.watch_selector);
+            return { exists: target !== null, visible: target !== null && mcp.isVisible(target) };
+        };
+
+        const stylesBefore = styles();
+        const watchBefore = watched();
+
+        const r = el.getBoundingClientRect();
+        const init = {
+            bubbles: true,
+            cancelable: true,
+            composed: true,
+            view: window,
+            clientX: r.left + r.width / 2,
+            clientY: r.top + r.height / 2
+        };
+        const pointer = (type, bubbles) => new PointerEvent(type, { ...init, bubbles, pointerId: 1, pointerType: 'mouse', isPrimary: true });
+        const mouse = (type, bubbles) => new MouseEvent(type, { ...init, bubbles });
+
+        const sequence = args.leave
+            ? [pointer('pointerout', true), pointer('pointerleave', false), mouse('mouseout', true), mouse('mouseleave', false)]
+            : [pointer('pointerover', true), pointer('pointerenter', false), mouse('mouseover', true), mouse('mouseenter', false),
+               pointer('pointermove', true), mouse('mousemove', true)];
+        sequence.forEach(event => el.dispatchEvent(event));
+
+        state.selector = args.leave ? null : selector;
+
+        if (args.wait_ms > 0) {
+            await new Promise(resolve => setTimeout(resolve, args.wait_ms));
+        }
+
+        const stylesAfter = styles();
+        const changedStyles = Object.fromEntries(
+            STYLE_PROPS.filter(p => stylesBefore[p] !== stylesAfter[p]).map(p => [p, { before: stylesBefore[p], after: stylesAfter[p] }])
+        );
+        const watchAfter = watched();
+
+        return {
+            selector,
+            action: args.leave ? 'unhover' : 'hover',
+            events: sequence.map(event => event.type),
+            position: { x: init.clientX, y: init.clientY },
+            changed_styles: changedStyles,
+            watch: args.watch_selector ? {
+                selector: args.watch_selector,
+                before: watchBefore,
+                after: watchAfter,
+                appeared: !watchBefore.visible && watchAfter.visible,
+                disappeared: watchBefore.visible && !watchAfter.visible
+            } : null,
+            // Synthetic events run JS hover handlers but cannot activate CSS :hover rules
+            css_hover_note: 'CSS :hover styles need a real pointer; use simulate_mouse_movement to move the cursor over the element'
+        };"#;
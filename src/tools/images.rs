@@ -0,0 +1,190 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct GetImagesRequest {
+    window_label: Option<String>,
+    /// Only report images inside this element
+    root_selector: Option<String>,
+    /// Include CSS background images (default true)
+    include_background: Option<bool>,
+    /// Return only images with at least one issue (default false)
+    issues_only: Option<bool>,
+    /// Relative aspect ratio difference that counts as distorted (default 0.1)
+    aspect_tolerance: Option<f64>,
+    /// Maximum number of images to return (default 200)
+    limit: Option<usize>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for auditing images and their load state
+pub async fn handle_get_images<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_images", &payload)
+        .optional("root_selector", FieldKind::NonEmptyString)
+        .optional("include_background", FieldKind::Bool)
+        .optional("issues_only", FieldKind::Bool)
+        .optional("aspect_tolerance", FieldKind::Number { min: Some(0.0), max: Some(10.0) })
+        .optional("limit", FieldKind::Integer { min: Some(1), max: Some(5000) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: GetImagesRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_images: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Image audit error",
+        &json!({
+            "root_selector": request.root_selector,
+            "include_background": request.include_background.unwrap_or(true),
+            "issues_only": request.issues_only.unwrap_or(false),
+            "aspect_tolerance": request.aspect_tolerance.unwrap_or(0.1),
+            "limit": request.limit.unwrap_or(200),
+        }),
+        GET_IMAGES_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "image audit",
+    )
+    .await
+}
+
+/// `<img>` state comes from the element itself. Background images have no load state
+/// in the DOM, so each distinct URL is loaded once into an `Image` (served from cache
+/// when the page already fetched it) with a short deadline.
+const GET_IMAGES_JS: &str = r#"        const root = args.root_selector ? mcp.query(args.root_selector) : document.documentElement;
+        const BACKGROUND_LOAD_TIMEOUT_MS = 2000;
+        const dpr = window.devicePixelRatio || 1;
+        const round = (v) => Math.round(v * 100) / 100;
+
+        const images = [];
+
+        for (const img of root.querySelectorAll('img')) {
+            const r = img.getBoundingClientRect();
+            const style = getComputedStyle(img);
+            const loaded = img.complete && img.naturalWidth > 0;
+            const issues = [];
+            const src = img.currentSrc || img.src || null;
+            if (!src) issues.push('missing_src');
+            else if (img.complete && img.naturalWidth === 0) issues.push('failed_to_load');
+            else if (!img.complete) issues.push(img.loading === 'lazy' ? 'not_loaded_lazy' : 'loading');
+            if (!img.hasAttribute('alt')) issues.push('missing_alt');
+
+            let aspect = null;
+            if (loaded && r.width > 0 && r.height > 0) {
+                const natural = img.naturalWidth / img.naturalHeight;
+                const displayed = r.width / r.height;
+                aspect = { natural: round(natural), displayed: round(displayed) };
+                // object-fit other than fill preserves the ratio even in a mismatched box
+                const fit = style.objectFit;
+                if ((fit === 'fill' || !fit) && Math.abs(displayed - natural) / natural > args.aspect_tolerance) {
+                    issues.push('distorted');
+                }
+                if (r.width * dpr > img.naturalWidth * 1.5 || r.height * dpr > img.naturalHeight * 1.5) {
+                    issues.push('upscaled');
+                }
+            }
+
+            images.push({
+                kind: 'img',
+                selector: mcp.cssPath(img),
+                url: src,
+                srcset: img.srcset || null,
+                alt: img.getAttribute('alt'),
+                natural_width: img.naturalWidth,
+                natural_height: img.naturalHeight,
+                displayed_width: round(r.width),
+                displayed_height: round(r.height),
+                complete: img.complete,
+                loaded,
+                loading: img.loading || 'eager',
+                object_fit: style.objectFit,
+                visible: mcp.isVisible(img),
+                aspect_ratio: aspect,
+                issues
+            });
+        }
+
+        if (args.include_background) {
+            const urlPattern = /url\(\s*(['"]?)(.*?)\1\s*\)/g;
+            const probes = new Map();
+            const probe = (url) => {
+                if (!probes.has(url)) {
+                    probes.set(url, new Promise(resolve => {
+                        const image = new Image();
+                        const timer = setTimeout(() => resolve({ status: 'timeout', width: 0, height: 0 }), BACKGROUND_LOAD_TIMEOUT_MS);
+                        image.onload = () => { clearTimeout(timer); resolve({ status: 'loaded', width: image.naturalWidth, height: image.naturalHeight }); };
+                        image.onerror = () => { clearTimeout(timer); resolve({ status: 'error', width: 0, height: 0 }); };
+                        image.src = url;
+                    }));
+                }
+                return probes.get(url);
+            };
+
+            const backgrounds = [];
+            for (const el of [root, ...root.querySelectorAll('*')]) {
+                const value = getComputedStyle(el).backgroundImage;
+                if (!value || value === 'none' || !value.includes('url(')) continue;
+                for (const match of value.matchAll(urlPattern)) {
+                    const url = match[2];
+                    if (!url) continue;
+                    backgrounds.push({ el, url, state: probe(url) });
+                }
+            }
+
+            for (const entry of backgrounds) {
+                const state = await entry.state;
+                const r = entry.el.getBoundingClientRect();
+                const issues = [];
+                if (state.status === 'error') issues.push('failed_to_load');
+                if (state.status === 'timeout') issues.push('loading');
+                images.push({
+                    kind: 'background',
+                    selector: mcp.cssPath(entry.el),
+                    url: entry.url.startsWith('data:') ? entry.url.substring(0, 64) + '...' : entry.url,
+                    natural_width: state.width,
+                    natural_height: state.height,
+                    displayed_width: round(r.width),
+                    displayed_height: round(r.height),
+                    loaded: state.status === 'loaded',
+                    background_size: getComputedStyle(entry.el).backgroundSize,
+                    visible: mcp.isVisible(entry.el),
+                    issues
+                });
+            }
+        }
+
+        const counts = {};
+        for (const image of images) {
+            for (const issue of image.issues) counts[issue] = (counts[issue] || 0) + 1;
+        }
+        const reported = args.issues_only ? images.filter(image => image.issues.length > 0) : images;
+
+        return {
+            total: images.length,
+            img_count: images.filter(image => image.kind === 'img').length,
+            background_count: images.filter(image => image.kind === 'background').length,
+            with_issues: images.filter(image => image.issues.length > 0).length,
+            issue_counts: counts,
+            truncated: reported.length > args.limit,
+            images: reported.slice(0, args.limit)
+        };"#;
@@ -0,0 +1,145 @@
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::TauriMcpExt;
+
+/// One timed step of the plugin's startup
+#[derive(Debug, Clone, Serialize)]
+pub struct InitPhase {
+    pub name: String,
+    /// Window the phase ran for, for per-window page-load phases
+    pub window_label: Option<String>,
+    /// Offset from `init_with_config` being called
+    pub started_after_ms: f64,
+    pub duration_ms: f64,
+    pub error: Option<String>,
+}
+
+/// Startup phases recorded from `init_with_config` until each window's first page load.
+/// Shared between the plugin builder's closures before the plugin state exists.
+#[derive(Debug, Clone, Serialize)]
+pub struct InitTiming {
+    pub started_at_ms: u64,
+    /// Time from `init_with_config` until the plugin's setup hook finished
+    pub ready_after_ms: Option<f64>,
+    pub phases: Vec<InitPhase>,
+    #[serde(skip)]
+    origin: Instant,
+    #[serde(skip)]
+    loaded_windows: HashSet<String>,
+}
+
+pub type SharedInitTiming = Arc<Mutex<InitTiming>>;
+
+impl InitTiming {
+    pub fn new_shared() -> SharedInitTiming {
+        Arc::new(Mutex::new(InitTiming {
+            started_at_ms: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or(0),
+            ready_after_ms: None,
+            phases: Vec::new(),
+            origin: Instant::now(),
+            loaded_windows: HashSet::new(),
+        }))
+    }
+
+    fn offset_ms(&self, at: Instant) -> f64 {
+        at.saturating_duration_since(self.origin).as_secs_f64() * 1000.0
+    }
+
+    pub fn record(&mut self, name: &str, window_label: Option<&str>, started: Instant, error: Option<String>) {
+        self.phases.push(InitPhase {
+            name: name.to_string(),
+            window_label: window_label.map(str::to_string),
+            started_after_ms: self.offset_ms(started),
+            duration_ms: started.elapsed().as_secs_f64() * 1000.0,
+            error,
+        });
+    }
+
+    pub fn mark_ready(&mut self) {
+        self.ready_after_ms = Some(self.offset_ms(Instant::now()));
+    }
+
+    /// Whether this is the first finished page load seen for a window, which is the only
+    /// one whose phases are recorded
+    pub fn first_page_load(&mut self, window_label: &str) -> bool {
+        self.loaded_windows.insert(window_label.to_string())
+    }
+
+    pub fn slowest_phase(&self) -> Option<&InitPhase> {
+        self.phases
+            .iter()
+            .max_by(|a, b| a.duration_ms.total_cmp(&b.duration_ms))
+    }
+
+    /// Short form reported by `health_check`
+    pub fn summary(&self) -> Value {
+        let slowest = self.slowest_phase();
+        json!({
+            "readyAfterMs": self.ready_after_ms,
+            "phaseCount": self.phases.len(),
+            "slowestPhase": slowest.map(|phase| phase.name.clone()),
+            "slowestPhaseMs": slowest.map(|phase| phase.duration_ms),
+            "failedPhases": self.phases.iter().filter(|phase| phase.error.is_some()).map(|phase| phase.name.clone()).collect::<Vec<_>>(),
+        })
+    }
+}
+
+/// Time `work` as a startup phase on the shared record
+pub fn time_phase<T>(timing: &SharedInitTiming, name: &str, window_label: Option<&str>, work: impl FnOnce() -> T) -> T {
+    let started = Instant::now();
+    let result = work();
+    if let Ok(mut timing) = timing.lock() {
+        timing.record(name, window_label, started, None);
+    }
+    result
+}
+
+/// Like [`time_phase`], also recording the error of a failed phase
+pub fn time_fallible_phase<T, E: std::fmt::Display>(
+    timing: &SharedInitTiming,
+    name: &str,
+    work: impl FnOnce() -> Result<T, E>,
+) -> Result<T, E> {
+    let started = Instant::now();
+    let result = work();
+    if let Ok(mut timing) = timing.lock() {
+        timing.record(name, None, started, result.as_ref().err().map(|e| e.to_string()));
+    }
+    result
+}
+
+/// Handler function for returning the recorded plugin startup phases
+pub fn handle_get_init_timing<R: Runtime>(
+    app: &AppHandle<R>,
+    _payload: Value,
+) -> Result<SocketResponse, Error> {
+    let timing = app
+        .tauri_mcp()
+        .init_timing()
+        .ok_or_else(|| Error::Anyhow { message: "Init timing record is unavailable (lock poisoned)".to_string() })?;
+    let slowest = timing.slowest_phase().cloned();
+    let total_ms: f64 = timing.phases.iter().map(|phase| phase.duration_ms).sum();
+
+    let mut data = serde_json::to_value(&timing)
+        .map_err(|e| Error::serialization_error(format!("Failed to serialize init timing: {}", e)))?;
+    if let Some(object) = data.as_object_mut() {
+        object.insert("slowest_phase".to_string(), json!(slowest));
+        object.insert("timed_total_ms".to_string(), json!(total_ms));
+    }
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(data),
+        error: None,
+    })
+}
@@ -0,0 +1,382 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, eval_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+/// Largest ring buffer accepted from the config or a request
+pub const MAX_TRACE_CAPACITY: usize = 10_000;
+
+/// A navigation this soon after a click or key press is taken to be caused by it
+const CAUSED_NAVIGATION_MS: u64 = 1000;
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct InteractionTraceRequest {
+    window_label: Option<String>,
+    /// Only the most recent `limit` events (default all buffered)
+    limit: Option<usize>,
+    /// Only return events with a greater id
+    since_id: Option<u64>,
+    /// Empty the buffer after reading it (default false)
+    clear: Option<bool>,
+    /// "entries" (default) or "replay", a bare list of commands to resend in order
+    format: Option<String>,
+    /// Resize the ring buffer, installing the recorder if it is not running yet
+    capacity: Option<usize>,
+    timeout_ms: Option<u64>,
+}
+
+/// Script that starts the recorder with the given ring buffer size, for
+/// `PluginConfig::record_interactions`
+pub(crate) fn recorder_script(capacity: usize) -> String {
+    format!("({})({});", INTERACTION_RECORDER_JS, capacity.clamp(1, MAX_TRACE_CAPACITY))
+}
+
+/// Handler function for reading the recent user interactions in a window
+pub async fn handle_get_interaction_trace<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_interaction_trace", &payload)
+        .optional("limit", FieldKind::Integer { min: Some(1), max: Some(MAX_TRACE_CAPACITY as i64) })
+        .optional("since_id", FieldKind::UINT)
+        .optional("clear", FieldKind::Bool)
+        .optional("format", FieldKind::OneOf(&["entries", "replay"]))
+        .optional("capacity", FieldKind::Integer { min: Some(1), max: Some(MAX_TRACE_CAPACITY as i64) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: InteractionTraceRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_interaction_trace: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Interaction trace error",
+        &json!({
+            "limit": request.limit,
+            "since_id": request.since_id.unwrap_or(0),
+            "clear": request.clear.unwrap_or(false),
+            "capacity": request.capacity,
+        }),
+        &format!("        ({})(args.capacity);\n{}", INTERACTION_RECORDER_JS, GET_TRACE_JS),
+    );
+    let mut data = eval_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "interaction trace",
+    )
+    .await?;
+
+    let replay = request.format.as_deref() == Some("replay");
+    if let Some(object) = data.as_object_mut() {
+        object.insert("format".to_string(), json!(if replay { "replay" } else { "entries" }));
+        if replay {
+            let events = object.get("entries").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            let (steps, skipped) = replay_steps(&events, &window_label);
+            object.insert("entries".to_string(), json!(steps));
+            object.insert("skipped".to_string(), json!(skipped));
+        }
+    }
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(data),
+        error: None,
+    })
+}
+
+/// Turn recorded events into socket commands that reproduce them, in the same
+/// `{command, payload}` shape as the mutation journal's replay format. Clicks become
+/// native clicks on the recorded selector, typed text is re-entered in one go, and
+/// shortcut keys are pressed in the page. Navigations caused by a preceding click or
+/// key press are left to that step; the rest are loaded directly.
+fn replay_steps(events: &[Value], window_label: &str) -> (Vec<Value>, Vec<Value>) {
+    let mut steps = Vec::new();
+    let mut skipped = Vec::new();
+    let mut last_action_ms: Option<u64> = None;
+
+    for (index, event) in events.iter().enumerate() {
+        let kind = event.get("type").and_then(|v| v.as_str()).unwrap_or_default();
+        // Selectors inside a shadow root are relative to it, which the replay commands cannot reach
+        let in_shadow_root = event.get("in_shadow_root").and_then(|v| v.as_bool()).unwrap_or(false);
+        let selector = event.get("selector").and_then(|v| v.as_str()).filter(|_| !in_shadow_root);
+        let time_ms = event.get("time_ms").and_then(|v| v.as_u64()).unwrap_or(0);
+        let mut skip = |reason: &str| skipped.push(json!({ "id": event.get("id"), "type": kind, "reason": reason }));
+
+        match kind {
+            "click" => match selector {
+                Some(selector) => {
+                    steps.push(json!({
+                        "command": "get_element_position",
+                        "payload": {
+                            "window_label": window_label,
+                            "selector_type": "css",
+                            "selector_value": selector,
+                            "should_click": true,
+                        }
+                    }));
+                    last_action_ms = Some(time_ms);
+                }
+                None => skip("no document-level selector was recorded for the clicked element"),
+            },
+            "input" => {
+                let control = event.get("control").and_then(|v| v.as_str()).unwrap_or_default();
+                let value = event.get("value").and_then(|v| v.as_str());
+                match (selector, value) {
+                    (_, _) if event.get("redacted").and_then(|v| v.as_bool()).unwrap_or(false) => {
+                        skip("password values are not recorded")
+                    }
+                    // Toggling a checkbox or radio is replayed by the click that did it
+                    (_, _) if control == "checkbox" || control == "radio" => {}
+                    (Some(selector), Some(value)) if control == "select" => steps.push(json!({
+                        "command": "execute_js",
+                        "payload": {
+                            "window_label": window_label,
+                            "code": format!(
+                                "(() => {{ const el = document.querySelector({}); el.value = {}; el.dispatchEvent(new Event('input', {{ bubbles: true }})); el.dispatchEvent(new Event('change', {{ bubbles: true }})); return el.value; }})()",
+                                Value::String(selector.to_string()),
+                                Value::String(value.to_string()),
+                            ),
+                        }
+                    })),
+                    (Some(selector), Some(value)) => steps.push(json!({
+                        "command": "send_text_to_element",
+                        "payload": {
+                            "window_label": window_label,
+                            "selector_type": "css",
+                            "selector_value": selector,
+                            "text": value,
+                        }
+                    })),
+                    _ => skip("no document-level selector or value was recorded for the edited element"),
+                }
+            }
+            "key" => match event.get("accelerator").and_then(|v| v.as_str()) {
+                Some(accelerator) => {
+                    steps.push(json!({
+                        "command": "trigger_shortcut",
+                        "payload": {
+                            "window_label": window_label,
+                            "accelerator": accelerator,
+                            "mechanism": "webview",
+                        }
+                    }));
+                    last_action_ms = Some(time_ms);
+                }
+                None => skip("key could not be expressed as an accelerator"),
+            },
+            "navigation" => {
+                let caused = last_action_ms.is_some_and(|t| time_ms.saturating_sub(t) <= CAUSED_NAVIGATION_MS);
+                match event.get("url").and_then(|v| v.as_str()) {
+                    _ if caused => {}
+                    // The trace starts on this page, so there is nothing to load
+                    _ if index == 0 => {}
+                    Some(url) => steps.push(json!({
+                        "command": "execute_js",
+                        "payload": {
+                            "window_label": window_label,
+                            "code": format!("location.assign({})", Value::String(url.to_string())),
+                        }
+                    })),
+                    None => skip("no URL was recorded for the navigation"),
+                }
+            }
+            // Submits follow from the click or Enter press before them
+            "submit" => {}
+            _ => skip("event type has no replay command"),
+        }
+    }
+
+    (steps, skipped)
+}
+
+/// Records trusted clicks, edits, shortcut-style key presses, form submits and
+/// navigations into `window.__MCP_INTERACTIONS__`, dropping the oldest entries past the
+/// capacity. The buffer is mirrored to `sessionStorage` so it survives reloads and
+/// same-origin page loads; consecutive edits of one field collapse into a single entry
+/// holding the final value, and password values are never stored. Registered as an
+/// initialization script when `PluginConfig::record_interactions` is set; otherwise the
+/// first `get_interaction_trace` call installs it for the current page only.
+const INTERACTION_RECORDER_JS: &str = r#"function(capacity) {
+    if (window.__MCP_INTERACTIONS__) {
+        if (capacity) window.__MCP_INTERACTIONS__.capacity = capacity;
+        return;
+    }
+    const STORAGE_KEY = '__mcp_interaction_trace__';
+    let saved = null;
+    try { saved = JSON.parse(sessionStorage.getItem(STORAGE_KEY) || 'null'); } catch (e) {}
+    const trace = window.__MCP_INTERACTIONS__ = {
+        capacity: capacity || (saved && saved.capacity) || 200,
+        installed_at_ms: Date.now(),
+        early: document.readyState === 'loading',
+        recording_since_ms: (saved && saved.recording_since_ms) || Date.now(),
+        next_id: (saved && saved.next_id) || 1,
+        dropped: (saved && saved.dropped) || 0,
+        events: saved && Array.isArray(saved.events) ? saved.events : []
+    };
+
+    let saveTimer = null;
+    trace.save = () => {
+        if (saveTimer) clearTimeout(saveTimer);
+        saveTimer = null;
+        try {
+            sessionStorage.setItem(STORAGE_KEY, JSON.stringify({
+                capacity: trace.capacity,
+                recording_since_ms: trace.recording_since_ms,
+                next_id: trace.next_id,
+                dropped: trace.dropped,
+                events: trace.events
+            }));
+        } catch (e) {}
+    };
+    const push = (event) => {
+        const entry = { id: trace.next_id++, time_ms: Date.now(), url: location.href, ...event };
+        trace.events.push(entry);
+        while (trace.events.length > trace.capacity) {
+            trace.events.shift();
+            trace.dropped += 1;
+        }
+        if (!saveTimer) saveTimer = setTimeout(trace.save, 250);
+        return entry;
+    };
+
+    // Selector relative to the element's own document or shadow root
+    const selectorFor = (el) => {
+        const parts = [];
+        for (let node = el; node && node.nodeType === Node.ELEMENT_NODE; node = node.parentElement) {
+            if (node.id && node.getRootNode().querySelectorAll('#' + CSS.escape(node.id)).length === 1) {
+                parts.unshift('#' + CSS.escape(node.id));
+                break;
+            }
+            const testId = node.getAttribute('data-testid');
+            if (testId) {
+                parts.unshift('[data-testid="' + CSS.escape(testId) + '"]');
+                break;
+            }
+            let part = node.tagName.toLowerCase();
+            const parent = node.parentElement;
+            if (parent) {
+                const same = [...parent.children].filter(child => child.tagName === node.tagName);
+                if (same.length > 1) part += ':nth-of-type(' + (same.indexOf(node) + 1) + ')';
+            }
+            parts.unshift(part);
+        }
+        return parts.join(' > ');
+    };
+    const describe = (el) => ({
+        selector: selectorFor(el),
+        in_shadow_root: el.getRootNode() instanceof ShadowRoot,
+        tag: el.tagName.toLowerCase(),
+        label: (el.getAttribute('aria-label') || el.innerText || el.getAttribute('placeholder') || el.getAttribute('name') || el.getAttribute('title') || '').trim().replace(/\s+/g, ' ').slice(0, 80) || null
+    });
+    const targetOf = (event) => {
+        const target = event.composedPath ? event.composedPath()[0] : event.target;
+        return target && target.nodeType === Node.ELEMENT_NODE ? target : (target && target.parentElement) || null;
+    };
+
+    let lastClick = null;
+    document.addEventListener('click', (event) => {
+        if (!event.isTrusted) return;
+        const el = targetOf(event);
+        if (!el) return;
+        // Activating a label clicks its control as well; keep only the user's click
+        if (lastClick && lastClick.el instanceof HTMLLabelElement && lastClick.el.control === el && event.timeStamp - lastClick.at < 50) return;
+        lastClick = { el, at: event.timeStamp };
+        push({ type: 'click', ...describe(el), button: event.button, x: Math.round(event.clientX), y: Math.round(event.clientY) });
+    }, true);
+
+    document.addEventListener('input', (event) => {
+        if (!event.isTrusted) return;
+        const el = targetOf(event);
+        if (!el) return;
+        const tag = el.tagName.toLowerCase();
+        const control = tag === 'input' ? (el.type || 'text') : tag === 'select' || tag === 'textarea' ? tag : el.isContentEditable ? 'contenteditable' : tag;
+        const redacted = control === 'password';
+        const value = redacted ? null : el.isContentEditable ? el.innerText : String(el.value);
+        const target = describe(el);
+        const last = trace.events[trace.events.length - 1];
+        if (last && last.type === 'input' && last.selector === target.selector && last.url === location.href) {
+            last.value = value;
+            last.time_ms = Date.now();
+            last.edits += 1;
+            if (!saveTimer) saveTimer = setTimeout(trace.save, 250);
+            return;
+        }
+        push({ type: 'input', ...target, control, value, redacted, edits: 1 });
+    }, true);
+
+    const SPECIAL_KEYS = new Set(['Enter', 'Escape', 'Tab', 'Backspace', 'Delete', 'ArrowUp', 'ArrowDown', 'ArrowLeft', 'ArrowRight', 'Home', 'End', 'PageUp', 'PageDown']);
+    document.addEventListener('keydown', (event) => {
+        if (!event.isTrusted || event.repeat || ['Control', 'Alt', 'Shift', 'Meta'].includes(event.key)) return;
+        const chord = event.ctrlKey || event.metaKey || event.altKey;
+        const special = SPECIAL_KEYS.has(event.key) || /^F\d{1,2}$/.test(event.key);
+        // Plain typing is captured by the input entries
+        if (!chord && !special) return;
+        const key = event.key === ' ' ? 'Space' : event.key === '+' ? 'Plus' : event.key.length === 1 ? event.key.toUpperCase() : event.key;
+        const modifiers = [event.ctrlKey && 'Ctrl', event.altKey && 'Alt', event.shiftKey && 'Shift', event.metaKey && 'Meta'].filter(Boolean);
+        const el = targetOf(event);
+        push({ type: 'key', key: event.key, code: event.code, accelerator: [...modifiers, key].join('+'), ...(el && el !== document.body ? describe(el) : { selector: null }) });
+    }, true);
+
+    document.addEventListener('submit', (event) => {
+        if (!event.isTrusted) return;
+        const el = targetOf(event);
+        push({ type: 'submit', ...(el ? describe(el) : { selector: null }) });
+    }, true);
+
+    const navigated = (navigation) => {
+        const last = trace.events[trace.events.length - 1];
+        if (last && last.type === 'navigation' && last.url === location.href && Date.now() - last.time_ms < 50) return;
+        push({ type: 'navigation', navigation, title: document.title || null });
+    };
+    for (const method of ['pushState', 'replaceState']) {
+        const native = history[method];
+        history[method] = function(...rest) {
+            const result = native.apply(this, rest);
+            navigated(method === 'pushState' ? 'push' : 'replace');
+            return result;
+        };
+    }
+    window.addEventListener('popstate', () => navigated('traverse'));
+    window.addEventListener('hashchange', () => navigated('hash'));
+    window.addEventListener('pagehide', () => trace.save());
+    if (trace.early) {
+        const entry = performance.getEntriesByType && performance.getEntriesByType('navigation')[0];
+        navigated(entry ? entry.type : 'load');
+    }
+}"#;
+
+const GET_TRACE_JS: &str = r#"        const trace = window.__MCP_INTERACTIONS__;
+        const matching = trace.events.filter(event => event.id > args.since_id);
+        const entries = args.limit ? matching.slice(-args.limit) : matching;
+        const result = {
+            recording_since_ms: trace.recording_since_ms,
+            installed_by: trace.early ? 'init_script' : 'get_interaction_trace',
+            capacity: trace.capacity,
+            buffered: trace.events.length,
+            dropped: trace.dropped,
+            last_id: trace.next_id - 1,
+            count: entries.length,
+            entries
+        };
+        if (!trace.early) {
+            result.note = 'The recorder was started by get_interaction_trace, so it only sees interactions since then and stops at the next full page load. Enable PluginConfig::record_interactions to record from page start.';
+        }
+        if (args.clear) {
+            trace.events = [];
+            trace.dropped = 0;
+            trace.recording_since_ms = Date.now();
+            trace.save();
+            result.cleared = true;
+        }
+        return result;"#;
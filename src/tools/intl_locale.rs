@@ -0,0 +1,269 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct GetIntlLocaleRequest {
+    window_label: Option<String>,
+    /// Currency used for the sample currency formatting (default "USD")
+    currency: Option<String>,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct SetIntlLocaleRequest {
+    window_label: Option<String>,
+    /// BCP 47 tag used when `Intl` constructors and `toLocale*` methods get no locale
+    locale: Option<String>,
+    /// IANA time zone used by date formatting that names none, e.g. "Europe/Berlin"
+    time_zone: Option<String>,
+    /// Remove the override and restore the original constructors
+    reset: Option<bool>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for reporting the default locale and formatting options `Intl` resolves to
+pub async fn handle_get_intl_locale<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_intl_locale", &payload)
+        .optional("currency", FieldKind::NonEmptyString)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: GetIntlLocaleRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_intl_locale: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Intl locale error",
+        &json!({ "currency": request.currency.as_deref().unwrap_or("USD") }),
+        &format!("{}\n        return snapshot();", INTL_SNAPSHOT_JS),
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "Intl locale",
+    )
+    .await
+}
+
+/// Handler function for fixing the locale and time zone `Intl` falls back to
+pub async fn handle_set_intl_locale<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("set_intl_locale", &payload)
+        .optional("locale", FieldKind::NonEmptyString)
+        .optional("time_zone", FieldKind::NonEmptyString)
+        .optional("reset", FieldKind::Bool)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: SetIntlLocaleRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for set_intl_locale: {}", e)))?;
+
+    let reset = request.reset.unwrap_or(false);
+    if !reset && request.locale.is_none() && request.time_zone.is_none() {
+        return Err(Error::invalid_parameter(
+            "locale",
+            "a locale, a time_zone, or reset: true",
+            "none of them",
+        ));
+    }
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Intl locale override error",
+        &json!({
+            "locale": request.locale,
+            "time_zone": request.time_zone,
+            "reset": reset,
+            "currency": "USD",
+        }),
+        &format!("{}\n{}", INTL_SNAPSHOT_JS, SET_INTL_LOCALE_JS),
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "Intl locale override",
+    )
+    .await
+}
+
+/// Defines `snapshot()`, shared by both commands: what a constructor called without a
+/// locale resolves to, sample formatting of a fixed number and instant, and the
+/// untouched browser defaults when an override is active.
+const INTL_SNAPSHOT_JS: &str = r#"        const SAMPLE_NUMBER = 1234567.891;
+        // 2024-03-09T14:05:00Z, a Saturday afternoon in most zones
+        const SAMPLE_INSTANT = Date.UTC(2024, 2, 9, 14, 5, 0);
+        const describe = (ctors) => {
+            const dateTime = new ctors.DateTimeFormat().resolvedOptions();
+            const number = new ctors.NumberFormat().resolvedOptions();
+            const full = new ctors.DateTimeFormat(undefined, { dateStyle: 'full', timeStyle: 'long' });
+            const currency = new ctors.NumberFormat(undefined, { style: 'currency', currency: args.currency });
+            return {
+                locale: dateTime.locale,
+                time_zone: dateTime.timeZone,
+                calendar: dateTime.calendar,
+                numbering_system: dateTime.numberingSystem,
+                hour_cycle: new ctors.DateTimeFormat(undefined, { hour: 'numeric' }).resolvedOptions().hourCycle || null,
+                number_locale: number.locale,
+                collator_locale: ctors.Collator ? new ctors.Collator().resolvedOptions().locale : null,
+                samples: {
+                    number: new ctors.NumberFormat().format(SAMPLE_NUMBER),
+                    currency: currency.format(SAMPLE_NUMBER),
+                    percent: new ctors.NumberFormat(undefined, { style: 'percent' }).format(0.256),
+                    date: new ctors.DateTimeFormat().format(SAMPLE_INSTANT),
+                    date_time: full.format(SAMPLE_INSTANT),
+                    relative_time: ctors.RelativeTimeFormat ? new ctors.RelativeTimeFormat(undefined, { numeric: 'auto' }).format(-1, 'day') : null,
+                    list: ctors.ListFormat ? new ctors.ListFormat().format(['a', 'b', 'c']) : null
+                }
+            };
+        };
+        const snapshot = () => {
+            const state = window.__MCP_INTL__;
+            const active = !!(state && (state.locale || state.time_zone));
+            return {
+                override: active ? { locale: state.locale, time_zone: state.time_zone } : null,
+                effective: describe(Intl),
+                native: active ? describe(state.originals) : null,
+                navigator_language: navigator.language,
+                navigator_languages: Array.from(navigator.languages || []),
+                // Date getters and getTimezoneOffset always follow the system zone
+                system_utc_offset_minutes: -new Date(SAMPLE_INSTANT).getTimezoneOffset()
+            };
+        };"#;
+
+/// Replaces the `Intl` constructors with wrappers that fill in the override locale
+/// when none is given (`undefined` or an empty list) and, for date formatting, the
+/// override time zone when the options name none. The `toLocale*` methods and
+/// `localeCompare` are wrapped the same way, since they do not go through the global
+/// constructors. The wrappers share the original prototypes, so `instanceof` and
+/// `supportedLocalesOf` keep working. The override lasts until the page reloads.
+const SET_INTL_LOCALE_JS: &str = r#"        const CONSTRUCTORS = ['DateTimeFormat', 'NumberFormat', 'Collator', 'PluralRules', 'RelativeTimeFormat', 'ListFormat', 'DisplayNames', 'Segmenter'];
+        const ZONED_METHODS = [[Date.prototype, 'toLocaleString'], [Date.prototype, 'toLocaleDateString'], [Date.prototype, 'toLocaleTimeString']];
+        const PLAIN_METHODS = [[Number.prototype, 'toLocaleString'], [Array.prototype, 'toLocaleString']];
+        if (typeof BigInt !== 'undefined') PLAIN_METHODS.push([BigInt.prototype, 'toLocaleString']);
+
+        const before = snapshot();
+
+        if (args.reset) {
+            const state = window.__MCP_INTL__;
+            if (!state) return { reset: true, was_active: false, before, after: before };
+            for (const name of Object.keys(state.originals)) {
+                Object.defineProperty(Intl, name, { value: state.originals[name], configurable: true, writable: true });
+            }
+            for (const [target, name, original] of state.methods) {
+                Object.defineProperty(target, name, { value: original, configurable: true, writable: true });
+            }
+            window.__MCP_INTL__ = undefined;
+            return { reset: true, was_active: true, before, after: snapshot() };
+        }
+
+        let state = window.__MCP_INTL__;
+        const originals = state ? state.originals : Object.fromEntries(CONSTRUCTORS.filter(name => typeof Intl[name] === 'function').map(name => [name, Intl[name]]));
+
+        // Validate against the untouched constructors before changing anything
+        let locale = state ? state.locale : null;
+        let timeZone = state ? state.time_zone : null;
+        if (args.locale !== null) {
+            try {
+                locale = Intl.getCanonicalLocales(args.locale)[0];
+            } catch (error) {
+                throw new Error('Invalid locale ' + JSON.stringify(args.locale) + ': ' + error.message);
+            }
+            const supported = originals.DateTimeFormat.supportedLocalesOf([locale]);
+            if (supported.length === 0) throw new Error('Locale ' + locale + ' is not supported by this webview');
+        }
+        if (args.time_zone !== null) {
+            try {
+                timeZone = new originals.DateTimeFormat('en-US', { timeZone: args.time_zone }).resolvedOptions().timeZone;
+            } catch (error) {
+                throw new Error('Invalid time_zone ' + JSON.stringify(args.time_zone) + ': ' + error.message);
+            }
+        }
+
+        if (!state) {
+            state = { originals, methods: [], locale: null, time_zone: null };
+            const current = () => window.__MCP_INTL__ || state;
+            const localeFor = (locales) => {
+                const active = current().locale;
+                return active && (locales === undefined || (Array.isArray(locales) && locales.length === 0)) ? active : locales;
+            };
+            const zonedOptions = (options) => {
+                const zone = current().time_zone;
+                if (!zone || (options !== undefined && options !== null && options.timeZone !== undefined)) return options;
+                return Object.assign({}, options, { timeZone: zone });
+            };
+
+            for (const [name, Original] of Object.entries(originals)) {
+                const zoned = name === 'DateTimeFormat';
+                const Wrapped = function (locales, options) {
+                    const resolvedOptions = zoned ? zonedOptions(options) : options;
+                    if (new.target === undefined) return Original(localeFor(locales), resolvedOptions);
+                    return Reflect.construct(Original, [localeFor(locales), resolvedOptions], new.target === Wrapped ? Original : new.target);
+                };
+                Object.setPrototypeOf(Wrapped, Original);
+                Object.defineProperty(Wrapped, 'prototype', { value: Original.prototype });
+                Object.defineProperty(Wrapped, 'name', { value: name });
+                Object.defineProperty(Intl, name, { value: Wrapped, configurable: true, writable: true });
+            }
+            for (const [target, name] of ZONED_METHODS) {
+                const original = target[name];
+                state.methods.push([target, name, original]);
+                Object.defineProperty(target, name, {
+                    value: function (locales, options) { return original.call(this, localeFor(locales), zonedOptions(options)); },
+                    configurable: true,
+                    writable: true
+                });
+            }
+            for (const [target, name] of PLAIN_METHODS) {
+                const original = target[name];
+                state.methods.push([target, name, original]);
+                Object.defineProperty(target, name, {
+                    value: function (locales, options) { return original.call(this, localeFor(locales), options); },
+                    configurable: true,
+                    writable: true
+                });
+            }
+            const originalCompare = String.prototype.localeCompare;
+            state.methods.push([String.prototype, 'localeCompare', originalCompare]);
+            Object.defineProperty(String.prototype, 'localeCompare', {
+                value: function (that, locales, options) { return originalCompare.call(this, that, localeFor(locales), options); },
+                configurable: true,
+                writable: true
+            });
+            window.__MCP_INTL__ = state;
+        }
+
+        state.locale = locale;
+        state.time_zone = timeZone;
+        return {
+            before,
+            after: snapshot(),
+            note: 'Formatters created before the override keep their old locale; re-render the view to pick it up. Date getters such as getHours() still use the system time zone. The override lasts until the page reloads.'
+        };"#;
@@ -0,0 +1,254 @@
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::shared::commands;
+use crate::socket_server::SocketResponse;
+use crate::tools::validation::{FieldKind, PayloadValidator};
+use crate::TauriMcpExt;
+
+/// Oldest entries are dropped once the journal holds this many
+const MAX_JOURNAL_ENTRIES: usize = 10_000;
+
+/// One mutating command as it was received, so it can be sent again verbatim
+#[derive(Debug, Clone, Serialize)]
+pub struct JournalEntry {
+    pub seq: u64,
+    pub timestamp_ms: u64,
+    pub command: String,
+    pub payload: Value,
+    pub success: bool,
+    pub error: Option<String>,
+    pub duration_ms: u64,
+}
+
+/// Ordered record of the mutating commands executed while recording is on
+#[derive(Debug, Default)]
+pub struct MutationJournal {
+    recording: bool,
+    started_at_ms: Option<u64>,
+    next_seq: u64,
+    dropped: u64,
+    entries: Vec<JournalEntry>,
+}
+
+impl MutationJournal {
+    pub fn is_recording(&self) -> bool {
+        self.recording
+    }
+
+    /// Turn recording on or off, returning the previous setting
+    pub fn set_recording(&mut self, recording: bool) -> bool {
+        if recording && !self.recording {
+            self.started_at_ms = Some(now_ms());
+        }
+        std::mem::replace(&mut self.recording, recording)
+    }
+
+    pub fn push(&mut self, command: &str, payload: Value, success: bool, error: Option<String>, duration: Duration) {
+        self.next_seq += 1;
+        self.entries.push(JournalEntry {
+            seq: self.next_seq,
+            timestamp_ms: now_ms(),
+            command: command.to_string(),
+            payload,
+            success,
+            error,
+            duration_ms: duration.as_millis() as u64,
+        });
+        if self.entries.len() > MAX_JOURNAL_ENTRIES {
+            let excess = self.entries.len() - MAX_JOURNAL_ENTRIES;
+            self.entries.drain(..excess);
+            self.dropped += excess as u64;
+        }
+    }
+
+    /// Remove all entries, returning how many there were. Sequence numbers keep counting.
+    pub fn clear(&mut self) -> usize {
+        self.dropped = 0;
+        std::mem::take(&mut self.entries).len()
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Whether a command changes the app, the page or the window. Commands that
+/// multiplex reads and writes through an action are only mutating for the write actions.
+pub fn is_mutating(command: &str, payload: &Value) -> bool {
+    let action = |key: &str| payload.get(key).and_then(|v| v.as_str()).unwrap_or_default().to_string();
+    match command {
+        commands::EXECUTE_JS
+        | commands::EXECUTE_JS_FILE
+        | commands::MANAGE_WINDOW
+        | commands::SIMULATE_TEXT_INPUT
+        | commands::SIMULATE_MOUSE_MOVEMENT
+        | commands::SEND_TEXT_TO_ELEMENT
+        | commands::HOT_RELOAD
+        | commands::SET_ZOOM
+        | commands::HANDLE_NEXT_DIALOG
+        | commands::UNREGISTER_SERVICE_WORKER
+        | commands::SIMULATE_HOVER
+        | commands::UNHOVER
+        | commands::SET_LOCAL_STORAGE_MAP
+        | commands::PURGE_ALL_STORAGE
+        | commands::NAVIGATE_HISTORY
+        | commands::SET_DOCUMENT_TITLE
+        | commands::TRIGGER_SHORTCUT
+        | commands::SET_ANIMATIONS_PAUSED
+        | commands::SET_CONTENTEDITABLE
+        | commands::SET_DESIGN_MODE
+        | commands::SET_SCROLL_RESTORATION
+        | commands::SET_SCROLL_BEHAVIOR
+        | commands::SET_SCROLL_POSITION
+        | commands::SIMULATE_CLOSE_REQUEST
+        | commands::SIMULATE_GESTURE
+        | commands::REORDER_ITEM
+        | commands::SELECT_OPTION
+        | commands::CLEAR_PERFORMANCE_ENTRIES
+        | commands::SET_SELECTION
+        | commands::MEASURE_INPUT_LATENCY
+        | commands::MEASURE_ACTION_FPS
+        | commands::FORCE_RERENDER
+        | commands::IMPORT_WINDOW_LAYOUT
+        | commands::TRIGGER_LAZY_LOAD
+        | commands::TRIGGER_INTERSECTION
+        | commands::TRIGGER_MENU_ITEM
+        | commands::SET_BATTERY
+        | commands::SET_CONNECTION
+        | commands::SET_INTL_LOCALE
+        | commands::REGISTER_USER_SCRIPT
+        | commands::UNREGISTER_USER_SCRIPT => true,
+        commands::MANAGE_LOCAL_STORAGE => matches!(action("action").as_str(), "set" | "remove" | "clear"),
+        commands::STORAGE_INSPECTOR => {
+            matches!(action("action").as_str(), "clear_storage" | "set_item" | "remove_item" | "clear_cookies")
+        }
+        commands::NETWORK_INSPECTOR => {
+            matches!(
+                action("action").as_str(),
+                "set_default_headers" | "clear_default_headers" | "fail_resource_type" | "clear_resource_failures"
+            )
+        }
+        commands::TRAY => action("action") == "activate_menu_item",
+        commands::TOGGLE_LAYOUT_OVERLAY => action("action") != "status",
+        _ => false,
+    }
+}
+
+/// Payload to keep for the journal, if this command should be recorded
+pub fn journal_payload<R: Runtime>(app: &AppHandle<R>, command: &str, payload: &Value) -> Option<Value> {
+    if !is_mutating(command, payload) {
+        return None;
+    }
+    let recording = app
+        .tauri_mcp()
+        .with_mutation_journal(|journal| journal.is_recording())
+        .unwrap_or(false);
+    recording.then(|| payload.clone())
+}
+
+/// Append a finished command to the journal
+pub fn record_mutation<R: Runtime>(
+    app: &AppHandle<R>,
+    command: &str,
+    payload: Value,
+    result: &crate::Result<SocketResponse>,
+    duration: Duration,
+) {
+    let (success, error) = match result {
+        Ok(response) => (response.success, response.error.clone()),
+        Err(e) => (false, Some(e.to_string())),
+    };
+    app.tauri_mcp()
+        .with_mutation_journal(|journal| journal.push(command, payload, success, error, duration));
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct MutationJournalRequest {
+    /// "get" (default), "start", "stop" or "clear"
+    action: Option<String>,
+    /// Only return entries with a greater sequence number
+    since_seq: Option<u64>,
+    /// Leave out commands that failed (default false)
+    successful_only: Option<bool>,
+    /// "entries" (default) or "replay", a bare list of commands to resend in order
+    format: Option<String>,
+}
+
+/// Handler function for recording and retrieving the journal of mutating commands
+pub async fn handle_get_mutation_journal<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_mutation_journal", &payload)
+        .optional("action", FieldKind::OneOf(&["get", "start", "stop", "clear"]))
+        .optional("since_seq", FieldKind::UINT)
+        .optional("successful_only", FieldKind::Bool)
+        .optional("format", FieldKind::OneOf(&["entries", "replay"]))
+        .finish()?;
+
+    let request: MutationJournalRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_mutation_journal: {}", e)))?;
+
+    let action = request.action.clone().unwrap_or_else(|| "get".to_string());
+    let since_seq = request.since_seq.unwrap_or(0);
+    let successful_only = request.successful_only.unwrap_or(false);
+    let replay = request.format.as_deref() == Some("replay");
+
+    let data = app
+        .tauri_mcp()
+        .with_mutation_journal(|journal| match action.as_str() {
+            "start" => {
+                let was_recording = journal.set_recording(true);
+                json!({
+                    "recording": true,
+                    "already_recording": was_recording,
+                    "started_at_ms": journal.started_at_ms,
+                    "next_seq": journal.next_seq + 1,
+                })
+            }
+            "clear" => json!({ "recording": journal.recording, "cleared": journal.clear() }),
+            _ => {
+                if action == "stop" {
+                    journal.set_recording(false);
+                }
+                let entries: Vec<&JournalEntry> = journal
+                    .entries
+                    .iter()
+                    .filter(|entry| entry.seq > since_seq && (entry.success || !successful_only))
+                    .collect();
+                let body = if replay {
+                    json!(entries
+                        .iter()
+                        .map(|entry| json!({ "command": entry.command, "payload": entry.payload }))
+                        .collect::<Vec<_>>())
+                } else {
+                    json!(entries)
+                };
+                json!({
+                    "recording": journal.recording,
+                    "started_at_ms": journal.started_at_ms,
+                    "format": if replay { "replay" } else { "entries" },
+                    "count": entries.len(),
+                    "last_seq": journal.next_seq,
+                    "dropped": journal.dropped,
+                    "entries": body,
+                })
+            }
+        })
+        .ok_or_else(|| Error::Anyhow {
+            message: "Mutation journal is unavailable (lock poisoned)".to_string(),
+        })?;
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(data),
+        error: None,
+    })
+}
@@ -0,0 +1,260 @@
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Listener, Manager, Runtime};
+use log::info;
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+
+/// Default time to wait for a probe script to report back
+pub const DEFAULT_PROBE_TIMEOUT_MS: u64 = 5000;
+
+/// Source of the ids pairing an `execute-js` request with its response
+static NEXT_JS_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Why [`request_js_response`] returned without a response
+#[derive(Debug)]
+pub enum JsRequestError {
+    /// The `execute-js` event could not be emitted to the window
+    Emit(tauri::Error),
+    /// No response with the request's id arrived in time
+    Timeout(mpsc::RecvTimeoutError),
+}
+
+impl JsRequestError {
+    /// Convert into the plugin error for `operation`, as the socket handlers report it
+    pub fn into_error(self, window_label: &str, operation: &str, timeout_ms: u64) -> Error {
+        match self {
+            JsRequestError::Emit(e) => Error::communication_error_with_context(
+                "Failed to emit execute-js event",
+                format!("window: {}, error: {}", window_label, e),
+            ),
+            JsRequestError::Timeout(_) => Error::timeout_error(operation, timeout_ms),
+        }
+    }
+}
+
+/// Send a script to a window as an `execute-js` request and wait for the
+/// `execute-js-response` carrying the same `request_id`.
+///
+/// Responses to other requests in flight at the same time are ignored, and the
+/// listener is removed both when the response arrives and when the wait times out.
+/// `self_reporting` scripts dispatch their own response with the id the guest passes
+/// them as `__mcpRequestId`, like the output of [`build_probe_script`]; for other
+/// scripts the guest replies with their completion value.
+pub fn request_js_response<R: Runtime>(
+    app: &AppHandle<R>,
+    window_label: &str,
+    js_code: &str,
+    self_reporting: bool,
+    timeout: Duration,
+) -> Result<String, JsRequestError> {
+    let request_id = NEXT_JS_REQUEST_ID.fetch_add(1, Ordering::Relaxed);
+    let (tx, rx) = mpsc::channel();
+
+    // Listen before emitting so a fast reply isn't missed
+    let listener = app.listen("execute-js-response", move |event| {
+        let payload = event.payload();
+        let id = serde_json::from_str::<Value>(payload)
+            .ok()
+            .and_then(|response| response.get("request_id").and_then(Value::as_u64));
+        if id == Some(request_id) {
+            let _ = tx.send(payload.to_string());
+        }
+    });
+
+    let result = app
+        .emit_to(
+            window_label,
+            "execute-js",
+            json!({
+                "code": js_code,
+                "request_id": request_id,
+                "self_reporting": self_reporting,
+            }),
+        )
+        .map_err(JsRequestError::Emit)
+        .and_then(|_| rx.recv_timeout(timeout).map_err(JsRequestError::Timeout));
+
+    app.unlisten(listener);
+    result
+}
+
+/// Helpers made available to every probe body under the `mcp` namespace
+const PROBE_HELPERS: &str = r#"        const mcp = {
+            // Build a reasonably stable CSS selector path for an element
+            cssPath(el) {
+                if (!el || el.nodeType !== 1) return null;
+                if (el.id) return '#' + CSS.escape(el.id);
+                const parts = [];
+                let current = el;
+                while (current && current.nodeType === 1 && parts.length < 12) {
+                    let part = current.tagName.toLowerCase();
+                    if (current.id) {
+                        parts.unshift('#' + CSS.escape(current.id));
+                        break;
+                    }
+                    const parent = current.parentElement;
+                    if (parent) {
+                        const siblings = Array.from(parent.children).filter(c => c.tagName === current.tagName);
+                        if (siblings.length > 1) {
+                            part += ':nth-of-type(' + (siblings.indexOf(current) + 1) + ')';
+                        }
+                    }
+                    parts.unshift(part);
+                    current = parent;
+                }
+                return parts.join(' > ');
+            },
+            // Plain-object copy of a DOMRect
+            rect(r) {
+                return { x: r.x, y: r.y, width: r.width, height: r.height, top: r.top, right: r.right, bottom: r.bottom, left: r.left };
+            },
+            // Resolve a selector to exactly one element or throw a descriptive error
+            query(selector) {
+                if (!selector) throw new Error('selector is required');
+                const el = document.querySelector(selector);
+                if (!el) throw new Error('No element matches selector: ' + selector);
+                return el;
+            },
+            // Whether an element is rendered and visible
+            isVisible(el) {
+                const style = getComputedStyle(el);
+                if (style.display === 'none' || style.visibility === 'hidden' || style.opacity === '0') return false;
+                const r = el.getBoundingClientRect();
+                return r.width > 0 && r.height > 0;
+            },
+            // Run a capture shim's own bookkeeping and charge its self-time to the registry
+            // read by get_capture_overhead; intercepted = false adds time without a call
+            timed(capture, work, intercepted = true) {
+                const registry = window.__MCP_CAPTURE_OVERHEAD__ || (window.__MCP_CAPTURE_OVERHEAD__ = { captures: {}, stack: [] });
+                const started = performance.now();
+                registry.stack.push(0);
+                try {
+                    return work();
+                } finally {
+                    const total = performance.now() - started;
+                    const self = total - (registry.stack.pop() || 0);
+                    if (registry.stack.length > 0) registry.stack[registry.stack.length - 1] += total;
+                    const entry = registry.captures[capture] || (registry.captures[capture] = {
+                        calls: 0, timed_sections: 0, self_time_ms: 0, max_ms: 0, installed_at_ms: Date.now(), active: true
+                    });
+                    if (intercepted) entry.calls += 1;
+                    entry.timed_sections += 1;
+                    entry.self_time_ms += self;
+                    entry.max_ms = Math.max(entry.max_ms, self);
+                }
+            }
+        };
+"#;
+
+/// Wrap a probe body into a self-invoking async script that reports back over
+/// the `execute-js-response` channel.
+///
+/// `args` is exposed to the body as the `args` constant, and whatever the body
+/// returns is serialized as the probe result.
+pub fn build_probe_script(error_prefix: &str, args: &Value, body: &str) -> String {
+    format!(
+        r#"(async () => {{
+    try {{
+        const args = {args};
+{helpers}
+        const response = await (async () => {{
+{body}
+        }})();
+
+        window.dispatchEvent(new CustomEvent('execute-js-response', {{
+            detail: {{
+                request_id: typeof __mcpRequestId === 'undefined' ? null : __mcpRequestId,
+                result: JSON.stringify(response),
+                type: 'object'
+            }}
+        }}));
+    }} catch (error) {{
+        window.dispatchEvent(new CustomEvent('execute-js-response', {{
+            detail: {{
+                request_id: typeof __mcpRequestId === 'undefined' ? null : __mcpRequestId,
+                error: {prefix} + ': ' + error.message,
+                type: 'error'
+            }}
+        }}));
+    }}
+}})();"#,
+        args = args,
+        helpers = PROBE_HELPERS,
+        body = body,
+        prefix = Value::String(error_prefix.to_string()),
+    )
+}
+
+/// Evaluate a probe script in a window and return its parsed result
+pub async fn eval_probe<R: Runtime>(
+    app: &AppHandle<R>,
+    window_label: &str,
+    js_code: String,
+    timeout_ms: u64,
+    operation: &str,
+) -> Result<Value, Error> {
+    // Verify the window exists
+    let _window = app
+        .get_webview_window(window_label)
+        .ok_or_else(|| Error::window_not_found(window_label))?;
+
+    let result_string = request_js_response(app, window_label, &js_code, true, Duration::from_millis(timeout_ms))
+        .map_err(|e| e.into_error(window_label, operation, timeout_ms))?;
+
+    // Parse the response
+    let response_value: Value = serde_json::from_str(&result_string)
+        .map_err(|e| Error::serialization_error(format!("Failed to parse {} response: {}", operation, e)))?;
+
+    // Check if result contains an error
+    if let Some(error) = response_value.get("error") {
+        if let Some(error_str) = error.as_str() {
+            return Err(Error::Anyhow { message: error_str.to_string() });
+        }
+    }
+
+    // Extract and process the result
+    let result_str = response_value
+        .get("result")
+        .ok_or_else(|| Error::Anyhow { message: format!("No result in {} response", operation) })?
+        .as_str()
+        .ok_or_else(|| Error::Anyhow { message: format!("{} result is not a string", operation) })?;
+
+    match serde_json::from_str::<Value>(result_str) {
+        Ok(value) => Ok(value),
+        Err(e) => {
+            // If parsing fails, return the raw result
+            info!("[TAURI_MCP] Failed to parse {} result: {}", operation, e);
+            Ok(json!({
+                "raw_result": result_str,
+                "parse_error": e.to_string()
+            }))
+        }
+    }
+}
+
+/// Evaluate a probe script and wrap its result into a socket response
+pub async fn run_probe<R: Runtime>(
+    app: &AppHandle<R>,
+    window_label: &str,
+    js_code: String,
+    timeout_ms: u64,
+    operation: &str,
+) -> Result<SocketResponse, Error> {
+    match eval_probe(app, window_label, js_code, timeout_ms, operation).await {
+        Ok(data) => Ok(SocketResponse {
+            success: true,
+            data: Some(data),
+            error: None,
+        }),
+        Err(Error::Anyhow { message }) => Ok(SocketResponse {
+            success: false,
+            data: None,
+            error: Some(message),
+        }),
+        Err(e) => Err(e),
+    }
+}
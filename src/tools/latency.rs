@@ -0,0 +1,386 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct InputLatencyRequest {
+    window_label: Option<String>,
+    /// Element that receives the input
+    selector: String,
+    /// "click" (default) or "keypress"
+    input: Option<String>,
+    /// Key for "keypress" (default "Enter")
+    key: Option<String>,
+    /// Number of samples to take (default 1)
+    repeat: Option<u32>,
+    /// Pause between samples (default 100)
+    interval_ms: Option<u64>,
+    /// How long to wait for a DOM change after each input (default 1000)
+    max_wait_ms: Option<u64>,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ActionFpsRequest {
+    window_label: Option<String>,
+    /// Element that receives the action
+    selector: String,
+    /// "click" (default), "keypress" or "scroll"
+    action: Option<String>,
+    /// Key for "keypress" (default "Enter")
+    key: Option<String>,
+    /// Distance for "scroll" in CSS pixels (default 0 horizontally, 1000 vertically)
+    scroll_x: Option<f64>,
+    scroll_y: Option<f64>,
+    /// How long frames are sampled after the action starts (default 1000)
+    duration_ms: Option<u64>,
+    /// Idle sampling before the action, used to estimate the refresh rate (default 300)
+    baseline_ms: Option<u64>,
+    /// Frame budget in milliseconds; estimated from the baseline when omitted
+    frame_budget_ms: Option<f64>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for measuring the delay from a simulated input to the next paint
+pub async fn handle_measure_input_latency<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("measure_input_latency", &payload)
+        .required("selector", FieldKind::NonEmptyString)
+        .optional("input", FieldKind::OneOf(&["click", "keypress"]))
+        .optional("key", FieldKind::NonEmptyString)
+        .optional("repeat", FieldKind::Integer { min: Some(1), max: Some(100) })
+        .optional("interval_ms", FieldKind::Integer { min: Some(0), max: Some(10_000) })
+        .optional("max_wait_ms", FieldKind::Integer { min: Some(16), max: Some(30_000) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: InputLatencyRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for measure_input_latency: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let repeat = request.repeat.unwrap_or(1);
+    let interval_ms = request.interval_ms.unwrap_or(100);
+    let max_wait_ms = request.max_wait_ms.unwrap_or(1000);
+
+    let js_code = build_probe_script(
+        "Input latency error",
+        &json!({
+            "selector": request.selector,
+            "input": request.input.clone().unwrap_or_else(|| "click".to_string()),
+            "key": request.key.clone().unwrap_or_else(|| "Enter".to_string()),
+            "repeat": repeat,
+            "interval_ms": interval_ms,
+            "max_wait_ms": max_wait_ms,
+        }),
+        &format!("{}{}", DISPATCH_INPUT_JS, MEASURE_INPUT_LATENCY_JS),
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS).saturating_add(repeat as u64 * (max_wait_ms + interval_ms)),
+        "input latency measurement",
+    )
+    .await
+}
+
+/// Handler function for measuring the frame rate while an input plays out
+pub async fn handle_measure_action_fps<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("measure_action_fps", &payload)
+        .required("selector", FieldKind::NonEmptyString)
+        .optional("action", FieldKind::OneOf(&["click", "keypress", "scroll"]))
+        .optional("key", FieldKind::NonEmptyString)
+        .optional("scroll_x", FieldKind::Number { min: None, max: None })
+        .optional("scroll_y", FieldKind::Number { min: None, max: None })
+        .optional("duration_ms", FieldKind::Integer { min: Some(100), max: Some(20_000) })
+        .optional("baseline_ms", FieldKind::Integer { min: Some(0), max: Some(2_000) })
+        .optional("frame_budget_ms", FieldKind::Number { min: Some(1.0), max: Some(1000.0) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: ActionFpsRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for measure_action_fps: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let duration_ms = request.duration_ms.unwrap_or(1000);
+    let baseline_ms = request.baseline_ms.unwrap_or(300);
+
+    let js_code = build_probe_script(
+        "Action FPS error",
+        &json!({
+            "selector": request.selector,
+            "input": request.action.clone().unwrap_or_else(|| "click".to_string()),
+            "key": request.key.clone().unwrap_or_else(|| "Enter".to_string()),
+            "scroll_x": request.scroll_x.unwrap_or(0.0),
+            "scroll_y": request.scroll_y.unwrap_or(1000.0),
+            "duration_ms": duration_ms,
+            "baseline_ms": baseline_ms,
+            "frame_budget_ms": request.frame_budget_ms,
+        }),
+        &format!("{}{}", DISPATCH_INPUT_JS, MEASURE_ACTION_FPS_JS),
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS).saturating_add(baseline_ms + duration_ms),
+        "action frame rate measurement",
+    )
+    .await
+}
+
+/// Dispatches the input sequence a real click or keypress produces on `el`. Shared by
+/// the latency and frame-rate probes, which both define `el` and `args.input`/`args.key`.
+const DISPATCH_INPUT_JS: &str = r#"        const dispatchInput = () => {
+            if (args.input === 'keypress') {
+                const init = { key: args.key, code: args.key.length === 1 ? 'Key' + args.key.toUpperCase() : args.key, bubbles: true, cancelable: true, composed: true };
+                const down = el.dispatchEvent(new KeyboardEvent('keydown', init));
+                if (down) el.dispatchEvent(new KeyboardEvent('keypress', init));
+                el.dispatchEvent(new KeyboardEvent('keyup', init));
+                return;
+            }
+            const r = el.getBoundingClientRect();
+            const point = { clientX: r.left + r.width / 2, clientY: r.top + r.height / 2, bubbles: true, cancelable: true, composed: true, view: window };
+            el.dispatchEvent(new PointerEvent('pointerdown', { ...point, pointerType: 'mouse', isPrimary: true, buttons: 1 }));
+            el.dispatchEvent(new MouseEvent('mousedown', { ...point, buttons: 1 }));
+            el.dispatchEvent(new PointerEvent('pointerup', { ...point, pointerType: 'mouse', isPrimary: true }));
+            el.dispatchEvent(new MouseEvent('mouseup', point));
+            el.click();
+        };
+"#;
+
+/// Each sample timestamps the dispatch, the end of the synchronous handlers, the first
+/// DOM mutation and the frame after it. The paint time is the second animation frame
+/// after the mutation, the earliest point the change can be on screen. Synthetic events
+/// are untrusted, so the Event Timing API cannot be used here.
+const MEASURE_INPUT_LATENCY_JS: &str = r#"        const el = mcp.query(args.selector);
+        const wait = (ms) => new Promise(resolve => setTimeout(resolve, ms));
+        const nextPaint = () => new Promise(resolve => requestAnimationFrame(() => requestAnimationFrame(() => resolve(performance.now()))));
+
+        const sample = async () => {
+            // Start from a settled frame so a pending paint is not attributed to this input
+            await nextPaint();
+            let mutationAt = null;
+            let mutations = 0;
+            let resolveMutation;
+            const mutated = new Promise(resolve => { resolveMutation = resolve; });
+            const observer = new MutationObserver(records => {
+                mutations += records.length;
+                if (mutationAt === null) {
+                    mutationAt = performance.now();
+                    resolveMutation();
+                }
+            });
+            observer.observe(document, { subtree: true, childList: true, attributes: true, characterData: true });
+
+            const start = performance.now();
+            dispatchInput();
+            const handlersDone = performance.now();
+
+            // Mutation records are delivered as a microtask, so sync changes are already seen
+            await Promise.race([mutated, wait(args.max_wait_ms)]);
+            const paintAt = mutationAt === null ? null : await nextPaint();
+            observer.disconnect();
+
+            const round = (v) => v === null ? null : Math.round(v * 100) / 100;
+            return {
+                handler_ms: round(handlersDone - start),
+                to_mutation_ms: mutationAt === null ? null : round(mutationAt - start),
+                to_paint_ms: paintAt === null ? null : round(paintAt - start),
+                mutations,
+                responded: mutationAt !== null
+            };
+        };
+
+        const samples = [];
+        for (let i = 0; i < args.repeat; i++) {
+            if (!el.isConnected) throw new Error('Element was removed from the document after ' + i + ' samples: ' + args.selector);
+            samples.push(await sample());
+            if (i + 1 < args.repeat && args.interval_ms > 0) await wait(args.interval_ms);
+        }
+
+        const stats = (values) => {
+            const sorted = values.filter(v => v !== null).sort((a, b) => a - b);
+            if (sorted.length === 0) return null;
+            const pick = (p) => sorted[Math.min(sorted.length - 1, Math.ceil(p * sorted.length) - 1)];
+            const mean = sorted.reduce((sum, v) => sum + v, 0) / sorted.length;
+            return {
+                min: sorted[0],
+                median: pick(0.5),
+                p95: pick(0.95),
+                max: sorted[sorted.length - 1],
+                mean: Math.round(mean * 100) / 100
+            };
+        };
+
+        const responded = samples.filter(s => s.responded).length;
+        return {
+            selector: mcp.cssPath(el),
+            input: args.input,
+            key: args.input === 'keypress' ? args.key : null,
+            samples: samples.length,
+            responded,
+            to_paint_ms: stats(samples.map(s => s.to_paint_ms)),
+            to_mutation_ms: stats(samples.map(s => s.to_mutation_ms)),
+            handler_ms: stats(samples.map(s => s.handler_ms)),
+            runs: samples,
+            note: responded < samples.length ? 'Some inputs caused no DOM change within max_wait_ms; they are left out of the paint and mutation stats' : null
+        };"#;
+
+/// Frames are timed with a rAF loop that runs through an idle baseline, the action and
+/// the rest of the window. The budget defaults to the median baseline frame interval,
+/// which tracks the display refresh rate when the page is idle; an interval of n budgets
+/// counts as n - 1 dropped frames. Scrolls go to the nearest scrollable ancestor and are
+/// animated by the browser (`behavior: 'smooth'`) after a matching wheel event.
+const MEASURE_ACTION_FPS_JS: &str = r#"        const el = mcp.query(args.selector);
+        const round = (v) => v === null ? null : Math.round(v * 100) / 100;
+        const isScrollable = (node) => {
+            const style = getComputedStyle(node);
+            const scrollsY = /(auto|scroll|overlay)/.test(style.overflowY) && node.scrollHeight > node.clientHeight;
+            const scrollsX = /(auto|scroll|overlay)/.test(style.overflowX) && node.scrollWidth > node.clientWidth;
+            return scrollsX || scrollsY;
+        };
+        const scroller = () => {
+            for (let node = el; node && node !== document.documentElement; node = node.parentElement) {
+                if (node !== document.body && isScrollable(node)) return node;
+            }
+            return document.scrollingElement || document.documentElement;
+        };
+
+        const frames = [];
+        let running = true;
+        const tick = (time) => {
+            frames.push(time);
+            if (running) requestAnimationFrame(tick);
+        };
+        // Align to a frame boundary before the loop starts
+        await new Promise(resolve => requestAnimationFrame(resolve));
+        requestAnimationFrame(tick);
+
+        let longTasks = null;
+        let longTaskObserver = null;
+        if (typeof PerformanceObserver === 'function' && (PerformanceObserver.supportedEntryTypes || []).includes('longtask')) {
+            longTasks = [];
+            longTaskObserver = new PerformanceObserver(list => longTasks.push(...list.getEntries()));
+            longTaskObserver.observe({ type: 'longtask' });
+        }
+
+        await new Promise(resolve => setTimeout(resolve, args.baseline_ms));
+
+        let target = null;
+        let scrollStart = null;
+        const actionAt = performance.now();
+        if (args.input === 'scroll') {
+            target = scroller();
+            scrollStart = { x: target.scrollLeft, y: target.scrollTop };
+            const r = el.getBoundingClientRect();
+            el.dispatchEvent(new WheelEvent('wheel', {
+                deltaX: args.scroll_x, deltaY: args.scroll_y, deltaMode: 0,
+                clientX: r.left + r.width / 2, clientY: r.top + r.height / 2,
+                bubbles: true, cancelable: true, composed: true, view: window
+            }));
+            target.scrollBy({ left: args.scroll_x, top: args.scroll_y, behavior: 'smooth' });
+        } else {
+            dispatchInput();
+        }
+        const handlerMs = performance.now() - actionAt;
+
+        await new Promise(resolve => setTimeout(resolve, Math.max(0, args.duration_ms - (performance.now() - actionAt))));
+        running = false;
+        if (longTaskObserver) {
+            longTasks.push(...longTaskObserver.takeRecords());
+            longTaskObserver.disconnect();
+        }
+        const endAt = performance.now();
+
+        const intervalsBetween = (from, to) => {
+            const result = [];
+            for (let i = 1; i < frames.length; i++) {
+                if (frames[i] > from && frames[i] <= to) result.push({ at: frames[i], ms: frames[i] - frames[i - 1] });
+            }
+            return result;
+        };
+        const baseline = intervalsBetween(-Infinity, actionAt);
+        const action = intervalsBetween(actionAt, endAt);
+
+        const median = (values) => {
+            if (values.length === 0) return null;
+            const sorted = [...values].sort((a, b) => a - b);
+            return sorted[Math.floor(sorted.length / 2)];
+        };
+        const baselineMedian = median(baseline.map(f => f.ms));
+        const budget = args.frame_budget_ms || baselineMedian || 1000 / 60;
+
+        const summarize = (intervals, elapsed) => {
+            if (intervals.length === 0) return null;
+            const sorted = intervals.map(f => f.ms).sort((a, b) => a - b);
+            const pick = (p) => sorted[Math.min(sorted.length - 1, Math.ceil(p * sorted.length) - 1)];
+            const dropped = intervals.reduce((sum, f) => sum + Math.max(0, Math.round(f.ms / budget) - 1), 0);
+            return {
+                frames: intervals.length,
+                fps: round(intervals.length / (elapsed / 1000)),
+                dropped_frames: dropped,
+                janky_frames: intervals.filter(f => f.ms > budget * 1.5).length,
+                frame_ms: {
+                    min: round(sorted[0]),
+                    median: round(pick(0.5)),
+                    p95: round(pick(0.95)),
+                    max: round(sorted[sorted.length - 1])
+                }
+            };
+        };
+
+        const worst = [...action]
+            .sort((a, b) => b.ms - a.ms)
+            .slice(0, 10)
+            .filter(f => f.ms > budget * 1.5)
+            .map(f => ({ ends_at_ms: round(f.at - actionAt), duration_ms: round(f.ms) }));
+        const expectedFrames = (endAt - actionAt) / budget;
+        const actionStats = summarize(action, endAt - actionAt);
+
+        return {
+            selector: mcp.cssPath(el),
+            action: args.input,
+            key: args.input === 'keypress' ? args.key : null,
+            scroll: target ? {
+                container: target === document.scrollingElement || target === document.documentElement ? 'document' : mcp.cssPath(target),
+                requested: { x: args.scroll_x, y: args.scroll_y },
+                moved: { x: round(target.scrollLeft - scrollStart.x), y: round(target.scrollTop - scrollStart.y) }
+            } : null,
+            frame_budget_ms: round(budget),
+            budget_source: args.frame_budget_ms ? 'parameter' : (baselineMedian ? 'baseline' : 'default_60hz'),
+            target_fps: round(1000 / budget),
+            handler_ms: round(handlerMs),
+            duration_ms: round(endAt - actionAt),
+            during_action: actionStats,
+            baseline: summarize(baseline, actionAt - (frames[0] || actionAt)),
+            frames_achieved_percent: actionStats ? round(Math.min(100, actionStats.frames / expectedFrames * 100)) : 0,
+            worst_frames: worst,
+            long_tasks: longTasks === null ? null : longTasks
+                .filter(task => task.startTime + task.duration >= actionAt)
+                .map(task => ({ start_ms: round(task.startTime - actionAt), duration_ms: round(task.duration) })),
+            note: document.hidden ? 'The page is hidden, so animation frames are throttled and the frame rate is not meaningful' : null
+        };"#;
@@ -0,0 +1,1406 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct BoxModelRequest {
+    window_label: Option<String>,
+    selector: String,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ElementRelationsRequest {
+    window_label: Option<String>,
+    /// Reference element
+    selector_a: String,
+    /// Element positioned relative to `selector_a`
+    selector_b: String,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct LayoutShiftsRequest {
+    window_label: Option<String>,
+    /// Keep observing for new shifts this long before reporting (default 0: buffered entries only)
+    observe_ms: Option<u64>,
+    /// Include shifts right after user input, which do not count towards CLS (default false)
+    include_input_shifts: Option<bool>,
+    /// Maximum number of shifts to return, largest first (default 50)
+    limit: Option<usize>,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct PositionedElementsRequest {
+    window_label: Option<String>,
+    /// Only report elements inside this one
+    root_selector: Option<String>,
+    /// Include sticky/fixed elements that are not rendered (default false)
+    include_hidden: Option<bool>,
+    /// Maximum number of elements to return (default 100)
+    limit: Option<usize>,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ElementFromPointRequest {
+    window_label: Option<String>,
+    /// Point in CSS pixels relative to the viewport, as `get_element_position` reports
+    x: f64,
+    y: f64,
+    /// Descend into open shadow roots for the topmost element (default true)
+    pierce_shadow: Option<bool>,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct HitTestRequest {
+    window_label: Option<String>,
+    selector: String,
+    /// Scroll the element's center into the viewport first (default false)
+    scroll_into_view: Option<bool>,
+    /// Descend into open shadow roots when hit testing (default true)
+    pierce_shadow: Option<bool>,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct InteractionBlockersRequest {
+    window_label: Option<String>,
+    selector: String,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct StackingContextsRequest {
+    window_label: Option<String>,
+    /// Report the context tree from the context containing this element
+    root_selector: Option<String>,
+    /// Elements to explain; with two, also which one paints on top and why
+    selectors: Option<Vec<String>>,
+    /// Maximum nesting depth of the returned tree (default 20)
+    max_depth: Option<usize>,
+    /// Maximum number of contexts in the returned tree (default 200)
+    max_contexts: Option<usize>,
+    /// Maximum paint-order entries listed per context (default 50)
+    max_items_per_context: Option<usize>,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct CompositingLayersRequest {
+    window_label: Option<String>,
+    /// Only consider elements inside this one
+    root_selector: Option<String>,
+    /// Include elements that are not rendered or lie outside the viewport (default true)
+    include_offscreen: Option<bool>,
+    /// Maximum number of layers to return, largest estimated memory first (default 100)
+    limit: Option<usize>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for reading the computed box model of an element
+pub async fn handle_get_box_model<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_box_model", &payload)
+        .required("selector", FieldKind::NonEmptyString)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: BoxModelRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_box_model: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Box model error",
+        &json!({ "selector": request.selector }),
+        BOX_MODEL_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "box model inspection",
+    )
+    .await
+}
+
+/// Handler function for comparing the position of two elements
+pub async fn handle_element_relations<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("element_relations", &payload)
+        .required("selector_a", FieldKind::NonEmptyString)
+        .required("selector_b", FieldKind::NonEmptyString)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: ElementRelationsRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for element_relations: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Element relations error",
+        &json!({ "selector_a": request.selector_a, "selector_b": request.selector_b }),
+        ELEMENT_RELATIONS_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "element relations",
+    )
+    .await
+}
+
+/// Handler function for reporting layout shifts with the nodes that moved
+pub async fn handle_get_layout_shifts<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_layout_shifts", &payload)
+        .optional("observe_ms", FieldKind::Integer { min: Some(0), max: Some(60_000) })
+        .optional("include_input_shifts", FieldKind::Bool)
+        .optional("limit", FieldKind::Integer { min: Some(1), max: None })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: LayoutShiftsRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_layout_shifts: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let observe_ms = request.observe_ms.unwrap_or(0);
+
+    let js_code = build_probe_script(
+        "Layout shift error",
+        &json!({
+            "observe_ms": observe_ms,
+            "include_input_shifts": request.include_input_shifts.unwrap_or(false),
+            "limit": request.limit.unwrap_or(50),
+        }),
+        LAYOUT_SHIFTS_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS).saturating_add(observe_ms),
+        "layout shift collection",
+    )
+    .await
+}
+
+/// Handler function for listing sticky and fixed elements and whether they are stuck
+pub async fn handle_get_positioned_elements<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_positioned_elements", &payload)
+        .optional("root_selector", FieldKind::NonEmptyString)
+        .optional("include_hidden", FieldKind::Bool)
+        .optional("limit", FieldKind::Integer { min: Some(1), max: None })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: PositionedElementsRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_positioned_elements: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Positioned elements error",
+        &json!({
+            "root_selector": request.root_selector,
+            "include_hidden": request.include_hidden.unwrap_or(false),
+            "limit": request.limit.unwrap_or(100),
+        }),
+        POSITIONED_ELEMENTS_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "positioned element inspection",
+    )
+    .await
+}
+
+/// Handler function for finding the element stack under a viewport point
+pub async fn handle_element_from_point<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("element_from_point", &payload)
+        .required("x", FieldKind::Number { min: None, max: None })
+        .required("y", FieldKind::Number { min: None, max: None })
+        .optional("pierce_shadow", FieldKind::Bool)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: ElementFromPointRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for element_from_point: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Element from point error",
+        &json!({
+            "x": request.x,
+            "y": request.y,
+            "pierce_shadow": request.pierce_shadow.unwrap_or(true),
+        }),
+        ELEMENT_FROM_POINT_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "element from point",
+    )
+    .await
+}
+
+/// Handler function for checking whether a click at an element's center reaches it
+pub async fn handle_hit_test<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("hit_test", &payload)
+        .required("selector", FieldKind::NonEmptyString)
+        .optional("scroll_into_view", FieldKind::Bool)
+        .optional("pierce_shadow", FieldKind::Bool)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: HitTestRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for hit_test: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Hit test error",
+        &json!({
+            "selector": request.selector,
+            "scroll_into_view": request.scroll_into_view.unwrap_or(false),
+            "pierce_shadow": request.pierce_shadow.unwrap_or(true),
+        }),
+        HIT_TEST_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "hit test",
+    )
+    .await
+}
+
+/// Handler function for finding the ancestors that stop an element from taking input
+pub async fn handle_get_interaction_blockers<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_interaction_blockers", &payload)
+        .required("selector", FieldKind::NonEmptyString)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: InteractionBlockersRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_interaction_blockers: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Interaction blockers error",
+        &json!({ "selector": request.selector }),
+        INTERACTION_BLOCKERS_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "interaction blocker inspection",
+    )
+    .await
+}
+
+/// Handler function for building the stacking context tree with paint order
+pub async fn handle_get_stacking_contexts<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_stacking_contexts", &payload)
+        .optional("root_selector", FieldKind::NonEmptyString)
+        .optional("selectors", FieldKind::Array)
+        .optional("max_depth", FieldKind::Integer { min: Some(1), max: Some(100) })
+        .optional("max_contexts", FieldKind::Integer { min: Some(1), max: Some(5000) })
+        .optional("max_items_per_context", FieldKind::Integer { min: Some(0), max: Some(1000) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: StackingContextsRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_stacking_contexts: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Stacking context error",
+        &json!({
+            "root_selector": request.root_selector,
+            "selectors": request.selectors.unwrap_or_default(),
+            "max_depth": request.max_depth.unwrap_or(20),
+            "max_contexts": request.max_contexts.unwrap_or(200),
+            "max_items_per_context": request.max_items_per_context.unwrap_or(50),
+        }),
+        STACKING_CONTEXTS_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "stacking context inspection",
+    )
+    .await
+}
+
+/// Handler function for estimating which elements get their own compositing layer
+pub async fn handle_get_compositing_layers<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_compositing_layers", &payload)
+        .optional("root_selector", FieldKind::NonEmptyString)
+        .optional("include_offscreen", FieldKind::Bool)
+        .optional("limit", FieldKind::Integer { min: Some(1), max: Some(5000) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: CompositingLayersRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_compositing_layers: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Compositing layer error",
+        &json!({
+            "root_selector": request.root_selector,
+            "include_offscreen": request.include_offscreen.unwrap_or(true),
+            "limit": request.limit.unwrap_or(100),
+        }),
+        COMPOSITING_LAYERS_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "compositing layer inspection",
+    )
+    .await
+}
+
+/// Computes content/padding/border/margin quads the same way DevTools' layout panel does
+const BOX_MODEL_JS: &str = r#"        const el = mcp.query(args.selector);
+        const style = getComputedStyle(el);
+        const px = (v) => parseFloat(v) || 0;
+        const border = el.getBoundingClientRect();
+
+        // Eight-point quad (clockwise from top-left) for a rect
+        const quad = (left, top, right, bottom) => [left, top, right, top, right, bottom, left, bottom];
+
+        const margin = {
+            top: px(style.marginTop), right: px(style.marginRight),
+            bottom: px(style.marginBottom), left: px(style.marginLeft)
+        };
+        const borderWidth = {
+            top: px(style.borderTopWidth), right: px(style.borderRightWidth),
+            bottom: px(style.borderBottomWidth), left: px(style.borderLeftWidth)
+        };
+        const padding = {
+            top: px(style.paddingTop), right: px(style.paddingRight),
+            bottom: px(style.paddingBottom), left: px(style.paddingLeft)
+        };
+
+        const paddingBox = {
+            left: border.left + borderWidth.left,
+            top: border.top + borderWidth.top,
+            right: border.right - borderWidth.right,
+            bottom: border.bottom - borderWidth.bottom
+        };
+        const contentBox = {
+            left: paddingBox.left + padding.left,
+            top: paddingBox.top + padding.top,
+            right: paddingBox.right - padding.right,
+            bottom: paddingBox.bottom - padding.bottom
+        };
+        const marginBox = {
+            left: border.left - margin.left,
+            top: border.top - margin.top,
+            right: border.right + margin.right,
+            bottom: border.bottom + margin.bottom
+        };
+
+        return {
+            selector: args.selector,
+            element: mcp.cssPath(el),
+            width: contentBox.right - contentBox.left,
+            height: contentBox.bottom - contentBox.top,
+            content: quad(contentBox.left, contentBox.top, contentBox.right, contentBox.bottom),
+            padding: quad(paddingBox.left, paddingBox.top, paddingBox.right, paddingBox.bottom),
+            border: quad(border.left, border.top, border.right, border.bottom),
+            margin: quad(marginBox.left, marginBox.top, marginBox.right, marginBox.bottom),
+            edges: { margin, border: borderWidth, padding },
+            styles: {
+                position: style.position,
+                display: style.display,
+                z_index: style.zIndex,
+                box_sizing: style.boxSizing,
+                overflow_x: style.overflowX,
+                overflow_y: style.overflowY
+            },
+            overflowing: {
+                horizontal: el.scrollWidth > el.clientWidth,
+                vertical: el.scrollHeight > el.clientHeight
+            }
+        };"#;
+
+/// Relates B to A: DOM and geometric containment, overlap, center distance and direction
+const ELEMENT_RELATIONS_JS: &str = r#"        const a = mcp.query(args.selector_a);
+        const b = mcp.query(args.selector_b);
+        const ra = a.getBoundingClientRect();
+        const rb = b.getBoundingClientRect();
+
+        const contains = (outer, inner) =>
+            inner.left >= outer.left && inner.right <= outer.right &&
+            inner.top >= outer.top && inner.bottom <= outer.bottom;
+
+        const overlapWidth = Math.max(0, Math.min(ra.right, rb.right) - Math.max(ra.left, rb.left));
+        const overlapHeight = Math.max(0, Math.min(ra.bottom, rb.bottom) - Math.max(ra.top, rb.top));
+        const overlapArea = overlapWidth * overlapHeight;
+        const smallerArea = Math.min(ra.width * ra.height, rb.width * rb.height);
+
+        const centerA = { x: ra.left + ra.width / 2, y: ra.top + ra.height / 2 };
+        const centerB = { x: rb.left + rb.width / 2, y: rb.top + rb.height / 2 };
+        const dx = centerB.x - centerA.x;
+        const dy = centerB.y - centerA.y;
+
+        // Edge-to-edge gaps; zero along an axis where the rects overlap
+        const gapX = Math.max(0, rb.left - ra.right, ra.left - rb.right);
+        const gapY = Math.max(0, rb.top - ra.bottom, ra.top - rb.bottom);
+
+        // Where B sits relative to A, only counting sides B is entirely beyond
+        const vertical = rb.bottom <= ra.top ? 'above' : rb.top >= ra.bottom ? 'below' : null;
+        const horizontal = rb.right <= ra.left ? 'left' : rb.left >= ra.right ? 'right' : null;
+        const direction = [vertical, horizontal].filter(Boolean).join('-') || 'overlapping';
+
+        return {
+            a: { selector: args.selector_a, element: mcp.cssPath(a), rect: mcp.rect(ra) },
+            b: { selector: args.selector_b, element: mcp.cssPath(b), rect: mcp.rect(rb) },
+            same_element: a === b,
+            dom: {
+                a_contains_b: a !== b && a.contains(b),
+                b_contains_a: a !== b && b.contains(a)
+            },
+            geometry: {
+                a_contains_b: contains(ra, rb),
+                b_contains_a: contains(rb, ra)
+            },
+            overlap: {
+                overlaps: overlapArea > 0,
+                area: overlapArea,
+                ratio_of_smaller: smallerArea > 0 ? overlapArea / smallerArea : 0,
+                rect: overlapArea > 0 ? {
+                    x: Math.max(ra.left, rb.left),
+                    y: Math.max(ra.top, rb.top),
+                    width: overlapWidth,
+                    height: overlapHeight
+                } : null
+            },
+            center_distance: Math.hypot(dx, dy),
+            center_delta: { x: dx, y: dy },
+            gap: { x: gapX, y: gapY, distance: Math.hypot(gapX, gapY) },
+            direction
+        };"#;
+
+/// Collects buffered `layout-shift` entries, attributes them to their source nodes and
+/// scores CLS both as a plain sum and with the 1s-gap/5s-cap session windows
+const LAYOUT_SHIFTS_JS: &str = r#"        const supported = typeof PerformanceObserver !== 'undefined'
+            && (PerformanceObserver.supportedEntryTypes || []).includes('layout-shift');
+        if (!supported) {
+            return {
+                supported: false,
+                reason: 'This webview engine does not report layout-shift entries',
+                shifts: [],
+                cumulative_score: null
+            };
+        }
+
+        const entries = [];
+        const observer = new PerformanceObserver(list => entries.push(...list.getEntries()));
+        observer.observe({ type: 'layout-shift', buffered: true });
+        // Buffered entries are delivered asynchronously, so always yield at least briefly
+        await new Promise(resolve => setTimeout(resolve, Math.max(args.observe_ms, 50)));
+        entries.push(...observer.takeRecords());
+        observer.disconnect();
+
+        const describe = (node) => {
+            if (!node) return null;
+            if (node.nodeType !== 1) {
+                return node.parentElement ? describe(node.parentElement) + ' (text)' : '#text';
+            }
+            let text = node.tagName.toLowerCase();
+            if (node.id) text += '#' + node.id;
+            if (node.classList && node.classList.length) text += '.' + Array.from(node.classList).slice(0, 3).join('.');
+            return text;
+        };
+
+        const all = entries.map(entry => ({
+            value: entry.value,
+            start_time_ms: entry.startTime,
+            had_recent_input: entry.hadRecentInput,
+            sources: (entry.sources || []).map(source => {
+                const element = source.node && (source.node.nodeType === 1 ? source.node : source.node.parentElement);
+                return {
+                    selector: element ? mcp.cssPath(element) : null,
+                    description: source.node ? describe(source.node) : 'removed node',
+                    connected: source.node ? source.node.isConnected : false,
+                    previous_rect: mcp.rect(source.previousRect),
+                    current_rect: mcp.rect(source.currentRect)
+                };
+            })
+        }));
+
+        const counted = all.filter(shift => !shift.had_recent_input);
+        const cumulative = counted.reduce((sum, shift) => sum + shift.value, 0);
+
+        // CLS as defined by Core Web Vitals: the worst session window
+        let worstWindow = 0;
+        let windowScore = 0;
+        let windowStart = null;
+        let previous = null;
+        counted.slice().sort((a, b) => a.start_time_ms - b.start_time_ms).forEach(shift => {
+            if (previous !== null && shift.start_time_ms - previous < 1000 && shift.start_time_ms - windowStart < 5000) {
+                windowScore += shift.value;
+            } else {
+                windowScore = shift.value;
+                windowStart = shift.start_time_ms;
+            }
+            previous = shift.start_time_ms;
+            worstWindow = Math.max(worstWindow, windowScore);
+        });
+
+        const reported = (args.include_input_shifts ? all : counted)
+            .sort((a, b) => b.value - a.value)
+            .slice(0, args.limit);
+
+        return {
+            supported: true,
+            shift_count: all.length,
+            input_excluded_count: all.length - counted.length,
+            cumulative_score: cumulative,
+            cls_session_window: worstWindow,
+            shifts: reported
+        };"#;
+
+/// A sticky element is "stuck" when it sits exactly at its inset inside the scrollport
+/// of its nearest scrolling ancestor while that ancestor is scrolled. Fixed elements
+/// report which interactive elements they cover, sampled at those elements' centers.
+const POSITIONED_ELEMENTS_JS: &str = r#"        const root = args.root_selector ? mcp.query(args.root_selector) : document.body;
+        const px = (v) => v === 'auto' ? null : parseFloat(v);
+        const round = (r) => ({ x: Math.round(r.x), y: Math.round(r.y), width: Math.round(r.width), height: Math.round(r.height) });
+
+        const scrollParent = (el) => {
+            for (let node = el.parentElement; node && node !== document.documentElement; node = node.parentElement) {
+                const style = getComputedStyle(node);
+                if (/(auto|scroll|overlay|hidden)/.test(style.overflowY + style.overflowX)) return node;
+            }
+            return null;
+        };
+
+        const candidates = [];
+        const walker = document.createTreeWalker(root, NodeFilter.SHOW_ELEMENT);
+        for (let node = walker.currentNode; node; node = walker.nextNode()) {
+            const position = getComputedStyle(node).position;
+            if (position === 'sticky' || position === 'fixed') candidates.push({ el: node, position });
+        }
+
+        const interactive = Array.from(document.querySelectorAll('a[href], button, input, select, textarea, [role="button"], [tabindex]'))
+            .filter(el => mcp.isVisible(el));
+
+        const results = [];
+        for (const { el, position } of candidates) {
+            const visible = mcp.isVisible(el);
+            if (!visible && !args.include_hidden) continue;
+            if (results.length >= args.limit) break;
+
+            const style = getComputedStyle(el);
+            const rect = el.getBoundingClientRect();
+            const insets = { top: px(style.top), right: px(style.right), bottom: px(style.bottom), left: px(style.left) };
+            const entry = {
+                selector: mcp.cssPath(el),
+                position,
+                rect: round(rect),
+                insets,
+                z_index: style.zIndex,
+                visible
+            };
+
+            if (position === 'sticky') {
+                const container = scrollParent(el);
+                const port = container ? container.getBoundingClientRect() : { top: 0, left: 0, bottom: window.innerHeight, right: window.innerWidth };
+                const scrolled = container ? (container.scrollTop > 0 || container.scrollLeft > 0) : (window.scrollY > 0 || window.scrollX > 0);
+                const near = (a, b) => a !== null && Math.abs(a - b) < 1.5;
+                const stuckEdges = [];
+                if (insets.top !== null && near(rect.top, port.top + insets.top)) stuckEdges.push('top');
+                if (insets.bottom !== null && near(port.bottom - rect.bottom, insets.bottom)) stuckEdges.push('bottom');
+                if (insets.left !== null && near(rect.left, port.left + insets.left)) stuckEdges.push('left');
+                if (insets.right !== null && near(port.right - rect.right, insets.right)) stuckEdges.push('right');
+                entry.scroll_container = container ? mcp.cssPath(container) : 'viewport';
+                entry.stuck = scrolled && stuckEdges.length > 0;
+                entry.stuck_edges = stuckEdges;
+                // Without any inset, sticky behaves like relative and never sticks
+                if (Object.values(insets).every(v => v === null)) entry.warning = 'No inset set; position: sticky has no effect';
+            } else if (visible) {
+                const covered = interactive.filter(target => {
+                    if (el.contains(target)) return false;
+                    const r = target.getBoundingClientRect();
+                    const cx = r.left + r.width / 2;
+                    const cy = r.top + r.height / 2;
+                    if (cx < rect.left || cx > rect.right || cy < rect.top || cy > rect.bottom) return false;
+                    const hit = document.elementFromPoint(cx, cy);
+                    return hit !== null && el.contains(hit);
+                });
+                entry.covers_interactive = covered.length;
+                entry.covered_sample = covered.slice(0, 5).map(target => mcp.cssPath(target));
+            }
+
+            results.push(entry);
+        }
+
+        return {
+            viewport: { width: window.innerWidth, height: window.innerHeight, scroll_x: window.scrollX, scroll_y: window.scrollY },
+            count: results.length,
+            sticky_count: results.filter(r => r.position === 'sticky').length,
+            fixed_count: results.filter(r => r.position === 'fixed').length,
+            truncated: candidates.length > results.length && results.length >= args.limit,
+            elements: results
+        };"#;
+
+/// `elementsFromPoint` stops at shadow hosts, so the topmost element repeats the query
+/// on each open shadow root it lands on. Elements with `pointer-events: none` are
+/// skipped by hit testing and never appear in the stack.
+const ELEMENT_FROM_POINT_JS: &str = r#"        const inViewport = args.x >= 0 && args.y >= 0 && args.x < window.innerWidth && args.y < window.innerHeight;
+        if (!inViewport) {
+            return {
+                x: args.x,
+                y: args.y,
+                in_viewport: false,
+                viewport: { width: window.innerWidth, height: window.innerHeight },
+                element: null,
+                stack: []
+            };
+        }
+
+        // Selector path that crosses shadow boundaries with ' >>> '
+        const path = (el) => {
+            const parts = [];
+            let node = el;
+            while (node) {
+                parts.unshift(mcp.cssPath(node));
+                const root = node.getRootNode();
+                node = root instanceof ShadowRoot ? root.host : null;
+            }
+            return parts.join(' >>> ');
+        };
+
+        const describe = (el, depth) => {
+            const r = mcp.rect(el.getBoundingClientRect());
+            const style = getComputedStyle(el);
+            return {
+                depth,
+                selector: path(el),
+                tag: el.tagName.toLowerCase(),
+                id: el.id || null,
+                class_name: typeof el.className === 'string' && el.className ? el.className.substring(0, 80) : null,
+                text: (el.innerText || '').replace(/\s+/g, ' ').trim().substring(0, 60),
+                rect: r,
+                position: style.position,
+                z_index: style.zIndex,
+                opacity: parseFloat(style.opacity),
+                in_shadow_root: el.getRootNode() instanceof ShadowRoot
+            };
+        };
+
+        let stack = document.elementsFromPoint(args.x, args.y);
+        let top = stack[0] || null;
+        let shadowDepth = 0;
+        if (args.pierce_shadow) {
+            while (top && top.shadowRoot) {
+                const inner = top.shadowRoot.elementsFromPoint(args.x, args.y).filter(el => top.shadowRoot.contains(el));
+                if (inner.length === 0 || inner[0] === top) break;
+                stack = [...inner, ...stack];
+                top = inner[0];
+                shadowDepth += 1;
+            }
+        }
+
+        // The first interactive element in the stack is what a click would activate
+        const INTERACTIVE = 'a[href], button, input, select, textarea, label, summary, [role="button"], [role="link"], [onclick], [tabindex]:not([tabindex="-1"]), [contenteditable]:not([contenteditable="false"])';
+        const clickTarget = top ? (top.closest(INTERACTIVE) || null) : null;
+
+        return {
+            x: args.x,
+            y: args.y,
+            in_viewport: true,
+            element: top ? describe(top, 0) : null,
+            click_target: clickTarget ? path(clickTarget) : null,
+            shadow_depth: shadowDepth,
+            stack: stack.map((el, i) => describe(el, i))
+        };"#;
+
+
+/// The center is hit tested the same way `element_from_point` does, descending into
+/// open shadow roots. A hit on the element, anything inside it (including its shadow
+/// tree) or a `<label>` for it counts as clickable. A hit on one of its ancestors means
+/// the element itself takes no pointer events; anything else is reported as the
+/// element covering it.
+const HIT_TEST_JS: &str = r#"        const el = mcp.query(args.selector);
+
+        // Selector path that crosses shadow boundaries with ' >>> '
+        const path = (node) => {
+            const parts = [];
+            while (node) {
+                parts.unshift(mcp.cssPath(node));
+                const root = node.getRootNode();
+                node = root instanceof ShadowRoot ? root.host : null;
+            }
+            return parts.join(' >>> ');
+        };
+        // contains() that also looks through shadow roots
+        const composedContains = (ancestor, node) => {
+            for (let current = node; current; ) {
+                if (current === ancestor) return true;
+                current = current.parentNode instanceof ShadowRoot ? current.parentNode.host : current.parentNode;
+            }
+            return false;
+        };
+
+        if (args.scroll_into_view) el.scrollIntoView({ block: 'center', inline: 'center' });
+        const rect = el.getBoundingClientRect();
+        const style = getComputedStyle(el);
+        const x = rect.left + rect.width / 2;
+        const y = rect.top + rect.height / 2;
+        const base = {
+            selector: path(el),
+            center: { x: Math.round(x * 100) / 100, y: Math.round(y * 100) / 100 },
+            rect: mcp.rect(rect),
+            pointer_events: style.pointerEvents,
+            disabled: el.matches(':disabled'),
+            scrolled: !!args.scroll_into_view
+        };
+        const fail = (reason, extra) => ({ ...base, clickable: false, reason, hit: null, obscured_by: null, ...extra });
+
+        if (style.display === 'none' || !el.getClientRects().length) return fail('not_rendered');
+        if (rect.width === 0 || rect.height === 0) return fail('zero_size');
+        if (style.visibility !== 'visible') return fail('hidden', { visibility: style.visibility });
+        if (x < 0 || y < 0 || x >= window.innerWidth || y >= window.innerHeight) {
+            return fail('outside_viewport', { viewport: { width: window.innerWidth, height: window.innerHeight } });
+        }
+
+        let hit = document.elementFromPoint(x, y);
+        if (args.pierce_shadow) {
+            while (hit && hit.shadowRoot) {
+                const inner = hit.shadowRoot.elementFromPoint(x, y);
+                if (!inner || inner === hit || !hit.shadowRoot.contains(inner)) break;
+                hit = inner;
+            }
+        }
+        if (!hit) return fail('no_element_at_center');
+
+        const describe = (node) => {
+            const nodeStyle = getComputedStyle(node);
+            return {
+                selector: path(node),
+                tag: node.tagName.toLowerCase(),
+                text: (node.innerText || '').replace(/\s+/g, ' ').trim().substring(0, 60),
+                rect: mcp.rect(node.getBoundingClientRect()),
+                position: nodeStyle.position,
+                z_index: nodeStyle.zIndex,
+                opacity: parseFloat(nodeStyle.opacity)
+            };
+        };
+
+        const label = hit.closest && hit.closest('label');
+        if (hit === el || composedContains(el, hit)) {
+            return { ...base, clickable: true, reason: hit === el ? 'hit' : 'hit_descendant', hit: describe(hit), obscured_by: null };
+        }
+        if (label && label.control === el) {
+            return { ...base, clickable: true, reason: 'hit_label', hit: describe(hit), obscured_by: null };
+        }
+        if (composedContains(hit, el)) {
+            return fail('hit_ancestor', {
+                hit: describe(hit),
+                note: style.pointerEvents === 'none'
+                    ? 'The element has pointer-events: none, so clicks fall through to its ancestor'
+                    : 'Clicks at the center reach an ancestor instead; the element or a wrapper may ignore pointer events or be clipped'
+            });
+        }
+
+        // The covering element's outermost out-of-flow ancestor is usually the overlay to blame
+        let overlay = null;
+        for (let node = hit; node && node !== document.body && node !== document.documentElement; node = node.parentElement) {
+            const position = getComputedStyle(node).position;
+            if (position === 'fixed' || position === 'absolute' || position === 'sticky') overlay = node;
+        }
+        return fail('obscured', {
+            hit: describe(hit),
+            obscured_by: path(hit),
+            overlay: overlay && overlay !== hit ? describe(overlay) : null
+        });"#;
+
+/// `pointer-events` and `visibility` inherit, so they only block when the element's own
+/// computed value does; the blamed ancestor is the outermost one of the unbroken run of
+/// that value, which is where it was most likely set. A disabled `<fieldset>` disables
+/// its controls except those inside its first `<legend>`, and an open modal dialog
+/// makes everything outside it inert. Ancestors are walked across shadow boundaries.
+/// `aria-disabled` does not stop events, so it is listed as advisory only.
+const INTERACTION_BLOCKERS_JS: &str = r#"        const el = mcp.query(args.selector);
+
+        // Selector path that crosses shadow boundaries with ' >>> '
+        const path = (node) => {
+            const parts = [];
+            while (node) {
+                parts.unshift(mcp.cssPath(node));
+                const root = node.getRootNode();
+                node = root instanceof ShadowRoot ? root.host : null;
+            }
+            return parts.join(' >>> ');
+        };
+        const parentOf = (node) => node.parentElement || (node.parentNode instanceof ShadowRoot ? node.parentNode.host : null);
+        const chain = [];
+        for (let node = el; node; node = parentOf(node)) chain.push(node);
+
+        const blockers = [];
+        const advisories = [];
+        const block = (type, node, detail) => blockers.push({ type, element: path(node), is_self: node === el, detail });
+
+        // Inherited properties: blame the outermost element of the run sharing the value
+        const inherited = (property, blocking) => {
+            const value = getComputedStyle(el)[property];
+            if (!blocking(value)) return;
+            let origin = el;
+            for (const node of chain.slice(1)) {
+                if (getComputedStyle(node)[property] !== value) break;
+                origin = node;
+            }
+            return { value, origin };
+        };
+        const pointer = inherited('pointerEvents', value => value === 'none');
+        if (pointer) block('pointer_events_none', pointer.origin, pointer.origin === el ? 'The element has pointer-events: none' : 'pointer-events: none is inherited from this ancestor; set pointer-events: auto on the element or fix the ancestor');
+        const hidden = inherited('visibility', value => value !== 'visible');
+        if (hidden) block('visibility_' + hidden.value, hidden.origin, 'visibility: ' + hidden.value + ' makes the element ignore pointer events');
+
+        const notRendered = chain.find(node => getComputedStyle(node).display === 'none');
+        if (notRendered) block('display_none', notRendered, 'display: none on this element removes it from layout');
+
+        const inert = chain.find(node => node.inert || node.hasAttribute('inert'));
+        if (inert) block('inert', inert, 'The inert attribute blocks all input and focus in this subtree');
+
+        const modal = [...document.querySelectorAll('dialog')].find(dialog => {
+            try { return dialog.matches(':modal'); } catch (e) { return false; }
+        });
+        if (modal && !chain.includes(modal)) block('outside_modal_dialog', modal, 'An open modal dialog makes everything outside it inert');
+
+        // Form controls and their disabled ancestors
+        if ('disabled' in el && el.hasAttribute('disabled')) block('disabled', el, 'The element has the disabled attribute');
+        for (const node of chain.slice(1)) {
+            if (node.tagName === 'FIELDSET' && node.disabled) {
+                const legend = [...node.children].find(child => child.tagName === 'LEGEND');
+                if (legend && legend.contains(el)) continue;
+                if ('disabled' in el && el.matches(':disabled')) {
+                    block('disabled_fieldset', node, 'A disabled fieldset disables every form control inside it except those in its first legend');
+                }
+            }
+            if (node.tagName === 'OPTGROUP' && node.disabled) block('disabled_optgroup', node, 'A disabled optgroup disables its options');
+        }
+
+        for (const node of chain) {
+            if (node.getAttribute('aria-disabled') === 'true') {
+                advisories.push({ type: 'aria_disabled', element: path(node), is_self: node === el, detail: 'aria-disabled only tells assistive technology the element is disabled; events still fire unless scripts ignore them' });
+            }
+        }
+        if (getComputedStyle(el).cursor === 'not-allowed') {
+            advisories.push({ type: 'cursor_not_allowed', element: path(el), is_self: true, detail: 'The cursor signals a disabled element' });
+        }
+
+        return {
+            selector: path(el),
+            interactive: blockers.length === 0,
+            disabled: el.matches(':disabled'),
+            blocking_element: blockers.length > 0 ? blockers[0].element : null,
+            blockers,
+            advisories,
+            pointer_events: getComputedStyle(el).pointerEvents,
+            note: blockers.length === 0 ? 'Nothing in the ancestor chain blocks input; if clicks still miss, hit_test shows whether something covers the element' : null
+        };"#;
+
+/// Follows the CSS 2 Appendix E painting layers: negative z-index contexts, in-flow
+/// blocks, floats, inline content, positioned descendants at z-index auto/0, then
+/// positive z-index contexts, with tree order breaking ties. Non-positioned content is
+/// only counted per layer; `display: none` subtrees are skipped. Comparing two
+/// elements finds their closest common context and compares the participants that
+/// represent each of them there, which is what decides who paints on top.
+const STACKING_CONTEXTS_JS: &str = r#"        const LAYERS = ['negative_z', 'block', 'float', 'inline', 'positioned', 'positive_z'];
+        const WILL_CHANGE = ['opacity', 'transform', 'translate', 'scale', 'rotate', 'filter', 'backdrop-filter', 'perspective', 'clip-path', 'mask', 'mask-image', 'isolation', 'mix-blend-mode', 'z-index', 'position', 'contain'];
+        const isTopLayer = (el) => {
+            try { if (el.matches(':modal')) return true; } catch (e) {}
+            try { if (el.matches(':popover-open')) return true; } catch (e) {}
+            return false;
+        };
+
+        const reasonsFor = (el, style, parentStyle) => {
+            const reasons = [];
+            const zSet = style.zIndex !== 'auto';
+            const flexItem = parentStyle && /(flex|grid)/.test(parentStyle.display);
+            if (el === document.documentElement) reasons.push('root');
+            if (style.position === 'fixed' || style.position === 'sticky') reasons.push('position: ' + style.position);
+            else if (zSet && (style.position === 'absolute' || style.position === 'relative')) reasons.push('position: ' + style.position + ' with z-index');
+            else if (zSet && flexItem) reasons.push('flex/grid item with z-index');
+            if (parseFloat(style.opacity) < 1) reasons.push('opacity < 1');
+            if (style.mixBlendMode && style.mixBlendMode !== 'normal') reasons.push('mix-blend-mode');
+            for (const prop of ['transform', 'scale', 'rotate', 'translate', 'perspective', 'filter', 'backdropFilter', 'clipPath', 'maskImage']) {
+                const value = style[prop];
+                if (value && value !== 'none') reasons.push(prop.replace(/[A-Z]/g, c => '-' + c.toLowerCase()));
+            }
+            if (style.isolation === 'isolate') reasons.push('isolation: isolate');
+            if (/(layout|paint|strict|content)/.test(style.contain || '')) reasons.push('contain');
+            if (/size/.test(style.containerType || '')) reasons.push('container-type');
+            const willChange = (style.willChange || 'auto').split(',').map(v => v.trim());
+            if (willChange.some(v => WILL_CHANGE.includes(v))) reasons.push('will-change');
+            if (isTopLayer(el)) reasons.push('top layer');
+            return reasons;
+        };
+
+        const contexts = new Map();
+        const info = new Map();
+        const ignoredZ = [];
+        let order = 0;
+
+        const rootContext = { el: document.documentElement, z: 0, reasons: ['root'], parent: null, children: [], participants: [], counts: { block: 0, float: 0, inline: 0 }, depth: 0 };
+        contexts.set(document.documentElement, rootContext);
+        info.set(document.documentElement, { context: null, childContext: rootContext, order: order++ });
+
+        const walker = document.createTreeWalker(document.documentElement, NodeFilter.SHOW_ELEMENT);
+        walker.nextNode();
+        for (let el = walker.currentNode; el && el !== document.documentElement; ) {
+            const style = getComputedStyle(el);
+            const parent = el.parentElement;
+            if (style.display === 'none' || !info.has(parent)) {
+                // Skip the whole hidden subtree
+                let next = null;
+                for (let node = el; node && !next; node = node.parentNode) {
+                    if (node === document.documentElement) break;
+                    next = node.nextElementSibling;
+                }
+                if (!next) break;
+                walker.currentNode = next;
+                el = next;
+                continue;
+            }
+
+            const parentStyle = getComputedStyle(parent);
+            const owner = info.get(parent).childContext;
+            const reasons = reasonsFor(el, style, parentStyle);
+            const positioned = style.position !== 'static';
+            const flexItem = /(flex|grid)/.test(parentStyle.display);
+            const zApplies = positioned || flexItem;
+            const z = style.zIndex !== 'auto' && zApplies ? parseInt(style.zIndex, 10) : 0;
+            if (style.zIndex !== 'auto' && !zApplies) {
+                ignoredZ.push({ selector: mcp.cssPath(el), z_index: style.zIndex, reason: 'z-index has no effect on a position: static element that is not a flex or grid item' });
+            }
+
+            const entry = { context: owner, childContext: owner, order: order++ };
+            let layer;
+            if (reasons.length > 0) {
+                const context = { el, z, reasons, parent: owner, children: [], participants: [], counts: { block: 0, float: 0, inline: 0 }, depth: owner.depth + 1 };
+                contexts.set(el, context);
+                owner.children.push(context);
+                entry.childContext = context;
+                layer = z < 0 ? 'negative_z' : z > 0 ? 'positive_z' : 'positioned';
+                owner.participants.push({ el, layer, z, order: entry.order, context: true });
+            } else if (positioned) {
+                layer = 'positioned';
+                owner.participants.push({ el, layer, z: 0, order: entry.order, context: false });
+            } else {
+                layer = style.float !== 'none' ? 'float' : style.display.startsWith('inline') ? 'inline' : 'block';
+                owner.counts[layer] += 1;
+            }
+            entry.layer = layer;
+            entry.z = z;
+            info.set(el, entry);
+            el = walker.nextNode();
+        }
+
+        const paintKey = (p) => [LAYERS.indexOf(p.layer), p.layer === 'negative_z' || p.layer === 'positive_z' ? p.z : 0, p.order];
+        const compareKeys = (a, b) => {
+            const ka = paintKey(a), kb = paintKey(b);
+            for (let i = 0; i < ka.length; i++) if (ka[i] !== kb[i]) return ka[i] - kb[i];
+            return 0;
+        };
+        const describeContext = (c) => ({ selector: mcp.cssPath(c.el), z_index: c.z, reasons: c.reasons });
+
+        let budget = args.max_contexts;
+        const serialize = (c, depth) => {
+            budget -= 1;
+            const paintOrder = [...c.participants].sort(compareKeys).slice(0, args.max_items_per_context).map(p => ({
+                selector: mcp.cssPath(p.el),
+                layer: p.layer,
+                z_index: p.z,
+                stacking_context: p.context
+            }));
+            const node = {
+                ...describeContext(c),
+                tag: c.el.tagName.toLowerCase(),
+                non_positioned_counts: c.counts,
+                paint_order: paintOrder,
+                paint_order_truncated: c.participants.length > paintOrder.length,
+                children: []
+            };
+            const ordered = [...c.children].sort((a, b) => compareKeys(
+                c.participants.find(p => p.el === a.el),
+                c.participants.find(p => p.el === b.el)
+            ));
+            for (const child of ordered) {
+                if (depth + 1 >= args.max_depth || budget <= 0) {
+                    node.children_truncated = true;
+                    break;
+                }
+                node.children.push(serialize(child, depth + 1));
+            }
+            return node;
+        };
+
+        const rootEl = args.root_selector ? mcp.query(args.root_selector) : document.documentElement;
+        let start = rootContext;
+        for (let node = rootEl; node; node = node.parentElement) {
+            if (contexts.has(node)) { start = contexts.get(node); break; }
+        }
+
+        // Chain of contexts from the root down to the one an element paints in
+        const chainOf = (el) => {
+            const entry = info.get(el);
+            if (!entry) return null;
+            const chain = [];
+            for (let c = contexts.get(el) || entry.context; c; c = c.parent) chain.unshift(c);
+            return chain;
+        };
+
+        const explained = args.selectors.map(selector => {
+            const el = mcp.query(selector);
+            const entry = info.get(el);
+            if (!entry) return { selector, rendered: false };
+            return {
+                selector,
+                rendered: true,
+                establishes_context: contexts.has(el),
+                layer: entry.layer || 'root',
+                context_chain: chainOf(el).map(describeContext)
+            };
+        });
+
+        let comparison = null;
+        if (args.selectors.length === 2) {
+            const [a, b] = args.selectors.map(selector => mcp.query(selector));
+            const chainA = chainOf(a);
+            const chainB = chainOf(b);
+            if (chainA && chainB) {
+                let i = 0;
+                while (i < chainA.length && i < chainB.length && chainA[i] === chainB[i]) i++;
+                const common = chainA[i - 1];
+                // Within the common context each element is represented by its own
+                // participant entry or by the child context that contains it
+                const representative = (el, chain) => {
+                    const target = chain[i] ? chain[i].el : el;
+                    const participant = common.participants.find(p => p.el === target);
+                    if (participant) return participant;
+                    const entry = info.get(target);
+                    return { el: target, layer: entry.layer || 'block', z: 0, order: entry.order, context: false };
+                };
+                const ra = representative(a, chainA);
+                const rb = representative(b, chainB);
+                let top;
+                let reason;
+                if (ra.el === rb.el || a.contains(b) || b.contains(a)) {
+                    const [outer, inner, innerRep] = a.contains(b) ? ['a', 'b', rb] : ['b', 'a', ra];
+                    const outerEl = outer === 'a' ? a : b;
+                    if (innerRep.layer === 'negative_z' && contexts.get(outerEl) !== common) {
+                        top = outer;
+                        reason = 'The descendant has a negative z-index and its ancestor does not establish a stacking context, so it paints below the ancestor';
+                    } else {
+                        top = inner;
+                        reason = 'One element contains the other; descendants paint over their ancestors within the same context';
+                    }
+                } else {
+                    const cmp = compareKeys(ra, rb);
+                    top = cmp > 0 ? 'a' : 'b';
+                    const winner = top === 'a' ? ra : rb;
+                    const loser = top === 'a' ? rb : ra;
+                    if (winner.layer !== loser.layer) {
+                        reason = 'In the common context, layer ' + winner.layer + ' paints after layer ' + loser.layer;
+                    } else if (winner.z !== loser.z) {
+                        reason = 'In the common context, z-index ' + winner.z + ' is above ' + loser.z;
+                    } else {
+                        reason = 'Same layer and z-index in the common context; the later element in document order paints on top';
+                    }
+                }
+                comparison = {
+                    common_context: describeContext(common),
+                    a: { selector: args.selectors[0], represented_by: mcp.cssPath(ra.el), layer: ra.layer, z_index: ra.z },
+                    b: { selector: args.selectors[1], represented_by: mcp.cssPath(rb.el), layer: rb.layer, z_index: rb.z },
+                    on_top: args.selectors[top === 'a' ? 0 : 1],
+                    reason
+                };
+            }
+        }
+
+        return {
+            total_contexts: contexts.size,
+            tree: serialize(start, 0),
+            tree_truncated: budget <= 0,
+            ignored_z_index: ignoredZ.slice(0, 50),
+            elements: explained,
+            comparison
+        };"#;
+
+/// Compositing is decided by the engine and not exposed to script, so this mirrors the
+/// common direct promotion reasons of Blink and WebKit from computed styles: 3D
+/// transforms, `will-change`, running transform/opacity/filter animations, fixed and
+/// sticky positioning, backdrop filters, scrollers, video, canvas and iframes. Memory is
+/// width × height × devicePixelRatio² × 4 bytes of the layer's box (content size for
+/// scrollers); engines tile large layers, so the in-viewport figure is closer to what is
+/// actually rasterized. Positioned elements painting over a layer may be promoted for
+/// overlap, which is only listed as candidates.
+const COMPOSITING_LAYERS_JS: &str = r#"        const root = args.root_selector ? mcp.query(args.root_selector) : document.documentElement;
+        const dpr = window.devicePixelRatio || 1;
+        const bytesFor = (width, height) => Math.round(Math.max(0, width) * Math.max(0, height) * dpr * dpr * 4);
+        const viewportBytes = bytesFor(window.innerWidth, window.innerHeight);
+        const documentScrolls = document.scrollingElement
+            ? document.scrollingElement.scrollHeight > window.innerHeight || document.scrollingElement.scrollWidth > window.innerWidth
+            : false;
+        const ANIMATED = ['transform', 'translate', 'scale', 'rotate', 'opacity', 'filter', 'backdropFilter', 'backdrop-filter'];
+        const WILL_CHANGE = ['transform', 'translate', 'scale', 'rotate', 'opacity', 'filter', 'backdrop-filter', 'perspective', 'scroll-position', 'top', 'left', 'bottom', 'right'];
+
+        // Elements in tree order, descending into open shadow roots
+        const elements = [];
+        const collect = (node) => {
+            for (let child = node.firstElementChild; child; child = child.nextElementSibling) {
+                elements.push(child);
+                if (child.shadowRoot) collect(child.shadowRoot);
+                collect(child);
+            }
+        };
+        if (root !== document.documentElement) elements.push(root);
+        if (root.shadowRoot) collect(root.shadowRoot);
+        collect(root);
+
+        const is3d = (style) => {
+            if ((style.transform || '').startsWith('matrix3d(')) return 'transform: ' + style.transform.substring(0, 60);
+            for (const prop of ['translate', 'scale']) {
+                const value = style[prop];
+                if (value && value !== 'none' && value.trim().split(/\s+/).length === 3) return prop + ': ' + value;
+            }
+            const rotate = style.rotate;
+            if (rotate && rotate !== 'none' && /^(x|y|[-\d.]+\s)/.test(rotate.trim())) return 'rotate: ' + rotate;
+            return null;
+        };
+        const animatedProperties = (el) => {
+            if (typeof el.getAnimations !== 'function') return [];
+            const found = new Set();
+            for (const animation of el.getAnimations()) {
+                if (animation.playState !== 'running' || !animation.effect || typeof animation.effect.getKeyframes !== 'function') continue;
+                if (animation.effect.target !== el) continue;
+                for (const frame of animation.effect.getKeyframes()) {
+                    for (const key of Object.keys(frame)) if (ANIMATED.includes(key)) found.add(key.replace(/[A-Z]/g, c => '-' + c.toLowerCase()));
+                }
+            }
+            return Array.from(found);
+        };
+
+        const layers = [];
+        const byReason = {};
+        let scanned = 0;
+        for (const el of elements) {
+            const style = getComputedStyle(el);
+            if (style.display === 'none' || style.display === 'contents') continue;
+            scanned++;
+            const reasons = [];
+            const add = (reason, detail, confidence) => reasons.push({ reason, detail, confidence });
+            const tag = el.tagName.toLowerCase();
+
+            const threeD = is3d(style);
+            if (threeD) add('3d_transform', threeD, 'likely');
+            const willChange = (style.willChange || 'auto').split(',').map(v => v.trim()).filter(v => WILL_CHANGE.includes(v));
+            if (willChange.length > 0) add('will_change', 'will-change: ' + willChange.join(', '), 'likely');
+            const animated = animatedProperties(el);
+            if (animated.length > 0) add('active_animation', 'running animation of ' + animated.join(', '), 'likely');
+            if (style.position === 'fixed') add('fixed_position', documentScrolls ? 'fixed inside a scrolling document' : 'fixed, document does not scroll', documentScrolls ? 'likely' : 'possible');
+            if (style.position === 'sticky') add('sticky_position', 'position: sticky', 'possible');
+            if (style.backdropFilter && style.backdropFilter !== 'none') add('backdrop_filter', 'backdrop-filter: ' + style.backdropFilter, 'likely');
+            if (style.perspective && style.perspective !== 'none') add('perspective', 'perspective: ' + style.perspective, 'possible');
+            if (style.transformStyle === 'preserve-3d') add('preserve_3d', 'transform-style: preserve-3d', 'possible');
+            if (style.backfaceVisibility === 'hidden' && (threeD || style.transformStyle === 'preserve-3d')) add('backface_hidden', 'backface-visibility: hidden in a 3D context', 'possible');
+            if (tag === 'video') add('video', 'video element', 'likely');
+            if (tag === 'canvas') add('canvas', el.width + '×' + el.height + ' canvas (accelerated when WebGL or large 2D)', 'possible');
+            if (tag === 'iframe') add('iframe', 'iframe', 'possible');
+            const scrollsY = /(auto|scroll)/.test(style.overflowY) && el.scrollHeight > el.clientHeight + 1;
+            const scrollsX = /(auto|scroll)/.test(style.overflowX) && el.scrollWidth > el.clientWidth + 1;
+            const isScroller = (scrollsX || scrollsY) && el !== document.documentElement && el !== document.body;
+            if (isScroller) add('composited_scroller', 'scrollable overflow (' + [scrollsX ? 'x' : null, scrollsY ? 'y' : null].filter(Boolean).join('/') + ')', 'possible');
+            if (reasons.length === 0) continue;
+
+            const rect = el.getBoundingClientRect();
+            const width = isScroller ? Math.max(el.scrollWidth, rect.width) : rect.width;
+            const height = isScroller ? Math.max(el.scrollHeight, rect.height) : rect.height;
+            const visibleWidth = Math.max(0, Math.min(rect.right, window.innerWidth) - Math.max(rect.left, 0));
+            const visibleHeight = Math.max(0, Math.min(rect.bottom, window.innerHeight) - Math.max(rect.top, 0));
+            const inViewport = visibleWidth > 0 && visibleHeight > 0;
+            if (!args.include_offscreen && !inViewport) continue;
+
+            for (const entry of reasons) byReason[entry.reason] = (byReason[entry.reason] || 0) + 1;
+            layers.push({
+                el,
+                selector: mcp.cssPath(el),
+                tag,
+                reasons,
+                rect: mcp.rect(rect),
+                in_viewport: inViewport,
+                layer_size: { width: Math.round(width), height: Math.round(height) },
+                estimated_bytes: bytesFor(width, height),
+                estimated_bytes_in_viewport: bytesFor(visibleWidth, visibleHeight),
+                will_change_idle: willChange.length > 0 && animated.length === 0,
+                z_index: style.zIndex
+            });
+        }
+
+        // Positioned elements later in tree order that overlap a likely layer may need
+        // their own layer to paint above it
+        const likely = layers.filter(layer => layer.reasons.some(r => r.confidence === 'likely') && layer.estimated_bytes > 0);
+        const overlapCandidates = [];
+        if (likely.length > 0) {
+            const promoted = new Set(layers.map(layer => layer.el));
+            const after = (a, b) => (a.compareDocumentPosition(b) & Node.DOCUMENT_POSITION_FOLLOWING) !== 0;
+            for (const el of elements) {
+                if (overlapCandidates.length >= 20) break;
+                if (promoted.has(el)) continue;
+                const style = getComputedStyle(el);
+                if (style.position === 'static' || style.display === 'none') continue;
+                const rect = el.getBoundingClientRect();
+                if (rect.width === 0 || rect.height === 0) continue;
+                const below = likely.find(layer => !layer.el.contains(el) && after(layer.el, el) &&
+                    rect.left < layer.rect.x + layer.rect.width && rect.right > layer.rect.x &&
+                    rect.top < layer.rect.y + layer.rect.height && rect.bottom > layer.rect.y);
+                if (below) overlapCandidates.push({ selector: mcp.cssPath(el), overlaps: below.selector });
+            }
+        }
+
+        const totalBytes = layers.reduce((sum, layer) => sum + layer.estimated_bytes, 0);
+        const visibleBytes = layers.reduce((sum, layer) => sum + layer.estimated_bytes_in_viewport, 0);
+        const idleWillChange = layers.filter(layer => layer.will_change_idle).length;
+        const warnings = [];
+        if (layers.length > 30) warnings.push(layers.length + ' elements look promoted; many layers cost memory and compositing time');
+        if (totalBytes > viewportBytes * 3) warnings.push('Estimated layer memory is ' + (totalBytes / viewportBytes).toFixed(1) + '× the viewport');
+        if (idleWillChange > 0) warnings.push(idleWillChange + ' element(s) keep will-change without a running animation; set it only while animating');
+        const huge = layers.filter(layer => layer.estimated_bytes > viewportBytes * 2);
+        if (huge.length > 0) warnings.push(huge.length + ' layer(s) are larger than twice the viewport');
+        if (overlapCandidates.length > 0) warnings.push('Positioned elements overlap promoted layers and may be promoted for overlap');
+
+        layers.sort((a, b) => b.estimated_bytes - a.estimated_bytes);
+        const toMb = (bytes) => Math.round(bytes / 10485.76) / 100;
+        return {
+            heuristic: true,
+            note: 'Promotion is estimated from computed styles; the engine may merge, skip or add layers',
+            device_pixel_ratio: dpr,
+            scanned_elements: scanned,
+            layer_count: layers.length,
+            returned: Math.min(layers.length, args.limit),
+            truncated: layers.length > args.limit,
+            by_reason: byReason,
+            viewport_layer_mb: toMb(viewportBytes),
+            estimated_total_mb: toMb(totalBytes),
+            estimated_in_viewport_mb: toMb(visibleBytes),
+            warnings,
+            overlap_candidates: overlapCandidates,
+            layers: layers.slice(0, args.limit).map(({ el, ...layer }) => ({ ...layer, estimated_mb: toMb(layer.estimated_bytes) }))
+        };"#;
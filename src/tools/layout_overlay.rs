@@ -0,0 +1,188 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct LayoutOverlayRequest {
+    window_label: Option<String>,
+    /// "on", "off", "toggle" (default) or "status"
+    action: Option<String>,
+    /// Elements to outline (default every element)
+    selector: Option<String>,
+    /// Any CSS color for the outlines (default a translucent red)
+    color: Option<String>,
+    /// Shade each outlined element's margins (default false)
+    show_margins: Option<bool>,
+    /// Most elements given a margin overlay (default 2000)
+    max_margin_boxes: Option<usize>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for showing, hiding and inspecting the element boundary overlay
+pub async fn handle_toggle_layout_overlay<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("toggle_layout_overlay", &payload)
+        .optional("action", FieldKind::OneOf(&["on", "off", "toggle", "status"]))
+        .optional("selector", FieldKind::NonEmptyString)
+        .optional("color", FieldKind::NonEmptyString)
+        .optional("show_margins", FieldKind::Bool)
+        .optional("max_margin_boxes", FieldKind::Integer { min: Some(1), max: Some(20_000) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: LayoutOverlayRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for toggle_layout_overlay: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Layout overlay error",
+        &json!({
+            "action": request.action.as_deref().unwrap_or("toggle"),
+            "selector": request.selector.as_deref().unwrap_or("*"),
+            "color": request.color.as_deref().unwrap_or("rgba(255, 0, 0, 0.6)"),
+            "show_margins": request.show_margins.unwrap_or(false),
+            "max_margin_boxes": request.max_margin_boxes.unwrap_or(2000),
+        }),
+        LAYOUT_OVERLAY_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "layout overlay",
+    )
+    .await
+}
+
+/// Outlines are drawn with an inset `outline`, which never affects layout. Margins are
+/// shaded by a separate click-through layer of boxes whose borders are as wide as each
+/// element's margins, redrawn on scroll and resize. Styles in shadow roots are not
+/// reached by the outline stylesheet. Turning it on again replaces the previous settings.
+const LAYOUT_OVERLAY_JS: &str = r#"        const STYLE_ID = '__mcp_layout_overlay__';
+        const LAYER_ID = '__mcp_layout_overlay_margins__';
+        const MARGIN_COLOR = 'rgba(246, 178, 107, 0.45)';
+        const state = window.__MCP_LAYOUT_OVERLAY__ || null;
+
+        const status = () => {
+            const current = window.__MCP_LAYOUT_OVERLAY__;
+            if (!current) return { enabled: false };
+            let outlined = null;
+            try { outlined = document.querySelectorAll(current.selector).length; } catch (e) {}
+            const layer = document.getElementById(LAYER_ID);
+            return {
+                enabled: true,
+                selector: current.selector,
+                color: current.color,
+                show_margins: current.show_margins,
+                outlined_elements: outlined,
+                margin_boxes: layer ? layer.childElementCount : 0,
+                margin_boxes_truncated: current.truncated,
+                enabled_at_ms: current.enabled_at_ms
+            };
+        };
+
+        const disable = () => {
+            const current = window.__MCP_LAYOUT_OVERLAY__;
+            if (current && current.cleanup) current.cleanup();
+            const style = document.getElementById(STYLE_ID);
+            if (style) style.remove();
+            const layer = document.getElementById(LAYER_ID);
+            if (layer) layer.remove();
+            delete window.__MCP_LAYOUT_OVERLAY__;
+            return { enabled: false, was_enabled: !!current };
+        };
+
+        const enable = () => {
+            if (!CSS.supports('color', args.color)) throw new Error('Not a valid CSS color: ' + args.color);
+            try {
+                document.querySelectorAll(args.selector);
+            } catch (error) {
+                throw new Error('Invalid selector ' + JSON.stringify(args.selector) + ': ' + error.message);
+            }
+            disable();
+
+            const style = document.createElement('style');
+            style.id = STYLE_ID;
+            style.textContent = ':is(' + args.selector + '):not(#' + LAYER_ID + ', #' + LAYER_ID + ' *) { outline: 1px solid ' + args.color + ' !important; outline-offset: -1px !important; }';
+            (document.head || document.documentElement).appendChild(style);
+
+            const current = window.__MCP_LAYOUT_OVERLAY__ = {
+                selector: args.selector,
+                color: args.color,
+                show_margins: args.show_margins,
+                truncated: false,
+                enabled_at_ms: Date.now(),
+                cleanup: null
+            };
+
+            if (args.show_margins) {
+                const layer = document.createElement('div');
+                layer.id = LAYER_ID;
+                layer.setAttribute('aria-hidden', 'true');
+                layer.style.cssText = 'position: absolute; top: 0; left: 0; width: 0; height: 0; pointer-events: none; z-index: 2147483647;';
+                document.documentElement.appendChild(layer);
+
+                const draw = () => {
+                    const boxes = [];
+                    let count = 0;
+                    for (const el of document.querySelectorAll(args.selector)) {
+                        if (layer.contains(el) || el === layer) continue;
+                        const cs = getComputedStyle(el);
+                        const margins = ['Top', 'Right', 'Bottom', 'Left'].map(side => Math.max(0, parseFloat(cs['margin' + side]) || 0));
+                        if (margins.every(m => m === 0) || cs.display === 'none') continue;
+                        const r = el.getBoundingClientRect();
+                        if (r.width === 0 && r.height === 0) continue;
+                        if (count >= args.max_margin_boxes) {
+                            current.truncated = true;
+                            break;
+                        }
+                        count += 1;
+                        const [top, right, bottom, left] = margins;
+                        boxes.push('<div style="position: absolute; box-sizing: border-box; border-style: solid; border-color: ' + MARGIN_COLOR + ';'
+                            + ' left: ' + (r.left + window.scrollX - left) + 'px; top: ' + (r.top + window.scrollY - top) + 'px;'
+                            + ' width: ' + (r.width + left + right) + 'px; height: ' + (r.height + top + bottom) + 'px;'
+                            + ' border-width: ' + top + 'px ' + right + 'px ' + bottom + 'px ' + left + 'px;"></div>');
+                    }
+                    layer.innerHTML = boxes.join('');
+                };
+                draw();
+
+                // Inner scroll containers and resizes move elements relative to the page
+                let pending = false;
+                const schedule = () => {
+                    if (pending) return;
+                    pending = true;
+                    requestAnimationFrame(() => {
+                        pending = false;
+                        if (document.getElementById(LAYER_ID)) draw();
+                    });
+                };
+                document.addEventListener('scroll', schedule, true);
+                window.addEventListener('resize', schedule);
+                current.cleanup = () => {
+                    document.removeEventListener('scroll', schedule, true);
+                    window.removeEventListener('resize', schedule);
+                };
+            }
+            return status();
+        };
+
+        let action = args.action;
+        if (action === 'toggle') action = state ? 'off' : 'on';
+        if (action === 'status') return status();
+        if (action === 'off') return disable();
+        return enable();"#;
@@ -0,0 +1,191 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct TriggerLazyLoadRequest {
+    window_label: Option<String>,
+    /// Scroll this element instead of the page
+    container_selector: Option<String>,
+    /// Pixels per step (default 90% of the visible height)
+    step_px: Option<u32>,
+    /// Maximum number of scroll steps (default 30)
+    max_steps: Option<u32>,
+    /// Quiet period without new requests that counts as network idle (default 500)
+    idle_ms: Option<u64>,
+    /// Longest wait for network idle after each step (default 3000)
+    step_timeout_ms: Option<u64>,
+    /// Scroll back to the starting position afterwards (default false)
+    restore_scroll: Option<bool>,
+    timeout_ms: Option<u64>,
+}
+
+/// Upper bound for the derived default timeout
+const MAX_DEFAULT_TIMEOUT_MS: u64 = 25_000;
+
+/// Handler function for scrolling to the bottom step by step and reporting what loaded
+pub async fn handle_trigger_lazy_load<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("trigger_lazy_load", &payload)
+        .optional("container_selector", FieldKind::NonEmptyString)
+        .optional("step_px", FieldKind::Integer { min: Some(1), max: Some(100_000) })
+        .optional("max_steps", FieldKind::Integer { min: Some(1), max: Some(500) })
+        .optional("idle_ms", FieldKind::Integer { min: Some(0), max: Some(10_000) })
+        .optional("step_timeout_ms", FieldKind::Integer { min: Some(0), max: Some(60_000) })
+        .optional("restore_scroll", FieldKind::Bool)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: TriggerLazyLoadRequest = serde_json::from_value(payload).map_err(|e| {
+        Error::serialization_error(format!("Invalid payload for trigger_lazy_load: {}", e))
+    })?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let max_steps = request.max_steps.unwrap_or(30);
+    let step_timeout_ms = request.step_timeout_ms.unwrap_or(3000);
+    // Allow every step its full wait by default, but stay under the MCP client's 30s limit
+    let timeout_ms = request.timeout_ms.unwrap_or(
+        (DEFAULT_PROBE_TIMEOUT_MS + max_steps as u64 * step_timeout_ms).min(MAX_DEFAULT_TIMEOUT_MS),
+    );
+
+    let js_code = build_probe_script(
+        "Lazy load error",
+        &json!({
+            "container_selector": request.container_selector,
+            "step_px": request.step_px,
+            "max_steps": max_steps,
+            "idle_ms": request.idle_ms.unwrap_or(500),
+            "step_timeout_ms": step_timeout_ms,
+            "restore_scroll": request.restore_scroll.unwrap_or(false),
+            // Stop stepping early enough to still return a result within the timeout
+            "budget_ms": timeout_ms.saturating_sub(1000),
+        }),
+        TRIGGER_LAZY_LOAD_JS,
+    );
+
+    run_probe(app, &window_label, js_code, timeout_ms, "lazy load").await
+}
+
+/// Requests are counted with a `PerformanceObserver` on resource entries, which are
+/// only emitted once a request finishes. Network idle therefore means no new entry
+/// for `idle_ms` and every `<img>` in scope complete. The page is at the bottom when
+/// the scroll position reaches the end and the scroll height stopped growing.
+const TRIGGER_LAZY_LOAD_JS: &str = r#"        const started = performance.now();
+        const container = args.container_selector ? mcp.query(args.container_selector) : null;
+        const scope = container || document.documentElement;
+        const scroller = container || document.scrollingElement || document.documentElement;
+        const sleep = (ms) => new Promise(resolve => setTimeout(resolve, ms));
+
+        const requests = [];
+        let lastRequestAt = performance.now();
+        const observer = new PerformanceObserver((list) => {
+            for (const entry of list.getEntries()) {
+                requests.push({ url: entry.name, initiator_type: entry.initiatorType, duration_ms: Math.round(entry.duration) });
+                lastRequestAt = performance.now();
+            }
+        });
+        observer.observe({ type: 'resource', buffered: false });
+
+        const initialElements = new WeakSet(scope.querySelectorAll('*'));
+        const elementCountBefore = scope.querySelectorAll('*').length;
+        const imagesBefore = scope.querySelectorAll('img').length;
+        const isLoaded = (img) => img.complete && img.naturalWidth > 0;
+        const loadedBefore = new WeakSet([...scope.querySelectorAll('img')].filter(isLoaded));
+        const startPosition = { top: scroller.scrollTop, left: scroller.scrollLeft };
+
+        const waitForIdle = async () => {
+            const stepStart = performance.now();
+            while (performance.now() - stepStart < args.step_timeout_ms) {
+                const pendingImages = [...scope.querySelectorAll('img')].filter(img => !img.complete && img.loading !== 'lazy').length;
+                if (pendingImages === 0 && performance.now() - lastRequestAt >= args.idle_ms) {
+                    return { idle: true, waited_ms: Math.round(performance.now() - stepStart) };
+                }
+                await sleep(50);
+            }
+            return { idle: false, waited_ms: Math.round(performance.now() - stepStart) };
+        };
+
+        const steps = [];
+        let stoppedReason = 'max_steps';
+        try {
+            for (let i = 0; i < args.max_steps; i++) {
+                if (performance.now() - started > args.budget_ms) {
+                    stoppedReason = 'time_budget';
+                    break;
+                }
+                const heightBefore = scroller.scrollHeight;
+                const requestsBefore = requests.length;
+                const elementsBefore = scope.querySelectorAll('*').length;
+                const step = args.step_px || Math.max(1, Math.round((container ? container.clientHeight : window.innerHeight) * 0.9));
+
+                scroller.scrollTop = scroller.scrollTop + step;
+                // Give scroll handlers and IntersectionObservers a frame to react
+                await new Promise(resolve => requestAnimationFrame(() => requestAnimationFrame(resolve)));
+                const idle = await waitForIdle();
+
+                const atEnd = Math.ceil(scroller.scrollTop + scroller.clientHeight) >= scroller.scrollHeight - 2;
+                steps.push({
+                    step: i + 1,
+                    scroll_top: Math.round(scroller.scrollTop),
+                    scroll_height: scroller.scrollHeight,
+                    new_elements: scope.querySelectorAll('*').length - elementsBefore,
+                    requests: requests.length - requestsBefore,
+                    network_idle: idle.idle,
+                    waited_ms: idle.waited_ms
+                });
+
+                if (atEnd && scroller.scrollHeight <= heightBefore) {
+                    stoppedReason = 'bottom';
+                    break;
+                }
+            }
+        } finally {
+            observer.disconnect();
+            if (args.restore_scroll) {
+                scroller.scrollTop = startPosition.top;
+                scroller.scrollLeft = startPosition.left;
+            }
+        }
+
+        const newImages = [...scope.querySelectorAll('img')].filter(img => !initialElements.has(img));
+        const existingLoaded = [...scope.querySelectorAll('img')]
+            .filter(img => initialElements.has(img) && !loadedBefore.has(img) && isLoaded(img)).length;
+        const byType = {};
+        for (const request of requests) byType[request.initiator_type] = (byType[request.initiator_type] || 0) + 1;
+
+        return {
+            target: container ? mcp.cssPath(container) : 'document',
+            stopped_reason: stoppedReason,
+            steps_taken: steps.length,
+            duration_ms: Math.round(performance.now() - started),
+            element_count: { before: elementCountBefore, after: scope.querySelectorAll('*').length },
+            new_elements: scope.querySelectorAll('*').length - elementCountBefore,
+            images: {
+                before: imagesBefore,
+                new: newImages.length,
+                new_loaded: newImages.filter(isLoaded).length,
+                new_failed: newImages.filter(img => img.complete && img.naturalWidth === 0).length,
+                new_pending: newImages.filter(img => !img.complete).length,
+                existing_loaded: existingLoaded,
+                failed: newImages.filter(img => img.complete && img.naturalWidth === 0).map(img => ({ selector: mcp.cssPath(img), url: img.currentSrc || img.src || null })).slice(0, 50)
+            },
+            requests: {
+                total: requests.length,
+                by_type: byType,
+                sample: requests.slice(0, 50)
+            },
+            final_scroll_top: Math.round(scroller.scrollTop),
+            steps
+        };"#;
@@ -0,0 +1,276 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct CaptureLiveRegionsRequest {
+    window_label: Option<String>,
+    /// Announcements kept before the oldest are dropped (default 500)
+    max_entries: Option<usize>,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct GetAnnouncementsRequest {
+    window_label: Option<String>,
+    /// "polite" or "assertive"
+    politeness: Option<String>,
+    /// Only announcements whose text contains this
+    text_contains: Option<String>,
+    /// Only announcements with a greater id
+    since_id: Option<u64>,
+    /// Maximum number of announcements to return, newest last (default 100)
+    limit: Option<usize>,
+    /// Empty the buffer after reading (default false)
+    clear: Option<bool>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for installing the live-region announcement recorder
+pub async fn handle_capture_live_regions<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("capture_live_regions", &payload)
+        .optional("max_entries", FieldKind::Integer { min: Some(1), max: Some(100_000) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: CaptureLiveRegionsRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for capture_live_regions: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Live region capture error",
+        &json!({ "max_entries": request.max_entries.unwrap_or(500) }),
+        CAPTURE_LIVE_REGIONS_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "live region capture",
+    )
+    .await
+}
+
+/// Handler function for reading recorded live-region announcements
+pub async fn handle_get_announcements<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_announcements", &payload)
+        .optional("politeness", FieldKind::OneOf(&["polite", "assertive"]))
+        .optional("text_contains", FieldKind::String)
+        .optional("since_id", FieldKind::UINT)
+        .optional("limit", FieldKind::Integer { min: Some(1), max: Some(100_000) })
+        .optional("clear", FieldKind::Bool)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: GetAnnouncementsRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_announcements: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Announcement read error",
+        &json!({
+            "politeness": request.politeness,
+            "text_contains": request.text_contains,
+            "since_id": request.since_id.unwrap_or(0),
+            "limit": request.limit.unwrap_or(100),
+            "clear": request.clear.unwrap_or(false),
+        }),
+        GET_ANNOUNCEMENTS_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "announcement read",
+    )
+    .await
+}
+
+/// Follows what screen readers announce: regions are `aria-live` elements or the
+/// implicitly live `status`, `alert` and `log` roles. Atomic regions (and `status` and
+/// `alert`, which are atomic by default) announce their whole text, others only the
+/// added or changed text; removals count only with `aria-relevant` removals or all.
+/// Changes inside an `aria-busy` region wait until it is no longer busy. A region added
+/// to the page together with its content is usually not announced, except alerts, so
+/// those entries are marked as unlikely to be heard.
+const CAPTURE_LIVE_REGIONS_JS: &str = r#"        const existing = window.__MCP_LIVE_REGIONS__;
+        if (existing) {
+            existing.max_entries = args.max_entries;
+            return { installed: true, already_installed: true, installed_at_ms: existing.installed_at_ms, buffered: existing.entries.length };
+        }
+
+        const store = { installed_at_ms: Date.now(), next_id: 1, dropped: 0, entries: [], max_entries: args.max_entries };
+        window.__MCP_LIVE_REGIONS__ = store;
+
+        const REGION_SELECTOR = '[aria-live], [role~="status"], [role~="alert"], [role~="log"]';
+        const ROLE_DEFAULTS = { alert: ['assertive', true], status: ['polite', true], log: ['polite', false] };
+        const normalize = (text) => (text || '').replace(/\s+/g, ' ').trim();
+        const settings = (region) => {
+            const role = (region.getAttribute('role') || '').split(/\s+/).find(r => r in ROLE_DEFAULTS) || null;
+            const [rolePoliteness, roleAtomic] = role ? ROLE_DEFAULTS[role] : ['off', false];
+            const live = region.getAttribute('aria-live');
+            const atomic = region.getAttribute('aria-atomic');
+            return {
+                role,
+                politeness: live === 'polite' || live === 'assertive' || live === 'off' ? live : rolePoliteness,
+                atomic: atomic === null ? roleAtomic : atomic === 'true',
+                relevant: (region.getAttribute('aria-relevant') || 'additions text').split(/\s+/)
+            };
+        };
+        const regionOf = (node) => {
+            const el = node.nodeType === Node.ELEMENT_NODE ? node : node.parentElement;
+            return el ? el.closest(REGION_SELECTOR) : null;
+        };
+        const textOf = (node) => {
+            if (node.nodeType === Node.TEXT_NODE) return node.data;
+            if (node.nodeType !== Node.ELEMENT_NODE || node.closest('[aria-hidden="true"]')) return '';
+            return node.innerText !== undefined && node.isConnected ? node.innerText : node.textContent;
+        };
+        const busyPending = new Set();
+
+        const record = (region, source, text, isNew) => {
+            text = normalize(text);
+            if (!text) return;
+            const config = settings(region);
+            const likely = !(isNew && config.role !== 'alert' && source !== 'busy_end');
+            store.entries.push({
+                id: store.next_id++,
+                timestamp_ms: Date.now(),
+                text,
+                politeness: config.politeness,
+                atomic: config.atomic,
+                role: config.role,
+                region: mcp.cssPath(region),
+                source,
+                likely_announced: likely,
+                note: likely ? null : 'The live region was added together with its content; most screen readers only announce changes to regions already on the page'
+            });
+            if (store.entries.length > store.max_entries) {
+                const excess = store.entries.length - store.max_entries;
+                store.entries.splice(0, excess);
+                store.dropped += excess;
+            }
+        };
+
+        const observer = new MutationObserver((mutations) => mcp.timed('live_regions', () => {
+            const changes = new Map();
+            // Regions inserted in this batch, whose initial content is not a change
+            const created = new Set();
+            const change = (region) => {
+                if (!changes.has(region)) changes.set(region, { added: [], removed: [], text: [], busyEnded: false });
+                return changes.get(region);
+            };
+            for (const mutation of mutations) {
+                if (mutation.type === 'attributes') {
+                    if (mutation.attributeName === 'aria-busy' && mutation.target.getAttribute('aria-busy') !== 'true' && busyPending.has(mutation.target)) {
+                        busyPending.delete(mutation.target);
+                        change(mutation.target).busyEnded = true;
+                    }
+                    continue;
+                }
+                if (mutation.type === 'characterData') {
+                    const region = regionOf(mutation.target);
+                    if (region) change(region).text.push(mutation.target);
+                    continue;
+                }
+                for (const node of mutation.addedNodes) {
+                    if (node.nodeType === Node.ELEMENT_NODE) {
+                        if (node.matches(REGION_SELECTOR)) created.add(node);
+                        node.querySelectorAll(REGION_SELECTOR).forEach(region => created.add(region));
+                    }
+                    const region = regionOf(node) || (mutation.target.nodeType === Node.ELEMENT_NODE ? regionOf(mutation.target) : null);
+                    if (region) change(region).added.push(node);
+                }
+                if (mutation.removedNodes.length > 0) {
+                    const region = regionOf(mutation.target);
+                    if (region) change(region).removed.push(...mutation.removedNodes);
+                }
+            }
+
+            for (const [region, delta] of changes) {
+                const config = settings(region);
+                if (config.politeness === 'off') continue;
+                if (region.getAttribute('aria-busy') === 'true') {
+                    busyPending.add(region);
+                    continue;
+                }
+                if (config.atomic || delta.busyEnded) {
+                    record(region, delta.busyEnded ? 'busy_end' : 'atomic_update', textOf(region), created.has(region));
+                    continue;
+                }
+                const relevant = config.relevant;
+                const all = relevant.includes('all');
+                const parts = [];
+                if (all || relevant.includes('additions')) parts.push(...delta.added.filter(node => node.isConnected).map(textOf));
+                if (all || relevant.includes('text')) parts.push(...delta.text.filter(node => node.isConnected).map(textOf));
+                if (parts.length > 0) record(region, 'addition', parts.join(' '), created.has(region));
+                if (all || relevant.includes('removals')) {
+                    const removed = delta.removed.map(node => node.textContent).join(' ');
+                    if (normalize(removed)) record(region, 'removal', removed, false);
+                }
+            }
+        }));
+        observer.observe(document.documentElement, { subtree: true, childList: true, characterData: true, attributes: true, attributeFilter: ['aria-busy'] });
+        store.observer = observer;
+
+        return {
+            installed: true,
+            already_installed: false,
+            installed_at_ms: store.installed_at_ms,
+            regions_on_page: document.querySelectorAll(REGION_SELECTOR).length,
+            note: 'Only announcements after installation are recorded; a navigation discards the recorder'
+        };"#;
+
+const GET_ANNOUNCEMENTS_JS: &str = r#"        const store = window.__MCP_LIVE_REGIONS__;
+        if (!store) {
+            return { installed: false, announcements: [], note: 'Live region capture is not installed in this window; call capture_live_regions first' };
+        }
+
+        const needle = args.text_contains ? args.text_contains.toLowerCase() : null;
+        const matches = store.entries.filter(entry =>
+            entry.id > args.since_id &&
+            (!args.politeness || entry.politeness === args.politeness) &&
+            (!needle || entry.text.toLowerCase().includes(needle))
+        );
+        const announcements = matches.slice(-args.limit);
+        const result = {
+            installed: true,
+            installed_at_ms: store.installed_at_ms,
+            total_buffered: store.entries.length,
+            matched: matches.length,
+            returned: announcements.length,
+            dropped: store.dropped,
+            last_id: store.next_id - 1,
+            announcements
+        };
+        if (args.clear) {
+            store.entries = [];
+            store.dropped = 0;
+        }
+        return result;"#;
@@ -1,5 +1,5 @@
 use serde::{Serialize, Serializer};
-use serde_json::Value;
+use serde_json::{json, Value};
 use std::fmt;
 use std::sync::mpsc;
 use std::time::Duration;
@@ -8,6 +8,8 @@ use tauri::{AppHandle, Emitter, Listener, Manager, Runtime};
 use crate::error::Error;
 use crate::models::LocalStorageRequest;
 use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
 
 // Define a custom error type for localStorage operations
 #[derive(Debug)]
@@ -179,3 +181,119 @@ async fn perform_local_storage_operation<R: Runtime>(
         Err(e) => Err(e.into()),
     }
 }
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct LocalStorageMapRequest {
+    window_label: Option<String>,
+    /// Replacement contents for `set_local_storage_map`
+    entries: Option<serde_json::Map<String, Value>>,
+    timeout_ms: Option<u64>,
+}
+
+fn parse_map_request(command: &str, payload: Value, entries_required: bool) -> Result<(LocalStorageMapRequest, String), Error> {
+    PayloadValidator::new(command, &payload)
+        .required_if(entries_required, "entries", FieldKind::Object)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: LocalStorageMapRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for {}: {}", command, e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    Ok((request, window_label))
+}
+
+/// Handler function for reading the whole localStorage as a typed JSON object
+pub async fn handle_get_local_storage_map<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    let (request, window_label) = parse_map_request("get_local_storage_map", payload, false)?;
+
+    let js_code = build_probe_script("localStorage map error", &json!({}), GET_LOCAL_STORAGE_MAP_JS);
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "localStorage map read",
+    )
+    .await
+}
+
+/// Handler function for replacing the whole localStorage from a JSON object
+pub async fn handle_set_local_storage_map<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    let (request, window_label) = parse_map_request("set_local_storage_map", payload, true)?;
+
+    let js_code = build_probe_script(
+        "localStorage map error",
+        &json!({ "entries": request.entries }),
+        SET_LOCAL_STORAGE_MAP_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "localStorage map write",
+    )
+    .await
+}
+
+/// Parses every value as JSON, falling back to the raw string and recording which keys did
+const GET_LOCAL_STORAGE_MAP_JS: &str = r#"        const entries = {};
+        const rawStringKeys = [];
+        for (let i = 0; i < localStorage.length; i++) {
+            const key = localStorage.key(i);
+            const raw = localStorage.getItem(key);
+            try {
+                entries[key] = JSON.parse(raw);
+            } catch (e) {
+                entries[key] = raw;
+                rawStringKeys.push(key);
+            }
+        }
+
+        return {
+            entries,
+            key_count: Object.keys(entries).length,
+            raw_string_keys: rawStringKeys.sort()
+        };"#;
+
+/// Clears and repopulates localStorage, restoring the previous contents if any write fails
+/// (e.g. on quota errors) so the store is never left half-written
+const SET_LOCAL_STORAGE_MAP_JS: &str = r#"        const snapshot = {};
+        for (let i = 0; i < localStorage.length; i++) {
+            const key = localStorage.key(i);
+            snapshot[key] = localStorage.getItem(key);
+        }
+
+        // Strings are stored as-is so they read back unchanged; everything else as JSON
+        const serialized = Object.entries(args.entries).map(([key, value]) =>
+            [key, typeof value === 'string' ? value : JSON.stringify(value)]);
+
+        try {
+            localStorage.clear();
+            serialized.forEach(([key, value]) => localStorage.setItem(key, value));
+        } catch (error) {
+            localStorage.clear();
+            Object.entries(snapshot).forEach(([key, value]) => localStorage.setItem(key, value));
+            throw new Error('Failed to write localStorage, previous contents restored: ' + (error && error.message || error));
+        }
+
+        return {
+            previous_key_count: Object.keys(snapshot).length,
+            key_count: serialized.length,
+            keys: serialized.map(([key]) => key).sort()
+        };"#;
@@ -0,0 +1,205 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+#[cfg(desktop)]
+use crate::tools::menu_items::{describe_item, find_item};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+/// Event emitted to the app when a menu item activation is simulated.
+///
+/// Like tray menus, native menu events cannot be injected, so `on_menu_event` handlers
+/// are not called. This event is opt-in: apps that want menu flows to be testable route
+/// it to their `on_menu_event` logic.
+pub const MENU_ACTIVATE_EVENT: &str = "tauri-mcp://menu-item-activate";
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct MenuRequest {
+    window_label: Option<String>,
+    /// Id of the item to activate for `trigger_menu_item`
+    item_id: Option<String>,
+    /// Activate the item even when it is disabled (default false)
+    allow_disabled: Option<bool>,
+}
+
+fn parse_request(command: &str, payload: Value) -> Result<(MenuRequest, String), Error> {
+    PayloadValidator::new(command, &payload)
+        .required_if(command == "trigger_menu_item", "item_id", FieldKind::NonEmptyString)
+        .optional("allow_disabled", FieldKind::Bool)
+        .optional("window_label", FieldKind::String)
+        .finish()?;
+
+    let request: MenuRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for {}: {}", command, e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    Ok((request, window_label))
+}
+
+/// Handler function for reading the window or app menu structure
+pub async fn handle_get_menu<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    let (_request, window_label) = parse_request("get_menu", payload)?;
+
+    get_menu(app, &window_label)
+}
+
+/// Handler function for simulating a click on a menu item
+pub async fn handle_trigger_menu_item<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    let (request, window_label) = parse_request("trigger_menu_item", payload)?;
+
+    trigger_menu_item(app, &request, &window_label)
+}
+
+/// The window's own menu (Windows/Linux) takes precedence over the app menu (macOS)
+#[cfg(desktop)]
+fn active_menu<R: Runtime>(
+    app: &AppHandle<R>,
+    window_label: &str,
+) -> Result<Option<(tauri::menu::Menu<R>, &'static str)>, Error> {
+    use tauri::Manager;
+
+    let window = app
+        .get_webview_window(window_label)
+        .ok_or_else(|| Error::window_not_found(window_label))?;
+
+    Ok(window
+        .menu()
+        .map(|menu| (menu, "window"))
+        .or_else(|| app.menu().map(|menu| (menu, "app"))))
+}
+
+#[cfg(desktop)]
+fn get_menu<R: Runtime>(app: &AppHandle<R>, window_label: &str) -> Result<SocketResponse, Error> {
+    let Some((menu, source)) = active_menu(app, window_label)? else {
+        return Ok(SocketResponse {
+            success: true,
+            data: Some(json!({ "window_label": window_label, "source": null, "items": [] })),
+            error: None,
+        });
+    };
+
+    let items = menu
+        .items()
+        .map_err(|e| Error::window_operation_failed("read menu items", e.to_string()))?;
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(json!({
+            "window_label": window_label,
+            "source": source,
+            "items": items.iter().map(|item| describe_item(item, 0)).collect::<Vec<_>>(),
+        })),
+        error: None,
+    })
+}
+
+#[cfg(desktop)]
+fn trigger_menu_item<R: Runtime>(
+    app: &AppHandle<R>,
+    request: &MenuRequest,
+    window_label: &str,
+) -> Result<SocketResponse, Error> {
+    use tauri::menu::MenuItemKind;
+    use tauri::Emitter;
+
+    let item_id = request.item_id.clone().unwrap_or_default();
+    let failure = |message: String| -> Result<SocketResponse, Error> {
+        Ok(SocketResponse {
+            success: false,
+            data: None,
+            error: Some(message),
+        })
+    };
+
+    let Some((menu, source)) = active_menu(app, window_label)? else {
+        return failure(format!("Window '{}' has no menu and no app menu is set", window_label));
+    };
+
+    let items = menu
+        .items()
+        .map_err(|e| Error::window_operation_failed("read menu items", e.to_string()))?;
+    let mut path = Vec::new();
+    let Some(item) = find_item(items, &item_id, &mut path) else {
+        return failure(format!("No menu item with id '{}' in the {} menu", item_id, source));
+    };
+
+    let enabled = match &item {
+        MenuItemKind::MenuItem(i) => i.is_enabled().ok(),
+        MenuItemKind::Check(i) => i.is_enabled().ok(),
+        MenuItemKind::Icon(i) => i.is_enabled().ok(),
+        MenuItemKind::Submenu(_) => {
+            return failure(format!("Menu item '{}' is a submenu and cannot be triggered", item_id));
+        }
+        MenuItemKind::Predefined(_) => {
+            return failure(format!(
+                "Menu item '{}' is a predefined item whose native action does not go through menu events",
+                item_id
+            ));
+        }
+    };
+    if enabled == Some(false) && !request.allow_disabled.unwrap_or(false) {
+        return failure(format!("Menu item '{}' is disabled; pass allow_disabled to trigger it anyway", item_id));
+    }
+
+    // Check state is left alone: nothing toggles it when no real click happens
+    let checked = match &item {
+        MenuItemKind::Check(i) => i.is_checked().ok(),
+        _ => None,
+    };
+
+    app.emit(
+        MENU_ACTIVATE_EVENT,
+        json!({ "id": item_id, "window_label": window_label }),
+    )
+    .map_err(|e| Error::communication_error_with_context(
+        "Failed to emit menu activation",
+        format!("window: {}, item: {}, error: {}", window_label, item_id, e),
+    ))?;
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(json!({
+            "item_id": item_id,
+            "window_label": window_label,
+            "source": source,
+            "path": path,
+            "checked": checked,
+            "event": MENU_ACTIVATE_EVENT,
+            "dispatched": true,
+            // Only apps that route the event react; no real MenuEvent is fired
+            "menu_event_fired": false,
+            "note": format!("Emitted the opt-in {} event; on_menu_event handlers are not called", MENU_ACTIVATE_EVENT),
+        })),
+        error: None,
+    })
+}
+
+#[cfg(not(desktop))]
+fn get_menu<R: Runtime>(_app: &AppHandle<R>, window_label: &str) -> Result<SocketResponse, Error> {
+    Ok(SocketResponse {
+        success: false,
+        data: Some(json!({ "window_label": window_label, "menu_supported": false })),
+        error: Some("Native menus are only available on desktop platforms".to_string()),
+    })
+}
+
+#[cfg(not(desktop))]
+fn trigger_menu_item<R: Runtime>(
+    app: &AppHandle<R>,
+    _request: &MenuRequest,
+    window_label: &str,
+) -> Result<SocketResponse, Error> {
+    get_menu(app, window_label)
+}
@@ -0,0 +1,80 @@
+use serde_json::{json, Value};
+use tauri::Runtime;
+
+/// Deepest submenu nesting reported when describing a menu
+pub(crate) const MAX_MENU_DEPTH: usize = 16;
+
+/// JSON description of a menu item, recursing into submenus
+pub(crate) fn describe_item<R: Runtime>(item: &tauri::menu::MenuItemKind<R>, depth: usize) -> Value {
+    use tauri::menu::MenuItemKind;
+
+    let id = item.id().0.clone();
+    match item {
+        MenuItemKind::MenuItem(i) => json!({
+            "id": id,
+            "kind": "item",
+            "text": i.text().ok(),
+            "enabled": i.is_enabled().ok(),
+        }),
+        MenuItemKind::Check(i) => json!({
+            "id": id,
+            "kind": "check",
+            "text": i.text().ok(),
+            "enabled": i.is_enabled().ok(),
+            "checked": i.is_checked().ok(),
+        }),
+        MenuItemKind::Icon(i) => json!({
+            "id": id,
+            "kind": "icon",
+            "text": i.text().ok(),
+            "enabled": i.is_enabled().ok(),
+        }),
+        MenuItemKind::Predefined(i) => json!({
+            "id": id,
+            "kind": "predefined",
+            "text": i.text().ok(),
+        }),
+        MenuItemKind::Submenu(s) => {
+            let items: Vec<Value> = if depth < MAX_MENU_DEPTH {
+                s.items()
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|child| describe_item(child, depth + 1))
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            json!({
+                "id": id,
+                "kind": "submenu",
+                "text": s.text().ok(),
+                "enabled": s.is_enabled().ok(),
+                "items": items,
+                "truncated": depth >= MAX_MENU_DEPTH,
+            })
+        }
+    }
+}
+
+/// Depth-first search for an item, returning it with the texts of its parent submenus
+pub(crate) fn find_item<R: Runtime>(
+    items: Vec<tauri::menu::MenuItemKind<R>>,
+    id: &str,
+    path: &mut Vec<String>,
+) -> Option<tauri::menu::MenuItemKind<R>> {
+    use tauri::menu::MenuItemKind;
+
+    for item in items {
+        if item.id().0 == id {
+            return Some(item);
+        }
+        if let MenuItemKind::Submenu(submenu) = &item {
+            path.push(submenu.text().unwrap_or_default());
+            if let Some(found) = find_item(submenu.items().unwrap_or_default(), id, path) {
+                return Some(found);
+            }
+            path.pop();
+        }
+    }
+    None
+}
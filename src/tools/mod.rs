@@ -6,42 +6,165 @@ use crate::shared::commands;
 use crate::socket_server::SocketResponse;
 
 // Export command modules
+pub mod analytics;
+pub mod animations;
+pub mod auto_inject;
+pub mod baselines;
+pub mod battery_connection;
+pub mod canvas;
+pub mod capture_overhead;
+pub mod close_request;
+pub mod computed_style;
 pub mod console_logs;
+pub mod contenteditable;
+pub mod contrast;
+pub mod csp;
+pub mod css_coverage;
+pub mod custom_elements;
+pub mod detached_nodes;
+pub mod devices;
 pub mod devtools_bridge;
+pub mod dialogs;
+pub mod document;
+pub mod dom_stats;
+pub mod drag_reorder;
+pub mod element_text;
 pub mod error_tracker;
 pub mod execute_js;
+pub mod fixture;
+pub mod fonts;
+pub mod gestures;
 pub mod health_check;
 pub mod hot_reload;
+pub mod hover;
+pub mod images;
+pub mod init_timing;
+pub mod interaction_trace;
+pub mod intl_locale;
+pub mod journal;
+pub mod js_probe;
+pub mod latency;
+pub mod layout;
+pub mod layout_overlay;
+pub mod lazy_load;
+pub mod live_regions;
 pub mod local_storage;
+pub mod menu;
+#[cfg(desktop)]
+pub mod menu_items;
+pub mod monitors;
 pub mod mouse_movement;
+pub mod navigation;
 pub mod network_inspector;
+pub mod observers;
+pub mod outline;
 pub mod performance;
 pub mod ping;
+pub mod pixel_color;
+pub mod postmessage;
+pub mod reflows;
+pub mod rerender;
+pub mod route;
+pub mod scroll_behavior;
+pub mod scroll_snap;
+pub mod select_state;
+pub mod selection;
+pub mod service_workers;
+pub mod shadow_dom;
+pub mod shortcuts;
 pub mod state_dump;
 pub mod storage_inspector;
+pub mod storage_purge;
+pub mod stylesheets;
+pub mod tab_order;
 pub mod take_screenshot;
 pub mod text_input;
+pub mod tray;
+pub mod user_scripts;
+pub mod validation;
 pub mod webview;
+pub mod webview_info;
 pub mod window_manager;
+pub mod workers;
+pub mod zoom;
 
 // Re-export command handler functions
+pub use analytics::{handle_capture_analytics, handle_get_analytics_events, handle_clear_analytics_events};
+pub use animations::handle_set_animations_paused;
+pub use baselines::{handle_save_baseline, handle_compare_baseline};
+pub use battery_connection::{handle_set_battery, handle_set_connection};
+pub use canvas::handle_capture_canvas;
+pub use capture_overhead::handle_get_capture_overhead;
+pub use close_request::handle_simulate_close_request;
+pub use computed_style::handle_diff_computed_style;
 pub use console_logs::{handle_get_console_logs, handle_inject_console_capture};
+pub use contenteditable::{handle_get_contenteditable, handle_set_contenteditable, handle_set_design_mode};
+pub use contrast::handle_check_contrast;
+pub use csp::handle_get_csp;
+pub use css_coverage::handle_get_css_coverage;
+pub use custom_elements::handle_get_custom_elements;
+pub use detached_nodes::handle_find_detached_nodes;
+pub use devices::{handle_enumerate_devices, handle_get_permissions_state};
 pub use devtools_bridge::handle_devtools_bridge;
-pub use error_tracker::{handle_get_exceptions, handle_inject_error_tracker, handle_clear_exceptions};
-pub use execute_js::handle_execute_js;
+pub use dialogs::handle_next_dialog;
+pub use document::{handle_get_document_title, handle_set_document_title, handle_get_favicon, handle_get_metadata};
+pub use dom_stats::handle_get_dom_stats;
+pub use drag_reorder::handle_reorder_item;
+pub use element_text::handle_get_text;
+pub use error_tracker::{handle_get_exceptions, handle_inject_error_tracker, handle_clear_exceptions, handle_start_exception_stream, handle_stop_exception_stream};
+pub use execute_js::{handle_execute_js, handle_execute_js_file};
+pub use fixture::{handle_capture_fixture, handle_assert_fixture};
+pub use fonts::handle_get_font_info;
+pub use gestures::handle_simulate_gesture;
 pub use health_check::handle_health_check;
 pub use hot_reload::handle_hot_reload;
-pub use local_storage::handle_get_local_storage;
+pub use hover::{handle_simulate_hover, handle_unhover};
+pub use images::handle_get_images;
+pub use init_timing::handle_get_init_timing;
+pub use interaction_trace::handle_get_interaction_trace;
+pub use intl_locale::{handle_get_intl_locale, handle_set_intl_locale};
+pub use journal::handle_get_mutation_journal;
+pub use latency::{handle_measure_input_latency, handle_measure_action_fps};
+pub use layout::{handle_get_box_model, handle_element_relations, handle_get_layout_shifts, handle_get_positioned_elements, handle_element_from_point, handle_get_stacking_contexts, handle_hit_test, handle_get_interaction_blockers, handle_get_compositing_layers};
+pub use layout_overlay::handle_toggle_layout_overlay;
+pub use lazy_load::handle_trigger_lazy_load;
+pub use live_regions::{handle_capture_live_regions, handle_get_announcements};
+pub use local_storage::{handle_get_local_storage, handle_get_local_storage_map, handle_set_local_storage_map};
+pub use menu::{handle_get_menu, handle_trigger_menu_item};
+pub use monitors::{handle_get_monitors, handle_screenshot_monitor};
 pub use mouse_movement::handle_simulate_mouse_movement;
+pub use navigation::{handle_navigate_history, handle_get_scroll_restoration, handle_set_scroll_restoration};
 pub use network_inspector::{handle_network_inspector, handle_inject_network_capture};
-pub use performance::handle_get_performance_metrics;
+pub use observers::{handle_get_observers_for, handle_trigger_intersection};
+pub use outline::handle_get_semantic_outline;
+pub use performance::{handle_get_performance_metrics, handle_get_loaded_scripts, handle_clear_performance_entries, handle_get_script_timing};
 pub use ping::handle_ping;
-pub use state_dump::handle_state_dump;
-pub use storage_inspector::handle_get_storage_inspector;
+pub use pixel_color::handle_get_pixel_color;
+pub use postmessage::{handle_capture_postmessage, handle_get_postmessages, handle_clear_postmessages};
+pub use reflows::handle_measure_reflows;
+pub use rerender::handle_force_rerender;
+pub use route::handle_get_route;
+pub use scroll_behavior::{handle_get_scroll_behavior, handle_set_scroll_behavior, handle_set_scroll_position};
+pub use scroll_snap::handle_get_scroll_snap_state;
+pub use select_state::{handle_get_select_state, handle_select_option};
+pub use selection::{handle_get_selection, handle_set_selection};
+pub use service_workers::{handle_get_service_workers, handle_unregister_service_worker};
+pub use shadow_dom::handle_get_shadow_dom;
+pub use shortcuts::{handle_list_shortcuts, handle_trigger_shortcut};
+pub use state_dump::{handle_diff_state, handle_state_dump};
+pub use storage_inspector::{handle_get_storage_inspector, handle_get_cookies};
+pub use storage_purge::handle_purge_all_storage;
+pub use stylesheets::handle_get_stylesheet;
+pub use tab_order::handle_get_tab_order;
 pub use take_screenshot::handle_take_screenshot;
 pub use text_input::handle_simulate_text_input;
+pub use tray::handle_tray;
+pub use user_scripts::{handle_register_user_script, handle_unregister_user_script, handle_list_user_scripts};
 pub use webview::{handle_get_dom, handle_get_element_position, handle_send_text_to_element};
-pub use window_manager::handle_manage_window;
+pub use webview_info::handle_get_webview_info;
+pub use window_manager::{handle_manage_window, handle_export_window_layout, handle_import_window_layout};
+pub use workers::handle_get_workers;
+pub use zoom::{handle_get_zoom, handle_set_zoom};
 
 /// Handle command routing for socket requests
 pub async fn handle_command<R: Runtime>(
@@ -57,6 +180,10 @@ pub async fn handle_command<R: Runtime>(
             .unwrap_or_else(|_| "[failed to serialize]".to_string())
     );
 
+    // Keep mutating commands for the journal when recording is on
+    let journal_payload = journal::journal_payload(app, command, &payload);
+    let started = std::time::Instant::now();
+
     let result = match command {
         commands::PING => handle_ping(app, payload),
         commands::TAKE_SCREENSHOT => handle_take_screenshot(app, payload).await,
@@ -74,6 +201,7 @@ pub async fn handle_command<R: Runtime>(
         commands::NETWORK_INSPECTOR => handle_network_inspector(app, payload).await,
         commands::INJECT_NETWORK_CAPTURE => handle_inject_network_capture(app, payload).await,
         commands::STATE_DUMP => handle_state_dump(app, payload).await,
+        commands::DIFF_STATE => handle_diff_state(app, payload).await,
         commands::DEVTOOLS_BRIDGE => handle_devtools_bridge(app, payload).await,
         commands::GET_EXCEPTIONS => handle_get_exceptions(app, payload).await,
         commands::INJECT_ERROR_TRACKER => handle_inject_error_tracker(app, payload).await,
@@ -81,6 +209,115 @@ pub async fn handle_command<R: Runtime>(
         commands::GET_PERFORMANCE_METRICS => handle_get_performance_metrics(app, payload).await,
         commands::STORAGE_INSPECTOR => handle_get_storage_inspector(app, payload).await,
         commands::HEALTH_CHECK => handle_health_check(app, payload),
+        commands::GET_BOX_MODEL => handle_get_box_model(app, payload).await,
+        commands::GET_ZOOM => handle_get_zoom(app, payload).await,
+        commands::SET_ZOOM => handle_set_zoom(app, payload).await,
+        commands::GET_FONT_INFO => handle_get_font_info(app, payload).await,
+        commands::TRAY => handle_tray(app, payload).await,
+        commands::GET_MONITORS => handle_get_monitors(app, payload),
+        commands::SCREENSHOT_MONITOR => handle_screenshot_monitor(app, payload).await,
+        commands::HANDLE_NEXT_DIALOG => handle_next_dialog(app, payload).await,
+        commands::GET_SERVICE_WORKERS => handle_get_service_workers(app, payload).await,
+        commands::UNREGISTER_SERVICE_WORKER => handle_unregister_service_worker(app, payload).await,
+        commands::ELEMENT_RELATIONS => handle_element_relations(app, payload).await,
+        commands::CAPTURE_CANVAS => handle_capture_canvas(app, payload).await,
+        commands::GET_CSP => handle_get_csp(app, payload).await,
+        commands::GET_SEMANTIC_OUTLINE => handle_get_semantic_outline(app, payload).await,
+        commands::SIMULATE_HOVER => handle_simulate_hover(app, payload).await,
+        commands::UNHOVER => handle_unhover(app, payload).await,
+        commands::GET_LAYOUT_SHIFTS => handle_get_layout_shifts(app, payload).await,
+        commands::GET_LOADED_SCRIPTS => handle_get_loaded_scripts(app, payload).await,
+        commands::GET_LOCAL_STORAGE_MAP => handle_get_local_storage_map(app, payload).await,
+        commands::SET_LOCAL_STORAGE_MAP => handle_set_local_storage_map(app, payload).await,
+        commands::GET_WEBVIEW_INFO => handle_get_webview_info(app, payload).await,
+        commands::NAVIGATE_HISTORY => handle_navigate_history(app, payload).await,
+        commands::GET_TEXT => handle_get_text(app, payload).await,
+        commands::EXECUTE_JS_FILE => handle_execute_js_file(app, payload).await,
+        commands::MEASURE_REFLOWS => handle_measure_reflows(app, payload).await,
+        commands::GET_DOCUMENT_TITLE => handle_get_document_title(app, payload).await,
+        commands::SET_DOCUMENT_TITLE => handle_set_document_title(app, payload).await,
+        commands::GET_FAVICON => handle_get_favicon(app, payload).await,
+        commands::LIST_SHORTCUTS => handle_list_shortcuts(app, payload).await,
+        commands::TRIGGER_SHORTCUT => handle_trigger_shortcut(app, payload).await,
+        commands::DIFF_COMPUTED_STYLE => handle_diff_computed_style(app, payload).await,
+        commands::SET_ANIMATIONS_PAUSED => handle_set_animations_paused(app, payload).await,
+        commands::GET_CONTENTEDITABLE => handle_get_contenteditable(app, payload).await,
+        commands::SET_CONTENTEDITABLE => handle_set_contenteditable(app, payload).await,
+        commands::ENUMERATE_DEVICES => handle_enumerate_devices(app, payload).await,
+        commands::GET_PERMISSIONS_STATE => handle_get_permissions_state(app, payload).await,
+        commands::START_EXCEPTION_STREAM => handle_start_exception_stream(app, payload).await,
+        commands::STOP_EXCEPTION_STREAM => handle_stop_exception_stream(app, payload).await,
+        commands::SIMULATE_GESTURE => handle_simulate_gesture(app, payload).await,
+        commands::CLEAR_PERFORMANCE_ENTRIES => handle_clear_performance_entries(app, payload).await,
+        commands::FIND_DETACHED_NODES => handle_find_detached_nodes(app, payload).await,
+        commands::GET_POSITIONED_ELEMENTS => handle_get_positioned_elements(app, payload).await,
+        commands::CHECK_CONTRAST => handle_check_contrast(app, payload).await,
+        commands::GET_SELECTION => handle_get_selection(app, payload).await,
+        commands::SET_SELECTION => handle_set_selection(app, payload).await,
+        commands::GET_MUTATION_JOURNAL => handle_get_mutation_journal(app, payload).await,
+        commands::ELEMENT_FROM_POINT => handle_element_from_point(app, payload).await,
+        commands::MEASURE_INPUT_LATENCY => handle_measure_input_latency(app, payload).await,
+        commands::GET_IMAGES => handle_get_images(app, payload).await,
+        commands::FORCE_RERENDER => handle_force_rerender(app, payload).await,
+        commands::EXPORT_WINDOW_LAYOUT => handle_export_window_layout(app, payload),
+        commands::IMPORT_WINDOW_LAYOUT => handle_import_window_layout(app, payload).await,
+        commands::CAPTURE_POSTMESSAGE => handle_capture_postmessage(app, payload).await,
+        commands::GET_POSTMESSAGES => handle_get_postmessages(app, payload).await,
+        commands::CLEAR_POSTMESSAGES => handle_clear_postmessages(app, payload).await,
+        commands::SAVE_BASELINE => handle_save_baseline(app, payload).await,
+        commands::COMPARE_BASELINE => handle_compare_baseline(app, payload).await,
+        commands::GET_WORKERS => handle_get_workers(app, payload).await,
+        commands::GET_CSS_COVERAGE => handle_get_css_coverage(app, payload).await,
+        commands::TRIGGER_LAZY_LOAD => handle_trigger_lazy_load(app, payload).await,
+        commands::GET_METADATA => handle_get_metadata(app, payload).await,
+        commands::GET_MENU => handle_get_menu(app, payload).await,
+        commands::TRIGGER_MENU_ITEM => handle_trigger_menu_item(app, payload).await,
+        commands::GET_STACKING_CONTEXTS => handle_get_stacking_contexts(app, payload).await,
+        commands::SET_BATTERY => handle_set_battery(app, payload).await,
+        commands::SET_CONNECTION => handle_set_connection(app, payload).await,
+        commands::GET_DOM_STATS => handle_get_dom_stats(app, payload).await,
+        commands::GET_ROUTE => handle_get_route(app, payload).await,
+        commands::REGISTER_USER_SCRIPT => handle_register_user_script(app, payload).await,
+        commands::UNREGISTER_USER_SCRIPT => handle_unregister_user_script(app, payload).await,
+        commands::LIST_USER_SCRIPTS => handle_list_user_scripts(app, payload).await,
+        commands::GET_PIXEL_COLOR => handle_get_pixel_color(app, payload).await,
+        commands::SET_DESIGN_MODE => handle_set_design_mode(app, payload).await,
+        commands::MEASURE_ACTION_FPS => handle_measure_action_fps(app, payload).await,
+        commands::GET_CUSTOM_ELEMENTS => handle_get_custom_elements(app, payload).await,
+        commands::GET_SCROLL_SNAP_STATE => handle_get_scroll_snap_state(app, payload).await,
+        commands::GET_TAB_ORDER => handle_get_tab_order(app, payload).await,
+        commands::GET_OBSERVERS_FOR => handle_get_observers_for(app, payload).await,
+        commands::SIMULATE_CLOSE_REQUEST => handle_simulate_close_request(app, payload).await,
+        commands::CAPTURE_LIVE_REGIONS => handle_capture_live_regions(app, payload).await,
+        commands::GET_ANNOUNCEMENTS => handle_get_announcements(app, payload).await,
+        commands::GET_SHADOW_DOM => handle_get_shadow_dom(app, payload).await,
+        commands::GET_INTERACTION_TRACE => handle_get_interaction_trace(app, payload).await,
+        commands::HIT_TEST => handle_hit_test(app, payload).await,
+        commands::GET_STYLESHEET => handle_get_stylesheet(app, payload).await,
+        commands::GET_SCRIPT_TIMING => handle_get_script_timing(app, payload).await,
+        commands::TOGGLE_LAYOUT_OVERLAY => handle_toggle_layout_overlay(app, payload).await,
+        commands::GET_SCROLL_RESTORATION => handle_get_scroll_restoration(app, payload).await,
+        commands::SET_SCROLL_RESTORATION => handle_set_scroll_restoration(app, payload).await,
+        commands::PURGE_ALL_STORAGE => handle_purge_all_storage(app, payload).await,
+        commands::GET_CAPTURE_OVERHEAD => handle_get_capture_overhead(app, payload).await,
+        commands::TRIGGER_INTERSECTION => handle_trigger_intersection(app, payload).await,
+        commands::GET_SCROLL_BEHAVIOR => handle_get_scroll_behavior(app, payload).await,
+        commands::SET_SCROLL_BEHAVIOR => handle_set_scroll_behavior(app, payload).await,
+        commands::SET_SCROLL_POSITION => handle_set_scroll_position(app, payload).await,
+        commands::GET_INIT_TIMING => handle_get_init_timing(app, payload),
+        commands::GET_INTERACTION_BLOCKERS => handle_get_interaction_blockers(app, payload).await,
+        commands::CAPTURE_ANALYTICS => handle_capture_analytics(app, payload).await,
+        commands::GET_ANALYTICS_EVENTS => handle_get_analytics_events(app, payload).await,
+        commands::CLEAR_ANALYTICS_EVENTS => handle_clear_analytics_events(app, payload).await,
+        commands::GET_INTL_LOCALE => handle_get_intl_locale(app, payload).await,
+        commands::SET_INTL_LOCALE => handle_set_intl_locale(app, payload).await,
+        commands::GET_COMPOSITING_LAYERS => handle_get_compositing_layers(app, payload).await,
+        commands::REORDER_ITEM => handle_reorder_item(app, payload).await,
+        commands::GET_SELECT_STATE => handle_get_select_state(app, payload).await,
+        commands::SELECT_OPTION => handle_select_option(app, payload).await,
+        commands::CAPTURE_FIXTURE => handle_capture_fixture(app, payload).await,
+        commands::ASSERT_FIXTURE => handle_assert_fixture(app, payload).await,
+        commands::GET_COOKIES => handle_get_cookies(app, payload).await,
         _ => Ok(SocketResponse {
             success: false,
             data: None,
@@ -88,6 +325,10 @@ pub async fn handle_command<R: Runtime>(
         }),
     };
 
+    if let Some(journal_payload) = journal_payload {
+        journal::record_mutation(app, command, journal_payload, &result, started.elapsed());
+    }
+
     // Log the response before returning it
     if let Ok(ref response) = result {
         let success_str = if response.success {
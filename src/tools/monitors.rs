@@ -0,0 +1,177 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Monitor, Runtime};
+use log::info;
+
+use crate::error::Error;
+use crate::shared::ScreenshotParams;
+use crate::socket_server::SocketResponse;
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+/// Whether this build can capture a whole monitor
+const MONITOR_CAPTURE_SUPPORTED: bool = cfg!(any(target_os = "macos", target_os = "windows", target_os = "linux"));
+
+/// Physical placement of the monitor to capture, resolved through Tauri
+#[derive(Debug, Clone)]
+pub struct MonitorTarget {
+    pub name: Option<String>,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub scale_factor: f64,
+    pub is_primary: bool,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ScreenshotMonitorRequest {
+    /// Identifier from `get_monitors`; defaults to the primary monitor
+    monitor_id: Option<String>,
+    quality: Option<i32>,
+    max_width: Option<i32>,
+    max_size_mb: Option<f32>,
+}
+
+/// Stable identifier for a monitor: its name when the platform reports one, otherwise its index
+fn monitor_id(monitor: &Monitor, index: usize) -> String {
+    monitor
+        .name()
+        .cloned()
+        .unwrap_or_else(|| index.to_string())
+}
+
+fn is_same_monitor(a: &Monitor, b: &Monitor) -> bool {
+    a.position() == b.position() && a.size() == b.size()
+}
+
+fn list_monitors<R: Runtime>(app: &AppHandle<R>) -> Result<(Vec<Monitor>, Option<Monitor>), Error> {
+    let monitors = app
+        .available_monitors()
+        .map_err(|e| Error::window_operation_failed("list monitors", e.to_string()))?;
+    let primary = app.primary_monitor().ok().flatten();
+    Ok((monitors, primary))
+}
+
+/// Handler function for listing the connected monitors
+pub fn handle_get_monitors<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_monitors", &payload).finish()?;
+
+    let (monitors, primary) = list_monitors(app)?;
+
+    let monitors: Vec<Value> = monitors
+        .iter()
+        .enumerate()
+        .map(|(index, monitor)| {
+            json!({
+                "id": monitor_id(monitor, index),
+                "name": monitor.name(),
+                "x": monitor.position().x,
+                "y": monitor.position().y,
+                "width": monitor.size().width,
+                "height": monitor.size().height,
+                "scale_factor": monitor.scale_factor(),
+                "is_primary": primary.as_ref().map(|p| is_same_monitor(p, monitor)).unwrap_or(false),
+            })
+        })
+        .collect();
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(json!({
+            "monitors": monitors,
+            "capture_supported": MONITOR_CAPTURE_SUPPORTED,
+        })),
+        error: None,
+    })
+}
+
+/// Handler function for capturing a full monitor rather than a window
+pub async fn handle_screenshot_monitor<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("screenshot_monitor", &payload)
+        .optional("monitor_id", FieldKind::String)
+        .optional("quality", FieldKind::Integer { min: Some(1), max: Some(100) })
+        .optional("max_width", FieldKind::Integer { min: Some(1), max: None })
+        .optional("max_size_mb", FieldKind::Number { min: Some(0.0), max: None })
+        .finish()?;
+
+    let request: ScreenshotMonitorRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for screenshot_monitor: {}", e)))?;
+
+    if !MONITOR_CAPTURE_SUPPORTED {
+        return Ok(SocketResponse {
+            success: false,
+            data: Some(json!({ "capture_supported": false })),
+            error: Some("Monitor capture is not supported on this platform".to_string()),
+        });
+    }
+
+    let (monitors, primary) = list_monitors(app)?;
+
+    let monitor = match &request.monitor_id {
+        Some(id) => monitors
+            .iter()
+            .enumerate()
+            .find(|(index, monitor)| monitor_id(monitor, *index) == *id)
+            .map(|(_, monitor)| monitor.clone())
+            .ok_or_else(|| {
+                let known: Vec<String> = monitors
+                    .iter()
+                    .enumerate()
+                    .map(|(index, monitor)| monitor_id(monitor, index))
+                    .collect();
+                Error::invalid_parameter("monitor_id", format!("one of {}", known.join("|")), id)
+            })?,
+        None => primary
+            .clone()
+            .or_else(|| monitors.first().cloned())
+            .ok_or_else(|| Error::window_operation_failed("screenshot monitor", "No monitors available"))?,
+    };
+
+    let target = MonitorTarget {
+        name: monitor.name().cloned(),
+        x: monitor.position().x,
+        y: monitor.position().y,
+        width: monitor.size().width,
+        height: monitor.size().height,
+        scale_factor: monitor.scale_factor(),
+        is_primary: primary.as_ref().map(|p| is_same_monitor(p, &monitor)).unwrap_or(false),
+    };
+
+    info!(
+        "[TAURI_MCP] Capturing monitor {:?} at ({}, {})",
+        target.name, target.x, target.y
+    );
+
+    let params = ScreenshotParams {
+        window_label: None,
+        quality: request.quality.map(|q| q as u8),
+        max_width: request.max_width,
+        max_size_mb: request.max_size_mb,
+        application_name: None,
+        target_dpr: None,
+        source_dpr: Some(target.scale_factor),
+        lossless: false,
+        region: None,
+        format: None,
+    };
+
+    let response = crate::platform::current::capture_monitor(params, target.clone()).await?;
+
+    Ok(SocketResponse {
+        success: response.success,
+        data: Some(json!({
+            "monitor_id": request.monitor_id,
+            "name": target.name,
+            "width": target.width,
+            "height": target.height,
+            "is_primary": target.is_primary,
+            "data": response.data,
+        })),
+        error: response.error,
+    })
+}
@@ -5,6 +5,7 @@ use crate::error::Error;
 use crate::models::MouseMovementRequest;
 use crate::shared::{MouseMovementParams, MouseMovementResult};
 use crate::socket_server::SocketResponse;
+use crate::tools::validation::{FieldKind, PayloadValidator};
 use enigo::{Button, Coordinate, Direction, Enigo, Mouse, Settings};
 use std::time::Instant;
 use log::info;
@@ -203,6 +204,14 @@ pub async fn handle_simulate_mouse_movement<R: Runtime>(
     app: &AppHandle<R>,
     payload: Value,
 ) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("simulateMouseMovement", &payload)
+        .required("x", FieldKind::INT)
+        .required("y", FieldKind::INT)
+        .optional("relative", FieldKind::Bool)
+        .optional("click", FieldKind::Bool)
+        .optional("button", FieldKind::OneOf(&["left", "right", "middle"]))
+        .finish()?;
+
     // Parse the payload
     let params: MouseMovementRequest = serde_json::from_value(payload)
         .map_err(|e| Error::serialization_error(format!("Invalid payload for simulateMouseMovement: {}", e)))?;
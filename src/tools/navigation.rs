@@ -0,0 +1,208 @@
+use serde_json::{json, Value};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager, Runtime};
+use log::info;
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct NavigateHistoryRequest {
+    window_label: Option<String>,
+    /// "back" or "forward"
+    direction: String,
+    /// Number of history entries to move (default 1)
+    steps: Option<u32>,
+    /// Substring the resulting URL is expected to contain
+    expect_url_contains: Option<String>,
+    /// How long to wait for the URL to change (default 3000)
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ScrollRestorationRequest {
+    window_label: Option<String>,
+    /// "auto" or "manual"; only for `set_scroll_restoration`
+    mode: Option<String>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for moving through the webview history and reporting where it landed
+pub async fn handle_navigate_history<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("navigate_history", &payload)
+        .required("direction", FieldKind::OneOf(&["back", "forward"]))
+        .optional("steps", FieldKind::Integer { min: Some(1), max: Some(100) })
+        .optional("expect_url_contains", FieldKind::String)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: NavigateHistoryRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for navigate_history: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let window = app
+        .get_webview_window(&window_label)
+        .ok_or_else(|| Error::window_not_found(&window_label))?;
+
+    let steps = request.steps.unwrap_or(1) as i64;
+    let delta = if request.direction == "back" { -steps } else { steps };
+
+    let read_url = || {
+        window
+            .url()
+            .map(|url| url.to_string())
+            .map_err(|e| Error::window_operation_failed("read URL", e.to_string()))
+    };
+
+    let url_before = read_url()?;
+    info!("[TAURI_MCP] history.go({}) from {}", delta, url_before);
+
+    // The native URL is read on our side, so full page loads (which tear down
+    // any in-page script) are observed just like client-side route changes
+    window
+        .eval(&format!("history.go({})", delta))
+        .map_err(|e| Error::window_operation_failed("navigate history", e.to_string()))?;
+
+    // Poll on a blocking thread so the wait does not stall the async runtime
+    let timeout_ms = request.timeout_ms.unwrap_or(3000);
+    let started = Instant::now();
+    let poll_window = window.clone();
+    let poll_url_before = url_before.clone();
+    let url_after = tauri::async_runtime::spawn_blocking(move || {
+        let mut url_after = poll_url_before.clone();
+        while started.elapsed() < Duration::from_millis(timeout_ms) {
+            std::thread::sleep(Duration::from_millis(50));
+            url_after = poll_window.url().map_err(|e| e.to_string())?.to_string();
+            if url_after != poll_url_before {
+                break;
+            }
+        }
+        Ok::<_, String>(url_after)
+    })
+    .await
+    .map_err(|e| e.to_string())
+    .and_then(|url| url)
+    .map_err(|e| Error::window_operation_failed("read URL", e))?;
+
+    let changed = url_after != url_before;
+    let matches_expected = request
+        .expect_url_contains
+        .as_ref()
+        .map(|expected| url_after.contains(expected.as_str()));
+    let success = changed && matches_expected.unwrap_or(true);
+
+    let error = if !changed {
+        Some(format!(
+            "URL did not change within {}ms after going {} {} step(s); there may be no history entry in that direction",
+            timeout_ms, request.direction, steps
+        ))
+    } else if matches_expected == Some(false) {
+        Some(format!(
+            "Navigated to {} which does not contain '{}'",
+            url_after,
+            request.expect_url_contains.clone().unwrap_or_default()
+        ))
+    } else {
+        None
+    };
+
+    Ok(SocketResponse {
+        success,
+        data: Some(json!({
+            "direction": request.direction,
+            "steps": steps,
+            "url_before": url_before,
+            "url_after": url_after,
+            "changed": changed,
+            "matches_expected": matches_expected,
+            "elapsed_ms": started.elapsed().as_millis() as u64,
+        })),
+        error,
+    })
+}
+
+/// Handler function for reading `history.scrollRestoration`
+pub async fn handle_get_scroll_restoration<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_scroll_restoration", &payload)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    scroll_restoration(app, "get_scroll_restoration", payload).await
+}
+
+/// Handler function for switching `history.scrollRestoration` between auto and manual
+pub async fn handle_set_scroll_restoration<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("set_scroll_restoration", &payload)
+        .required("mode", FieldKind::OneOf(&["auto", "manual"]))
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    scroll_restoration(app, "set_scroll_restoration", payload).await
+}
+
+async fn scroll_restoration<R: Runtime>(
+    app: &AppHandle<R>,
+    command: &str,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    let request: ScrollRestorationRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for {}: {}", command, e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Scroll restoration error",
+        &json!({ "mode": request.mode }),
+        SCROLL_RESTORATION_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "scroll restoration",
+    )
+    .await
+}
+
+/// The mode belongs to the current history entry: entries added with `pushState` copy
+/// it, while a full page load starts again at "auto".
+const SCROLL_RESTORATION_JS: &str = r#"        const supported = 'scrollRestoration' in history;
+        const before = supported ? history.scrollRestoration : null;
+        if (args.mode !== null) {
+            if (!supported) throw new Error('history.scrollRestoration is not supported in this webview');
+            history.scrollRestoration = args.mode;
+        }
+        return {
+            supported,
+            mode: supported ? history.scrollRestoration : null,
+            previous_mode: args.mode !== null ? before : undefined,
+            changed: args.mode !== null ? before !== history.scrollRestoration : undefined,
+            url: location.href,
+            history_length: history.length,
+            scroll: { x: window.scrollX, y: window.scrollY }
+        };"#;
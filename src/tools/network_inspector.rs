@@ -2,11 +2,14 @@ use serde::{Serialize, Serializer};
 use serde_json::Value;
 use std::fmt;
 use std::sync::mpsc;
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tauri::{AppHandle, Emitter, Listener, Manager, Runtime};
 
 use crate::error::Error;
 use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator, EVICTION_POLICIES};
+use crate::TauriMcpExt;
 
 // HTTP method enumeration
 #[allow(dead_code)]
@@ -43,23 +46,31 @@ impl HttpMethod {
 }
 
 // Request type enumeration
-#[allow(dead_code)]
 #[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase")]
 pub enum RequestType {
     Fetch,
     Xhr,
+    WebSocket,
 }
 
-#[allow(dead_code)]
 impl RequestType {
     pub fn from_str(s: &str) -> Option<Self> {
         match s.to_lowercase().as_str() {
             "fetch" => Some(RequestType::Fetch),
             "xhr" => Some(RequestType::Xhr),
+            "websocket" => Some(RequestType::WebSocket),
             _ => None,
         }
     }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RequestType::Fetch => "fetch",
+            RequestType::Xhr => "xhr",
+            RequestType::WebSocket => "websocket",
+        }
+    }
 }
 
 // Define a custom error type for network inspector operations
@@ -115,7 +126,7 @@ pub struct NetworkRequest {
     pub id: String,
     pub url: String,
     pub method: String,
-    pub request_type: String, // "fetch" or "xhr"
+    pub request_type: String, // "fetch", "xhr" or "websocket"
     pub status_code: Option<u16>,
     pub request_headers: std::collections::HashMap<String, String>,
     pub response_headers: std::collections::HashMap<String, String>,
@@ -125,10 +136,39 @@ pub struct NetworkRequest {
     pub start_time_ms: u64,
     pub end_time_ms: Option<u64>,
     pub duration_ms: Option<u64>,
+    /// When the WebSocket handshake completed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open_time_ms: Option<u64>,
+    /// Subprotocol the server selected for a WebSocket
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+    /// WebSocket close code and reason, once closed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub close_code: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub close_reason: Option<String>,
+    /// Most recent WebSocket frames, oldest first; older ones are dropped past the cap
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frames: Option<Vec<WebSocketFrame>>,
+    /// Frames seen on the connection, including dropped ones
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frame_count: Option<u64>,
+}
+
+// Single WebSocket message
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct WebSocketFrame {
+    pub direction: String, // "sent" or "received"
+    pub opcode: String, // "text" or "binary"
+    pub size_bytes: u64,
+    pub time_ms: u64,
+    /// Text payload, when capture of payloads is enabled and the frame is under the limit
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload: Option<String>,
 }
 
 // Request filter for querying
-#[derive(Debug, Clone, serde::Deserialize)]
+#[derive(Debug, Clone, Default, serde::Deserialize)]
 pub struct NetworkRequestFilter {
     pub url_pattern: Option<String>,
     pub method: Option<String>,
@@ -139,16 +179,72 @@ pub struct NetworkRequestFilter {
     pub start_time_ms: Option<u64>,
     pub end_time_ms: Option<u64>,
     pub limit: Option<usize>,
+    /// Only return requests the previous `since_last_poll` query for this window did not,
+    /// oldest first, so a limited query never skips any
+    pub since_last_poll: Option<bool>,
+    /// Truncate request and response bodies and WebSocket frame payloads to this many bytes (default: no truncation)
+    pub max_body_bytes: Option<usize>,
+    /// Header names, case-insensitive, whose values are replaced with "[REDACTED]" (default: none)
+    pub redact_headers: Option<Vec<String>>,
+}
+
+/// Where a window's `since_last_poll` queries continue: the start time of the newest
+/// request returned so far and the ids of those returned that started in that millisecond
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NetworkPollCursor {
+    pub time_ms: u64,
+    pub ids: Vec<String>,
+}
+
+impl NetworkPollCursor {
+    /// Cursor after a `since_last_poll` query returned `requests`; it stays put when none came back
+    pub fn advance(previous: Option<NetworkPollCursor>, requests: &[NetworkRequest]) -> Option<NetworkPollCursor> {
+        let Some(newest) = requests.iter().map(|r| r.start_time_ms).max() else {
+            return previous;
+        };
+        let mut cursor = match previous {
+            Some(previous) if previous.time_ms == newest => previous,
+            _ => NetworkPollCursor { time_ms: newest, ids: Vec::new() },
+        };
+        for request in requests.iter().filter(|r| r.start_time_ms == newest) {
+            if !cursor.ids.contains(&request.id) {
+                cursor.ids.push(request.id.clone());
+            }
+        }
+        Some(cursor)
+    }
 }
 
 // Request model for network inspection
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct NetworkInspectorRequest {
     pub window_label: Option<String>,
-    pub action: String, // "get_requests", "clear_requests", "start_capture", "stop_capture"
+    pub action: String, // "get_requests", "clear_requests", "start_capture", "stop_capture", "set_default_headers", "clear_default_headers", "get_header_injections", "fail_resource_type", "clear_resource_failures", "get_resource_failures", "export_har"
     pub filter: Option<NetworkRequestFilter>,
+    /// Headers added to outgoing requests by `set_default_headers`
+    pub headers: Option<std::collections::HashMap<String, String>>,
+    /// Resource types made to fail by `fail_resource_type`
+    pub resource_types: Option<Vec<String>>,
 }
 
+/// Actions `network_inspector` accepts
+const NETWORK_INSPECTOR_ACTIONS: &[&str] = &[
+    "get_requests",
+    "clear_requests",
+    "start_capture",
+    "stop_capture",
+    "set_default_headers",
+    "clear_default_headers",
+    "get_header_injections",
+    "fail_resource_type",
+    "clear_resource_failures",
+    "get_resource_failures",
+    "export_har",
+];
+
+/// Resource types `fail_resource_type` can make fail
+const FAILABLE_RESOURCE_TYPES: &[&str] = &["image", "script", "fetch", "xhr", "stylesheet"];
+
 // Response model for network requests
 #[derive(Debug, serde::Serialize)]
 pub struct NetworkInspectorResponse {
@@ -156,6 +252,16 @@ pub struct NetworkInspectorResponse {
     pub total_count: usize,
     pub returned_count: usize,
     pub capture_active: bool,
+    /// Start time (Unix ms) of the newest request returned by `since_last_poll` queries so far,
+    /// usable as `start_time_ms` for an explicit delta (which repeats requests from that millisecond)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<u64>,
+    /// Eviction policy of the capture buffer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eviction_policy: Option<String>,
+    /// Entries evicted from the capture buffer so far
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evicted_count: Option<u64>,
 }
 
 /// Handler function for network inspection
@@ -163,7 +269,29 @@ pub async fn handle_network_inspector<R: Runtime>(
     app: &AppHandle<R>,
     payload: Value,
 ) -> Result<SocketResponse, Error> {
-    let request: NetworkInspectorRequest = serde_json::from_value(payload)
+    PayloadValidator::new("network_inspector", &payload)
+        .required("action", FieldKind::OneOf(NETWORK_INSPECTOR_ACTIONS))
+        .optional("window_label", FieldKind::String)
+        .optional("headers", FieldKind::Object)
+        .optional("resource_types", FieldKind::Array)
+        .nested("filter", |filter| {
+            filter
+                .optional("url_pattern", FieldKind::String)
+                .optional("method", FieldKind::String)
+                .optional("status_code", FieldKind::Integer { min: Some(100), max: Some(599) })
+                .optional("min_duration_ms", FieldKind::UINT)
+                .optional("max_duration_ms", FieldKind::UINT)
+                .optional("request_type", FieldKind::String)
+                .optional("start_time_ms", FieldKind::UINT)
+                .optional("end_time_ms", FieldKind::UINT)
+                .optional("limit", FieldKind::UINT)
+                .optional("since_last_poll", FieldKind::Bool)
+                .optional("max_body_bytes", FieldKind::UINT)
+                .optional("redact_headers", FieldKind::Array)
+        })
+        .finish()?;
+
+    let mut request: NetworkInspectorRequest = serde_json::from_value(payload)
         .map_err(|e| Error::serialization_error(format!("Invalid payload for network inspector: {}", e)))?;
 
     // Get the window label or use "main" as default
@@ -177,9 +305,39 @@ pub async fn handle_network_inspector<R: Runtime>(
         .get_webview_window(&window_label)
         .ok_or_else(|| Error::window_not_found(&window_label))?;
 
+    // Normalize the request type filter, which the page compares exactly
+    if let Some(filter) = request.filter.as_mut() {
+        if let Some(request_type) = filter.request_type.as_deref() {
+            let parsed = RequestType::from_str(request_type).ok_or_else(|| {
+                Error::invalid_parameter("filter.request_type", "one of fetch, xhr, websocket", request_type)
+            })?;
+            filter.request_type = Some(parsed.as_str().to_string());
+        }
+    }
+
+    // Header injection runs as its own page script, independent of the capture buffer
+    if matches!(
+        request.action.as_str(),
+        "set_default_headers" | "clear_default_headers" | "get_header_injections"
+    ) {
+        return default_headers(app, request, &window_label).await;
+    }
+
+    // Resource failures are injected the same way
+    if matches!(
+        request.action.as_str(),
+        "fail_resource_type" | "clear_resource_failures" | "get_resource_failures"
+    ) {
+        return resource_failures(app, request, &window_label).await;
+    }
+
+    if request.action == "export_har" {
+        return export_har(app, request, &window_label).await;
+    }
+
     // Handle different actions
     let result = match request.action.as_str() {
-        "get_requests" => retrieve_network_requests(app.clone(), request).await,
+        "get_requests" => retrieve_network_requests(app.clone(), request, true).await,
         "clear_requests" => clear_network_requests(app.clone(), request).await,
         "start_capture" => start_network_capture(app.clone(), request).await,
         "stop_capture" => stop_network_capture(app.clone(), request).await,
@@ -209,6 +367,292 @@ pub async fn handle_network_inspector<R: Runtime>(
     }
 }
 
+/// Set, clear or report the headers injected into matching fetch/XHR requests
+async fn default_headers<R: Runtime>(
+    app: &AppHandle<R>,
+    request: NetworkInspectorRequest,
+    window_label: &str,
+) -> Result<SocketResponse, Error> {
+    if request.action == "set_default_headers" && request.headers.as_ref().is_none_or(|h| h.is_empty()) {
+        return Err(Error::invalid_parameter(
+            "headers",
+            "a non-empty object of header names to values",
+            "nothing",
+        ));
+    }
+
+    let url_pattern = request.filter.as_ref().and_then(|f| f.url_pattern.clone());
+    let js_code = build_probe_script(
+        "Default headers error",
+        &serde_json::json!({
+            "action": request.action,
+            "headers": request.headers,
+            "url_pattern": url_pattern,
+            "limit": request.filter.as_ref().and_then(|f| f.limit).unwrap_or(100),
+        }),
+        DEFAULT_HEADERS_JS,
+    );
+
+    run_probe(app, window_label, js_code, DEFAULT_PROBE_TIMEOUT_MS, "default headers").await
+}
+
+/// Add a rule that makes requests of the given resource types fail, clear all rules,
+/// or report the requests failed so far
+async fn resource_failures<R: Runtime>(
+    app: &AppHandle<R>,
+    request: NetworkInspectorRequest,
+    window_label: &str,
+) -> Result<SocketResponse, Error> {
+    if request.action == "fail_resource_type" {
+        let types = request.resource_types.as_deref().unwrap_or_default();
+        if types.is_empty() {
+            return Err(Error::invalid_parameter(
+                "resource_types",
+                format!("a non-empty array of {}", FAILABLE_RESOURCE_TYPES.join(", ")),
+                "nothing",
+            ));
+        }
+        if let Some(unknown) = types.iter().find(|t| !FAILABLE_RESOURCE_TYPES.contains(&t.as_str())) {
+            return Err(Error::invalid_parameter(
+                "resource_types",
+                format!("one of {}", FAILABLE_RESOURCE_TYPES.join(", ")),
+                unknown.clone(),
+            ));
+        }
+    }
+
+    let url_pattern = request.filter.as_ref().and_then(|f| f.url_pattern.clone());
+    let js_code = build_probe_script(
+        "Resource failure error",
+        &serde_json::json!({
+            "action": request.action,
+            "resource_types": request.resource_types,
+            "url_pattern": url_pattern,
+            "limit": request.filter.as_ref().and_then(|f| f.limit).unwrap_or(100),
+        }),
+        RESOURCE_FAILURES_JS,
+    );
+
+    run_probe(app, window_label, js_code, DEFAULT_PROBE_TIMEOUT_MS, "resource failures").await
+}
+
+/// Most entries a HAR export holds, and the default when no limit is given
+const MAX_HAR_ENTRIES: usize = 1000;
+
+/// Export the captured requests as a HAR 1.2 document. The filter applies as for
+/// `get_requests`, but the limit defaults to (and is capped at) `MAX_HAR_ENTRIES`, and
+/// the `since_last_poll` cursor is left where it was.
+async fn export_har<R: Runtime>(
+    app: &AppHandle<R>,
+    mut request: NetworkInspectorRequest,
+    window_label: &str,
+) -> Result<SocketResponse, Error> {
+    let filter = request.filter.get_or_insert_with(NetworkRequestFilter::default);
+    filter.limit = Some(filter.limit.unwrap_or(MAX_HAR_ENTRIES).min(MAX_HAR_ENTRIES));
+
+    let (requests, total_count) = match retrieve_network_requests(app.clone(), request, false).await {
+        Ok(response) => (response.requests, response.total_count),
+        Err(e) => {
+            return Ok(SocketResponse {
+                success: false,
+                data: None,
+                error: Some(e.to_string()),
+            })
+        }
+    };
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(build_har(window_label, &requests, total_count)),
+        error: None,
+    })
+}
+
+/// HAR `{ "log": ... }` document for the given requests, with a single page per window
+fn build_har(window_label: &str, requests: &[NetworkRequest], total_count: usize) -> Value {
+    let mut requests: Vec<&NetworkRequest> = requests.iter().collect();
+    requests.sort_by_key(|r| r.start_time_ms);
+
+    let page_started = requests
+        .first()
+        .map(|r| r.start_time_ms)
+        .unwrap_or_else(|| SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0));
+
+    let entries: Vec<Value> = requests.iter().map(|r| har_entry(window_label, r)).collect();
+
+    let mut har = serde_json::json!({
+        "log": {
+            "version": "1.2",
+            "creator": {
+                "name": "tauri-plugin-mcp",
+                "version": env!("CARGO_PKG_VERSION"),
+            },
+            "pages": [{
+                "startedDateTime": har_timestamp(page_started),
+                "id": window_label,
+                "title": window_label,
+                "pageTimings": { "onContentLoad": -1, "onLoad": -1 },
+            }],
+            "entries": entries,
+        }
+    });
+
+    if total_count > requests.len() {
+        har["log"]["comment"] = Value::String(format!(
+            "Exported the {} most recent of {} matching requests",
+            requests.len(),
+            total_count
+        ));
+    }
+
+    har
+}
+
+fn har_entry(window_label: &str, request: &NetworkRequest) -> Value {
+    let duration = request
+        .duration_ms
+        .or_else(|| request.end_time_ms.map(|end| end.saturating_sub(request.start_time_ms)))
+        .unwrap_or(0);
+    let request_mime = header_value(&request.request_headers, "content-type").unwrap_or_default();
+    let response_mime = header_value(&request.response_headers, "content-type").unwrap_or_default();
+    let response_size = request.response_body.as_ref().map(|b| b.len() as i64);
+
+    let mut har_request = serde_json::json!({
+        "method": request.method,
+        "url": request.url,
+        "httpVersion": "HTTP/1.1",
+        "cookies": [],
+        "headers": har_headers(&request.request_headers),
+        "queryString": har_query_string(&request.url),
+        "headersSize": -1,
+        "bodySize": request.request_body.as_ref().map(|b| b.len() as i64).unwrap_or(0),
+    });
+    if let Some(body) = &request.request_body {
+        har_request["postData"] = serde_json::json!({ "mimeType": request_mime, "text": body });
+    }
+
+    let mut content = serde_json::json!({
+        "size": response_size.unwrap_or(0),
+        "mimeType": response_mime,
+    });
+    if let Some(body) = &request.response_body {
+        content["text"] = Value::String(body.clone());
+    }
+
+    let mut entry = serde_json::json!({
+        "pageref": window_label,
+        "startedDateTime": har_timestamp(request.start_time_ms),
+        "time": duration,
+        "request": har_request,
+        "response": {
+            // HAR uses status 0 for requests that never got a response
+            "status": request.status_code.unwrap_or(0),
+            "statusText": "",
+            "httpVersion": "HTTP/1.1",
+            "cookies": [],
+            "headers": har_headers(&request.response_headers),
+            "content": content,
+            "redirectURL": header_value(&request.response_headers, "location").unwrap_or_default(),
+            "headersSize": -1,
+            "bodySize": response_size.unwrap_or(-1),
+        },
+        "cache": {},
+        // Only the total duration is captured, so it is all attributed to waiting
+        "timings": {
+            "blocked": -1,
+            "dns": -1,
+            "connect": -1,
+            "ssl": -1,
+            "send": 0,
+            "wait": duration,
+            "receive": 0,
+        },
+        "_resourceType": request.request_type,
+    });
+
+    if let Some(error) = &request.error {
+        entry["_error"] = Value::String(error.clone());
+    }
+
+    // Chrome DevTools shows WebSocket frames from `_webSocketMessages`, timed in seconds
+    if let Some(frames) = &request.frames {
+        entry["_webSocketMessages"] = frames
+            .iter()
+            .map(|frame| {
+                serde_json::json!({
+                    "type": if frame.direction == "sent" { "send" } else { "receive" },
+                    "time": frame.time_ms as f64 / 1000.0,
+                    "opcode": if frame.opcode == "text" { 1 } else { 2 },
+                    "data": frame.payload.clone().unwrap_or_default(),
+                })
+            })
+            .collect();
+    }
+
+    entry
+}
+
+fn header_value(headers: &std::collections::HashMap<String, String>, name: &str) -> Option<String> {
+    headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.clone())
+}
+
+fn har_headers(headers: &std::collections::HashMap<String, String>) -> Vec<Value> {
+    let mut headers: Vec<(&String, &String)> = headers.iter().collect();
+    headers.sort();
+    headers
+        .into_iter()
+        .map(|(name, value)| serde_json::json!({ "name": name, "value": value }))
+        .collect()
+}
+
+/// Query parameters as they appear in the URL, without percent-decoding
+fn har_query_string(url: &str) -> Vec<Value> {
+    let query = url
+        .split_once('?')
+        .map(|(_, rest)| rest.split('#').next().unwrap_or_default())
+        .unwrap_or_default();
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            serde_json::json!({ "name": name, "value": value })
+        })
+        .collect()
+}
+
+/// ISO 8601 UTC timestamp for Unix milliseconds
+fn har_timestamp(ms: u64) -> String {
+    let secs = ms / 1000;
+    let days = (secs / 86_400) as i64;
+    let time = secs % 86_400;
+
+    // Civil date from days since the epoch (Howard Hinnant's algorithm)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        time / 3_600,
+        time % 3_600 / 60,
+        time % 60,
+        ms % 1000
+    )
+}
+
 /// Inject the network capture script into the webview
 pub async fn handle_inject_network_capture<R: Runtime>(
     app: &AppHandle<R>,
@@ -217,8 +661,24 @@ pub async fn handle_inject_network_capture<R: Runtime>(
     #[derive(serde::Deserialize)]
     pub struct InjectionRequest {
         window_label: Option<String>,
+        /// "fifo" (default), "keep_errors" or "sampled"
+        eviction_policy: Option<String>,
+        /// Capacity of the capture buffer (default 500)
+        max_entries: Option<usize>,
+        /// Frames kept per WebSocket connection (default 100)
+        websocket_max_frames: Option<usize>,
+        /// Largest text frame whose payload is kept, in bytes; 0 keeps sizes only (default 0)
+        websocket_payload_limit: Option<usize>,
     }
 
+    PayloadValidator::new("inject_network_capture", &payload)
+        .optional("window_label", FieldKind::String)
+        .optional("eviction_policy", FieldKind::OneOf(EVICTION_POLICIES))
+        .optional("max_entries", FieldKind::Integer { min: Some(1), max: None })
+        .optional("websocket_max_frames", FieldKind::Integer { min: Some(1), max: Some(10_000) })
+        .optional("websocket_payload_limit", FieldKind::Integer { min: Some(0), max: Some(1_000_000) })
+        .finish()?;
+
     let request: InjectionRequest = serde_json::from_value(payload)
         .map_err(|e| Error::serialization_error(format!("Invalid payload for injection: {}", e)))?;
 
@@ -233,7 +693,15 @@ pub async fn handle_inject_network_capture<R: Runtime>(
 
     // Send injection event to the window
     window
-        .emit("inject-network-capture", ())
+        .emit(
+            "inject-network-capture",
+            serde_json::json!({
+                "eviction_policy": request.eviction_policy,
+                "max_entries": request.max_entries,
+                "websocket_max_frames": request.websocket_max_frames,
+                "websocket_payload_limit": request.websocket_payload_limit,
+            }),
+        )
         .map_err(|e| Error::communication_error_with_context(
             "Failed to emit injection event",
             format!("window: {}, error: {}", window_label, e),
@@ -246,10 +714,12 @@ pub async fn handle_inject_network_capture<R: Runtime>(
     })
 }
 
-/// Helper function to retrieve network requests from the webview
+/// Helper function to retrieve network requests from the webview. Only `get_requests`
+/// advances the `since_last_poll` cursor.
 async fn retrieve_network_requests<R: Runtime>(
     app: AppHandle<R>,
     request: NetworkInspectorRequest,
+    advance_cursor: bool,
 ) -> Result<NetworkInspectorResponse, NetworkInspectorError> {
     let window_label = request
         .window_label
@@ -266,8 +736,25 @@ async fn retrieve_network_requests<R: Runtime>(
         start_time_ms: None,
         end_time_ms: None,
         limit: Some(100),
+        since_last_poll: None,
+        max_body_bytes: None,
+        redact_headers: None,
     });
 
+    // Continue after the requests earlier delta queries returned: at or after the cursor's
+    // millisecond, minus the ones already returned from it
+    let since_last_poll = filter.since_last_poll.unwrap_or(false);
+    let previous_cursor = if since_last_poll {
+        app.tauri_mcp().network_poll_cursor(&window_label)
+    } else {
+        None
+    };
+    let start_time_ms = match (filter.start_time_ms, previous_cursor.as_ref()) {
+        (Some(start), Some(cursor)) => Some(start.max(cursor.time_ms)),
+        (start, cursor) => start.or(cursor.map(|c| c.time_ms)),
+    };
+    let exclude_ids = previous_cursor.as_ref().map(|c| c.ids.clone()).unwrap_or_default();
+
     // Build the filter payload
     let filter_payload = serde_json::json!({
         "url_pattern": filter.url_pattern,
@@ -276,9 +763,11 @@ async fn retrieve_network_requests<R: Runtime>(
         "min_duration_ms": filter.min_duration_ms,
         "max_duration_ms": filter.max_duration_ms,
         "request_type": filter.request_type,
-        "start_time_ms": filter.start_time_ms,
+        "start_time_ms": start_time_ms,
         "end_time_ms": filter.end_time_ms,
         "limit": filter.limit.unwrap_or(100),
+        "exclude_ids": exclude_ids,
+        "oldest_first": since_last_poll,
     });
 
     // Emit event to retrieve network requests from webview
@@ -309,11 +798,16 @@ async fn retrieve_network_requests<R: Runtime>(
             }
 
             // Extract requests array from response
-            let requests: Vec<NetworkRequest> = response
+            let mut requests: Vec<NetworkRequest> = response
                 .get("requests")
                 .and_then(|r| serde_json::from_value(r.clone()).ok())
                 .unwrap_or_default();
 
+            // Enforced here rather than in the page, which may be running an older capture script
+            for request in requests.iter_mut() {
+                sanitize_request(request, filter.max_body_bytes, filter.redact_headers.as_deref());
+            }
+
             let total_count = response
                 .get("total_count")
                 .and_then(|c| c.as_u64())
@@ -327,17 +821,75 @@ async fn retrieve_network_requests<R: Runtime>(
                 .and_then(|c| c.as_bool())
                 .unwrap_or(false);
 
+            let eviction_policy = response
+                .get("eviction_policy")
+                .and_then(|p| p.as_str())
+                .map(|p| p.to_string());
+
+            let evicted_count = response.get("evicted_count").and_then(|c| c.as_u64());
+
+            let cursor = if since_last_poll {
+                let cursor = NetworkPollCursor::advance(previous_cursor, &requests);
+                if let Some(cursor) = cursor.clone().filter(|_| advance_cursor) {
+                    app.tauri_mcp().set_network_poll_cursor(&window_label, cursor);
+                }
+                cursor.map(|c| c.time_ms)
+            } else {
+                None
+            };
+
             Ok(NetworkInspectorResponse {
                 requests,
                 total_count,
                 returned_count,
                 capture_active,
+                cursor,
+                eviction_policy,
+                evicted_count,
             })
         }
         Err(e) => Err(e.into()),
     }
 }
 
+/// Truncate the bodies and WebSocket frame payloads of a captured request and redact the given headers
+fn sanitize_request(request: &mut NetworkRequest, max_body_bytes: Option<usize>, redact_headers: Option<&[String]>) {
+    if let Some(max_bytes) = max_body_bytes {
+        for body in [&mut request.request_body, &mut request.response_body].into_iter().flatten() {
+            truncate_body(body, max_bytes);
+        }
+        for frame in request.frames.iter_mut().flatten() {
+            if let Some(payload) = frame.payload.as_mut() {
+                truncate_body(payload, max_bytes);
+            }
+        }
+    }
+
+    if let Some(names) = redact_headers.filter(|names| !names.is_empty()) {
+        for headers in [&mut request.request_headers, &mut request.response_headers] {
+            for (name, value) in headers.iter_mut() {
+                if names.iter().any(|n| n.eq_ignore_ascii_case(name)) {
+                    *value = "[REDACTED]".to_string();
+                }
+            }
+        }
+    }
+}
+
+/// Cut a body down to at most `max_bytes` (on a character boundary) and note how much was dropped
+fn truncate_body(body: &mut String, max_bytes: usize) {
+    if body.len() <= max_bytes {
+        return;
+    }
+    let mut end = max_bytes;
+    while !body.is_char_boundary(end) {
+        end -= 1;
+    }
+    let dropped = body.len() - end;
+    body.truncate(end);
+    body.push_str(&format!("[truncated {} bytes]", dropped));
+}
+
 /// Helper function to clear network requests from the webview
 async fn clear_network_requests<R: Runtime>(
     app: AppHandle<R>,
@@ -357,6 +909,9 @@ async fn clear_network_requests<R: Runtime>(
         total_count: 0,
         returned_count: 0,
         capture_active: true,
+        cursor: None,
+        eviction_policy: None,
+        evicted_count: None,
     })
 }
 
@@ -379,6 +934,9 @@ async fn start_network_capture<R: Runtime>(
         total_count: 0,
         returned_count: 0,
         capture_active: true,
+        cursor: None,
+        eviction_policy: None,
+        evicted_count: None,
     })
 }
 
@@ -401,5 +959,283 @@ async fn stop_network_capture<R: Runtime>(
         total_count: 0,
         returned_count: 0,
         capture_active: false,
+        cursor: None,
+        eviction_policy: None,
+        evicted_count: None,
     })
 }
+
+/// Wraps fetch and XHR once; the wrappers read the current configuration on every
+/// request, so later `set_default_headers` calls only swap the headers. Headers the
+/// request already sets are left alone.
+const DEFAULT_HEADERS_JS: &str = r#"        const state = window.__MCP_DEFAULT_HEADERS__ || (window.__MCP_DEFAULT_HEADERS__ = { headers: null, pattern: null, injections: [], total: 0 });
+
+        const matches = (url) => {
+            if (!state.headers) return false;
+            if (!state.pattern) return true;
+            try { return new RegExp(state.pattern, 'i').test(url); } catch (e) { return url.includes(state.pattern); }
+        };
+        const record = (type, method, url, names) => {
+            state.total += 1;
+            state.injections.push({ type, method, url, headers: names, time_ms: Date.now() });
+            if (state.injections.length > 500) state.injections.shift();
+        };
+
+        if (!state.installed) {
+            const originalFetch = window.fetch;
+            window.fetch = function(input, init) {
+                const url = input instanceof Request ? input.url : String(input);
+                if (!matches(url)) return originalFetch.call(this, input, init);
+                const headers = new Headers(init && init.headers ? init.headers : (input instanceof Request ? input.headers : undefined));
+                const added = [];
+                for (const [name, value] of Object.entries(state.headers)) {
+                    if (!headers.has(name)) { headers.set(name, value); added.push(name); }
+                }
+                const method = ((init && init.method) || (input instanceof Request ? input.method : 'GET')).toUpperCase();
+                record('fetch', method, url, added);
+                return originalFetch.call(this, input, { ...(init || {}), headers });
+            };
+
+            const originalOpen = XMLHttpRequest.prototype.open;
+            const originalSetHeader = XMLHttpRequest.prototype.setRequestHeader;
+            const originalSend = XMLHttpRequest.prototype.send;
+            XMLHttpRequest.prototype.open = function(method, url, ...rest) {
+                this.__mcpHeaderTarget = { method: String(method).toUpperCase(), url: String(url), set: new Set() };
+                return originalOpen.call(this, method, url, ...rest);
+            };
+            XMLHttpRequest.prototype.setRequestHeader = function(name, value) {
+                if (this.__mcpHeaderTarget) this.__mcpHeaderTarget.set.add(String(name).toLowerCase());
+                return originalSetHeader.call(this, name, value);
+            };
+            XMLHttpRequest.prototype.send = function(body) {
+                const target = this.__mcpHeaderTarget;
+                if (target && matches(target.url)) {
+                    const added = [];
+                    for (const [name, value] of Object.entries(state.headers)) {
+                        if (!target.set.has(name.toLowerCase())) { originalSetHeader.call(this, name, value); added.push(name); }
+                    }
+                    record('xhr', target.method, target.url, added);
+                }
+                return originalSend.call(this, body);
+            };
+            state.installed = true;
+        }
+
+        if (args.action === 'set_default_headers') {
+            state.headers = args.headers;
+            state.pattern = args.url_pattern || null;
+            return { active: true, headers: Object.keys(state.headers), url_pattern: state.pattern };
+        }
+
+        const report = {
+            active: state.headers !== null,
+            headers: state.headers ? Object.keys(state.headers) : [],
+            url_pattern: state.pattern,
+            injected_count: state.total,
+            injections: state.injections.slice(-args.limit)
+        };
+
+        if (args.action === 'clear_default_headers') {
+            state.headers = null;
+            state.pattern = null;
+            state.injections = [];
+            state.total = 0;
+            report.active = false;
+        }
+
+        return report;"#;
+
+
+/// Installs the interceptors once; each checks the current rules per request. fetch
+/// rejects with the same `TypeError` a network failure gives, XHR reports status 0 and
+/// fires `error`, and `<img>`/`<script>`/stylesheet `<link>` elements get their URL
+/// swapped for one that cannot load, so their `error` handlers run. Elements are only
+/// caught when their URL is assigned after the rule exists (via the property or
+/// `setAttribute`); markup already parsed and CSS `url()` references are unaffected.
+const RESOURCE_FAILURES_JS: &str = r#"        const FAILED_URL = 'about:invalid#mcp-fail-resource-type';
+        const state = window.__MCP_RESOURCE_FAILURES__ || (window.__MCP_RESOURCE_FAILURES__ = { rules: [], next_id: 1, failures: [], total: 0 });
+
+        const matchingRule = (type, url) => state.rules.find(rule => {
+            if (!rule.resource_types.includes(type)) return false;
+            if (!rule.url_pattern) return true;
+            try { return new RegExp(rule.url_pattern, 'i').test(url); } catch (e) { return url.includes(rule.url_pattern); }
+        });
+        const shouldFail = (type, url) => {
+            const rule = matchingRule(type, String(url));
+            if (!rule) return false;
+            state.total += 1;
+            state.failures.push({ type, url: String(url), rule_id: rule.id, rule: { resource_types: rule.resource_types, url_pattern: rule.url_pattern }, time_ms: Date.now() });
+            if (state.failures.length > 500) state.failures.shift();
+            return true;
+        };
+        const absolute = (url) => { try { return new URL(String(url), document.baseURI).href; } catch (e) { return String(url); } };
+
+        if (!state.installed) {
+            const originalFetch = window.fetch;
+            window.fetch = function(input, init) {
+                const url = input instanceof Request ? input.url : absolute(input);
+                if (shouldFail('fetch', url)) return Promise.reject(new TypeError('Failed to fetch'));
+                return originalFetch.call(this, input, init);
+            };
+
+            const originalOpen = XMLHttpRequest.prototype.open;
+            const originalSend = XMLHttpRequest.prototype.send;
+            XMLHttpRequest.prototype.open = function(method, url, ...rest) {
+                this.__mcpFailureUrl = absolute(url);
+                return originalOpen.call(this, method, url, ...rest);
+            };
+            XMLHttpRequest.prototype.send = function(body) {
+                if (!shouldFail('xhr', this.__mcpFailureUrl)) return originalSend.call(this, body);
+                // Mirror a network error: readyState 4, status 0, then error and loadend
+                setTimeout(() => {
+                    Object.defineProperty(this, 'readyState', { value: 4, configurable: true });
+                    this.dispatchEvent(new Event('readystatechange'));
+                    this.dispatchEvent(new ProgressEvent('error'));
+                    this.dispatchEvent(new ProgressEvent('loadend'));
+                }, 0);
+            };
+
+            const isStylesheet = (link) => /(^|\s)stylesheet(\s|$)/i.test(link.rel || link.getAttribute('rel') || '');
+            const typeOf = (el, attribute) => {
+                if (el instanceof HTMLImageElement && (attribute === 'src' || attribute === 'srcset')) return 'image';
+                if (el instanceof HTMLScriptElement && attribute === 'src') return 'script';
+                if (el instanceof HTMLLinkElement && attribute === 'href' && isStylesheet(el)) return 'stylesheet';
+                return null;
+            };
+            const originalSetAttribute = Element.prototype.setAttribute;
+            const rewrite = (el, attribute, value) => {
+                const type = typeOf(el, attribute);
+                if (!type) return value;
+                // srcset holds candidate lists; the first URL stands for the set
+                const url = attribute === 'srcset' ? String(value).trim().split(/\s+/)[0] : value;
+                if (!url || !shouldFail(type, absolute(url))) return value;
+                if (attribute === 'srcset') {
+                    originalSetAttribute.call(el, 'src', FAILED_URL);
+                    return '';
+                }
+                return FAILED_URL;
+            };
+
+            Element.prototype.setAttribute = function(name, value) {
+                const attribute = String(name).toLowerCase();
+                return originalSetAttribute.call(this, name, rewrite(this, attribute, value));
+            };
+            for (const [proto, attribute] of [[HTMLImageElement.prototype, 'src'], [HTMLImageElement.prototype, 'srcset'], [HTMLScriptElement.prototype, 'src'], [HTMLLinkElement.prototype, 'href']]) {
+                const descriptor = Object.getOwnPropertyDescriptor(proto, attribute);
+                if (!descriptor || !descriptor.set) continue;
+                Object.defineProperty(proto, attribute, {
+                    ...descriptor,
+                    set(value) { descriptor.set.call(this, rewrite(this, attribute, value)); }
+                });
+            }
+            state.installed = true;
+        }
+
+        if (args.action === 'fail_resource_type') {
+            const rule = { id: state.next_id++, resource_types: args.resource_types, url_pattern: args.url_pattern || null, created_at_ms: Date.now() };
+            state.rules.push(rule);
+            return { rule, active_rules: state.rules };
+        }
+
+        const report = {
+            active_rules: state.rules,
+            failed_count: state.total,
+            failures: state.failures.slice(-args.limit)
+        };
+
+        if (args.action === 'clear_resource_failures') {
+            state.rules = [];
+            state.failures = [];
+            state.total = 0;
+            report.cleared = true;
+        }
+
+        return report;"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(id: &str, start_time_ms: u64) -> NetworkRequest {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "url": "https://example.com/",
+            "method": "GET",
+            "request_type": "fetch",
+            "request_headers": {},
+            "response_headers": {},
+            "start_time_ms": start_time_ms,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn short_bodies_are_left_alone() {
+        let mut body = "hello".to_string();
+        truncate_body(&mut body, 5);
+        assert_eq!(body, "hello");
+    }
+
+    #[test]
+    fn long_bodies_are_cut_on_a_char_boundary() {
+        let mut body = "héllo".to_string();
+        // Byte 2 falls inside "é", so the cut moves back to byte 1
+        truncate_body(&mut body, 2);
+        assert_eq!(body, "h[truncated 5 bytes]");
+    }
+
+    #[test]
+    fn sanitize_truncates_frames_and_redacts_headers_case_insensitively() {
+        let mut captured: NetworkRequest = serde_json::from_value(serde_json::json!({
+            "id": "a",
+            "url": "wss://example.com/",
+            "method": "GET",
+            "request_type": "websocket",
+            "request_headers": { "Authorization": "Bearer secret", "Accept": "*/*" },
+            "response_headers": { "set-cookie": "session=1" },
+            "response_body": "0123456789",
+            "start_time_ms": 1,
+            "frames": [{ "direction": "sent", "opcode": "text", "size_bytes": 6, "time_ms": 2, "payload": "abcdef" }],
+        }))
+        .unwrap();
+        let redact = vec!["authorization".to_string(), "SET-COOKIE".to_string()];
+        sanitize_request(&mut captured, Some(4), Some(&redact));
+
+        assert_eq!(captured.response_body.as_deref(), Some("0123[truncated 6 bytes]"));
+        assert_eq!(captured.frames.unwrap()[0].payload.as_deref(), Some("abcd[truncated 2 bytes]"));
+        assert_eq!(captured.request_headers["Authorization"], "[REDACTED]");
+        assert_eq!(captured.request_headers["Accept"], "*/*");
+        assert_eq!(captured.response_headers["set-cookie"], "[REDACTED]");
+    }
+
+    #[test]
+    fn cursor_moves_to_newest_returned_request() {
+        let cursor = NetworkPollCursor::advance(None, &[request("a", 10), request("b", 30), request("c", 20)]);
+        assert_eq!(cursor, Some(NetworkPollCursor { time_ms: 30, ids: vec!["b".to_string()] }));
+    }
+
+    #[test]
+    fn cursor_stays_put_when_nothing_is_returned() {
+        let previous = NetworkPollCursor { time_ms: 30, ids: vec!["b".to_string()] };
+        assert_eq!(NetworkPollCursor::advance(Some(previous.clone()), &[]), Some(previous));
+        assert_eq!(NetworkPollCursor::advance(None, &[]), None);
+    }
+
+    #[test]
+    fn cursor_keeps_ids_returned_in_the_same_millisecond() {
+        // A limited page that ends partway through requests started in the same millisecond
+        let first = NetworkPollCursor::advance(None, &[request("a", 10), request("b", 20)]);
+        let second = NetworkPollCursor::advance(first, &[request("c", 20)]);
+        assert_eq!(
+            second,
+            Some(NetworkPollCursor { time_ms: 20, ids: vec!["b".to_string(), "c".to_string()] })
+        );
+    }
+
+    #[test]
+    fn cursor_drops_old_ids_when_time_moves_on() {
+        let previous = NetworkPollCursor { time_ms: 20, ids: vec!["b".to_string(), "c".to_string()] };
+        let cursor = NetworkPollCursor::advance(Some(previous), &[request("d", 25), request("e", 40)]);
+        assert_eq!(cursor, Some(NetworkPollCursor { time_ms: 40, ids: vec!["e".to_string()] }));
+    }
+}
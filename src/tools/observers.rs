@@ -0,0 +1,354 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ObserversForRequest {
+    window_label: Option<String>,
+    /// Element whose observers to report; every tracked observer when omitted
+    selector: Option<String>,
+    /// "intersection", "resize" or "all" (default)
+    observer_type: Option<String>,
+    /// Target selectors listed per observer (default 10)
+    max_targets: Option<usize>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for reporting the Intersection/Resize observers watching an element
+pub async fn handle_get_observers_for<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_observers_for", &payload)
+        .optional("selector", FieldKind::NonEmptyString)
+        .optional("observer_type", FieldKind::OneOf(&["intersection", "resize", "all"]))
+        .optional("max_targets", FieldKind::Integer { min: Some(0), max: Some(1000) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: ObserversForRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_observers_for: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Observer inspection error",
+        &json!({
+            "selector": request.selector,
+            "observer_type": request.observer_type.as_deref().unwrap_or("all"),
+            "max_targets": request.max_targets.unwrap_or(10),
+        }),
+        &format!("        {}\n{}", OBSERVER_TRACKER_JS, GET_OBSERVERS_JS),
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "observer inspection",
+    )
+    .await
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct TriggerIntersectionRequest {
+    window_label: Option<String>,
+    /// Element whose intersection to trigger
+    selector: String,
+    /// "auto" (default), "scroll" or "dispatch"
+    mode: Option<String>,
+    /// Whether the synthetic entry reports the element as entering (default true)
+    is_intersecting: Option<bool>,
+    /// How long to wait for callbacks after scrolling (default 300)
+    wait_ms: Option<u64>,
+    /// Scroll back to the starting position afterwards (default false)
+    restore_scroll: Option<bool>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for making an element's IntersectionObserver callbacks fire
+pub async fn handle_trigger_intersection<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("trigger_intersection", &payload)
+        .required("selector", FieldKind::NonEmptyString)
+        .optional("mode", FieldKind::OneOf(&["auto", "scroll", "dispatch"]))
+        .optional("is_intersecting", FieldKind::Bool)
+        .optional("wait_ms", FieldKind::Integer { min: Some(0), max: Some(10_000) })
+        .optional("restore_scroll", FieldKind::Bool)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: TriggerIntersectionRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for trigger_intersection: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let wait_ms = request.wait_ms.unwrap_or(300);
+    let js_code = build_probe_script(
+        "Intersection trigger error",
+        &json!({
+            "selector": request.selector,
+            "mode": request.mode.as_deref().unwrap_or("auto"),
+            "is_intersecting": request.is_intersecting.unwrap_or(true),
+            "wait_ms": wait_ms,
+            "restore_scroll": request.restore_scroll.unwrap_or(false),
+        }),
+        &format!("        {}\n{}", OBSERVER_TRACKER_JS, TRIGGER_INTERSECTION_JS),
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS).saturating_add(wait_ms),
+        "intersection trigger",
+    )
+    .await
+}
+
+/// Wraps `IntersectionObserver` and `ResizeObserver` so each instance records its
+/// options, its current targets and the latest entry per target. Registered as an
+/// initialization script when `PluginConfig::track_observers` is set, so observers the
+/// app creates at startup are seen; otherwise the first `get_observers_for` call
+/// installs it and only later observers are tracked.
+pub(crate) const OBSERVER_TRACKER_JS: &str = r#"(function() {
+    if (window.__MCP_OBSERVERS__) return;
+    const registry = window.__MCP_OBSERVERS__ = { installed_at_ms: Date.now(), early: document.readyState === 'loading', next_id: 1, records: new Map() };
+
+    const wrap = (name, type) => {
+        const Native = window[name];
+        if (typeof Native !== 'function') return;
+        const Tracked = function(callback, options) {
+            const record = {
+                id: registry.next_id++,
+                type,
+                options: options || {},
+                created_at_ms: Date.now(),
+                targets: new Map(),
+                callbacks: 0,
+                last_callback_ms: null,
+                disconnected: false
+            };
+            const wrapped = function(entries, observer) {
+                record.callbacks += 1;
+                record.last_callback_ms = Date.now();
+                for (const entry of entries) {
+                    const target = record.targets.get(entry.target);
+                    if (!target) continue;
+                    target.callbacks += 1;
+                    target.last_entry = type === 'intersection'
+                        ? { time_ms: Date.now(), is_intersecting: entry.isIntersecting, intersection_ratio: entry.intersectionRatio }
+                        : { time_ms: Date.now(), width: entry.contentRect.width, height: entry.contentRect.height };
+                }
+                return callback.call(this, entries, observer);
+            };
+            const instance = Reflect.construct(Native, [wrapped, options], new.target || Tracked);
+            // Lets trigger_intersection deliver synthetic entries through the app's callback
+            record.deliver = (entries) => wrapped.call(instance, entries, instance);
+            registry.records.set(instance, record);
+            return instance;
+        };
+        Tracked.prototype = Native.prototype;
+        Object.setPrototypeOf(Tracked, Native);
+
+        const proto = Native.prototype;
+        const observe = proto.observe;
+        const unobserve = proto.unobserve;
+        const disconnect = proto.disconnect;
+        proto.observe = function(target, options) {
+            const record = registry.records.get(this);
+            if (record && target) {
+                record.disconnected = false;
+                if (!record.targets.has(target)) record.targets.set(target, { since_ms: Date.now(), options: options || null, callbacks: 0, last_entry: null });
+            }
+            return observe.apply(this, arguments);
+        };
+        proto.unobserve = function(target) {
+            const record = registry.records.get(this);
+            if (record) record.targets.delete(target);
+            return unobserve.apply(this, arguments);
+        };
+        proto.disconnect = function() {
+            const record = registry.records.get(this);
+            if (record) {
+                record.targets.clear();
+                record.disconnected = true;
+            }
+            return disconnect.apply(this, arguments);
+        };
+        window[name] = Tracked;
+    };
+    wrap('IntersectionObserver', 'intersection');
+    wrap('ResizeObserver', 'resize');
+})();"#;
+
+/// Observers that were disconnected or no longer have targets are left out, since the
+/// browser has stopped delivering entries to them.
+const GET_OBSERVERS_JS: &str = r#"        const registry = window.__MCP_OBSERVERS__;
+        const el = args.selector ? mcp.query(args.selector) : null;
+        const describeRoot = (root) => {
+            if (!root) return 'viewport';
+            if (root === document) return 'document';
+            return mcp.cssPath(root);
+        };
+
+        const observers = [];
+        for (const [observer, record] of registry.records) {
+            if (args.observer_type !== 'all' && record.type !== args.observer_type) continue;
+            if (record.targets.size === 0) continue;
+            if (el && !record.targets.has(el)) continue;
+
+            const summary = {
+                id: record.id,
+                type: record.type,
+                created_at_ms: record.created_at_ms,
+                target_count: record.targets.size,
+                callbacks: record.callbacks,
+                last_callback_ms: record.last_callback_ms
+            };
+            if (record.type === 'intersection') {
+                summary.root = describeRoot(observer.root);
+                summary.root_margin = observer.rootMargin;
+                summary.thresholds = [...observer.thresholds];
+                if ('scrollMargin' in observer) summary.scroll_margin = observer.scrollMargin;
+            }
+            if (el) {
+                const target = record.targets.get(el);
+                summary.target = {
+                    observed_since_ms: target.since_ms,
+                    box: record.type === 'resize' ? ((target.options && target.options.box) || 'content-box') : undefined,
+                    callbacks: target.callbacks,
+                    last_entry: target.last_entry
+                };
+            } else {
+                summary.targets = [...record.targets.keys()].slice(0, args.max_targets).map(target => target.isConnected ? mcp.cssPath(target) : '(detached) ' + target.nodeName.toLowerCase());
+                summary.detached_targets = [...record.targets.keys()].filter(target => !target.isConnected).length;
+            }
+            observers.push(summary);
+        }
+
+        // What an intersection observer would see for the element right now
+        let current = null;
+        if (el) {
+            const r = el.getBoundingClientRect();
+            current = {
+                rect: mcp.rect(r),
+                connected: el.isConnected,
+                rendered: el.getClientRects().length > 0,
+                in_viewport: r.bottom > 0 && r.right > 0 && r.top < window.innerHeight && r.left < window.innerWidth
+            };
+        }
+
+        return {
+            selector: el ? mcp.cssPath(el) : null,
+            tracking_since_ms: registry.installed_at_ms,
+            tracked_from_page_start: registry.early,
+            count: observers.length,
+            observers,
+            element: current,
+            note: registry.early ? null : 'Observer tracking started after the page loaded, so observers created earlier are not listed; enable PluginConfig::track_observers to track them from the start'
+        };"#;
+
+
+/// Dispatch hands each tracked observer of the element a synthetic entry through its own
+/// callback, so nothing depends on scroll math; the entry is a plain object on the
+/// `IntersectionObserverEntry` prototype because the real constructor is not exposed.
+/// Observers created before tracking started are invisible, and "auto" falls back to
+/// scrolling the element into view, where a callback can only be confirmed if tracked.
+const TRIGGER_INTERSECTION_JS: &str = r#"        const el = mcp.query(args.selector);
+        const registry = window.__MCP_OBSERVERS__;
+        const watching = [...registry.records.entries()]
+            .filter(([, record]) => record.type === 'intersection' && record.targets.has(el));
+        const counts = () => watching.map(([, record]) => record.targets.get(el) ? record.targets.get(el).callbacks : 0);
+        const inViewport = () => {
+            const r = el.getBoundingClientRect();
+            return r.bottom > 0 && r.right > 0 && r.top < window.innerHeight && r.left < window.innerWidth;
+        };
+
+        let mode = args.mode;
+        if (mode === 'auto') mode = watching.some(([, record]) => record.deliver) ? 'dispatch' : 'scroll';
+
+        const before = counts();
+        const results = [];
+        let scrolled = false;
+        const start = { x: window.scrollX, y: window.scrollY };
+        const wasInViewport = inViewport();
+
+        if (mode === 'dispatch') {
+            if (watching.length === 0) {
+                throw new Error('No tracked IntersectionObserver is watching ' + args.selector + '; enable PluginConfig::track_observers so observers created at startup are tracked, or use mode "scroll"');
+            }
+            const rect = el.getBoundingClientRect();
+            const zero = new DOMRectReadOnly(rect.x, rect.y, 0, 0);
+            for (const [observer, record] of watching) {
+                if (!record.deliver) {
+                    results.push({ observer_id: record.id, fired: false, error: 'Observer was tracked by an older shim that cannot deliver entries; reload the page' });
+                    continue;
+                }
+                const root = observer.root && observer.root !== document && observer.root.getBoundingClientRect
+                    ? observer.root.getBoundingClientRect()
+                    : new DOMRectReadOnly(0, 0, window.innerWidth, window.innerHeight);
+                const entry = Object.create(IntersectionObserverEntry.prototype, {
+                    target: { value: el, enumerable: true },
+                    time: { value: performance.now(), enumerable: true },
+                    isIntersecting: { value: args.is_intersecting, enumerable: true },
+                    intersectionRatio: { value: args.is_intersecting ? 1 : 0, enumerable: true },
+                    boundingClientRect: { value: rect, enumerable: true },
+                    intersectionRect: { value: args.is_intersecting ? rect : zero, enumerable: true },
+                    rootBounds: { value: root, enumerable: true },
+                    isVisible: { value: args.is_intersecting, enumerable: true }
+                });
+                try {
+                    const returned = record.deliver([entry]);
+                    if (returned && typeof returned.then === 'function') await returned;
+                    results.push({ observer_id: record.id, fired: true });
+                } catch (error) {
+                    results.push({ observer_id: record.id, fired: true, error: String(error && error.message || error) });
+                }
+            }
+        } else {
+            el.scrollIntoView({ block: 'center', inline: 'center', behavior: 'instant' });
+            scrolled = true;
+            // Intersection entries are queued after the next rendering update
+            await new Promise(resolve => requestAnimationFrame(() => requestAnimationFrame(resolve)));
+            await new Promise(resolve => setTimeout(resolve, args.wait_ms));
+            const after = counts();
+            watching.forEach(([, record], i) => {
+                results.push({ observer_id: record.id, fired: after[i] > before[i], callbacks: after[i] - before[i] });
+            });
+        }
+
+        const nowInViewport = inViewport();
+        if (scrolled && args.restore_scroll) window.scrollTo({ left: start.x, top: start.y, behavior: 'instant' });
+
+        return {
+            selector: mcp.cssPath(el),
+            mode,
+            tracked_observers: watching.length,
+            callback_fired: watching.length > 0 ? results.some(result => result.fired) : null,
+            observers: results,
+            synthetic_is_intersecting: mode === 'dispatch' ? args.is_intersecting : null,
+            was_in_viewport: wasInViewport,
+            in_viewport: nowInViewport,
+            scroll_restored: scrolled && args.restore_scroll,
+            note: watching.length > 0 ? null : (registry.early
+                ? 'No IntersectionObserver is watching this element'
+                : 'Observer tracking started after the page loaded, so whether an earlier observer fired cannot be confirmed; enable PluginConfig::track_observers to track them from the start')
+        };"#;
@@ -0,0 +1,177 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct SemanticOutlineRequest {
+    window_label: Option<String>,
+    /// Element to start from; defaults to `body`
+    root_selector: Option<String>,
+    /// Upper bound on the number of outline nodes (default 300)
+    max_nodes: Option<usize>,
+    /// Keep elements that are not rendered (default false)
+    include_hidden: Option<bool>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for reading the page as a compact tree of landmarks and interactive elements
+pub async fn handle_get_semantic_outline<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_semantic_outline", &payload)
+        .optional("root_selector", FieldKind::NonEmptyString)
+        .optional("max_nodes", FieldKind::Integer { min: Some(1), max: Some(5000) })
+        .optional("include_hidden", FieldKind::Bool)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: SemanticOutlineRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_semantic_outline: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Semantic outline error",
+        &json!({
+            "root_selector": request.root_selector,
+            "max_nodes": request.max_nodes.unwrap_or(300),
+            "include_hidden": request.include_hidden.unwrap_or(false),
+        }),
+        SEMANTIC_OUTLINE_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "semantic outline",
+    )
+    .await
+}
+
+/// Walks the DOM keeping only landmarks, headings and interactive elements; wrappers
+/// in between are skipped and their semantic descendants hoisted to the nearest kept ancestor
+const SEMANTIC_OUTLINE_JS: &str = r#"        const root = args.root_selector ? mcp.query(args.root_selector) : document.body;
+
+        const LANDMARK_TAGS = { nav: 'navigation', main: 'main', aside: 'complementary', header: 'banner', footer: 'contentinfo', form: 'form', dialog: 'dialog', search: 'search' };
+        const LANDMARK_ROLES = ['navigation', 'main', 'complementary', 'banner', 'contentinfo', 'form', 'dialog', 'alertdialog', 'search', 'region', 'menu', 'menubar', 'tablist', 'toolbar'];
+        const INTERACTIVE_ROLES = ['button', 'link', 'checkbox', 'radio', 'switch', 'tab', 'menuitem', 'menuitemcheckbox', 'menuitemradio', 'option', 'textbox', 'searchbox', 'combobox', 'slider', 'spinbutton'];
+        const SKIP_TAGS = ['SCRIPT', 'STYLE', 'NOSCRIPT', 'TEMPLATE', 'SVG', 'CANVAS'];
+
+        const clip = (text) => {
+            const value = (text || '').replace(/\s+/g, ' ').trim();
+            return value.length > 80 ? value.substring(0, 77) + '...' : value;
+        };
+
+        const roleOf = (el) => {
+            const explicit = (el.getAttribute('role') || '').trim().split(/\s+/)[0];
+            if (explicit && explicit !== 'presentation' && explicit !== 'none') return explicit;
+            const tag = el.tagName.toLowerCase();
+            if (/^h[1-6]$/.test(tag)) return 'heading';
+            if (LANDMARK_TAGS[tag]) {
+                // header/footer are only landmarks outside sectioning content
+                if ((tag === 'header' || tag === 'footer') && el.closest('article, aside, main, nav, section') !== null) return null;
+                return LANDMARK_TAGS[tag];
+            }
+            if (tag === 'section' && (el.getAttribute('aria-label') || el.getAttribute('aria-labelledby'))) return 'region';
+            if (tag === 'a' && el.hasAttribute('href')) return 'link';
+            if (tag === 'button' || tag === 'summary') return 'button';
+            if (tag === 'select') return el.multiple ? 'listbox' : 'combobox';
+            if (tag === 'textarea') return 'textbox';
+            if (tag === 'input') {
+                const type = (el.type || 'text').toLowerCase();
+                if (type === 'hidden') return null;
+                if (['button', 'submit', 'reset', 'image'].includes(type)) return 'button';
+                if (type === 'checkbox' || type === 'radio' || type === 'range') return type === 'range' ? 'slider' : type;
+                if (type === 'search') return 'searchbox';
+                return 'textbox';
+            }
+            if (el.isContentEditable && !(el.parentElement && el.parentElement.isContentEditable)) return 'textbox';
+            if (el.tabIndex >= 0 && el.hasAttribute('tabindex')) return 'focusable';
+            return null;
+        };
+
+        const nameOf = (el) => {
+            const labelledBy = el.getAttribute('aria-labelledby');
+            if (labelledBy) {
+                const text = labelledBy.split(/\s+/).map(id => document.getElementById(id)).filter(Boolean).map(n => n.textContent).join(' ');
+                if (clip(text)) return clip(text);
+            }
+            if (el.getAttribute('aria-label')) return clip(el.getAttribute('aria-label'));
+            if (el.labels && el.labels.length > 0) return clip(Array.from(el.labels).map(l => l.textContent).join(' '));
+            if (el.tagName === 'INPUT' && ['button', 'submit', 'reset'].includes(el.type)) return clip(el.value);
+            if (el.getAttribute('alt')) return clip(el.getAttribute('alt'));
+            const text = clip(el.innerText !== undefined ? el.innerText : el.textContent);
+            if (text) return text;
+            return clip(el.getAttribute('placeholder') || el.getAttribute('title') || '');
+        };
+
+        let count = 0;
+        let truncated = false;
+
+        const walk = (el, out) => {
+            for (const child of el.children) {
+                if (count >= args.max_nodes) {
+                    truncated = true;
+                    return;
+                }
+                if (SKIP_TAGS.includes(child.tagName.toUpperCase()) || child.getAttribute('aria-hidden') === 'true') continue;
+                const visible = mcp.isVisible(child);
+                // display:none hides the whole subtree; other invisible wrappers may still hold visible children
+                if (!visible && !args.include_hidden && getComputedStyle(child).display === 'none') continue;
+
+                const role = roleOf(child);
+                if (!role || (!visible && !args.include_hidden)) {
+                    walk(child, out);
+                    continue;
+                }
+
+                count += 1;
+                const node = { role, name: nameOf(child), selector: mcp.cssPath(child), visible };
+                if (role === 'heading') {
+                    const level = child.getAttribute('aria-level') || child.tagName.substring(1);
+                    node.level = Number(level) || null;
+                }
+                if (INTERACTIVE_ROLES.includes(role) || role === 'combobox' || role === 'listbox' || role === 'focusable') {
+                    node.enabled = !(child.disabled || child.getAttribute('aria-disabled') === 'true' || child.closest('fieldset:disabled') !== null);
+                }
+                if ('checked' in child && (child.type === 'checkbox' || child.type === 'radio')) node.checked = child.checked;
+                else if (child.hasAttribute('aria-checked')) node.checked = child.getAttribute('aria-checked') === 'true';
+                if (child.hasAttribute('aria-expanded')) node.expanded = child.getAttribute('aria-expanded') === 'true';
+                if (role === 'link') node.href = child.getAttribute('href');
+                if (role === 'textbox' || role === 'searchbox' || role === 'combobox') {
+                    if ('value' in child && child.type !== 'password') node.value = clip(child.value);
+                }
+
+                // Headings and controls are leaves; landmarks keep their semantic descendants
+                const isLandmark = LANDMARK_ROLES.includes(role);
+                if (isLandmark) {
+                    const children = [];
+                    walk(child, children);
+                    if (children.length > 0) node.children = children;
+                }
+                out.push(node);
+            }
+        };
+
+        const outline = [];
+        walk(root, outline);
+
+        return {
+            url: location.href,
+            title: document.title,
+            node_count: count,
+            truncated,
+            outline
+        };"#;
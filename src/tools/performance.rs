@@ -1,10 +1,11 @@
 use serde_json::{json, Value};
-use tauri::{AppHandle, Runtime, Manager, Emitter, Listener};
+use tauri::{AppHandle, Runtime, Manager};
 use log::info;
-use std::sync::mpsc;
 
 use crate::error::Error;
 use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, request_js_response, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
 
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct PerformanceMetricsRequest {
@@ -14,10 +15,26 @@ pub struct PerformanceMetricsRequest {
     include_user_timing: Option<bool>,
     include_memory: Option<bool>,
     include_long_tasks: Option<bool>,
+    /// Add resources ordered by start time with TTFB, blocking time and normalized offsets
+    include_waterfall: Option<bool>,
+    /// Add Cumulative Layout Shift under `web_vitals`
+    include_cls: Option<bool>,
+    /// Add Interaction to Next Paint under `web_vitals`
+    include_inp: Option<bool>,
+    /// Add First Input Delay under `web_vitals`
+    include_fid: Option<bool>,
+    /// How long the buffered web vitals observers collect before responding (default 500)
+    vitals_window_ms: Option<u64>,
     resource_filter: Option<ResourceFilter>,
     timeout_ms: Option<u64>,
 }
 
+impl PerformanceMetricsRequest {
+    fn includes_web_vitals(&self) -> bool {
+        self.include_cls.unwrap_or(false) || self.include_inp.unwrap_or(false) || self.include_fid.unwrap_or(false)
+    }
+}
+
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct ResourceFilter {
     resource_type: Option<Vec<String>>, // "script", "stylesheet", "image", "fetch", "xmlhttprequest", etc.
@@ -27,10 +44,62 @@ pub struct ResourceFilter {
     url_pattern: Option<String>,
 }
 
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct LoadedScriptsRequest {
+    window_label: Option<String>,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ScriptTimingRequest {
+    window_label: Option<String>,
+    /// How long to collect buffered long-animation-frame and long task entries (default 100)
+    observe_ms: Option<u64>,
+    /// Number of scripts listed as the heaviest (default 5)
+    top: Option<usize>,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ClearPerformanceEntriesRequest {
+    window_label: Option<String>,
+    /// Clear resource timings (default true)
+    resources: Option<bool>,
+    /// Clear user timing marks (default true)
+    marks: Option<bool>,
+    /// Clear user timing measures (default true)
+    measures: Option<bool>,
+    /// New resource timing buffer size; the browser default is 250 entries
+    reset_buffer_size: Option<u32>,
+    timeout_ms: Option<u64>,
+}
+
 pub async fn handle_get_performance_metrics<R: Runtime>(
     app: &AppHandle<R>,
     payload: Value,
 ) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_performance_metrics", &payload)
+        .optional("window_label", FieldKind::String)
+        .optional("include_navigation", FieldKind::Bool)
+        .optional("include_resources", FieldKind::Bool)
+        .optional("include_user_timing", FieldKind::Bool)
+        .optional("include_memory", FieldKind::Bool)
+        .optional("include_long_tasks", FieldKind::Bool)
+        .optional("include_waterfall", FieldKind::Bool)
+        .optional("include_cls", FieldKind::Bool)
+        .optional("include_inp", FieldKind::Bool)
+        .optional("include_fid", FieldKind::Bool)
+        .optional("vitals_window_ms", FieldKind::Integer { min: Some(0), max: Some(30_000) })
+        .nested("resource_filter", |filter| {
+            filter
+                .optional("resource_type", FieldKind::Array)
+                .optional("min_duration_ms", FieldKind::Number { min: Some(0.0), max: None })
+                .optional("max_duration_ms", FieldKind::Number { min: Some(0.0), max: None })
+                .optional("url_pattern", FieldKind::String)
+        })
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
     let request: PerformanceMetricsRequest = serde_json::from_value(payload)
         .map_err(|e| Error::serialization_error(format!("Invalid payload for performance metrics: {}", e)))?;
 
@@ -46,35 +115,17 @@ pub async fn handle_get_performance_metrics<R: Runtime>(
         .ok_or_else(|| Error::window_not_found(&window_label))?;
 
     // Generate the introspection JavaScript code
-    let js_code = generate_performance_metrics_code(
-        request.include_navigation.unwrap_or(true),
-        request.include_resources.unwrap_or(true),
-        request.include_user_timing.unwrap_or(true),
-        request.include_memory.unwrap_or(true),
-        request.include_long_tasks.unwrap_or(false),
-        request.resource_filter.clone(),
-    );
+    let js_code = generate_performance_metrics_code(&request);
 
-    // Execute the JavaScript in the window
-    let (tx, rx) = mpsc::channel();
-
-    app.emit_to(&window_label, "execute-js", &js_code)
-        .map_err(|e| Error::communication_error_with_context(
-            "Failed to emit execute-js event",
-            format!("window: {}, error: {}", window_label, e),
-        ))?;
-
-    // Listen for response
-    app.once("execute-js-response", move |event| {
-        let payload = event.payload().to_string();
-        let _ = tx.send(payload);
-    });
-
-    // Wait for the response with timeout
-    let timeout = std::time::Duration::from_millis(request.timeout_ms.unwrap_or(10000));
-    let result_string = rx
-        .recv_timeout(timeout)
-        .map_err(|_| Error::timeout_error("performance metrics execution", request.timeout_ms.unwrap_or(10000)))?;
+    // Execute the JavaScript in the window and wait for its own response
+    let vitals_window_ms = if request.includes_web_vitals() {
+        request.vitals_window_ms.unwrap_or(DEFAULT_VITALS_WINDOW_MS)
+    } else {
+        0
+    };
+    let timeout_ms = request.timeout_ms.unwrap_or(10000).saturating_add(vitals_window_ms);
+    let result_string = request_js_response(app, &window_label, &js_code, true, std::time::Duration::from_millis(timeout_ms))
+        .map_err(|e| e.into_error(&window_label, "performance metrics execution", timeout_ms))?;
 
     // Parse the response
     let response_value: Value = serde_json::from_str(&result_string)
@@ -136,15 +187,339 @@ pub async fn handle_get_performance_metrics<R: Runtime>(
     }
 }
 
+/// Handler function for listing the scripts loaded by the page with their sizes
+pub async fn handle_get_loaded_scripts<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_loaded_scripts", &payload)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: LoadedScriptsRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_loaded_scripts: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script("Loaded scripts error", &json!({}), LOADED_SCRIPTS_JS);
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "loaded scripts",
+    )
+    .await
+}
+
+/// Handler function for estimating download and parse/execute time per script
+pub async fn handle_get_script_timing<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_script_timing", &payload)
+        .optional("observe_ms", FieldKind::Integer { min: Some(0), max: Some(10_000) })
+        .optional("top", FieldKind::Integer { min: Some(1), max: Some(100) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: ScriptTimingRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_script_timing: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let observe_ms = request.observe_ms.unwrap_or(100);
+    let js_code = build_probe_script(
+        "Script timing error",
+        &json!({
+            "observe_ms": observe_ms,
+            "top": request.top.unwrap_or(5),
+        }),
+        &format!(
+            "        const loaded = await (async () => {{\n{}\n        }})();\n{}",
+            LOADED_SCRIPTS_JS, SCRIPT_TIMING_JS
+        ),
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS).saturating_add(observe_ms),
+        "script timing",
+    )
+    .await
+}
+
+/// Handler function for clearing resource timings, marks and measures before a measurement
+pub async fn handle_clear_performance_entries<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("clear_performance_entries", &payload)
+        .optional("resources", FieldKind::Bool)
+        .optional("marks", FieldKind::Bool)
+        .optional("measures", FieldKind::Bool)
+        .optional("reset_buffer_size", FieldKind::Integer { min: Some(1), max: Some(100_000) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: ClearPerformanceEntriesRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for clear_performance_entries: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Performance entries error",
+        &json!({
+            "resources": request.resources.unwrap_or(true),
+            "marks": request.marks.unwrap_or(true),
+            "measures": request.measures.unwrap_or(true),
+            "reset_buffer_size": request.reset_buffer_size,
+        }),
+        CLEAR_PERFORMANCE_ENTRIES_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "performance entry clearing",
+    )
+    .await
+}
+
+/// Joins `<script>` tags with script resource-timing entries; modules pulled in by
+/// `import()` or static imports only show up in resource timing
+const LOADED_SCRIPTS_JS: &str = r#"        const resolve = (url) => {
+            try { return new URL(url, location.href).href; } catch (e) { return url; }
+        };
+
+        const timings = new Map();
+        performance.getEntriesByType('resource')
+            .filter(entry => entry.initiatorType === 'script' || /\.(m?js|jsx|ts|tsx)(\?|#|$)/.test(entry.name))
+            .forEach(entry => timings.set(entry.name, entry));
+
+        const tags = Array.from(document.scripts);
+        const scripts = [];
+        const seen = new Set();
+
+        const describe = (url, entry, tag) => {
+            seen.add(url);
+            const transfer = entry ? entry.transferSize || 0 : null;
+            const decoded = entry ? entry.decodedBodySize || 0 : null;
+            return {
+                url,
+                source: tag ? 'script_tag' : 'import',
+                module: tag ? tag.type === 'module' : null,
+                async: tag ? tag.async : null,
+                defer: tag ? tag.defer : null,
+                transfer_size: transfer,
+                encoded_body_size: entry ? entry.encodedBodySize || 0 : null,
+                decoded_body_size: decoded,
+                // A zero transfer size with a body means it came from the HTTP cache
+                cached: entry ? transfer === 0 && decoded > 0 : null,
+                duration_ms: entry ? entry.duration : null,
+                timing_available: entry !== undefined
+            };
+        };
+
+        tags.filter(tag => tag.src).forEach(tag => {
+            const url = resolve(tag.src);
+            if (!seen.has(url)) scripts.push(describe(url, timings.get(url), tag));
+        });
+        timings.forEach((entry, url) => {
+            if (!seen.has(url)) scripts.push(describe(url, entry, null));
+        });
+
+        const inline = tags.filter(tag => !tag.src);
+        const inlineBytes = inline.reduce((sum, tag) => sum + new Blob([tag.textContent || '']).size, 0);
+        const sum = (key) => scripts.reduce((total, s) => total + (s[key] || 0), 0);
+
+        scripts.sort((a, b) => (b.decoded_body_size || 0) - (a.decoded_body_size || 0));
+
+        return {
+            scripts,
+            external_count: scripts.length,
+            total_transfer_size: sum('transfer_size'),
+            total_decoded_size: sum('decoded_body_size'),
+            cached_count: scripts.filter(s => s.cached).length,
+            inline_count: inline.length,
+            inline_bytes: inlineBytes
+        };"#;
+
+/// Runs after `LOADED_SCRIPTS_JS`, whose result is `loaded`. Download time comes from
+/// resource timing. Parse/execute time is taken, in order of preference, from user
+/// timing measures named after the script, from long-animation-frame script
+/// attribution (evaluation of the script itself, with compile time split out), or from
+/// the first long task starting right after the download finished, which may include
+/// unrelated work. Scripts that never ran in a task over 50 ms have no estimate.
+const SCRIPT_TIMING_JS: &str = r#"        const supported = (typeof PerformanceObserver !== 'undefined' && PerformanceObserver.supportedEntryTypes) || [];
+        const collect = (type) => new Promise(resolve => {
+            if (!supported.includes(type)) {
+                resolve({ supported: false, entries: [] });
+                return;
+            }
+            const entries = [];
+            try {
+                const observer = new PerformanceObserver(list => entries.push(...list.getEntries()));
+                observer.observe({ type, buffered: true });
+                setTimeout(() => {
+                    entries.push(...observer.takeRecords());
+                    observer.disconnect();
+                    resolve({ supported: true, entries });
+                }, args.observe_ms);
+            } catch (e) {
+                resolve({ supported: false, entries });
+            }
+        });
+        const [frames, longTasks] = await Promise.all([collect('long-animation-frame'), collect('longtask')]);
+
+        const round = (ms) => ms === null || ms === undefined ? null : Math.round(ms * 10) / 10;
+        const resources = new Map(performance.getEntriesByType('resource').map(entry => [entry.name, entry]));
+        const measures = performance.getEntriesByType('measure');
+        const paint = performance.getEntriesByName('first-contentful-paint')[0] || performance.getEntriesByName('first-paint')[0] || null;
+        const firstPaint = paint ? paint.startTime : null;
+
+        // Script evaluations and callbacks attributed by long animation frames, per source URL
+        const attributed = new Map();
+        for (const frame of frames.entries) {
+            for (const script of frame.scripts || []) {
+                const key = script.sourceURL || location.href;
+                const record = attributed.get(key) || { evaluations: [], callbacks_ms: 0 };
+                if (script.invokerType === 'classic-script' || script.invokerType === 'module-script') record.evaluations.push(script);
+                else record.callbacks_ms += script.duration;
+                attributed.set(key, record);
+            }
+        }
+
+        const baseName = (url) => {
+            try {
+                const file = new URL(url).pathname.split('/').pop() || '';
+                return file.replace(/\.(m?js|jsx|ts|tsx)$/, '').replace(/[.-][0-9a-z_]{6,}$/i, '');
+            } catch (e) {
+                return '';
+            }
+        };
+
+        const timeScript = (url, info) => {
+            const entry = resources.get(url);
+            const download = entry ? {
+                start_ms: round(entry.startTime),
+                end_ms: round(entry.responseEnd),
+                download_ms: round(entry.responseEnd - entry.startTime),
+                ttfb_ms: entry.responseStart > 0 && entry.requestStart > 0 ? round(entry.responseStart - entry.requestStart) : null
+            } : null;
+            const renderBlocking = entry && entry.renderBlockingStatus ? entry.renderBlockingStatus === 'blocking' : null;
+
+            const record = attributed.get(url);
+            const name = baseName(url);
+            const ownMeasures = name.length >= 3 ? measures.filter(measure => measure.name.includes(name)) : [];
+
+            let execute = null;
+            if (ownMeasures.length > 0) {
+                execute = {
+                    method: 'user_timing',
+                    execute_ms: round(ownMeasures.reduce((sum, measure) => sum + measure.duration, 0)),
+                    measures: ownMeasures.map(measure => ({ name: measure.name, start_ms: round(measure.startTime), duration_ms: round(measure.duration) }))
+                };
+            } else if (record && record.evaluations.length > 0) {
+                const compile = record.evaluations.reduce((sum, script) => sum + (script.executionStart > script.startTime ? script.executionStart - script.startTime : 0), 0);
+                const total = record.evaluations.reduce((sum, script) => sum + script.duration, 0);
+                execute = {
+                    method: 'long_animation_frame',
+                    execute_ms: round(total),
+                    compile_ms: round(compile),
+                    forced_layout_ms: round(record.evaluations.reduce((sum, script) => sum + (script.forcedStyleAndLayoutDuration || 0), 0)),
+                    started_ms: round(record.evaluations[0].startTime)
+                };
+            } else if (entry) {
+                const task = longTasks.entries.find(task => task.startTime >= entry.responseEnd - 1 && task.startTime <= entry.responseEnd + 50);
+                if (task) execute = { method: 'long_task_after_download', execute_ms: round(task.duration), started_ms: round(task.startTime) };
+            }
+
+            const executeMs = execute ? execute.execute_ms : null;
+            const kb = info && info.decoded_body_size ? info.decoded_body_size / 1024 : null;
+            const endsAt = execute && execute.started_ms !== undefined ? execute.started_ms + executeMs : download ? download.end_ms : null;
+            let suggestion = null;
+            if (executeMs !== null && executeMs >= 50) {
+                suggestion = renderBlocking || (info && info.source === 'script_tag' && !info.async && !info.defer && !info.module)
+                    ? 'Blocks rendering and takes long to run; load it with defer/async or split it'
+                    : 'Takes long to run; consider code-splitting or loading it later';
+            }
+            return {
+                url,
+                ...(info ? { source: info.source, module: info.module, async: info.async, defer: info.defer, transfer_size: info.transfer_size, decoded_body_size: info.decoded_body_size, cached: info.cached } : {}),
+                download,
+                render_blocking: renderBlocking,
+                ...(execute || { method: null, execute_ms: null }),
+                later_callbacks_ms: record ? round(record.callbacks_ms) : null,
+                execute_ms_per_100kb: executeMs !== null && kb ? round(executeMs / (kb / 100)) : null,
+                finished_before_first_paint: firstPaint !== null && endsAt !== null ? endsAt <= firstPaint : null,
+                suggestion
+            };
+        };
+
+        const scripts = loaded.scripts.map(info => timeScript(info.url, info));
+        const inlineRecord = attributed.get(location.href);
+        if (inlineRecord && inlineRecord.evaluations.length > 0) {
+            const inline = timeScript(location.href, null);
+            inline.url = 'inline scripts';
+            scripts.push(inline);
+        }
+        scripts.sort((a, b) => (b.execute_ms ?? -1) - (a.execute_ms ?? -1));
+
+        const estimated = scripts.filter(script => script.execute_ms !== null);
+        return {
+            first_paint_ms: round(firstPaint),
+            apis: {
+                long_animation_frame: frames.supported,
+                longtask: longTasks.supported,
+                render_blocking_status: performance.getEntriesByType('resource').some(entry => 'renderBlockingStatus' in entry)
+            },
+            totals: {
+                scripts: scripts.length,
+                with_execute_estimate: estimated.length,
+                execute_ms: round(estimated.reduce((sum, script) => sum + script.execute_ms, 0)),
+                transfer_size: loaded.total_transfer_size,
+                decoded_size: loaded.total_decoded_size
+            },
+            heaviest: estimated.slice(0, args.top).map(script => ({ url: script.url, execute_ms: script.execute_ms, method: script.method, decoded_body_size: script.decoded_body_size ?? null, suggestion: script.suggestion })),
+            scripts,
+            caveats: [
+                'Only scripts that ran inside a task or frame longer than 50 ms can be attributed; a null execute_ms usually means the script was cheap.',
+                'long_task_after_download estimates may include other work that ran in the same task.',
+                'Resource timing is limited to the last 250 entries unless the buffer was enlarged with clear_performance_entries.'
+            ]
+        };"#;
+
 /// Generate the JavaScript code to collect performance metrics
-fn generate_performance_metrics_code(
-    include_navigation: bool,
-    include_resources: bool,
-    include_user_timing: bool,
-    include_memory: bool,
-    include_long_tasks: bool,
-    resource_filter: Option<ResourceFilter>,
-) -> String {
+fn generate_performance_metrics_code(request: &PerformanceMetricsRequest) -> String {
+    let include_navigation = request.include_navigation.unwrap_or(true);
+    let include_resources = request.include_resources.unwrap_or(true);
+    let include_user_timing = request.include_user_timing.unwrap_or(true);
+    let include_memory = request.include_memory.unwrap_or(true);
+    let include_long_tasks = request.include_long_tasks.unwrap_or(false);
+    let include_waterfall = request.include_waterfall.unwrap_or(false);
+    let resource_filter = request.resource_filter.clone();
+
     let mut code = String::from(
         r#"(async () => {
     try {
@@ -235,6 +610,7 @@ fn generate_performance_metrics_code(
             if (resources.length > 0) {
                 const resourcesByType = {};
                 const allResources = [];
+                const waterfallTimings = [];
 
                 resources.forEach(resource => {
                     const resourceType = resource.initiatorType || 'other';
@@ -318,7 +694,18 @@ fn generate_performance_metrics_code(
 
                     resourcesByType[resourceType].push(resourceEntry);
                     allResources.push(resourceEntry);
-                });
+"#,
+        );
+
+        if include_waterfall {
+            code.push_str(
+                r#"                    waterfallTimings.push({ entry: resourceEntry, timing: resource });
+"#,
+            );
+        }
+
+        code.push_str(
+            r#"                });
 
                 metrics.resource_timing = {
                     by_type: resourcesByType,
@@ -331,7 +718,50 @@ fn generate_performance_metrics_code(
                     },
                     resources: allResources.slice(0, 100) // Limit to first 100 for performance
                 };
-            }
+"#,
+        );
+
+        if include_waterfall {
+            code.push_str(
+                r#"
+                // Waterfall view: resources in request order with offsets normalized to the whole span
+                const waterfallStart = Math.min(...waterfallTimings.map(w => w.timing.startTime), Infinity);
+                const waterfallEnd = Math.max(...waterfallTimings.map(w => w.timing.responseEnd), 0);
+                const waterfallSpan = Math.max(waterfallEnd - (isFinite(waterfallStart) ? waterfallStart : 0), 1);
+
+                metrics.resource_timing.waterfall = {
+                    start_time_ms: isFinite(waterfallStart) ? waterfallStart : 0,
+                    end_time_ms: waterfallEnd,
+                    span_ms: waterfallEnd - (isFinite(waterfallStart) ? waterfallStart : 0),
+                    resources: waterfallTimings
+                        .sort((a, b) => a.timing.startTime - b.timing.startTime)
+                        .slice(0, 100)
+                        .map(({ entry, timing }) => {
+                            // Detailed timings are zero for cross-origin resources without Timing-Allow-Origin
+                            const detailed = timing.requestStart > 0;
+                            const offset = timing.startTime - waterfallStart;
+                            return {
+                                name: entry.name,
+                                type: entry.type,
+                                start_time_ms: entry.start_time_ms,
+                                duration_ms: entry.duration_ms,
+                                ttfb_ms: detailed ? timing.responseStart - timing.requestStart : null,
+                                blocking_ms: detailed
+                                    ? Math.max(0, timing.requestStart - timing.startTime - entry.dns_lookup_ms - entry.tcp_connection_ms)
+                                    : null,
+                                offset_ms: offset,
+                                offset_ratio: offset / waterfallSpan,
+                                duration_ratio: entry.duration_ms / waterfallSpan,
+                                cache_behavior: entry.cache_behavior
+                            };
+                        })
+                };
+"#,
+            );
+        }
+
+        code.push_str(
+            r#"            }
         } catch (e) {
             errors.push(`Error collecting resource timing: ${e.message}`);
         }
@@ -505,7 +935,25 @@ fn generate_performance_metrics_code(
         } catch (e) {
             errors.push(`Error collecting LCP: ${e.message}`);
         }
+"#,
+    );
+
+    // 8. Core Web Vitals from buffered observers
+    if request.includes_web_vitals() {
+        code.push_str(&format!(
+            "        const vitalsConfig = {};\n",
+            json!({
+                "cls": request.include_cls.unwrap_or(false),
+                "inp": request.include_inp.unwrap_or(false),
+                "fid": request.include_fid.unwrap_or(false),
+                "window_ms": request.vitals_window_ms.unwrap_or(DEFAULT_VITALS_WINDOW_MS),
+            })
+        ));
+        code.push_str(WEB_VITALS_JS);
+    }
 
+    code.push_str(
+        r#"
         // Final response
         const response = {
             metrics: metrics,
@@ -515,6 +963,7 @@ fn generate_performance_metrics_code(
 
         window.dispatchEvent(new CustomEvent('execute-js-response', {
             detail: {
+                request_id: typeof __mcpRequestId === 'undefined' ? null : __mcpRequestId,
                 result: JSON.stringify(response),
                 type: 'object'
             }
@@ -522,6 +971,7 @@ fn generate_performance_metrics_code(
     } catch (error) {
         window.dispatchEvent(new CustomEvent('execute-js-response', {
             detail: {
+                request_id: typeof __mcpRequestId === 'undefined' ? null : __mcpRequestId,
                 error: 'Performance metrics collection error: ' + error.message,
                 type: 'error'
             }
@@ -532,3 +982,118 @@ fn generate_performance_metrics_code(
 
     code
 }
+
+const DEFAULT_VITALS_WINDOW_MS: u64 = 500;
+
+/// CLS sums layout shifts without recent input over the page's life rather than taking the
+/// largest session window, so it can read higher than field tools on long-lived pages.
+/// INP follows the field definition: the worst interaction, skipping one per 50 interactions.
+/// Ratings use the web.dev thresholds.
+const WEB_VITALS_JS: &str = r#"        try {
+            const rate = (value, good, poor) => value === null ? null : value <= good ? 'good' : value <= poor ? 'needs-improvement' : 'poor';
+            const observers = [];
+            const observe = (type, onEntries, options) => {
+                try {
+                    const observer = new PerformanceObserver(list => onEntries(list.getEntries()));
+                    observer.observe({ type, buffered: true, ...options });
+                    observers.push({ observer, onEntries });
+                } catch (e) {
+                    errors.push(`${type} observer unavailable: ${e.message}`);
+                }
+            };
+
+            let layoutShift = 0;
+            let shiftCount = 0;
+            if (vitalsConfig.cls) {
+                observe('layout-shift', entries => entries.forEach(entry => {
+                    if (!entry.hadRecentInput) {
+                        layoutShift += entry.value;
+                        shiftCount++;
+                    }
+                }));
+            }
+
+            const interactions = new Map();
+            if (vitalsConfig.inp) {
+                observe('event', entries => entries.forEach(entry => {
+                    if (!entry.interactionId) return;
+                    const worst = interactions.get(entry.interactionId);
+                    if (!worst || entry.duration > worst.duration) interactions.set(entry.interactionId, entry);
+                }), { durationThreshold: 16 });
+            }
+
+            let firstInput = null;
+            if (vitalsConfig.inp || vitalsConfig.fid) {
+                observe('first-input', entries => entries.forEach(entry => {
+                    if (!firstInput) firstInput = entry;
+                    if (entry.interactionId && !interactions.has(entry.interactionId)) interactions.set(entry.interactionId, entry);
+                }));
+            }
+
+            await new Promise(resolve => setTimeout(resolve, vitalsConfig.window_ms));
+            observers.forEach(({ observer, onEntries }) => {
+                onEntries(observer.takeRecords());
+                observer.disconnect();
+            });
+
+            const webVitals = { collected_over_ms: vitalsConfig.window_ms };
+            if (vitalsConfig.cls) {
+                const value = Math.round(layoutShift * 10000) / 10000;
+                webVitals.cls = { value, rating: rate(value, 0.1, 0.25), shift_count: shiftCount };
+            }
+            if (vitalsConfig.inp) {
+                const durations = [...interactions.values()].map(entry => entry.duration).sort((a, b) => b - a);
+                const value = durations.length ? durations[Math.min(Math.floor(durations.length / 50), durations.length - 1)] : null;
+                webVitals.inp = { value_ms: value, rating: rate(value, 200, 500), interaction_count: durations.length };
+            }
+            if (vitalsConfig.fid) {
+                const value = firstInput ? Math.round((firstInput.processingStart - firstInput.startTime) * 100) / 100 : null;
+                webVitals.fid = {
+                    value_ms: value,
+                    rating: rate(value, 100, 300),
+                    event_type: firstInput ? firstInput.name : null
+                };
+            }
+            metrics.web_vitals = webVitals;
+        } catch (e) {
+            errors.push(`Error collecting web vitals: ${e.message}`);
+        }
+"#;
+
+/// Navigation and paint entries cannot be cleared and stay in the timeline
+const CLEAR_PERFORMANCE_ENTRIES_JS: &str = r#"        const count = (type) => performance.getEntriesByType(type).length;
+        const cleared = {};
+
+        if (args.resources) {
+            cleared.resources = count('resource');
+            performance.clearResourceTimings();
+        }
+        if (args.marks) {
+            cleared.marks = count('mark');
+            performance.clearMarks();
+        }
+        if (args.measures) {
+            cleared.measures = count('measure');
+            performance.clearMeasures();
+        }
+
+        let bufferSize = null;
+        if (args.reset_buffer_size) {
+            if (typeof performance.setResourceTimingBufferSize === 'function') {
+                performance.setResourceTimingBufferSize(args.reset_buffer_size);
+                bufferSize = args.reset_buffer_size;
+            } else {
+                bufferSize = 'unsupported';
+            }
+        }
+
+        return {
+            cleared,
+            resource_buffer_size: bufferSize,
+            cleared_at_ms: performance.now(),
+            remaining: {
+                resources: count('resource'),
+                marks: count('mark'),
+                measures: count('measure')
+            }
+        };"#;
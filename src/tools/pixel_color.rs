@@ -0,0 +1,157 @@
+use image::{DynamicImage, GenericImageView};
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::models::ScreenshotRequest;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, eval_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::take_screenshot::decode_data_url;
+use crate::tools::validation::{FieldKind, PayloadValidator};
+use crate::TauriMcpExt;
+
+/// Largest sampling radius in CSS pixels
+const MAX_RADIUS: i64 = 50;
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct GetPixelColorRequest {
+    window_label: Option<String>,
+    /// Point in CSS pixels relative to the viewport
+    x: f64,
+    y: f64,
+    /// Average over a circle of this radius in CSS pixels (default 0, a single pixel)
+    radius: Option<u32>,
+}
+
+/// Handler function for reading the rendered color at a viewport point
+pub async fn handle_get_pixel_color<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_pixel_color", &payload)
+        .required("x", FieldKind::Number { min: Some(0.0), max: None })
+        .required("y", FieldKind::Number { min: Some(0.0), max: None })
+        .optional("radius", FieldKind::Integer { min: Some(0), max: Some(MAX_RADIUS) })
+        .optional("window_label", FieldKind::String)
+        .finish()?;
+
+    let request: GetPixelColorRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_pixel_color: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    // The fallback capture path for other Unix systems cannot return the rendered page
+    if !cfg!(any(target_os = "macos", target_os = "windows", target_os = "linux")) {
+        return Ok(SocketResponse {
+            success: false,
+            data: None,
+            error: Some("get_pixel_color needs a native window capture, which is only available on macOS, Windows and Linux".to_string()),
+        });
+    }
+
+    let js_code = build_probe_script("Viewport error", &json!({}), VIEWPORT_JS);
+    let viewport = eval_probe(app, &window_label, js_code, DEFAULT_PROBE_TIMEOUT_MS, "viewport lookup").await?;
+    let get = |key: &str| viewport.get(key).and_then(|v| v.as_f64()).unwrap_or(0.0);
+    let (viewport_width, viewport_height) = (get("width"), get("height"));
+
+    if request.x >= viewport_width || request.y >= viewport_height {
+        return Err(Error::invalid_parameter(
+            "x/y",
+            format!("a point inside the {}x{} viewport", viewport_width, viewport_height),
+            format!("({}, {})", request.x, request.y),
+        ));
+    }
+
+    let response = app
+        .tauri_mcp()
+        .take_screenshot_async(ScreenshotRequest {
+            window_label: window_label.clone(),
+            target_dpr: None,
+            lossless: true,
+            region: None,
+            format: None,
+            quality: None,
+        })
+        .await?;
+    let data_url = match (response.success, response.data) {
+        (true, Some(data)) => data,
+        _ => {
+            return Err(Error::window_operation_failed(
+                "pixel capture",
+                response.error.unwrap_or_else(|| "Screenshot returned no data".to_string()),
+            ))
+        }
+    };
+    let image = decode_data_url(&data_url)?;
+
+    // Same mapping as baseline crops: the viewport is the bottom of the capture,
+    // below any title bar, scaled to the image width
+    let scale = image.width() as f64 / viewport_width.max(1.0);
+    let offset_y = (image.height() as f64 - viewport_height * scale).max(0.0);
+    let center_x = (request.x * scale).floor() as i64;
+    let center_y = (request.y * scale + offset_y).floor() as i64;
+    let radius = request.radius.unwrap_or(0);
+
+    let (rgba, samples) = average_color(&image, center_x, center_y, (radius as f64 * scale).round() as i64);
+    if samples == 0 {
+        return Err(Error::window_operation_failed(
+            "pixel capture",
+            format!("Point ({}, {}) falls outside the {}x{} capture", request.x, request.y, image.width(), image.height()),
+        ));
+    }
+    let [r, g, b, a] = rgba;
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(json!({
+            "x": request.x,
+            "y": request.y,
+            "radius": radius,
+            "rgba": { "r": r, "g": g, "b": b, "a": a },
+            "hex": format!("#{:02x}{:02x}{:02x}", r, g, b),
+            "hex_with_alpha": format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a),
+            "sample_count": samples,
+            "image_point": { "x": center_x, "y": center_y },
+            "scale": scale,
+            "device_pixel_ratio": viewport.get("device_pixel_ratio"),
+        })),
+        error: None,
+    })
+}
+
+/// Mean color of the pixels within `radius` of the center, rounded per channel.
+/// Pixels outside the image are skipped.
+fn average_color(image: &DynamicImage, center_x: i64, center_y: i64, radius: i64) -> ([u8; 4], u64) {
+    let mut sums = [0u64; 4];
+    let mut samples = 0u64;
+    for dy in -radius..=radius {
+        for dx in -radius..=radius {
+            if dx * dx + dy * dy > radius * radius {
+                continue;
+            }
+            let (x, y) = (center_x + dx, center_y + dy);
+            if x < 0 || y < 0 || x >= image.width() as i64 || y >= image.height() as i64 {
+                continue;
+            }
+            let pixel = image.get_pixel(x as u32, y as u32).0;
+            for (sum, channel) in sums.iter_mut().zip(pixel) {
+                *sum += channel as u64;
+            }
+            samples += 1;
+        }
+    }
+    if samples == 0 {
+        return ([0; 4], 0);
+    }
+    (sums.map(|sum| ((sum as f64) / samples as f64).round() as u8), samples)
+}
+
+const VIEWPORT_JS: &str = r#"        return {
+            width: window.innerWidth,
+            height: window.innerHeight,
+            device_pixel_ratio: window.devicePixelRatio
+        };"#;
@@ -0,0 +1,335 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct CapturePostMessageRequest {
+    window_label: Option<String>,
+    /// Messages kept before the oldest are dropped (default 1000)
+    max_entries: Option<usize>,
+    /// Characters of serialized message data kept per entry (default 2000)
+    max_data_chars: Option<usize>,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct GetPostMessagesRequest {
+    window_label: Option<String>,
+    /// "in" or "out"
+    direction: Option<String>,
+    /// "window", "worker", "port", "broadcast" or "service_worker"
+    channel: Option<String>,
+    /// Only messages whose origin contains this text
+    origin: Option<String>,
+    /// Only messages with a greater id
+    since_id: Option<u64>,
+    /// Maximum number of messages to return, newest last (default 100)
+    limit: Option<usize>,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ClearPostMessagesRequest {
+    window_label: Option<String>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for installing the postMessage recorder in a window
+pub async fn handle_capture_postmessage<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("capture_postmessage", &payload)
+        .optional("max_entries", FieldKind::Integer { min: Some(1), max: Some(100_000) })
+        .optional("max_data_chars", FieldKind::Integer { min: Some(0), max: Some(1_000_000) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: CapturePostMessageRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for capture_postmessage: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "postMessage capture error",
+        &json!({
+            "max_entries": request.max_entries.unwrap_or(1000),
+            "max_data_chars": request.max_data_chars.unwrap_or(2000),
+        }),
+        CAPTURE_POSTMESSAGE_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "postMessage capture",
+    )
+    .await
+}
+
+/// Handler function for reading recorded postMessage traffic
+pub async fn handle_get_postmessages<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_postmessages", &payload)
+        .optional("direction", FieldKind::OneOf(&["in", "out"]))
+        .optional("channel", FieldKind::OneOf(&["window", "worker", "port", "broadcast", "service_worker"]))
+        .optional("origin", FieldKind::String)
+        .optional("since_id", FieldKind::UINT)
+        .optional("limit", FieldKind::Integer { min: Some(1), max: Some(100_000) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: GetPostMessagesRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_postmessages: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "postMessage read error",
+        &json!({
+            "direction": request.direction,
+            "channel": request.channel,
+            "origin": request.origin,
+            "since_id": request.since_id.unwrap_or(0),
+            "limit": request.limit.unwrap_or(100),
+        }),
+        GET_POSTMESSAGES_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "postMessage read",
+    )
+    .await
+}
+
+/// Handler function for emptying the postMessage buffer
+pub async fn handle_clear_postmessages<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("clear_postmessages", &payload)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: ClearPostMessagesRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for clear_postmessages: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script("postMessage clear error", &json!({}), CLEAR_POSTMESSAGES_JS);
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "postMessage clear",
+    )
+    .await
+}
+
+/// Outgoing messages are recorded by wrapping `postMessage` on the window and on the
+/// Worker, MessagePort, BroadcastChannel and ServiceWorker prototypes. Incoming window
+/// messages come from a capture-phase listener; every other channel gets its own
+/// listener the first time the page posts on it or listens to it, so only channels
+/// touched after installation are seen. A navigation discards the recorder.
+const CAPTURE_POSTMESSAGE_JS: &str = r#"        const existing = window.__MCP_POSTMESSAGE__;
+        if (existing) {
+            existing.max_entries = args.max_entries;
+            existing.max_data_chars = args.max_data_chars;
+            return { installed: true, already_installed: true, installed_at_ms: existing.installed_at_ms, buffered: existing.entries.length };
+        }
+
+        const store = {
+            installed_at_ms: Date.now(),
+            next_id: 1,
+            dropped: 0,
+            entries: [],
+            max_entries: args.max_entries,
+            max_data_chars: args.max_data_chars
+        };
+        window.__MCP_POSTMESSAGE__ = store;
+
+        const serialize = (data) => {
+            const seen = new WeakSet();
+            let text;
+            try {
+                text = JSON.stringify(data, (key, value) => {
+                    if (value instanceof ArrayBuffer) return '[ArrayBuffer ' + value.byteLength + ' bytes]';
+                    if (ArrayBuffer.isView(value)) return '[' + value.constructor.name + ' ' + value.byteLength + ' bytes]';
+                    if (typeof Blob !== 'undefined' && value instanceof Blob) return '[Blob ' + value.size + ' bytes]';
+                    if (typeof MessagePort !== 'undefined' && value instanceof MessagePort) return '[MessagePort]';
+                    if (value instanceof Map) return { '[Map]': Array.from(value.entries()) };
+                    if (value instanceof Set) return { '[Set]': Array.from(value) };
+                    if (typeof value === 'bigint') return value.toString() + 'n';
+                    if (value && typeof value === 'object') {
+                        if (seen.has(value)) return '[Circular]';
+                        seen.add(value);
+                    }
+                    return value;
+                });
+            } catch (error) {
+                text = '[Unserializable: ' + error.message + ']';
+            }
+            if (text === undefined) text = String(data);
+            const truncated = text.length > store.max_data_chars;
+            return { data: truncated ? text.substring(0, store.max_data_chars) : text, truncated, size: text.length };
+        };
+
+        const describeWindow = (source) => {
+            if (!source) return null;
+            if (source === window) return 'self';
+            if (source === window.parent) return 'parent';
+            if (source === window.opener) return 'opener';
+            for (const frame of document.querySelectorAll('iframe, frame')) {
+                if (frame.contentWindow === source) return 'iframe ' + mcp.cssPath(frame);
+            }
+            return 'other window';
+        };
+
+        const record = (direction, channel, fields, data) => {
+            const entry = {
+                id: store.next_id++,
+                timestamp_ms: Date.now(),
+                direction,
+                channel,
+                ...fields,
+                data_type: data === null ? 'null' : Array.isArray(data) ? 'array' : typeof data,
+                ...serialize(data)
+            };
+            store.entries.push(entry);
+            if (store.entries.length > store.max_entries) {
+                const excess = store.entries.length - store.max_entries;
+                store.entries.splice(0, excess);
+                store.dropped += excess;
+            }
+        };
+
+        // Incoming window messages, before any page listener can stop propagation
+        window.addEventListener('message', (event) => {
+            record('in', 'window', {
+                origin: event.origin,
+                source: describeWindow(event.source),
+                ports: event.ports ? event.ports.length : 0
+            }, event.data);
+        }, true);
+
+        const originalWindowPost = window.postMessage;
+        window.postMessage = function (message, targetOrOptions, transfer) {
+            const targetOrigin = typeof targetOrOptions === 'string' ? targetOrOptions : (targetOrOptions && targetOrOptions.targetOrigin) || '/';
+            record('out', 'window', { origin: targetOrigin, target: 'self' }, message);
+            return originalWindowPost.apply(this, arguments);
+        };
+
+        const originalAdd = EventTarget.prototype.addEventListener;
+        const observed = new WeakSet();
+        const observe = (target, channel, label) => {
+            if (!target || observed.has(target)) return;
+            observed.add(target);
+            // addEventListener does not start a MessagePort, so this listener is passive
+            originalAdd.call(target, 'message', (event) => {
+                record('in', channel, { origin: event.origin || null, source: label(target) }, event.data);
+            });
+        };
+
+        const channels = [
+            ['worker', typeof Worker !== 'undefined' && Worker, (t) => 'worker'],
+            ['port', typeof MessagePort !== 'undefined' && MessagePort, (t) => 'port'],
+            ['broadcast', typeof BroadcastChannel !== 'undefined' && BroadcastChannel, (t) => 'broadcast ' + t.name],
+            ['service_worker', typeof ServiceWorker !== 'undefined' && ServiceWorker, (t) => 'service worker ' + t.scriptURL]
+        ].filter(([, ctor]) => ctor && ctor.prototype && typeof ctor.prototype.postMessage === 'function');
+
+        const patched = ['window'];
+        for (const [channel, ctor, label] of channels) {
+            const proto = ctor.prototype;
+            const originalPost = proto.postMessage;
+            proto.postMessage = function (message) {
+                record('out', channel, { target: label(this) }, message);
+                // Service workers answer through navigator.serviceWorker, not the worker object
+                if (channel !== 'service_worker') observe(this, channel, label);
+                return originalPost.apply(this, arguments);
+            };
+            const originalProtoAdd = proto.addEventListener || originalAdd;
+            proto.addEventListener = function (type) {
+                if (type === 'message') observe(this, channel, label);
+                return originalProtoAdd.apply(this, arguments);
+            };
+            const onmessage = Object.getOwnPropertyDescriptor(proto, 'onmessage');
+            if (onmessage && onmessage.set) {
+                Object.defineProperty(proto, 'onmessage', {
+                    ...onmessage,
+                    set(handler) {
+                        observe(this, channel, label);
+                        return onmessage.set.call(this, handler);
+                    }
+                });
+            }
+            patched.push(channel);
+        }
+        if (navigator.serviceWorker) observe(navigator.serviceWorker, 'service_worker', () => 'service worker');
+
+        return {
+            installed: true,
+            already_installed: false,
+            installed_at_ms: store.installed_at_ms,
+            channels: patched,
+            note: 'Only traffic after installation is recorded; worker, port and broadcast replies are seen once the page posts on or listens to that channel'
+        };"#;
+
+const GET_POSTMESSAGES_JS: &str = r#"        const store = window.__MCP_POSTMESSAGE__;
+        if (!store) {
+            return { installed: false, messages: [], note: 'postMessage capture is not installed in this window; call capture_postmessage first' };
+        }
+
+        const matches = store.entries.filter(entry =>
+            entry.id > args.since_id &&
+            (!args.direction || entry.direction === args.direction) &&
+            (!args.channel || entry.channel === args.channel) &&
+            (!args.origin || (entry.origin || '').includes(args.origin))
+        );
+        const messages = matches.slice(-args.limit);
+
+        return {
+            installed: true,
+            installed_at_ms: store.installed_at_ms,
+            total_buffered: store.entries.length,
+            matched: matches.length,
+            returned: messages.length,
+            dropped: store.dropped,
+            last_id: store.next_id - 1,
+            messages
+        };"#;
+
+const CLEAR_POSTMESSAGES_JS: &str = r#"        const store = window.__MCP_POSTMESSAGE__;
+        if (!store) return { installed: false, cleared: 0 };
+        const cleared = store.entries.length;
+        store.entries = [];
+        store.dropped = 0;
+        return { installed: true, cleared };"#;
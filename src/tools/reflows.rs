@@ -0,0 +1,192 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct MeasureReflowsRequest {
+    window_label: Option<String>,
+    /// "measure" (default) instruments for `duration_ms` and reports, "start" leaves the
+    /// instrumentation running so other commands can drive the UI, "stop" reports and removes it
+    action: Option<String>,
+    /// Measurement window for "measure" (default 2000)
+    duration_ms: Option<u64>,
+    /// Number of distinct stacks to report (default 10)
+    top_stacks: Option<usize>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for counting forced synchronous layouts
+pub async fn handle_measure_reflows<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("measure_reflows", &payload)
+        .optional("action", FieldKind::OneOf(&["measure", "start", "stop"]))
+        .optional("duration_ms", FieldKind::Integer { min: Some(1), max: Some(60_000) })
+        .optional("top_stacks", FieldKind::Integer { min: Some(1), max: Some(100) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: MeasureReflowsRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for measure_reflows: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let action = request.action.clone().unwrap_or_else(|| "measure".to_string());
+    let duration_ms = if action == "measure" {
+        request.duration_ms.unwrap_or(2000)
+    } else {
+        0
+    };
+
+    let js_code = build_probe_script(
+        "Reflow measurement error",
+        &json!({
+            "action": action,
+            "duration_ms": duration_ms,
+            "top_stacks": request.top_stacks.unwrap_or(10),
+        }),
+        MEASURE_REFLOWS_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS).saturating_add(duration_ms),
+        "reflow measurement",
+    )
+    .await
+}
+
+/// Wraps the layout-reading APIs and counts reads that happen while the DOM has pending
+/// mutations. `MutationObserver.takeRecords()` is synchronous, so a read right after a
+/// write in the same task is caught; style sheet changes that bypass the DOM are not.
+const MEASURE_REFLOWS_JS: &str = r#"        const start = () => {
+            const state = {
+                started_at: performance.now(),
+                reads: 0,
+                forced: 0,
+                by_property: {},
+                stacks: new Map(),
+                frames: 0,
+                mutated_frames: 0,
+                dirty: false,
+                frame_dirty: false,
+                restore: [],
+                running: true
+            };
+
+            const observer = new MutationObserver(() => mcp.timed('reflows', () => { state.dirty = true; state.frame_dirty = true; }, false));
+            observer.observe(document, { attributes: true, childList: true, subtree: true, characterData: true });
+            state.observer = observer;
+
+            const onRead = (name) => mcp.timed('reflows', function __mcpReflowRead() {
+                state.reads += 1;
+                if (observer.takeRecords().length > 0) {
+                    state.dirty = true;
+                    state.frame_dirty = true;
+                }
+                if (!state.dirty) return;
+                // The engine had to lay out synchronously to answer this read
+                state.dirty = false;
+                state.forced += 1;
+                state.by_property[name] = (state.by_property[name] || 0) + 1;
+                const stack = (new Error().stack || '').split('\n')
+                    .map(line => line.trim())
+                    .filter(line => line && line !== 'Error' && !line.includes('__mcpReflow'))
+                    // Skips the mcp.timed and onRead frames
+                    .slice(2, 7)
+                    .join('\n');
+                const entry = state.stacks.get(stack) || { stack, count: 0, properties: {} };
+                entry.count += 1;
+                entry.properties[name] = (entry.properties[name] || 0) + 1;
+                state.stacks.set(stack, entry);
+            });
+
+            const wrapGetter = (proto, name) => {
+                const descriptor = Object.getOwnPropertyDescriptor(proto, name);
+                if (!descriptor || !descriptor.get || !descriptor.configurable) return;
+                Object.defineProperty(proto, name, {
+                    ...descriptor,
+                    get: function __mcpReflowGetter() { onRead(name); return descriptor.get.call(this); }
+                });
+                state.restore.push(() => Object.defineProperty(proto, name, descriptor));
+            };
+            const wrapMethod = (owner, name) => {
+                const original = owner[name];
+                if (typeof original !== 'function') return;
+                owner[name] = function __mcpReflowMethod(...callArgs) { onRead(name); return original.apply(this, callArgs); };
+                state.restore.push(() => { owner[name] = original; });
+            };
+
+            ['offsetWidth', 'offsetHeight', 'offsetTop', 'offsetLeft', 'offsetParent', 'innerText']
+                .forEach(name => wrapGetter(HTMLElement.prototype, name));
+            ['clientWidth', 'clientHeight', 'clientTop', 'clientLeft', 'scrollWidth', 'scrollHeight', 'scrollTop', 'scrollLeft']
+                .forEach(name => wrapGetter(Element.prototype, name));
+            ['getBoundingClientRect', 'getClientRects'].forEach(name => wrapMethod(Element.prototype, name));
+            wrapMethod(window, 'getComputedStyle');
+
+            // Layout is clean again once a frame has been rendered
+            const sample = () => {
+                if (!state.running) return;
+                state.frames += 1;
+                if (state.frame_dirty) state.mutated_frames += 1;
+                state.frame_dirty = false;
+                state.dirty = false;
+                requestAnimationFrame(sample);
+            };
+            requestAnimationFrame(sample);
+
+            window.__MCP_REFLOW__ = state;
+            return state;
+        };
+
+        const stop = (state) => {
+            state.running = false;
+            state.observer.disconnect();
+            state.restore.reverse().forEach(restore => restore());
+            delete window.__MCP_REFLOW__;
+
+            const duration = performance.now() - state.started_at;
+            return {
+                duration_ms: duration,
+                forced_reflow_count: state.forced,
+                layout_reads: state.reads,
+                forced_per_second: duration > 0 ? state.forced / (duration / 1000) : 0,
+                by_property: state.by_property,
+                frames: state.frames,
+                frames_with_mutations: state.mutated_frames,
+                top_stacks: Array.from(state.stacks.values())
+                    .sort((a, b) => b.count - a.count)
+                    .slice(0, args.top_stacks),
+                approximate: true
+            };
+        };
+
+        const existing = window.__MCP_REFLOW__;
+
+        if (args.action === 'start') {
+            if (existing) stop(existing);
+            start();
+            return { running: true };
+        }
+
+        if (args.action === 'stop') {
+            if (!existing) throw new Error('Reflow measurement is not running; call with action "start" first');
+            return stop(existing);
+        }
+
+        if (existing) stop(existing);
+        const state = start();
+        await new Promise(resolve => setTimeout(resolve, args.duration_ms));
+        return stop(state);"#;
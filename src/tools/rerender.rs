@@ -0,0 +1,318 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ForceRerenderRequest {
+    window_label: Option<String>,
+    /// "auto" (default), "react" or "vue"
+    framework: Option<String>,
+    /// DOM element whose owning component is re-rendered
+    selector: Option<String>,
+    /// Display name of the component to re-render (first match)
+    component_name: Option<String>,
+    /// Vue component uid, as reported by `devtools_bridge`
+    component_id: Option<String>,
+    /// Time to let the framework flush after forcing (default 100)
+    settle_ms: Option<u64>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for forcing a React or Vue component to re-render
+pub async fn handle_force_rerender<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("force_rerender", &payload)
+        .optional("framework", FieldKind::OneOf(&["auto", "react", "vue"]))
+        .optional("selector", FieldKind::NonEmptyString)
+        .optional("component_name", FieldKind::NonEmptyString)
+        .optional("component_id", FieldKind::NonEmptyString)
+        .optional("settle_ms", FieldKind::Integer { min: Some(0), max: Some(10_000) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: ForceRerenderRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for force_rerender: {}", e)))?;
+
+    let targets = [&request.selector, &request.component_name, &request.component_id]
+        .iter()
+        .filter(|target| target.is_some())
+        .count();
+    if targets > 1 {
+        return Err(Error::invalid_parameter(
+            "selector/component_name/component_id",
+            "at most one target (none re-renders the root component)",
+            format!("{} targets", targets),
+        ));
+    }
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let settle_ms = request.settle_ms.unwrap_or(100);
+
+    let js_code = build_probe_script(
+        "Force re-render error",
+        &json!({
+            "framework": request.framework.clone().unwrap_or_else(|| "auto".to_string()),
+            "selector": request.selector,
+            "component_name": request.component_name,
+            "component_id": request.component_id,
+            "settle_ms": settle_ms,
+        }),
+        FORCE_RERENDER_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS).saturating_add(settle_ms),
+        "forced re-render",
+    )
+    .await
+}
+
+/// React: commits are counted by wrapping the devtools hook's `onCommitFiberRoot`, and
+/// the update is scheduled through the renderer's devtools `scheduleUpdate`, falling
+/// back to a class `forceUpdate` or a copied hook state. A fiber that re-rendered has
+/// swapped with its alternate. Vue: renders are counted by wrapping the instance's
+/// render function and the update is `$forceUpdate()`. Counters persist across calls.
+const FORCE_RERENDER_JS: &str = r#"        const settle = async () => {
+            await new Promise(resolve => requestAnimationFrame(() => requestAnimationFrame(resolve)));
+            await new Promise(resolve => setTimeout(resolve, args.settle_ms));
+        };
+        const counters = window.__MCP_RENDER_COUNTERS__ || (window.__MCP_RENDER_COUNTERS__ = { react_commits: 0, react_hooked: false });
+        const ownKey = (el, prefixes) => el && Object.keys(el).find(key => prefixes.some(prefix => key.startsWith(prefix)));
+
+        // ----- React -----
+        const reactHook = window.__REACT_DEVTOOLS_GLOBAL_HOOK__;
+        const reactRoots = () => {
+            const roots = new Set();
+            if (reactHook && reactHook.renderers && typeof reactHook.getFiberRoots === 'function') {
+                for (const id of reactHook.renderers.keys()) {
+                    for (const root of reactHook.getFiberRoots(id)) roots.add(root);
+                }
+            }
+            if (roots.size === 0) {
+                for (const el of document.querySelectorAll('*')) {
+                    const key = ownKey(el, ['__reactContainer$']);
+                    if (key && el[key] && el[key].stateNode) roots.add(el[key].stateNode);
+                    else if (el._reactRootContainer) roots.add(el._reactRootContainer._internalRoot || el._reactRootContainer);
+                }
+            }
+            return [...roots].filter(root => root && root.current);
+        };
+        const fiberName = (fiber) => fiber.type && (fiber.type.displayName || fiber.type.name) || null;
+        const isComponent = (fiber) => fiber && (typeof fiber.type === 'function' || (fiber.type && typeof fiber.type === 'object' && (fiber.type.render || fiber.type.type)));
+        const hostRootOf = (fiber) => { let f = fiber; while (f.return) f = f.return; return f; };
+        // Either a fiber or its alternate is the committed one
+        const currentOf = (fiber) => {
+            const root = hostRootOf(fiber);
+            return root.stateNode && root.stateNode.current === root ? fiber : (fiber.alternate || fiber);
+        };
+        const findFiber = (fiber, match) => {
+            const stack = [fiber];
+            while (stack.length) {
+                const f = stack.pop();
+                if (!f) continue;
+                if (isComponent(f) && match(f)) return f;
+                if (f.sibling) stack.push(f.sibling);
+                if (f.child) stack.push(f.child);
+            }
+            return null;
+        };
+
+        const forceReact = async (roots) => {
+            if (reactHook && !counters.react_hooked && typeof reactHook.onCommitFiberRoot === 'function') {
+                const original = reactHook.onCommitFiberRoot;
+                reactHook.onCommitFiberRoot = function (...hookArgs) {
+                    counters.react_commits += 1;
+                    return original.apply(this, hookArgs);
+                };
+                counters.react_hooked = true;
+            }
+
+            let target;
+            if (args.selector) {
+                const el = mcp.query(args.selector);
+                let fiber = el[ownKey(el, ['__reactFiber$', '__reactInternalInstance$'])];
+                if (!fiber) throw new Error('Element is not rendered by React: ' + args.selector);
+                while (fiber && !isComponent(fiber)) fiber = fiber.return;
+                target = fiber;
+            } else if (args.component_name) {
+                for (const root of roots) {
+                    target = findFiber(root.current, f => fiberName(f) === args.component_name);
+                    if (target) break;
+                }
+            } else if (args.component_id) {
+                throw new Error('component_id identifies Vue components; use component_name or selector for React');
+            } else {
+                target = findFiber(roots[0].current, () => true);
+            }
+            if (!target) throw new Error('No React component found for ' + (args.component_name || args.selector || 'the root'));
+            target = currentOf(target);
+
+            const commitsBefore = counters.react_commits;
+            const rootCurrentBefore = hostRootOf(target).stateNode.current;
+            let method = null;
+            let note = null;
+
+            const renderer = reactHook && reactHook.renderers
+                ? [...reactHook.renderers.values()].find(r => typeof r.scheduleUpdate === 'function')
+                : null;
+            if (renderer) {
+                renderer.scheduleUpdate(target);
+                method = 'devtools_schedule_update';
+            } else {
+                let fiber = target;
+                while (fiber && !(fiber.stateNode && typeof fiber.stateNode.forceUpdate === 'function')) fiber = fiber.return;
+                const hookState = target.memoizedState;
+                const queue = hookState && hookState.queue;
+                if (fiber === target) {
+                    target.stateNode.forceUpdate();
+                    method = 'class_force_update';
+                } else if (queue && typeof queue.dispatch === 'function' && hookState.memoizedState && typeof hookState.memoizedState === 'object') {
+                    // Same-value dispatches bail out, so hand back a shallow copy of the first state hook
+                    const state = hookState.memoizedState;
+                    queue.dispatch(Array.isArray(state) ? [...state] : { ...state });
+                    method = 'hook_state_copy';
+                    note = 'Forced by replacing the first useState value with a shallow copy; effects depending on its identity will re-run';
+                } else if (fiber) {
+                    fiber.stateNode.forceUpdate();
+                    method = 'ancestor_class_force_update';
+                    note = 'Forced the nearest class component ancestor (' + (fiberName(fiber) || 'Anonymous') + '); memoized children may skip rendering';
+                } else {
+                    throw new Error('Cannot force this React component: no devtools scheduleUpdate, class instance or object state hook. Load the app with the React DevTools hook installed.');
+                }
+            }
+
+            await settle();
+            const after = currentOf(target);
+            const rootCommitted = hostRootOf(target).stateNode.current !== rootCurrentBefore;
+            return {
+                framework: 'react',
+                component: fiberName(target) || 'Anonymous',
+                method,
+                rendered: after !== target || counters.react_commits > commitsBefore,
+                root_committed: rootCommitted,
+                commit_count_before: counters.react_hooked ? commitsBefore : null,
+                commit_count_after: counters.react_hooked ? counters.react_commits : null,
+                note: note || (counters.react_hooked ? null : 'No React DevTools hook; commit counts unavailable, rendered is inferred from the fiber swap')
+            };
+        };
+
+        // ----- Vue -----
+        const vueRoots = () => {
+            const roots = [];
+            for (const el of document.querySelectorAll('*')) {
+                if (el.__vue_app__ && el.__vue_app__._instance) roots.push({ version: 3, instance: el.__vue_app__._instance });
+                else if (el.__vue__ && !el.__vue__.$parent) roots.push({ version: 2, instance: el.__vue__ });
+            }
+            return roots;
+        };
+        const vueName = (inst, version) => version === 3
+            ? (inst.type && (inst.type.name || inst.type.__name)) || null
+            : (inst.$options && (inst.$options.name || inst.$options._componentTag)) || null;
+        const vueChildren = (inst, version) => {
+            if (version === 2) return inst.$children || [];
+            const children = [];
+            const visit = (vnode) => {
+                if (!vnode || typeof vnode !== 'object') return;
+                if (vnode.component) { children.push(vnode.component); return; }
+                if (vnode.suspense && vnode.suspense.activeBranch) visit(vnode.suspense.activeBranch);
+                if (Array.isArray(vnode.children)) vnode.children.forEach(visit);
+            };
+            visit(inst.subTree);
+            return children;
+        };
+        const findVue = (inst, version, match) => {
+            const stack = [inst];
+            while (stack.length) {
+                const current = stack.pop();
+                if (match(current)) return current;
+                stack.push(...vueChildren(current, version));
+            }
+            return null;
+        };
+
+        const forceVue = async (roots) => {
+            let version = roots[0].version;
+            let target;
+            if (args.selector) {
+                let el = mcp.query(args.selector);
+                for (; el && !target; el = el.parentElement) {
+                    if (el.__vueParentComponent) { target = el.__vueParentComponent; version = 3; }
+                    else if (el.__vue__) { target = el.__vue__; version = 2; }
+                }
+                if (!target) throw new Error('Element is not rendered by a Vue component (Vue 3 only exposes this in development builds): ' + args.selector);
+            } else if (args.component_name || args.component_id) {
+                for (const root of roots) {
+                    target = findVue(root.instance, root.version, inst => args.component_id
+                        ? String(root.version === 3 ? inst.uid : inst._uid) === args.component_id
+                        : vueName(inst, root.version) === args.component_name);
+                    if (target) { version = root.version; break; }
+                }
+            } else {
+                target = roots[0].instance;
+            }
+            if (!target) throw new Error('No Vue component found for ' + (args.component_name || args.component_id));
+
+            // Count renders by wrapping the render function once per instance
+            const renderKey = version === 3 ? 'render' : '_render';
+            let instrumentedNow = false;
+            if (!target.__mcpRenderCount && typeof target[renderKey] === 'function') {
+                const original = target[renderKey];
+                target.__mcpRenderCount = { count: 0 };
+                target[renderKey] = function (...renderArgs) {
+                    target.__mcpRenderCount.count += 1;
+                    return original.apply(this, renderArgs);
+                };
+                instrumentedNow = true;
+            }
+            const counter = target.__mcpRenderCount;
+            const before = counter ? counter.count : null;
+
+            const proxy = version === 3 ? target.proxy : target;
+            proxy.$forceUpdate();
+            if (typeof proxy.$nextTick === 'function') await proxy.$nextTick();
+            await settle();
+
+            const after = counter ? counter.count : null;
+            return {
+                framework: 'vue',
+                vue_version: version,
+                component: vueName(target, version) || 'Anonymous',
+                component_id: String(version === 3 ? target.uid : target._uid),
+                method: 'force_update',
+                rendered: counter ? after > before : null,
+                render_count_before: before,
+                render_count_after: after,
+                instrumented_now: instrumentedNow,
+                note: counter ? null : 'Component has no instrumentable render function; render counts unavailable'
+            };
+        };
+
+        const react = args.framework !== 'vue' ? reactRoots() : [];
+        const vue = args.framework !== 'react' ? vueRoots() : [];
+        const useVue = args.framework === 'vue' || (args.framework === 'auto' && react.length === 0 && vue.length > 0);
+        if (!useVue && react.length > 0) return { forced: true, ...(await forceReact(react)) };
+        if (useVue && vue.length > 0) return { forced: true, ...(await forceVue(vue)) };
+
+        return {
+            forced: false,
+            framework: 'none',
+            rendered: false,
+            note: args.framework === 'auto'
+                ? 'No React or Vue root was detected in this window; nothing to re-render'
+                : 'No ' + args.framework + ' root was detected in this window'
+        };"#;
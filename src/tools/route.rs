@@ -0,0 +1,195 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct GetRouteRequest {
+    window_label: Option<String>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for reading the current client-side route
+pub async fn handle_get_route<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_route", &payload)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: GetRouteRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_route: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script("Route error", &json!({}), GET_ROUTE_JS);
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "route inspection",
+    )
+    .await
+}
+
+/// Vue Router is read from the app's `$router`. React Router has no global, so the
+/// fiber tree is searched for its context providers: v6 `RouteContext` (the deepest
+/// `matches`) with `LocationContext`, or the v5 router context's deepest `match`.
+/// Without either, the route comes from `location`, including `#/` hash routes.
+const GET_ROUTE_JS: &str = r#"        const MAX_FIBERS = 100000;
+        const ownKey = (el, prefixes) => el && Object.keys(el).find(key => prefixes.some(prefix => key.startsWith(prefix)));
+        const plain = (value) => {
+            try { return value === undefined ? null : JSON.parse(JSON.stringify(value)); } catch (e) { return '[Unserializable]'; }
+        };
+        const parseQuery = (search) => {
+            const query = {};
+            for (const [key, value] of new URLSearchParams(search)) {
+                query[key] = key in query ? [].concat(query[key], value) : value;
+            }
+            return query;
+        };
+
+        const base = {
+            location: { href: location.href, pathname: location.pathname, search: location.search, hash: location.hash },
+            history: { length: history.length, state: plain(history.state) }
+        };
+
+        // ----- Vue Router -----
+        for (const el of document.querySelectorAll('*')) {
+            let router = null;
+            let version = null;
+            if (el.__vue_app__) {
+                router = el.__vue_app__.config && el.__vue_app__.config.globalProperties.$router;
+                version = 4;
+            } else if (el.__vue__ && !el.__vue__.$parent) {
+                router = el.__vue__.$router;
+                version = 3;
+            }
+            if (!router || !router.currentRoute) continue;
+            const route = router.currentRoute.value || router.currentRoute;
+            const matched = (route.matched || []).map(record => ({ path: record.path, name: record.name ? String(record.name) : null }));
+            return {
+                router: 'vue-router',
+                router_version: version,
+                pattern: matched.length > 0 ? matched[matched.length - 1].path : null,
+                name: route.name ? String(route.name) : null,
+                path: route.path,
+                full_path: route.fullPath,
+                params: plain(route.params) || {},
+                query: plain(route.query) || {},
+                hash: route.hash || null,
+                meta: plain(route.meta),
+                matched,
+                ...base
+            };
+        }
+
+        // ----- React Router -----
+        const reactRoots = () => {
+            const roots = new Set();
+            const hook = window.__REACT_DEVTOOLS_GLOBAL_HOOK__;
+            if (hook && hook.renderers && typeof hook.getFiberRoots === 'function') {
+                for (const id of hook.renderers.keys()) {
+                    for (const root of hook.getFiberRoots(id)) roots.add(root);
+                }
+            }
+            if (roots.size === 0) {
+                for (const el of document.querySelectorAll('*')) {
+                    const key = ownKey(el, ['__reactContainer$']);
+                    if (key && el[key] && el[key].stateNode) roots.add(el[key].stateNode);
+                    else if (el._reactRootContainer) roots.add(el._reactRootContainer._internalRoot || el._reactRootContainer);
+                }
+            }
+            return [...roots].filter(root => root && root.current);
+        };
+
+        let routeContext = null;
+        let locationContext = null;
+        let v5Context = null;
+        let visited = 0;
+        for (const root of reactRoots()) {
+            const stack = [{ fiber: root.current, depth: 0 }];
+            while (stack.length > 0 && visited < MAX_FIBERS) {
+                const { fiber, depth } = stack.pop();
+                visited += 1;
+                const value = fiber.memoizedProps && fiber.memoizedProps.value;
+                if (value && typeof value === 'object') {
+                    if (Array.isArray(value.matches) && 'outlet' in value) {
+                        if (!routeContext || value.matches.length >= routeContext.matches.length) routeContext = value;
+                    } else if (value.location && 'navigationType' in value) {
+                        locationContext = value;
+                    } else if (value.history && value.location && value.match && (!v5Context || depth >= v5Context.depth)) {
+                        v5Context = { value, depth };
+                    }
+                }
+                if (fiber.sibling) stack.push({ fiber: fiber.sibling, depth });
+                if (fiber.child) stack.push({ fiber: fiber.child, depth: depth + 1 });
+            }
+        }
+
+        if (routeContext && routeContext.matches.length > 0) {
+            const matches = routeContext.matches;
+            let pattern = '';
+            for (const match of matches) {
+                const path = match.route && match.route.path;
+                if (!path) continue;
+                pattern = path.startsWith('/') ? path : pattern.replace(/\/$/, '') + '/' + path;
+            }
+            const last = matches[matches.length - 1];
+            const loc = locationContext ? locationContext.location : { pathname: last.pathname, search: location.search, hash: location.hash };
+            return {
+                router: 'react-router',
+                router_version: window.__reactRouterVersion || 6,
+                pattern: pattern || '/',
+                path: loc.pathname,
+                params: plain(last.params) || {},
+                query: parseQuery(loc.search || ''),
+                hash: loc.hash || null,
+                route_state: plain(loc.state),
+                matched: matches.map(match => ({ path: match.route ? match.route.path || null : null, id: match.route ? match.route.id || null : null, pathname: match.pathname })),
+                ...base
+            };
+        }
+
+        if (v5Context) {
+            const { match, location: loc } = v5Context.value;
+            return {
+                router: 'react-router',
+                router_version: 5,
+                pattern: match.path,
+                path: loc.pathname,
+                params: plain(match.params) || {},
+                query: parseQuery(loc.search || ''),
+                hash: loc.hash || null,
+                route_state: plain(loc.state),
+                is_exact: match.isExact,
+                ...base
+            };
+        }
+
+        // ----- Plain location -----
+        const hashRoute = location.hash.startsWith('#/') || location.hash.startsWith('#!/');
+        const hashUrl = hashRoute ? new URL(location.hash.replace(/^#!?/, ''), location.origin) : null;
+        return {
+            router: null,
+            router_version: null,
+            pattern: null,
+            path: hashUrl ? hashUrl.pathname : location.pathname,
+            params: {},
+            query: parseQuery(hashUrl ? hashUrl.search : location.search),
+            hash: hashUrl ? (hashUrl.hash || null) : (location.hash || null),
+            hash_routing: hashRoute,
+            note: 'No React Router or Vue Router instance detected; route read from location',
+            ...base
+        };"#;
@@ -0,0 +1,305 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, eval_probe, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ScrollBehaviorRequest {
+    window_label: Option<String>,
+    /// Scroll container (default the document's scrolling element)
+    selector: Option<String>,
+    /// "auto", "smooth" or "reset"; only for `set_scroll_behavior`
+    behavior: Option<String>,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct SetScrollPositionRequest {
+    window_label: Option<String>,
+    /// Scroll container (default the document's scrolling element)
+    selector: Option<String>,
+    /// Target horizontal offset (default unchanged)
+    x: Option<f64>,
+    /// Target vertical offset (default unchanged)
+    y: Option<f64>,
+    /// Scroll this element into view instead of to x/y, honouring scroll-margin
+    to_selector: Option<String>,
+    /// "auto" (default, the CSS scroll-behavior decides), "smooth" or "instant"
+    behavior: Option<String>,
+    /// Fail unless the scroll "smooth"ly animated or "instant"ly jumped
+    expect: Option<String>,
+    /// Longest time to sample positions while waiting for the scroll to settle (default 3000)
+    max_wait_ms: Option<u64>,
+    /// Allowed distance between the final and the target position (default 1)
+    tolerance_px: Option<f64>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for reading the effective CSS `scroll-behavior` of a scroll container
+pub async fn handle_get_scroll_behavior<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_scroll_behavior", &payload)
+        .optional("selector", FieldKind::NonEmptyString)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    scroll_behavior(app, "get_scroll_behavior", payload).await
+}
+
+/// Handler function for overriding the CSS `scroll-behavior` of a scroll container
+pub async fn handle_set_scroll_behavior<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("set_scroll_behavior", &payload)
+        .required("behavior", FieldKind::OneOf(&["auto", "smooth", "reset"]))
+        .optional("selector", FieldKind::NonEmptyString)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    scroll_behavior(app, "set_scroll_behavior", payload).await
+}
+
+async fn scroll_behavior<R: Runtime>(
+    app: &AppHandle<R>,
+    command: &str,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    let request: ScrollBehaviorRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for {}: {}", command, e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Scroll behavior error",
+        &json!({ "selector": request.selector, "behavior": request.behavior }),
+        &format!("{}\n{}", SCROLLER_JS, SCROLL_BEHAVIOR_JS),
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "scroll behavior",
+    )
+    .await
+}
+
+/// Handler function for scrolling to a position or element and reporting whether the
+/// scroll animated and where it landed
+pub async fn handle_set_scroll_position<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("set_scroll_position", &payload)
+        .optional("selector", FieldKind::NonEmptyString)
+        .optional("x", FieldKind::Number { min: Some(0.0), max: None })
+        .optional("y", FieldKind::Number { min: Some(0.0), max: None })
+        .optional("to_selector", FieldKind::NonEmptyString)
+        .optional("behavior", FieldKind::OneOf(&["auto", "smooth", "instant"]))
+        .optional("expect", FieldKind::OneOf(&["smooth", "instant"]))
+        .optional("max_wait_ms", FieldKind::Integer { min: Some(100), max: Some(20_000) })
+        .optional("tolerance_px", FieldKind::Number { min: Some(0.0), max: None })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: SetScrollPositionRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for set_scroll_position: {}", e)))?;
+
+    let has_coordinates = request.x.is_some() || request.y.is_some();
+    if has_coordinates == request.to_selector.is_some() {
+        return Err(Error::invalid_parameter(
+            "x/y/to_selector",
+            "either x and/or y, or to_selector",
+            if has_coordinates { "both" } else { "neither" },
+        ));
+    }
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let max_wait_ms = request.max_wait_ms.unwrap_or(3000);
+    let js_code = build_probe_script(
+        "Scroll position error",
+        &json!({
+            "selector": request.selector,
+            "x": request.x,
+            "y": request.y,
+            "to_selector": request.to_selector,
+            "behavior": request.behavior.as_deref().unwrap_or("auto"),
+            "max_wait_ms": max_wait_ms,
+            "tolerance_px": request.tolerance_px.unwrap_or(1.0),
+        }),
+        &format!("{}\n{}", SCROLLER_JS, SET_SCROLL_POSITION_JS),
+    );
+
+    let data = eval_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS).saturating_add(max_wait_ms),
+        "scroll position",
+    )
+    .await?;
+
+    let animated = data.get("animated").and_then(|v| v.as_bool()).unwrap_or(false);
+    let landed = data.get("landed_exactly").and_then(|v| v.as_bool()).unwrap_or(false);
+    let moved = data.get("moved").and_then(|v| v.as_bool()).unwrap_or(false);
+
+    let error = if !landed {
+        Some(format!(
+            "Scroll did not land on the target; it stopped at {}",
+            data.get("final").cloned().unwrap_or(Value::Null)
+        ))
+    } else {
+        match request.expect.as_deref() {
+            Some("smooth") if !moved => Some("Already at the target, so smooth scrolling could not be observed".to_string()),
+            Some("smooth") if !animated => Some("Expected a smooth scroll but the position jumped in a single frame".to_string()),
+            Some("instant") if animated => Some("Expected an instant scroll but the position animated over several frames".to_string()),
+            _ => None,
+        }
+    };
+
+    Ok(SocketResponse {
+        success: error.is_none(),
+        data: Some(data),
+        error,
+    })
+}
+
+/// Resolves `args.selector` to the scroll container, treating the root elements as the
+/// viewport, and exposes its offsets, maximum offsets and scroll-behavior source.
+const SCROLLER_JS: &str = r#"        const scroller = args.selector ? mcp.query(args.selector) : (document.scrollingElement || document.documentElement);
+        const isRoot = scroller === document.scrollingElement || scroller === document.documentElement || scroller === document.body;
+        const position = () => isRoot ? { x: window.scrollX, y: window.scrollY } : { x: scroller.scrollLeft, y: scroller.scrollTop };
+        const maxPosition = () => isRoot
+            ? { x: Math.max(0, document.documentElement.scrollWidth - window.innerWidth), y: Math.max(0, document.documentElement.scrollHeight - window.innerHeight) }
+            : { x: Math.max(0, scroller.scrollWidth - scroller.clientWidth), y: Math.max(0, scroller.scrollHeight - scroller.clientHeight) };
+        // The viewport takes scroll-behavior from the root element, never from body
+        const styleOwner = isRoot ? document.documentElement : scroller;
+        const reducedMotion = window.matchMedia('(prefers-reduced-motion: reduce)').matches;"#;
+
+/// `scroll-behavior` only decides how script calls with behavior "auto" and fragment
+/// navigation scroll; user scrolling is unaffected. "reset" removes the inline override.
+const SCROLL_BEHAVIOR_JS: &str = r#"        const describe = () => ({
+            computed: getComputedStyle(styleOwner).scrollBehavior || null,
+            inline: styleOwner.style.getPropertyValue('scroll-behavior') || null,
+            inline_priority: styleOwner.style.getPropertyPriority('scroll-behavior') || null
+        });
+        const before = describe();
+        if (args.behavior === 'reset') {
+            styleOwner.style.removeProperty('scroll-behavior');
+        } else if (args.behavior !== null) {
+            styleOwner.style.setProperty('scroll-behavior', args.behavior, 'important');
+        }
+        const after = describe();
+        return {
+            selector: mcp.cssPath(styleOwner),
+            is_viewport: isRoot,
+            supported: 'scrollBehavior' in document.documentElement.style,
+            scroll_behavior: after.computed,
+            inline_override: after.inline,
+            previous: args.behavior !== null ? before : undefined,
+            changed: args.behavior !== null ? before.computed !== after.computed : undefined,
+            prefers_reduced_motion: reducedMotion,
+            note: after.computed === 'smooth' && reducedMotion
+                ? 'prefers-reduced-motion is set; many stylesheets switch smooth scrolling off under a reduced-motion media query'
+                : null
+        };"#;
+
+/// Positions are sampled every animation frame until they have not changed for a few
+/// frames. A scroll that reaches the target in one frame jumped; one that passes through
+/// intermediate positions animated. The target for an element follows `scrollIntoView`
+/// with block and inline "start", including scroll-margin and scroll-padding.
+const SET_SCROLL_POSITION_JS: &str = r#"        const px = (value) => parseFloat(value) || 0;
+        const round = (v) => Math.round(v * 100) / 100;
+        const clamp = (value, max) => Math.min(max, Math.max(0, value));
+        const start = position();
+        const max = maxPosition();
+
+        let target;
+        let element = null;
+        if (args.to_selector) {
+            element = mcp.query(args.to_selector);
+            const r = element.getBoundingClientRect();
+            const elementStyle = getComputedStyle(element);
+            const scrollerStyle = getComputedStyle(styleOwner);
+            const origin = isRoot ? { left: 0, top: 0 } : (() => {
+                const box = scroller.getBoundingClientRect();
+                return { left: box.left + scroller.clientLeft, top: box.top + scroller.clientTop };
+            })();
+            target = {
+                x: clamp(start.x + r.left - px(elementStyle.scrollMarginLeft) - origin.left - px(scrollerStyle.scrollPaddingLeft), max.x),
+                y: clamp(start.y + r.top - px(elementStyle.scrollMarginTop) - origin.top - px(scrollerStyle.scrollPaddingTop), max.y)
+            };
+        } else {
+            target = {
+                x: clamp(args.x === null ? start.x : args.x, max.x),
+                y: clamp(args.y === null ? start.y : args.y, max.y)
+            };
+        }
+
+        const samples = [];
+        const started = performance.now();
+        if (element) {
+            element.scrollIntoView({ behavior: args.behavior, block: 'start', inline: 'start' });
+        } else {
+            (isRoot ? window : scroller).scrollTo({ left: target.x, top: target.y, behavior: args.behavior });
+        }
+        samples.push({ t_ms: 0, ...position() });
+
+        let stableFrames = 0;
+        while (performance.now() - started < args.max_wait_ms) {
+            await new Promise(resolve => requestAnimationFrame(resolve));
+            const current = position();
+            const last = samples[samples.length - 1];
+            if (Math.abs(current.x - last.x) < 0.5 && Math.abs(current.y - last.y) < 0.5) {
+                stableFrames += 1;
+                if (stableFrames >= 5) break;
+                continue;
+            }
+            stableFrames = 0;
+            samples.push({ t_ms: round(performance.now() - started), ...current });
+        }
+
+        const end = position();
+        const atTarget = (p) => Math.abs(p.x - target.x) <= args.tolerance_px && Math.abs(p.y - target.y) <= args.tolerance_px;
+        const moved = Math.abs(end.x - start.x) >= 0.5 || Math.abs(end.y - start.y) >= 0.5;
+        // Positions strictly between start and end mean the scroll was animated
+        const intermediate = samples.filter(sample => !atTarget(sample)
+            && (Math.abs(sample.x - start.x) >= 0.5 || Math.abs(sample.y - start.y) >= 0.5));
+        const settledAt = samples.find(atTarget);
+
+        return {
+            selector: mcp.cssPath(styleOwner),
+            to_selector: element ? mcp.cssPath(element) : null,
+            requested_behavior: args.behavior,
+            css_scroll_behavior: getComputedStyle(styleOwner).scrollBehavior || null,
+            prefers_reduced_motion: reducedMotion,
+            start: { x: round(start.x), y: round(start.y) },
+            target: { x: round(target.x), y: round(target.y) },
+            final: { x: round(end.x), y: round(end.y) },
+            moved,
+            animated: intermediate.length > 0,
+            intermediate_frames: intermediate.length,
+            duration_ms: settledAt ? settledAt.t_ms : round(performance.now() - started),
+            landed_exactly: atTarget(end),
+            distance_from_target_px: round(Math.hypot(end.x - target.x, end.y - target.y)),
+            samples: samples.slice(0, 120).map(sample => ({ t_ms: sample.t_ms, x: round(sample.x), y: round(sample.y) }))
+        };"#;
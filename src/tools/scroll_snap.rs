@@ -0,0 +1,163 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ScrollSnapRequest {
+    window_label: Option<String>,
+    /// Scroll-snap container
+    selector: String,
+    /// How long the scroll position is watched for movement (default 100)
+    settle_ms: Option<u64>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for reading a scroll-snap container's snap points and position
+pub async fn handle_get_scroll_snap_state<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_scroll_snap_state", &payload)
+        .required("selector", FieldKind::NonEmptyString)
+        .optional("settle_ms", FieldKind::Integer { min: Some(0), max: Some(5_000) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: ScrollSnapRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_scroll_snap_state: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let settle_ms = request.settle_ms.unwrap_or(100);
+
+    let js_code = build_probe_script(
+        "Scroll snap error",
+        &json!({
+            "selector": request.selector,
+            "settle_ms": settle_ms,
+        }),
+        SCROLL_SNAP_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS).saturating_add(settle_ms),
+        "scroll snap inspection",
+    )
+    .await
+}
+
+/// Snap positions follow the CSS Scroll Snap spec for horizontal-tb writing modes: the
+/// area's aligned edge (grown by its scroll-margin) meets the container's snapport
+/// (shrunk by its scroll-padding), clamped to the scrollable range. The snapped child is
+/// the snap point closest to the current scroll position.
+const SCROLL_SNAP_JS: &str = r#"        const container = mcp.query(args.selector);
+        const style = getComputedStyle(container);
+        const px = (value) => parseFloat(value) || 0;
+        const round = (v) => Math.round(v * 100) / 100;
+        const isRoot = container === document.documentElement || container === document.scrollingElement;
+        const position = () => ({ x: container.scrollLeft, y: container.scrollTop });
+
+        const start = position();
+        await new Promise(resolve => setTimeout(resolve, args.settle_ms));
+        await new Promise(resolve => requestAnimationFrame(resolve));
+        const current = position();
+        const moving = Math.abs(current.x - start.x) > 0.5 || Math.abs(current.y - start.y) > 0.5;
+
+        const snapType = style.scrollSnapType;
+        const [axisValue, strictnessValue] = snapType === 'none' ? ['none', null] : snapType.split(/\s+/);
+        const axis = axisValue === 'block' ? 'y' : axisValue === 'inline' ? 'x' : axisValue;
+        // Without a snap type, positions are still computed on both axes for reference
+        const snapX = axis !== 'y';
+        const snapY = axis !== 'x';
+
+        const viewWidth = isRoot ? window.innerWidth : container.clientWidth;
+        const viewHeight = isRoot ? window.innerHeight : container.clientHeight;
+        const maxX = Math.max(0, container.scrollWidth - viewWidth);
+        const maxY = Math.max(0, container.scrollHeight - viewHeight);
+        const padding = {
+            top: px(style.scrollPaddingTop), right: px(style.scrollPaddingRight),
+            bottom: px(style.scrollPaddingBottom), left: px(style.scrollPaddingLeft)
+        };
+        const origin = isRoot ? { left: 0, top: 0 } : (() => {
+            const r = container.getBoundingClientRect();
+            return { left: r.left + container.clientLeft, top: r.top + container.clientTop };
+        })();
+
+        const snapPosition = (align, areaStart, areaSize, marginStart, marginEnd, padStart, padEnd, view, max) => {
+            let value;
+            if (align === 'start') value = areaStart - marginStart - padStart;
+            else if (align === 'end') value = areaStart + areaSize + marginEnd - (view - padEnd);
+            else if (align === 'center') value = areaStart + areaSize / 2 - (padStart + (view - padStart - padEnd) / 2);
+            else return null;
+            return round(Math.min(max, Math.max(0, value)));
+        };
+
+        // Snap areas can be any descendant, but nested scroll containers own their own areas
+        const areas = [];
+        const walk = (node) => {
+            for (const child of node.children) {
+                const childStyle = getComputedStyle(child);
+                if (childStyle.scrollSnapAlign && childStyle.scrollSnapAlign !== 'none') areas.push({ el: child, style: childStyle });
+                const nested = /(auto|scroll|hidden)/.test(childStyle.overflowX + ' ' + childStyle.overflowY);
+                if (!nested) walk(child);
+            }
+        };
+        walk(isRoot ? document.body || container : container);
+
+        const points = areas.map(({ el, style: areaStyle }, index) => {
+            const r = el.getBoundingClientRect();
+            const [blockAlign, inlineAlign = blockAlign] = areaStyle.scrollSnapAlign.split(/\s+/);
+            const left = r.left - origin.left + current.x;
+            const top = r.top - origin.top + current.y;
+            return {
+                index,
+                selector: mcp.cssPath(el),
+                align: areaStyle.scrollSnapAlign,
+                snap_stop: areaStyle.scrollSnapStop,
+                x: snapX ? snapPosition(inlineAlign, left, r.width, px(areaStyle.scrollMarginLeft), px(areaStyle.scrollMarginRight), padding.left, padding.right, viewWidth, maxX) : null,
+                y: snapY ? snapPosition(blockAlign, top, r.height, px(areaStyle.scrollMarginTop), px(areaStyle.scrollMarginBottom), padding.top, padding.bottom, viewHeight, maxY) : null,
+                rect: mcp.rect(r)
+            };
+        });
+
+        let snapped = null;
+        let bestDistance = Infinity;
+        for (const point of points) {
+            const dx = point.x === null ? 0 : Math.abs(point.x - current.x);
+            const dy = point.y === null ? 0 : Math.abs(point.y - current.y);
+            if (point.x === null && point.y === null) continue;
+            const distance = Math.hypot(dx, dy);
+            if (distance < bestDistance) {
+                bestDistance = distance;
+                snapped = point;
+            }
+        }
+
+        return {
+            selector: mcp.cssPath(container),
+            snap_type: snapType,
+            snap_axis: axis,
+            strictness: strictnessValue || (axis === 'none' ? null : 'proximity'),
+            is_snap_container: axis !== 'none',
+            scroll: { x: round(current.x), y: round(current.y), max_x: round(maxX), max_y: round(maxY) },
+            scroll_padding: padding,
+            is_scrolling: moving,
+            snap_points: points,
+            snapped_index: snapped ? snapped.index : null,
+            snapped_selector: snapped ? snapped.selector : null,
+            distance_from_snap_px: snapped ? round(bestDistance) : null,
+            at_snap_point: snapped !== null && bestDistance <= 1,
+            note: axis === 'none' ? 'The element has scroll-snap-type: none, so the browser does not snap; positions are shown for both axes' : null
+        };"#;
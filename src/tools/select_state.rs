@@ -0,0 +1,339 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct GetSelectStateRequest {
+    window_label: Option<String>,
+    /// A `<select>`, an ARIA combobox or listbox, or an element containing one
+    selector: String,
+    /// Maximum number of options to return (default 200)
+    max_options: Option<usize>,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct SelectOptionRequest {
+    window_label: Option<String>,
+    selector: String,
+    /// Option value (`value` attribute, or `data-value` for ARIA options)
+    value: Option<String>,
+    /// Visible option text, matched after trimming whitespace
+    label: Option<String>,
+    /// Zero-based option index
+    index: Option<usize>,
+    /// Keep other options of a multiple select selected (default false)
+    add: Option<bool>,
+    /// How long to wait for an ARIA listbox to open and to close after picking (default 1000)
+    wait_ms: Option<u64>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for reading the value and options of a native or ARIA dropdown
+pub async fn handle_get_select_state<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_select_state", &payload)
+        .required("selector", FieldKind::NonEmptyString)
+        .optional("max_options", FieldKind::Integer { min: Some(1), max: Some(10_000) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: GetSelectStateRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_select_state: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Select state error",
+        &json!({
+            "selector": request.selector,
+            "max_options": request.max_options.unwrap_or(200),
+        }),
+        &format!("{}\n        return readState(resolve());", SELECT_STATE_JS),
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "select state",
+    )
+    .await
+}
+
+/// Handler function for choosing an option of a native or ARIA dropdown
+pub async fn handle_select_option<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("select_option", &payload)
+        .required("selector", FieldKind::NonEmptyString)
+        .optional("value", FieldKind::String)
+        .optional("label", FieldKind::String)
+        .optional("index", FieldKind::UINT)
+        .optional("add", FieldKind::Bool)
+        .optional("wait_ms", FieldKind::Integer { min: Some(0), max: Some(30_000) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: SelectOptionRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for select_option: {}", e)))?;
+
+    let criteria = [request.value.is_some(), request.label.is_some(), request.index.is_some()]
+        .iter()
+        .filter(|given| **given)
+        .count();
+    if criteria != 1 {
+        return Err(Error::invalid_parameter(
+            "value",
+            "exactly one of value, label or index",
+            format!("{} of them", criteria),
+        ));
+    }
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let wait_ms = request.wait_ms.unwrap_or(1000);
+
+    let js_code = build_probe_script(
+        "Select option error",
+        &json!({
+            "selector": request.selector,
+            "value": request.value,
+            "label": request.label,
+            "index": request.index,
+            "add": request.add.unwrap_or(false),
+            "wait_ms": wait_ms,
+            "max_options": 200,
+        }),
+        &format!("{}\n{}", SELECT_STATE_JS, SELECT_OPTION_JS),
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS).saturating_add(wait_ms * 2),
+        "option selection",
+    )
+    .await
+}
+
+/// Defines `resolve()`, `findListbox()`, `ariaOptions()` and `readState()`, shared by
+/// both commands. A selector on a wrapper resolves to the `<select>` or combobox inside
+/// it. The listbox of a combobox is found through `aria-controls`/`aria-owns`, then
+/// inside the combobox; many libraries only render it while open.
+const SELECT_STATE_JS: &str = r#"        const text = (el) => (el.getAttribute('aria-label') || el.textContent || '').replace(/\s+/g, ' ').trim();
+        const resolve = () => {
+            const el = mcp.query(args.selector);
+            if (el instanceof HTMLSelectElement) return el;
+            const role = el.getAttribute('role');
+            if (role === 'combobox' || role === 'listbox') return el;
+            const inner = el.querySelector('select, [role="combobox"], [role="listbox"]');
+            if (!inner) throw new Error('Element ' + mcp.cssPath(el) + ' is neither a <select> nor an ARIA combobox or listbox, and contains none');
+            return inner;
+        };
+        const byIds = (el, attr) => {
+            const root = el.getRootNode();
+            return (el.getAttribute(attr) || '').split(/\s+/).filter(Boolean)
+                .map(id => (root.getElementById ? root.getElementById(id) : null) || document.getElementById(id))
+                .filter(Boolean);
+        };
+        const findListbox = (el) => {
+            if (el.getAttribute('role') === 'listbox') return el;
+            for (const candidate of [...byIds(el, 'aria-controls'), ...byIds(el, 'aria-owns')]) {
+                if (candidate.getAttribute('role') === 'listbox') return candidate;
+                const inner = candidate.querySelector('[role="listbox"]');
+                if (inner) return inner;
+            }
+            // ARIA 1.1 pattern: the combobox wraps a textbox and the listbox
+            return el.querySelector('[role="listbox"]');
+        };
+        const ariaOptions = (listbox) => listbox ? Array.from(listbox.querySelectorAll('[role="option"]')) : [];
+        const isExpanded = (el) => el.getAttribute('aria-expanded') === 'true';
+
+        const readState = (el) => {
+            if (el instanceof HTMLSelectElement) {
+                const options = Array.from(el.options);
+                return {
+                    kind: 'native',
+                    element: mcp.cssPath(el),
+                    multiple: el.multiple,
+                    disabled: el.disabled,
+                    required: el.required,
+                    value: el.value,
+                    selected_index: el.selectedIndex,
+                    selected: options.filter(option => option.selected).map(option => ({ index: option.index, value: option.value, label: option.label })),
+                    option_count: options.length,
+                    options: options.slice(0, args.max_options).map(option => ({
+                        index: option.index,
+                        value: option.value,
+                        label: option.label,
+                        selected: option.selected,
+                        disabled: option.disabled,
+                        group: option.parentElement instanceof HTMLOptGroupElement ? option.parentElement.label : null
+                    })),
+                    options_truncated: options.length > args.max_options,
+                    valid: el.checkValidity()
+                };
+            }
+            const listbox = findListbox(el);
+            const options = ariaOptions(listbox);
+            const active = byIds(el, 'aria-activedescendant')[0] || null;
+            const input = el.matches('input, textarea') ? el : el.querySelector('input, textarea');
+            const selected = options.filter(option => option.getAttribute('aria-selected') === 'true');
+            return {
+                kind: el.getAttribute('role') === 'listbox' ? 'aria_listbox' : 'aria_combobox',
+                element: mcp.cssPath(el),
+                expanded: el.getAttribute('role') === 'listbox' ? true : isExpanded(el),
+                disabled: el.getAttribute('aria-disabled') === 'true' || !!(input && input.disabled),
+                autocomplete: el.getAttribute('aria-autocomplete'),
+                input_value: input ? input.value : null,
+                displayed_text: input ? input.value : text(el),
+                listbox: listbox ? mcp.cssPath(listbox) : null,
+                multiselectable: !!listbox && listbox.getAttribute('aria-multiselectable') === 'true',
+                active_option: active ? text(active) : null,
+                selected: selected.map(option => ({ index: options.indexOf(option), value: option.getAttribute('data-value'), label: text(option) })),
+                option_count: options.length,
+                options: options.slice(0, args.max_options).map((option, index) => ({
+                    index,
+                    id: option.id || null,
+                    value: option.getAttribute('data-value'),
+                    label: text(option),
+                    selected: option.getAttribute('aria-selected') === 'true',
+                    disabled: option.getAttribute('aria-disabled') === 'true',
+                    active: option === active
+                })),
+                options_truncated: options.length > args.max_options,
+                note: !listbox ? 'No listbox is rendered; it likely appears only while the combobox is expanded' : null
+            };
+        };"#;
+
+/// A native select gets the option selected directly, followed by the `input` and
+/// `change` events a user choice fires. An ARIA combobox is opened with a click (then
+/// Alt+ArrowDown if that did not expand it), the option is scrolled into view and
+/// clicked with the full pointer/mouse sequence, since libraries differ in which of
+/// those events they act on. The state afterwards is read back to verify the pick.
+const SELECT_OPTION_JS: &str = r#"        const el = resolve();
+        const wait = (ms) => new Promise(resolve => setTimeout(resolve, ms));
+        const waitFor = async (check) => {
+            const deadline = Date.now() + args.wait_ms;
+            for (;;) {
+                const result = check();
+                if (result || Date.now() >= deadline) return result;
+                await wait(25);
+            }
+        };
+        const describe = () => args.value !== null ? 'value ' + JSON.stringify(args.value)
+            : args.label !== null ? 'label ' + JSON.stringify(args.label)
+            : 'index ' + args.index;
+        const pick = (options, valueOf, labelOf) => {
+            if (args.index !== null) return options[args.index] || null;
+            if (args.value !== null) return options.find(option => valueOf(option) === args.value) || null;
+            const wanted = args.label.replace(/\s+/g, ' ').trim();
+            return options.find(option => labelOf(option) === wanted)
+                || options.find(option => labelOf(option).toLowerCase() === wanted.toLowerCase())
+                || null;
+        };
+
+        if (el instanceof HTMLSelectElement) {
+            if (el.disabled) throw new Error('The select is disabled');
+            const option = pick(Array.from(el.options), option => option.value, option => option.label.replace(/\s+/g, ' ').trim());
+            if (!option) throw new Error('No option with ' + describe() + '; available: ' + Array.from(el.options).slice(0, 20).map(o => JSON.stringify(o.label)).join(', '));
+            if (option.disabled) throw new Error('Option ' + JSON.stringify(option.label) + ' is disabled');
+            const before = readState(el);
+            el.focus();
+            if (el.multiple && args.add) {
+                option.selected = true;
+            } else if (el.multiple) {
+                for (const other of el.options) other.selected = other === option;
+            } else {
+                el.selectedIndex = option.index;
+            }
+            el.dispatchEvent(new Event('input', { bubbles: true, composed: true }));
+            el.dispatchEvent(new Event('change', { bubbles: true }));
+            const after = readState(el);
+            return { kind: 'native', picked: { index: option.index, value: option.value, label: option.label }, verified: option.selected, before: before.selected, after };
+        }
+
+        const click = (target) => {
+            const r = target.getBoundingClientRect();
+            const init = { bubbles: true, cancelable: true, composed: true, view: window, clientX: r.left + r.width / 2, clientY: r.top + r.height / 2, button: 0 };
+            target.dispatchEvent(new PointerEvent('pointerdown', { ...init, pointerId: 1, pointerType: 'mouse', isPrimary: true, buttons: 1 }));
+            target.dispatchEvent(new MouseEvent('mousedown', { ...init, buttons: 1 }));
+            target.dispatchEvent(new PointerEvent('pointerup', { ...init, pointerId: 1, pointerType: 'mouse', isPrimary: true, buttons: 0 }));
+            target.dispatchEvent(new MouseEvent('mouseup', { ...init, buttons: 0 }));
+            target.dispatchEvent(new MouseEvent('click', { ...init, buttons: 0 }));
+        };
+        const key = (target, keyName, extra) => {
+            for (const type of ['keydown', 'keyup']) {
+                target.dispatchEvent(new KeyboardEvent(type, { key: keyName, code: keyName, bubbles: true, cancelable: true, composed: true, ...extra }));
+            }
+        };
+
+        const isListbox = el.getAttribute('role') === 'listbox';
+        if (el.getAttribute('aria-disabled') === 'true') throw new Error('The combobox is aria-disabled');
+        const before = readState(el);
+        const steps = [];
+        const input = el.matches('input, textarea') ? el : el.querySelector('input, textarea');
+        if (!isListbox && !(isExpanded(el) && ariaOptions(findListbox(el)).length > 0)) {
+            (input || el).focus();
+            // Some comboboxes put the toggle on a separate button next to the input
+            const toggle = el.querySelector('button, [aria-haspopup]') || el;
+            click(toggle);
+            steps.push('click');
+            let opened = await waitFor(() => ariaOptions(findListbox(el)).length > 0);
+            if (!opened) {
+                key(input || el, 'ArrowDown', { altKey: true });
+                steps.push('alt+arrowdown');
+                opened = await waitFor(() => ariaOptions(findListbox(el)).length > 0);
+            }
+            if (!opened) throw new Error('The combobox did not show any options after a click and Alt+ArrowDown (waited ' + args.wait_ms + 'ms per attempt)');
+        }
+
+        const listbox = findListbox(el);
+        const options = ariaOptions(listbox);
+        const option = pick(options, option => option.getAttribute('data-value'), text);
+        if (!option) {
+            key(input || el, 'Escape');
+            throw new Error('No option with ' + describe() + '; available: ' + options.slice(0, 20).map(o => JSON.stringify(text(o))).join(', '));
+        }
+        if (option.getAttribute('aria-disabled') === 'true') throw new Error('Option ' + JSON.stringify(text(option)) + ' is aria-disabled');
+        const picked = { index: options.indexOf(option), value: option.getAttribute('data-value'), label: text(option) };
+        option.scrollIntoView({ block: 'nearest' });
+        click(option);
+        steps.push('click option');
+
+        // Single-select comboboxes usually close; wait for that or for the option to be selected
+        await waitFor(() => !el.isConnected || (!isListbox && !isExpanded(el)) || option.getAttribute('aria-selected') === 'true');
+        await wait(50);
+        const after = readState(el);
+        const shown = (after.displayed_text || '').toLowerCase();
+        const verified = after.selected.some(entry => entry.label === picked.label)
+            || (picked.label !== '' && shown.includes(picked.label.toLowerCase()));
+        return {
+            kind: before.kind,
+            picked,
+            steps,
+            verified,
+            before: { displayed_text: before.displayed_text, selected: before.selected },
+            after,
+            note: verified ? null : 'The pick could not be confirmed from aria-selected or the displayed text; the component may keep its value elsewhere'
+        };"#;
@@ -0,0 +1,253 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct GetSelectionRequest {
+    window_label: Option<String>,
+    /// Text field or contenteditable to read; defaults to the focused element
+    selector: Option<String>,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct SetSelectionRequest {
+    window_label: Option<String>,
+    /// Text field or contenteditable to select in
+    selector: String,
+    /// Start offset in characters
+    start: Option<u64>,
+    /// End offset in characters; defaults to `start` (a collapsed caret)
+    end: Option<u64>,
+    /// "forward" (default), "backward" or "none"
+    direction: Option<String>,
+    /// Select the whole value or content instead of an offset range
+    select_all: Option<bool>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for reading the selection or caret in a text field
+pub async fn handle_get_selection<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_selection", &payload)
+        .optional("selector", FieldKind::NonEmptyString)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: GetSelectionRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_selection: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Selection error",
+        &json!({ "selector": request.selector, "write": false }),
+        SELECTION_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "selection read",
+    )
+    .await
+}
+
+/// Handler function for placing the selection or caret in a text field
+pub async fn handle_set_selection<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    let validator = PayloadValidator::new("set_selection", &payload);
+    let select_all = validator.field("select_all").and_then(|v| v.as_bool()).unwrap_or(false);
+    validator
+        .required("selector", FieldKind::NonEmptyString)
+        .required_if(!select_all, "start", FieldKind::UINT)
+        .optional("end", FieldKind::UINT)
+        .optional("direction", FieldKind::OneOf(&["forward", "backward", "none"]))
+        .optional("select_all", FieldKind::Bool)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: SetSelectionRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for set_selection: {}", e)))?;
+
+    if let (Some(start), Some(end)) = (request.start, request.end) {
+        if end < start {
+            return Err(Error::invalid_parameter(
+                "end",
+                "an offset greater than or equal to 'start' (use direction 'backward' for a reversed selection)",
+                end.to_string(),
+            ));
+        }
+    }
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Selection error",
+        &json!({
+            "selector": request.selector,
+            "write": true,
+            "start": request.start,
+            "end": request.end.or(request.start),
+            "direction": request.direction.clone().unwrap_or_else(|| "forward".to_string()),
+            "select_all": select_all,
+        }),
+        SELECTION_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "selection update",
+    )
+    .await
+}
+
+/// Inputs use the selection API on the element itself. Contenteditable offsets count
+/// characters across the host's text nodes, so they match `innerText` positions for
+/// plain text but not across block boundaries, which insert line breaks.
+const SELECTION_JS: &str = r#"        // Input types without a text selection (email, number, ...) throw on setSelectionRange
+        const SELECTABLE_TYPES = ['text', 'search', 'url', 'tel', 'password'];
+
+        let el;
+        if (args.selector) {
+            el = mcp.query(args.selector);
+        } else {
+            el = document.activeElement;
+            if (!el || el === document.body) throw new Error('No element is focused; pass a selector');
+        }
+
+        const isField = (el.tagName === 'TEXTAREA') ||
+            (el.tagName === 'INPUT' && SELECTABLE_TYPES.includes((el.type || 'text').toLowerCase()));
+        if (!isField && !el.isContentEditable) {
+            if (el.tagName === 'INPUT') {
+                throw new Error('Input type "' + el.type + '" does not support text selection: ' + mcp.cssPath(el));
+            }
+            throw new Error('Element is not a text field or contenteditable: ' + mcp.cssPath(el));
+        }
+        // Read-only fields still take a selection, disabled ones cannot be focused
+        if (args.write && el.disabled) throw new Error('Element is disabled: ' + mcp.cssPath(el));
+
+        let host = el;
+        if (!isField) {
+            while (host.parentElement && host.parentElement.isContentEditable) host = host.parentElement;
+        }
+
+        const textNodes = () => {
+            const walker = document.createTreeWalker(host, NodeFilter.SHOW_TEXT);
+            const nodes = [];
+            while (walker.nextNode()) nodes.push(walker.currentNode);
+            return nodes;
+        };
+        // Character offset of a (node, offset) boundary within the host
+        const toOffset = (node, offset) => {
+            if (!host.contains(node)) return null;
+            const range = document.createRange();
+            range.selectNodeContents(host);
+            range.setEnd(node, offset);
+            return range.toString().length;
+        };
+        // Boundary for a character offset, clamped to the end of the content
+        const toBoundary = (offset) => {
+            let remaining = offset;
+            const nodes = textNodes();
+            for (const node of nodes) {
+                if (remaining <= node.length) return { node, offset: remaining };
+                remaining -= node.length;
+            }
+            if (nodes.length === 0) return { node: host, offset: 0 };
+            const last = nodes[nodes.length - 1];
+            return { node: last, offset: last.length };
+        };
+
+        const read = () => {
+            if (isField) {
+                const start = el.selectionStart;
+                const end = el.selectionEnd;
+                const direction = el.selectionDirection || 'none';
+                return {
+                    kind: 'field',
+                    start,
+                    end,
+                    anchor: direction === 'backward' ? end : start,
+                    focus: direction === 'backward' ? start : end,
+                    direction,
+                    collapsed: start === end,
+                    selected_text: el.value.substring(start, end),
+                    length: el.value.length
+                };
+            }
+            const selection = window.getSelection();
+            const inside = selection.rangeCount > 0 && host.contains(selection.anchorNode) && host.contains(selection.focusNode);
+            const length = host.textContent.length;
+            if (!inside) {
+                return { kind: 'contenteditable', start: null, end: null, anchor: null, focus: null, direction: 'none', collapsed: true, selected_text: '', length, has_selection: false };
+            }
+            const anchor = toOffset(selection.anchorNode, selection.anchorOffset);
+            const focus = toOffset(selection.focusNode, selection.focusOffset);
+            return {
+                kind: 'contenteditable',
+                start: Math.min(anchor, focus),
+                end: Math.max(anchor, focus),
+                anchor,
+                focus,
+                direction: anchor === focus ? 'none' : (focus < anchor ? 'backward' : 'forward'),
+                collapsed: anchor === focus,
+                selected_text: selection.toString(),
+                length,
+                has_selection: true
+            };
+        };
+
+        const describe = () => ({
+            selector: mcp.cssPath(host),
+            focused: document.activeElement === el || host.contains(document.activeElement),
+            ...read()
+        });
+
+        if (!args.write) return describe();
+
+        el.focus();
+        if (isField) {
+            const length = el.value.length;
+            const start = args.select_all ? 0 : Math.min(args.start, length);
+            const end = args.select_all ? length : Math.min(args.end, length);
+            el.setSelectionRange(start, end, args.direction);
+        } else {
+            const length = host.textContent.length;
+            const start = args.select_all ? 0 : Math.min(args.start, length);
+            const end = args.select_all ? length : Math.min(args.end, length);
+            const from = toBoundary(args.direction === 'backward' ? end : start);
+            const to = toBoundary(args.direction === 'backward' ? start : end);
+            const selection = window.getSelection();
+            selection.removeAllRanges();
+            selection.setBaseAndExtent(from.node, from.offset, to.node, to.offset);
+        }
+        // Browsers only fire select for user selections and select(); dispatch it for listeners
+        el.dispatchEvent(new Event('select', { bubbles: true }));
+
+        const result = describe();
+        result.clamped = !args.select_all && (args.end > result.length);
+        return result;"#;
@@ -0,0 +1,135 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ServiceWorkerRequest {
+    window_label: Option<String>,
+    /// Registration scope to unregister; every registration when omitted and `all` is set
+    scope: Option<String>,
+    timeout_ms: Option<u64>,
+}
+
+fn parse_request(command: &str, payload: Value) -> Result<(ServiceWorkerRequest, String), Error> {
+    let request: ServiceWorkerRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for {}: {}", command, e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    Ok((request, window_label))
+}
+
+/// Handler function for listing service worker registrations
+pub async fn handle_get_service_workers<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_service_workers", &payload)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let (request, window_label) = parse_request("get_service_workers", payload)?;
+
+    let js_code = build_probe_script("Service worker error", &json!({}), GET_SERVICE_WORKERS_JS);
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "service worker inspection",
+    )
+    .await
+}
+
+/// Handler function for unregistering service workers between tests
+pub async fn handle_unregister_service_worker<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    let validator = PayloadValidator::new("unregister_service_worker", &payload);
+    let all = validator.field("all").and_then(|v| v.as_bool()).unwrap_or(false);
+    validator
+        // Unregistering everything has to be asked for explicitly
+        .required_if(!all, "scope", FieldKind::NonEmptyString)
+        .optional("all", FieldKind::Bool)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let (request, window_label) = parse_request("unregister_service_worker", payload)?;
+
+    // A given scope wins over `all`
+    let js_code = build_probe_script(
+        "Service worker error",
+        &json!({ "scope": request.scope }),
+        UNREGISTER_SERVICE_WORKER_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "service worker unregistration",
+    )
+    .await
+}
+
+const GET_SERVICE_WORKERS_JS: &str = r#"        if (!('serviceWorker' in navigator)) {
+            return { supported: false, controller: null, registrations: [] };
+        }
+
+        const describe = (worker) => worker
+            ? { script_url: worker.scriptURL, state: worker.state }
+            : null;
+
+        const registrations = await navigator.serviceWorker.getRegistrations();
+        return {
+            supported: true,
+            controller: describe(navigator.serviceWorker.controller),
+            registrations: registrations.map(reg => ({
+                scope: reg.scope,
+                update_via_cache: reg.updateViaCache,
+                active: describe(reg.active),
+                installing: describe(reg.installing),
+                waiting: describe(reg.waiting)
+            }))
+        };"#;
+
+const UNREGISTER_SERVICE_WORKER_JS: &str = r#"        if (!('serviceWorker' in navigator)) {
+            return { supported: false, unregistered: [] };
+        }
+
+        const registrations = await navigator.serviceWorker.getRegistrations();
+        let scopeUrl = null;
+        if (args.scope) {
+            try {
+                scopeUrl = new URL(args.scope, location.href).href;
+            } catch (e) {
+                throw new Error('Invalid service worker scope: ' + args.scope);
+            }
+        }
+        const targets = args.scope
+            ? registrations.filter(reg => reg.scope === args.scope || reg.scope === scopeUrl)
+            : registrations;
+
+        if (args.scope && targets.length === 0) {
+            throw new Error('No service worker registered for scope: ' + args.scope);
+        }
+
+        const results = await Promise.all(targets.map(async reg => ({
+            scope: reg.scope,
+            unregistered: await reg.unregister()
+        })));
+
+        return { supported: true, unregistered: results };"#;
@@ -0,0 +1,172 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ShadowDomRequest {
+    window_label: Option<String>,
+    /// Shadow host element
+    selector: String,
+    /// Levels of nested shadow roots to expand below the host's own (default 5)
+    max_depth: Option<u32>,
+    /// Keep the contents of `<style>` elements (default true)
+    include_styles: Option<bool>,
+    /// Longest serialized HTML returned (default 200000)
+    max_chars: Option<usize>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for serializing an element's open shadow root
+pub async fn handle_get_shadow_dom<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_shadow_dom", &payload)
+        .required("selector", FieldKind::NonEmptyString)
+        .optional("max_depth", FieldKind::Integer { min: Some(0), max: Some(50) })
+        .optional("include_styles", FieldKind::Bool)
+        .optional("max_chars", FieldKind::Integer { min: Some(100), max: Some(5_000_000) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: ShadowDomRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_shadow_dom: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Shadow DOM error",
+        &json!({
+            "selector": request.selector,
+            "max_depth": request.max_depth.unwrap_or(5),
+            "include_styles": request.include_styles.unwrap_or(true),
+            "max_chars": request.max_chars.unwrap_or(200_000),
+        }),
+        SHADOW_DOM_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "shadow DOM serialization",
+    )
+    .await
+}
+
+/// Nested open roots are written inline as `<template shadowrootmode="open">`, the
+/// declarative shadow DOM form, so the output reads like the composed component.
+/// Closed roots cannot be reached from script and look the same as no root at all,
+/// so custom elements without an open root are listed as possibly closed.
+const SHADOW_DOM_JS: &str = r#"        const host = mcp.query(args.selector);
+        if (!host.shadowRoot) {
+            const custom = host.localName.includes('-') || host.hasAttribute('is');
+            return {
+                host: mcp.cssPath(host),
+                accessible: false,
+                mode: null,
+                html: null,
+                note: custom
+                    ? 'The element has no open shadow root; it is either closed (inaccessible to script) or not attached yet'
+                    : 'The element has no open shadow root'
+            };
+        }
+
+        const VOID = new Set(['area', 'base', 'br', 'col', 'embed', 'hr', 'img', 'input', 'link', 'meta', 'source', 'track', 'wbr']);
+        const RAW_TEXT = new Set(['style', 'script', 'xmp', 'iframe', 'noembed', 'noframes', 'plaintext']);
+        const escapeText = (text) => text.replace(/&/g, '&amp;').replace(/</g, '&lt;').replace(/>/g, '&gt;').replace(/\u00a0/g, '&nbsp;');
+        const escapeAttr = (text) => text.replace(/&/g, '&amp;').replace(/"/g, '&quot;').replace(/\u00a0/g, '&nbsp;');
+
+        let nestedRoots = 0;
+        const depthLimited = [];
+        const possiblyClosed = [];
+        const describeRoot = (root) => {
+            const attrs = ['shadowrootmode="open"'];
+            if (root.delegatesFocus) attrs.push('shadowrootdelegatesfocus');
+            if (root.clonable) attrs.push('shadowrootclonable');
+            return '<template ' + attrs.join(' ') + '>';
+        };
+
+        const serializeChildren = (parent, depth) => {
+            let html = '';
+            for (const child of parent.childNodes) html += serialize(child, depth);
+            return html;
+        };
+        const serialize = (node, depth) => {
+            switch (node.nodeType) {
+                case Node.TEXT_NODE: {
+                    const parent = node.parentNode;
+                    return parent && parent.nodeType === Node.ELEMENT_NODE && RAW_TEXT.has(parent.localName) ? node.data : escapeText(node.data);
+                }
+                case Node.COMMENT_NODE:
+                    return '<!--' + node.data + '-->';
+                case Node.ELEMENT_NODE:
+                    break;
+                default:
+                    return '';
+            }
+            const tag = node.localName;
+            let html = '<' + tag;
+            for (const attr of node.attributes) html += ' ' + attr.name + '="' + escapeAttr(attr.value) + '"';
+            html += '>';
+            if (VOID.has(tag)) return html;
+
+            if (node.shadowRoot) {
+                if (depth < args.max_depth) {
+                    nestedRoots += 1;
+                    html += describeRoot(node.shadowRoot) + serializeChildren(node.shadowRoot, depth + 1) + '</template>';
+                } else {
+                    depthLimited.push(mcp.cssPath(node));
+                    html += '<!-- open shadow root not expanded: max_depth reached -->';
+                }
+            } else if ((tag.includes('-') || node.hasAttribute('is')) && customElements.get(node.getAttribute('is') || tag)) {
+                possiblyClosed.push(mcp.cssPath(node));
+            }
+            if (tag === 'style' && !args.include_styles) {
+                html += '/* ' + node.textContent.length + ' characters omitted */';
+            } else if (tag === 'template') {
+                html += serializeChildren(node.content, depth);
+            } else {
+                html += serializeChildren(node, depth);
+            }
+            return html + '</' + tag + '>';
+        };
+
+        const root = host.shadowRoot;
+        let html = serializeChildren(root, 0);
+        const length = html.length;
+        const truncated = length > args.max_chars;
+        if (truncated) html = html.substring(0, args.max_chars);
+
+        const slots = [...root.querySelectorAll('slot')].map(slot => ({
+            name: slot.name || null,
+            assigned_nodes: slot.assignedNodes({ flatten: true }).filter(n => n.nodeType === Node.ELEMENT_NODE || n.textContent.trim()).length,
+            uses_fallback: slot.assignedNodes().length === 0
+        }));
+
+        return {
+            host: mcp.cssPath(host),
+            accessible: true,
+            mode: root.mode,
+            delegates_focus: root.delegatesFocus,
+            slot_assignment: root.slotAssignment || 'named',
+            adopted_stylesheets: root.adoptedStyleSheets ? root.adoptedStyleSheets.length : 0,
+            child_elements: root.childElementCount,
+            slots,
+            nested_shadow_roots: nestedRoots,
+            not_expanded: depthLimited,
+            possibly_closed: possiblyClosed,
+            length,
+            truncated,
+            html
+        };"#;
@@ -0,0 +1,438 @@
+use enigo::{Direction, Enigo, Key, Keyboard, Settings};
+use serde_json::{json, Value};
+use tauri::{AppHandle, Emitter, Runtime};
+
+use crate::TauriMcpExt;
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, eval_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+/// Event emitted to the app when a shortcut is triggered with the "event" mechanism.
+///
+/// The global-shortcut plugin has no way to invoke a registered handler, so the handler
+/// is never called directly. This event is opt-in: apps that want shortcuts to be
+/// testable route it to the same handler.
+pub const SHORTCUT_TRIGGER_EVENT: &str = "tauri-mcp://shortcut-trigger";
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ShortcutRequest {
+    window_label: Option<String>,
+    /// Accelerator such as `CmdOrCtrl+Shift+K`
+    accelerator: Option<String>,
+    /// "auto" (default), "event", "native" or "webview"
+    mechanism: Option<String>,
+    timeout_ms: Option<u64>,
+}
+
+/// Accelerator split into modifiers and the DOM `key` name of the main key
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Accelerator {
+    ctrl: bool,
+    alt: bool,
+    shift: bool,
+    meta: bool,
+    key: String,
+}
+
+impl Accelerator {
+    fn parse(accelerator: &str) -> Result<Self, Error> {
+        let invalid = || {
+            Error::invalid_parameter(
+                "accelerator",
+                "modifiers and one key joined by '+', e.g. CmdOrCtrl+Shift+K",
+                accelerator,
+            )
+        };
+
+        let mut parsed = Accelerator {
+            ctrl: false,
+            alt: false,
+            shift: false,
+            meta: false,
+            key: String::new(),
+        };
+
+        for part in accelerator.split('+').map(str::trim) {
+            match part.to_ascii_lowercase().as_str() {
+                "cmdorctrl" | "commandorcontrol" => {
+                    if cfg!(target_os = "macos") {
+                        parsed.meta = true;
+                    } else {
+                        parsed.ctrl = true;
+                    }
+                }
+                "ctrl" | "control" => parsed.ctrl = true,
+                "alt" | "option" => parsed.alt = true,
+                "shift" => parsed.shift = true,
+                "cmd" | "command" | "super" | "meta" => parsed.meta = true,
+                "" => return Err(invalid()),
+                key => {
+                    if !parsed.key.is_empty() {
+                        return Err(invalid());
+                    }
+                    parsed.key = dom_key_name(key).ok_or_else(invalid)?;
+                }
+            }
+        }
+
+        if parsed.key.is_empty() {
+            return Err(invalid());
+        }
+        Ok(parsed)
+    }
+
+    /// Canonical form used to match accelerators written differently
+    fn canonical(&self) -> String {
+        let mut parts = Vec::new();
+        if self.ctrl {
+            parts.push("Ctrl");
+        }
+        if self.alt {
+            parts.push("Alt");
+        }
+        if self.shift {
+            parts.push("Shift");
+        }
+        if self.meta {
+            parts.push("Meta");
+        }
+        parts.push(&self.key);
+        parts.join("+")
+    }
+
+    fn enigo_key(&self) -> Option<Key> {
+        let key = match self.key.as_str() {
+            "Enter" => Key::Return,
+            "Escape" => Key::Escape,
+            " " => Key::Space,
+            "Tab" => Key::Tab,
+            "Backspace" => Key::Backspace,
+            "Delete" => Key::Delete,
+            "ArrowUp" => Key::UpArrow,
+            "ArrowDown" => Key::DownArrow,
+            "ArrowLeft" => Key::LeftArrow,
+            "ArrowRight" => Key::RightArrow,
+            "Home" => Key::Home,
+            "End" => Key::End,
+            "PageUp" => Key::PageUp,
+            "PageDown" => Key::PageDown,
+            "F1" => Key::F1,
+            "F2" => Key::F2,
+            "F3" => Key::F3,
+            "F4" => Key::F4,
+            "F5" => Key::F5,
+            "F6" => Key::F6,
+            "F7" => Key::F7,
+            "F8" => Key::F8,
+            "F9" => Key::F9,
+            "F10" => Key::F10,
+            "F11" => Key::F11,
+            "F12" => Key::F12,
+            other => {
+                let mut chars = other.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Key::Unicode(c),
+                    _ => return None,
+                }
+            }
+        };
+        Some(key)
+    }
+}
+
+/// Map an accelerator key token to the DOM `KeyboardEvent.key` value
+fn dom_key_name(token: &str) -> Option<String> {
+    let name = match token {
+        "enter" | "return" => "Enter",
+        "esc" | "escape" => "Escape",
+        "space" => " ",
+        "tab" => "Tab",
+        "backspace" => "Backspace",
+        "delete" | "del" => "Delete",
+        "up" | "arrowup" => "ArrowUp",
+        "down" | "arrowdown" => "ArrowDown",
+        "left" | "arrowleft" => "ArrowLeft",
+        "right" | "arrowright" => "ArrowRight",
+        "home" => "Home",
+        "end" => "End",
+        "pageup" => "PageUp",
+        "pagedown" => "PageDown",
+        "plus" => "+",
+        other => {
+            if other.chars().count() == 1 {
+                return Some(other.to_string());
+            }
+            let number = other.strip_prefix('f')?.parse::<u8>().ok()?;
+            return (1..=24).contains(&number).then(|| format!("F{}", number));
+        }
+    };
+    Some(name.to_string())
+}
+
+fn parse_request(command: &str, payload: Value) -> Result<(ShortcutRequest, String), Error> {
+    PayloadValidator::new(command, &payload)
+        .required_if(command == "trigger_shortcut", "accelerator", FieldKind::NonEmptyString)
+        .optional("mechanism", FieldKind::OneOf(&["auto", "event", "native", "webview"]))
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: ShortcutRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for {}: {}", command, e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    Ok((request, window_label))
+}
+
+/// Handler function for listing the shortcuts declared in the plugin config and those found in the page
+pub async fn handle_list_shortcuts<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    let (request, window_label) = parse_request("list_shortcuts", payload)?;
+
+    let declared: Vec<Value> = app
+        .tauri_mcp()
+        .shortcuts()
+        .iter()
+        .map(|accelerator| match Accelerator::parse(accelerator) {
+            Ok(parsed) => json!({ "accelerator": accelerator, "normalized": parsed.canonical() }),
+            Err(e) => json!({ "accelerator": accelerator, "error": e.to_string() }),
+        })
+        .collect();
+
+    let js_code = build_probe_script("Shortcut listing error", &json!({}), LIST_SHORTCUTS_JS);
+    let webview = match eval_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "shortcut listing",
+    )
+    .await
+    {
+        Ok(webview) => webview,
+        Err(Error::Anyhow { message }) => json!({ "error": message }),
+        Err(e) => return Err(e),
+    };
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(json!({
+            // From `.shortcuts(...)` in the plugin config; registrations with the
+            // global-shortcut plugin cannot be enumerated
+            "declared": declared,
+            "webview": webview,
+        })),
+        error: None,
+    })
+}
+
+/// Handler function for firing a shortcut through the app, the OS or the webview
+pub async fn handle_trigger_shortcut<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    let (request, window_label) = parse_request("trigger_shortcut", payload)?;
+    let accelerator = request.accelerator.clone().unwrap_or_default();
+    let parsed = Accelerator::parse(&accelerator)?;
+
+    let declared = app
+        .tauri_mcp()
+        .shortcuts()
+        .iter()
+        .any(|declared| Accelerator::parse(declared).is_ok_and(|d| d == parsed));
+
+    // Nothing tells whether the app routes the trigger event, so "auto" synthesizes the
+    // keys in the webview and the event is only emitted when asked for
+    let mechanism = match request.mechanism.as_deref().unwrap_or("auto") {
+        "auto" => "webview",
+        other => other,
+    };
+
+    match mechanism {
+        "event" => {
+            app.emit(
+                SHORTCUT_TRIGGER_EVENT,
+                json!({ "accelerator": accelerator, "normalized": parsed.canonical() }),
+            )
+            .map_err(|e| Error::communication_error_with_context(
+                "Failed to emit shortcut trigger",
+                format!("accelerator: {}, error: {}", accelerator, e),
+            ))?;
+
+            Ok(SocketResponse {
+                success: true,
+                data: Some(json!({
+                    "accelerator": accelerator,
+                    "mechanism": "event",
+                    "declared": declared,
+                    "event": SHORTCUT_TRIGGER_EVENT,
+                    "dispatched": true,
+                    // Only apps that route the event react to it
+                    "handler_called": false,
+                    "note": format!("Emitted the opt-in {} event; the shortcut's registered handler was not called", SHORTCUT_TRIGGER_EVENT),
+                })),
+                error: None,
+            })
+        }
+        "native" => {
+            press_native(&parsed)?;
+
+            Ok(SocketResponse {
+                success: true,
+                data: Some(json!({
+                    "accelerator": accelerator,
+                    "mechanism": "native",
+                    "declared": declared,
+                    "dispatched": true,
+                })),
+                error: None,
+            })
+        }
+        _ => {
+            let js_code = build_probe_script(
+                "Shortcut trigger error",
+                &json!({
+                    "key": parsed.key,
+                    "ctrl": parsed.ctrl,
+                    "alt": parsed.alt,
+                    "shift": parsed.shift,
+                    "meta": parsed.meta,
+                }),
+                TRIGGER_SHORTCUT_JS,
+            );
+
+            let mut result = match eval_probe(
+                app,
+                &window_label,
+                js_code,
+                request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+                "shortcut trigger",
+            )
+            .await
+            {
+                Ok(result) => result,
+                Err(Error::Anyhow { message }) => {
+                    return Ok(SocketResponse {
+                        success: false,
+                        data: None,
+                        error: Some(message),
+                    })
+                }
+                Err(e) => return Err(e),
+            };
+
+            if let Some(object) = result.as_object_mut() {
+                object.insert("accelerator".to_string(), json!(accelerator));
+                object.insert("mechanism".to_string(), json!("webview"));
+                object.insert("declared".to_string(), json!(declared));
+            }
+
+            Ok(SocketResponse {
+                success: true,
+                data: Some(result),
+                error: None,
+            })
+        }
+    }
+}
+
+/// Press the combination at the OS level, which reaches real global shortcut
+/// registrations as well as whichever window has focus
+fn press_native(accelerator: &Accelerator) -> Result<(), Error> {
+    let key = accelerator.enigo_key().ok_or_else(|| {
+        Error::invalid_parameter(
+            "accelerator",
+            "a key that can be pressed natively (letters, digits, F1-F12, arrows, Enter, ...)",
+            &accelerator.key,
+        )
+    })?;
+
+    let mut enigo = Enigo::new(&Settings::default())
+        .map_err(|e| Error::communication_error(format!("Failed to initialize Enigo: {}", e)))?;
+
+    let modifiers: Vec<Key> = [
+        (accelerator.ctrl, Key::Control),
+        (accelerator.alt, Key::Alt),
+        (accelerator.shift, Key::Shift),
+        (accelerator.meta, Key::Meta),
+    ]
+    .into_iter()
+    .filter_map(|(active, key)| active.then_some(key))
+    .collect();
+
+    let press = |enigo: &mut Enigo, key: Key, direction: Direction| {
+        enigo
+            .key(key, direction)
+            .map_err(|e| Error::communication_error(format!("Failed to simulate shortcut: {}", e)))
+    };
+
+    for modifier in &modifiers {
+        press(&mut enigo, *modifier, Direction::Press)?;
+    }
+    let result = press(&mut enigo, key, Direction::Click);
+    // Always release the modifiers so a failed click does not leave them stuck
+    for modifier in modifiers.iter().rev() {
+        press(&mut enigo, *modifier, Direction::Release)?;
+    }
+    result
+}
+
+/// Collects shortcuts the page advertises through `accesskey` and `aria-keyshortcuts`
+const LIST_SHORTCUTS_JS: &str = r#"        const entries = [];
+        document.querySelectorAll('[accesskey], [aria-keyshortcuts]').forEach(el => {
+            const label = (el.getAttribute('aria-label') || el.textContent || '').replace(/\s+/g, ' ').trim().substring(0, 80);
+            if (el.hasAttribute('aria-keyshortcuts')) {
+                el.getAttribute('aria-keyshortcuts').split(/\s+/).filter(Boolean).forEach(shortcut => {
+                    entries.push({ source: 'aria-keyshortcuts', shortcut, selector: mcp.cssPath(el), label });
+                });
+            }
+            if (el.hasAttribute('accesskey')) {
+                entries.push({ source: 'accesskey', shortcut: el.getAttribute('accesskey'), selector: mcp.cssPath(el), label });
+            }
+        });
+        return { url: location.href, shortcuts: entries };"#;
+
+/// Dispatches keydown/keyup for the combination on the focused element; a handler
+/// calling `preventDefault()` is the only signal that something consumed it
+const TRIGGER_SHORTCUT_JS: &str = r#"        const target = document.activeElement && document.activeElement !== document.documentElement
+            ? document.activeElement
+            : document.body;
+
+        let code = args.key;
+        if (/^[a-z]$/i.test(args.key)) code = 'Key' + args.key.toUpperCase();
+        else if (/^[0-9]$/.test(args.key)) code = 'Digit' + args.key;
+        else if (args.key === ' ') code = 'Space';
+
+        const key = args.key.length === 1 && args.shift ? args.key.toUpperCase() : args.key;
+        const init = {
+            key,
+            code,
+            ctrlKey: args.ctrl,
+            altKey: args.alt,
+            shiftKey: args.shift,
+            metaKey: args.meta,
+            bubbles: true,
+            cancelable: true,
+            composed: true,
+            view: window
+        };
+
+        const down = new KeyboardEvent('keydown', init);
+        const notCanceled = target.dispatchEvent(down);
+        target.dispatchEvent(new KeyboardEvent('keyup', init));
+
+        return {
+            target: mcp.cssPath(target),
+            key,
+            code,
+            default_prevented: !notCanceled,
+            handled: !notCanceled
+        };"#;
@@ -1,9 +1,12 @@
 use serde_json::{json, Value};
-use tauri::{AppHandle, Runtime, Manager, Emitter, Listener};
+use tauri::{AppHandle, Runtime, Manager};
 use log::info;
 
 use crate::error::Error;
 use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::request_js_response;
+use crate::tools::validation::{FieldKind, PayloadValidator};
+use crate::TauriMcpExt;
 
 #[allow(dead_code)]
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -55,26 +58,10 @@ pub async fn handle_state_dump<R: Runtime>(
         request.path.clone(),
     );
 
-    // Execute the JavaScript in the window
-    let (tx, rx) = std::sync::mpsc::channel();
-
-    app.emit_to(&window_label, "execute-js", &js_code)
-        .map_err(|e| Error::communication_error_with_context(
-            "Failed to emit execute-js event",
-            format!("window: {}, error: {}", window_label, e),
-        ))?;
-
-    // Listen for response
-    app.once("execute-js-response", move |event| {
-        let payload = event.payload().to_string();
-        let _ = tx.send(payload);
-    });
-
-    // Wait for the response with timeout
-    let timeout = std::time::Duration::from_millis(request.timeout_ms.unwrap_or(5000));
-    let result_string = rx
-        .recv_timeout(timeout)
-        .map_err(|_| Error::timeout_error("state dump execution", request.timeout_ms.unwrap_or(5000)))?;
+    // Execute the JavaScript in the window and wait for its own response
+    let timeout_ms = request.timeout_ms.unwrap_or(5000);
+    let result_string = request_js_response(app, &window_label, &js_code, true, std::time::Duration::from_millis(timeout_ms))
+        .map_err(|e| e.into_error(&window_label, "state dump execution", timeout_ms))?;
 
     // Parse the response
     let response_value: Value = serde_json::from_str(&result_string)
@@ -135,6 +122,200 @@ pub async fn handle_state_dump<R: Runtime>(
     }
 }
 
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct DiffStateRequest {
+    /// "diff" (default) compares a fresh dump against the baseline, "save_baseline" stores a
+    /// fresh dump, "clear" drops the baseline, "list" returns the stored baseline ids
+    action: Option<String>,
+    baseline_id: Option<String>,
+    /// Replace the baseline with the fresh dump after diffing
+    update_baseline: Option<bool>,
+    /// Maximum number of changes to report (default 200)
+    max_changes: Option<usize>,
+    window_label: Option<String>,
+    max_depth: Option<usize>,
+    path: Option<String>,
+    timeout_ms: Option<u64>,
+}
+
+/// Most baselines `diff_state` keeps; saving another drops the least recently used one
+pub const MAX_STATE_BASELINES: usize = 32;
+
+/// Handler function for diffing the application state against a stored baseline
+pub async fn handle_diff_state<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    let validator = PayloadValidator::new("diff_state", &payload)
+        .optional("action", FieldKind::OneOf(&["diff", "save_baseline", "clear", "list"]));
+    let action = validator
+        .field("action")
+        .and_then(|v| v.as_str())
+        .unwrap_or("diff")
+        .to_string();
+    validator
+        .required_if(action != "list", "baseline_id", FieldKind::NonEmptyString)
+        .optional("update_baseline", FieldKind::Bool)
+        .optional("max_changes", FieldKind::Integer { min: Some(1), max: None })
+        .optional("window_label", FieldKind::String)
+        .optional("max_depth", FieldKind::Integer { min: Some(1), max: None })
+        .optional("path", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: DiffStateRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for diff_state: {}", e)))?;
+
+    let mcp = app.tauri_mcp();
+    let baseline_id = request.baseline_id.clone().unwrap_or_default();
+
+    match action.as_str() {
+        "list" => {
+            return Ok(SocketResponse {
+                success: true,
+                data: Some(json!({
+                    "baselines": mcp.state_baseline_ids(),
+                    "max_baselines": MAX_STATE_BASELINES,
+                })),
+                error: None,
+            })
+        }
+        "clear" => {
+            return Ok(SocketResponse {
+                success: true,
+                data: Some(json!({
+                    "baseline_id": baseline_id,
+                    "removed": mcp.remove_state_baseline(&baseline_id),
+                })),
+                error: None,
+            })
+        }
+        _ => {}
+    }
+
+    // Reuse state_dump so the baseline and the fresh dump are produced the same way
+    let dump = handle_state_dump(
+        app,
+        json!({
+            "window_label": request.window_label,
+            "max_depth": request.max_depth,
+            "path": request.path,
+            "timeout_ms": request.timeout_ms,
+        }),
+    )
+    .await?;
+
+    if !dump.success {
+        return Ok(dump);
+    }
+
+    let current = dump
+        .data
+        .as_ref()
+        .and_then(|data| data.get("state"))
+        .cloned()
+        .ok_or_else(|| Error::Anyhow { message: "State dump returned no state to compare".to_string() })?;
+
+    if action == "save_baseline" {
+        let evicted = mcp.set_state_baseline(&baseline_id, current);
+        return Ok(SocketResponse {
+            success: true,
+            data: Some(json!({
+                "baseline_id": baseline_id,
+                "saved": true,
+                "evicted_baseline": evicted,
+                "detected_libraries": dump.data.as_ref().and_then(|data| data.get("detected_libraries")),
+            })),
+            error: None,
+        });
+    }
+
+    let baseline = mcp.state_baseline(&baseline_id).ok_or_else(|| Error::Anyhow {
+        message: format!(
+            "No baseline stored with id '{}'; save one first with action 'save_baseline'",
+            baseline_id
+        ),
+    })?;
+
+    let mut changes = Vec::new();
+    diff_values("", &baseline, &current, &mut changes);
+
+    let total_changes = changes.len();
+    let max_changes = request.max_changes.unwrap_or(200);
+    changes.truncate(max_changes);
+
+    let count = |kind: &str| changes.iter().filter(|c| c["type"] == kind).count();
+    let summary = json!({
+        "added": count("added"),
+        "removed": count("removed"),
+        "changed": count("changed"),
+    });
+
+    // The baseline was just read, so updating it never evicts another
+    let updated = request.update_baseline.unwrap_or(false);
+    if updated {
+        mcp.set_state_baseline(&baseline_id, current);
+    }
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(json!({
+            "baseline_id": baseline_id,
+            "identical": total_changes == 0,
+            "total_changes": total_changes,
+            "truncated": total_changes > max_changes,
+            "summary": summary,
+            "changes": changes,
+            "baseline_updated": updated,
+        })),
+        error: None,
+    })
+}
+
+fn join_key(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// Collect added/removed/changed paths between two JSON values, descending into
+/// objects and arrays so each change points at the innermost differing field
+pub(crate) fn diff_values(path: &str, before: &Value, after: &Value, changes: &mut Vec<Value>) {
+    match (before, after) {
+        (Value::Object(a), Value::Object(b)) => {
+            for (key, old) in a {
+                match b.get(key) {
+                    Some(new) => diff_values(&join_key(path, key), old, new, changes),
+                    None => changes.push(json!({ "type": "removed", "path": join_key(path, key), "before": old })),
+                }
+            }
+            for (key, new) in b {
+                if !a.contains_key(key) {
+                    changes.push(json!({ "type": "added", "path": join_key(path, key), "after": new }));
+                }
+            }
+        }
+        (Value::Array(a), Value::Array(b)) => {
+            for (index, old) in a.iter().enumerate() {
+                let item_path = format!("{}[{}]", path, index);
+                match b.get(index) {
+                    Some(new) => diff_values(&item_path, old, new, changes),
+                    None => changes.push(json!({ "type": "removed", "path": item_path, "before": old })),
+                }
+            }
+            for (index, new) in b.iter().enumerate().skip(a.len()) {
+                changes.push(json!({ "type": "added", "path": format!("{}[{}]", path, index), "after": new }));
+            }
+        }
+        _ if before != after => {
+            changes.push(json!({ "type": "changed", "path": path, "before": before, "after": after }));
+        }
+        _ => {}
+    }
+}
+
 /// Generate the JavaScript code to introspect application state
 fn generate_state_dump_code(max_depth: usize, path: Option<String>) -> String {
     let path_str = path.unwrap_or_else(String::new);
@@ -148,6 +329,7 @@ fn generate_state_dump_code(max_depth: usize, path: Option<String>) -> String {
         const libraries = [];
         const MAX_DEPTH = {};
         const MAX_SIZE = 1000000;
+        const MAX_PATH_MATCHES = 1000;
         let currentSize = 0;
         const seen = new WeakSet();
 
@@ -230,14 +412,62 @@ fn generate_state_dump_code(max_depth: usize, path: Option<String>) -> String {
             return '[Object ' + (value.constructor?.name || 'Unknown') + ']';
         }}
 
-        function getByPath(obj, path) {{
-            const parts = path.split('.');
-            let current = obj;
-            for (const part of parts) {{
-                if (current == null) return undefined;
-                current = current[part];
+        // Path syntax: dotted keys, [0] indices, ["quoted keys"] and * / [*] wildcards
+        function parsePath(path) {{
+            const tokens = [];
+            let i = 0;
+            while (i < path.length) {{
+                if (path[i] === '.') {{
+                    i++;
+                    continue;
+                }}
+                if (path[i] === '[') {{
+                    const end = path.indexOf(']', i);
+                    if (end < 0) throw new Error('Unclosed [ in path ' + path);
+                    const inner = path.slice(i + 1, end).trim();
+                    if (inner === '*') tokens.push({{ wildcard: true }});
+                    else if (/^\d+$/.test(inner)) tokens.push({{ key: inner }});
+                    else if (/^(['"]).*\1$/.test(inner)) tokens.push({{ key: inner.slice(1, -1) }});
+                    else throw new Error('Invalid segment [' + inner + '] in path ' + path);
+                    i = end + 1;
+                    continue;
+                }}
+                let end = i;
+                while (end < path.length && path[end] !== '.' && path[end] !== '[') end++;
+                const key = path.slice(i, end);
+                tokens.push(key === '*' ? {{ wildcard: true }} : {{ key }});
+                i = end;
             }}
-            return current;
+            return tokens;
+        }}
+
+        function joinPath(base, key, isIndex) {{
+            if (isIndex) return base + '[' + key + ']';
+            if (/^[A-Za-z_$][\w$]*$/.test(key)) return base ? base + '.' + key : key;
+            return base + '[' + JSON.stringify(key) + ']';
+        }}
+
+        // Every [resolved path, value] pair the path matches, wildcards expanded
+        function resolvePath(obj, path) {{
+            const matches = [];
+            const walk = (value, tokens, at) => {{
+                if (matches.length >= MAX_PATH_MATCHES) return;
+                if (tokens.length === 0) {{
+                    matches.push([at, value]);
+                    return;
+                }}
+                if (value == null || typeof value !== 'object') return;
+                const [token, ...rest] = tokens;
+                if (token.wildcard) {{
+                    if (Array.isArray(value)) value.forEach((item, index) => walk(item, rest, joinPath(at, String(index), true)));
+                    else for (const key of Object.keys(value)) walk(value[key], rest, joinPath(at, key, false));
+                }} else if (Object.prototype.hasOwnProperty.call(value, token.key)) {{
+                    walk(value[token.key], rest, joinPath(at, token.key, Array.isArray(value)));
+                }}
+            }};
+            const tokens = parsePath(path);
+            walk(obj, tokens, '');
+            return {{ matches, wildcard: tokens.some(token => token.wildcard) }};
         }}
 
         if (typeof window !== 'undefined' && window.__zustand_state) {{
@@ -331,19 +561,25 @@ fn generate_state_dump_code(max_depth: usize, path: Option<String>) -> String {
         max_depth
     );
 
-    // Add path handling
+    // Add path handling; a path without wildcards keeps its own spelling as the key
     if !path_str.is_empty() {
         code.push_str(&format!(
             r#"        try {{
-            const pathValue = getByPath(result, '{}');
+            const path = {};
+            const {{ matches, wildcard }} = resolvePath(result, path);
             finalResult = {{}};
-            finalResult['{}'] = safeStringify(pathValue, 0);
+            if (wildcard) {{
+                for (const [resolved, value] of matches) finalResult[resolved] = value;
+                if (matches.length >= MAX_PATH_MATCHES) errors.push('path-filter: stopped after ' + MAX_PATH_MATCHES + ' matches');
+            }} else if (matches.length > 0) {{
+                finalResult[path] = safeStringify(matches[0][1], 0);
+            }}
+            if (matches.length === 0) errors.push('path-filter: nothing matched ' + path);
         }} catch (e) {{
             errors.push('path-filter: ' + e.message);
         }}
 "#,
-            path_str.replace("'", "\\'"),
-            path_str.replace("'", "\\'")
+            Value::String(path_str)
         ));
     }
 
@@ -361,6 +597,7 @@ fn generate_state_dump_code(max_depth: usize, path: Option<String>) -> String {
 
         window.dispatchEvent(new CustomEvent('execute-js-response', {
             detail: {
+                request_id: typeof __mcpRequestId === 'undefined' ? null : __mcpRequestId,
                 result: JSON.stringify(response),
                 type: 'object'
             }
@@ -368,6 +605,7 @@ fn generate_state_dump_code(max_depth: usize, path: Option<String>) -> String {
     } catch (error) {
         window.dispatchEvent(new CustomEvent('execute-js-response', {
             detail: {
+                request_id: typeof __mcpRequestId === 'undefined' ? null : __mcpRequestId,
                 error: 'State dump error: ' + error.message,
                 type: 'error'
             }
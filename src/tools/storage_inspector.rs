@@ -1,12 +1,16 @@
 use serde::{Serialize, Serializer};
-use serde_json::Value;
+use serde_json::{json, Value};
 use std::fmt;
 use std::sync::mpsc;
 use std::time::Duration;
-use tauri::{AppHandle, Emitter, Listener, Manager, Runtime};
+use tauri::webview::Cookie;
+use tauri::{AppHandle, Emitter, Listener, Manager, Runtime, WebviewWindow};
 
 use crate::error::Error;
 use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, eval_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::storage_purge::EXPIRE_COOKIES_JS;
+use crate::tools::validation::{FieldKind, PayloadValidator};
 
 // Define a custom error type for storage inspector operations
 #[derive(Debug)]
@@ -50,7 +54,6 @@ impl From<mpsc::RecvTimeoutError> for StorageInspectorError {
 }
 
 // Storage types
-#[allow(dead_code)]
 #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum StorageType {
@@ -59,7 +62,6 @@ pub enum StorageType {
     IndexedDB,
 }
 
-#[allow(dead_code)]
 impl StorageType {
     pub fn from_str(s: &str) -> Option<Self> {
         match s.to_lowercase().as_str() {
@@ -69,6 +71,15 @@ impl StorageType {
             _ => None,
         }
     }
+
+    /// Spelling the page script compares against
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StorageType::LocalStorage => "localStorage",
+            StorageType::SessionStorage => "sessionStorage",
+            StorageType::IndexedDB => "indexedDB",
+        }
+    }
 }
 
 // Storage item (key-value pair)
@@ -127,13 +138,15 @@ pub struct IndexedDBQueryResult {
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
 pub struct StorageInspectorRequest {
     pub window_label: Option<String>,
-    pub action: String, // "get_storage", "clear_storage", "list_indexeddb", "query_indexeddb"
+    pub action: String, // "get_storage", "clear_storage", "set_item", "remove_item", "list_indexeddb", "query_indexeddb", "get_cookies", "clear_cookies"
     pub storage_type: Option<String>, // "localStorage", "sessionStorage", "indexedDB"
-    pub key_pattern: Option<String>, // regex or substring for filtering
+    pub key_pattern: Option<String>, // regex or substring for filtering, also applied to cookie names
     pub page: Option<usize>,
     pub page_size: Option<usize>,
     pub db_name: Option<String>, // for IndexedDB operations
     pub store_name: Option<String>, // for IndexedDB operations
+    pub key: Option<String>, // for set_item and remove_item
+    pub value: Option<Value>, // for set_item, must be a string
 }
 
 // Handler function for the socket server
@@ -142,9 +155,14 @@ pub async fn handle_get_storage_inspector<R: Runtime>(
     payload: Value,
 ) -> Result<SocketResponse, Error> {
     // Parse params from payload
-    let params: StorageInspectorRequest = serde_json::from_value(payload)
+    let mut params: StorageInspectorRequest = serde_json::from_value(payload)
         .map_err(|e| Error::serialization_error(format!("Invalid payload for storage inspector: {}", e)))?;
 
+    // Storage types match case-insensitively, but the page script compares exact spellings
+    if let Some(storage_type) = params.storage_type.as_deref().and_then(StorageType::from_str) {
+        params.storage_type = Some(storage_type.as_str().to_string());
+    }
+
     // Validate input parameters
     match params.action.as_str() {
         "get_storage" => {
@@ -165,7 +183,33 @@ pub async fn handle_get_storage_inspector<R: Runtime>(
                 });
             }
         }
-        "list_indexeddb" => {
+        "set_item" | "remove_item" => {
+            let Some(storage_type) = params.storage_type.as_deref() else {
+                return Ok(SocketResponse {
+                    success: false,
+                    data: None,
+                    error: Some(format!("storage_type is required for {} action", params.action)),
+                });
+            };
+            if !matches!(
+                StorageType::from_str(storage_type),
+                Some(StorageType::LocalStorage | StorageType::SessionStorage)
+            ) {
+                return Err(Error::invalid_parameter("storage_type", "localStorage or sessionStorage", storage_type));
+            }
+            if params.key.is_none() {
+                return Ok(SocketResponse {
+                    success: false,
+                    data: None,
+                    error: Some(format!("key is required for {} action", params.action)),
+                });
+            }
+            if params.action == "set_item" && !params.value.as_ref().is_some_and(Value::is_string) {
+                let received = params.value.as_ref().map(|v| v.to_string()).unwrap_or_else(|| "nothing".to_string());
+                return Err(Error::invalid_parameter("value", "a string", received));
+            }
+        }
+        "list_indexeddb" | "get_cookies" | "clear_cookies" => {
             // No validation needed
         }
         "query_indexeddb" => {
@@ -191,10 +235,29 @@ pub async fn handle_get_storage_inspector<R: Runtime>(
         .window_label
         .clone()
         .unwrap_or_else(|| "main".to_string());
-    let _window = app
+    let window = app
         .get_webview_window(&window_label)
         .ok_or_else(|| Error::window_not_found(&window_label))?;
 
+    // Cookies are read natively and through document.cookie rather than by the guest listener
+    let key_pattern = params.key_pattern.as_deref();
+    match params.action.as_str() {
+        "get_cookies" => {
+            let data = get_cookies(app, &window, &window_label, key_pattern, DEFAULT_PROBE_TIMEOUT_MS).await?;
+            return Ok(SocketResponse { success: true, data: Some(data), error: None });
+        }
+        "clear_cookies" => {
+            let js_code = build_probe_script(
+                "Cookie clearing error",
+                &json!({ "key_pattern": key_pattern }),
+                &format!("{}\n{}\n{}", EXPIRE_COOKIES_JS, COOKIE_FILTER_JS, CLEAR_COOKIES_JS),
+            );
+            let data = eval_probe(app, &window_label, js_code, DEFAULT_PROBE_TIMEOUT_MS, "cookie clearing").await?;
+            return Ok(SocketResponse { success: true, data: Some(data), error: None });
+        }
+        _ => {}
+    }
+
     // Call the implementation function with cloned app handle and params
     let result = perform_storage_inspector_operation(app.clone(), params.clone()).await;
 
@@ -269,3 +332,171 @@ async fn perform_storage_inspector_operation<R: Runtime>(
         Err(e) => Err(e.into()),
     }
 }
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct GetCookiesRequest {
+    window_label: Option<String>,
+    /// Regex or substring the cookie name must match
+    key_pattern: Option<String>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for listing a window's cookies
+pub async fn handle_get_cookies<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_cookies", &payload)
+        .optional("key_pattern", FieldKind::String)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: GetCookiesRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_cookies: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+    let window = app
+        .get_webview_window(&window_label)
+        .ok_or_else(|| Error::window_not_found(&window_label))?;
+
+    let data = get_cookies(
+        app,
+        &window,
+        &window_label,
+        request.key_pattern.as_deref(),
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+    )
+    .await?;
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(data),
+        error: None,
+    })
+}
+
+/// Cookies from the webview's native cookie store, merged with the ones `document.cookie`
+/// shows the page. The native store only holds cookies of http(s) URLs, so cookies set on
+/// custom protocols such as tauri:// are known from the page alone.
+async fn get_cookies<R: Runtime>(
+    app: &AppHandle<R>,
+    window: &WebviewWindow<R>,
+    window_label: &str,
+    key_pattern: Option<&str>,
+    timeout_ms: u64,
+) -> Result<Value, Error> {
+    // Reading cookies blocks on the webview (and deadlocks WebView2 on its own thread)
+    let native_window = window.clone();
+    let native = tauri::async_runtime::spawn_blocking(move || {
+        let url = native_window.url().map_err(|e| e.to_string())?;
+        let cookies = if matches!(url.scheme(), "http" | "https") {
+            native_window.cookies_for_url(url)
+        } else {
+            native_window.cookies()
+        };
+        cookies.map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(|e| e.to_string())
+    .and_then(|cookies| cookies);
+
+    let (native_cookies, native_error) = match native {
+        Ok(cookies) => (Some(cookies.iter().map(cookie_to_json).collect::<Vec<_>>()), None),
+        Err(e) => (None, Some(e)),
+    };
+
+    let js_code = build_probe_script(
+        "Cookie lookup error",
+        &json!({ "key_pattern": key_pattern, "native": native_cookies }),
+        &format!("{}\n{}", COOKIE_FILTER_JS, GET_COOKIES_JS),
+    );
+    let mut data = eval_probe(app, window_label, js_code, timeout_ms, "cookie lookup").await?;
+
+    let http_only_count = data
+        .get("cookies")
+        .and_then(|v| v.as_array())
+        .map(|cookies| cookies.iter().filter(|c| c["http_only"] == json!(true)).count())
+        .unwrap_or(0);
+    let note = if native_error.is_none() {
+        "HttpOnly cookies cannot be read by page script, so their values are reported as null"
+    } else {
+        "The native cookie store is unavailable, so only cookies visible to document.cookie are listed; HttpOnly cookies are missing and domain, path, expiry and flags are unknown"
+    };
+    if let Some(object) = data.as_object_mut() {
+        object.insert(
+            "metadata".to_string(),
+            json!({
+                "native_store": native_error.is_none(),
+                "native_error": native_error,
+                "http_only_count": http_only_count,
+                "note": note,
+            }),
+        );
+    }
+    Ok(data)
+}
+
+fn cookie_to_json(cookie: &Cookie<'static>) -> Value {
+    let http_only = cookie.http_only().unwrap_or(false);
+    json!({
+        "name": cookie.name(),
+        "value": if http_only { Value::Null } else { json!(cookie.value()) },
+        "domain": cookie.domain(),
+        "path": cookie.path(),
+        // Unix seconds, null for session cookies
+        "expires": cookie.expires_datetime().map(|at| at.unix_timestamp()),
+        "http_only": http_only,
+        "secure": cookie.secure().unwrap_or(false),
+        "same_site": cookie.same_site().map(|same_site| same_site.to_string()),
+    })
+}
+
+/// Defines `matchesPattern(name)` for `args.key_pattern`, a case-insensitive regex that
+/// falls back to a substring match like the guest's storage filter
+const COOKIE_FILTER_JS: &str = r#"        const matchesPattern = (name) => {
+            if (!args.key_pattern) return true;
+            try {
+                return new RegExp(args.key_pattern, 'i').test(name);
+            } catch (e) {
+                return name.toLowerCase().includes(args.key_pattern.toLowerCase());
+            }
+        };"#;
+
+/// Native entries are authoritative; cookies only the page sees are added with unknown attributes
+const GET_COOKIES_JS: &str = r#"        const pageCookies = document.cookie.split(';').map(part => part.trim()).filter(Boolean).map(part => {
+            const eq = part.indexOf('=');
+            return eq < 0 ? { name: '', value: part } : { name: part.slice(0, eq), value: part.slice(eq + 1) };
+        });
+        const cookies = (args.native || []).map(cookie => ({ ...cookie, source: 'native' }));
+        for (const cookie of pageCookies) {
+            if (cookies.some(known => known.name === cookie.name && !known.http_only)) continue;
+            cookies.push({
+                name: cookie.name,
+                value: cookie.value,
+                domain: null,
+                path: null,
+                expires: null,
+                http_only: false,
+                secure: null,
+                same_site: null,
+                source: 'document'
+            });
+        }
+        const filtered = cookies.filter(cookie => matchesPattern(cookie.name));
+        return { url: location.href, count: filtered.length, cookies: filtered };"#;
+
+/// Only cookies visible to script can be expired; see `EXPIRE_COOKIES_JS`
+const CLEAR_COOKIES_JS: &str = r#"        const before = cookieNames().filter(matchesPattern);
+        expireCookies(before);
+        const remaining = cookieNames().filter(matchesPattern);
+        return {
+            cleared: before.length - remaining.length,
+            remaining: remaining.length,
+            remaining_names: remaining,
+            note: 'HttpOnly cookies are invisible to script; use purge_all_storage with clear_browsing_data to remove them'
+        };"#;
@@ -0,0 +1,183 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Manager, Runtime};
+use log::info;
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, eval_probe};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct PurgeAllStorageRequest {
+    window_label: Option<String>,
+    /// Also wipe the webview's whole data store natively, including HttpOnly cookies and
+    /// the HTTP cache (default false)
+    clear_browsing_data: Option<bool>,
+    /// How long to wait for a blocked IndexedDB deletion (default 2000)
+    blocked_wait_ms: Option<u64>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for clearing every storage subsystem of a window in one go
+pub async fn handle_purge_all_storage<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("purge_all_storage", &payload)
+        .optional("clear_browsing_data", FieldKind::Bool)
+        .optional("blocked_wait_ms", FieldKind::Integer { min: Some(0), max: Some(10_000) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: PurgeAllStorageRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for purge_all_storage: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let blocked_wait_ms = request.blocked_wait_ms.unwrap_or(2000);
+    let js_code = build_probe_script(
+        "Storage purge error",
+        &json!({ "blocked_wait_ms": blocked_wait_ms }),
+        &format!("{}\n{}", EXPIRE_COOKIES_JS, PURGE_ALL_STORAGE_JS),
+    );
+    let mut data = eval_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(10_000).saturating_add(blocked_wait_ms),
+        "storage purge",
+    )
+    .await?;
+
+    let mut success = data.get("success").and_then(|v| v.as_bool()).unwrap_or(false);
+    if request.clear_browsing_data.unwrap_or(false) {
+        let window = app
+            .get_webview_window(&window_label)
+            .ok_or_else(|| Error::window_not_found(&window_label))?;
+        info!("[TAURI_MCP] Clearing all browsing data of window {}", window_label);
+        let native = match window.clear_all_browsing_data() {
+            Ok(()) => json!({ "success": true }),
+            Err(e) => {
+                success = false;
+                json!({ "success": false, "error": e.to_string() })
+            }
+        };
+        if let Some(subsystems) = data.get_mut("subsystems").and_then(|v| v.as_object_mut()) {
+            subsystems.insert("browsing_data".to_string(), native);
+        }
+        if let Some(object) = data.as_object_mut() {
+            object.insert("success".to_string(), json!(success));
+        }
+    }
+
+    Ok(SocketResponse {
+        success,
+        data: Some(data),
+        error: (!success).then(|| "Some storage subsystems could not be fully cleared; see subsystems".to_string()),
+    })
+}
+
+/// Defines `cookieNames()`, the cookie names visible to script, and `expireCookies(names)`.
+pub(crate) const EXPIRE_COOKIES_JS: &str = r#"        const cookieNames = () => document.cookie.split(';').map(part => part.split('=')[0].trim()).filter(Boolean);
+        // A cookie only goes away when expired with the domain and path it was set with
+        const expireCookies = (names) => {
+            const hosts = [null];
+            const labels = location.hostname.split('.');
+            for (let i = 0; i < labels.length - 1; i++) {
+                const domain = labels.slice(i).join('.');
+                hosts.push(domain, '.' + domain);
+            }
+            const paths = ['/'];
+            const segments = location.pathname.split('/').filter(Boolean);
+            for (let i = 1; i <= segments.length; i++) paths.push('/' + segments.slice(0, i).join('/'));
+            for (const name of new Set(names)) {
+                for (const host of hosts) {
+                    for (const path of paths) {
+                        document.cookie = name + '=; expires=Thu, 01 Jan 1970 00:00:00 GMT; max-age=0; path=' + path + (host ? '; domain=' + host : '');
+                    }
+                }
+            }
+        };"#;
+
+/// Each subsystem is cleared independently so one failure does not stop the rest.
+/// Cookies can only be expired from script, so HttpOnly cookies and cookies scoped to
+/// other paths survive unless `clear_browsing_data` is set. Deleting an IndexedDB
+/// database the app still has open is blocked until the app closes its connection.
+const PURGE_ALL_STORAGE_JS: &str = r#"        const subsystems = {};
+        const run = async (name, clear) => {
+            try {
+                subsystems[name] = { success: true, ...(await clear()) };
+            } catch (error) {
+                subsystems[name] = { success: false, error: String(error && error.message || error) };
+            }
+        };
+
+        const webStorage = (storage) => async () => {
+            const keys = storage.length;
+            storage.clear();
+            return { cleared: keys, remaining: storage.length };
+        };
+        await run('local_storage', webStorage(localStorage));
+        await run('session_storage', webStorage(sessionStorage));
+
+        await run('cookies', async () => {
+            const before = cookieNames();
+            expireCookies(before);
+            const remaining = cookieNames();
+            return {
+                cleared: before.length - remaining.length,
+                remaining: remaining.length,
+                remaining_names: remaining,
+                note: 'HttpOnly cookies are invisible to script; use clear_browsing_data to remove them'
+            };
+        });
+
+        await run('indexed_db', async () => {
+            if (!window.indexedDB) return { supported: false, cleared: 0 };
+            if (typeof indexedDB.databases !== 'function') throw new Error('indexedDB.databases() is not available, so databases cannot be listed');
+            const databases = await indexedDB.databases();
+            const results = await Promise.all(databases.map(db => new Promise(resolve => {
+                const request = indexedDB.deleteDatabase(db.name);
+                let timer = null;
+                request.onsuccess = () => { clearTimeout(timer); resolve({ name: db.name, status: 'deleted' }); };
+                request.onerror = () => { clearTimeout(timer); resolve({ name: db.name, status: 'error', error: String(request.error) }); };
+                request.onblocked = () => {
+                    timer = setTimeout(() => resolve({ name: db.name, status: 'blocked' }), args.blocked_wait_ms);
+                };
+            })));
+            const failed = results.filter(result => result.status !== 'deleted');
+            if (failed.length > 0) {
+                throw new Error('Could not delete ' + failed.map(result => result.name + ' (' + result.status + ')').join(', ') + '; blocked databases are still open in the app and are deleted once it closes them');
+            }
+            return { cleared: results.length, databases: results.map(result => result.name) };
+        });
+
+        await run('cache_storage', async () => {
+            if (!window.caches) return { supported: false, cleared: 0 };
+            const names = await caches.keys();
+            const deleted = await Promise.all(names.map(name => caches.delete(name)));
+            return { cleared: deleted.filter(Boolean).length, caches: names };
+        });
+
+        await run('service_workers', async () => {
+            if (!('serviceWorker' in navigator)) return { supported: false, cleared: 0 };
+            const registrations = await navigator.serviceWorker.getRegistrations();
+            const results = await Promise.all(registrations.map(reg => reg.unregister()));
+            return {
+                cleared: results.filter(Boolean).length,
+                scopes: registrations.map(reg => reg.scope),
+                // The controller keeps serving this page until it reloads
+                still_controlling: navigator.serviceWorker.controller !== null
+            };
+        });
+
+        return {
+            success: Object.values(subsystems).every(result => result.success),
+            origin: location.origin,
+            subsystems
+        };"#;
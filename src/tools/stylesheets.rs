@@ -0,0 +1,293 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct GetStylesheetRequest {
+    window_label: Option<String>,
+    /// Position in `document.styleSheets`, followed by adopted stylesheets
+    index: Option<usize>,
+    /// URL of the stylesheet; an exact match wins, otherwise the first href containing it
+    href: Option<String>,
+    /// Maximum number of rules returned, counting nested rules (default 500)
+    max_rules: Option<usize>,
+    /// Read the rules of `@import`ed sheets into the import rule (default true)
+    include_imports: Option<bool>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for returning the parsed rules of one stylesheet
+pub async fn handle_get_stylesheet<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_stylesheet", &payload)
+        .optional("index", FieldKind::UINT)
+        .optional("href", FieldKind::NonEmptyString)
+        .optional("max_rules", FieldKind::Integer { min: Some(1), max: Some(20_000) })
+        .optional("include_imports", FieldKind::Bool)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: GetStylesheetRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_stylesheet: {}", e)))?;
+
+    if request.index.is_some() && request.href.is_some() {
+        return Err(Error::invalid_parameter(
+            "index/href",
+            "either index or href, not both",
+            "both",
+        ));
+    }
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Stylesheet error",
+        &json!({
+            "index": request.index,
+            "href": request.href,
+            "max_rules": request.max_rules.unwrap_or(500),
+            "include_imports": request.include_imports.unwrap_or(true),
+        }),
+        GET_STYLESHEET_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "stylesheet inspection",
+    )
+    .await
+}
+
+/// Sheets are numbered like `get_css_coverage` does: `document.styleSheets` first, then
+/// adopted stylesheets. Without an index or href the sheets are listed instead. Each
+/// rule carries a dotted `path` of its position in the tree, and style rules list the
+/// specificity of every selector in their list. Declarations come from the CSSOM, so
+/// shorthands appear expanded into their longhands.
+const GET_STYLESHEET_JS: &str = r#"        const sheets = [...document.styleSheets, ...(document.adoptedStyleSheets || [])];
+        const adoptedStart = document.styleSheets.length;
+        const readRules = (sheet) => {
+            try {
+                return { rules: sheet.cssRules, error: null };
+            } catch (error) {
+                return { rules: null, error: String(error && error.message || error) };
+            }
+        };
+        const describeSheet = (sheet, index) => {
+            const owner = sheet.ownerNode;
+            const { rules } = readRules(sheet);
+            return {
+                index,
+                href: sheet.href || null,
+                source: sheet.href || (owner ? 'inline <' + owner.tagName.toLowerCase() + '> ' + mcp.cssPath(owner) : 'adopted stylesheet #' + (index - adoptedStart)),
+                adopted: index >= adoptedStart,
+                title: sheet.title || null,
+                media: sheet.media && sheet.media.mediaText ? sheet.media.mediaText : null,
+                disabled: sheet.disabled,
+                accessible: rules !== null,
+                top_level_rules: rules ? rules.length : null
+            };
+        };
+
+        if (args.index === null && args.href === null) {
+            return { count: sheets.length, sheets: sheets.map(describeSheet) };
+        }
+
+        let index = args.index;
+        if (args.href !== null) {
+            index = sheets.findIndex(sheet => sheet.href === args.href);
+            if (index < 0) index = sheets.findIndex(sheet => sheet.href && sheet.href.includes(args.href));
+            if (index < 0) throw new Error('No stylesheet with an href matching ' + JSON.stringify(args.href) + '; call without index or href to list them');
+        }
+        if (index >= sheets.length) throw new Error('Stylesheet index ' + index + ' is out of range; the document has ' + sheets.length);
+        const sheet = sheets[index];
+        const info = describeSheet(sheet, index);
+
+        // Specificity as [ids, classes, types]; :where() counts nothing and
+        // :is()/:not()/:has() count their most specific argument
+        const splitList = (text) => {
+            const parts = [];
+            let depth = 0;
+            let quote = null;
+            let current = '';
+            for (const ch of text) {
+                if (quote) {
+                    if (ch === quote) quote = null;
+                } else if (ch === '"' || ch === "'") {
+                    quote = ch;
+                } else if (ch === '(' || ch === '[') {
+                    depth += 1;
+                } else if (ch === ')' || ch === ']') {
+                    depth -= 1;
+                } else if (ch === ',' && depth === 0) {
+                    parts.push(current.trim());
+                    current = '';
+                    continue;
+                }
+                current += ch;
+            }
+            if (current.trim()) parts.push(current.trim());
+            return parts;
+        };
+        const maxSpec = (list) => splitList(list).map(specificity).reduce((best, spec) => compare(spec, best) > 0 ? spec : best, [0, 0, 0]);
+        const compare = (a, b) => a[0] - b[0] || a[1] - b[1] || a[2] - b[2];
+        const specificity = (selector) => {
+            const spec = [0, 0, 0];
+            let rest = selector.replace(/\\./g, 'x').replace(/"[^"]*"|'[^']*'/g, '""');
+            // Functional pseudo-classes, innermost first
+            const FUNCTIONAL = /:(where|is|not|has|matches|-webkit-any|nth-child|nth-last-child|host|host-context|slotted|part)\(([^()]*)\)/;
+            for (let match = FUNCTIONAL.exec(rest); match; match = FUNCTIONAL.exec(rest)) {
+                const [whole, name, inner] = match;
+                let add = [0, 0, 0];
+                if (name === 'where') add = [0, 0, 0];
+                else if (name === 'nth-child' || name === 'nth-last-child') {
+                    const of = inner.match(/\sof\s(.+)$/);
+                    add = of ? maxSpec(of[1]) : [0, 0, 0];
+                    add = [add[0], add[1] + 1, add[2]];
+                } else if (name === 'slotted') add = [0, 0, 1].map((v, i) => v + maxSpec(inner)[i]);
+                else if (name === 'host' || name === 'host-context') add = [0, 1, 0].map((v, i) => v + maxSpec(inner)[i]);
+                else if (name === 'part') add = [0, 0, 1];
+                else add = maxSpec(inner);
+                spec[0] += add[0];
+                spec[1] += add[1];
+                spec[2] += add[2];
+                rest = rest.replace(whole, ' ');
+            }
+            rest = rest.replace(/\[[^\]]*\]/g, () => { spec[1] += 1; return ' '; });
+            rest = rest.replace(/#[\w-]+/g, () => { spec[0] += 1; return ' '; });
+            rest = rest.replace(/::[\w-]+(\([^)]*\))?/g, () => { spec[2] += 1; return ' '; });
+            rest = rest.replace(/:(before|after|first-line|first-letter)\b/g, () => { spec[2] += 1; return ' '; });
+            rest = rest.replace(/:[\w-]+(\([^)]*\))?/g, () => { spec[1] += 1; return ' '; });
+            rest = rest.replace(/\.[\w-]+/g, () => { spec[1] += 1; return ' '; });
+            for (const part of rest.split(/[\s>+~|&]+/)) {
+                if (/^[a-zA-Z][\w-]*$/.test(part)) spec[2] += 1;
+            }
+            return spec;
+        };
+
+        const declarations = (style) => {
+            const list = [];
+            for (let i = 0; i < style.length; i++) {
+                const property = style[i];
+                list.push({ property, value: style.getPropertyValue(property).trim(), important: style.getPropertyPriority(property) === 'important' });
+            }
+            return list;
+        };
+        const has = (name) => typeof window[name] !== 'undefined';
+
+        let emitted = 0;
+        let truncated = false;
+        const importErrors = [];
+        const visit = (list, prefix) => {
+            const out = [];
+            for (let i = 0; i < list.length; i++) {
+                if (emitted >= args.max_rules) {
+                    truncated = true;
+                    break;
+                }
+                emitted += 1;
+                const rule = list[i];
+                const path = prefix ? prefix + '.' + i : String(i);
+                const entry = { path };
+                if (rule instanceof CSSStyleRule) {
+                    entry.type = 'style';
+                    entry.selector = rule.selectorText;
+                    entry.specificity = splitList(rule.selectorText).map(sel => ({ selector: sel, specificity: specificity(sel) }));
+                    entry.declarations = declarations(rule.style);
+                } else if (has('CSSMediaRule') && rule instanceof CSSMediaRule) {
+                    entry.type = 'media';
+                    entry.condition = rule.conditionText || rule.media.mediaText;
+                    entry.matches = window.matchMedia(entry.condition).matches;
+                } else if (has('CSSSupportsRule') && rule instanceof CSSSupportsRule) {
+                    entry.type = 'supports';
+                    entry.condition = rule.conditionText;
+                    entry.matches = CSS.supports(rule.conditionText);
+                } else if (has('CSSContainerRule') && rule instanceof CSSContainerRule) {
+                    entry.type = 'container';
+                    entry.name = rule.containerName || null;
+                    entry.condition = rule.containerQuery || rule.conditionText;
+                } else if (has('CSSLayerBlockRule') && rule instanceof CSSLayerBlockRule) {
+                    entry.type = 'layer';
+                    entry.name = rule.name || null;
+                } else if (has('CSSLayerStatementRule') && rule instanceof CSSLayerStatementRule) {
+                    entry.type = 'layer_statement';
+                    entry.names = [...rule.nameList];
+                } else if (has('CSSScopeRule') && rule instanceof CSSScopeRule) {
+                    entry.type = 'scope';
+                    entry.start = rule.start || null;
+                    entry.end = rule.end || null;
+                } else if (rule instanceof CSSImportRule) {
+                    entry.type = 'import';
+                    entry.href = rule.href;
+                    entry.media = rule.media && rule.media.mediaText ? rule.media.mediaText : null;
+                    entry.layer = rule.layerName === undefined ? null : rule.layerName;
+                    const imported = rule.styleSheet ? readRules(rule.styleSheet) : { rules: null, error: 'Not loaded' };
+                    entry.accessible = imported.rules !== null;
+                    if (imported.rules === null) importErrors.push({ path, href: rule.href, error: imported.error });
+                    else if (args.include_imports) entry.rules = visit(imported.rules, path);
+                } else if (rule instanceof CSSFontFaceRule) {
+                    entry.type = 'font_face';
+                    entry.declarations = declarations(rule.style);
+                } else if (rule instanceof CSSKeyframesRule) {
+                    entry.type = 'keyframes';
+                    entry.name = rule.name;
+                    entry.keyframes = [...rule.cssRules].map(frame => ({ key: frame.keyText, declarations: declarations(frame.style) }));
+                } else if (rule instanceof CSSPageRule) {
+                    entry.type = 'page';
+                    entry.selector = rule.selectorText || null;
+                    entry.declarations = declarations(rule.style);
+                } else if (rule instanceof CSSNamespaceRule) {
+                    entry.type = 'namespace';
+                    entry.prefix = rule.prefix || null;
+                    entry.namespace = rule.namespaceURI;
+                } else if (has('CSSPropertyRule') && rule instanceof CSSPropertyRule) {
+                    entry.type = 'property';
+                    entry.name = rule.name;
+                    entry.syntax = rule.syntax;
+                    entry.inherits = rule.inherits;
+                    entry.initial_value = rule.initialValue === undefined ? null : rule.initialValue;
+                } else {
+                    entry.type = rule.constructor.name.replace(/^CSS|Rule$/g, '').replace(/[A-Z]/g, (c, i) => (i ? '_' : '') + c.toLowerCase()) || 'unknown';
+                    entry.css_text = rule.cssText.length > 500 ? rule.cssText.substring(0, 500) + '...' : rule.cssText;
+                }
+                // Grouping rules and CSS nesting both expose child rules
+                if (!entry.rules && rule.cssRules && entry.type !== 'keyframes' && rule.cssRules.length > 0) {
+                    entry.rules = visit(rule.cssRules, path);
+                }
+                out.push(entry);
+            }
+            return out;
+        };
+
+        const { rules, error } = readRules(sheet);
+        if (rules === null) {
+            return {
+                sheet: info,
+                rules: null,
+                inaccessible: true,
+                error,
+                note: 'The rules of this cross-origin stylesheet cannot be read from script. Serve it with Access-Control-Allow-Origin and load it with a crossorigin attribute, or fetch ' + (sheet.href || 'the file') + ' directly.'
+            };
+        }
+
+        const tree = visit(rules, '');
+        return {
+            sheet: info,
+            rule_count: emitted,
+            truncated,
+            inaccessible_imports: importErrors,
+            rules: tree
+        };"#;
@@ -0,0 +1,239 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct TabOrderRequest {
+    window_label: Option<String>,
+    /// Press Tab through the page to catch traps set up by key handlers (default false)
+    simulate_tab: Option<bool>,
+    /// Most Tab presses in the simulation (default 100)
+    max_steps: Option<u32>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for computing the sequential focus order and flagging focus traps
+pub async fn handle_get_tab_order<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_tab_order", &payload)
+        .optional("simulate_tab", FieldKind::Bool)
+        .optional("max_steps", FieldKind::Integer { min: Some(1), max: Some(1000) })
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: TabOrderRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_tab_order: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Tab order error",
+        &json!({
+            "simulate_tab": request.simulate_tab.unwrap_or(false),
+            "max_steps": request.max_steps.unwrap_or(100),
+        }),
+        TAB_ORDER_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "tab order",
+    )
+    .await
+}
+
+/// The order follows the HTML sequential focus navigation rules: positive tabindex values
+/// first in ascending order, then tabindex 0 and natively focusable elements in tree
+/// order, with each open shadow root (and the slots in it) ordered as its own scope.
+/// Only the checked radio button of a group, or the first one, is a tab stop.
+///
+/// Synthetic Tab key events do not move focus, so the optional simulation dispatches
+/// the keydown, lets the app's handler move focus when it cancels the event (as focus
+/// trap libraries do), and otherwise moves to the next element in the computed order.
+const TAB_ORDER_JS: &str = r#"        const NATIVE = 'a[href], area[href], button, input:not([type="hidden"]), select, textarea, iframe, embed, object, audio[controls], video[controls], [contenteditable]:not([contenteditable="false"]), details > summary:first-of-type';
+
+        const isRendered = (el) => {
+            if (typeof el.checkVisibility === 'function') {
+                return el.checkVisibility({ visibilityProperty: true });
+            }
+            return el.getClientRects().length > 0 && getComputedStyle(el).visibility !== 'hidden';
+        };
+        const isFocusable = (el) => {
+            if (el.inert || el.closest('[inert]')) return false;
+            if (el.matches(':disabled')) return false;
+            if (!el.hasAttribute('tabindex') && !el.matches(NATIVE)) return false;
+            return isRendered(el);
+        };
+
+        // Tab stops of one scope (document or shadow root), with nested scopes expanded
+        const scopeOrder = (roots) => {
+            const candidates = [];
+            const visit = (node) => {
+                for (const el of node.children || []) {
+                    const entry = { el, nested: [] };
+                    let include = isFocusable(el) && el.tabIndex >= 0;
+                    if (el.shadowRoot) {
+                        entry.nested = scopeOrder([el.shadowRoot]);
+                    } else if (el.localName === 'slot') {
+                        entry.nested = scopeOrder(el.assignedElements({ flatten: true }).map(assigned => ({ children: [assigned] })));
+                    }
+                    if (include || entry.nested.length > 0) {
+                        entry.include = include;
+                        candidates.push(entry);
+                    }
+                    if (!el.shadowRoot && el.localName !== 'slot') visit(el);
+                }
+            };
+            for (const root of roots) visit(root);
+
+            const positive = candidates.filter(c => c.include && c.el.tabIndex > 0).sort((a, b) => a.el.tabIndex - b.el.tabIndex);
+            const rest = candidates.filter(c => !(c.include && c.el.tabIndex > 0));
+            const ordered = [];
+            for (const c of [...positive, ...rest]) {
+                if (c.include) ordered.push(c.el);
+                ordered.push(...c.nested);
+            }
+            return ordered;
+        };
+
+        // Keep one stop per radio group: the checked button, else the first in order
+        const radioStops = new Map();
+        const order = scopeOrder([document]).filter(el => {
+            if (el.localName !== 'input' || el.type !== 'radio' || !el.name) return true;
+            const key = (el.form ? mcp.cssPath(el.form) : ':document') + '|' + el.name;
+            const group = el.form
+                ? [...el.form.elements].filter(other => other.type === 'radio' && other.name === el.name)
+                : [...el.getRootNode().querySelectorAll('input[type="radio"]')].filter(other => other.name === el.name && !other.form);
+            const checked = group.find(other => other.checked);
+            if (checked) return checked === el;
+            if (radioStops.has(key)) return false;
+            radioStops.set(key, el);
+            return true;
+        });
+
+        const label = (el) => {
+            const text = el.getAttribute('aria-label') || el.getAttribute('title') || el.getAttribute('placeholder') || (el.innerText || el.value || '').toString();
+            return text.trim().replace(/\s+/g, ' ').slice(0, 80) || null;
+        };
+        const onScreen = (el) => {
+            const r = el.getBoundingClientRect();
+            const style = getComputedStyle(el);
+            return r.width > 0 && r.height > 0 && style.opacity !== '0' && r.right > 0 && r.bottom > 0 && r.left < window.innerWidth && r.top < window.innerHeight;
+        };
+        const describe = (el, index) => ({
+            index,
+            selector: mcp.cssPath(el),
+            tag: el.localName,
+            tabindex: el.hasAttribute('tabindex') ? el.tabIndex : null,
+            role: el.getAttribute('role'),
+            label: label(el),
+            in_viewport: onScreen(el),
+            in_shadow_dom: el.getRootNode() instanceof ShadowRoot
+        });
+
+        const issues = [];
+        const positiveTabindex = order.filter(el => el.tabIndex > 0);
+        if (positiveTabindex.length > 0) {
+            issues.push({ type: 'positive_tabindex', message: positiveTabindex.length + ' element(s) use a positive tabindex, which overrides the visual order', selectors: positiveTabindex.map(mcp.cssPath) });
+        }
+        const ariaHidden = order.filter(el => el.closest('[aria-hidden="true"]'));
+        if (ariaHidden.length > 0) {
+            issues.push({ type: 'focusable_in_aria_hidden', message: ariaHidden.length + ' tab stop(s) are inside aria-hidden content and are announced as nothing', selectors: ariaHidden.map(mcp.cssPath) });
+        }
+        const invisible = order.filter(el => { const r = el.getBoundingClientRect(); return r.width === 0 || r.height === 0 || getComputedStyle(el).opacity === '0'; });
+        if (invisible.length > 0) {
+            issues.push({ type: 'invisible_tab_stop', message: invisible.length + ' tab stop(s) have no size or are fully transparent', selectors: invisible.map(mcp.cssPath) });
+        }
+
+        // Modal containment: native modal dialogs make the rest inert, aria-modal does not
+        const nativeModal = document.querySelector('dialog:modal');
+        const ariaModal = [...document.querySelectorAll('[aria-modal="true"]')].find(el => isRendered(el));
+        const modalEl = nativeModal || ariaModal || null;
+        let modal = null;
+        if (modalEl) {
+            const inside = order.filter(el => modalEl.contains(el));
+            const outside = order.length - inside.length;
+            modal = { selector: mcp.cssPath(modalEl), native: modalEl === nativeModal, tab_stops_inside: inside.length, tab_stops_outside: outside };
+            if (outside > 0) {
+                issues.push({ type: 'modal_does_not_contain_focus', message: 'An open modal leaves ' + outside + ' tab stop(s) outside it reachable', selectors: order.filter(el => !modalEl.contains(el)).slice(0, 20).map(mcp.cssPath) });
+            }
+            if (inside.length === 0) {
+                issues.push({ type: 'modal_without_tab_stops', message: 'The open modal has no tab stops, so keyboard users cannot act on it', selectors: [modal.selector] });
+            }
+        }
+
+        let simulation = null;
+        if (args.simulate_tab && order.length > 0) {
+            const initial = document.activeElement;
+            const visited = [];
+            const seen = new Set();
+            let cycle = null;
+            let current = order.includes(initial) ? initial : null;
+            for (let step = 0; step < args.max_steps; step++) {
+                const from = current || document.body;
+                const event = new KeyboardEvent('keydown', { key: 'Tab', code: 'Tab', bubbles: true, cancelable: true, composed: true });
+                const proceed = from.dispatchEvent(event);
+                let next;
+                let movedBy;
+                if (!proceed) {
+                    await new Promise(resolve => setTimeout(resolve, 0));
+                    next = document.activeElement;
+                    movedBy = 'app';
+                } else {
+                    const position = current ? order.indexOf(current) : -1;
+                    next = order[(position + 1) % order.length];
+                    next.focus();
+                    movedBy = 'browser_order';
+                }
+                if (!next || next === document.body) {
+                    visited.push({ step, selector: null, moved_by: movedBy });
+                    break;
+                }
+                const key = next;
+                visited.push({ step, selector: mcp.cssPath(next), moved_by: movedBy });
+                if (seen.has(key)) {
+                    cycle = { length: step - visited.findIndex(v => v.element === key), elements: seen.size };
+                    break;
+                }
+                seen.add(key);
+                visited[visited.length - 1].element = key;
+                current = next;
+            }
+            if (initial && typeof initial.focus === 'function') initial.focus();
+            else if (document.activeElement && document.activeElement.blur) document.activeElement.blur();
+
+            const trapped = cycle !== null && seen.size < order.length;
+            if (trapped) {
+                issues.push({ type: 'focus_trap', message: 'Tab cycles through ' + seen.size + ' of ' + order.length + ' tab stops and never reaches the rest', selectors: [...seen].map(mcp.cssPath) });
+            }
+            simulation = {
+                steps: visited.map(({ step, selector, moved_by }) => ({ step, selector, moved_by })),
+                cycled: cycle !== null,
+                distinct_elements: seen.size,
+                trapped,
+                app_handled_steps: visited.filter(v => v.moved_by === 'app').length
+            };
+        }
+
+        return {
+            count: order.length,
+            active_element: document.activeElement && document.activeElement !== document.body ? mcp.cssPath(document.activeElement) : null,
+            order: order.map(describe),
+            modal,
+            issues,
+            simulation
+        };"#;
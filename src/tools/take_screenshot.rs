@@ -3,22 +3,87 @@ use crate::shared::ScreenshotParams;
 use base64;
 use image::DynamicImage;
 use serde_json::Value;
-use tauri::{AppHandle, Runtime};
+use tauri::{AppHandle, Manager, Runtime};
 use log::info;
 use crate::TauriMcpExt;
 use crate::models::ScreenshotRequest;
 use crate::socket_server::SocketResponse;
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+/// Image formats `process_image` can encode, which double as the data URL subtype
+pub const SCREENSHOT_FORMATS: &[&str] = &["png", "jpeg", "webp"];
 
 /// Common function to process and compress an image - used by platform implementations
 pub fn process_image(mut dynamic_image: DynamicImage, params: &ScreenshotParams) -> Result<String> {
     // Extract parameters from the shared struct
-    let quality = params.quality.unwrap_or(85) as u8;
+    let quality = params.quality.unwrap_or(80).clamp(1, 100);
     let max_width = params.max_width.map(|w| w as u32);
     let max_size_bytes = params
         .max_size_mb
         .map(|mb| (mb * 1024.0 * 1024.0) as u64)
         .unwrap_or(2 * 1024 * 1024);
 
+    // Crop to the requested region, converted from logical to captured pixels
+    if let Some(region) = params.region {
+        let scale = params.source_dpr.filter(|dpr| *dpr > 0.0).unwrap_or(1.0);
+        let to_pixels = |value: u32| (value as f64 * scale).round() as u32;
+        let (x, y, width, height) = (to_pixels(region.x), to_pixels(region.y), to_pixels(region.width), to_pixels(region.height));
+        if width == 0 || height == 0 || x.saturating_add(width) > dynamic_image.width() || y.saturating_add(height) > dynamic_image.height() {
+            return Err(Error::invalid_parameter(
+                "region",
+                format!(
+                    "a non-empty rectangle inside the {}x{} capture ({}x{} logical pixels at DPR {})",
+                    dynamic_image.width(),
+                    dynamic_image.height(),
+                    (dynamic_image.width() as f64 / scale).floor(),
+                    (dynamic_image.height() as f64 / scale).floor(),
+                    scale
+                ),
+                format!("{}x{} at ({}, {})", region.width, region.height, region.x, region.y),
+            ));
+        }
+        info!("[SCREENSHOT] Cropping to {}x{} at ({}, {})", width, height, x, y);
+        dynamic_image = dynamic_image.crop_imm(x, y, width, height);
+    }
+
+    // Normalize to the requested device pixel ratio before any size limits apply
+    if let (Some(target_dpr), Some(source_dpr)) = (params.target_dpr, params.source_dpr) {
+        if target_dpr > 0.0 && source_dpr > 0.0 && (target_dpr - source_dpr).abs() > f64::EPSILON {
+            let scale = target_dpr / source_dpr;
+            let width = ((dynamic_image.width() as f64 * scale).round() as u32).max(1);
+            let height = ((dynamic_image.height() as f64 * scale).round() as u32).max(1);
+            info!(
+                "[SCREENSHOT] Normalizing DPR {} -> {}: {}x{} to {}x{}",
+                source_dpr,
+                target_dpr,
+                dynamic_image.width(),
+                dynamic_image.height(),
+                width,
+                height
+            );
+            dynamic_image = dynamic_image.resize_exact(width, height, image::imageops::FilterType::Lanczos3);
+        }
+    }
+
+    // Lossless captures are read back for exact pixel values: PNG unless WebP was asked for
+    let format = match (params.lossless, params.format.as_deref()) {
+        (true, None) => "png",
+        (_, Some(format)) => format,
+        (false, None) => "jpeg",
+    };
+    if !SCREENSHOT_FORMATS.contains(&format) {
+        return Err(Error::invalid_parameter("format", "one of png, jpeg, webp", format));
+    }
+    if params.lossless && format == "jpeg" {
+        return Err(Error::invalid_parameter("format", "png or webp when lossless is set", format));
+    }
+
+    // Lossless captures skip resizing and the size limit
+    if params.lossless {
+        let output_data = encode_image(&dynamic_image, format, quality)?;
+        return Ok(format!("data:image/{};base64,{}", format, base64::encode(&output_data)));
+    }
+
     // Use max_width if specified, otherwise use a default if image is very large
     let effective_max_width = max_width.unwrap_or_else(|| {
         if dynamic_image.width() > 1920 {
@@ -48,121 +113,148 @@ pub fn process_image(mut dynamic_image: DynamicImage, params: &ScreenshotParams)
         );
     }
 
-    // Use JPG for better compression
-    let mut output_data = Vec::new();
     let mut current_quality = quality;
+    let mut output_data = encode_image(&dynamic_image, format, current_quality)?;
 
-    // Try encoding with JPEG
-    match dynamic_image.write_to(
-        &mut std::io::Cursor::new(&mut output_data),
-        image::ImageOutputFormat::Jpeg(current_quality),
-    ) {
-        Ok(_) => {
-            // Reduce quality if needed to meet max size
-            while output_data.len() as u64 > max_size_bytes && current_quality > 30 {
-                info!(
-                    "[SCREENSHOT] Output size {} bytes exceeds max {}. Reducing quality to {}",
-                    output_data.len(),
-                    max_size_bytes,
-                    current_quality - 10
-                );
+    // Reduce quality if needed to meet max size; only JPEG encodes lossy here
+    while format == "jpeg" && output_data.len() as u64 > max_size_bytes && current_quality > 30 {
+        info!(
+            "[SCREENSHOT] Output size {} bytes exceeds max {}. Reducing quality to {}",
+            output_data.len(),
+            max_size_bytes,
+            current_quality - 10
+        );
 
-                // Reduce quality and try again
-                current_quality -= 10;
-                output_data.clear();
-
-                if let Err(e) = dynamic_image.write_to(
-                    &mut std::io::Cursor::new(&mut output_data),
-                    image::ImageOutputFormat::Jpeg(current_quality),
-                ) {
-                    return Err(Error::window_operation_failed(
-                        "image encoding",
-                        format!("Failed to re-encode JPEG: {}", e),
-                    ));
-                }
-            }
+        // Reduce quality and try again
+        current_quality -= 10;
+        output_data = encode_image(&dynamic_image, format, current_quality)?;
+    }
 
-            // If still too large, resize the image
-            if output_data.len() as u64 > max_size_bytes && dynamic_image.width() > 800 {
-                info!("[SCREENSHOT] Image still too large after quality reduction. Resizing...");
-                let scale_factor = 0.8; // reduce by 20% each iteration
-
-                while output_data.len() as u64 > max_size_bytes && dynamic_image.width() > 800 {
-                    // Resize image
-                    let new_width = (dynamic_image.width() as f32 * scale_factor) as u32;
-                    let new_height = (dynamic_image.height() as f32 * scale_factor) as u32;
-
-                    info!("[SCREENSHOT] Resizing to {}x{}", new_width, new_height);
-                    dynamic_image = dynamic_image.resize(
-                        new_width,
-                        new_height,
-                        image::imageops::FilterType::Triangle,
-                    );
-
-                    // Re-encode with current quality
-                    output_data.clear();
-                    if let Err(e) = dynamic_image.write_to(
-                        &mut std::io::Cursor::new(&mut output_data),
-                        image::ImageOutputFormat::Jpeg(current_quality),
-                    ) {
-                        return Err(Error::window_operation_failed(
-                            "image resizing",
-                            format!("Failed to encode resized image: {}", e),
-                        ));
-                    }
-
-                    // Give up if we're getting very small
-                    if dynamic_image.width() <= 800 {
-                        break;
-                    }
-                }
-            }
+    // If still too large, resize the image
+    if output_data.len() as u64 > max_size_bytes && dynamic_image.width() > 800 {
+        info!("[SCREENSHOT] Image still too large after quality reduction. Resizing...");
+        let scale_factor = 0.8; // reduce by 20% each iteration
 
-            // Convert to base64
-            let base64_data = base64::encode(&output_data);
-            let data_url = format!("data:image/jpeg;base64,{}", base64_data);
+        while output_data.len() as u64 > max_size_bytes && dynamic_image.width() > 800 {
+            // Resize image
+            let new_width = (dynamic_image.width() as f32 * scale_factor) as u32;
+            let new_height = (dynamic_image.height() as f32 * scale_factor) as u32;
 
-            info!(
-                "[SCREENSHOT] Final image size: {}x{}, data size: {} bytes, quality: {}",
-                dynamic_image.width(),
-                dynamic_image.height(),
-                output_data.len(),
-                current_quality
+            info!("[SCREENSHOT] Resizing to {}x{}", new_width, new_height);
+            dynamic_image = dynamic_image.resize(
+                new_width,
+                new_height,
+                image::imageops::FilterType::Triangle,
             );
 
-            // Final check - reject if still too large
-            if base64_data.len() > 5 * 1024 * 1024 {
-                return Err(Error::window_operation_failed(
-                    "screenshot compression",
-                    format!(
-                        "Screenshot is still too large: {} bytes. Try using a smaller max_width.",
-                        base64_data.len()
-                    ),
-                ));
-            }
+            // Re-encode with current quality
+            output_data = encode_image(&dynamic_image, format, current_quality)?;
 
-            Ok(data_url)
+            // Give up if we're getting very small
+            if dynamic_image.width() <= 800 {
+                break;
+            }
         }
-        Err(e) => Err(Error::window_operation_failed(
-            "JPEG encoding",
-            format!("Failed to encode JPEG: {}", e),
-        )),
     }
+
+    // Convert to base64
+    let base64_data = base64::encode(&output_data);
+    let data_url = format!("data:image/{};base64,{}", format, base64_data);
+
+    info!(
+        "[SCREENSHOT] Final image size: {}x{}, data size: {} bytes, format: {}, quality: {}",
+        dynamic_image.width(),
+        dynamic_image.height(),
+        output_data.len(),
+        format,
+        current_quality
+    );
+
+    // Final check - reject if still too large
+    if base64_data.len() > 5 * 1024 * 1024 {
+        return Err(Error::window_operation_failed(
+            "screenshot compression",
+            format!(
+                "Screenshot is still too large: {} bytes. Try using a smaller max_width or the jpeg format.",
+                base64_data.len()
+            ),
+        ));
+    }
+
+    Ok(data_url)
+}
+
+/// Encode an image in one of the screenshot formats. PNG is lossless and the image crate
+/// only encodes WebP losslessly, so quality only affects JPEG and is ignored otherwise.
+fn encode_image(image: &DynamicImage, format: &str, quality: u8) -> Result<Vec<u8>> {
+    let output_format = match format {
+        "png" => image::ImageOutputFormat::Png,
+        "webp" => image::ImageOutputFormat::WebP,
+        _ => image::ImageOutputFormat::Jpeg(quality),
+    };
+    let mut output_data = Vec::new();
+    image
+        .write_to(&mut std::io::Cursor::new(&mut output_data), output_format)
+        .map_err(|e| {
+            Error::window_operation_failed(
+                "image encoding",
+                format!("Failed to encode {}: {}", format.to_uppercase(), e),
+            )
+        })?;
+    Ok(output_data)
+}
+
+/// Decode a screenshot data URL back into an image
+pub fn decode_data_url(data_url: &str) -> Result<DynamicImage> {
+    let encoded = data_url.split_once(',').map(|(_, data)| data).unwrap_or(data_url);
+    let bytes = base64::decode(encoded)
+        .map_err(|e| Error::serialization_error(format!("Invalid screenshot data: {}", e)))?;
+    image::load_from_memory(&bytes)
+        .map_err(|e| Error::window_operation_failed("screenshot decoding", format!("Failed to decode screenshot: {}", e)))
 }
 
 pub async fn handle_take_screenshot<R: Runtime>(
     app: &AppHandle<R>,
     payload: Value,
 ) -> Result<SocketResponse> {
+    PayloadValidator::new("takeScreenshot", &payload)
+        .required("window_label", FieldKind::String)
+        .optional("target_dpr", FieldKind::Number { min: Some(0.25), max: Some(8.0) })
+        .optional("region", FieldKind::Object)
+        .optional("format", FieldKind::OneOf(SCREENSHOT_FORMATS))
+        .optional("quality", FieldKind::INT)
+        .optional("lossless", FieldKind::Bool)
+        .finish()?;
+
+    // Quality is clamped into 1-100 rather than rejected, whatever the format
+    let mut payload = payload;
+    if let Some(quality) = payload.get("quality").and_then(|q| q.as_i64().or(q.as_u64().map(|_| i64::MAX))) {
+        payload["quality"] = serde_json::json!(quality.clamp(1, 100));
+    }
+
     let payload: ScreenshotRequest = serde_json::from_value(payload)
         .map_err(|e| Error::Anyhow { message: format!("Invalid payload for takeScreenshot: {}", e) })?;
 
+    let target_dpr = payload.target_dpr;
+    let source_dpr = app
+        .get_webview_window(&payload.window_label)
+        .and_then(|window| window.scale_factor().ok());
+
     // Call the async method
     let result = app.tauri_mcp().take_screenshot_async(payload).await;
     match result {
         Ok(response) => {
-            let data = serde_json::to_value(response)
+            let mut data = serde_json::to_value(response)
                 .map_err(|e| Error::Anyhow { message: format!("Failed to serialize response: {}", e) })?;
+            if let Some(object) = data.as_object_mut() {
+                object.insert("sourceDpr".to_string(), serde_json::json!(source_dpr));
+                object.insert("targetDpr".to_string(), serde_json::json!(target_dpr));
+                // The fallback capture path for other Unix systems has no native pixels to rescale
+                object.insert(
+                    "dprNormalized".to_string(),
+                    serde_json::json!(target_dpr.is_some() && cfg!(any(target_os = "macos", target_os = "windows", target_os = "linux"))),
+                );
+            }
             Ok(SocketResponse {
                 success: true,
                 data: Some(data),
@@ -5,12 +5,19 @@ use crate::TauriMcpExt;
 use crate::error::Error;
 use crate::models::TextInputRequest;
 use crate::socket_server::SocketResponse;
+use crate::tools::validation::{FieldKind, PayloadValidator};
 
 pub async fn handle_simulate_text_input<R: Runtime>(
     app: &AppHandle<R>,
     payload: Value,
 ) -> Result<SocketResponse, Error> {
     // Parse the payload
+    PayloadValidator::new("simulateTextInput", &payload)
+        .required("text", FieldKind::String)
+        .optional("delayMs", FieldKind::UINT)
+        .optional("initialDelayMs", FieldKind::UINT)
+        .finish()?;
+
     let params: TextInputRequest = serde_json::from_value(payload)
         .map_err(|e| Error::serialization_error(format!("Invalid payload for simulateTextInput: {}", e)))?;
 
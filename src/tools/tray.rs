@@ -0,0 +1,161 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+/// Event emitted to the app when a tray menu item activation is simulated.
+///
+/// Tauri offers no way to inject a real `MenuEvent`, so `on_menu_event` handlers are
+/// not called. This event is opt-in: apps that want tray flows to be testable route it
+/// to the same logic as their menu handler.
+pub const TRAY_MENU_ACTIVATE_EVENT: &str = "tauri-mcp://tray-menu-item-activate";
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct TrayRequest {
+    action: String,
+    tray_id: Option<String>,
+    item_id: Option<String>,
+}
+
+/// Handler function for tray inspection and menu item activation
+pub async fn handle_tray<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    let validator = PayloadValidator::new("tray", &payload);
+    let action = validator.field("action").and_then(|v| v.as_str());
+    validator
+        .required("action", FieldKind::OneOf(&["get_state", "activate_menu_item"]))
+        .optional("tray_id", FieldKind::String)
+        .required_if(action == Some("activate_menu_item"), "item_id", FieldKind::NonEmptyString)
+        .finish()?;
+
+    let request: TrayRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for tray: {}", e)))?;
+
+    // Tauri gives config-defined trays the id "main" unless one is set
+    let tray_id = request.tray_id.clone().unwrap_or_else(|| "main".to_string());
+
+    tray_action(app, &request, &tray_id)
+}
+
+#[cfg(all(desktop, feature = "tray"))]
+fn tray_action<R: Runtime>(
+    app: &AppHandle<R>,
+    request: &TrayRequest,
+    tray_id: &str,
+) -> Result<SocketResponse, Error> {
+    use crate::tools::menu_items::{describe_item, find_item};
+    use crate::TauriMcpExt;
+    use tauri::menu::MenuItemKind;
+    use tauri::Emitter;
+
+    let failure = |message: String| -> Result<SocketResponse, Error> {
+        Ok(SocketResponse {
+            success: false,
+            data: None,
+            error: Some(message),
+        })
+    };
+
+    let Some(tray) = app.tray_by_id(tray_id) else {
+        return failure(format!("No tray icon configured with id '{}'", tray_id));
+    };
+
+    // Tauri cannot read a tray's menu back, so it is only known if the app registered it
+    let menu = app.tauri_mcp().tray_menu(tray_id);
+    let items = match &menu {
+        Some(menu) => Some(
+            menu.items()
+                .map_err(|e| Error::window_operation_failed("read tray menu items", e.to_string()))?,
+        ),
+        None => None,
+    };
+
+    match request.action.as_str() {
+        "get_state" => {
+            let rect = tray
+                .rect()
+                .ok()
+                .flatten()
+                .and_then(|rect| serde_json::to_value(rect).ok());
+
+            // Icon, tooltip, title and visibility are write-only in Tauri and cannot be reported
+            Ok(SocketResponse {
+                success: true,
+                data: Some(json!({
+                    "tray_id": tray.id().0,
+                    "exists": true,
+                    "rect": rect,
+                    "menu_registered": items.is_some(),
+                    "menu": items.as_ref().map(|items| items.iter().map(|item| describe_item(item, 0)).collect::<Vec<_>>()),
+                })),
+                error: None,
+            })
+        }
+        _ => {
+            let item_id = request.item_id.clone().unwrap_or_default();
+            let Some(items) = items else {
+                return failure(format!(
+                    "The menu of tray '{}' is unknown; register it with `app.tauri_mcp().register_tray_menu(\"{}\", menu)`",
+                    tray_id, tray_id
+                ));
+            };
+
+            let mut path = Vec::new();
+            let Some(item) = find_item(items, &item_id, &mut path) else {
+                return Err(Error::invalid_parameter(
+                    "item_id",
+                    format!("the id of an item in the '{}' tray menu", tray_id),
+                    item_id,
+                ));
+            };
+            if matches!(item, MenuItemKind::Submenu(_)) {
+                return failure(format!("Tray menu item '{}' is a submenu and cannot be activated", item_id));
+            }
+
+            app.emit(
+                TRAY_MENU_ACTIVATE_EVENT,
+                json!({ "tray_id": tray_id, "id": item_id }),
+            )
+            .map_err(|e| Error::communication_error_with_context(
+                "Failed to emit tray menu activation",
+                format!("tray: {}, item: {}, error: {}", tray_id, item_id, e),
+            ))?;
+
+            Ok(SocketResponse {
+                success: true,
+                data: Some(json!({
+                    "tray_id": tray_id,
+                    "item_id": item_id,
+                    "path": path,
+                    "event": TRAY_MENU_ACTIVATE_EVENT,
+                    "dispatched": true,
+                    // Only apps that route the event react; no real MenuEvent is fired
+                    "menu_event_fired": false,
+                    "note": format!("Emitted the opt-in {} event; on_menu_event handlers are not called", TRAY_MENU_ACTIVATE_EVENT),
+                })),
+                error: None,
+            })
+        }
+    }
+}
+
+#[cfg(not(all(desktop, feature = "tray")))]
+fn tray_action<R: Runtime>(
+    _app: &AppHandle<R>,
+    request: &TrayRequest,
+    tray_id: &str,
+) -> Result<SocketResponse, Error> {
+    Ok(SocketResponse {
+        success: false,
+        data: Some(json!({
+            "tray_id": tray_id,
+            "action": request.action,
+            "tray_supported": false,
+        })),
+        error: Some("Tray support is not enabled; build tauri-plugin-mcp with the `tray` feature".to_string()),
+    })
+}
@@ -0,0 +1,319 @@
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager, Runtime, Webview};
+use log::{info, warn};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::validation::{FieldKind, PayloadValidator};
+use crate::TauriMcpExt;
+
+/// Largest snippet accepted by `register_user_script`
+const MAX_SOURCE_BYTES: usize = 1024 * 1024;
+
+/// A CSS or JS snippet re-injected into matching windows on every page load
+#[derive(Debug, Clone, Serialize)]
+pub struct UserScript {
+    pub id: String,
+    /// "css" or "js"
+    pub kind: String,
+    pub source: String,
+    /// Window the script is limited to, or `None` for every window
+    pub window_label: Option<String>,
+    pub created_at_ms: u64,
+}
+
+impl UserScript {
+    fn applies_to(&self, label: &str) -> bool {
+        self.window_label.as_deref().is_none_or(|l| l == label)
+    }
+
+    /// Script that applies the snippet once per page. CSS goes into a tagged `<style>`
+    /// element that `unregister_user_script` can remove again; JS runs through a global
+    /// `eval` so a syntax error in the snippet is reported instead of breaking the wrapper.
+    fn injection_script(&self) -> String {
+        let id = Value::String(self.id.clone());
+        let source = Value::String(self.source.clone());
+        if self.kind == "css" {
+            format!(
+                r#"(function() {{
+    const id = {id};
+    let style = document.querySelector('style[data-mcp-user-script="' + id + '"]');
+    if (!style) {{
+        style = document.createElement('style');
+        style.setAttribute('data-mcp-user-script', id);
+        (document.head || document.documentElement).appendChild(style);
+    }}
+    style.textContent = {source};
+}})();"#
+            )
+        } else {
+            format!(
+                r#"(function() {{
+    const ran = window.__MCP_USER_SCRIPTS__ || (window.__MCP_USER_SCRIPTS__ = {{}});
+    const id = {id};
+    if (ran[id]) return;
+    ran[id] = true;
+    try {{
+        (0, eval)({source});
+    }} catch (error) {{
+        console.error('[tauri-mcp] User script ' + id + ' failed:', error);
+    }}
+}})();"#
+            )
+        }
+    }
+}
+
+/// User scripts registered through the socket, in registration order
+#[derive(Debug, Default)]
+pub struct UserScriptRegistry {
+    next_id: u64,
+    scripts: Vec<UserScript>,
+}
+
+impl UserScriptRegistry {
+    pub fn add(&mut self, kind: &str, source: String, window_label: Option<String>) -> UserScript {
+        self.next_id += 1;
+        let script = UserScript {
+            id: format!("user-script-{}", self.next_id),
+            kind: kind.to_string(),
+            source,
+            window_label,
+            created_at_ms: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or(0),
+        };
+        self.scripts.push(script.clone());
+        script
+    }
+
+    pub fn remove(&mut self, id: &str) -> Option<UserScript> {
+        let index = self.scripts.iter().position(|script| script.id == id)?;
+        Some(self.scripts.remove(index))
+    }
+
+    pub fn remove_all(&mut self) -> Vec<UserScript> {
+        std::mem::take(&mut self.scripts)
+    }
+
+    pub fn scripts(&self) -> &[UserScript] {
+        &self.scripts
+    }
+}
+
+/// Re-apply the registered user scripts to a freshly loaded webview
+pub fn inject_user_scripts<R: Runtime>(webview: &Webview<R>) {
+    let label = webview.label().to_string();
+    let Some(mcp) = webview.try_state::<crate::TauriMcp<R>>() else {
+        return;
+    };
+    let scripts: Vec<UserScript> = mcp
+        .with_user_scripts(|registry| {
+            registry
+                .scripts()
+                .iter()
+                .filter(|script| script.applies_to(&label))
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if scripts.is_empty() {
+        return;
+    }
+    info!("[TAURI_MCP] Re-injecting {} user script(s) into window {}", scripts.len(), label);
+
+    for script in scripts {
+        if let Err(e) = webview.eval(&script.injection_script()) {
+            warn!("[TAURI_MCP] Failed to inject user script {} into window {}: {}", script.id, label, e);
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct RegisterUserScriptRequest {
+    /// "css" or "js"
+    kind: String,
+    source: String,
+    window_label: Option<String>,
+    /// Apply to every window instead of only `window_label` (default false)
+    all_windows: Option<bool>,
+    /// Also apply to the currently loaded page (default true)
+    inject_now: Option<bool>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct UnregisterUserScriptRequest {
+    /// Handle returned by `register_user_script`
+    id: Option<String>,
+    /// Remove every registered script (default false)
+    all: Option<bool>,
+}
+
+/// Handler function for registering a CSS/JS snippet that survives reloads
+pub async fn handle_register_user_script<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("register_user_script", &payload)
+        .required("kind", FieldKind::OneOf(&["css", "js"]))
+        .required("source", FieldKind::NonEmptyString)
+        .optional("window_label", FieldKind::String)
+        .optional("all_windows", FieldKind::Bool)
+        .optional("inject_now", FieldKind::Bool)
+        .finish()?;
+
+    let request: RegisterUserScriptRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for register_user_script: {}", e)))?;
+
+    if request.source.len() > MAX_SOURCE_BYTES {
+        return Err(Error::invalid_parameter(
+            "source",
+            format!("at most {} bytes", MAX_SOURCE_BYTES),
+            format!("{} bytes", request.source.len()),
+        ));
+    }
+
+    let all_windows = request.all_windows.unwrap_or(false);
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    if !all_windows && app.get_webview_window(&window_label).is_none() {
+        return Err(Error::window_not_found(&window_label));
+    }
+
+    let script = app
+        .tauri_mcp()
+        .with_user_scripts(|registry| {
+            registry.add(
+                &request.kind,
+                request.source.clone(),
+                (!all_windows).then(|| window_label.clone()),
+            )
+        })
+        .ok_or_else(|| Error::Anyhow {
+            message: "User script registry is unavailable".to_string(),
+        })?;
+
+    let mut injected_into = Vec::new();
+    if request.inject_now.unwrap_or(true) {
+        let code = script.injection_script();
+        for (label, window) in app.webview_windows() {
+            if !script.applies_to(&label) {
+                continue;
+            }
+            match window.eval(&code) {
+                Ok(()) => injected_into.push(label),
+                Err(e) => warn!("[TAURI_MCP] Failed to inject user script {} into window {}: {}", script.id, label, e),
+            }
+        }
+        injected_into.sort();
+    }
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(json!({
+            "id": script.id,
+            "kind": script.kind,
+            "window_label": script.window_label,
+            "source_bytes": script.source.len(),
+            "injected_into": injected_into,
+        })),
+        error: None,
+    })
+}
+
+/// Handler function for removing one or all registered user scripts
+pub async fn handle_unregister_user_script<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    let validator = PayloadValidator::new("unregister_user_script", &payload);
+    let all = validator.field("all").and_then(|v| v.as_bool()).unwrap_or(false);
+    validator
+        .required_if(!all, "id", FieldKind::NonEmptyString)
+        .optional("all", FieldKind::Bool)
+        .finish()?;
+
+    let request: UnregisterUserScriptRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for unregister_user_script: {}", e)))?;
+
+    let removed = app
+        .tauri_mcp()
+        .with_user_scripts(|registry| {
+            if request.all.unwrap_or(false) {
+                registry.remove_all()
+            } else {
+                registry.remove(request.id.as_deref().unwrap_or_default()).into_iter().collect()
+            }
+        })
+        .unwrap_or_default();
+
+    if removed.is_empty() && !all {
+        return Ok(SocketResponse {
+            success: false,
+            data: None,
+            error: Some(format!(
+                "No user script registered with id '{}'",
+                request.id.unwrap_or_default()
+            )),
+        });
+    }
+
+    // Injected styles can be taken out again; scripts that already ran cannot be undone
+    for script in removed.iter().filter(|script| script.kind == "css") {
+        let selector = Value::String(format!("style[data-mcp-user-script=\"{}\"]", script.id));
+        for (label, window) in app.webview_windows() {
+            if script.applies_to(&label) {
+                let _ = window.eval(&format!("document.querySelectorAll({}).forEach(el => el.remove());", selector));
+            }
+        }
+    }
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(json!({
+            "removed": removed.iter().map(|script| &script.id).collect::<Vec<_>>(),
+            "js_effects_remain": removed.iter().any(|script| script.kind == "js"),
+        })),
+        error: None,
+    })
+}
+
+/// Handler function for listing the registered user scripts
+pub async fn handle_list_user_scripts<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("list_user_scripts", &payload)
+        .optional("window_label", FieldKind::String)
+        .finish()?;
+
+    let window_label = payload.get("window_label").and_then(|v| v.as_str());
+    let scripts = app
+        .tauri_mcp()
+        .with_user_scripts(|registry| {
+            registry
+                .scripts()
+                .iter()
+                .filter(|script| window_label.is_none_or(|label| script.applies_to(label)))
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(json!({
+            "count": scripts.len(),
+            "scripts": scripts,
+        })),
+        error: None,
+    })
+}
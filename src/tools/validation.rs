@@ -0,0 +1,302 @@
+use serde_json::Value;
+
+use crate::error::{Error, ValidationIssue};
+
+/// Eviction policies accepted by the capture injection commands
+pub const EVICTION_POLICIES: &[&str] = &["fifo", "keep_errors", "sampled"];
+
+/// Expected shape of a single payload field
+#[derive(Debug, Clone, Copy)]
+pub enum FieldKind {
+    String,
+    NonEmptyString,
+    Bool,
+    Integer { min: Option<i64>, max: Option<i64> },
+    Number { min: Option<f64>, max: Option<f64> },
+    OneOf(&'static [&'static str]),
+    Object,
+    Array,
+}
+
+impl FieldKind {
+    /// Any integer
+    pub const INT: FieldKind = FieldKind::Integer { min: None, max: None };
+    /// Non-negative integer (durations, sizes, limits)
+    pub const UINT: FieldKind = FieldKind::Integer { min: Some(0), max: None };
+
+    fn describe(&self) -> String {
+        match self {
+            FieldKind::String => "a string".to_string(),
+            FieldKind::NonEmptyString => "a non-empty string".to_string(),
+            FieldKind::Bool => "a boolean".to_string(),
+            FieldKind::Integer { min, max } => match (min, max) {
+                (Some(min), Some(max)) => format!("an integer between {} and {}", min, max),
+                (Some(min), None) => format!("an integer >= {}", min),
+                (None, Some(max)) => format!("an integer <= {}", max),
+                (None, None) => "an integer".to_string(),
+            },
+            FieldKind::Number { min, max } => match (min, max) {
+                (Some(min), Some(max)) => format!("a number between {} and {}", min, max),
+                (Some(min), None) => format!("a number >= {}", min),
+                (None, Some(max)) => format!("a number <= {}", max),
+                (None, None) => "a number".to_string(),
+            },
+            FieldKind::OneOf(values) => format!("one of {}", values.join("|")),
+            FieldKind::Object => "an object".to_string(),
+            FieldKind::Array => "an array".to_string(),
+        }
+    }
+
+    fn accepts(&self, value: &Value) -> bool {
+        match self {
+            FieldKind::String => value.is_string(),
+            FieldKind::NonEmptyString => value.as_str().map(|s| !s.trim().is_empty()).unwrap_or(false),
+            FieldKind::Bool => value.is_boolean(),
+            FieldKind::Integer { min, max } => match value.as_i64() {
+                Some(n) => min.map_or(true, |min| n >= min) && max.map_or(true, |max| n <= max),
+                // Large unsigned values only fail an explicit upper bound
+                None => value.is_u64() && max.is_none(),
+            },
+            FieldKind::Number { min, max } => match value.as_f64() {
+                Some(n) => min.map_or(true, |min| n >= min) && max.map_or(true, |max| n <= max),
+                None => false,
+            },
+            FieldKind::OneOf(values) => value
+                .as_str()
+                .map(|s| values.contains(&s))
+                .unwrap_or(false),
+            FieldKind::Object => value.is_object(),
+            FieldKind::Array => value.is_array(),
+        }
+    }
+}
+
+/// Short description of a received value for error messages
+fn describe_value(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Bool(b) => format!("boolean {}", b),
+        Value::Number(n) => format!("number {}", n),
+        Value::String(s) => {
+            let preview: String = s.chars().take(40).collect();
+            if preview.len() < s.len() {
+                format!("string \"{}...\"", preview)
+            } else {
+                format!("string \"{}\"", preview)
+            }
+        }
+        Value::Array(items) => format!("array of {} item(s)", items.len()),
+        Value::Object(_) => "object".to_string(),
+    }
+}
+
+/// Collects every problem in a command payload so clients can fix them in one
+/// round trip, instead of serde reporting only the first.
+pub struct PayloadValidator<'a> {
+    command: &'a str,
+    payload: &'a Value,
+    issues: Vec<ValidationIssue>,
+}
+
+impl<'a> PayloadValidator<'a> {
+    pub fn new(command: &'a str, payload: &'a Value) -> Self {
+        let mut issues = Vec::new();
+        if !payload.is_object() {
+            issues.push(ValidationIssue {
+                param: "payload".to_string(),
+                expected: "an object".to_string(),
+                received: describe_value(payload),
+            });
+        }
+        Self {
+            command,
+            payload,
+            issues,
+        }
+    }
+
+    /// Value of a field, treating `null` as absent
+    pub fn field(&self, name: &str) -> Option<&'a Value> {
+        self.payload.get(name).filter(|v| !v.is_null())
+    }
+
+    /// The field must be present and match `kind`
+    pub fn required(mut self, name: &str, kind: FieldKind) -> Self {
+        if !self.payload.is_object() {
+            return self;
+        }
+        match self.field(name) {
+            Some(value) => self.check(name, kind, value),
+            None => self.issues.push(ValidationIssue {
+                param: name.to_string(),
+                expected: kind.describe(),
+                received: "missing".to_string(),
+            }),
+        }
+        self
+    }
+
+    /// The field may be absent or null, but must match `kind` when given
+    pub fn optional(mut self, name: &str, kind: FieldKind) -> Self {
+        if let Some(value) = self.field(name) {
+            self.check(name, kind, value);
+        }
+        self
+    }
+
+    /// The field is required only when `condition` holds (e.g. depends on an action)
+    pub fn required_if(self, condition: bool, name: &str, kind: FieldKind) -> Self {
+        if condition {
+            self.required(name, kind)
+        } else {
+            self.optional(name, kind)
+        }
+    }
+
+    /// Validate the fields of a nested object; its issues are reported as `name.field`
+    pub fn nested(mut self, name: &str, fields: impl FnOnce(PayloadValidator<'a>) -> PayloadValidator<'a>) -> Self {
+        let Some(value) = self.field(name) else {
+            return self;
+        };
+        if !value.is_object() {
+            self.check(name, FieldKind::Object, value);
+            return self;
+        }
+        let inner = fields(PayloadValidator::new(self.command, value));
+        self.issues.extend(inner.issues.into_iter().map(|mut issue| {
+            issue.param = format!("{}.{}", name, issue.param);
+            issue
+        }));
+        self
+    }
+
+    /// Return all collected issues as a single error
+    pub fn finish(self) -> Result<(), Error> {
+        if self.issues.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::validation_failed(self.command, self.issues))
+        }
+    }
+
+    fn check(&mut self, name: &str, kind: FieldKind, value: &Value) {
+        if !kind.accepts(value) {
+            self.issues.push(ValidationIssue {
+                param: name.to_string(),
+                expected: kind.describe(),
+                received: describe_value(value),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn issues(result: Result<(), Error>) -> Vec<ValidationIssue> {
+        match result {
+            Err(Error::ValidationFailed { issues, .. }) => issues,
+            Err(other) => panic!("unexpected error: {}", other),
+            Ok(()) => Vec::new(),
+        }
+    }
+
+    #[test]
+    fn valid_payload_passes() {
+        let payload = json!({ "selector": "#app", "timeout_ms": 100, "window_label": null });
+        let result = PayloadValidator::new("cmd", &payload)
+            .required("selector", FieldKind::NonEmptyString)
+            .optional("timeout_ms", FieldKind::UINT)
+            .optional("window_label", FieldKind::String)
+            .finish();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn every_problem_is_reported() {
+        let payload = json!({ "selector": "  ", "timeout_ms": -1, "format": "gif" });
+        let found = issues(
+            PayloadValidator::new("cmd", &payload)
+                .required("selector", FieldKind::NonEmptyString)
+                .optional("timeout_ms", FieldKind::UINT)
+                .optional("format", FieldKind::OneOf(&["png", "jpeg"]))
+                .required("window_label", FieldKind::String)
+                .finish(),
+        );
+        let params: Vec<&str> = found.iter().map(|issue| issue.param.as_str()).collect();
+        assert_eq!(params, ["selector", "timeout_ms", "format", "window_label"]);
+        assert_eq!(found[1].expected, "an integer >= 0");
+        assert_eq!(found[1].received, "number -1");
+        assert_eq!(found[3].received, "missing");
+    }
+
+    #[test]
+    fn non_object_payload_is_one_issue() {
+        let payload = json!("selector");
+        let found = issues(
+            PayloadValidator::new("cmd", &payload)
+                .required("selector", FieldKind::NonEmptyString)
+                .finish(),
+        );
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].param, "payload");
+    }
+
+    #[test]
+    fn integer_bounds() {
+        let kind = FieldKind::Integer { min: Some(1), max: Some(10) };
+        assert!(kind.accepts(&json!(1)));
+        assert!(kind.accepts(&json!(10)));
+        assert!(!kind.accepts(&json!(0)));
+        assert!(!kind.accepts(&json!(11)));
+        assert!(!kind.accepts(&json!(1.5)));
+        // Beyond i64 only passes without an upper bound
+        assert!(FieldKind::UINT.accepts(&json!(u64::MAX)));
+        assert!(!kind.accepts(&json!(u64::MAX)));
+    }
+
+    #[test]
+    fn required_if_only_requires_when_the_condition_holds() {
+        let payload = json!({});
+        assert!(PayloadValidator::new("cmd", &payload)
+            .required_if(false, "text", FieldKind::String)
+            .finish()
+            .is_ok());
+        assert_eq!(
+            issues(
+                PayloadValidator::new("cmd", &payload)
+                    .required_if(true, "text", FieldKind::String)
+                    .finish()
+            )
+            .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn nested_issues_are_prefixed_with_the_object_name() {
+        let payload = json!({ "filter": { "limit": -1, "url": 3 }, "other": "x" });
+        let found = issues(
+            PayloadValidator::new("cmd", &payload)
+                .nested("filter", |filter| {
+                    filter
+                        .optional("limit", FieldKind::UINT)
+                        .optional("url", FieldKind::String)
+                })
+                .nested("other", |other| other)
+                .nested("missing", |missing| missing.required("x", FieldKind::Bool))
+                .finish(),
+        );
+        let params: Vec<&str> = found.iter().map(|i| i.param.as_str()).collect();
+        assert_eq!(params, vec!["filter.limit", "filter.url", "other"]);
+    }
+
+    #[test]
+    fn long_strings_are_shortened_in_messages() {
+        let long = "x".repeat(100);
+        assert_eq!(describe_value(&json!(long)), format!("string \"{}...\"", "x".repeat(40)));
+        assert_eq!(describe_value(&json!("short")), "string \"short\"");
+    }
+}
@@ -4,6 +4,10 @@ use std::fmt;
 use std::sync::mpsc;
 use tauri::{AppHandle, Error as TauriError, Listener, Manager, Runtime, WebviewWindow};
 
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+const SELECTOR_TYPES: &[&str] = &["id", "class", "tag", "text", "xpath", "css"];
+
 // Custom error enum for the get_dom_text command
 #[derive(Debug)] // Add Serialize for the enum itself if it needs to be directly serialized
 // For now, we serialize its string representation
@@ -157,6 +161,14 @@ pub async fn handle_get_element_position<R: Runtime>(
     app: &AppHandle<R>,
     payload: Value,
 ) -> Result<crate::socket_server::SocketResponse, crate::error::Error> {
+    PayloadValidator::new("get_element_position", &payload)
+        .required("window_label", FieldKind::String)
+        .required("selector_type", FieldKind::OneOf(SELECTOR_TYPES))
+        .required("selector_value", FieldKind::NonEmptyString)
+        .optional("should_click", FieldKind::Bool)
+        .optional("raw_coordinates", FieldKind::Bool)
+        .finish()?;
+
     // Parse the payload
     let payload = serde_json::from_value::<GetElementPositionPayload>(payload).map_err(|e| {
         crate::error::Error::serialization_error(format!("Invalid payload for get_element_position: {}", e))
@@ -256,6 +268,14 @@ pub async fn handle_send_text_to_element<R: Runtime>(
     app: &AppHandle<R>,
     payload: Value,
 ) -> Result<crate::socket_server::SocketResponse, crate::error::Error> {
+    PayloadValidator::new("send_text_to_element", &payload)
+        .required("window_label", FieldKind::String)
+        .required("selector_type", FieldKind::OneOf(SELECTOR_TYPES))
+        .required("selector_value", FieldKind::NonEmptyString)
+        .required("text", FieldKind::String)
+        .optional("delay_ms", FieldKind::Integer { min: Some(0), max: Some(u32::MAX as i64) })
+        .finish()?;
+
     // Parse the payload
     let payload = serde_json::from_value::<SendTextToElementPayload>(payload).map_err(|e| {
         crate::error::Error::serialization_error(format!("Invalid payload for send_text_to_element: {}", e))
@@ -0,0 +1,126 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, eval_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct WebviewInfoRequest {
+    window_label: Option<String>,
+    timeout_ms: Option<u64>,
+}
+
+/// Webview engine Tauri uses on the current platform
+fn platform_engine() -> &'static str {
+    if cfg!(target_os = "windows") {
+        "WebView2 (Chromium)"
+    } else if cfg!(any(target_os = "macos", target_os = "ios")) {
+        "WKWebView (WebKit)"
+    } else if cfg!(target_os = "android") {
+        "Android System WebView (Chromium)"
+    } else {
+        "WebKitGTK (WebKit)"
+    }
+}
+
+/// Handler function for reporting the webview engine, version and feature support of a window
+pub async fn handle_get_webview_info<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_webview_info", &payload)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: WebviewInfoRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_webview_info: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script("Webview info error", &json!({}), WEBVIEW_INFO_JS);
+
+    let page = match eval_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "webview inspection",
+    )
+    .await
+    {
+        Ok(page) => page,
+        Err(Error::Anyhow { message }) => {
+            return Ok(SocketResponse {
+                success: false,
+                data: None,
+                error: Some(message),
+            })
+        }
+        Err(e) => return Err(e),
+    };
+
+    // The native version is authoritative; the user agent only approximates it on WebKit
+    let native_version = tauri::webview_version().ok();
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(json!({
+            "window_label": window_label,
+            "engine": platform_engine(),
+            "version": native_version,
+            "tauri_version": tauri::VERSION,
+            "os": std::env::consts::OS,
+            "arch": std::env::consts::ARCH,
+            "user_agent": page.get("user_agent"),
+            "user_agent_version": page.get("user_agent_version"),
+            "features": page.get("features"),
+            "display": page.get("display"),
+        })),
+        error: None,
+    })
+}
+
+/// Feature detection for the APIs other tools depend on
+const WEBVIEW_INFO_JS: &str = r#"        const ua = navigator.userAgent;
+        const match = ua.match(/(Edg|Chrome)\/([\d.]+)/) || ua.match(/Version\/([\d.]+).*Safari/) || ua.match(/AppleWebKit\/([\d.]+)/);
+        const observerTypes = typeof PerformanceObserver !== 'undefined' ? (PerformanceObserver.supportedEntryTypes || []) : [];
+
+        return {
+            user_agent: ua,
+            user_agent_version: match ? match[match.length - 1] : null,
+            features: {
+                performance_memory: typeof performance !== 'undefined' && 'memory' in performance,
+                performance_observer: typeof PerformanceObserver !== 'undefined',
+                observer_entry_types: observerTypes,
+                longtask: observerTypes.includes('longtask'),
+                layout_shift: observerTypes.includes('layout-shift'),
+                largest_contentful_paint: observerTypes.includes('largest-contentful-paint'),
+                clipboard: typeof navigator.clipboard !== 'undefined',
+                clipboard_read: !!(navigator.clipboard && navigator.clipboard.read),
+                service_worker: 'serviceWorker' in navigator,
+                web_gl: (() => { try { return !!document.createElement('canvas').getContext('webgl'); } catch (e) { return false; } })(),
+                web_gl2: typeof WebGL2RenderingContext !== 'undefined',
+                web_gpu: 'gpu' in navigator,
+                resize_observer: typeof ResizeObserver !== 'undefined',
+                intersection_observer: typeof IntersectionObserver !== 'undefined',
+                css_has: typeof CSS !== 'undefined' && CSS.supports && CSS.supports('selector(:has(a))'),
+                css_container_queries: typeof CSS !== 'undefined' && CSS.supports && CSS.supports('container-type: inline-size'),
+                dialog_element: typeof HTMLDialogElement !== 'undefined',
+                popover: typeof HTMLElement !== 'undefined' && 'popover' in HTMLElement.prototype,
+                view_transitions: 'startViewTransition' in document
+            },
+            display: {
+                device_pixel_ratio: window.devicePixelRatio,
+                viewport: { width: window.innerWidth, height: window.innerHeight },
+                screen: { width: screen.width, height: screen.height },
+                color_scheme: matchMedia('(prefers-color-scheme: dark)').matches ? 'dark' : 'light',
+                reduced_motion: matchMedia('(prefers-reduced-motion: reduce)').matches
+            }
+        };"#;
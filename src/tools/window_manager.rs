@@ -1,15 +1,32 @@
-use serde_json::Value;
-use tauri::{AppHandle, Runtime};
+use serde_json::{json, Value};
+use tauri::{AppHandle, Manager, Runtime};
 
 use crate::TauriMcpExt;
 use crate::error::Error;
 use crate::models::WindowManagerRequest;
 use crate::socket_server::SocketResponse;
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+const WINDOW_OPERATIONS: &[&str] = &[
+    "minimize", "maximize", "unmaximize", "close", "show", "hide",
+    "setPosition", "setSize", "center", "toggleFullscreen", "focus",
+];
 
 pub async fn handle_manage_window<R: Runtime>(
     app: &AppHandle<R>,
     payload: Value,
 ) -> Result<SocketResponse, Error> {
+    let validator = PayloadValidator::new("manageWindow", &payload);
+    let operation = validator.field("operation").and_then(|v| v.as_str());
+    validator
+        .required("operation", FieldKind::OneOf(WINDOW_OPERATIONS))
+        .optional("window_label", FieldKind::String)
+        .required_if(operation == Some("setPosition"), "x", FieldKind::INT)
+        .required_if(operation == Some("setPosition"), "y", FieldKind::INT)
+        .required_if(operation == Some("setSize"), "width", FieldKind::UINT)
+        .required_if(operation == Some("setSize"), "height", FieldKind::UINT)
+        .finish()?;
+
     let payload: WindowManagerRequest = serde_json::from_value(payload)
         .map_err(|e| Error::serialization_error(format!("Invalid payload for manageWindow: {}", e)))?;
 
@@ -32,3 +49,223 @@ pub async fn handle_manage_window<R: Runtime>(
         }),
     }
 }
+
+/// Geometry and state of one window, as captured by `export_window_layout`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct WindowLayout {
+    label: String,
+    /// Outer position in physical pixels, as `setPosition` applies it
+    x: i32,
+    y: i32,
+    /// Inner size in physical pixels, as `setSize` applies it
+    width: u32,
+    height: u32,
+    #[serde(default)]
+    maximized: bool,
+    #[serde(default)]
+    minimized: bool,
+    #[serde(default)]
+    fullscreen: bool,
+    #[serde(default = "default_visible")]
+    visible: bool,
+    #[serde(default)]
+    focused: bool,
+}
+
+fn default_visible() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ImportWindowLayoutRequest {
+    /// Layout blob from `export_window_layout`
+    layout: ImportedLayout,
+    /// Only restore these windows
+    labels: Option<Vec<String>>,
+    /// Also restore maximized/minimized/fullscreen/visible/focus (default true)
+    restore_state: Option<bool>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ImportedLayout {
+    windows: Vec<WindowLayout>,
+}
+
+/// Handler function for capturing the geometry and state of every window
+pub fn handle_export_window_layout<R: Runtime>(
+    app: &AppHandle<R>,
+    _payload: Value,
+) -> Result<SocketResponse, Error> {
+    let mut windows: Vec<(String, tauri::WebviewWindow<R>)> = app.webview_windows().into_iter().collect();
+    windows.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut layouts = Vec::new();
+    let mut errors = Vec::new();
+    for (label, window) in windows {
+        let geometry = window
+            .outer_position()
+            .and_then(|position| window.inner_size().map(|size| (position, size)));
+        let (position, size) = match geometry {
+            Ok(geometry) => geometry,
+            Err(e) => {
+                errors.push(json!({ "label": label, "error": e.to_string() }));
+                continue;
+            }
+        };
+        let monitor = window.current_monitor().ok().flatten();
+        let layout = WindowLayout {
+            label: label.clone(),
+            x: position.x,
+            y: position.y,
+            width: size.width,
+            height: size.height,
+            maximized: window.is_maximized().unwrap_or(false),
+            minimized: window.is_minimized().unwrap_or(false),
+            fullscreen: window.is_fullscreen().unwrap_or(false),
+            visible: window.is_visible().unwrap_or(true),
+            focused: window.is_focused().unwrap_or(false),
+        };
+        let mut entry = serde_json::to_value(&layout)
+            .map_err(|e| Error::serialization_error(format!("Failed to serialize window layout: {}", e)))?;
+        // Informational only; ignored on import
+        entry["title"] = json!(window.title().ok());
+        entry["scale_factor"] = json!(window.scale_factor().ok());
+        entry["monitor"] = json!(monitor.as_ref().and_then(|m| m.name().cloned()));
+        layouts.push(entry);
+    }
+
+    let captured_at_ms = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0);
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(json!({
+            "version": 1,
+            "captured_at_ms": captured_at_ms,
+            "windows": layouts,
+            "errors": errors,
+        })),
+        error: None,
+    })
+}
+
+/// Handler function for restoring a layout captured by `export_window_layout`
+pub async fn handle_import_window_layout<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("import_window_layout", &payload)
+        .required("layout", FieldKind::Object)
+        .optional("labels", FieldKind::Array)
+        .optional("restore_state", FieldKind::Bool)
+        .finish()?;
+
+    let request: ImportWindowLayoutRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for import_window_layout: {}", e)))?;
+    let restore_state = request.restore_state.unwrap_or(true);
+
+    let mut results = Vec::new();
+    let mut restored = 0;
+    let mut skipped = 0;
+    let mut focus_label = None;
+
+    for layout in &request.layout.windows {
+        if let Some(labels) = &request.labels {
+            if !labels.contains(&layout.label) {
+                continue;
+            }
+        }
+        let Some(window) = app.get_webview_window(&layout.label) else {
+            skipped += 1;
+            results.push(json!({ "label": layout.label, "status": "skipped", "reason": "window does not exist" }));
+            continue;
+        };
+
+        let mut steps = Vec::new();
+        // Geometry only applies to a normal window, so leave fullscreen/maximized/minimized first
+        if window.is_fullscreen().unwrap_or(false) {
+            steps.push(run_operation(app, &layout.label, "toggleFullscreen", None, None).await);
+        }
+        if window.is_maximized().unwrap_or(false) {
+            steps.push(run_operation(app, &layout.label, "unmaximize", None, None).await);
+        }
+        if window.is_minimized().unwrap_or(false) {
+            // manage_window has no unminimize operation
+            let result = window.unminimize().map_err(|e| e.to_string());
+            steps.push(json!({ "operation": "unminimize", "success": result.is_ok(), "error": result.err() }));
+        }
+        steps.push(run_operation(app, &layout.label, "setPosition", Some((layout.x, layout.y)), None).await);
+        steps.push(run_operation(app, &layout.label, "setSize", None, Some((layout.width, layout.height))).await);
+
+        if restore_state {
+            if layout.visible != window.is_visible().unwrap_or(true) {
+                let operation = if layout.visible { "show" } else { "hide" };
+                steps.push(run_operation(app, &layout.label, operation, None, None).await);
+            }
+            if layout.fullscreen {
+                steps.push(run_operation(app, &layout.label, "toggleFullscreen", None, None).await);
+            } else if layout.maximized {
+                steps.push(run_operation(app, &layout.label, "maximize", None, None).await);
+            } else if layout.minimized {
+                steps.push(run_operation(app, &layout.label, "minimize", None, None).await);
+            }
+            if layout.focused {
+                focus_label = Some(layout.label.clone());
+            }
+        }
+
+        let success = steps.iter().all(|step| step["success"].as_bool().unwrap_or(false));
+        if success {
+            restored += 1;
+        }
+        results.push(json!({
+            "label": layout.label,
+            "status": if success { "restored" } else { "partial" },
+            "steps": steps,
+        }));
+    }
+
+    // Focus last so restoring the other windows does not steal it
+    if let Some(label) = focus_label {
+        let step = run_operation(app, &label, "focus", None, None).await;
+        if let Some(result) = results.iter_mut().find(|result| result["label"] == json!(label)) {
+            if let Some(steps) = result["steps"].as_array_mut() {
+                steps.push(step);
+            }
+        }
+    }
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(json!({
+            "restored": restored,
+            "skipped": skipped,
+            "windows": results,
+        })),
+        error: None,
+    })
+}
+
+/// Run one `manage_window` operation and describe its outcome as a restore step
+async fn run_operation<R: Runtime>(
+    app: &AppHandle<R>,
+    label: &str,
+    operation: &str,
+    position: Option<(i32, i32)>,
+    size: Option<(u32, u32)>,
+) -> Value {
+    let request = WindowManagerRequest {
+        window_label: Some(label.to_string()),
+        operation: operation.to_string(),
+        x: position.map(|(x, _)| x),
+        y: position.map(|(_, y)| y),
+        width: size.map(|(width, _)| width),
+        height: size.map(|(_, height)| height),
+    };
+    match app.tauri_mcp().manage_window_async(request).await {
+        Ok(_) => json!({ "operation": operation, "success": true }),
+        Err(e) => json!({ "operation": operation, "success": false, "error": e.to_string() }),
+    }
+}
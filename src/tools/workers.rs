@@ -0,0 +1,181 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Runtime};
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, run_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct GetWorkersRequest {
+    window_label: Option<String>,
+    /// Include the most recent messages in each direction (default false)
+    include_messages: Option<bool>,
+    /// Messages per direction when `include_messages` is set (default 5)
+    message_limit: Option<usize>,
+    /// Include workers that were terminated (default true)
+    include_terminated: Option<bool>,
+    timeout_ms: Option<u64>,
+}
+
+/// Handler function for reporting dedicated and shared workers created by the page
+pub async fn handle_get_workers<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_workers", &payload)
+        .optional("include_messages", FieldKind::Bool)
+        .optional("message_limit", FieldKind::Integer { min: Some(1), max: Some(20) })
+        .optional("include_terminated", FieldKind::Bool)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: GetWorkersRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_workers: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let js_code = build_probe_script(
+        "Worker inspection error",
+        &json!({
+            "include_messages": request.include_messages.unwrap_or(false),
+            "message_limit": request.message_limit.unwrap_or(5),
+            "include_terminated": request.include_terminated.unwrap_or(true),
+        }),
+        GET_WORKERS_JS,
+    );
+
+    run_probe(
+        app,
+        &window_label,
+        js_code,
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "worker inspection",
+    )
+    .await
+}
+
+/// The first call replaces the `Worker` and `SharedWorker` constructors with proxies
+/// that register each new instance, so only workers created after that are known.
+/// Each instance gets its own `postMessage`/`terminate` wrappers and a passive
+/// `message` listener; the page's use of the worker is otherwise unchanged.
+const GET_WORKERS_JS: &str = r#"        const HISTORY = 20;
+        let installedNow = false;
+        let registry = window.__MCP_WORKERS__;
+
+        if (!registry) {
+            installedNow = true;
+            registry = window.__MCP_WORKERS__ = { installed_at_ms: Date.now(), next_id: 1, workers: [] };
+
+            const preview = (data) => {
+                let text;
+                try {
+                    text = JSON.stringify(data, (key, value) => {
+                        if (value instanceof ArrayBuffer) return '[ArrayBuffer ' + value.byteLength + ' bytes]';
+                        if (ArrayBuffer.isView(value)) return '[' + value.constructor.name + ' ' + value.byteLength + ' bytes]';
+                        if (typeof value === 'bigint') return value.toString() + 'n';
+                        return value;
+                    });
+                } catch (error) {
+                    text = '[Unserializable: ' + error.message + ']';
+                }
+                if (text === undefined) text = String(data);
+                return text.length > 500 ? text.substring(0, 500) + '...' : text;
+            };
+            const remember = (list, data) => {
+                list.push({ timestamp_ms: Date.now(), data: preview(data) });
+                if (list.length > HISTORY) list.shift();
+            };
+
+            const register = (kind, instance, port, url, options) => {
+                const entry = {
+                    id: registry.next_id++,
+                    kind,
+                    script_url: (() => { try { return new URL(String(url), location.href).href; } catch (e) { return String(url); } })(),
+                    name: (typeof options === 'string' ? options : options && options.name) || null,
+                    type: (options && typeof options === 'object' && options.type) || 'classic',
+                    created_at_ms: Date.now(),
+                    state: kind === 'shared' ? 'connected' : 'running',
+                    messages_sent: 0,
+                    messages_received: 0,
+                    message_errors: 0,
+                    errors: 0,
+                    last_error: null,
+                    sent: [],
+                    received: []
+                };
+                registry.workers.push(entry);
+
+                const originalPost = port.postMessage;
+                port.postMessage = function (message) {
+                    entry.messages_sent += 1;
+                    remember(entry.sent, message);
+                    return originalPost.apply(this, arguments);
+                };
+                EventTarget.prototype.addEventListener.call(port, 'message', (event) => {
+                    entry.messages_received += 1;
+                    remember(entry.received, event.data);
+                });
+                EventTarget.prototype.addEventListener.call(port, 'messageerror', () => { entry.message_errors += 1; });
+                EventTarget.prototype.addEventListener.call(instance, 'error', (event) => {
+                    entry.errors += 1;
+                    entry.last_error = event.message || 'Worker error (script failed to load or threw during startup)';
+                });
+                if (typeof instance.terminate === 'function') {
+                    const originalTerminate = instance.terminate;
+                    instance.terminate = function () {
+                        entry.state = 'terminated';
+                        entry.terminated_at_ms = Date.now();
+                        return originalTerminate.apply(this, arguments);
+                    };
+                }
+            };
+
+            if (typeof Worker === 'function') {
+                window.Worker = new Proxy(Worker, {
+                    construct(target, ctorArgs, newTarget) {
+                        const worker = Reflect.construct(target, ctorArgs, newTarget);
+                        register('dedicated', worker, worker, ctorArgs[0], ctorArgs[1]);
+                        return worker;
+                    }
+                });
+            }
+            if (typeof SharedWorker === 'function') {
+                window.SharedWorker = new Proxy(SharedWorker, {
+                    construct(target, ctorArgs, newTarget) {
+                        const worker = Reflect.construct(target, ctorArgs, newTarget);
+                        register('shared', worker, worker.port, ctorArgs[0], ctorArgs[1]);
+                        return worker;
+                    }
+                });
+            }
+        }
+
+        const workers = registry.workers
+            .filter(entry => args.include_terminated || entry.state !== 'terminated')
+            .map(entry => {
+                const { sent, received, ...rest } = entry;
+                if (!args.include_messages) return rest;
+                return {
+                    ...rest,
+                    last_sent: sent.slice(-args.message_limit),
+                    last_received: received.slice(-args.message_limit)
+                };
+            });
+
+        return {
+            tracking_since_ms: registry.installed_at_ms,
+            installed_now: installedNow,
+            supported: { dedicated: typeof Worker === 'function', shared: typeof SharedWorker === 'function' },
+            active: registry.workers.filter(entry => entry.state !== 'terminated').length,
+            total_created: registry.workers.length,
+            workers,
+            note: installedNow
+                ? 'Worker tracking was just installed; workers created before this call are not visible. Repeat the action under test, then call again.'
+                : null
+        };"#;
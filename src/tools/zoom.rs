@@ -0,0 +1,205 @@
+use serde_json::{json, Value};
+use tauri::{AppHandle, Manager, Runtime};
+use log::info;
+
+use crate::error::Error;
+use crate::socket_server::SocketResponse;
+use crate::tools::js_probe::{build_probe_script, eval_probe, DEFAULT_PROBE_TIMEOUT_MS};
+use crate::tools::validation::{FieldKind, PayloadValidator};
+use crate::TauriMcpExt;
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct GetZoomRequest {
+    window_label: Option<String>,
+    timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct SetZoomRequest {
+    window_label: Option<String>,
+    factor: f64,
+    /// Skip the native webview zoom and apply CSS zoom to the document instead
+    force_css: Option<bool>,
+    timeout_ms: Option<u64>,
+}
+
+/// Whether the webview zoom can be set natively on this platform
+fn native_zoom_supported() -> bool {
+    cfg!(desktop)
+}
+
+/// Handler function for reading the effective zoom factor of a window
+pub async fn handle_get_zoom<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("get_zoom", &payload)
+        .optional("window_label", FieldKind::String)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: GetZoomRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for get_zoom: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let page = eval_probe(
+        app,
+        &window_label,
+        build_probe_script("Zoom error", &json!({}), READ_ZOOM_JS),
+        request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS),
+        "zoom inspection",
+    )
+    .await;
+
+    let page = match page {
+        Ok(page) => page,
+        Err(Error::Anyhow { message }) => {
+            return Ok(SocketResponse {
+                success: false,
+                data: None,
+                error: Some(message),
+            })
+        }
+        Err(e) => return Err(e),
+    };
+
+    // Tauri has no getter for the native zoom, so report what we last applied
+    let native_factor = app.tauri_mcp().zoom_level(&window_label).unwrap_or(1.0);
+    let css_factor = page.get("css_zoom").and_then(|v| v.as_f64()).unwrap_or(1.0);
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(json!({
+            "window_label": window_label,
+            "factor": native_factor * css_factor,
+            "native_factor": native_factor,
+            "css_factor": css_factor,
+            "device_pixel_ratio": page.get("device_pixel_ratio"),
+            "viewport": page.get("viewport"),
+            "platform": std::env::consts::OS,
+            "native_supported": native_zoom_supported(),
+        })),
+        error: None,
+    })
+}
+
+/// Handler function for applying a zoom factor to a window
+pub async fn handle_set_zoom<R: Runtime>(
+    app: &AppHandle<R>,
+    payload: Value,
+) -> Result<SocketResponse, Error> {
+    PayloadValidator::new("set_zoom", &payload)
+        .required("factor", FieldKind::Number { min: Some(0.1), max: Some(10.0) })
+        .optional("window_label", FieldKind::String)
+        .optional("force_css", FieldKind::Bool)
+        .optional("timeout_ms", FieldKind::UINT)
+        .finish()?;
+
+    let request: SetZoomRequest = serde_json::from_value(payload)
+        .map_err(|e| Error::serialization_error(format!("Invalid payload for set_zoom: {}", e)))?;
+
+    // Get the window label or use "main" as default
+    let window_label = request
+        .window_label
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    let window = app
+        .get_webview_window(&window_label)
+        .ok_or_else(|| Error::window_not_found(&window_label))?;
+
+    let timeout_ms = request.timeout_ms.unwrap_or(DEFAULT_PROBE_TIMEOUT_MS);
+
+    // Prefer the native webview zoom; fall back to CSS zoom if it's unavailable
+    let native_error = if request.force_css.unwrap_or(false) || !native_zoom_supported() {
+        Some("native zoom skipped".to_string())
+    } else {
+        window.set_zoom(request.factor).err().map(|e| e.to_string())
+    };
+
+    let (method, native_factor, css_factor) = match native_error {
+        None => {
+            app.tauri_mcp().set_zoom_level(&window_label, request.factor);
+            // Drop any CSS fallback from an earlier call so the factors don't compound
+            if let Err(e) = window.eval(RESET_CSS_ZOOM_JS) {
+                info!("[TAURI_MCP] Failed to reset CSS zoom for {}: {}", window_label, e);
+            }
+            ("native", request.factor, 1.0)
+        }
+        Some(reason) => {
+            info!("[TAURI_MCP] Falling back to CSS zoom for {}: {}", window_label, reason);
+            let native_factor = app.tauri_mcp().zoom_level(&window_label).unwrap_or(1.0);
+            let css_factor = request.factor / native_factor;
+            eval_probe(
+                app,
+                &window_label,
+                build_probe_script("Zoom error", &json!({ "factor": css_factor }), APPLY_CSS_ZOOM_JS),
+                timeout_ms,
+                "CSS zoom",
+            )
+            .await?;
+            ("css", native_factor, css_factor)
+        }
+    };
+
+    Ok(SocketResponse {
+        success: true,
+        data: Some(json!({
+            "window_label": window_label,
+            "factor": native_factor * css_factor,
+            "method": method,
+            "native_factor": native_factor,
+            "css_factor": css_factor,
+            "platform": std::env::consts::OS,
+            "native_supported": native_zoom_supported(),
+        })),
+        error: None,
+    })
+}
+
+/// Reads the CSS zoom we may have applied plus the page's own view of scaling
+const READ_ZOOM_JS: &str = r#"        const root = document.documentElement;
+        const applied = parseFloat(root.dataset.mcpZoom || '');
+        return {
+            css_zoom: Number.isFinite(applied) ? applied : 1,
+            device_pixel_ratio: window.devicePixelRatio,
+            viewport: {
+                width: window.innerWidth,
+                height: window.innerHeight,
+                scale: window.visualViewport ? window.visualViewport.scale : 1
+            }
+        };"#;
+
+/// Removes a CSS zoom previously applied by `APPLY_CSS_ZOOM_JS`
+const RESET_CSS_ZOOM_JS: &str = r#"(() => {
+    const root = document.documentElement;
+    if (!root.dataset.mcpZoom) return;
+    root.style.zoom = '';
+    root.style.transform = '';
+    root.style.transformOrigin = '';
+    root.style.width = '';
+    delete root.dataset.mcpZoom;
+})();"#;
+
+/// Applies a CSS zoom factor to the document, using a transform where `zoom` is unsupported
+const APPLY_CSS_ZOOM_JS: &str = r#"        const root = document.documentElement;
+        const factor = args.factor;
+        const reset = factor === 1;
+        if ('zoom' in root.style) {
+            root.style.zoom = reset ? '' : String(factor);
+        } else {
+            root.style.transform = reset ? '' : 'scale(' + factor + ')';
+            root.style.transformOrigin = reset ? '' : '0 0';
+            root.style.width = reset ? '' : (100 / factor) + '%';
+        }
+        if (reset) {
+            delete root.dataset.mcpZoom;
+        } else {
+            root.dataset.mcpZoom = String(factor);
+        }
+        return { css_zoom: factor };"#;